@@ -0,0 +1 @@
+(function(){var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};var a=function(b){return b+1};debugger;})();
@@ -0,0 +1,2 @@
+// @generated by a code generator, do not edit.
+debugger;
@@ -0,0 +1,6 @@
+/* eslint no-debugger: "off", eqeqeq: ["warn", "smart"] -- legacy code */
+
+debugger;
+
+if (a == b) {
+}
@@ -0,0 +1,3 @@
+import { a, b, missing } from "./lib/a";
+
+export default a + b + missing;
@@ -0,0 +1,4 @@
+// `await` is an identifier in scripts
+var await = 1;
+
+module.exports = await;
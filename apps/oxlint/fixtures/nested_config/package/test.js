@@ -0,0 +1 @@
+var b = a == 1 || a == 2;
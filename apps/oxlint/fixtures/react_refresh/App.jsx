@@ -0,0 +1,3 @@
+export const title = "Hello";
+
+export const App = () => <h1>{title}</h1>;
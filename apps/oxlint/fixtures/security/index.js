@@ -0,0 +1,8 @@
+/* eslint no-proto: "off" */
+debugger;
+eval("1 + 1");
+const a = {};
+a.__proto__ = null;
+a.hasOwnProperty("b");
+new Buffer(10);
+/(a+)+/.test("a");
@@ -0,0 +1,3 @@
+const self = require('./index');
+
+module.exports = self;
@@ -0,0 +1,4 @@
+// eslint-disable-next-line no-debugger -- stepping through the tests
+debugger;
+// eslint-disable-next-line no-debugger
+debugger;
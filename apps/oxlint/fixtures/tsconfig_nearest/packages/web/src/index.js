@@ -0,0 +1,3 @@
+import { title, missing } from "@/utils";
+
+document.title = title + missing;
@@ -0,0 +1,4 @@
+// eslint-disable-next-line no-debugger
+debugger;
+// eslint-disable-next-line no-console
+debugger;
@@ -0,0 +1,2 @@
+// eslint-disable-next-line import/no-unused-modules -- kept for the plugins
+export const disabled = 1;
@@ -0,0 +1,5 @@
+import { used } from './utils';
+import * as all from './all';
+
+export { reexported } from './utils';
+export const main = [used, all];
@@ -0,0 +1,2 @@
+/* eslint import/no-unused-modules: "off" */
+export const off = 1;
@@ -0,0 +1,5 @@
+export const used = 1;
+export const unused = 2;
+export const reexported = 3;
+// eslint-disable-next-line import/no-unused-modules
+export const suppressed = 4;
@@ -0,0 +1,16 @@
+;; A broken WebAssembly rule plugin, which returns 4 GiB of problems out of its memory.
+;;
+;; Build `huge_output.wasm` with `wat2wasm huge_output.wat -o huge_output.wasm`.
+(module
+  (memory (export "memory") 1)
+
+  (data (i32.const 0) "{\"name\":\"huge-output\",\"rules\":[{\"name\":\"huge-output\"}]}")
+
+  (func (export "oxlint_alloc") (param $size i32) (result i32)
+    (i32.const 1024))
+
+  (func (export "oxlint_plugin") (result i64)
+    (i64.const 55))
+
+  (func (export "oxlint_lint") (param $ptr i32) (param $len i32) (result i64)
+    (i64.const 0xffffffff)))
@@ -0,0 +1,138 @@
+;; A WebAssembly rule plugin with a single rule, `fixture/no-foo`, reporting the identifiers named
+;; `foo` with a fix renaming them to `bar`. It finds them in the ESTree JSON of the program, which
+;; has the `start` and `end` of each node before its `name`.
+;;
+;; Build `no_foo.wasm` with `wat2wasm no_foo.wat -o no_foo.wasm`.
+(module
+  (memory (export "memory") 1)
+
+  (data (i32.const 0) "{\"name\":\"fixture\",\"rules\":[{\"name\":\"no-foo\"}]}")
+  (data (i32.const 128) "\"start\":")
+  (data (i32.const 144) ",\"end\":")
+  (data (i32.const 160) ",\"name\":\"foo\"")
+  (data (i32.const 192) "{\"rule\":\"no-foo\",\"message\":\"Unexpected identifier `foo`\",\"help\":\"Rename it to `bar`\",\"start\":")
+  (data (i32.const 320) ",\"fix\":{\"content\":\"bar\",\"start\":")
+  (data (i32.const 384) "}}")
+
+  ;; Start of the free memory
+  (global $heap (mut i32) (i32.const 1024))
+  ;; End of the output written so far
+  (global $out (mut i32) (i32.const 0))
+  ;; Position following the last number read by `$read_number`
+  (global $pos (mut i32) (i32.const 0))
+
+  (func $alloc (export "oxlint_alloc") (param $size i32) (result i32)
+    (local $ptr i32)
+    (local $pages i32)
+    (local.set $ptr (global.get $heap))
+    (global.set $heap (i32.add (local.get $ptr) (local.get $size)))
+    (local.set $pages
+      (i32.sub
+        (i32.shr_u (i32.add (global.get $heap) (i32.const 65535)) (i32.const 16))
+        (memory.size)))
+    (if (i32.gt_s (local.get $pages) (i32.const 0))
+      (then
+        (if (i32.eq (memory.grow (local.get $pages)) (i32.const -1))
+          (then (unreachable)))))
+    (local.get $ptr))
+
+  (func (export "oxlint_plugin") (result i64)
+    (i64.const 46))
+
+  ;; Whether the `len` bytes at `pattern` are found at `ptr`, before `end`.
+  (func $starts_with (param $ptr i32) (param $end i32) (param $pattern i32) (param $len i32)
+    (result i32)
+    (local $i i32)
+    (if (i32.gt_u (i32.add (local.get $ptr) (local.get $len)) (local.get $end))
+      (then (return (i32.const 0))))
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+        (if (i32.ne
+              (i32.load8_u (i32.add (local.get $ptr) (local.get $i)))
+              (i32.load8_u (i32.add (local.get $pattern) (local.get $i))))
+          (then (return (i32.const 0))))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $next)))
+    (i32.const 1))
+
+  ;; The decimal number at `ptr`, setting `$pos` after its last digit.
+  (func $read_number (param $ptr i32) (param $end i32) (result i32)
+    (local $n i32)
+    (local $digit i32)
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $ptr) (local.get $end)))
+        (local.set $digit (i32.sub (i32.load8_u (local.get $ptr)) (i32.const 48)))
+        (br_if $done (i32.gt_u (local.get $digit) (i32.const 9)))
+        (local.set $n (i32.add (i32.mul (local.get $n) (i32.const 10)) (local.get $digit)))
+        (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+        (br $next)))
+    (global.set $pos (local.get $ptr))
+    (local.get $n))
+
+  (func $write_byte (param $byte i32)
+    (i32.store8 (global.get $out) (local.get $byte))
+    (global.set $out (i32.add (global.get $out) (i32.const 1))))
+
+  (func $write (param $ptr i32) (param $len i32)
+    (local $i i32)
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+        (call $write_byte (i32.load8_u (i32.add (local.get $ptr) (local.get $i))))
+        (local.set $i (i32.add (local.get $i) (i32.const 1)))
+        (br $next))))
+
+  (func $write_number (param $n i32)
+    (if (i32.ge_u (local.get $n) (i32.const 10))
+      (then (call $write_number (i32.div_u (local.get $n) (i32.const 10)))))
+    (call $write_byte (i32.add (i32.rem_u (local.get $n) (i32.const 10)) (i32.const 48))))
+
+  (func $write_span (param $start i32) (param $end i32)
+    (call $write_number (local.get $start))
+    (call $write (i32.const 144) (i32.const 7))
+    (call $write_number (local.get $end)))
+
+  (func (export "oxlint_lint") (param $ptr i32) (param $len i32) (result i64)
+    (local $end i32)
+    (local $output i32)
+    (local $start_offset i32)
+    (local $end_offset i32)
+    (local $first i32)
+    (local.set $end (i32.add (local.get $ptr) (local.get $len)))
+    ;; A diagnostic is shorter than 8 times the JSON of the identifier it reports
+    (local.set $output (call $alloc (i32.add (i32.mul (local.get $len) (i32.const 8)) (i32.const 2))))
+    (global.set $out (local.get $output))
+    (local.set $first (i32.const 1))
+    (call $write_byte (i32.const 91)) ;; [
+    (block $done
+      (loop $next
+        (br_if $done (i32.ge_u (local.get $ptr) (local.get $end)))
+        (if (call $starts_with (local.get $ptr) (local.get $end) (i32.const 128) (i32.const 8))
+          (then
+            (local.set $start_offset
+              (call $read_number (i32.add (local.get $ptr) (i32.const 8)) (local.get $end)))
+            (local.set $ptr (global.get $pos))
+            (if (call $starts_with (local.get $ptr) (local.get $end) (i32.const 144) (i32.const 7))
+              (then
+                (local.set $end_offset
+                  (call $read_number (i32.add (local.get $ptr) (i32.const 7)) (local.get $end)))
+                (local.set $ptr (global.get $pos))
+                (if (call $starts_with (local.get $ptr) (local.get $end) (i32.const 160) (i32.const 13))
+                  (then
+                    (if (i32.eqz (local.get $first))
+                      (then (call $write_byte (i32.const 44)))) ;; ,
+                    (local.set $first (i32.const 0))
+                    (call $write (i32.const 192) (i32.const 93))
+                    (call $write_span (local.get $start_offset) (local.get $end_offset))
+                    (call $write (i32.const 320) (i32.const 32))
+                    (call $write_span (local.get $start_offset) (local.get $end_offset))
+                    (call $write (i32.const 384) (i32.const 2))))))
+            (br $next)))
+        (local.set $ptr (i32.add (local.get $ptr) (i32.const 1)))
+        (br $next)))
+    (call $write_byte (i32.const 93)) ;; ]
+    (i64.or
+      (i64.shl (i64.extend_i32_u (local.get $output)) (i64.const 32))
+      (i64.extend_i32_u (i32.sub (global.get $out) (local.get $output))))))
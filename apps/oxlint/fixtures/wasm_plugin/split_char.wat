@@ -0,0 +1,17 @@
+;; A broken WebAssembly rule plugin, which reports a problem splitting the `é` of `unicode.js`.
+;;
+;; Build `split_char.wasm` with `wat2wasm split_char.wat -o split_char.wasm`.
+(module
+  (memory (export "memory") 1)
+
+  (data (i32.const 0) "{\"name\":\"split-char\",\"rules\":[{\"name\":\"split-char\"}]}")
+  (data (i32.const 128) "[{\"rule\":\"split-char\",\"message\":\"Unexpected character\",\"start\":25,\"end\":26}]")
+
+  (func (export "oxlint_alloc") (param $size i32) (result i32)
+    (i32.const 1024))
+
+  (func (export "oxlint_plugin") (result i64)
+    (i64.const 53))
+
+  (func (export "oxlint_lint") (param $ptr i32) (param $len i32) (result i64)
+    (i64.or (i64.shl (i64.const 128) (i64.const 32)) (i64.const 76))))
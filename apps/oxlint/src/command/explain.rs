@@ -0,0 +1,12 @@
+use bpaf::Bpaf;
+
+use super::VERSION;
+
+/// Print the documentation of a rule.
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options("explain"), version(VERSION))]
+pub struct ExplainCommand {
+    /// Name of the rule, e.g. `no-debugger` or `unicorn/no-null`
+    #[bpaf(positional("RULE"))]
+    pub rule: String,
+}
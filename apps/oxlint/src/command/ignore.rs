@@ -1,9 +1,15 @@
 use std::ffi::OsString;
 
 use bpaf::{doc::Style, Bpaf};
+use oxc_linter::GeneratedFiles;
 
 pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
-    ("Disables excluding of files from .eslintignore files, ", Style::Text),
+    (
+        "Disables excluding of files from .eslintignore, .oxlintignore and .gitignore files, ",
+        Style::Text,
+    ),
+    ("ignorePatterns", Style::Literal),
+    (" of the configuration, ", Style::Text),
     ("--ignore-path", Style::Literal),
     (" flags and ", Style::Text),
     ("--ignore-pattern", Style::Literal),
@@ -17,7 +23,7 @@ pub struct IgnoreOptions {
     #[bpaf(argument("PATH"), fallback(".eslintignore".into()), hide_usage)]
     pub ignore_path: OsString,
 
-    /// Specify patterns of files to ignore (in addition to those in .eslintignore)
+    /// Specify patterns of files to ignore (in addition to those in .eslintignore and .oxlintignore)
     ///
     /// The supported syntax is the same as for .eslintignore and .gitignore files
     /// You should quote your patterns in order to avoid shell interpretation of glob patterns
@@ -30,6 +36,12 @@ pub struct IgnoreOptions {
     /// Follow symbolic links. Oxlint ignores symbolic links by default.
     #[bpaf(switch, hide_usage)]
     pub symlinks: bool,
+
+    /// How to lint the files which look generated or minified, i.e. with a `@generated` comment,
+    /// a `//# sourceMappingURL=` comment or very long lines: `skip` them (default), `warn` about
+    /// their problems without fixing them, or `lint` them like the other files
+    #[bpaf(argument("MODE"), fallback(GeneratedFiles::Skip), hide_usage)]
+    pub generated_files: GeneratedFiles,
 }
 
 #[cfg(test)]
@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::VERSION;
+
+/// Create an oxlint configuration file for the project in the current directory.
+///
+/// The plugins and environments are suggested from the dependencies of the `package.json`
+/// next to the configuration file, the categories of rules to enable are asked for.
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options("init"), version(VERSION))]
+pub struct InitCommand {
+    /// Write the oxlint configuration to PATH, which must not exist
+    #[bpaf(long, short, argument("PATH"), fallback(PathBuf::from(".oxlintrc.json")))]
+    pub output: PathBuf,
+
+    /// Do not ask any question, and use the suggested answers
+    #[bpaf(long, short)]
+    pub yes: bool,
+
+    /// `oxlint --init` is the same as `oxlint init`
+    #[bpaf(long("init"), switch, hide)]
+    pub init: bool,
+}
@@ -1,7 +1,7 @@
 use std::{path::PathBuf, str::FromStr};
 
 use bpaf::Bpaf;
-use oxc_linter::AllowWarnDeny;
+use oxc_linter::{AllowWarnDeny, LintOptions, DEFAULT_CACHE_LOCATION};
 
 use super::{
     expand_glob,
@@ -18,6 +18,26 @@ pub struct LintCommand {
     #[bpaf(external(lint_filter), map(LintFilter::into_tuple), many, hide_usage)]
     pub filter: Vec<(AllowWarnDeny, String)>,
 
+    /// Only run the given rule or plugin, ignoring the configuration and the allow / deny flags.
+    /// Prints the linted files and the time spent in each rule, which is useful for debugging rules.
+    /// For example `--filter no-debugger --filter unicorn`
+    #[bpaf(long("filter"), argument("RULE"), many, hide_usage)]
+    pub rule_filter: Vec<String>,
+
+    /// Enable the rules of the nursery, which are still under development, with warnings.
+    /// The allow / deny flags apply after it, and `all` then includes the nursery
+    #[bpaf(switch, hide_usage)]
+    pub nursery: bool,
+
+    /// Only run the rules of the security preset, as errors, ignoring the configuration and the
+    /// allow / deny flags: the rules reporting `eval`, `javascript:` URLs, unsafe regular
+    /// expressions, prototype pollution and other weaknesses. They run even when the
+    /// configuration or an `eslint` comment turns them off, only `eslint-disable` comments
+    /// suppress their problems. With `--format json`, the problems have the CWE id of their
+    /// weakness
+    #[bpaf(switch, hide_usage)]
+    pub security: bool,
+
     #[bpaf(external)]
     pub enable_plugins: EnablePlugins,
 
@@ -33,10 +53,23 @@ pub struct LintCommand {
     #[bpaf(external)]
     pub output_options: OutputOptions,
 
+    #[bpaf(external)]
+    pub cache_options: CacheOptions,
+
     /// list all the rules that are currently registered
     #[bpaf(long("rules"), switch, hide_usage)]
     pub list_rules: bool,
 
+    /// Print the JSON schema of the `rules` configuration, including the options of each rule
+    #[bpaf(long("rules-schema"), switch, hide_usage)]
+    pub rules_schema: bool,
+
+    /// Print the configuration the given file is linted with, as JSON: the configuration file,
+    /// the environments, the globals and the enabled rules with their severity and options,
+    /// after `extends`, the nested configuration files, `severityOverrides` and the other flags
+    #[bpaf(long("print-config"), argument("PATH"), hide_usage)]
+    pub print_config: Option<PathBuf>,
+
     #[bpaf(external)]
     pub misc_options: MiscOptions,
 
@@ -65,9 +98,14 @@ pub struct BasicOptions {
     #[bpaf(long, short, argument("./oxlintrc.json"))]
     pub config: Option<PathBuf>,
 
-    /// TypeScript `tsconfig.json` path for reading path alias and project references for import plugin
+    /// TypeScript `tsconfig.json` path for reading path alias, project references and compiler options, instead of the nearest `tsconfig.json` of each file
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
+
+    /// Lint the source text read from stdin as the file at this path, e.g. the unsaved buffer of
+    /// an editor. The path determines how the file is parsed and the configuration it is linted with
+    #[bpaf(argument("PATH"), hide_usage)]
+    pub stdin_filename: Option<PathBuf>,
 }
 
 // This is formatted according to
@@ -76,6 +114,9 @@ pub struct BasicOptions {
 ///
 /// Accumulate rules and categories from left to right on the command-line.
 ///   For example `-D correctness -A no-debugger` or `-A all -D no-debugger`.
+///   Rules of a plugin are named `plugin/rule`, `*` matches any part of the name and
+///   `plugin/category` the rules of the category in the plugin.
+///   For example `-A unicorn/* -W react/jsx-key -D typescript/correctness`.
 ///   The categories are:
 ///   * `correctness` - code that is outright wrong or useless (default)
 ///   * `suspicious`  - code that is most likely wrong or useless
@@ -83,7 +124,7 @@ pub struct BasicOptions {
 ///   * `style`       - code that should be written in a more idiomatic way
 ///   * `nursery`     - new lints that are still under development
 ///   * `restriction` - lints which prevent the use of language and library features
-///   * `all`         - all the categories listed above except nursery
+///   * `all`         - all the categories listed above except nursery, unless `--nursery` is given
 ///
 /// Arguments:
 //  ^ This shows up on the website but not from the cli's `--help`.
@@ -118,10 +159,34 @@ impl LintFilter {
 
 /// Fix Problems
 #[derive(Debug, Clone, Bpaf)]
+#[bpaf(
+    guard(interactive_requires_fix, "`--interactive` requires `--fix` or `--fix-dangerously`"),
+    guard(dry_run_requires_fix, "`--dry-run` requires `--fix` or `--fix-dangerously`")
+)]
 pub struct FixOptions {
     /// Fix as many issues as possible. Only unfixed issues are reported in the output
     #[bpaf(switch)]
     pub fix: bool,
+
+    /// Fix as many issues as possible, including with fixes which may change the behavior of the code
+    #[bpaf(switch)]
+    pub fix_dangerously: bool,
+
+    /// Review each fix before it is applied, only accepted fixes are written
+    #[bpaf(switch, hide_usage)]
+    pub interactive: bool,
+
+    /// Print the fixes as a unified diff of each file instead of writing them
+    #[bpaf(switch, hide_usage)]
+    pub dry_run: bool,
+}
+
+fn interactive_requires_fix(options: &FixOptions) -> bool {
+    options.fix || options.fix_dangerously || !options.interactive
+}
+
+fn dry_run_requires_fix(options: &FixOptions) -> bool {
+    options.fix || options.fix_dangerously || !options.dry_run
 }
 
 /// Handle Warnings
@@ -135,18 +200,76 @@ pub struct WarningOptions {
     #[bpaf(switch, hide_usage)]
     pub deny_warnings: bool,
 
+    /// Exit successfully when only warnings are reported,
+    /// even when the `denyWarnings` of the configuration file is set
+    #[bpaf(switch, hide_usage)]
+    pub allow_warnings: bool,
+
     /// Specify a warning threshold,
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     #[bpaf(argument("INT"), hide_usage)]
     pub max_warnings: Option<usize>,
+
+    /// Report at most this number of problems of each rule in a file, followed by a note with the
+    /// number of problems left out, which are not counted as warnings or errors
+    #[bpaf(argument("INT"), hide_usage)]
+    pub max_problems_per_rule: Option<usize>,
+
+    /// Report disable comments, e.g. `// eslint-disable-line no-debugger`,
+    /// which do not disable any problem. They are removed by `--fix`
+    #[bpaf(switch, hide_usage)]
+    pub report_unused_disable_directives: bool,
 }
 
 /// Output
 #[derive(Debug, Clone, Bpaf)]
 pub struct OutputOptions {
-    /// Use a specific output format (default, json, unix, checkstyle, github)
+    /// Use a specific output format (default, json, unix, checkstyle, github, junit, stylish)
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Report the problems of a rule with the same message on one line as a single problem
+    /// pointing at all of them. Only the default format, which renders every location, groups
+    /// them
+    #[bpaf(switch, hide_usage)]
+    pub group_same_line: bool,
+
+    /// Measure the time spent in each rule, and print a table of the slowest rules
+    /// with the file each of them spent the most time on
+    #[bpaf(switch, hide_usage)]
+    pub timing: bool,
+
+    /// Follow each problem with the rationale of its rule, the configuration enabling the rule
+    /// for the file, and how to fix or suppress the problem
+    #[bpaf(switch, hide_usage)]
+    pub explain: bool,
+
+    /// Print statistics after the summary: the files which could not be parsed, the problems
+    /// which can be fixed and the number of problems of each rule.
+    /// With `--format json`, the output becomes an object with the diagnostics and the statistics
+    #[bpaf(switch, hide_usage)]
+    pub stats: bool,
+
+    /// Translate the messages and the help of the problems with the catalog of LOCALE, e.g. `de`,
+    /// read from `LOCALE.json` in the `OXLINT_LOCALE_DIR` directory, `.oxlint/locales` by default.
+    /// A path to a `.json` catalog is accepted too. The rule names, and the output of
+    /// `--format json`, stay in English
+    #[bpaf(long, env("OXLINT_LOCALE"), argument("LOCALE"), optional, hide_usage)]
+    pub locale: Option<String>,
+}
+
+/// Caching
+#[derive(Debug, Clone, Bpaf)]
+pub struct CacheOptions {
+    /// Only lint the files which changed, or whose configuration changed, since they were linted
+    /// without problems. With the import plugin, whose rules depend on other files, all files are
+    /// linted, but the dependencies which did not change are not parsed again
+    #[bpaf(switch, hide_usage)]
+    pub cache: bool,
+
+    /// Path of the cache file
+    #[bpaf(argument("PATH"), fallback(DEFAULT_CACHE_LOCATION.into()), hide_usage)]
+    pub cache_location: PathBuf,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -158,6 +281,10 @@ pub enum OutputFormat {
     Json,
     Unix,
     Checkstyle,
+    /// JUnit XML, rendered as test results by CI systems such as Jenkins and GitLab
+    Junit,
+    /// The diagnostics grouped by file, one per line, like the default formatter of ESLint
+    Stylish,
 }
 
 impl FromStr for OutputFormat {
@@ -170,6 +297,8 @@ impl FromStr for OutputFormat {
             "unix" => Ok(Self::Unix),
             "checkstyle" => Ok(Self::Checkstyle),
             "github" => Ok(Self::Github),
+            "junit" => Ok(Self::Junit),
+            "stylish" => Ok(Self::Stylish),
             _ => Err(format!("'{s}' is not a known format")),
         }
     }
@@ -223,6 +352,41 @@ pub struct EnablePlugins {
     /// Enable the React performance plugin and detect rendering performance problems
     #[bpaf(switch, hide_usage)]
     pub react_perf_plugin: bool,
+
+    /// Enable the React Refresh plugin and detect modules whose components can't be hot reloaded
+    #[bpaf(switch, hide_usage)]
+    pub react_refresh_plugin: bool,
+
+    /// Enable the JSON plugin and lint `.json` and `.jsonc` files, such as `package.json`
+    #[bpaf(switch, hide_usage)]
+    pub json_plugin: bool,
+
+    /// Load the rules of a plugin compiled to WebAssembly, which run in a sandbox without access
+    /// to the file system or the network. Its rules are configured as `plugin/rule` in the
+    /// `rules` of the configuration file, and run as warnings otherwise
+    #[bpaf(long("plugin"), argument("PATH"), many, hide_usage)]
+    pub plugins: Vec<PathBuf>,
+}
+
+impl EnablePlugins {
+    /// Enable or disable the plugins of `options` according to these flags.
+    #[must_use]
+    pub fn apply(&self, options: LintOptions) -> LintOptions {
+        options
+            .with_react_plugin(self.react_plugin)
+            .with_unicorn_plugin(self.unicorn_plugin)
+            .with_typescript_plugin(self.typescript_plugin)
+            .with_oxc_plugin(self.oxc_plugin)
+            .with_import_plugin(self.import_plugin)
+            .with_jsdoc_plugin(self.jsdoc_plugin)
+            .with_jest_plugin(self.jest_plugin)
+            .with_vitest_plugin(self.vitest_plugin)
+            .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
+            .with_nextjs_plugin(self.nextjs_plugin)
+            .with_react_perf_plugin(self.react_perf_plugin)
+            .with_react_refresh_plugin(self.react_refresh_plugin)
+            .with_json_plugin(self.json_plugin)
+    }
 }
 
 #[cfg(test)]
@@ -337,6 +501,39 @@ mod lint_options {
         assert!(options.fix_options.fix);
     }
 
+    #[test]
+    fn fix_interactive() {
+        let options = get_lint_options("--fix --interactive test.js");
+        assert!(options.fix_options.fix);
+        assert!(options.fix_options.interactive);
+
+        let result = lint_command().run_inner(&["--interactive", "test.js"]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn fix_dangerously() {
+        let options = get_lint_options("--fix-dangerously test.js");
+        assert!(!options.fix_options.fix);
+        assert!(options.fix_options.fix_dangerously);
+
+        let options = get_lint_options("--fix-dangerously --interactive test.js");
+        assert!(options.fix_options.interactive);
+    }
+
+    #[test]
+    fn fix_dry_run() {
+        let options = get_lint_options("--fix --dry-run test.js");
+        assert!(options.fix_options.fix);
+        assert!(options.fix_options.dry_run);
+
+        let options = get_lint_options("--fix-dangerously --dry-run test.js");
+        assert!(options.fix_options.dry_run);
+
+        let result = lint_command().run_inner(&["--dry-run", "test.js"]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn filter() {
         let options =
@@ -359,6 +556,23 @@ mod lint_options {
         assert!(options.paths.is_empty());
     }
 
+    #[test]
+    fn cache() {
+        let options = get_lint_options(".");
+        assert!(!options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, PathBuf::from(".oxlintcache"));
+
+        let options = get_lint_options("--cache --cache-location tmp/cache .");
+        assert!(options.cache_options.cache);
+        assert_eq!(options.cache_options.cache_location, PathBuf::from("tmp/cache"));
+    }
+
+    #[test]
+    fn format_junit() {
+        let options = get_lint_options("--format junit .");
+        assert_eq!(options.output_options.format, OutputFormat::Junit);
+    }
+
     #[test]
     fn format_error() {
         let args = "-f asdf".split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -368,6 +582,12 @@ mod lint_options {
         ));
     }
 
+    #[test]
+    fn rules_schema() {
+        let options = get_lint_options("--rules-schema");
+        assert!(options.rules_schema);
+    }
+
     #[test]
     fn list_rules() {
         let options = get_lint_options("--rules");
@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::VERSION;
+
+/// Convert an ESLint configuration to an oxlint configuration.
+///
+/// Rules, plugins, env, globals, settings and the severities of overrides are migrated,
+/// the parts of the configuration which have no oxlint equivalent are reported.
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options("migrate"), version(VERSION))]
+pub struct MigrateCommand {
+    /// Write the oxlint configuration to PATH, which must not exist
+    #[bpaf(long, short, argument("PATH"), fallback(PathBuf::from(".oxlintrc.json")))]
+    pub output: PathBuf,
+
+    /// ESLint configuration file, e.g. `.eslintrc.json` or `eslint.config.js`.
+    /// Defaults to the ESLint configuration file in the current directory.
+    /// JavaScript configuration files are evaluated with `node`.
+    #[bpaf(positional("CONFIG"))]
+    pub config: Option<PathBuf>,
+}
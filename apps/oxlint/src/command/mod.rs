@@ -1,13 +1,23 @@
+mod explain;
 mod ignore;
+mod init;
 mod lint;
+mod migrate;
+mod rules;
+mod suppress;
 
 use std::path::PathBuf;
 
 use bpaf::Bpaf;
 
 pub use self::{
+    explain::{explain_command, ExplainCommand},
     ignore::IgnoreOptions,
+    init::{init_command, InitCommand},
     lint::{lint_command, LintCommand, OutputFormat, OutputOptions, WarningOptions},
+    migrate::{migrate_command, MigrateCommand},
+    rules::{rules_command, RulesCommand, RulesFormat},
+    suppress::{suppress_command, SuppressCommand},
 };
 
 const VERSION: &str = match option_env!("OXC_VERSION") {
@@ -25,6 +35,14 @@ pub struct MiscOptions {
     /// Number of threads to use. Set to 1 for using only 1 CPU core
     #[bpaf(argument("INT"), hide_usage)]
     pub threads: Option<usize>,
+
+    /// Keep running and lint the changed files, and the files importing them, again when files change
+    #[bpaf(switch, hide_usage)]
+    pub watch: bool,
+
+    /// Keep running and lint the files of the JSON-RPC requests read from stdin, answering on stdout
+    #[bpaf(switch, hide_usage)]
+    pub daemon: bool,
 }
 
 #[allow(clippy::ptr_arg)]
@@ -83,4 +101,16 @@ mod misc_options {
         let options = get_misc_options("--threads 4 .");
         assert_eq!(options.threads, Some(4));
     }
+
+    #[test]
+    fn watch() {
+        assert!(!get_misc_options(".").watch);
+        assert!(get_misc_options("--watch .").watch);
+    }
+
+    #[test]
+    fn daemon() {
+        assert!(!get_misc_options(".").daemon);
+        assert!(get_misc_options("--daemon .").daemon);
+    }
 }
@@ -0,0 +1,47 @@
+use std::str::FromStr;
+
+use bpaf::Bpaf;
+
+use super::VERSION;
+
+/// List all the rules with their plugin, category, whether they are turned on by default, the
+/// kind of fixes they provide and whether they take options.
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options("rules"), version(VERSION))]
+pub struct RulesCommand {
+    /// Use a specific output format (table, json)
+    #[bpaf(long, short, fallback(RulesFormat::Table))]
+    pub format: RulesFormat,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum RulesFormat {
+    Table,
+    Json,
+}
+
+impl FromStr for RulesFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("'{s}' is not a known format")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{rules_command, RulesFormat};
+
+    #[test]
+    fn format() {
+        let options = rules_command().run_inner(&[] as &[&str]).unwrap();
+        assert_eq!(options.format, RulesFormat::Table);
+        let options = rules_command().run_inner(&["--format", "json"]).unwrap();
+        assert_eq!(options.format, RulesFormat::Json);
+        assert!(rules_command().run_inner(&["-f", "yaml"]).is_err());
+    }
+}
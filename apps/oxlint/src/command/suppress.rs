@@ -0,0 +1,69 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+use super::{
+    expand_glob,
+    ignore::{ignore_options, IgnoreOptions},
+    lint::{basic_options, enable_plugins, BasicOptions, EnablePlugins},
+    validate_paths, PATHS_ERROR_MESSAGE, VERSION,
+};
+
+/// Insert `// eslint-disable-next-line` comments above the current problems of the given rules.
+///
+/// Use it to enable a rule before its existing problems are fixed, and search for the reason of
+/// the comments to find them later.
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options("suppress"), version(VERSION))]
+pub struct SuppressCommand {
+    #[bpaf(external)]
+    pub basic_options: BasicOptions,
+
+    /// Rules whose problems are suppressed, separated by commas, e.g. `--rules no-debugger,eqeqeq`
+    #[bpaf(argument("RULES"), some("`--rules` is required"), map(split_rules))]
+    pub rules: Vec<String>,
+
+    /// Description of the inserted comments, after `--`
+    #[bpaf(argument("TEXT"), fallback(String::from("TODO")))]
+    pub reason: String,
+
+    #[bpaf(external)]
+    pub enable_plugins: EnablePlugins,
+
+    #[bpaf(external)]
+    pub ignore_options: IgnoreOptions,
+
+    /// Single file, single path or list of paths
+    #[bpaf(positional("PATH"), many, guard(validate_paths, PATHS_ERROR_MESSAGE), map(expand_glob))]
+    pub paths: Vec<PathBuf>,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn split_rules(rules: Vec<String>) -> Vec<String> {
+    rules
+        .iter()
+        .flat_map(|rules| rules.split(','))
+        .map(str::trim)
+        .filter(|rule| !rule.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::suppress_command;
+
+    #[test]
+    fn rules() {
+        let args = &["--rules", "no-debugger, eqeqeq", "--rules", "unicorn/no-null", "src"];
+        let command = suppress_command().run_inner(args.as_slice()).unwrap();
+        assert_eq!(command.rules, ["no-debugger", "eqeqeq", "unicorn/no-null"]);
+        assert_eq!(command.reason, "TODO");
+
+        let args = &["--rules", "no-debugger", "--reason", "JIRA-123", "src"];
+        let command = suppress_command().run_inner(args.as_slice()).unwrap();
+        assert_eq!(command.reason, "JIRA-123");
+
+        assert!(suppress_command().run_inner(&["src"]).is_err());
+    }
+}
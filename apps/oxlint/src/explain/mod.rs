@@ -0,0 +1,48 @@
+use oxc_linter::docs::RuleDocs;
+
+use crate::{command::ExplainCommand, CliRunResult, Runner};
+
+pub struct ExplainRunner {
+    options: ExplainCommand,
+}
+
+impl Runner for ExplainRunner {
+    type Options = ExplainCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    #[allow(clippy::print_stdout)]
+    fn run(self) -> CliRunResult {
+        let docs = RuleDocs::find(&self.options.rule);
+        if docs.is_empty() {
+            return CliRunResult::InvalidOptions {
+                message: format!("Rule `{}` not found.", self.options.rule),
+            };
+        }
+        // Rules with the same name in several plugins are printed one after the other
+        let markdown = docs.iter().map(RuleDocs::render_markdown).collect::<Vec<_>>().join("\n");
+        print!("{markdown}");
+        CliRunResult::None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ExplainRunner;
+    use crate::{explain_command, CliRunResult, Runner};
+
+    fn run(rule: &str) -> CliRunResult {
+        let args = [rule];
+        ExplainRunner::new(explain_command().run_inner(args.as_slice()).unwrap()).run()
+    }
+
+    #[test]
+    fn explain() {
+        assert!(matches!(run("no-debugger"), CliRunResult::None));
+        assert!(matches!(run("eslint/no-debugger"), CliRunResult::None));
+        assert!(matches!(run("no-such-rule"), CliRunResult::InvalidOptions { .. }));
+        assert!(explain_command().run_inner(&[] as &[&str]).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+    path::Path,
+};
+
+use serde_json::{json, Map, Value};
+
+use crate::{command::InitCommand, CliRunResult, Runner};
+
+/// The categories of rules which can be enabled in addition to `correctness`
+const CATEGORIES: [&str; 5] = ["suspicious", "pedantic", "perf", "style", "restriction"];
+
+/// Packages of code which runs in browsers
+const BROWSER_PACKAGES: &[&str] =
+    &["react", "preact", "vue", "svelte", "solid-js", "@angular/core", "next"];
+
+/// A plugin which is not enabled by default, suggested when the project depends on one of
+/// `packages`.
+struct Plugin {
+    name: &'static str,
+    packages: &'static [&'static str],
+    question: &'static str,
+}
+
+const PLUGINS: &[Plugin] = &[
+    Plugin {
+        name: "jsx-a11y",
+        packages: &["react", "preact"],
+        question: "Check the accessibility of JSX elements with the jsx-a11y plugin?",
+    },
+    Plugin {
+        name: "nextjs",
+        packages: &["next"],
+        question: "Check the use of Next.js with the nextjs plugin?",
+    },
+    Plugin {
+        name: "jest",
+        packages: &["jest"],
+        question: "Lint the Jest tests with the jest plugin?",
+    },
+    Plugin {
+        name: "vitest",
+        packages: &["vitest"],
+        question: "Lint the Vitest tests with the vitest plugin?",
+    },
+];
+
+pub struct InitRunner {
+    options: InitCommand,
+}
+
+impl Runner for InitRunner {
+    type Options = InitCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        self.run_with(io::stdin().lock(), io::stdout())
+    }
+}
+
+impl InitRunner {
+    fn run_with<R: BufRead, W: Write>(self, input: R, output: W) -> CliRunResult {
+        let InitCommand { output: path, yes, .. } = self.options;
+        if path.exists() {
+            return CliRunResult::InvalidOptions {
+                message: format!("{} already exists", path.display()),
+            };
+        }
+        let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let packages = match read_dependencies(&dir.join("package.json")) {
+            Ok(packages) => packages,
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+
+        let mut prompt = Prompt { input, output, yes };
+        let mut config = prompt.config(&packages);
+        // Completion of the configuration in editors
+        if dir.join("node_modules/oxlint/configuration_schema.json").is_file() {
+            config.insert(
+                "$schema".to_string(),
+                json!("./node_modules/oxlint/configuration_schema.json"),
+            );
+        }
+
+        let mut json = serde_json::to_string_pretty(&config).unwrap();
+        json.push('\n');
+        if let Err(err) = fs::write(&path, json) {
+            return CliRunResult::InvalidOptions {
+                message: format!("Failed to write {}: {err}", path.display()),
+            };
+        }
+        prompt.say(&format!("Created {}, run `oxlint` to lint the project.", path.display()));
+        CliRunResult::None
+    }
+}
+
+/// The names of the dependencies of all kinds of the `package.json` at `path`, none when it does
+/// not exist.
+fn read_dependencies(path: &Path) -> Result<Vec<String>, String> {
+    let Ok(string) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+    let package_json = serde_json::from_str::<Value>(&string)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+    let kinds = ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"];
+    Ok(kinds
+        .iter()
+        .filter_map(|kind| package_json.get(kind)?.as_object())
+        .flat_map(|dependencies| dependencies.keys().cloned())
+        .collect())
+}
+
+struct Prompt<R, W> {
+    input: R,
+    output: W,
+    /// Use the suggested answers without asking
+    yes: bool,
+}
+
+impl<R: BufRead, W: Write> Prompt<R, W> {
+    /// Ask for the plugins, environments and categories of the configuration, suggesting the
+    /// ones used by `packages`.
+    fn config(&mut self, packages: &[String]) -> Map<String, Value> {
+        let depends_on =
+            |names: &[&str]| names.iter().any(|name| packages.iter().any(|p| p == name));
+
+        let mut plugins = vec![];
+        for plugin in PLUGINS.iter().filter(|plugin| depends_on(plugin.packages)) {
+            if self.confirm(plugin.question, true) {
+                plugins.push(plugin.name);
+            }
+        }
+        if self.confirm(
+            "Check the imports across files with the import plugin? It is slower, as the imported modules are resolved.",
+            false,
+        ) {
+            plugins.push("import");
+        }
+
+        let mut env = Map::new();
+        let suggested = if depends_on(BROWSER_PACKAGES) { "browser" } else { "node" };
+        let runtime =
+            self.choose("Where does the code run?", &["browser", "node", "both"], suggested);
+        for name in ["browser", "node"] {
+            if runtime == name || runtime == "both" {
+                env.insert(name.to_string(), Value::Bool(true));
+            }
+        }
+        if plugins.contains(&"jest") {
+            env.insert("jest".to_string(), Value::Bool(true));
+        }
+
+        let mut categories = Map::new();
+        categories.insert("correctness".to_string(), json!("warn"));
+        for category in self.choose_many(
+            "Which categories of rules should be enabled in addition to correctness?",
+            &CATEGORIES,
+            &["suspicious"],
+        ) {
+            categories.insert(category.to_string(), json!("warn"));
+        }
+
+        let mut config = Map::new();
+        config.insert("plugins".to_string(), json!(plugins));
+        config.insert("categories".to_string(), Value::Object(categories));
+        config.insert("env".to_string(), Value::Object(env));
+        config.insert("rules".to_string(), Value::Object(Map::new()));
+        config
+    }
+
+    fn say(&mut self, message: &str) {
+        let _ = writeln!(self.output, "{message}");
+    }
+
+    /// Ask `question`, the answer is empty with `--yes` and at the end of the input.
+    fn ask(&mut self, question: &str) -> String {
+        if self.yes {
+            return String::new();
+        }
+        let _ = write!(self.output, "{question} ");
+        let _ = self.output.flush();
+        let mut line = String::new();
+        if self.input.read_line(&mut line).unwrap_or_default() == 0 {
+            let _ = writeln!(self.output);
+        }
+        line.trim().to_lowercase()
+    }
+
+    fn confirm(&mut self, question: &str, default: bool) -> bool {
+        let hint = if default { "Y/n" } else { "y/N" };
+        loop {
+            match self.ask(&format!("{question} [{hint}]")).as_str() {
+                "" => return default,
+                "y" | "yes" => return true,
+                "n" | "no" => return false,
+                _ => self.say("Please answer y or n."),
+            }
+        }
+    }
+
+    fn choose<'a>(&mut self, question: &str, choices: &[&'a str], default: &'a str) -> &'a str {
+        loop {
+            let answer = self.ask(&format!("{question} ({}) [{default}]", choices.join(", ")));
+            if answer.is_empty() {
+                return default;
+            }
+            if let Some(choice) = choices.iter().find(|choice| **choice == answer) {
+                return choice;
+            }
+            self.say(&format!("Please answer one of {}.", choices.join(", ")));
+        }
+    }
+
+    /// Choose any number of `choices`, separated by commas or spaces, or `none`.
+    fn choose_many<'a>(
+        &mut self,
+        question: &str,
+        choices: &[&'a str],
+        default: &[&'a str],
+    ) -> Vec<&'a str> {
+        loop {
+            let answer = self.ask(&format!(
+                "{question} ({}, none) [{}]",
+                choices.join(", "),
+                default.join(", ")
+            ));
+            if answer.is_empty() {
+                return default.to_vec();
+            }
+            if answer == "none" {
+                return vec![];
+            }
+            let chosen = answer
+                .split([',', ' '])
+                .filter(|name| !name.is_empty())
+                .map(|name| choices.iter().find(|choice| **choice == name).copied().ok_or(name))
+                .collect::<Result<Vec<_>, _>>();
+            match chosen {
+                Ok(chosen) => return chosen,
+                Err(name) => self.say(&format!("Unknown category `{name}`.")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, io::Cursor, path::Path};
+
+    use serde_json::{json, Value};
+
+    use super::InitRunner;
+    use crate::{init_command, CliRunResult, Runner};
+
+    /// Create the configuration of a project with `package_json`, answering with `input`.
+    fn init(package_json: Option<&str>, args: &[&str], input: &str) -> (CliRunResult, Value) {
+        let dir = tempfile::tempdir().unwrap();
+        if let Some(package_json) = package_json {
+            fs::write(dir.path().join("package.json"), package_json).unwrap();
+        }
+        let output = dir.path().join(".oxlintrc.json");
+        let mut args = args.to_vec();
+        args.extend(["--output", output.to_str().unwrap()]);
+        let options = init_command().run_inner(args.as_slice()).unwrap();
+        let result = InitRunner::new(options).run_with(Cursor::new(input), Vec::new());
+        let config = fs::read_to_string(&output)
+            .map_or(Value::Null, |config| serde_json::from_str(&config).unwrap());
+        (result, config)
+    }
+
+    #[test]
+    fn suggested() {
+        let package_json =
+            r#"{ "dependencies": { "react": "*" }, "devDependencies": { "jest": "*" } }"#;
+        let (result, config) = init(Some(package_json), &["--yes"], "");
+        assert!(matches!(result, CliRunResult::None));
+        assert_eq!(
+            config,
+            json!({
+                "plugins": ["jsx-a11y", "jest"],
+                "categories": { "correctness": "warn", "suspicious": "warn" },
+                "env": { "browser": true, "jest": true },
+                "rules": {},
+            })
+        );
+
+        // The end of the input answers the same
+        assert_eq!(init(Some(package_json), &[], "").1, config);
+    }
+
+    #[test]
+    fn answered() {
+        let package_json = r#"{ "dependencies": { "react": "*" } }"#;
+        let input = "n\nyes\nmaybe\nboth\npedantic, styles\nperf style\n";
+        let (result, config) = init(Some(package_json), &[], input);
+        assert!(matches!(result, CliRunResult::None));
+        assert_eq!(
+            config,
+            json!({
+                "plugins": ["import"],
+                "categories": { "correctness": "warn", "perf": "warn", "style": "warn" },
+                "env": { "browser": true, "node": true },
+                "rules": {},
+            })
+        );
+    }
+
+    #[test]
+    fn without_package_json() {
+        let (result, config) = init(None, &["-y"], "");
+        assert!(matches!(result, CliRunResult::None));
+        assert_eq!(config["plugins"], json!([]));
+        assert_eq!(config["env"], json!({ "node": true }));
+    }
+
+    #[test]
+    fn existing_config() {
+        let options = init_command()
+            .run_inner(&["--init", "--output", "fixtures/nested_config/.oxlintrc.json"])
+            .unwrap();
+        assert!(options.init);
+        let result = InitRunner::new(options).run_with(Cursor::new(""), Vec::new());
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+        assert!(Path::new("fixtures/nested_config/.oxlintrc.json").is_file());
+    }
+}
@@ -1,12 +1,22 @@
 mod command;
+mod explain;
+mod init;
 mod lint;
+mod migrate;
 mod result;
+mod rules;
 mod runner;
+mod suppress;
 mod walk;
 
 pub use crate::{
     command::*,
+    explain::ExplainRunner,
+    init::InitRunner,
     lint::LintRunner,
+    migrate::MigrateRunner,
     result::{CliRunResult, LintResult},
+    rules::RulesRunner,
     runner::Runner,
+    suppress::SuppressRunner,
 };
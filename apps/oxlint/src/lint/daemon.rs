@@ -0,0 +1,269 @@
+//! `--daemon`: keep the linter in memory and lint files on request, read as JSON-RPC 2.0 messages
+//! from stdin, one per line. The responses are written to stdout, one per line.
+//!
+//! Methods:
+//! * `lint`, with `{ "paths": ["src/a.js", "src"] }`: lint the files and the files of the
+//!   directories, or the paths given on the command line when `paths` is empty or omitted. The
+//!   result is `{ "diagnostics": [...] }`, the objects of the `json` output format.
+//! * `shutdown`: exit after responding with `null`.
+//!
+//! The configuration files are read once. The modules parsed for the rules checking imports are
+//! kept between requests, except those of the linted files and of the files importing them, which
+//! are parsed again.
+
+use std::{
+    io::{BufRead, Write},
+    path::PathBuf,
+    thread,
+};
+
+use oxc_diagnostics::{diagnostic_channel, render_json_diagnostic, Error};
+use oxc_linter::LintService;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{
+    walk::{Extensions, Walk},
+    IgnoreOptions,
+};
+
+// Error codes of the JSON-RPC 2.0 specification
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+#[derive(Deserialize)]
+struct Request {
+    /// Absent for notifications, which get no response
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LintParams {
+    #[serde(default)]
+    paths: Vec<PathBuf>,
+}
+
+pub(super) struct Daemon {
+    /// The paths given on the command line, linted when a request has none
+    roots: Vec<PathBuf>,
+    ignore_options: IgnoreOptions,
+    extensions: Extensions,
+}
+
+impl Daemon {
+    pub(super) fn new(
+        roots: Vec<PathBuf>,
+        ignore_options: IgnoreOptions,
+        extensions: Extensions,
+    ) -> Self {
+        Self { roots, ignore_options, extensions }
+    }
+
+    /// Answer the requests read from `reader` until `shutdown` is requested or `reader` is
+    /// closed, reusing the linter and the module graph of `lint_service`.
+    ///
+    /// # Errors
+    ///
+    /// When reading a request or writing a response fails.
+    pub(super) fn run<R: BufRead, W: Write>(
+        &self,
+        mut lint_service: LintService,
+        reader: R,
+        mut writer: W,
+    ) -> std::io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let request = match serde_json::from_str::<Value>(&line) {
+                Ok(request) => request,
+                Err(error) => {
+                    Self::respond(
+                        &mut writer,
+                        &Value::Null,
+                        Err((PARSE_ERROR, error.to_string())),
+                    )?;
+                    continue;
+                }
+            };
+            let Request { id, method, params } = match Request::deserialize(&request) {
+                Ok(request) => request,
+                Err(error) => {
+                    let id = request.get("id").cloned().unwrap_or_default();
+                    Self::respond(&mut writer, &id, Err((INVALID_REQUEST, error.to_string())))?;
+                    continue;
+                }
+            };
+            let result = match method.as_str() {
+                "lint" => match Self::lint_params(params) {
+                    Ok(params) => {
+                        let (service, diagnostics) = self.lint(&lint_service, &params.paths);
+                        lint_service = service;
+                        Ok(json!({ "diagnostics": diagnostics }))
+                    }
+                    Err(error) => Err((INVALID_PARAMS, error.to_string())),
+                },
+                "shutdown" => Ok(Value::Null),
+                _ => Err((METHOD_NOT_FOUND, format!("Unknown method `{method}`"))),
+            };
+            if let Some(id) = &id {
+                Self::respond(&mut writer, id, result)?;
+            }
+            if method == "shutdown" {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn lint_params(params: Value) -> serde_json::Result<LintParams> {
+        if params.is_null() {
+            return Ok(LintParams::default());
+        }
+        LintParams::deserialize(params)
+    }
+
+    /// Lint `paths`, walking the directories, with a service sharing the linter and the module
+    /// graph of `lint_service`. Returns the service, to share them with the next request.
+    fn lint(&self, lint_service: &LintService, paths: &[PathBuf]) -> (LintService, Vec<Value>) {
+        let roots = if paths.is_empty() { &self.roots } else { paths };
+        let mut paths =
+            Walk::new(roots, &self.ignore_options).with_extensions(self.extensions.clone()).paths();
+        if !self.ignore_options.no_ignore {
+            paths.retain(|path| !lint_service.linter().is_ignored(path));
+        }
+        // The files may have been modified since the previous request
+        let lint_service = lint_service.with_changed_paths(&paths, &paths);
+
+        let (sender, receiver) = diagnostic_channel();
+        let mut diagnostics = vec![];
+        thread::scope(|scope| {
+            scope.spawn(|| lint_service.run(&sender));
+            while let Ok(Some((_, errors))) = receiver.recv() {
+                diagnostics.extend(errors.iter().map(Self::diagnostic_json));
+            }
+        });
+        (lint_service, diagnostics)
+    }
+
+    fn diagnostic_json(error: &Error) -> Value {
+        serde_json::from_str(&render_json_diagnostic(error)).unwrap_or_default()
+    }
+
+    fn respond<W: Write>(
+        writer: &mut W,
+        id: &Value,
+        result: Result<Value, (i64, String)>,
+    ) -> std::io::Result<()> {
+        let response = match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err((code, message)) => {
+                json!({ "jsonrpc": "2.0", "id": id, "error": { "code": code, "message": message } })
+            }
+        };
+        serde_json::to_writer(&mut *writer, &response)?;
+        writeln!(writer)?;
+        writer.flush()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use oxc_linter::{LintOptions, LintService, LintServiceOptions, Linter};
+    use serde_json::Value;
+
+    use super::Daemon;
+    use crate::{lint_command, walk::Extensions};
+
+    fn run(dir: &Path, requests: &[&str]) -> Vec<Value> {
+        let ignore_options = lint_command().run_inner(&[] as &[&str]).unwrap().ignore_options;
+        let daemon = Daemon::new(vec![dir.to_path_buf()], ignore_options, Extensions::default());
+        let linter = Linter::from_options(LintOptions::default()).unwrap();
+        let options = LintServiceOptions {
+            cwd: dir.to_path_buf().into_boxed_path(),
+            paths: vec![],
+            tsconfig: None,
+            cache: None,
+            stdin: None,
+        };
+        let mut output = vec![];
+        let input = requests.join("\n");
+        daemon.run(LintService::new(linter, options), input.as_bytes(), &mut output).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_lint() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.js");
+        let b = dir.path().join("b.js");
+        fs::write(&a, "debugger;").unwrap();
+        fs::write(&b, "debugger; debugger;").unwrap();
+
+        let lint = |paths: &[&PathBuf]| {
+            let params = serde_json::json!({ "paths": paths });
+            format!(r#"{{ "jsonrpc": "2.0", "id": 1, "method": "lint", "params": {params} }}"#)
+        };
+        let responses = run(
+            dir.path(),
+            &[
+                &lint(&[&a]),
+                &lint(&[]),
+                r#"{ "jsonrpc": "2.0", "id": 2, "method": "shutdown" }"#,
+                &lint(&[&a]),
+            ],
+        );
+
+        assert_eq!(responses.len(), 3, "no response after the shutdown");
+        let diagnostics = responses[0]["result"]["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        // Relative to the current working directory
+        assert_eq!(diagnostics[0]["filename"], "a.js");
+        assert_eq!(diagnostics[0]["rule_id"], "eslint(no-debugger)");
+        // All the files of the directory
+        let mut filenames = responses[1]["result"]["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|diagnostic| diagnostic["filename"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        filenames.sort_unstable();
+        assert_eq!(filenames, ["a.js", "b.js"]);
+        assert_eq!(responses[2], serde_json::json!({ "jsonrpc": "2.0", "id": 2, "result": null }));
+    }
+
+    #[test]
+    fn test_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let responses = run(
+            dir.path(),
+            &[
+                "{",
+                r#"{ "jsonrpc": "2.0", "id": 1 }"#,
+                r#"{ "jsonrpc": "2.0", "id": 2, "method": "format" }"#,
+                r#"{ "jsonrpc": "2.0", "id": 3, "method": "lint", "params": { "files": [] } }"#,
+                r#"{ "jsonrpc": "2.0", "method": "lint" }"#,
+            ],
+        );
+        let codes = responses.iter().map(|response| &response["error"]["code"]).collect::<Vec<_>>();
+        assert_eq!(codes, [-32700, -32600, -32601, -32602]);
+        assert_eq!(responses[1]["id"], 1);
+    }
+}
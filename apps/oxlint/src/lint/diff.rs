@@ -0,0 +1,70 @@
+//! `--fix --dry-run`: print the fixes as unified diffs instead of writing them.
+
+use std::{
+    fmt::Write as _,
+    io::{self, IsTerminal, Write},
+};
+
+use owo_colors::OwoColorize;
+use oxc_linter::FixedFile;
+use similar::TextDiff;
+
+/// Print the diff of each fixed file to stdout, colored when stdout is a terminal.
+pub(super) fn print_fixed_files(fixed_files: &[FixedFile]) {
+    let color = io::stdout().is_terminal()
+        && std::env::var("NO_COLOR").map_or(true, |no_color| no_color == "0");
+    let mut stdout = io::stdout().lock();
+    for fixed_file in fixed_files {
+        let _ = stdout.write_all(render_diff(fixed_file, color).as_bytes());
+    }
+    let _ = stdout.flush();
+}
+
+/// Render the changes of `fixed_file` as a unified diff.
+fn render_diff(fixed_file: &FixedFile, color: bool) -> String {
+    let path = fixed_file.path.to_string_lossy();
+    let diff = TextDiff::from_lines(&fixed_file.source_text, &fixed_file.fixed_code)
+        .unified_diff()
+        .header(&path, &path)
+        .to_string();
+    if !color {
+        return diff;
+    }
+    diff.lines().fold(String::new(), |mut output, line| {
+        let _ = if line.starts_with("+++") || line.starts_with("---") {
+            writeln!(output, "{}", line.bold())
+        } else if line.starts_with('+') {
+            writeln!(output, "{}", line.green())
+        } else if line.starts_with('-') {
+            writeln!(output, "{}", line.red())
+        } else if line.starts_with("@@") {
+            writeln!(output, "{}", line.cyan())
+        } else {
+            writeln!(output, "{line}")
+        };
+        output
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use oxc_linter::FixedFile;
+
+    use super::render_diff;
+
+    #[test]
+    fn test_render_diff() {
+        let fixed_file = FixedFile {
+            path: PathBuf::from("test.js"),
+            source_text: "let a = 1;\ndebugger;\nlet b = 2;\n".to_string(),
+            fixed_code: "let a = 1;\n\nlet b = 2;\n".to_string(),
+        };
+        assert_eq!(
+            render_diff(&fixed_file, false),
+            "--- test.js\n+++ test.js\n@@ -1,3 +1,3 @@\n let a = 1;\n-debugger;\n+\n let b = 2;\n"
+        );
+        assert!(render_diff(&fixed_file, true).contains("\u{1b}[31m-debugger;\u{1b}[39m\n"));
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use oxc_diagnostics::MessageCatalog;
+
+/// The directory of the catalogs of `--locale`, unless `OXLINT_LOCALE_DIR` is set.
+const DEFAULT_LOCALE_DIR: &str = ".oxlint/locales";
+
+/// The message catalog of `locale`, `None` for English.
+///
+/// `locale` is the path of a `.json` catalog, or the name of a locale whose catalog is
+/// `<locale>.json` in the directory of `OXLINT_LOCALE_DIR`, falling back to the catalog of its
+/// language, e.g. `de.json` for `de-AT` or `de_AT.UTF-8`.
+pub(super) fn message_catalog(locale: &str) -> Result<Option<MessageCatalog>, String> {
+    let dir =
+        env::var_os("OXLINT_LOCALE_DIR").map_or_else(|| DEFAULT_LOCALE_DIR.into(), PathBuf::from);
+    let Some(path) = catalog_path(locale, &dir) else {
+        if is_english(locale) {
+            return Ok(None);
+        }
+        return Err(format!(
+            "No message catalog for the locale `{locale}`, add `{locale}.json` to {}",
+            dir.display()
+        ));
+    };
+    MessageCatalog::read(&path).map(Some).map_err(|err| err.message.clone())
+}
+
+/// The path of the catalog of `locale`, see [`message_catalog`].
+fn catalog_path(locale: &str, dir: &Path) -> Option<PathBuf> {
+    if Path::new(locale).extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+        return Some(PathBuf::from(locale));
+    }
+    // `de_AT.UTF-8` as in `LANG`
+    let locale = locale.split('.').next().unwrap_or(locale).replace('_', "-");
+    let language = locale.split('-').next().unwrap_or(&locale);
+    let path = [locale.as_str(), language]
+        .into_iter()
+        .map(|name| dir.join(format!("{name}.json")))
+        .find(|path| path.is_file());
+    path
+}
+
+/// Whether the messages are already in the language of `locale`.
+fn is_english(locale: &str) -> bool {
+    let language = locale.split(['-', '_', '.']).next().unwrap_or(locale);
+    matches!(language, "en" | "C" | "POSIX")
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use oxc_diagnostics::MessageCatalog;
+
+    use super::{catalog_path, is_english};
+
+    #[test]
+    fn test_message_catalog() {
+        let catalog = MessageCatalog::read(Path::new("fixtures/locale/de.json")).unwrap();
+        assert_eq!(
+            catalog.translate("`debugger` statement is not allowed").as_deref(),
+            Some("`debugger`-Anweisungen sind nicht erlaubt")
+        );
+        assert_eq!(
+            catalog.translate("'document' is not defined.").as_deref(),
+            Some("'document' ist nicht definiert.")
+        );
+        assert_eq!(
+            catalog.translate("Array prototype method 'indexOf' cannot find NaN.").as_deref(),
+            Some("Die Array-Methode 'indexOf' kann NaN nicht finden.")
+        );
+        assert_eq!(catalog.translate("'document' is not defined. Really."), None);
+        assert_eq!(catalog.translate("Unexpected token"), None);
+
+        // Braces which are not placeholders are text
+        let catalog = MessageCatalog::parse(
+            r#"{ "messages": { "Empty block {}": "Leerer Block {}", "{a b}": "{c}" } }"#,
+        )
+        .unwrap();
+        assert_eq!(catalog.translate("Empty block {}").as_deref(), Some("Leerer Block {}"));
+        assert_eq!(catalog.translate("{a b}").as_deref(), Some("{c}"));
+
+        assert!(MessageCatalog::parse(r#"{ "messages": { "a": 1 } }"#).is_err());
+        assert!(MessageCatalog::parse("[]").is_err());
+    }
+
+    #[test]
+    fn test_catalog_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+        fs::write(dir.join("de.json"), "").unwrap();
+        fs::write(dir.join("pt-BR.json"), "").unwrap();
+
+        assert_eq!(catalog_path("de", dir), Some(dir.join("de.json")));
+        assert_eq!(catalog_path("de-AT", dir), Some(dir.join("de.json")));
+        assert_eq!(catalog_path("de_AT.UTF-8", dir), Some(dir.join("de.json")));
+        assert_eq!(catalog_path("pt_BR", dir), Some(dir.join("pt-BR.json")));
+        assert_eq!(catalog_path("pt", dir), None);
+        assert_eq!(catalog_path("ja.json", dir), Some(Path::new("ja.json").to_path_buf()));
+
+        assert!(is_english("en"));
+        assert!(is_english("en_US.UTF-8"));
+        assert!(!is_english("de"));
+    }
+}
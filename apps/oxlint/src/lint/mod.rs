@@ -1,27 +1,48 @@
-use std::{env, io::BufWriter, time::Instant};
+mod daemon;
+mod diff;
+mod locale;
+mod review;
+mod watch;
+
+use std::{
+    env,
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+    process::Termination,
+    sync::Arc,
+    time::Instant,
+};
 
 use ignore::gitignore::Gitignore;
-use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler};
+use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, MessageCatalog};
 use oxc_linter::{
-    partial_loader::LINT_PARTIAL_LOADER_EXT, LintOptions, LintService, LintServiceOptions, Linter,
+    json::JSON_EXT, partial_loader::LINT_PARTIAL_LOADER_EXT, LintCache, LintOptions, LintService,
+    LintServiceOptions, Linter, RuleTiming, RuleTimings, WasmPlugin,
 };
 use oxc_span::VALID_EXTENSIONS;
 
 use crate::{
     command::{LintCommand, OutputFormat, OutputOptions, WarningOptions},
-    walk::{Extensions, Walk},
+    walk::{Extensions, Walk, IGNORE_FILE_NAME},
     CliRunResult, LintResult, MiscOptions, Runner,
 };
 
+use self::{daemon::Daemon, review::InteractiveFixReviewer, watch::Watcher};
+
+/// Number of the slowest rules printed by `--timing`, like `TIMING=1 eslint`
+const TIMING_TABLE_ROWS: usize = 10;
+
 pub struct LintRunner {
     options: LintCommand,
+    /// The source text of `--stdin-filename`, read from stdin when not given
+    stdin: Option<String>,
 }
 
 impl Runner for LintRunner {
     type Options = LintCommand;
 
     fn new(options: Self::Options) -> Self {
-        Self { options }
+        Self { options, stdin: None }
     }
 
     fn run(self) -> CliRunResult {
@@ -31,31 +52,89 @@ impl Runner for LintRunner {
             return CliRunResult::None;
         }
 
+        if self.options.rules_schema {
+            let mut stdout = BufWriter::new(std::io::stdout());
+            Linter::print_rules_schema(&mut stdout);
+            return CliRunResult::None;
+        }
+
         let LintCommand {
             paths,
             filter,
+            rule_filter,
+            nursery,
+            security,
             basic_options,
             warning_options,
             ignore_options,
             fix_options,
             enable_plugins,
             output_options,
+            cache_options,
             misc_options,
+            print_config,
             ..
         } = self.options;
 
         let mut paths = paths;
-        let provided_path_count = paths.len();
         let now = Instant::now();
 
+        if misc_options.daemon && (misc_options.watch || fix_options.interactive) {
+            return CliRunResult::InvalidOptions {
+                message: "`--daemon` reads requests from stdin, it cannot be used with `--watch` or `--fix-interactive`.".to_string(),
+            };
+        }
+
+        let stdin = match &basic_options.stdin_filename {
+            Some(stdin_filename) => {
+                if !paths.is_empty() || misc_options.watch || misc_options.daemon {
+                    return CliRunResult::InvalidOptions {
+                        message:
+                            "`--stdin-filename` cannot be used with paths, `--watch` or `--daemon`."
+                                .to_string(),
+                    };
+                }
+                if (fix_options.fix || fix_options.fix_dangerously) && !fix_options.dry_run {
+                    return CliRunResult::InvalidOptions {
+                        message: "`--stdin-filename` cannot be used with `--fix`, use `--fix --dry-run` to print the fixes.".to_string(),
+                    };
+                }
+                let source_text = match self.stdin {
+                    Some(source_text) => source_text,
+                    None => match io::read_to_string(io::stdin()) {
+                        Ok(source_text) => source_text,
+                        Err(err) => {
+                            return CliRunResult::InvalidOptions {
+                                message: format!("Failed to read stdin: {err}"),
+                            };
+                        }
+                    },
+                };
+                paths.push(stdin_filename.clone());
+                Some(source_text)
+            }
+            None => None,
+        };
+        let provided_path_count = paths.len();
+
         // The ignore crate whitelists explicit paths, but priority
         // should be given to the ignore file. Many users lint
         // automatically and pass a list of changed files explicitly.
         // To accommodate this, unless `--no-ignore` is passed,
         // pre-filter the paths.
         if !paths.is_empty() && !ignore_options.no_ignore {
-            let (ignore, _err) = Gitignore::new(&ignore_options.ignore_path);
-            paths.retain(|p| if p.is_dir() { true } else { !ignore.matched(p, false).is_ignore() });
+            // The last matching file takes precedence, e.g. `.oxlintignore` may negate a pattern
+            let ignores =
+                [Gitignore::new(&ignore_options.ignore_path).0, Gitignore::new(IGNORE_FILE_NAME).0];
+            paths.retain(|p| {
+                p.is_dir()
+                    || !ignores
+                        .iter()
+                        .rev()
+                        .map(|ignore| ignore.matched(p, false))
+                        .find(|m| !m.is_none())
+                        .is_some_and(|m| m.is_ignore())
+            });
         }
 
         if paths.is_empty() {
@@ -78,36 +157,90 @@ impl Runner for LintRunner {
             }
         }
 
+        let json_extensions = if enable_plugins.json_plugin { JSON_EXT } else { &[] };
         let extensions = VALID_EXTENSIONS
             .iter()
             .chain(LINT_PARTIAL_LOADER_EXT.iter())
+            .chain(json_extensions.iter())
             .copied()
             .collect::<Vec<&'static str>>();
 
-        let paths =
-            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
-
-        let number_of_files = paths.len();
+        let extensions = Extensions(extensions);
+        let roots = paths;
+        // The file of stdin may not exist, no files are linted when printing the configuration, and
+        // the daemon lints the files of each request
+        let mut paths = if stdin.is_some() || print_config.is_some() || misc_options.daemon {
+            roots.iter().map(|path| path.clone().into_boxed_path()).collect()
+        } else {
+            Walk::new(&roots, &ignore_options).with_extensions(extensions.clone()).paths()
+        };
+        let daemon = misc_options
+            .daemon
+            .then(|| Daemon::new(roots.clone(), ignore_options.clone(), extensions.clone()));
+        let watcher = misc_options
+            .watch
+            .then(|| Watcher::new(roots, ignore_options.clone(), extensions, &paths));
 
+        let report_linted_paths = !rule_filter.is_empty();
         let cwd = std::env::current_dir().unwrap().into_boxed_path();
+        // Without an explicit config, each file is linted with the config files of its directory
+        let nested_configs = basic_options.config.is_none();
         let lint_options = LintOptions::default()
             .with_filter(filter)
             .with_config_path(basic_options.config)
-            .with_fix(fix_options.fix)
-            .with_react_plugin(enable_plugins.react_plugin)
-            .with_unicorn_plugin(enable_plugins.unicorn_plugin)
-            .with_typescript_plugin(enable_plugins.typescript_plugin)
-            .with_oxc_plugin(enable_plugins.oxc_plugin)
-            .with_import_plugin(enable_plugins.import_plugin)
-            .with_jsdoc_plugin(enable_plugins.jsdoc_plugin)
-            .with_jest_plugin(enable_plugins.jest_plugin)
-            .with_vitest_plugin(enable_plugins.vitest_plugin)
-            .with_jsx_a11y_plugin(enable_plugins.jsx_a11y_plugin)
-            .with_nextjs_plugin(enable_plugins.nextjs_plugin)
-            .with_react_perf_plugin(enable_plugins.react_perf_plugin);
+            .with_fix(fix_options.fix || fix_options.fix_dangerously)
+            .with_fix_dangerously(fix_options.fix_dangerously)
+            .with_dry_run(fix_options.dry_run)
+            .with_report_fixes(
+                matches!(output_options.format, OutputFormat::Json) || output_options.stats,
+            )
+            .with_report_unused_directives(warning_options.report_unused_disable_directives)
+            .with_generated_files(ignore_options.generated_files)
+            .with_max_problems_per_rule(warning_options.max_problems_per_rule)
+            .with_collapse_same_line(
+                output_options.group_same_line
+                    && matches!(output_options.format, OutputFormat::Default),
+            )
+            .with_explain(output_options.explain)
+            .with_rule_filter(rule_filter)
+            .with_security(security)
+            .with_nursery(nursery);
+        let lint_options = enable_plugins.apply(lint_options);
+
+        if let Err(diagnostic) = lint_options.check_filters() {
+            let handler = GraphicalReportHandler::new();
+            let mut err = String::new();
+            handler.render_report(&mut err, &diagnostic).unwrap();
+            return CliRunResult::InvalidOptions { message: err };
+        }
+
+        let mut wasm_plugins = vec![];
+        for path in &enable_plugins.plugins {
+            match WasmPlugin::load(path) {
+                Ok(plugin) => wasm_plugins.push(plugin),
+                Err(diagnostic) => {
+                    let handler = GraphicalReportHandler::new();
+                    let mut err = String::new();
+                    handler.render_report(&mut err, &diagnostic).unwrap();
+                    return CliRunResult::InvalidOptions { message: err };
+                }
+            }
+        }
 
         let linter = match Linter::from_options(lint_options) {
-            Ok(lint_service) => lint_service,
+            Ok(linter) => {
+                let linter = linter
+                    .with_timing(report_linted_paths || output_options.timing)
+                    .with_nested_configs(nested_configs)
+                    .with_wasm_plugins(wasm_plugins);
+                if fix_options.interactive {
+                    let reviewer =
+                        InteractiveFixReviewer::new(BufReader::new(io::stdin()), io::stderr());
+                    linter.with_fix_reviewer(Arc::new(reviewer))
+                } else {
+                    linter
+                }
+            }
             Err(diagnostic) => {
                 let handler = GraphicalReportHandler::new();
                 let mut err = String::new();
@@ -118,6 +251,33 @@ impl Runner for LintRunner {
             }
         };
 
+        if let Some(path) = print_config {
+            return match linter.resolved_config(&path) {
+                Ok(config) => {
+                    let mut stdout = BufWriter::new(io::stdout());
+                    serde_json::to_writer_pretty(&mut stdout, &config).unwrap();
+                    writeln!(stdout).unwrap();
+                    CliRunResult::None
+                }
+                Err(diagnostic) => {
+                    let handler = GraphicalReportHandler::new();
+                    let mut err = String::new();
+                    handler.render_report(&mut err, &diagnostic).unwrap();
+                    CliRunResult::InvalidOptions { message: err }
+                }
+            };
+        }
+
+        // The `ignorePatterns` of the configuration files are known once the linter is created
+        if !ignore_options.no_ignore {
+            paths.retain(|path| !linter.is_ignored(path));
+        }
+        let linted_paths = report_linted_paths.then(|| {
+            let mut paths = paths.clone();
+            paths.sort_unstable();
+            paths
+        });
+
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
             if !path.is_file() {
@@ -130,44 +290,144 @@ impl Runner for LintRunner {
             }
         }
 
-        let options = LintServiceOptions { cwd, paths, tsconfig };
+        let cache =
+            cache_options.cache.then(|| Arc::new(LintCache::load(cache_options.cache_location)));
+        let options = LintServiceOptions { cwd, paths, tsconfig, cache, stdin };
         let lint_service = LintService::new(linter, options);
-        let mut diagnostic_service =
-            Self::get_diagnostic_service(&warning_options, &output_options, &misc_options);
-
-        // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
-        rayon::spawn({
-            let tx_error = diagnostic_service.sender().clone();
-            let lint_service = lint_service.clone();
-            move || {
-                lint_service.run(&tx_error);
+
+        if let Some(daemon) = daemon {
+            let stdout = BufWriter::new(io::stdout());
+            return match daemon.run(lint_service, io::stdin().lock(), stdout) {
+                Ok(()) => CliRunResult::None,
+                Err(err) => {
+                    CliRunResult::InvalidOptions { message: format!("Daemon failed: {err}") }
+                }
+            };
+        }
+        // The output of `--format json` is read by tools, which expect the messages in English
+        let catalog = match output_options.locale.as_deref() {
+            Some(locale) if output_options.format != OutputFormat::Json => {
+                match locale::message_catalog(locale) {
+                    Ok(catalog) => catalog,
+                    Err(message) => return CliRunResult::InvalidOptions { message },
+                }
             }
-        });
-        diagnostic_service.run();
+            _ => None,
+        };
+        let mut diagnostic_service = Self::get_diagnostic_service(
+            &warning_options,
+            &output_options,
+            &misc_options,
+            catalog.as_ref(),
+        );
+
+        if let Some(config_path) = &lint_service.linter().options().config_path {
+            let warnings = lint_service.linter().config_warnings();
+            if !warnings.is_empty() {
+                let diagnostics = DiagnosticService::wrap_diagnostics(config_path, "", warnings);
+                diagnostic_service.sender().send(Some(diagnostics)).unwrap();
+            }
+        }
+
+        run_lint_service(&lint_service, &mut diagnostic_service, now);
 
-        CliRunResult::LintResult(LintResult {
+        if fix_options.dry_run {
+            diff::print_fixed_files(&lint_service.fixed_files());
+        }
+
+        if let Some(paths) = linted_paths {
+            if !misc_options.silent {
+                Self::print_filter_report(&paths, lint_service.linter(), !output_options.timing);
+            }
+        }
+
+        if output_options.timing && !misc_options.silent {
+            if let Some(timings) = lint_service.linter().timings() {
+                Self::print_timings(timings);
+            }
+        }
+
+        let print_summary = matches!(output_options.format, OutputFormat::Default);
+        let deny_warnings = !warning_options.allow_warnings
+            && (warning_options.deny_warnings || lint_service.linter().deny_warnings());
+        let lint_result = |lint_service: &LintService,
+                           diagnostic_service: &DiagnosticService,
+                           now: Instant| LintResult {
             duration: now.elapsed(),
             number_of_rules: lint_service.linter().number_of_rules(),
-            number_of_files,
+            number_of_files: lint_service.number_of_paths(),
             number_of_warnings: diagnostic_service.warnings_count(),
             number_of_errors: diagnostic_service.errors_count(),
             max_warnings_exceeded: diagnostic_service.max_warnings_exceeded(),
-            deny_warnings: warning_options.deny_warnings,
-            print_summary: matches!(output_options.format, OutputFormat::Default),
-        })
+            deny_warnings,
+            print_summary,
+            stats: diagnostic_service.stats().cloned(),
+        };
+        let result = lint_result(&lint_service, &diagnostic_service, now);
+
+        if let Some(watcher) = watcher {
+            CliRunResult::LintResult(result).report();
+            watcher.run(lint_service, |lint_service, now| {
+                let mut diagnostic_service = Self::get_diagnostic_service(
+                    &warning_options,
+                    &output_options,
+                    &misc_options,
+                    catalog.as_ref(),
+                );
+                run_lint_service(lint_service, &mut diagnostic_service, now);
+                lint_result(lint_service, &diagnostic_service, now)
+            });
+        }
+
+        CliRunResult::LintResult(result)
     }
 }
 
 impl LintRunner {
+    /// Lint `source_text` as the file of `--stdin-filename` instead of reading stdin.
+    #[must_use]
+    pub fn with_stdin(mut self, source_text: String) -> Self {
+        self.stdin = Some(source_text);
+        self
+    }
+
+    /// Report of a `--filter` run: the linted files and the time spent in each rule, unless the
+    /// table of `--timing` is printed.
+    #[allow(clippy::print_stdout)]
+    fn print_filter_report(paths: &[Box<Path>], linter: &Linter, print_timings: bool) {
+        println!("Linted files:");
+        for path in paths {
+            println!("  {}", path.display());
+        }
+        if let Some(timings) = linter.timings().filter(|_| print_timings) {
+            let timings = timings.sorted();
+            let ids = timings.iter().map(RuleTiming::id).collect::<Vec<_>>();
+            let width = ids.iter().map(String::len).max().unwrap_or_default();
+            println!("Time spent in each rule:");
+            for (id, timing) in ids.iter().zip(&timings) {
+                println!("  {id:width$}  {:?}", timing.duration);
+            }
+        }
+    }
+
+    /// Table of the slowest rules of a `--timing` run.
+    #[allow(clippy::print_stdout)]
+    fn print_timings(timings: &RuleTimings) {
+        println!("{}", timings.table(TIMING_TABLE_ROWS));
+    }
+
     fn get_diagnostic_service(
         warning_options: &WarningOptions,
         output_options: &OutputOptions,
         misc_options: &MiscOptions,
+        catalog: Option<&MessageCatalog>,
     ) -> DiagnosticService {
         let mut diagnostic_service = DiagnosticService::default()
             .with_quiet(warning_options.quiet)
             .with_silent(misc_options.silent)
-            .with_max_warnings(warning_options.max_warnings);
+            .with_max_warnings(warning_options.max_warnings)
+            .with_stats(output_options.stats)
+            .with_catalog(catalog.cloned());
 
         match output_options.format {
             OutputFormat::Default => {}
@@ -175,13 +435,41 @@ impl LintRunner {
             OutputFormat::Unix => diagnostic_service.set_unix_reporter(),
             OutputFormat::Checkstyle => diagnostic_service.set_checkstyle_reporter(),
             OutputFormat::Github => diagnostic_service.set_github_reporter(),
+            OutputFormat::Junit => diagnostic_service.set_junit_reporter(),
+            OutputFormat::Stylish => diagnostic_service.set_stylish_reporter(),
         }
         diagnostic_service
     }
 }
 
+/// Lint the files of `lint_service`, printing the diagnostics as they are reported. The run
+/// started at `now`, for the statistics.
+pub(crate) fn run_lint_service(
+    lint_service: &LintService,
+    diagnostic_service: &mut DiagnosticService,
+    now: Instant,
+) {
+    // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
+    rayon::spawn({
+        let tx_error = diagnostic_service.sender().clone();
+        let lint_service = lint_service.clone();
+        move || {
+            lint_service.run(&tx_error);
+        }
+    });
+    diagnostic_service.run();
+    if let Some(stats) = diagnostic_service.stats_mut() {
+        stats.files = lint_service.number_of_paths();
+        stats.parse_failures = lint_service.number_of_parse_failures();
+        stats.duration = now.elapsed();
+    }
+    diagnostic_service.finish();
+}
+
 #[cfg(all(test, not(target_os = "windows")))]
 mod test {
+    use oxc_linter::LintCache;
+
     use super::LintRunner;
     use crate::{lint_command, CliRunResult, LintResult, Runner};
 
@@ -195,6 +483,13 @@ mod test {
         }
     }
 
+    fn test_stdin(args: &[&str], source_text: &str) -> CliRunResult {
+        let mut new_args = vec!["--silent"];
+        new_args.extend(args);
+        let options = lint_command().run_inner(new_args.as_slice()).unwrap();
+        LintRunner::new(options).with_stdin(source_text.to_string()).run()
+    }
+
     fn test_invalid_options(args: &[&str]) -> String {
         let mut new_args = vec!["--quiet"];
         new_args.extend(args);
@@ -216,6 +511,21 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn max_warnings() {
+        let result = test(&["--max-warnings", "1", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 2);
+        assert!(result.max_warnings_exceeded);
+        let result = test(&["--max-warnings", "2", "fixtures/linter"]);
+        assert!(!result.max_warnings_exceeded);
+        // Errors don't count as warnings
+        let args = &["--max-warnings", "0", "-D", "correctness", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+        assert!(!result.max_warnings_exceeded);
+    }
+
     #[test]
     fn dir() {
         let args = &["fixtures/linter"];
@@ -289,6 +599,25 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn oxlintignore_and_ignore_patterns() {
+        // `dist/` is ignored by the `ignorePatterns` of the configuration, and `generated/*`
+        // except `generated/keep.js` by the `.oxlintignore` file
+        let args = &["-W", "no-debugger", "fixtures/ignore_files"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+
+        let args = &["-W", "no-debugger", "fixtures/ignore_files/dist/bundle.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["-W", "no-debugger", "--no-ignore", "fixtures/ignore_files"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 4);
+        assert_eq!(result.number_of_warnings, 4);
+    }
+
     #[test]
     fn filter_allow_all() {
         let args = &["-A", "all", "fixtures/linter"];
@@ -316,6 +645,359 @@ mod test {
         assert_eq!(result.number_of_errors, 1);
     }
 
+    #[test]
+    fn filter_precedence() {
+        let args = &["-W", "correctness", "-D", "no-debugger", "fixtures/linter/debugger.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn filter_plugin_patterns() {
+        let result = test(&["-A", "all", "-D", "eslint/*", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 0);
+        assert!(result.number_of_errors >= 2);
+        let result = test(&["-A", "all", "-W", "eslint/no-debug*", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 1);
+        let result = test(&["-D", "correctness", "-A", "eslint/*", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings + result.number_of_errors, 0);
+        let result = test(&["-W", "all", "-D", "eslint/correctness", "fixtures/linter"]);
+        assert_eq!(result.number_of_errors, 2);
+
+        // Naming a rule of a disabled plugin enables it
+        let result = test(&["-A", "all", "-W", "react/jsx-key", "fixtures/linter"]);
+        assert_eq!(result.number_of_rules, 1);
+
+        let message = test_invalid_options(&["-W", "eslint/no-such-*"]);
+        assert!(
+            message.contains("`-W eslint/no-such-*` does not match any rule, plugin or category.")
+        );
+    }
+
+    #[test]
+    fn rule_filter() {
+        let args = &["-A", "all", "--filter", "no-debugger", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_rules, 1);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        // plugin names and configured severities
+        let args = &[
+            "--filter",
+            "eslint/no-console",
+            "--filter",
+            "unicorn",
+            "-c",
+            "fixtures/no_console_off/eslintrc.json",
+            "fixtures/no_console_off/test.js",
+        ];
+        let result = test(args);
+        assert!(result.number_of_rules > 1);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn timing() {
+        // Running the rules one by one reports the same diagnostics
+        let result = test(&["--timing", "fixtures/linter"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn stylish() {
+        let result = test(&["--format", "stylish", "fixtures/linter"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn deny_warnings_config() {
+        let args = &["-c", "fixtures/deny_warnings/.oxlintrc.json", "fixtures/deny_warnings"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert!(result.deny_warnings);
+
+        let result = test(&["--allow-warnings", args[0], args[1], args[2]]);
+        assert!(!result.deny_warnings);
+    }
+
+    #[test]
+    fn require_suppression_reasons() {
+        let args = &["fixtures/suppression_reasons"];
+        assert_eq!(test(args).number_of_warnings, 0);
+
+        // the directive without a reason
+        let args =
+            &["-c", "fixtures/suppression_reasons/oxlintrc.json", "fixtures/suppression_reasons"];
+        assert_eq!(test(args).number_of_warnings, 1);
+    }
+
+    #[test]
+    fn explain() {
+        // The explanations follow the help, the problems are the same
+        let result = test(&["--explain", "fixtures/linter"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn stats() {
+        let result = test(&["--stats", "fixtures/stats"]);
+        let stats = result.stats.unwrap();
+        assert_eq!((stats.files, stats.parse_failures), (2, 0));
+        assert_eq!((stats.warnings, stats.errors, stats.fixable), (3, 0, 3));
+        let rules = stats.sorted_rules();
+        assert_eq!(rules.len(), 2);
+        assert_eq!((rules[0].0, rules[0].1.problems(), rules[0].1.fixable), ("no-debugger", 2, 2));
+        assert_eq!((rules[1].0, rules[1].1.problems()), ("use-isnan", 1));
+
+        assert!(test(&["fixtures/stats"]).stats.is_none());
+
+        // The syntax errors are not reported by a rule
+        let result = test_stdin(&["--stats", "--stdin-filename", "a.js"], "let = ;");
+        let CliRunResult::LintResult(LintResult { stats: Some(stats), .. }) = result else {
+            panic!("{result:?}");
+        };
+        assert_eq!((stats.files, stats.parse_failures, stats.errors), (1, 1, 1));
+        assert!(stats.rules.is_empty());
+    }
+
+    #[test]
+    fn locale() {
+        // The problems are the same, only their messages are translated
+        let result = test(&["--locale", "fixtures/locale/de.json", "fixtures/linter"]);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+
+        let result = test(&["--locale", "en-US", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 2);
+
+        let message = test_invalid_options(&["--locale", "xx", "fixtures/linter"]);
+        assert!(message.starts_with("No message catalog for the locale `xx`"));
+        // Not needed for the output of `--format json`, which is not translated
+        let result = test(&["--locale", "xx", "--format", "json", "fixtures/linter"]);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn unused_exports() {
+        // `unused` of `utils.js`, the exports of `index.js` are ignored and `suppressed` of
+        // `utils.js` and the exports of `disabled.js` and `off.js` are disabled by their comments
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-c",
+            "fixtures/unused_exports/oxlintrc.json",
+            "fixtures/unused_exports",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 5);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn self_import() {
+        // The relative path of the file is compared with the resolved path of `./index`
+        let args =
+            &["--import-plugin", "-A", "all", "-D", "no-self-import", "fixtures/self_import"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn max_problems_per_rule() {
+        let result = test(&["fixtures/max_problems"]);
+        assert_eq!(result.number_of_warnings, 4);
+
+        // The note with the number of problems left out is not counted
+        let result = test(&["--max-problems-per-rule", "1", "fixtures/max_problems"]);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+
+        // Nor by `--max-warnings`
+        let args =
+            &["--max-problems-per-rule", "1", "--max-warnings", "1", "fixtures/max_problems"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert!(!result.max_warnings_exceeded);
+        let args =
+            &["--max-problems-per-rule", "2", "--max-warnings", "1", "fixtures/max_problems"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert!(result.max_warnings_exceeded);
+    }
+
+    #[test]
+    fn group_same_line() {
+        // The two statements of the first line are reported together
+        let result = test(&["--group-same-line", "fixtures/max_problems"]);
+        assert_eq!(result.number_of_warnings, 3);
+
+        // Only by the default format
+        let args = &["--group-same-line", "--format", "json", "fixtures/max_problems"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 4);
+    }
+
+    #[test]
+    fn generated_files() {
+        // `generated.js` has a `@generated` header and `bundle.js` is minified
+        let result = test(&["fixtures/generated"]);
+        assert_eq!(result.number_of_warnings, 1);
+
+        let result = test(&["--generated-files", "lint", "fixtures/generated"]);
+        assert_eq!(result.number_of_warnings, 3);
+
+        let result =
+            test(&["-D", "correctness", "--generated-files", "warn", "fixtures/generated"]);
+        assert_eq!(result.number_of_errors, 1);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
+    #[test]
+    fn nursery() {
+        // `no-undef` is in the nursery
+        let result = test(&["fixtures/nursery"]);
+        assert_eq!(result.number_of_warnings, 0);
+        let result = test(&["-W", "all", "fixtures/nursery"]);
+        assert_eq!(result.number_of_warnings, 0);
+        let result = test(&["-W", "no-undef", "fixtures/nursery"]);
+        assert_eq!(result.number_of_warnings, 1);
+
+        let result = test(&["--nursery", "fixtures/nursery"]);
+        assert_eq!(result.number_of_warnings, 1);
+        let result = test(&["--nursery", "-A", "all", "-D", "all", "fixtures/nursery"]);
+        assert_eq!(result.number_of_errors, 1);
+        let result = test(&["--nursery", "-A", "no-undef", "fixtures/nursery"]);
+        assert_eq!(result.number_of_warnings, 0);
+    }
+
+    #[test]
+    fn security() {
+        // `no-eval` and `no-proto` are reported although the configuration and the comment turn
+        // them off, and `no-debugger` is not a rule of the security preset
+        let result = test(&["--security", "fixtures/security"]);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 5);
+        let result = test(&["--security", "--filter", "no-eval", "fixtures/security"]);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn cjs_script() {
+        // `.cjs` files are parsed as scripts
+        let result = test(&["fixtures/module_format/script.cjs"]);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn inline_config() {
+        let result = test(&["fixtures/inline_config/test.js"]);
+        assert_eq!(result.number_of_files, 1);
+        // `eqeqeq`, and not `no-debugger`
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn print_config() {
+        let args = &["--print-config", "fixtures/nested_config/package/test.js"];
+        let options = lint_command().run_inner(args).unwrap();
+        assert!(matches!(LintRunner::new(options).run(), CliRunResult::None));
+    }
+
+    #[test]
+    fn react_refresh_plugin() {
+        let result = test(&["fixtures/react_refresh/App.jsx"]);
+        assert_eq!(result.number_of_warnings, 0);
+        let result = test(&["--react-refresh-plugin", "fixtures/react_refresh/App.jsx"]);
+        assert_eq!(result.number_of_warnings, 1);
+        let args =
+            &["-c", "fixtures/react_refresh/oxlintrc.json", "fixtures/react_refresh/App.jsx"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn rule_filter_unknown() {
+        let message = test_invalid_options(&["--filter", "no-such-rule"]);
+        assert!(message.contains("`--filter no-such-rule` does not match any rule or plugin."));
+
+        let message = test_invalid_options(&["--filter", "unicron"]);
+        assert!(message.contains("Did you mean `unicorn`?"));
+    }
+
+    #[test]
+    fn notebook() {
+        // one `debugger` in each code cell, `answer` is declared in another cell,
+        // and the python notebook is not linted
+        let args = &["-W", "no-debugger", "-D", "no-undef", "fixtures/notebook"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn unknown_rules() {
+        let message = test_invalid_options(&["-D", "no-debuger"]);
+        assert!(message.contains("`-D no-debuger` does not match any rule, plugin or category."));
+        assert!(message.contains("Did you mean `no-debugger`"));
+
+        let message = test_invalid_options(&["-A", "corectness"]);
+        assert!(message.contains("Did you mean `correctness`?"));
+
+        // warnings for the config file and the disable comment, and the unsuppressed `debugger`
+        let args =
+            &["-c", "fixtures/unknown_rules/eslintrc.json", "fixtures/unknown_rules/test.js"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn report_unused_disable_directives() {
+        let args = &["-W", "no-debugger", "fixtures/unused_disable_directives"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 1);
+
+        // the unsuppressed `debugger` and the unused `no-console` directive
+        let args = &[
+            "-W",
+            "no-debugger",
+            "--report-unused-disable-directives",
+            "fixtures/unused_disable_directives",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn nested_config() {
+        // `no-var` is enabled in the root, the package turns it off and enables `eqeqeq`
+        let args = &["fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 3);
+        assert_eq!(result.number_of_errors, 0);
+
+        // An explicit config disables the discovery of config files
+        let args = &["-c", "fixtures/nested_config/.oxlintrc.json", "fixtures/nested_config"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
     #[test]
     fn eslintrc_error() {
         let args = &["-c", "fixtures/linter/eslintrc.json", "fixtures/linter/debugger.js"];
@@ -417,6 +1099,61 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn severity_overrides() {
+        let args =
+            &["-c", "fixtures/severity_overrides/eslintrc.json", "fixtures/severity_overrides"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 1);
+        assert_eq!(result.number_of_errors, 1);
+    }
+
+    #[test]
+    fn rule_ignore_patterns() {
+        // `no-debugger` is ignored in `scripts/`
+        let args =
+            &["-c", "fixtures/rule_ignore_patterns/oxlintrc.json", "fixtures/rule_ignore_patterns"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 1);
+    }
+
+    #[test]
+    fn stdin_filename() {
+        let lint = |args: &[&str], source_text: &str| match test_stdin(args, source_text) {
+            CliRunResult::LintResult(result) => result,
+            other => panic!("{other:?}"),
+        };
+
+        // The file does not exist, its name determines the source type and the rules which apply
+        let source_text = "export const foo = 4;\nexport const Bar = () => <></>;\n";
+        let args = &["-A", "all", "-W", "react-refresh/only-export-components", "--stdin-filename"];
+        let result = lint(&[args.as_slice(), &["Bar.jsx"]].concat(), source_text);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 1);
+        let result = lint(&[args.as_slice(), &["Bar.test.jsx"]].concat(), source_text);
+        assert_eq!(result.number_of_warnings, 0);
+
+        // The severity overrides of the configuration match the file name
+        let args = &["-c", "fixtures/severity_overrides/eslintrc.json", "--stdin-filename"];
+        let result = lint(
+            &[args.as_slice(), &["fixtures/severity_overrides/new/a.js"]].concat(),
+            "debugger;",
+        );
+        assert_eq!(result.number_of_errors, 1);
+        let result = lint(
+            &[args.as_slice(), &["fixtures/severity_overrides/legacy/a.js"]].concat(),
+            "debugger;",
+        );
+        assert_eq!(result.number_of_warnings + result.number_of_errors, 0);
+
+        let result = test_stdin(&["--stdin-filename", "a.js", "fixtures/linter"], "");
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+        let result = test_stdin(&["--fix", "--stdin-filename", "a.js"], "");
+        assert!(matches!(result, CliRunResult::InvalidOptions { .. }));
+    }
+
     #[test]
     fn typescript_eslint() {
         let args = &[
@@ -480,6 +1217,127 @@ mod test {
         assert_eq!(result.number_of_errors, 0);
     }
 
+    #[test]
+    fn lint_html_file() {
+        // The classic and module scripts, the JSON data block is not linted
+        let args = &["fixtures/html/debugger.html"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_json_file() {
+        let args = &["fixtures/json/package.json"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 0);
+
+        let args = &["--json-plugin", "fixtures/json"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+    }
+
+    #[test]
+    fn lint_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join(".oxlintcache");
+        let location = location.to_str().unwrap();
+
+        let args = &["--cache", "--cache-location", location, "-A", "all", "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 2);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(LintCache::load(location.into()).len(), 2);
+
+        // A configuration change lints the files again
+        let args = &["--cache", "--cache-location", location, "fixtures/linter"];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 2);
+        assert!(LintCache::load(location.into()).is_empty());
+    }
+
+    #[test]
+    fn lint_cache_package_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join(".oxlintcache");
+        let location = location.to_str().unwrap();
+        let package_json = dir.path().join("package.json");
+        let app = dir.path().join("App.jsx");
+        std::fs::copy("fixtures/react_refresh/App.jsx", &app).unwrap();
+        let args = &["--cache", "--cache-location", location, "--react-refresh-plugin"];
+        let args = [args.as_slice(), &[app.to_str().unwrap()]].concat();
+
+        // The exports of CommonJS modules are not checked
+        std::fs::write(&package_json, r#"{ "type": "commonjs" }"#).unwrap();
+        let result = test(&args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(LintCache::load(location.into()).len(), 1);
+
+        // The file is linted again as an ES module when the `"type"` of the package changes
+        std::fs::write(&package_json, r#"{ "type": "module" }"#).unwrap();
+        let result = test(&args);
+        assert_eq!(result.number_of_warnings, 1);
+        assert!(LintCache::load(location.into()).is_empty());
+    }
+
+    #[test]
+    fn lint_cache_module_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join(".oxlintcache");
+        let location = location.to_str().unwrap();
+
+        // The dependencies of the linted file are restored from the cache by the second run
+        let args = &[
+            "--cache",
+            "--cache-location",
+            location,
+            "--import-plugin",
+            "-A",
+            "all",
+            "-W",
+            "import/named",
+            "fixtures/module_cache/index.js",
+        ];
+        for _ in 0..2 {
+            let result = test(args);
+            assert_eq!(result.number_of_files, 1);
+            assert_eq!(result.number_of_warnings, 1);
+            let cache = LintCache::load(location.into());
+            assert_eq!((cache.len(), cache.number_of_modules()), (0, 2));
+        }
+    }
+
+    #[test]
+    fn lint_fix_dry_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("debugger.js");
+        std::fs::write(&path, "debugger;\n").unwrap();
+
+        let args =
+            &["--fix", "--dry-run", "-A", "all", "-W", "no-debugger", path.to_str().unwrap()];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 1);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "debugger;\n");
+    }
+
+    #[test]
+    fn lint_fix_multipass() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("spread.js");
+        std::fs::write(&path, "const a = [...[...[1, 2]]];\n").unwrap();
+
+        // The fixes of the nested spreads overlap, the inner one is applied by a second pass
+        let args =
+            &["--fix", "-A", "all", "-W", "unicorn/no-useless-spread", path.to_str().unwrap()];
+        let result = test(args);
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "const a = [1, 2];\n");
+    }
+
     #[test]
     fn test_tsconfig_option() {
         // passed
@@ -490,6 +1348,25 @@ mod test {
             .contains("oxc/tsconfig.json\" does not exist, Please provide a valid tsconfig file."));
     }
 
+    #[test]
+    fn nearest_tsconfig() {
+        // The `paths` of the nearest tsconfig resolve `@/utils`, which does not export `missing`,
+        // and its `DOM` lib declares `document`, which is not declared for `packages/node`
+        let args = &[
+            "--import-plugin",
+            "-A",
+            "all",
+            "-W",
+            "no-undef",
+            "-W",
+            "import/named",
+            "fixtures/tsconfig_nearest",
+        ];
+        let result = test(args);
+        assert_eq!(result.number_of_files, 3);
+        assert_eq!(result.number_of_warnings, 2);
+    }
+
     #[test]
     fn test_enable_vitest_plugin() {
         let args = &[
@@ -511,4 +1388,54 @@ mod test {
         assert_eq!(result.number_of_files, 1);
         assert_eq!(result.number_of_errors, 1);
     }
+
+    #[test]
+    fn wasm_plugin() {
+        // `fixture/no-foo` reports the declaration of `foo` and its reference
+        let plugin = ["--plugin", "fixtures/wasm_plugin/no_foo.wasm"];
+        let result = test(&[&plugin[..], &["fixtures/wasm_plugin/index.js"]].concat());
+        assert_eq!(result.number_of_warnings, 2);
+        assert_eq!(result.number_of_errors, 0);
+
+        let args = ["-c", "fixtures/wasm_plugin/error.json", "fixtures/wasm_plugin/index.js"];
+        let result = test(&[&plugin[..], &args].concat());
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 2);
+
+        let args = ["-c", "fixtures/wasm_plugin/off.json", "fixtures/wasm_plugin/index.js"];
+        let result = test(&[&plugin[..], &args].concat());
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(result.number_of_errors, 0);
+
+        // The fixes of plugins are applied like the ones of the built-in rules
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("index.js");
+        std::fs::copy("fixtures/wasm_plugin/index.js", &file).unwrap();
+        let result = test(&[&plugin[..], &["--fix", file.to_str().unwrap()]].concat());
+        assert_eq!(result.number_of_warnings, 0);
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            "export const bar = 1;\n\nexport function get() {\n  return bar;\n}\n"
+        );
+    }
+
+    #[test]
+    fn wasm_plugin_invalid() {
+        let message =
+            test_invalid_options(&["--plugin", "fixtures/wasm_plugin/index.js", "fixtures"]);
+        assert!(message.contains("Failed to load the plugin"));
+    }
+
+    #[test]
+    fn wasm_plugin_failure() {
+        // A problem splitting the `é` of the file, and an output out of the memory of the plugin,
+        // are reported as failures of the plugin
+        for plugin in
+            ["fixtures/wasm_plugin/split_char.wasm", "fixtures/wasm_plugin/huge_output.wasm"]
+        {
+            let result = test(&["--plugin", plugin, "fixtures/wasm_plugin/unicode.js"]);
+            assert_eq!(result.number_of_warnings, 0);
+            assert_eq!(result.number_of_errors, 1);
+        }
+    }
 }
@@ -0,0 +1,218 @@
+use std::{
+    fmt::Write as _,
+    io::{BufRead, Write},
+    path::Path,
+    sync::Mutex,
+};
+
+use oxc_linter::{Fix, FixReviewer, Message};
+use rustc_hash::FxHashSet;
+
+const HELP: &str = "y - apply this fix
+n - do not apply this fix
+a - apply this fix and all remaining fixes of this rule
+s - do not apply this fix nor any remaining fixes of this rule
+q - do not apply this fix nor any remaining fixes
+? - print help";
+
+/// Review of fixes for `--fix --interactive`: each fix is printed as a diff hunk
+/// and only the fixes accepted by the user are applied.
+pub struct InteractiveFixReviewer<R, W> {
+    state: Mutex<ReviewState<R, W>>,
+}
+
+struct ReviewState<R, W> {
+    input: R,
+    output: W,
+    /// Rules whose remaining fixes are applied without asking
+    accepted_rules: FxHashSet<&'static str>,
+    /// Rules whose remaining fixes are not applied
+    skipped_rules: FxHashSet<&'static str>,
+    quit: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Answer {
+    Yes,
+    No,
+    AcceptRule,
+    SkipRule,
+    Quit,
+}
+
+impl<R: BufRead, W: Write> InteractiveFixReviewer<R, W> {
+    pub fn new(input: R, output: W) -> Self {
+        Self {
+            state: Mutex::new(ReviewState {
+                input,
+                output,
+                accepted_rules: FxHashSet::default(),
+                skipped_rules: FxHashSet::default(),
+                quit: false,
+            }),
+        }
+    }
+}
+
+impl<R: BufRead + Send, W: Write + Send> FixReviewer for InteractiveFixReviewer<R, W> {
+    fn review(&self, path: &Path, source_text: &str, messages: &mut [Message]) {
+        // Review one file at a time, files are fixed in parallel.
+        let mut state = self.state.lock().unwrap();
+        messages.sort_by_key(|message| message.fix.as_ref().map(|fix| fix.span));
+        for message in messages.iter_mut() {
+            let rejected = match &message.fix {
+                Some(fix) => !state.review(path, source_text, fix, message),
+                None => false,
+            };
+            if rejected {
+                message.fix = None;
+            }
+        }
+    }
+}
+
+impl<R: BufRead, W: Write> ReviewState<R, W> {
+    /// Whether to apply `fix`.
+    fn review(&mut self, path: &Path, source_text: &str, fix: &Fix, message: &Message) -> bool {
+        let rule_name = message.rule_name().unwrap_or_default();
+        if self.quit || self.skipped_rules.contains(rule_name) {
+            return false;
+        }
+        if self.accepted_rules.contains(rule_name) {
+            return true;
+        }
+
+        let (line, hunk) = render_hunk(source_text, fix);
+        let _ =
+            writeln!(self.output, "\n{}:{line}: {}\n{hunk}", path.display(), message.error.message);
+
+        let answer = self.ask();
+        match answer {
+            Answer::AcceptRule => {
+                self.accepted_rules.insert(rule_name);
+            }
+            Answer::SkipRule => {
+                self.skipped_rules.insert(rule_name);
+            }
+            Answer::Quit => self.quit = true,
+            Answer::Yes | Answer::No => {}
+        }
+        matches!(answer, Answer::Yes | Answer::AcceptRule)
+    }
+
+    fn ask(&mut self) -> Answer {
+        loop {
+            let _ = write!(self.output, "Apply this fix [y,n,a,s,q,?]? ");
+            let _ = self.output.flush();
+            let mut line = String::new();
+            // Stop asking at the end of the input.
+            if self.input.read_line(&mut line).unwrap_or_default() == 0 {
+                return Answer::Quit;
+            }
+            match line.trim() {
+                "y" => return Answer::Yes,
+                "n" => return Answer::No,
+                "a" => return Answer::AcceptRule,
+                "s" => return Answer::SkipRule,
+                "q" => return Answer::Quit,
+                _ => {
+                    let _ = writeln!(self.output, "{HELP}");
+                }
+            }
+        }
+    }
+}
+
+/// Render the lines changed by `fix` as a diff hunk, returning it with its first line number.
+fn render_hunk(source_text: &str, fix: &Fix) -> (usize, String) {
+    let start = fix.span.start as usize;
+    let end = fix.span.end as usize;
+    let line_start = source_text[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = source_text[end..].find('\n').map_or(source_text.len(), |i| end + i);
+    let line = source_text[..line_start].matches('\n').count() + 1;
+
+    let before = &source_text[line_start..line_end];
+    let after = format!(
+        "{}{}{}",
+        &source_text[line_start..start],
+        fix.content,
+        &source_text[end..line_end]
+    );
+
+    let mut hunk = String::new();
+    for line in before.split('\n') {
+        let _ = writeln!(hunk, "-{line}");
+    }
+    for line in after.split('\n') {
+        let _ = writeln!(hunk, "+{line}");
+    }
+    (line, hunk)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_linter::{Fix, FixReviewer, Message};
+    use oxc_span::Span;
+
+    use super::{render_hunk, InteractiveFixReviewer};
+
+    fn messages(source_text: &str, pattern: &str) -> Vec<Message<'static>> {
+        source_text
+            .match_indices(pattern)
+            .map(|(start, _)| {
+                #[allow(clippy::cast_possible_truncation)]
+                let span = Span::new(start as u32, (start + pattern.len()) as u32);
+                Message::new(
+                    OxcDiagnostic::warn("remove").with_label(span),
+                    Some(Fix::delete(span)),
+                )
+            })
+            .collect()
+    }
+
+    fn review(source_text: &str, input: &str) -> (Vec<bool>, String) {
+        let mut output = Vec::new();
+        let reviewer = InteractiveFixReviewer::new(input.as_bytes(), &mut output);
+        let mut messages = messages(source_text, "debugger;");
+        reviewer.review(Path::new("test.js"), source_text, &mut messages);
+        drop(reviewer);
+        let accepted = messages.iter().map(|message| message.fix.is_some()).collect();
+        (accepted, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_render_hunk() {
+        let source_text = "let a = 1;\nif (a) { debugger; }\nfoo();\n";
+        let span = Span::new(20, 29);
+        let (line, hunk) = render_hunk(source_text, &Fix::delete(span));
+        assert_eq!(line, 2);
+        assert_eq!(hunk, "-if (a) { debugger; }\n+if (a) {  }\n");
+    }
+
+    #[test]
+    fn test_review() {
+        let source_text = "debugger;\ndebugger;\ndebugger;\n";
+
+        let (accepted, output) = review(source_text, "y\nn\ny\n");
+        assert_eq!(accepted, [true, false, true]);
+        assert!(output.contains("test.js:2: remove\n-debugger;\n+\n"));
+
+        // Unknown answers print the help and ask again
+        let (accepted, output) = review(source_text, "x\nq\n");
+        assert_eq!(accepted, [false, false, false]);
+        assert!(output.contains("y - apply this fix"));
+
+        let (accepted, _) = review(source_text, "n\na\n");
+        assert_eq!(accepted, [false, true, true]);
+
+        let (accepted, _) = review(source_text, "y\ns\n");
+        assert_eq!(accepted, [true, false, false]);
+
+        // The end of the input rejects all remaining fixes
+        let (accepted, _) = review(source_text, "y\n");
+        assert_eq!(accepted, [true, false, false]);
+    }
+}
@@ -0,0 +1,137 @@
+//! `--watch`: lint the changed files again until the process is interrupted.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Termination,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use oxc_linter::LintService;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    walk::{Extensions, Walk},
+    CliRunResult, IgnoreOptions, LintResult,
+};
+
+/// How often the files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The modification time and the size of each file, either changes when a file is written.
+type Snapshot = FxHashMap<Box<Path>, (Option<SystemTime>, u64)>;
+
+/// Polls the linted files for changes, without relying on platform specific file system events.
+pub(super) struct Watcher {
+    /// The paths given on the command line, walked again to find created and deleted files
+    roots: Vec<PathBuf>,
+    ignore_options: IgnoreOptions,
+    extensions: Extensions,
+    snapshot: Snapshot,
+}
+
+impl Watcher {
+    pub(super) fn new(
+        roots: Vec<PathBuf>,
+        ignore_options: IgnoreOptions,
+        extensions: Extensions,
+        paths: &[Box<Path>],
+    ) -> Self {
+        Self { roots, ignore_options, extensions, snapshot: Self::snapshot(paths) }
+    }
+
+    fn snapshot(paths: &[Box<Path>]) -> Snapshot {
+        paths
+            .iter()
+            .map(|path| {
+                let stamp = fs::metadata(path)
+                    .map(|metadata| (metadata.modified().ok(), metadata.len()))
+                    .unwrap_or_default();
+                (path.clone(), stamp)
+            })
+            .collect()
+    }
+
+    /// The files which were modified, created or deleted since the previous call, and all the
+    /// files to lint.
+    fn changes(&mut self) -> (Vec<Box<Path>>, Vec<Box<Path>>) {
+        let paths = Walk::new(&self.roots, &self.ignore_options)
+            .with_extensions(self.extensions.clone())
+            .paths();
+        let snapshot = Self::snapshot(&paths);
+        let mut changed = snapshot
+            .iter()
+            .filter(|(path, stamp)| self.snapshot.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        changed.extend(self.snapshot.keys().filter(|path| !snapshot.contains_key(*path)).cloned());
+        self.snapshot = snapshot;
+        (changed, paths)
+    }
+
+    /// Lint the changed files and the files importing them again whenever files change, reusing
+    /// the linter and the module graph of `lint_service`. Prints the diagnostics and a summary
+    /// after each run.
+    #[allow(clippy::print_stdout)]
+    pub(super) fn run(
+        mut self,
+        mut lint_service: LintService,
+        mut result: impl FnMut(&LintService, Instant) -> LintResult,
+    ) -> ! {
+        println!("Watching for file changes...");
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let (changed, mut paths) = self.changes();
+            if changed.is_empty() {
+                continue;
+            }
+            if !self.ignore_options.no_ignore {
+                paths.retain(|path| !lint_service.linter().is_ignored(path));
+            }
+            let now = Instant::now();
+            lint_service = lint_service.with_changed_paths(&changed, &paths);
+            CliRunResult::LintResult(result(&lint_service, now)).report();
+            println!("Watching for file changes...");
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use super::Watcher;
+    use crate::{lint_command, walk::Extensions};
+
+    #[test]
+    fn test_changes() {
+        let dir = tempfile::tempdir().unwrap();
+        let a = dir.path().join("a.js");
+        let b = dir.path().join("b.js");
+        fs::write(&a, "debugger;").unwrap();
+        fs::write(&b, "debugger;").unwrap();
+
+        let ignore_options = lint_command().run_inner(&[] as &[&str]).unwrap().ignore_options;
+        let mut watcher = Watcher::new(
+            vec![dir.path().to_path_buf()],
+            ignore_options,
+            Extensions::default(),
+            &[a.clone().into_boxed_path(), b.clone().into_boxed_path()],
+        );
+        let (changed, paths) = watcher.changes();
+        assert!(changed.is_empty());
+        assert_eq!(paths.len(), 2);
+
+        fs::write(&a, "debugger; debugger;").unwrap();
+        fs::remove_file(&b).unwrap();
+        let c = dir.path().join("c.js");
+        fs::write(&c, "").unwrap();
+        let (mut changed, paths) = watcher.changes();
+        changed.sort_unstable();
+        assert_eq!(changed, [Path::new(&a).into(), Path::new(&b).into(), Path::new(&c).into()]);
+        assert_eq!(paths.len(), 2);
+
+        assert!(watcher.changes().0.is_empty());
+    }
+}
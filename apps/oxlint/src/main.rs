@@ -10,12 +10,35 @@ static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
-use oxlint::{CliRunResult, LintRunner, Runner};
+use oxlint::{
+    CliRunResult, ExplainRunner, InitRunner, LintRunner, MigrateRunner, RulesRunner, Runner,
+    SuppressRunner,
+};
 
 fn main() -> CliRunResult {
     init_tracing();
     init_miette();
 
+    if std::env::args().nth(1).as_deref() == Some("migrate") {
+        return MigrateRunner::new(oxlint::migrate_command().run()).run();
+    }
+
+    if std::env::args().nth(1).as_deref() == Some("suppress") {
+        return SuppressRunner::new(oxlint::suppress_command().run()).run();
+    }
+
+    if matches!(std::env::args().nth(1).as_deref(), Some("init" | "--init")) {
+        return InitRunner::new(oxlint::init_command().run()).run();
+    }
+
+    if std::env::args().nth(1).as_deref() == Some("explain") {
+        return ExplainRunner::new(oxlint::explain_command().run()).run();
+    }
+
+    if std::env::args().nth(1).as_deref() == Some("rules") {
+        return RulesRunner::new(oxlint::rules_command().run()).run();
+    }
+
     let command = oxlint::lint_command().run();
     command.handle_threads();
     LintRunner::new(command).run()
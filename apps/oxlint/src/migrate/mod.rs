@@ -0,0 +1,195 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use oxc_linter::migrate_eslint_config;
+use serde_json::Value;
+
+use crate::{command::MigrateCommand, CliRunResult, Runner};
+
+/// ESLint configuration files, in the order ESLint looks them up
+const ESLINT_CONFIG_FILES: &[&str] = &[
+    "eslint.config.js",
+    "eslint.config.mjs",
+    "eslint.config.cjs",
+    ".eslintrc.js",
+    ".eslintrc.cjs",
+    ".eslintrc.yaml",
+    ".eslintrc.yml",
+    ".eslintrc.json",
+    ".eslintrc",
+    "package.json",
+];
+
+/// Evaluates the JavaScript configuration file passed as the first argument and prints it as JSON.
+/// Plugins are replaced by their names, parsers and processors are only kept when they are names.
+const EVALUATE_CONFIG_SCRIPT: &str = r"
+const { pathToFileURL } = require('node:url');
+import(pathToFileURL(process.argv[1]).href).then(async (module) => {
+  let config = await module.default;
+  if (typeof config === 'function') config = await config();
+  if (Array.isArray(config)) config = config.flat(Infinity);
+  process.stdout.write(JSON.stringify(config, (key, value) => {
+    if (key === 'plugins' && value && typeof value === 'object' && !Array.isArray(value)) {
+      return Object.keys(value);
+    }
+    if ((key === 'parser' || key === 'processor') && typeof value !== 'string') {
+      return undefined;
+    }
+    return value;
+  }));
+});
+";
+
+pub struct MigrateRunner {
+    options: MigrateCommand,
+}
+
+impl Runner for MigrateRunner {
+    type Options = MigrateCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    #[allow(clippy::print_stdout)]
+    fn run(self) -> CliRunResult {
+        let MigrateCommand { config, output } = self.options;
+
+        let Some(path) = config.or_else(find_eslint_config) else {
+            return CliRunResult::InvalidOptions {
+                message: "No ESLint configuration file found in the current directory".to_string(),
+            };
+        };
+        if !path.is_file() {
+            return CliRunResult::PathNotFound { paths: vec![path] };
+        }
+        if output.exists() {
+            return CliRunResult::InvalidOptions {
+                message: format!("{} already exists", output.display()),
+            };
+        }
+
+        let eslint_config = match read_eslint_config(&path) {
+            Ok(eslint_config) => eslint_config,
+            Err(message) => return CliRunResult::InvalidOptions { message },
+        };
+        let migrated = migrate_eslint_config(&eslint_config);
+
+        let mut json = serde_json::to_string_pretty(&migrated.config).unwrap();
+        json.push('\n');
+        if let Err(err) = fs::write(&output, json) {
+            return CliRunResult::InvalidOptions {
+                message: format!("Failed to write {}: {err}", output.display()),
+            };
+        }
+
+        println!("Migrated {} to {}.", path.display(), output.display());
+        if !migrated.unsupported.is_empty() {
+            println!("The following have no oxlint equivalent and were not migrated:");
+            for unsupported in &migrated.unsupported {
+                println!("  * {unsupported}");
+            }
+        }
+        CliRunResult::None
+    }
+}
+
+fn find_eslint_config() -> Option<PathBuf> {
+    ESLINT_CONFIG_FILES.iter().map(PathBuf::from).find(|path| {
+        if path.file_name().is_some_and(|name| name == "package.json") {
+            return read_json(path).is_ok_and(|json| json.get("eslintConfig").is_some());
+        }
+        path.is_file()
+    })
+}
+
+fn read_eslint_config(path: &Path) -> Result<Value, String> {
+    let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or_default();
+    match extension {
+        "js" | "mjs" | "cjs" | "ts" | "mts" | "cts" => evaluate_js_config(path),
+        "yaml" | "yml" => Err(format!(
+            "YAML configuration files are not supported, convert {} to JSON first",
+            path.display()
+        )),
+        _ if path.file_name().is_some_and(|name| name == "package.json") => read_json(path)?
+            .get_mut("eslintConfig")
+            .map(Value::take)
+            .ok_or_else(|| format!("{} has no `eslintConfig`", path.display())),
+        _ => read_json(path),
+    }
+}
+
+fn read_json(path: &Path) -> Result<Value, String> {
+    let mut string = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+    json_strip_comments::strip(&mut string)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))?;
+    serde_json::from_str(&string)
+        .map_err(|err| format!("Failed to parse {}: {err}", path.display()))
+}
+
+fn evaluate_js_config(path: &Path) -> Result<Value, String> {
+    let output = Command::new("node")
+        .arg("-e")
+        .arg(EVALUATE_CONFIG_SCRIPT)
+        .arg(path)
+        .output()
+        .map_err(|err| format!("Failed to run `node` to evaluate {}: {err}", path.display()))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Failed to evaluate {}:\n{}",
+            path.display(),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    serde_json::from_slice(&output.stdout)
+        .map_err(|err| format!("Failed to parse the evaluated {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::MigrateRunner;
+    use crate::{migrate_command, CliRunResult, Runner};
+
+    #[test]
+    fn migrate() {
+        let dir = tempfile::tempdir().unwrap();
+        let output = dir.path().join(".oxlintrc.json");
+        let output = output.to_str().unwrap();
+        let args = &["fixtures/migrate/.eslintrc.json", "--output", output];
+        let options = migrate_command().run_inner(args.as_slice()).unwrap();
+        assert!(matches!(MigrateRunner::new(options).run(), CliRunResult::None));
+
+        let config: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(output).unwrap()).unwrap();
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "plugins": ["import"],
+                "env": { "browser": true },
+                "rules": { "eqeqeq": ["error", "smart"], "import/no-cycle": "warn" },
+                "severityOverrides": [
+                    { "files": ["*.test.js"], "rules": ["eqeqeq"], "severity": "off" },
+                ],
+            })
+        );
+
+        // The output is not overwritten
+        let options = migrate_command().run_inner(args.as_slice()).unwrap();
+        assert!(matches!(MigrateRunner::new(options).run(), CliRunResult::InvalidOptions { .. }));
+    }
+
+    #[test]
+    fn migrate_yaml() {
+        let options = migrate_command().run_inner(&["fixtures/migrate/.eslintrc.yml"]).unwrap();
+        match MigrateRunner::new(options).run() {
+            CliRunResult::InvalidOptions { message } => {
+                assert!(message.starts_with("YAML configuration files are not supported"));
+            }
+            other => panic!("Expected InvalidOptions, got {other:?}"),
+        }
+    }
+}
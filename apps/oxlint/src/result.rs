@@ -1,9 +1,12 @@
 use std::{
+    fmt::Write,
     path::PathBuf,
     process::{ExitCode, Termination},
     time::Duration,
 };
 
+use oxc_diagnostics::DiagnosticStats;
+
 #[derive(Debug)]
 pub enum CliRunResult {
     None,
@@ -24,6 +27,8 @@ pub struct LintResult {
     pub max_warnings_exceeded: bool,
     pub deny_warnings: bool,
     pub print_summary: bool,
+    /// The statistics of `--stats`, printed after the summary
+    pub stats: Option<DiagnosticStats>,
 }
 
 #[derive(Debug)]
@@ -54,6 +59,7 @@ impl Termination for CliRunResult {
                 max_warnings_exceeded,
                 deny_warnings,
                 print_summary,
+                stats,
             }) => {
                 if print_summary {
                     let threads = rayon::current_num_threads();
@@ -73,7 +79,6 @@ impl Termination for CliRunResult {
                         println!(
                             "Exceeded maximum number of warnings. Found {number_of_warnings}."
                         );
-                        return ExitCode::from(1);
                     }
 
                     println!(
@@ -81,10 +86,18 @@ impl Termination for CliRunResult {
                         if number_of_warnings == 1 { "" } else { "s" },
                         if number_of_errors == 1 { "" } else { "s" }
                     );
+
+                    if let Some(stats) = &stats {
+                        print!("{}", Self::format_stats(stats));
+                    }
                 }
 
-                let exit_code =
-                    u8::from((number_of_warnings > 0 && deny_warnings) || number_of_errors > 0);
+                // Also when the summary is not printed, e.g. with `--format json` in CI
+                let exit_code = u8::from(
+                    (number_of_warnings > 0 && deny_warnings)
+                        || max_warnings_exceeded
+                        || number_of_errors > 0,
+                );
                 ExitCode::from(exit_code)
             }
             Self::FormatResult(FormatResult { duration, number_of_files }) => {
@@ -120,4 +133,69 @@ impl CliRunResult {
             format!("{:.1}s", duration.as_secs_f64())
         }
     }
+
+    /// The statistics of `--stats`: the files which could not be parsed, the fixable problems and
+    /// the problems of each rule, the rules with the most problems first.
+    fn format_stats(stats: &DiagnosticStats) -> String {
+        let mut s = String::new();
+        writeln!(s, "\nFiles which could not be parsed: {}", stats.parse_failures).unwrap();
+        writeln!(s, "Problems fixable with `--fix`: {}", stats.fixable).unwrap();
+        let rules = stats.sorted_rules();
+        if rules.is_empty() {
+            return s;
+        }
+        writeln!(s, "Problems per rule:").unwrap();
+        let width = rules.iter().map(|(rule, _)| rule.len()).max().unwrap_or_default();
+        for (rule, rule_stats) in rules {
+            write!(s, "  {rule:width$}  {}", rule_stats.problems()).unwrap();
+            if rule_stats.fixable > 0 {
+                write!(s, " ({} fixable)", rule_stats.fixable).unwrap();
+            }
+            writeln!(s).unwrap();
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::process::{ExitCode, Termination};
+
+    use oxc_diagnostics::{DiagnosticStats, RuleStats};
+
+    use super::{CliRunResult, LintResult};
+
+    #[test]
+    fn max_warnings_exceeded() {
+        let result = |max_warnings_exceeded| {
+            CliRunResult::LintResult(LintResult {
+                number_of_warnings: 2,
+                max_warnings_exceeded,
+                ..LintResult::default()
+            })
+            .report()
+        };
+        assert_eq!(result(false), ExitCode::SUCCESS);
+        assert_eq!(result(true), ExitCode::from(1));
+    }
+
+    #[test]
+    fn format_stats() {
+        let mut stats =
+            DiagnosticStats { parse_failures: 1, fixable: 2, ..DiagnosticStats::default() };
+        stats.rules.insert("eqeqeq".to_string(), RuleStats { warnings: 1, ..RuleStats::default() });
+        stats
+            .rules
+            .insert("no-debugger".to_string(), RuleStats { warnings: 1, errors: 1, fixable: 2 });
+        assert_eq!(
+            CliRunResult::format_stats(&stats),
+            "
+Files which could not be parsed: 1
+Problems fixable with `--fix`: 2
+Problems per rule:
+  no-debugger  2 (2 fixable)
+  eqeqeq       1
+"
+        );
+    }
 }
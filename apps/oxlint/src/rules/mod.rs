@@ -0,0 +1,82 @@
+use std::io::{BufWriter, Write};
+
+use oxc_linter::table::RuleTable;
+
+use crate::{
+    command::{RulesCommand, RulesFormat},
+    CliRunResult, Runner,
+};
+
+pub struct RulesRunner {
+    options: RulesCommand,
+}
+
+impl Runner for RulesRunner {
+    type Options = RulesCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    fn run(self) -> CliRunResult {
+        let mut stdout = BufWriter::new(std::io::stdout());
+        Self::write(self.options.format, &mut stdout);
+        stdout.flush().unwrap();
+        CliRunResult::None
+    }
+}
+
+impl RulesRunner {
+    fn write<W: Write>(format: RulesFormat, writer: &mut W) {
+        let table = RuleTable::new();
+        match format {
+            RulesFormat::Table => write!(writer, "{}", table.render_text_table()).unwrap(),
+            RulesFormat::Json => {
+                serde_json::to_writer_pretty(&mut *writer, &table.render_json()).unwrap();
+                writeln!(writer).unwrap();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RulesRunner;
+    use crate::command::RulesFormat;
+
+    #[test]
+    fn table() {
+        let mut writer = Vec::new();
+        RulesRunner::write(RulesFormat::Table, &mut writer);
+        let output = String::from_utf8(writer).unwrap();
+        let eqeqeq = output.lines().find(|line| line.starts_with("eqeqeq ")).unwrap();
+        assert_eq!(
+            eqeqeq.split_whitespace().collect::<Vec<_>>(),
+            ["eqeqeq", "eslint", "pedantic", "off", "dangerous_fix", "yes"]
+        );
+    }
+
+    #[test]
+    fn json() {
+        let mut writer = Vec::new();
+        RulesRunner::write(RulesFormat::Json, &mut writer);
+        let rules = serde_json::from_slice::<serde_json::Value>(&writer).unwrap();
+        let no_debugger = rules
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|rule| rule["plugin"] == "eslint" && rule["name"] == "no-debugger")
+            .unwrap();
+        assert_eq!(
+            no_debugger,
+            &serde_json::json!({
+                "name": "no-debugger",
+                "plugin": "eslint",
+                "category": "correctness",
+                "default": true,
+                "fix": "fix",
+                "options": false,
+            })
+        );
+    }
+}
@@ -0,0 +1,318 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env,
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{ast::JSXText, Visit};
+use oxc_diagnostics::{eslint_rule_id, DiagnosticService, GraphicalReportHandler};
+use oxc_linter::{
+    partial_loader::LINT_PARTIAL_LOADER_EXT, Fix, FixReviewer, LintOptions, LintService,
+    LintServiceOptions, Linter, Message,
+};
+use oxc_parser::Parser;
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    command::SuppressCommand,
+    lint::run_lint_service,
+    walk::{Extensions, Walk},
+    CliRunResult, Runner,
+};
+
+pub struct SuppressRunner {
+    options: SuppressCommand,
+}
+
+impl Runner for SuppressRunner {
+    type Options = SuppressCommand;
+
+    fn new(options: Self::Options) -> Self {
+        Self { options }
+    }
+
+    #[allow(clippy::print_stdout)]
+    fn run(self) -> CliRunResult {
+        let SuppressCommand {
+            basic_options,
+            rules,
+            reason,
+            enable_plugins,
+            ignore_options,
+            mut paths,
+        } = self.options;
+        let now = Instant::now();
+
+        let Ok(cwd) = env::current_dir() else {
+            return CliRunResult::InvalidOptions {
+                message: "Failed to get current working directory.".to_string(),
+            };
+        };
+        if paths.is_empty() {
+            paths.push(cwd.clone());
+        }
+
+        // Only the given rules run, with the options they are configured with
+        let nested_configs = basic_options.config.is_none();
+        let lint_options = LintOptions::default()
+            .with_config_path(basic_options.config)
+            .with_rule_filter(rules.clone())
+            .with_fix(true);
+        let lint_options = enable_plugins.apply(lint_options);
+
+        if let Err(diagnostic) = lint_options.check_filters() {
+            let handler = GraphicalReportHandler::new();
+            let mut err = String::new();
+            handler.render_report(&mut err, &diagnostic).unwrap();
+            return CliRunResult::InvalidOptions { message: err };
+        }
+
+        let inserter = Arc::new(SuppressionInserter::new(reason));
+        let linter = match Linter::from_options(lint_options) {
+            Ok(linter) => linter
+                .with_nested_configs(nested_configs)
+                .with_fix_reviewer(Arc::clone(&inserter) as Arc<dyn FixReviewer>),
+            Err(diagnostic) => {
+                let handler = GraphicalReportHandler::new();
+                let mut err = String::new();
+                handler.render_report(&mut err, diagnostic.as_ref()).unwrap();
+                return CliRunResult::InvalidOptions {
+                    message: format!("Failed to parse configuration file.\n{err}"),
+                };
+            }
+        };
+
+        let extensions = VALID_EXTENSIONS
+            .iter()
+            .chain(LINT_PARTIAL_LOADER_EXT.iter())
+            .copied()
+            .collect::<Vec<&'static str>>();
+        let paths =
+            Walk::new(&paths, &ignore_options).with_extensions(Extensions(extensions)).paths();
+
+        let options = LintServiceOptions {
+            cwd: cwd.into_boxed_path(),
+            paths,
+            tsconfig: basic_options.tsconfig,
+            cache: None,
+            stdin: None,
+        };
+        let lint_service = LintService::new(linter, options);
+        // Problems which could not be suppressed, e.g. syntax errors, are reported
+        let mut diagnostic_service = DiagnosticService::default();
+        run_lint_service(&lint_service, &mut diagnostic_service, now);
+
+        println!(
+            "Suppressed {} problems of {} in {} files.",
+            inserter.problems.load(Ordering::Relaxed),
+            rules.join(", "),
+            inserter.files.load(Ordering::Relaxed),
+        );
+        CliRunResult::None
+    }
+}
+
+/// Replaces the fixes of the reported problems by an `eslint-disable-next-line` comment above
+/// their line, listing the rules reported on the line, which the fixer then inserts.
+struct SuppressionInserter {
+    reason: String,
+    /// Number of suppressed problems
+    problems: AtomicUsize,
+    /// Number of files with suppressed problems
+    files: AtomicUsize,
+}
+
+impl SuppressionInserter {
+    fn new(reason: String) -> Self {
+        Self { reason, problems: AtomicUsize::new(0), files: AtomicUsize::new(0) }
+    }
+
+    /// The fix inserting the comment which disables `rules` on the line starting at `line_start`.
+    /// The comment is wrapped in braces in JSX children, where a line comment would be text.
+    fn comment(
+        &self,
+        source_text: &str,
+        line_start: usize,
+        rules: &BTreeSet<String>,
+        in_jsx_text: bool,
+    ) -> Fix<'static> {
+        let line = &source_text[line_start..];
+        let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        let newline = if source_text.contains("\r\n") { "\r\n" } else { "\n" };
+        let rules = rules.iter().map(String::as_str).collect::<Vec<_>>().join(", ");
+        let directive = format!("eslint-disable-next-line {rules} -- {}", self.reason);
+        let comment =
+            if in_jsx_text { format!("{{/* {directive} */}}") } else { format!("// {directive}") };
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(line_start as u32, line_start as u32);
+        Fix::new(format!("{indent}{comment}{newline}"), span)
+    }
+}
+
+impl FixReviewer for SuppressionInserter {
+    fn review(&self, path: &Path, source_text: &str, messages: &mut [Message]) {
+        // The rules reported on each line, keyed by the start of the line
+        let mut lines = BTreeMap::<usize, BTreeSet<String>>::new();
+        for message in messages.iter() {
+            let Some(rule_name) = message.rule_name() else { continue };
+            lines
+                .entry(line_start(source_text, message.start()))
+                .or_default()
+                .insert(rule_id(message, rule_name));
+        }
+
+        if lines.is_empty() {
+            return;
+        }
+
+        let jsx_texts = jsx_texts(path, source_text);
+        let comments = lines
+            .iter()
+            .map(|(&start, rules)| {
+                #[allow(clippy::cast_possible_truncation)]
+                let in_jsx_text = jsx_texts
+                    .iter()
+                    .any(|span| span.start < start as u32 && start as u32 <= span.end);
+                (start, self.comment(source_text, start, rules, in_jsx_text))
+            })
+            .collect::<FxHashMap<_, _>>();
+        let mut problems = 0;
+        for message in messages.iter_mut() {
+            message.fix = message.rule_name().and_then(|_| {
+                problems += 1;
+                comments.get(&line_start(source_text, message.start())).cloned()
+            });
+        }
+
+        if problems > 0 {
+            self.problems.fetch_add(problems, Ordering::Relaxed);
+            self.files.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// The spans of the JSX texts of the file at `path`, empty for files without JSX or which are not
+/// JavaScript, such as `.vue` files.
+fn jsx_texts(path: &Path, source_text: &str) -> Vec<Span> {
+    #[derive(Default)]
+    struct JsxTexts(Vec<Span>);
+
+    impl<'a> Visit<'a> for JsxTexts {
+        fn visit_jsx_text(&mut self, text: &JSXText<'a>) {
+            self.0.push(text.span);
+        }
+    }
+
+    let Some(source_type) = SourceType::from_path(path).ok().filter(|ty| ty.is_jsx()) else {
+        return vec![];
+    };
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, source_type).parse().program;
+    let mut jsx_texts = JsxTexts::default();
+    jsx_texts.visit_program(&program);
+    jsx_texts.0
+}
+
+fn line_start(source_text: &str, offset: u32) -> usize {
+    source_text[..offset as usize].rfind('\n').map_or(0, |i| i + 1)
+}
+
+/// The ESLint id of the rule which reported `message`, e.g. `unicorn/no-null`, so the comments
+/// are understood by ESLint as well.
+fn rule_id(message: &Message, rule_name: &str) -> String {
+    message
+        .error
+        .message
+        .split_once(':')
+        .map(|(id, _)| id)
+        .filter(|id| id.strip_suffix(')').is_some_and(|id| id.ends_with(&format!("({rule_name}"))))
+        .map_or_else(|| rule_name.to_string(), eslint_rule_id)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{collections::BTreeSet, path::Path};
+
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_linter::{FixReviewer, Fixer, Message};
+    use oxc_span::Span;
+
+    use super::SuppressionInserter;
+    use crate::{suppress_command, CliRunResult, Runner, SuppressRunner};
+
+    #[test]
+    fn insert_comments() {
+        let source_text = "let a = 1;\n  debugger; a == null;\n";
+        let mut messages = vec![
+            Message::new(
+                OxcDiagnostic::warn("eslint(no-debugger): debugger").with_label(Span::new(13, 22)),
+                None,
+            ),
+            Message::new(
+                OxcDiagnostic::warn("eslint(eqeqeq): eqeqeq").with_label(Span::new(23, 32)),
+                None,
+            ),
+        ];
+        // Rule names are set by the linter
+        assert!(messages.iter().all(|message| message.rule_name().is_none()));
+
+        let inserter = SuppressionInserter::new("TODO".to_string());
+        let rules = BTreeSet::from(["no-debugger".to_string(), "eqeqeq".to_string()]);
+        let fix = inserter.comment(source_text, 11, &rules, true);
+        assert_eq!(fix.content, "  {/* eslint-disable-next-line eqeqeq, no-debugger -- TODO */}\n");
+        let fix = inserter.comment(source_text, 11, &rules, false);
+        assert_eq!(fix.content, "  // eslint-disable-next-line eqeqeq, no-debugger -- TODO\n");
+        for message in &mut messages {
+            message.fix = Some(fix.clone());
+        }
+        let result = Fixer::new(source_text, messages).fix();
+        assert_eq!(
+            result.fixed_code,
+            "let a = 1;\n  // eslint-disable-next-line eqeqeq, no-debugger -- TODO\n  debugger; a == null;\n"
+        );
+        assert!(result.messages.is_empty());
+
+        // Syntax errors are not suppressed
+        let mut messages = vec![Message::new(OxcDiagnostic::error("syntax error"), None)];
+        inserter.review(Path::new("test.js"), source_text, &mut messages);
+        assert!(messages[0].fix.is_none());
+    }
+
+    #[test]
+    fn suppress() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test.js");
+        std::fs::write(&path, "function foo() {\n  debugger; if (foo == null) {}\n}\n").unwrap();
+
+        let args = &["--rules", "no-debugger,eqeqeq", "--reason", "later", path.to_str().unwrap()];
+        let options = suppress_command().run_inner(args.as_slice()).unwrap();
+        assert!(matches!(SuppressRunner::new(options).run(), CliRunResult::None));
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "function foo() {\n  // eslint-disable-next-line eqeqeq, no-debugger -- later\n  debugger; if (foo == null) {}\n}\n"
+        );
+
+        // The problems are suppressed
+        let options = suppress_command().run_inner(args.as_slice()).unwrap();
+        SuppressRunner::new(options).run();
+        assert_eq!(std::fs::read_to_string(&path).unwrap().matches("eslint-disable").count(), 1);
+
+        // Comments in JSX children are wrapped in braces
+        let path = dir.path().join("test.jsx");
+        std::fs::write(&path, "const a = (\n  <div>\n    {a == b}\n  </div>\n);\n").unwrap();
+        let args = &["--rules", "eqeqeq", path.to_str().unwrap()];
+        let options = suppress_command().run_inner(args.as_slice()).unwrap();
+        SuppressRunner::new(options).run();
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "const a = (\n  <div>\n    {/* eslint-disable-next-line eqeqeq -- TODO */}\n    {a == b}\n  </div>\n);\n"
+        );
+    }
+}
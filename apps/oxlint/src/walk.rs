@@ -8,6 +8,10 @@ use oxc_span::VALID_EXTENSIONS;
 
 use crate::IgnoreOptions;
 
+/// Name of the ignore files of oxlint, which take precedence over the `.eslintignore` and
+/// `.gitignore` files of the same directory.
+pub const IGNORE_FILE_NAME: &str = ".oxlintignore";
+
 #[derive(Clone)]
 pub struct Extensions(pub Vec<&'static str>);
 
@@ -87,7 +91,9 @@ impl Walk {
         }
 
         if !options.no_ignore {
+            // Later names take precedence
             inner.add_custom_ignore_filename(&options.ignore_path);
+            inner.add_custom_ignore_filename(IGNORE_FILE_NAME);
 
             if !options.ignore_pattern.is_empty() {
                 let mut override_builder = OverrideBuilder::new(Path::new("/"));
@@ -104,8 +110,16 @@ impl Walk {
         // Turning off `follow_links` because:
         // * following symlinks is a really slow syscall
         // * it is super rare to have symlinked source code
-        let inner =
-            inner.ignore(false).git_global(false).follow_links(options.symlinks).build_parallel();
+        // `.gitignore` files are honored outside of git repositories too, e.g. in extracted archives
+        // The directories are walked by as many threads as the files are linted, see `--threads`
+        let inner = inner
+            .threads(rayon::current_num_threads())
+            .ignore(false)
+            .git_ignore(!options.no_ignore)
+            .git_global(false)
+            .require_git(false)
+            .follow_links(options.symlinks)
+            .build_parallel();
         Self { inner, extensions: Extensions::default() }
     }
 
@@ -141,6 +155,8 @@ impl Walk {
 mod test {
     use std::{env, ffi::OsString};
 
+    use oxc_linter::GeneratedFiles;
+
     use super::{Extensions, Walk};
     use crate::IgnoreOptions;
 
@@ -153,6 +169,7 @@ mod test {
             ignore_path: OsString::from(".gitignore"),
             ignore_pattern: vec![],
             symlinks: false,
+            generated_files: GeneratedFiles::Skip,
         };
 
         let mut paths = Walk::new(&fixtures, &ignore_options)
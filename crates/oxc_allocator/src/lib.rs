@@ -4,9 +4,11 @@ use std::{
 };
 
 mod arena;
+mod pool;
 
 pub use arena::{Box, String, Vec};
 use bumpalo::Bump;
+pub use pool::{AllocatorGuard, AllocatorPool};
 
 #[derive(Default)]
 pub struct Allocator {
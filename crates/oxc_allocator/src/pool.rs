@@ -0,0 +1,73 @@
+use std::{mem, ops::Deref, sync::Mutex};
+
+use crate::Allocator;
+
+/// Allocators shared by the threads which parse one file after the other, so that the memory of
+/// an arena is reused by the next file instead of being freed and allocated again.
+///
+/// The pool holds at most one allocator per thread which used it at the same time.
+#[derive(Default)]
+pub struct AllocatorPool {
+    allocators: Mutex<Vec<Allocator>>,
+}
+
+impl AllocatorPool {
+    /// An allocator of the pool, or a new one when all of them are in use. It is reset and given
+    /// back to the pool when the guard is dropped.
+    ///
+    /// # Panics
+    ///
+    /// When the lock is poisoned.
+    pub fn get(&self) -> AllocatorGuard<'_> {
+        let allocator = self.allocators.lock().unwrap().pop().unwrap_or_default();
+        AllocatorGuard { allocator, pool: self }
+    }
+}
+
+/// An allocator borrowed from an [`AllocatorPool`].
+pub struct AllocatorGuard<'pool> {
+    allocator: Allocator,
+    pool: &'pool AllocatorPool,
+}
+
+impl Deref for AllocatorGuard<'_> {
+    type Target = Allocator;
+
+    fn deref(&self) -> &Self::Target {
+        &self.allocator
+    }
+}
+
+impl Drop for AllocatorGuard<'_> {
+    fn drop(&mut self) {
+        // An empty arena does not allocate
+        let mut allocator = mem::take(&mut self.allocator);
+        // Keeps the largest chunk of the arena
+        allocator.reset();
+        if let Ok(mut allocators) = self.pool.allocators.lock() {
+            allocators.push(allocator);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AllocatorPool;
+
+    #[test]
+    fn test_reuse() {
+        let pool = AllocatorPool::default();
+        let first = pool.get();
+        first.alloc_str("a".repeat(4096).as_str());
+
+        // Allocators in use are not shared
+        let second = pool.get();
+        assert_eq!(second.allocated_bytes(), 0);
+        drop(second);
+        drop(first);
+
+        let reused = pool.get();
+        assert!(reused.allocated_bytes() >= 4096);
+        assert_eq!(reused.alloc_str("b"), "b");
+    }
+}
@@ -4,7 +4,7 @@
 use crate::ast::*;
 use oxc_span::{GetSpan, Span};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum AstType {
     BooleanLiteral,
     NullLiteral,
@@ -335,6 +335,175 @@ pub enum AstKind<'a> {
     ExpressionArrayElement(&'a Expression<'a>),
 }
 
+impl AstKind<'_> {
+    /// The type of the node, without its data.
+    pub fn ty(&self) -> AstType {
+        match self {
+            Self::BooleanLiteral(_) => AstType::BooleanLiteral,
+            Self::NullLiteral(_) => AstType::NullLiteral,
+            Self::NumericLiteral(_) => AstType::NumericLiteral,
+            Self::BigIntLiteral(_) => AstType::BigIntLiteral,
+            Self::RegExpLiteral(_) => AstType::RegExpLiteral,
+            Self::StringLiteral(_) => AstType::StringLiteral,
+            Self::Program(_) => AstType::Program,
+            Self::IdentifierName(_) => AstType::IdentifierName,
+            Self::IdentifierReference(_) => AstType::IdentifierReference,
+            Self::BindingIdentifier(_) => AstType::BindingIdentifier,
+            Self::LabelIdentifier(_) => AstType::LabelIdentifier,
+            Self::ThisExpression(_) => AstType::ThisExpression,
+            Self::ArrayExpression(_) => AstType::ArrayExpression,
+            Self::ArrayExpressionElement(_) => AstType::ArrayExpressionElement,
+            Self::Elision(_) => AstType::Elision,
+            Self::ObjectExpression(_) => AstType::ObjectExpression,
+            Self::ObjectProperty(_) => AstType::ObjectProperty,
+            Self::PropertyKey(_) => AstType::PropertyKey,
+            Self::TemplateLiteral(_) => AstType::TemplateLiteral,
+            Self::TaggedTemplateExpression(_) => AstType::TaggedTemplateExpression,
+            Self::MemberExpression(_) => AstType::MemberExpression,
+            Self::CallExpression(_) => AstType::CallExpression,
+            Self::NewExpression(_) => AstType::NewExpression,
+            Self::MetaProperty(_) => AstType::MetaProperty,
+            Self::SpreadElement(_) => AstType::SpreadElement,
+            Self::Argument(_) => AstType::Argument,
+            Self::UpdateExpression(_) => AstType::UpdateExpression,
+            Self::UnaryExpression(_) => AstType::UnaryExpression,
+            Self::BinaryExpression(_) => AstType::BinaryExpression,
+            Self::PrivateInExpression(_) => AstType::PrivateInExpression,
+            Self::LogicalExpression(_) => AstType::LogicalExpression,
+            Self::ConditionalExpression(_) => AstType::ConditionalExpression,
+            Self::AssignmentExpression(_) => AstType::AssignmentExpression,
+            Self::AssignmentTarget(_) => AstType::AssignmentTarget,
+            Self::SimpleAssignmentTarget(_) => AstType::SimpleAssignmentTarget,
+            Self::AssignmentTargetWithDefault(_) => AstType::AssignmentTargetWithDefault,
+            Self::SequenceExpression(_) => AstType::SequenceExpression,
+            Self::Super(_) => AstType::Super,
+            Self::AwaitExpression(_) => AstType::AwaitExpression,
+            Self::ChainExpression(_) => AstType::ChainExpression,
+            Self::ParenthesizedExpression(_) => AstType::ParenthesizedExpression,
+            Self::Directive(_) => AstType::Directive,
+            Self::Hashbang(_) => AstType::Hashbang,
+            Self::BlockStatement(_) => AstType::BlockStatement,
+            Self::VariableDeclaration(_) => AstType::VariableDeclaration,
+            Self::VariableDeclarator(_) => AstType::VariableDeclarator,
+            Self::UsingDeclaration(_) => AstType::UsingDeclaration,
+            Self::EmptyStatement(_) => AstType::EmptyStatement,
+            Self::ExpressionStatement(_) => AstType::ExpressionStatement,
+            Self::IfStatement(_) => AstType::IfStatement,
+            Self::DoWhileStatement(_) => AstType::DoWhileStatement,
+            Self::WhileStatement(_) => AstType::WhileStatement,
+            Self::ForStatement(_) => AstType::ForStatement,
+            Self::ForStatementInit(_) => AstType::ForStatementInit,
+            Self::ForInStatement(_) => AstType::ForInStatement,
+            Self::ForOfStatement(_) => AstType::ForOfStatement,
+            Self::ContinueStatement(_) => AstType::ContinueStatement,
+            Self::BreakStatement(_) => AstType::BreakStatement,
+            Self::ReturnStatement(_) => AstType::ReturnStatement,
+            Self::WithStatement(_) => AstType::WithStatement,
+            Self::SwitchStatement(_) => AstType::SwitchStatement,
+            Self::SwitchCase(_) => AstType::SwitchCase,
+            Self::LabeledStatement(_) => AstType::LabeledStatement,
+            Self::ThrowStatement(_) => AstType::ThrowStatement,
+            Self::TryStatement(_) => AstType::TryStatement,
+            Self::CatchClause(_) => AstType::CatchClause,
+            Self::CatchParameter(_) => AstType::CatchParameter,
+            Self::DebuggerStatement(_) => AstType::DebuggerStatement,
+            Self::AssignmentPattern(_) => AstType::AssignmentPattern,
+            Self::ObjectPattern(_) => AstType::ObjectPattern,
+            Self::ArrayPattern(_) => AstType::ArrayPattern,
+            Self::BindingRestElement(_) => AstType::BindingRestElement,
+            Self::Function(_) => AstType::Function,
+            Self::FormalParameters(_) => AstType::FormalParameters,
+            Self::FormalParameter(_) => AstType::FormalParameter,
+            Self::FunctionBody(_) => AstType::FunctionBody,
+            Self::ArrowFunctionExpression(_) => AstType::ArrowFunctionExpression,
+            Self::YieldExpression(_) => AstType::YieldExpression,
+            Self::Class(_) => AstType::Class,
+            Self::ClassBody(_) => AstType::ClassBody,
+            Self::MethodDefinition(_) => AstType::MethodDefinition,
+            Self::PropertyDefinition(_) => AstType::PropertyDefinition,
+            Self::PrivateIdentifier(_) => AstType::PrivateIdentifier,
+            Self::StaticBlock(_) => AstType::StaticBlock,
+            Self::ModuleDeclaration(_) => AstType::ModuleDeclaration,
+            Self::ImportExpression(_) => AstType::ImportExpression,
+            Self::ImportDeclaration(_) => AstType::ImportDeclaration,
+            Self::ImportSpecifier(_) => AstType::ImportSpecifier,
+            Self::ImportDefaultSpecifier(_) => AstType::ImportDefaultSpecifier,
+            Self::ImportNamespaceSpecifier(_) => AstType::ImportNamespaceSpecifier,
+            Self::ExportNamedDeclaration(_) => AstType::ExportNamedDeclaration,
+            Self::ExportDefaultDeclaration(_) => AstType::ExportDefaultDeclaration,
+            Self::ExportAllDeclaration(_) => AstType::ExportAllDeclaration,
+            Self::ExportSpecifier(_) => AstType::ExportSpecifier,
+            Self::TSThisParameter(_) => AstType::TSThisParameter,
+            Self::TSEnumDeclaration(_) => AstType::TSEnumDeclaration,
+            Self::TSEnumMember(_) => AstType::TSEnumMember,
+            Self::TSTypeAnnotation(_) => AstType::TSTypeAnnotation,
+            Self::TSLiteralType(_) => AstType::TSLiteralType,
+            Self::TSUnionType(_) => AstType::TSUnionType,
+            Self::TSIntersectionType(_) => AstType::TSIntersectionType,
+            Self::TSParenthesizedType(_) => AstType::TSParenthesizedType,
+            Self::TSIndexedAccessType(_) => AstType::TSIndexedAccessType,
+            Self::TSNamedTupleMember(_) => AstType::TSNamedTupleMember,
+            Self::TSAnyKeyword(_) => AstType::TSAnyKeyword,
+            Self::TSStringKeyword(_) => AstType::TSStringKeyword,
+            Self::TSBooleanKeyword(_) => AstType::TSBooleanKeyword,
+            Self::TSNumberKeyword(_) => AstType::TSNumberKeyword,
+            Self::TSNeverKeyword(_) => AstType::TSNeverKeyword,
+            Self::TSIntrinsicKeyword(_) => AstType::TSIntrinsicKeyword,
+            Self::TSUnknownKeyword(_) => AstType::TSUnknownKeyword,
+            Self::TSNullKeyword(_) => AstType::TSNullKeyword,
+            Self::TSUndefinedKeyword(_) => AstType::TSUndefinedKeyword,
+            Self::TSVoidKeyword(_) => AstType::TSVoidKeyword,
+            Self::TSSymbolKeyword(_) => AstType::TSSymbolKeyword,
+            Self::TSThisType(_) => AstType::TSThisType,
+            Self::TSObjectKeyword(_) => AstType::TSObjectKeyword,
+            Self::TSBigIntKeyword(_) => AstType::TSBigIntKeyword,
+            Self::TSTypeReference(_) => AstType::TSTypeReference,
+            Self::TSTypeName(_) => AstType::TSTypeName,
+            Self::TSQualifiedName(_) => AstType::TSQualifiedName,
+            Self::TSTypeParameterInstantiation(_) => AstType::TSTypeParameterInstantiation,
+            Self::TSTypeParameter(_) => AstType::TSTypeParameter,
+            Self::TSTypeParameterDeclaration(_) => AstType::TSTypeParameterDeclaration,
+            Self::TSTypeAliasDeclaration(_) => AstType::TSTypeAliasDeclaration,
+            Self::TSClassImplements(_) => AstType::TSClassImplements,
+            Self::TSInterfaceDeclaration(_) => AstType::TSInterfaceDeclaration,
+            Self::TSPropertySignature(_) => AstType::TSPropertySignature,
+            Self::TSMethodSignature(_) => AstType::TSMethodSignature,
+            Self::TSInterfaceHeritage(_) => AstType::TSInterfaceHeritage,
+            Self::TSModuleDeclaration(_) => AstType::TSModuleDeclaration,
+            Self::TSModuleBlock(_) => AstType::TSModuleBlock,
+            Self::TSTypeLiteral(_) => AstType::TSTypeLiteral,
+            Self::TSInferType(_) => AstType::TSInferType,
+            Self::TSTypeQuery(_) => AstType::TSTypeQuery,
+            Self::TSImportType(_) => AstType::TSImportType,
+            Self::TSTemplateLiteralType(_) => AstType::TSTemplateLiteralType,
+            Self::TSAsExpression(_) => AstType::TSAsExpression,
+            Self::TSSatisfiesExpression(_) => AstType::TSSatisfiesExpression,
+            Self::TSTypeAssertion(_) => AstType::TSTypeAssertion,
+            Self::TSImportEqualsDeclaration(_) => AstType::TSImportEqualsDeclaration,
+            Self::TSExternalModuleReference(_) => AstType::TSExternalModuleReference,
+            Self::TSNonNullExpression(_) => AstType::TSNonNullExpression,
+            Self::Decorator(_) => AstType::Decorator,
+            Self::TSInstantiationExpression(_) => AstType::TSInstantiationExpression,
+            Self::JSXElement(_) => AstType::JSXElement,
+            Self::JSXOpeningElement(_) => AstType::JSXOpeningElement,
+            Self::JSXClosingElement(_) => AstType::JSXClosingElement,
+            Self::JSXFragment(_) => AstType::JSXFragment,
+            Self::JSXElementName(_) => AstType::JSXElementName,
+            Self::JSXNamespacedName(_) => AstType::JSXNamespacedName,
+            Self::JSXMemberExpression(_) => AstType::JSXMemberExpression,
+            Self::JSXMemberExpressionObject(_) => AstType::JSXMemberExpressionObject,
+            Self::JSXExpressionContainer(_) => AstType::JSXExpressionContainer,
+            Self::JSXAttributeItem(_) => AstType::JSXAttributeItem,
+            Self::JSXSpreadAttribute(_) => AstType::JSXSpreadAttribute,
+            Self::JSXIdentifier(_) => AstType::JSXIdentifier,
+            Self::JSXText(_) => AstType::JSXText,
+            Self::FinallyClause(_) => AstType::FinallyClause,
+            Self::ClassHeritage(_) => AstType::ClassHeritage,
+            Self::ExpressionArrayElement(_) => AstType::ExpressionArrayElement,
+        }
+    }
+}
+
 impl<'a> GetSpan for AstKind<'a> {
     #[allow(clippy::match_same_arms)]
     fn span(&self) -> Span {
@@ -0,0 +1,83 @@
+use petgraph::{visit::EdgeRef, Direction};
+use rustc_hash::FxHashSet;
+
+use crate::{BasicBlock, BasicBlockId, ControlFlowGraph, EdgeType};
+
+/// The part of a [`ControlFlowGraph`] which belongs to a single function, or to the program
+/// outside of any function.
+///
+/// The basic blocks of nested functions are not part of it, as their `NewFunction` edges are not
+/// followed.
+#[derive(Debug, Clone, Copy)]
+pub struct FunctionCfg<'a> {
+    cfg: &'a ControlFlowGraph,
+    entry: BasicBlockId,
+}
+
+impl<'a> FunctionCfg<'a> {
+    /// The function which starts at the basic block `entry`, which is the basic block of the
+    /// function node, or of the program node.
+    pub fn new(cfg: &'a ControlFlowGraph, entry: BasicBlockId) -> Self {
+        Self { cfg, entry }
+    }
+
+    pub fn cfg(&self) -> &'a ControlFlowGraph {
+        self.cfg
+    }
+
+    pub fn entry(&self) -> BasicBlockId {
+        self.entry
+    }
+
+    pub fn basic_block(&self, id: BasicBlockId) -> &'a BasicBlock {
+        self.cfg.basic_block(id)
+    }
+
+    /// The basic blocks of the function in depth first order, starting at its entry.
+    pub fn basic_blocks(&self) -> Vec<BasicBlockId> {
+        let mut visited = FxHashSet::default();
+        let mut blocks = vec![];
+        let mut stack = vec![self.entry];
+        while let Some(block) = stack.pop() {
+            if !visited.insert(block) {
+                continue;
+            }
+            blocks.push(block);
+            let successors = self.successors(block).map(|(successor, _)| successor);
+            stack.extend(successors.collect::<Vec<_>>().into_iter().rev());
+        }
+        blocks
+    }
+
+    /// The basic blocks which `block` leads to, with the kind of their edge.
+    pub fn successors(
+        &self,
+        block: BasicBlockId,
+    ) -> impl Iterator<Item = (BasicBlockId, EdgeType)> + 'a {
+        self.cfg
+            .graph
+            .edges_directed(block, Direction::Outgoing)
+            .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction))
+            .map(|edge| (edge.target(), *edge.weight()))
+    }
+
+    /// The basic blocks which lead to `block`, with the kind of their edge.
+    pub fn predecessors(
+        &self,
+        block: BasicBlockId,
+    ) -> impl Iterator<Item = (BasicBlockId, EdgeType)> + 'a {
+        self.cfg
+            .graph
+            .edges_directed(block, Direction::Incoming)
+            .filter(|edge| !matches!(edge.weight(), EdgeType::NewFunction))
+            .map(|edge| (edge.source(), *edge.weight()))
+    }
+
+    /// Whether `block` can run when the function is called, i.e. it is not after a `return`,
+    /// `throw`, `break` or `continue` on all paths from the entry of the function.
+    ///
+    /// Conditions are not evaluated, so the code after `while (true) {}` is reachable.
+    pub fn is_reachable(&self, block: BasicBlockId) -> bool {
+        !self.cfg.basic_block(block).unreachable && self.cfg.is_reachable(self.entry, block)
+    }
+}
@@ -1,5 +1,6 @@
 mod builder;
 mod dot;
+mod function;
 pub mod visit;
 
 use itertools::Itertools;
@@ -21,6 +22,7 @@ pub mod graph {
 
 pub use builder::{ControlFlowGraphBuilder, CtxCursor, CtxFlags};
 pub use dot::DisplayDot;
+pub use function::FunctionCfg;
 use visit::set_depth_first_search;
 
 pub type BasicBlockId = NodeIndex;
@@ -82,7 +84,7 @@ pub enum IterationInstructionKind {
     In,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EdgeType {
     /// Conditional jumps
     Jump,
@@ -104,7 +106,7 @@ pub enum EdgeType {
     Join,
 }
 
-#[derive(Default, Debug, Clone, Copy)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ErrorEdgeKind {
     /// Error kind for edges between a block which can throw, to it's respective catch block.
     Explicit,
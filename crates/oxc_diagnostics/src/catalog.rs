@@ -0,0 +1,198 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use crate::{reporter::is_rule_id, DiagnosticWithSource, Error, OxcDiagnostic};
+
+/// Translations of the messages, the help and the labels of diagnostics, read from a JSON file:
+///
+/// ```json
+/// {
+///   "messages": {
+///     "`debugger` statement is not allowed": "Die `debugger`-Anweisung ist nicht erlaubt",
+///     "'{name}' is not defined.": "'{name}' ist nicht definiert."
+///   }
+/// }
+/// ```
+///
+/// The English texts are keyed without the rule of the message, e.g. `eslint(no-debugger): `,
+/// which is kept as is. `{name}` placeholders match any text, which is inserted into the
+/// translation. The texts without a translation are left in English.
+#[derive(Debug, Default, Clone)]
+pub struct MessageCatalog {
+    messages: HashMap<String, String>,
+    /// The messages with placeholders
+    templates: Vec<Template>,
+}
+
+#[derive(Debug, Clone)]
+struct Template {
+    parts: Vec<TemplatePart>,
+    translation: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TemplatePart {
+    Text(String),
+    Placeholder(String),
+}
+
+impl MessageCatalog {
+    /// Read the catalog file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when the file cannot be read or is not a valid catalog.
+    pub fn read(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let json = fs::read_to_string(path).map_err(|err| {
+            OxcDiagnostic::error(format!(
+                "Failed to read the message catalog {} with error \"{err}\"",
+                path.display()
+            ))
+        })?;
+        Self::parse(&json).map_err(|err| {
+            OxcDiagnostic::error(format!(
+                "Failed to parse the message catalog {}: {}",
+                path.display(),
+                err.message
+            ))
+        })
+    }
+
+    /// Parse a catalog, see [`MessageCatalog`] for the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` when `json` is not a valid catalog.
+    pub fn parse(json: &str) -> Result<Self, OxcDiagnostic> {
+        let value = serde_json::from_str::<serde_json::Value>(json)
+            .map_err(|err| OxcDiagnostic::error(err.to_string()))?;
+        let Some(messages) = value.get("messages").and_then(serde_json::Value::as_object) else {
+            return Err(OxcDiagnostic::error("`messages` must be an object"));
+        };
+        let mut catalog = Self::default();
+        for (message, translation) in messages {
+            let Some(translation) = translation.as_str() else {
+                return Err(OxcDiagnostic::error(format!(
+                    "The translation of \"{message}\" must be a string"
+                )));
+            };
+            let parts = parse_template(message);
+            if parts.iter().any(|part| matches!(part, TemplatePart::Placeholder(_))) {
+                catalog.templates.push(Template { parts, translation: translation.to_string() });
+            } else {
+                catalog.messages.insert(message.clone(), translation.to_string());
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// The translation of `text`, `None` when the catalog does not translate it.
+    pub fn translate(&self, text: &str) -> Option<String> {
+        if let Some(translation) = self.messages.get(text) {
+            return Some(translation.clone());
+        }
+        self.templates.iter().find_map(|template| template.translate(text))
+    }
+
+    /// Translate the message, the help and the labels of `error`.
+    pub(crate) fn translate_error(&self, mut error: Error) -> Error {
+        if let Some(diagnostic) = error.downcast_mut::<DiagnosticWithSource>() {
+            self.translate_diagnostic(&mut diagnostic.diagnostic);
+        } else if let Some(diagnostic) = error.downcast_mut::<OxcDiagnostic>() {
+            self.translate_diagnostic(diagnostic);
+        }
+        error
+    }
+
+    fn translate_diagnostic(&self, diagnostic: &mut OxcDiagnostic) {
+        let inner = &mut diagnostic.inner;
+        // The rule of the message, e.g. `eslint(no-debugger): `, is not translated
+        let (rule, message) = match inner.message.split_once(": ") {
+            Some((rule, message)) if is_rule_id(rule) => (Some(rule), message),
+            _ => (None, inner.message.as_str()),
+        };
+        if let Some(translation) = self.translate(message) {
+            inner.message = match rule {
+                Some(rule) => format!("{rule}: {translation}"),
+                None => translation,
+            };
+        }
+        // The help may be followed by other lines, e.g. the explanation of `--explain`
+        if let Some(help) = &inner.help {
+            inner.help = Some(
+                help.lines()
+                    .map(|line| self.translate(line).unwrap_or_else(|| line.to_string()))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            );
+        }
+        for label in inner.labels.iter_mut().flatten() {
+            if let Some(translation) = label.label().and_then(|text| self.translate(text)) {
+                label.set_label(Some(translation));
+            }
+        }
+    }
+}
+
+impl Template {
+    /// The translation of `text` if it matches the template, with the text matched by each
+    /// placeholder. A placeholder matches the shortest text followed by the rest of the template.
+    fn translate(&self, text: &str) -> Option<String> {
+        let mut rest = text;
+        let mut values = vec![];
+        let mut parts = self.parts.iter().peekable();
+        while let Some(part) = parts.next() {
+            match part {
+                TemplatePart::Text(s) => rest = rest.strip_prefix(s.as_str())?,
+                TemplatePart::Placeholder(name) => {
+                    let end = match parts.peek() {
+                        Some(TemplatePart::Text(next)) => rest.find(next.as_str())?,
+                        _ => rest.len(),
+                    };
+                    if end == 0 {
+                        return None;
+                    }
+                    values.push((name, &rest[..end]));
+                    rest = &rest[end..];
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return None;
+        }
+        let mut translation = self.translation.clone();
+        for (name, value) in values {
+            translation = translation.replace(&format!("{{{name}}}"), value);
+        }
+        Some(translation)
+    }
+}
+
+/// Split `message` into text and `{name}` placeholders, where `name` is made of ASCII letters,
+/// digits and underscores. Other braces, e.g. of code in the message, are text.
+fn parse_template(message: &str) -> Vec<TemplatePart> {
+    let mut parts = vec![];
+    let mut text = String::new();
+    let mut rest = message;
+    while let Some(start) = rest.find('{') {
+        let placeholder = rest[start + 1..].find('}').map(|end| &rest[start + 1..=start + end]);
+        let placeholder = placeholder.filter(|name| {
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+        if let Some(name) = placeholder {
+            text.push_str(&rest[..start]);
+            if !text.is_empty() {
+                parts.push(TemplatePart::Text(std::mem::take(&mut text)));
+            }
+            parts.push(TemplatePart::Placeholder(name.to_string()));
+            rest = &rest[start + name.len() + 2..];
+        } else {
+            text.push_str(&rest[..=start]);
+            rest = &rest[start + 1..];
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        parts.push(TemplatePart::Text(text));
+    }
+    parts
+}
@@ -408,7 +408,8 @@ impl GraphicalReportHandler {
                 .read_span(right.inner(), self.context_lines, self.context_lines)
                 .map_err(|e| {
                     println!("{:#?}", e);
-                    fmt::Error })?;
+                    fmt::Error
+                })?;
 
             if contexts.is_empty() {
                 contexts.push((right, right_conts));
@@ -1,20 +1,32 @@
 //! Diagnostics Wrapper
 //! Exports `miette`
 
+mod catalog;
 mod graphic_reporter;
 mod graphical_theme;
 mod reporter;
 mod service;
+mod stats;
 
 use std::{
+    env,
     fmt::{self, Display},
+    fs,
     ops::Deref,
+    path::PathBuf,
+    sync::Arc,
 };
 
 pub use crate::{
+    catalog::MessageCatalog,
     graphic_reporter::GraphicalReportHandler,
     graphical_theme::GraphicalTheme,
-    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+    reporter::{eslint_rule_id, render_json_diagnostic},
+    service::{
+        diagnostic_channel, DiagnosticReceiver, DiagnosticSender, DiagnosticService,
+        DiagnosticTuple,
+    },
+    stats::{DiagnosticStats, RuleStats},
 };
 
 pub type Error = miette::Error;
@@ -46,6 +58,30 @@ pub struct OxcDiagnosticInner {
     pub labels: Option<Vec<LabeledSpan>>,
     pub help: Option<String>,
     pub severity: Severity,
+    pub fix: Option<DiagnosticFix>,
+    /// Labels in other files, see [`OxcDiagnostic::and_related_label`]
+    pub related: Vec<RelatedLabel>,
+    /// The id of the weakness of the Common Weakness Enumeration the problem is an instance of,
+    /// e.g. 95 for <https://cwe.mitre.org/data/definitions/95.html>
+    pub cwe: Option<u32>,
+}
+
+/// A label in another file than the one of the diagnostic, e.g. the declaration of an imported
+/// binding or the next import of an import cycle.
+#[derive(Debug, Clone)]
+pub struct RelatedLabel {
+    /// The path of the file, read when the diagnostic is reported
+    pub path: PathBuf,
+    pub label: LabeledSpan,
+}
+
+/// A fix of a diagnostic, which replaces the text between the byte offsets `start` and `end` of
+/// the source text with `content`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiagnosticFix {
+    pub start: u32,
+    pub end: u32,
+    pub content: String,
 }
 
 impl fmt::Display for OxcDiagnostic {
@@ -83,6 +119,9 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Error,
+                fix: None,
+                related: vec![],
+                cwe: None,
             }),
         }
     }
@@ -95,6 +134,9 @@ impl OxcDiagnostic {
                 labels: None,
                 help: None,
                 severity: Severity::Warning,
+                fix: None,
+                related: vec![],
+                cwe: None,
             }),
         }
     }
@@ -111,6 +153,18 @@ impl OxcDiagnostic {
         self
     }
 
+    #[must_use]
+    pub fn with_fix(mut self, fix: DiagnosticFix) -> Self {
+        self.inner.fix = Some(fix);
+        self
+    }
+
+    #[must_use]
+    pub fn with_cwe(mut self, cwe: u32) -> Self {
+        self.inner.cwe = Some(cwe);
+        self
+    }
+
     #[must_use]
     pub fn with_label<T: Into<LabeledSpan>>(mut self, label: T) -> Self {
         self.inner.labels = Some(vec![label.into()]);
@@ -145,8 +199,116 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add a label in the file at `path`, which is not the file of the diagnostic. Reporters
+    /// render it as related information, with the source text of the file read when reporting.
+    #[must_use]
+    pub fn and_related_label<P: Into<PathBuf>, T: Into<LabeledSpan>>(
+        mut self,
+        path: P,
+        label: T,
+    ) -> Self {
+        self.inner.related.push(RelatedLabel { path: path.into(), label: label.into() });
+        self
+    }
+
     #[must_use]
     pub fn with_source_code<T: SourceCode + Send + Sync + 'static>(self, code: T) -> Error {
         Error::from(self).with_source_code(code)
     }
 }
+
+/// An [`OxcDiagnostic`] with the source code of its labels, which reporters can downcast an
+/// [`Error`] to, e.g. to read the fix of the diagnostic.
+#[derive(Debug)]
+pub(crate) struct DiagnosticWithSource {
+    pub diagnostic: OxcDiagnostic,
+    pub source: Arc<NamedSource<String>>,
+    /// The [`OxcDiagnosticInner::related`] labels with the source text of their files
+    pub related: Vec<RelatedDiagnostic>,
+}
+
+impl DiagnosticWithSource {
+    /// Read the files of the related labels of `diagnostic`. The labels of the files which
+    /// cannot be read are dropped.
+    pub fn new(diagnostic: OxcDiagnostic, source: Arc<NamedSource<String>>) -> Self {
+        let cwd = env::current_dir().unwrap_or_default();
+        let related = diagnostic
+            .related
+            .iter()
+            .filter_map(|RelatedLabel { path, label }| {
+                let source_text = fs::read_to_string(path).ok()?;
+                let name = path.strip_prefix(&cwd).unwrap_or(path).to_string_lossy();
+                Some(RelatedDiagnostic {
+                    label: label.clone(),
+                    source: NamedSource::new(name, source_text),
+                })
+            })
+            .collect();
+        Self { diagnostic, source, related }
+    }
+}
+
+/// A [`RelatedLabel`] with the source text of its file, rendered by miette as a related
+/// diagnostic whose message is the text of the label.
+#[derive(Debug)]
+pub(crate) struct RelatedDiagnostic {
+    pub label: LabeledSpan,
+    pub source: NamedSource<String>,
+}
+
+impl fmt::Display for RelatedDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label.label().unwrap_or("related location"))
+    }
+}
+
+impl std::error::Error for RelatedDiagnostic {}
+
+impl Diagnostic for RelatedDiagnostic {
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Advice)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        // The text of the label is the message
+        let label = LabeledSpan::new_with_span(None, *self.label.inner());
+        Some(Box::new(std::iter::once(label)))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for DiagnosticWithSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
+        self.diagnostic.fmt(f)
+    }
+}
+
+impl std::error::Error for DiagnosticWithSource {}
+
+impl Diagnostic for DiagnosticWithSource {
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.diagnostic.help()
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.diagnostic.severity()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.diagnostic.labels()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(self.source.as_ref())
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.related.is_empty() {
+            return None;
+        }
+        Some(Box::new(self.related.iter().map(|related| related as &dyn Diagnostic)))
+    }
+}
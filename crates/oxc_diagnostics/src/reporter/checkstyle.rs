@@ -1,6 +1,6 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{borrow::Cow, collections::BTreeMap};
 
-use super::{DiagnosticReporter, Info};
+use super::{eslint_rule_id, DiagnosticReporter, Info};
 use crate::{Error, Severity};
 
 #[derive(Default)]
@@ -24,11 +24,11 @@ impl DiagnosticReporter for CheckstyleReporter {
 #[allow(clippy::print_stdout)]
 fn format_checkstyle(diagnostics: &[Error]) {
     let infos = diagnostics.iter().map(Info::new).collect::<Vec<_>>();
-    let mut grouped: HashMap<String, Vec<Info>> = HashMap::new();
+    let mut grouped: BTreeMap<String, Vec<Info>> = BTreeMap::new();
     for info in infos {
         grouped.entry(info.filename.clone()).or_default().push(info);
     }
-    let messages = grouped.into_values().map(|infos| {
+    let messages = grouped.into_values().fold(String::new(), |mut files, infos| {
          let messages = infos
              .iter()
              .fold(String::new(), |mut acc, info| {
@@ -37,22 +37,25 @@ fn format_checkstyle(diagnostics: &[Error]) {
                      Severity::Error => "error",
                      _ => "warning",
                  };
-                 let message = rule_id.as_ref().map_or_else(|| xml_escape(message), |rule_id| Cow::Owned(format!("{} ({rule_id})", xml_escape(message))));
-                 let source = rule_id.as_ref().map_or_else(|| Cow::Borrowed(""), |rule_id| Cow::Owned(format!("eslint.rules.{rule_id}")));
+                 let rule_id = rule_id.as_deref().map(eslint_rule_id);
+                 let message = rule_id.as_ref().map_or_else(|| xml_escape(message), |rule_id| Cow::Owned(format!("{} ({})", xml_escape(message), xml_escape(rule_id))));
+                 let source = rule_id.as_ref().map_or_else(|| Cow::Borrowed(""), |rule_id| Cow::Owned(format!("eslint.rules.{}", xml_escape(rule_id))));
                  let line = format!(r#"<error line="{line}" column="{column}" severity="{severity}" message="{message}" source="{source}" />"#);
                  acc.push_str(&line);
                  acc
              });
-         let filename = &infos[0].filename;
-         format!(r#"<file name="{filename}">{messages}</file>"#)
-     }).collect::<Vec<_>>().join(" ");
+         let filename = xml_escape(&infos[0].filename);
+         let file = format!(r#"<file name="{filename}">{messages}</file>"#);
+         files.push_str(&file);
+         files
+     });
     println!(
         r#"<?xml version="1.0" encoding="utf-8"?><checkstyle version="4.3">{messages}</checkstyle>"#
     );
 }
 
 /// <https://github.com/tafia/quick-xml/blob/6e34a730853fe295d68dc28460153f08a5a12955/src/escapei.rs#L84-L86>
-fn xml_escape(raw: &str) -> Cow<str> {
+pub(super) fn xml_escape(raw: &str) -> Cow<str> {
     xml_escape_impl(raw, |ch| matches!(ch, b'<' | b'>' | b'&' | b'\'' | b'\"'))
 }
 
@@ -1,18 +1,19 @@
 use miette::JSONReportHandler;
 
-use super::DiagnosticReporter;
-use crate::Error;
+use super::{DiagnosticReporter, Info};
+use crate::{DiagnosticStats, DiagnosticWithSource, Error};
 
 #[derive(Default)]
 pub struct JsonReporter {
     diagnostics: Vec<Error>,
+    stats: Option<serde_json::Value>,
 }
 
 impl DiagnosticReporter for JsonReporter {
     // NOTE: this output does not conform to eslint json format yet
     // https://eslint.org/docs/latest/use/formatters/#json
     fn finish(&mut self) {
-        format_json(&mut self.diagnostics);
+        format_json(&mut self.diagnostics, self.stats.take());
     }
 
     fn render_diagnostics(&mut self, _s: &[u8]) {}
@@ -21,20 +22,56 @@ impl DiagnosticReporter for JsonReporter {
         self.diagnostics.push(error);
         None
     }
+
+    fn render_stats(&mut self, stats: &DiagnosticStats) {
+        self.stats = Some(stats.to_json());
+    }
 }
 
 /// <https://github.com/fregante/eslint-formatters/tree/main/packages/eslint-formatter-json>
+///
+/// With `stats`, the array of diagnostics is wrapped in an object with the statistics:
+/// `{ "diagnostics": [...], "stats": {...} }`.
 #[allow(clippy::print_stdout)]
-fn format_json(diagnostics: &mut Vec<Error>) {
-    let handler = JSONReportHandler::new();
+fn format_json(diagnostics: &mut Vec<Error>, stats: Option<serde_json::Value>) {
     let messages = diagnostics
         .drain(..)
-        .map(|error| {
-            let mut output = String::from("\t");
-            handler.render_report(&mut output, error.as_ref()).unwrap();
-            output
-        })
+        .map(|error| format!("\t{}", render_json_diagnostic(&error)))
         .collect::<Vec<_>>()
         .join(",\n");
-    println!("[\n{messages}\n]");
+    match stats {
+        Some(stats) => {
+            println!("{{\"diagnostics\": [\n{messages}\n], \"stats\": {stats}}}");
+        }
+        None => println!("[\n{messages}\n]"),
+    }
+}
+
+/// Render `error` as the JSON object of the `json` output format: the object rendered by miette,
+/// with the message, the labels and the file name, and the rule and the fix of the diagnostic.
+/// The diagnostics of the security preset have the CWE id of their weakness too, e.g. `"CWE-95"`.
+///
+/// # Panics
+///
+/// When miette fails to render the diagnostic.
+pub fn render_json_diagnostic(error: &Error) -> String {
+    let handler = JSONReportHandler::new();
+    let mut output = String::new();
+    handler.render_report(&mut output, error.as_ref()).unwrap();
+    // Append the rule, the fix and the CWE id to the object rendered by miette
+    let rule_id = serde_json::to_string(&Info::new(error).rule_id).unwrap();
+    let diagnostic = error.downcast_ref::<DiagnosticWithSource>().map(|error| &error.diagnostic);
+    let fix = diagnostic.and_then(|diagnostic| diagnostic.fix.as_ref()).map_or_else(
+        || "null".to_string(),
+        |fix| {
+            let content = serde_json::to_string(&fix.content).unwrap();
+            format!(r#"{{"start": {},"end": {},"content": {content}}}"#, fix.start, fix.end)
+        },
+    );
+    let cwe = diagnostic
+        .and_then(|diagnostic| diagnostic.cwe)
+        .map(|cwe| format!(r#","cwe": "CWE-{cwe}""#))
+        .unwrap_or_default();
+    output.insert_str(output.len() - 1, &format!(r#","rule_id": {rule_id},"fix": {fix}{cwe}"#));
+    output
 }
@@ -0,0 +1,61 @@
+use std::{collections::BTreeMap, fmt::Write};
+
+use super::{checkstyle::xml_escape, DiagnosticReporter, Info};
+use crate::{Error, Severity};
+
+#[derive(Default)]
+pub struct JunitReporter {
+    diagnostics: Vec<Error>,
+}
+
+impl DiagnosticReporter for JunitReporter {
+    fn finish(&mut self) {
+        format_junit(&self.diagnostics);
+    }
+
+    fn render_diagnostics(&mut self, _s: &[u8]) {}
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        self.diagnostics.push(error);
+        None
+    }
+}
+
+/// Each file is a test suite and each diagnostic a failed test case, named after its rule.
+/// <https://github.com/eslint/eslint/blob/v8.57.0/lib/cli-engine/formatters/junit.js>
+#[allow(clippy::print_stdout)]
+fn format_junit(diagnostics: &[Error]) {
+    let mut grouped: BTreeMap<String, Vec<Info>> = BTreeMap::new();
+    for info in diagnostics.iter().map(Info::new) {
+        grouped.entry(info.filename.clone()).or_default().push(info);
+    }
+    let mut output = String::from(r#"<?xml version="1.0" encoding="utf-8"?>"#);
+    output.push_str("\n<testsuites>\n");
+    for (filename, infos) in &grouped {
+        let filename = xml_escape(filename);
+        let errors = infos.iter().filter(|info| info.severity == Severity::Error).count();
+        writeln!(
+            output,
+            r#"<testsuite package="org.oxlint" time="0" tests="{}" errors="{errors}" name="{filename}">"#,
+            infos.len()
+        )
+        .unwrap();
+        for Info { line, column, message, severity, rule_id, .. } in infos {
+            let severity = match severity {
+                Severity::Error => "error",
+                _ => "warning",
+            };
+            let rule_id = rule_id.as_deref().unwrap_or_default();
+            let name = xml_escape(rule_id);
+            let message = xml_escape(message);
+            writeln!(
+                output,
+                r#"<testcase time="0" name="org.oxlint.{name}" classname="{filename}"><failure type="{severity}" message="{message}">line {line}, col {column}, {severity} - {message} ({name})</failure></testcase>"#
+            )
+            .unwrap();
+        }
+        output.push_str("</testsuite>\n");
+    }
+    output.push_str("</testsuites>");
+    println!("{output}");
+}
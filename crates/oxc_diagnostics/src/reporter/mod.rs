@@ -2,15 +2,22 @@ mod checkstyle;
 mod github;
 mod graphical;
 mod json;
+mod junit;
+mod stylish;
 mod unix;
 
 use std::io::{BufWriter, Stdout};
 
 pub use self::{
-    checkstyle::CheckstyleReporter, github::GithubReporter, graphical::GraphicalReporter,
-    json::JsonReporter, unix::UnixReporter,
+    checkstyle::CheckstyleReporter,
+    github::GithubReporter,
+    graphical::GraphicalReporter,
+    json::{render_json_diagnostic, JsonReporter},
+    junit::JunitReporter,
+    stylish::StylishReporter,
+    unix::UnixReporter,
 };
-use crate::{Error, Severity};
+use crate::{DiagnosticStats, Error, Severity};
 
 /// stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
 /// See `https://github.com/rust-lang/rust/issues/60673`.
@@ -22,47 +29,99 @@ pub trait DiagnosticReporter {
     fn finish(&mut self);
     fn render_diagnostics(&mut self, s: &[u8]);
     fn render_error(&mut self, error: Error) -> Option<String>;
+
+    /// Include the statistics of the run in the output, before [`DiagnosticReporter::finish`].
+    /// The statistics are only part of the formats with room for them.
+    fn render_stats(&mut self, _stats: &DiagnosticStats) {}
 }
 
-struct Info {
+pub(crate) struct Info {
     line: usize,
     column: usize,
     filename: String,
     message: String,
     severity: Severity,
-    rule_id: Option<String>,
+    pub(crate) rule_id: Option<String>,
 }
 
 impl Info {
-    fn new(diagnostic: &Error) -> Self {
+    pub(crate) fn new(diagnostic: &Error) -> Self {
         let mut line = 0;
         let mut column = 0;
         let mut filename = String::new();
-        let mut message = String::new();
-        let mut severity = Severity::Warning;
-        let mut rule_id = None;
+        let mut labelled = false;
         if let Some(mut labels) = diagnostic.labels() {
             if let Some(source) = diagnostic.source_code() {
                 if let Some(label) = labels.next() {
                     if let Ok(span_content) = source.read_span(label.inner(), 0, 0) {
+                        labelled = true;
                         line = span_content.line() + 1;
                         column = span_content.column() + 1;
                         if let Some(name) = span_content.name() {
                             filename = name.to_string();
-                        };
-                        if matches!(diagnostic.severity(), Some(Severity::Error)) {
-                            severity = Severity::Error;
                         }
-                        let msg = diagnostic.to_string();
-                        // Our messages usually comes with `eslint(rule): message`
-                        (rule_id, message) = msg.split_once(':').map_or_else(
-                            || (None, msg.to_string()),
-                            |(id, msg)| (Some(id.to_string()), msg.trim().to_string()),
-                        );
                     }
                 }
             }
         }
+        let severity = match diagnostic.severity() {
+            Some(Severity::Error) => Severity::Error,
+            _ => Severity::Warning,
+        };
+        let msg = diagnostic.to_string();
+        // Our messages usually comes with `eslint(rule): message`
+        let (rule_id, message) = msg
+            .split_once(':')
+            // Diagnostics without labels, e.g. the failure to read a file, are rarely reported
+            // by a rule
+            .filter(|(id, _)| labelled || is_rule_id(id))
+            .map_or_else(
+                || (None, msg.clone()),
+                |(id, msg)| (Some(id.to_string()), msg.trim().to_string()),
+            );
+        let related = related_locations(diagnostic);
+        let message = if related.is_empty() {
+            message
+        } else {
+            format!("{message} ({})", related.join(", "))
+        };
         Self { line, column, filename, message, severity, rule_id }
     }
 }
+
+/// The labels of the related diagnostics, in other files, with their location, e.g.
+/// `import cycle continues here at src/b.js:1:1`, for the reporters printing a line per problem.
+fn related_locations(diagnostic: &Error) -> Vec<String> {
+    let Some(related) = diagnostic.related() else { return vec![] };
+    related
+        .filter_map(|related| {
+            let source = related.source_code()?;
+            let label = related.labels()?.next()?;
+            let span_content = source.read_span(label.inner(), 0, 0).ok()?;
+            let name = span_content.name().unwrap_or_default();
+            let line = span_content.line() + 1;
+            let column = span_content.column() + 1;
+            Some(format!("{related} at {name}:{line}:{column}"))
+        })
+        .collect()
+}
+
+/// Whether `id` looks like the id of a rule, e.g. `eslint(no-debugger)`.
+pub(crate) fn is_rule_id(id: &str) -> bool {
+    id.strip_suffix(')').and_then(|id| id.split_once('(')).is_some_and(|(plugin, rule)| {
+        !plugin.is_empty() && !rule.is_empty() && !id.contains(char::is_whitespace)
+    })
+}
+
+/// The ESLint id of a rule, e.g. `no-debugger` for `eslint(no-debugger)` and `unicorn/no-null` for
+/// `eslint-plugin-unicorn(no-null)`, as tools consuming the output of ESLint formatters expect.
+pub fn eslint_rule_id(rule_id: &str) -> String {
+    let Some((plugin, rule)) = rule_id.strip_suffix(')').and_then(|id| id.split_once('(')) else {
+        return rule_id.to_string();
+    };
+    match plugin {
+        "eslint" => rule.to_string(),
+        "typescript-eslint" => format!("@typescript-eslint/{rule}"),
+        _ => format!("{}/{rule}", plugin.strip_prefix("eslint-plugin-").unwrap_or(plugin)),
+    }
+}
@@ -0,0 +1,120 @@
+use std::{
+    fmt::Write as _,
+    io::{BufWriter, Stdout, Write},
+};
+
+use owo_colors::OwoColorize;
+
+use super::{eslint_rule_id, writer, DiagnosticReporter, Info};
+use crate::{
+    graphical_theme::{GraphicalTheme, ThemeStyles},
+    Error, Severity,
+};
+
+/// The diagnostics of each file under its name, one per line with aligned columns, followed by
+/// the number of problems, like the default `stylish` formatter of ESLint.
+///
+/// ```text
+/// src/index.js
+///   1:1   warning  `debugger` statement is not allowed  no-debugger
+///   3:10  error    Unexpected var, use let or const     no-var
+///
+/// ✖ 2 problems (1 error, 1 warning)
+/// ```
+pub struct StylishReporter {
+    writer: BufWriter<Stdout>,
+    styles: ThemeStyles,
+    /// Diagnostics of the file being reported
+    infos: Vec<Info>,
+    errors: usize,
+    warnings: usize,
+}
+
+impl Default for StylishReporter {
+    fn default() -> Self {
+        Self {
+            writer: writer(),
+            styles: GraphicalTheme::default().styles,
+            infos: vec![],
+            errors: 0,
+            warnings: 0,
+        }
+    }
+}
+
+impl DiagnosticReporter for StylishReporter {
+    fn finish(&mut self) {
+        let total = self.errors + self.warnings;
+        if total > 0 {
+            let plural = |count: usize, word: &str| {
+                format!("{count} {word}{}", if count == 1 { "" } else { "s" })
+            };
+            let summary = format!(
+                "\u{2716} {} ({}, {})",
+                plural(total, "problem"),
+                plural(self.errors, "error"),
+                plural(self.warnings, "warning")
+            );
+            let style = if self.errors > 0 { self.styles.error } else { self.styles.warning };
+            writeln!(self.writer, "\n{}", summary.style(style)).unwrap();
+        }
+        self.writer.flush().unwrap();
+    }
+
+    /// Called after the diagnostics of each file.
+    fn render_diagnostics(&mut self, _s: &[u8]) {
+        if self.infos.is_empty() {
+            return;
+        }
+        let infos = std::mem::take(&mut self.infos);
+        let output = format_stylish(&infos, &self.styles);
+        self.writer.write_all(output.as_bytes()).unwrap();
+    }
+
+    fn render_error(&mut self, error: Error) -> Option<String> {
+        let info = Info::new(&error);
+        match info.severity {
+            Severity::Error => self.errors += 1,
+            _ => self.warnings += 1,
+        }
+        self.infos.push(info);
+        None
+    }
+}
+
+fn format_stylish(infos: &[Info], styles: &ThemeStyles) -> String {
+    let rows = infos
+        .iter()
+        .map(|info| {
+            let severity = match info.severity {
+                Severity::Error => "error",
+                _ => "warning",
+            };
+            let rule_id = info.rule_id.as_deref().map(eslint_rule_id).unwrap_or_default();
+            // Keep each diagnostic on a single line to align the columns
+            let message = info.message.replace('\n', " ");
+            (format!("{}:{}", info.line, info.column), severity, message, rule_id)
+        })
+        .collect::<Vec<_>>();
+    let position_width = rows.iter().map(|row| row.0.len()).max().unwrap_or_default();
+    let severity_width = rows.iter().map(|row| row.1.len()).max().unwrap_or_default();
+    let message_width = rows.iter().map(|row| row.2.chars().count()).max().unwrap_or_default();
+
+    let filename = infos.iter().map(|info| info.filename.as_str()).find(|name| !name.is_empty());
+    let mut output = format!("\n{}\n", filename.unwrap_or_default());
+    for (position, severity, message, rule_id) in rows {
+        let severity_style = if severity == "error" { styles.error } else { styles.warning };
+        let line = format!(
+            "  {}  {}  {message:message_width$}",
+            format!("{position:position_width$}").style(styles.linum),
+            format!("{severity:severity_width$}").style(severity_style),
+        );
+        if rule_id.is_empty() {
+            output.push_str(line.trim_end());
+        } else {
+            let _ = write!(output, "{line}  {}", rule_id.style(styles.linum));
+        }
+        output.push('\n');
+    }
+    output
+}
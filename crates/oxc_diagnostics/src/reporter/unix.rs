@@ -3,7 +3,7 @@ use std::{
     io::{BufWriter, Stdout, Write},
 };
 
-use super::{writer, DiagnosticReporter, Info};
+use super::{eslint_rule_id, writer, DiagnosticReporter, Info};
 use crate::{Error, Severity};
 
 pub struct UnixReporter {
@@ -44,7 +44,11 @@ fn format_unix(diagnostic: &Error) -> String {
         Severity::Error => "Error",
         _ => "Warning",
     };
-    let rule_id =
-        rule_id.map_or_else(|| Cow::Borrowed(""), |rule_id| Cow::Owned(format!("/{rule_id}")));
+    let rule_id = rule_id.map_or_else(
+        || Cow::Borrowed(""),
+        |rule_id| Cow::Owned(format!("/{}", eslint_rule_id(&rule_id))),
+    );
+    // Keep each diagnostic on a single line for tools parsing the output line by line
+    let message = message.replace('\n', " ");
     format!("{filename}:{line}:{column}: {message} [{severity}{rule_id}]\n")
 }
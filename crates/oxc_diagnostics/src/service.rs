@@ -7,15 +7,27 @@ use std::{
 use crate::{
     reporter::{
         CheckstyleReporter, DiagnosticReporter, GithubReporter, GraphicalReporter, JsonReporter,
-        UnixReporter,
+        JunitReporter, StylishReporter, UnixReporter,
     },
-    Error, NamedSource, OxcDiagnostic, Severity,
+    DiagnosticStats, DiagnosticWithSource, Error, MessageCatalog, NamedSource, OxcDiagnostic,
+    Severity,
 };
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
-pub type DiagnosticSender = mpsc::Sender<Option<DiagnosticTuple>>;
+pub type DiagnosticSender = mpsc::SyncSender<Option<DiagnosticTuple>>;
 pub type DiagnosticReceiver = mpsc::Receiver<Option<DiagnosticTuple>>;
 
+/// Number of files whose diagnostics wait to be reported. The threads sending diagnostics are
+/// blocked when the reporter falls behind, so the diagnostics of large code bases, which hold the
+/// source text of their files, are not all kept in memory.
+const CHANNEL_CAPACITY: usize = 256;
+
+/// A bounded channel for the diagnostics of the linted files, used by [`DiagnosticService`] and by
+/// the other consumers of the diagnostics of a lint run.
+pub fn diagnostic_channel() -> (DiagnosticSender, DiagnosticReceiver) {
+    mpsc::sync_channel(CHANNEL_CAPACITY)
+}
+
 pub struct DiagnosticService {
     reporter: Box<dyn DiagnosticReporter>,
 
@@ -35,13 +47,19 @@ pub struct DiagnosticService {
     /// Total number of errors received
     errors_count: Cell<usize>,
 
+    /// Statistics of the received diagnostics, see [`DiagnosticService::with_stats`]
+    stats: Option<DiagnosticStats>,
+
+    /// Translations of the diagnostics, see [`DiagnosticService::with_catalog`]
+    catalog: Option<MessageCatalog>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
 
 impl Default for DiagnosticService {
     fn default() -> Self {
-        let (sender, receiver) = mpsc::channel();
+        let (sender, receiver) = diagnostic_channel();
         Self {
             reporter: Box::<GraphicalReporter>::default(),
             quiet: false,
@@ -49,6 +67,8 @@ impl Default for DiagnosticService {
             max_warnings: None,
             warnings_count: Cell::new(0),
             errors_count: Cell::new(0),
+            stats: None,
+            catalog: None,
             sender,
             receiver,
         }
@@ -72,6 +92,14 @@ impl DiagnosticService {
         self.reporter = Box::<GithubReporter>::default();
     }
 
+    pub fn set_junit_reporter(&mut self) {
+        self.reporter = Box::<JunitReporter>::default();
+    }
+
+    pub fn set_stylish_reporter(&mut self) {
+        self.reporter = Box::<StylishReporter>::default();
+    }
+
     #[must_use]
     pub fn with_quiet(mut self, yes: bool) -> Self {
         self.quiet = yes;
@@ -90,6 +118,21 @@ impl DiagnosticService {
         self
     }
 
+    /// Collect the [`DiagnosticStats`] of the received diagnostics, which are passed to the
+    /// reporter by [`DiagnosticService::finish`].
+    #[must_use]
+    pub fn with_stats(mut self, yes: bool) -> Self {
+        self.stats = yes.then(DiagnosticStats::default);
+        self
+    }
+
+    /// Translate the diagnostics with `catalog` before they are rendered.
+    #[must_use]
+    pub fn with_catalog(mut self, catalog: Option<MessageCatalog>) -> Self {
+        self.catalog = catalog;
+        self
+    }
+
     pub fn sender(&self) -> &DiagnosticSender {
         &self.sender
     }
@@ -102,6 +145,16 @@ impl DiagnosticService {
         self.errors_count.get()
     }
 
+    /// The statistics collected with [`DiagnosticService::with_stats`], to be completed with the
+    /// files and the duration of the run before [`DiagnosticService::finish`].
+    pub fn stats_mut(&mut self) -> Option<&mut DiagnosticStats> {
+        self.stats.as_mut()
+    }
+
+    pub fn stats(&self) -> Option<&DiagnosticStats> {
+        self.stats.as_ref()
+    }
+
     pub fn max_warnings_exceeded(&self) -> bool {
         self.max_warnings.map_or(false, |max_warnings| self.warnings_count.get() > max_warnings)
     }
@@ -114,11 +167,16 @@ impl DiagnosticService {
         let source = Arc::new(NamedSource::new(path.to_string_lossy(), source_text.to_owned()));
         let diagnostics = diagnostics
             .into_iter()
-            .map(|diagnostic| diagnostic.with_source_code(Arc::clone(&source)))
+            .map(|diagnostic| {
+                Error::new(DiagnosticWithSource::new(diagnostic, Arc::clone(&source)))
+            })
             .collect();
         (path.to_path_buf(), diagnostics)
     }
 
+    /// Receive and print the diagnostics until the senders are done, the output is completed by
+    /// [`DiagnosticService::finish`].
+    ///
     /// # Panics
     ///
     /// * When the writer fails to write
@@ -130,6 +188,9 @@ impl DiagnosticService {
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
                 if is_warning || is_error {
+                    if let Some(stats) = &mut self.stats {
+                        stats.add(&diagnostic);
+                    }
                     if is_warning {
                         let warnings_count = self.warnings_count() + 1;
                         self.warnings_count.set(warnings_count);
@@ -149,6 +210,10 @@ impl DiagnosticService {
                     continue;
                 }
 
+                let diagnostic = match &self.catalog {
+                    Some(catalog) => catalog.translate_error(diagnostic),
+                    None => diagnostic,
+                };
                 if let Some(mut err_str) = self.reporter.render_error(diagnostic) {
                     // Skip large output and print only once
                     if err_str.lines().any(|line| line.len() >= 400) {
@@ -165,7 +230,14 @@ impl DiagnosticService {
             }
             self.reporter.render_diagnostics(output.as_bytes());
         }
+    }
 
+    /// Complete the output of the reporter, e.g. print the JSON array of the diagnostics of the
+    /// `json` format, with the statistics of the run if they are collected.
+    pub fn finish(&mut self) {
+        if let Some(stats) = &self.stats {
+            self.reporter.render_stats(stats);
+        }
         self.reporter.finish();
     }
 }
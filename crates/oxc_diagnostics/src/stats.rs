@@ -0,0 +1,99 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde_json::json;
+
+use crate::{eslint_rule_id, reporter::Info, DiagnosticWithSource, Error, Severity};
+
+/// Statistics of a run, to track the number of problems over time and to find the rules
+/// reporting most of them. The files, the parse failures and the duration are filled in by the
+/// caller of [`DiagnosticService::run`](crate::DiagnosticService::run), which knows them.
+#[derive(Debug, Default, Clone)]
+pub struct DiagnosticStats {
+    /// Number of linted files
+    pub files: usize,
+    /// Number of linted files which could not be parsed
+    pub parse_failures: usize,
+    pub duration: Duration,
+    pub warnings: usize,
+    pub errors: usize,
+    /// Number of problems with a fix
+    pub fixable: usize,
+    /// The problems of each rule, keyed by the ESLint id of the rule, see
+    /// [`eslint_rule_id`](crate::eslint_rule_id)
+    pub rules: BTreeMap<String, RuleStats>,
+}
+
+/// The problems reported by a rule, see [`DiagnosticStats::rules`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RuleStats {
+    pub warnings: usize,
+    pub errors: usize,
+    pub fixable: usize,
+}
+
+impl RuleStats {
+    pub fn problems(&self) -> usize {
+        self.warnings + self.errors
+    }
+
+    fn count(&mut self, is_error: bool, is_fixable: bool) {
+        if is_error {
+            self.errors += 1;
+        } else {
+            self.warnings += 1;
+        }
+        self.fixable += usize::from(is_fixable);
+    }
+}
+
+impl DiagnosticStats {
+    /// Count `diagnostic`, a warning or an error.
+    pub(crate) fn add(&mut self, diagnostic: &Error) {
+        let is_error = diagnostic.severity() != Some(Severity::Warning);
+        let is_fixable = diagnostic
+            .downcast_ref::<DiagnosticWithSource>()
+            .is_some_and(|diagnostic| diagnostic.diagnostic.fix.is_some());
+        if let Some(rule_id) = Info::new(diagnostic).rule_id {
+            self.rules.entry(eslint_rule_id(&rule_id)).or_default().count(is_error, is_fixable);
+        }
+        if is_error {
+            self.errors += 1;
+        } else {
+            self.warnings += 1;
+        }
+        self.fixable += usize::from(is_fixable);
+    }
+
+    /// The rules which reported problems, the rules with the most problems first.
+    pub fn sorted_rules(&self) -> Vec<(&str, RuleStats)> {
+        let mut rules =
+            self.rules.iter().map(|(rule, stats)| (rule.as_str(), *stats)).collect::<Vec<_>>();
+        rules.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.problems()));
+        rules
+    }
+
+    /// The statistics as the `stats` object of the `json` output format.
+    pub fn to_json(&self) -> serde_json::Value {
+        let rules = self
+            .rules
+            .iter()
+            .map(|(rule, stats)| {
+                let stats = json!({
+                    "warnings": stats.warnings,
+                    "errors": stats.errors,
+                    "fixable": stats.fixable,
+                });
+                (rule.clone(), stats)
+            })
+            .collect::<serde_json::Map<_, _>>();
+        json!({
+            "files": self.files,
+            "parse_failures": self.parse_failures,
+            "duration_ms": u64::try_from(self.duration.as_millis()).unwrap_or(u64::MAX),
+            "warnings": self.warnings,
+            "errors": self.errors,
+            "fixable": self.fixable,
+            "rules": rules,
+        })
+    }
+}
@@ -3,42 +3,6 @@
 use oxc_allocator::{Box, Vec};
 use oxc_span::{CompactStr, Span};
 
-/// The type which includes all nodes.
-#[derive(Debug)]
-pub enum Node<'a> {
-    Branch(Box<'a, Branch<'a>>),
-    Leaf(Box<'a, Leaf<'a>>),
-}
-
-/// The type which includes all branch nodes.
-#[derive(Debug)]
-pub enum Branch<'a> {
-    Alternative(Box<'a, Alternative<'a>>),
-    CapturingGroup(Box<'a, CapturingGroup<'a>>),
-    CharacterClass(Box<'a, CharacterClass<'a>>),
-    CharacterClassRange(Box<'a, CharacterClassRange>),
-    ClassIntersection(Box<'a, ClassIntersection<'a>>),
-    ClassStringDisjunction(Box<'a, ClassStringDisjunction<'a>>),
-    ClassSubtraction(Box<'a, ClassSubtraction<'a>>),
-    ExpressionCharacterClass(Box<'a, ExpressionCharacterClass<'a>>),
-    Group(Box<'a, Group<'a>>),
-    LookaroundAssertion(Box<'a, LookaroundAssertion<'a>>),
-    Pattern(Box<'a, Pattern<'a>>),
-    Quantifier(Box<'a, Quantifier<'a>>),
-    RegExpLiteral(Box<'a, RegExpLiteral<'a>>),
-    StringAlternative(Box<'a, StringAlternative<'a>>),
-}
-
-/// The type which includes all leaf nodes.
-#[derive(Debug)]
-pub enum Leaf<'a> {
-    Backreference(Box<'a, Backreference<'a>>),
-    BoundaryAssertion(Box<'a, BoundaryAssertion<'a>>),
-    Character(Box<'a, Character>),
-    CharacterSet(Box<'a, CharacterSet<'a>>),
-    Flags(Box<'a, Flags>),
-}
-
 /// The type which includes all atom nodes.
 #[derive(Debug)]
 pub enum Element<'a> {
@@ -50,7 +14,7 @@ pub enum Element<'a> {
 /// The type which includes all atom nodes that Quantifier node can have as children.
 #[derive(Debug)]
 pub enum QuantifiableElement<'a> {
-    Backreference(Box<'a, Backreference<'a>>),
+    Backreference(Box<'a, Backreference>),
     CapturingGroup(Box<'a, CapturingGroup<'a>>),
     Character(Box<'a, Character>),
     CharacterClass(Box<'a, CharacterClass<'a>>),
@@ -122,7 +86,6 @@ pub struct CapturingGroup<'a> {
     pub span: Span,
     pub name: Option<CompactStr>,
     pub alternatives: Vec<'a, Alternative<'a>>,
-    pub references: Vec<'a, Backreference<'a>>,
 }
 
 /// The lookaround assertion.
@@ -175,7 +138,7 @@ pub enum CharacterClass<'a> {
 #[derive(Debug)]
 pub struct ClassRangesCharacterClass<'a> {
     pub span: Span,
-    pub unicode_sets: bool,
+    pub negate: bool,
     pub elements: Vec<'a, ClassRangesCharacterClassElement<'a>>,
 }
 
@@ -184,6 +147,7 @@ pub struct ClassRangesCharacterClass<'a> {
 #[derive(Debug)]
 pub struct UnicodeSetsCharacterClass<'a> {
     pub span: Span,
+    pub negate: bool,
     pub elements: Vec<'a, UnicodeSetsCharacterClassElement<'a>>,
 }
 
@@ -218,7 +182,7 @@ pub struct EdgeAssertion {
     pub kind: EdgeAssertionKind,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EdgeAssertionKind {
     Start,
     End,
@@ -235,7 +199,8 @@ pub struct WordBoundaryAssertion {
 /// The character set.
 #[derive(Debug)]
 pub enum CharacterSet<'a> {
-    AnyCharacterSet,
+    /// `.`
+    AnyCharacterSet(Span),
     EscapeCharacterSet(Box<'a, EscapeCharacterSet>),
     UnicodePropertyCharacterSet(Box<'a, UnicodePropertyCharacterSet<'a>>),
 }
@@ -249,7 +214,7 @@ pub struct EscapeCharacterSet {
     pub negate: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EscapeCharacterSetKind {
     Digit,
     Space,
@@ -354,26 +319,28 @@ pub struct StringAlternative<'a> {
 #[derive(Debug)]
 pub struct Character {
     pub span: Span,
-    pub value: u16, // UTF-16 code point
+    /// The code point, or a UTF-16 code unit for the halves of the characters outside of the
+    /// Basic Multilingual Plane without the `u` or `v` flag, e.g. both halves of `/😀/` have
+    /// the span of the whole character.
+    pub value: u32,
 }
 
 #[derive(Debug)]
 pub enum BackreferenceRef {
-    Number(i32),
-    CompactStr(CompactStr),
+    Number(u32),
+    Named(CompactStr),
 }
 
 /// The backreference.
 /// E.g. `\1`, `\k<name>`
 #[derive(Debug)]
-pub struct Backreference<'a> {
+pub struct Backreference {
     pub span: Span,
     pub reference: BackreferenceRef,
-    pub resolved: CapturingGroup<'a>,
 }
 
 /// The flags.
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, Copy)]
 pub struct Flags {
     pub span: Span,
     pub dot_all: bool,
@@ -1 +1,80 @@
-mod token;
+/// Cursor over the characters of a pattern, at byte offsets of the pattern.
+pub(crate) struct Reader<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(source: &'a str) -> Self {
+        Self { source, offset: 0 }
+    }
+
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Move back to `offset`, which was returned by [`Reader::offset`].
+    pub(crate) fn rewind(&mut self, offset: usize) {
+        self.offset = offset;
+    }
+
+    pub(crate) fn peek(&self) -> Option<char> {
+        self.source[self.offset..].chars().next()
+    }
+
+    /// The character after the next one.
+    pub(crate) fn peek2(&self) -> Option<char> {
+        self.source[self.offset..].chars().nth(1)
+    }
+
+    pub(crate) fn starts_with(&self, s: &str) -> bool {
+        self.source[self.offset..].starts_with(s)
+    }
+
+    pub(crate) fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.offset += c.len_utf8();
+        Some(c)
+    }
+
+    pub(crate) fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.offset += c.len_utf8();
+        }
+        eaten
+    }
+
+    pub(crate) fn eat_str(&mut self, s: &str) -> bool {
+        let eaten = self.starts_with(s);
+        if eaten {
+            self.offset += s.len();
+        }
+        eaten
+    }
+
+    /// Eat the digits of `radix`, and return their value unless there are none.
+    pub(crate) fn eat_digits(&mut self, radix: u32) -> Option<f64> {
+        let mut value = None;
+        while let Some(digit) = self.peek().and_then(|c| c.to_digit(radix)) {
+            self.offset += 1;
+            value = Some(value.unwrap_or(0.0) * f64::from(radix) + f64::from(digit));
+        }
+        value
+    }
+
+    /// Eat exactly `count` hexadecimal digits.
+    pub(crate) fn eat_hex_digits(&mut self, count: usize) -> Option<u32> {
+        let start = self.offset;
+        let mut value = 0;
+        for _ in 0..count {
+            let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) else {
+                self.offset = start;
+                return None;
+            };
+            self.offset += 1;
+            value = value * 16 + digit;
+        }
+        Some(value)
+    }
+}
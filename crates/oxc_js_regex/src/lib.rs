@@ -1,5 +1,4 @@
 pub mod ast;
 mod lexer;
 pub mod parser;
-pub mod validator;
 pub mod visitor;
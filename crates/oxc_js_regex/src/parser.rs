@@ -1 +1,1294 @@
+//! Parser of the regular expressions, following the grammar of the
+//! [specification](https://tc39.es/ecma262/#sec-patterns) with the additions of
+//! [Annex B](https://tc39.es/ecma262/#sec-regular-expressions-patterns) outside of the Unicode
+//! mode, e.g. `/]{/` or `/\1/` without a group.
 
+use oxc_allocator::{Allocator, Box, Vec};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
+
+#[allow(clippy::wildcard_imports)]
+use crate::{ast::*, lexer::Reader};
+
+/// The characters which must be escaped to match themselves.
+const SYNTAX_CHARACTERS: &str = "^$\\.*+?()[]{}|";
+/// The characters which must be escaped in a class with the `v` flag.
+const CLASS_SET_SYNTAX_CHARACTERS: &str = "()[]{}/-\\|";
+/// The characters which cannot be doubled in a class with the `v` flag, e.g. `[a&&&b]`.
+const CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS: &str = "&!#$%*+,.:;<=>?@^`~";
+/// The characters which can be escaped in a class with the `v` flag.
+const CLASS_SET_RESERVED_PUNCTUATORS: &str = "&-!#%,:;<=>@`~";
+/// The properties of `\p{key=value}`.
+const NON_BINARY_PROPERTIES: [&str; 6] =
+    ["General_Category", "gc", "Script", "sc", "Script_Extensions", "scx"];
+/// The properties which match strings, only allowed with the `v` flag.
+const PROPERTIES_OF_STRINGS: [&str; 7] = [
+    "Basic_Emoji",
+    "Emoji_Keycap_Sequence",
+    "RGI_Emoji_Modifier_Sequence",
+    "RGI_Emoji_Flag_Sequence",
+    "RGI_Emoji_Tag_Sequence",
+    "RGI_Emoji_ZWJ_Sequence",
+    "RGI_Emoji",
+];
+
+/// Parser of a regular expression literal, e.g. `/ab+c/gu`.
+pub struct Parser<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    span_offset: u32,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(allocator: &'a Allocator, source_text: &'a str) -> Self {
+        Self { allocator, source_text, span_offset: 0 }
+    }
+
+    /// Add `span_offset` to the spans, e.g. the start of the literal in its file.
+    #[must_use]
+    pub fn with_span_offset(mut self, span_offset: u32) -> Self {
+        self.span_offset = span_offset;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// The first syntax error of the literal.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn parse(self) -> Result<RegExpLiteral<'a>, OxcDiagnostic> {
+        let span = Span::new(self.span_offset, self.span_offset + self.source_text.len() as u32);
+        let Some((pattern, flags)) =
+            self.source_text.strip_prefix('/').and_then(|body| body.rsplit_once('/'))
+        else {
+            return Err(OxcDiagnostic::error(
+                "Invalid regular expression: expected a literal like `/pattern/flags`",
+            )
+            .with_label(span));
+        };
+        let flags_offset = span.end - flags.len() as u32;
+        let flags = FlagsParser::new(flags).with_span_offset(flags_offset).parse()?;
+        let options = ParserOptions::from_flags(flags).with_span_offset(self.span_offset + 1);
+        let pattern = PatternParser::new(self.allocator, pattern, options).parse()?;
+        Ok(RegExpLiteral { span, pattern, flags })
+    }
+}
+
+/// Parser of the flags of a regular expression, e.g. `gu`.
+pub struct FlagsParser<'a> {
+    source_text: &'a str,
+    span_offset: u32,
+}
+
+impl<'a> FlagsParser<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text, span_offset: 0 }
+    }
+
+    #[must_use]
+    pub fn with_span_offset(mut self, span_offset: u32) -> Self {
+        self.span_offset = span_offset;
+        self
+    }
+
+    /// # Errors
+    ///
+    /// An unknown or repeated flag, or both `u` and `v`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn parse(self) -> Result<Flags, OxcDiagnostic> {
+        let mut flags = Flags {
+            span: Span::new(self.span_offset, self.span_offset + self.source_text.len() as u32),
+            ..Flags::default()
+        };
+        for (index, c) in self.source_text.char_indices() {
+            let start = self.span_offset + index as u32;
+            let span = Span::new(start, start + c.len_utf8() as u32);
+            let flag = match c {
+                'd' => &mut flags.has_indices,
+                'g' => &mut flags.global,
+                'i' => &mut flags.ignore_case,
+                'm' => &mut flags.multiline,
+                's' => &mut flags.dot_all,
+                'u' => &mut flags.unicode,
+                'v' => &mut flags.unicode_sets,
+                'y' => &mut flags.sticky,
+                _ => {
+                    return Err(OxcDiagnostic::error(format!(
+                        "Invalid regular expression: unknown flag `{c}`"
+                    ))
+                    .with_label(span))
+                }
+            };
+            if *flag {
+                return Err(OxcDiagnostic::error(format!(
+                    "Invalid regular expression: duplicated flag `{c}`"
+                ))
+                .with_label(span));
+            }
+            *flag = true;
+        }
+        if flags.unicode && flags.unicode_sets {
+            return Err(OxcDiagnostic::error(
+                "Invalid regular expression: the `u` and `v` flags cannot be used together",
+            )
+            .with_label(flags.span));
+        }
+        Ok(flags)
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParserOptions {
+    /// The `u` or the `v` flag
+    pub unicode_mode: bool,
+    /// The `v` flag
+    pub unicode_sets_mode: bool,
+    /// Added to the spans, e.g. the start of the pattern in its file
+    pub span_offset: u32,
+}
+
+impl ParserOptions {
+    /// The options of a pattern with `flags`.
+    pub fn from_flags(flags: Flags) -> Self {
+        Self {
+            unicode_mode: flags.unicode || flags.unicode_sets,
+            unicode_sets_mode: flags.unicode_sets,
+            span_offset: 0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_span_offset(mut self, span_offset: u32) -> Self {
+        self.span_offset = span_offset;
+        self
+    }
+}
+
+/// Parser of the pattern of a regular expression, e.g. `ab+c` of `/ab+c/gu`.
+///
+/// The names of the Unicode properties, e.g. `Script` of `\p{Script=Greek}`, are not checked
+/// against the tables of Unicode, only the properties of strings are told apart.
+pub struct PatternParser<'a> {
+    allocator: &'a Allocator,
+    source_text: &'a str,
+    options: ParserOptions,
+    reader: Reader<'a>,
+    /// The number of capturing groups, counted before parsing for the backreferences
+    group_count: u32,
+    /// The names of the capturing groups, collected before parsing for the named backreferences
+    group_names: std::vec::Vec<CompactStr>,
+    /// The named groups parsed so far and the alternatives they are in
+    named_groups: std::vec::Vec<(CompactStr, std::vec::Vec<(u32, u32)>)>,
+    /// The disjunctions around the position, and the index of their alternative being parsed
+    alternative_path: std::vec::Vec<(u32, u32)>,
+    disjunction_count: u32,
+}
+
+/// An element of a class without the `v` flag, which may be a bound of a range.
+enum ClassAtom<'a> {
+    Character(Character),
+    /// The halves of a character outside of the BMP without the `u` flag
+    Surrogates(Character, Character),
+    Set(ClassRangesCharacterClassElement<'a>),
+}
+
+/// A class with the `v` flag.
+enum ClassSet<'a> {
+    Union(UnicodeSetsCharacterClass<'a>),
+    Expression(ExpressionCharacterClass<'a>),
+}
+
+impl<'a> PatternParser<'a> {
+    pub fn new(allocator: &'a Allocator, source_text: &'a str, options: ParserOptions) -> Self {
+        Self {
+            allocator,
+            source_text,
+            options,
+            reader: Reader::new(source_text),
+            group_count: 0,
+            group_names: vec![],
+            named_groups: vec![],
+            alternative_path: vec![],
+            disjunction_count: 0,
+        }
+    }
+
+    /// # Errors
+    ///
+    /// The first syntax error of the pattern.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn parse(mut self) -> Result<Pattern<'a>, OxcDiagnostic> {
+        self.scan_groups();
+        let alternatives = self.parse_disjunction()?;
+        if self.reader.peek().is_some() {
+            let start = self.reader.offset();
+            self.reader.next();
+            return Err(self.error("Unmatched ')'", start));
+        }
+        let start = self.options.span_offset;
+        let span = Span::new(start, start + self.source_text.len() as u32);
+        Ok(Pattern { span, alternatives })
+    }
+
+    fn alloc<T>(&self, value: T) -> Box<'a, T> {
+        Box::new_in(value, self.allocator)
+    }
+
+    /// The span from `start` to the position.
+    #[allow(clippy::cast_possible_truncation)]
+    fn span(&self, start: usize) -> Span {
+        let offset = self.options.span_offset;
+        Span::new(offset + start as u32, offset + self.reader.offset() as u32)
+    }
+
+    fn error(&self, message: &str, start: usize) -> OxcDiagnostic {
+        OxcDiagnostic::error(format!("Invalid regular expression: {message}"))
+            .with_label(self.span(start))
+    }
+
+    /// Count the capturing groups and collect their names, which backreferences may use before
+    /// the groups, e.g. `/\k<a>(?<a>.)/`.
+    fn scan_groups(&mut self) {
+        let mut reader = Reader::new(self.source_text);
+        let mut class_depth = 0;
+        while let Some(c) = reader.next() {
+            match c {
+                '\\' => {
+                    reader.next();
+                }
+                '[' if class_depth == 0 || self.options.unicode_sets_mode => class_depth += 1,
+                ']' if class_depth > 0 => class_depth -= 1,
+                '(' if class_depth == 0 => {
+                    if !reader.eat('?') {
+                        self.group_count += 1;
+                    } else if reader.eat('<')
+                        && reader.peek() != Some('=')
+                        && reader.peek() != Some('!')
+                    {
+                        self.group_count += 1;
+                        if let Some(name) = eat_group_name(&mut reader) {
+                            self.group_names.push(name);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// `a|b`
+    fn parse_disjunction(&mut self) -> Result<Vec<'a, Alternative<'a>>, OxcDiagnostic> {
+        let disjunction = self.disjunction_count;
+        self.disjunction_count += 1;
+        let mut alternatives = Vec::new_in(self.allocator);
+        for index in 0.. {
+            self.alternative_path.push((disjunction, index));
+            let alternative = self.parse_alternative();
+            self.alternative_path.pop();
+            alternatives.push(alternative?);
+            if !self.reader.eat('|') {
+                break;
+            }
+        }
+        Ok(alternatives)
+    }
+
+    fn parse_alternative(&mut self) -> Result<Alternative<'a>, OxcDiagnostic> {
+        let start = self.reader.offset();
+        let mut elements = Vec::new_in(self.allocator);
+        while self.reader.peek().is_some_and(|c| c != '|' && c != ')') {
+            self.parse_term(&mut elements)?;
+        }
+        Ok(Alternative { span: self.span(start), elements })
+    }
+
+    fn parse_term(&mut self, elements: &mut Vec<'a, Element<'a>>) -> Result<(), OxcDiagnostic> {
+        let start = self.reader.offset();
+        if let Some(assertion) = self.parse_assertion(start)? {
+            elements.push(assertion);
+            return Ok(());
+        }
+        let atom = self.parse_atom(start, elements)?;
+        let element = self.parse_quantified(start, atom)?;
+        elements.push(element);
+        Ok(())
+    }
+
+    /// `^`, `$`, `\b`, `\B` or a lookaround.
+    fn parse_assertion(&mut self, start: usize) -> Result<Option<Element<'a>>, OxcDiagnostic> {
+        let assertion = if self.reader.eat('^') || self.reader.eat('$') {
+            let kind = if self.source_text[start..].starts_with('^') {
+                EdgeAssertionKind::Start
+            } else {
+                EdgeAssertionKind::End
+            };
+            let edge = self.alloc(EdgeAssertion { span: self.span(start), kind });
+            Assertion::BoundaryAssertion(self.alloc(BoundaryAssertion::EdgeAssertion(edge)))
+        } else if self.reader.eat_str("\\b") || self.reader.eat_str("\\B") {
+            let negate = self.source_text[start..].starts_with("\\B");
+            let boundary = self.alloc(WordBoundaryAssertion { span: self.span(start), negate });
+            Assertion::BoundaryAssertion(
+                self.alloc(BoundaryAssertion::WordBoundaryAssertion(boundary)),
+            )
+        } else if let Some(prefix) =
+            ["(?=", "(?!", "(?<=", "(?<!"].into_iter().find(|prefix| self.reader.eat_str(prefix))
+        {
+            let negate = prefix.ends_with('!');
+            let alternatives = self.parse_group_body(start)?;
+            let span = self.span(start);
+            let lookaround = if prefix.len() == 3 {
+                let lookahead = LookaheadAssertion { span, negate, alternatives };
+                // Lookaheads can be repeated outside of the Unicode mode, see Annex B
+                if !self.options.unicode_mode {
+                    let element = QuantifiableElement::LookaheadAssertion(self.alloc(lookahead));
+                    return self.parse_quantified(start, element).map(Some);
+                }
+                LookaroundAssertion::LookaheadAssertion(self.alloc(lookahead))
+            } else {
+                let lookbehind = LookbehindAssertion { span, negate, alternatives };
+                LookaroundAssertion::LookbehindAssertion(self.alloc(lookbehind))
+            };
+            Assertion::LookaroundAssertion(self.alloc(lookaround))
+        } else {
+            return Ok(None);
+        };
+        let quantifier_start = self.reader.offset();
+        if self.parse_quantifier(quantifier_start)?.is_some() {
+            return Err(self.error("Nothing to repeat", quantifier_start));
+        }
+        Ok(Some(Element::Assertion(self.alloc(assertion))))
+    }
+
+    /// `element`, which starts at `start`, with its quantifier if any.
+    fn parse_quantified(
+        &mut self,
+        start: usize,
+        element: QuantifiableElement<'a>,
+    ) -> Result<Element<'a>, OxcDiagnostic> {
+        let quantifier_start = self.reader.offset();
+        Ok(match self.parse_quantifier(quantifier_start)? {
+            Some((min, max, greedy)) => Element::Quantifier(self.alloc(Quantifier {
+                span: self.span(start),
+                min,
+                max,
+                greedy,
+                element,
+            })),
+            None => Element::QuantifiableElement(self.alloc(element)),
+        })
+    }
+
+    /// `*`, `+`, `?` or `{min,max}`, followed by `?` when they are lazy. Returns the minimum,
+    /// the maximum and whether the quantifier is greedy.
+    fn parse_quantifier(
+        &mut self,
+        start: usize,
+    ) -> Result<Option<(f64, f64, bool)>, OxcDiagnostic> {
+        let (min, max) = match self.reader.peek() {
+            Some(c @ ('*' | '+' | '?')) => {
+                self.reader.next();
+                match c {
+                    '*' => (0.0, f64::INFINITY),
+                    '+' => (1.0, f64::INFINITY),
+                    _ => (0.0, 1.0),
+                }
+            }
+            Some('{') => match self.eat_braced_quantifier() {
+                Some(range) => {
+                    if range.0 > range.1 {
+                        return Err(self.error("numbers out of order in {} quantifier", start));
+                    }
+                    range
+                }
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+        let greedy = !self.reader.eat('?');
+        Ok(Some((min, max, greedy)))
+    }
+
+    /// `{min}`, `{min,}` or `{min,max}`, which are literal characters outside of the Unicode
+    /// mode when they are not quantifiers, e.g. `/{a}/`.
+    fn eat_braced_quantifier(&mut self) -> Option<(f64, f64)> {
+        let start = self.reader.offset();
+        let range = eat_quantifier_range(&mut self.reader);
+        if range.is_none() {
+            self.reader.rewind(start);
+        }
+        range
+    }
+
+    fn parse_atom(
+        &mut self,
+        start: usize,
+        elements: &mut Vec<'a, Element<'a>>,
+    ) -> Result<QuantifiableElement<'a>, OxcDiagnostic> {
+        match self.reader.peek() {
+            Some('.') => {
+                self.reader.next();
+                let set = CharacterSet::AnyCharacterSet(self.span(start));
+                Ok(QuantifiableElement::CharacterSet(self.alloc(set)))
+            }
+            Some('(') => self.parse_group(start),
+            Some('[') => self.parse_class(start),
+            Some('\\') => self.parse_atom_escape(start),
+            Some('*' | '+' | '?') => {
+                self.reader.next();
+                Err(self.error("Nothing to repeat", start))
+            }
+            Some('{' | '}' | ']') if self.options.unicode_mode => {
+                self.reader.next();
+                Err(self.error("Lone quantifier brackets", start))
+            }
+            Some('{') if self.eat_braced_quantifier().is_some() => {
+                Err(self.error("Nothing to repeat", start))
+            }
+            Some(c) => {
+                self.reader.next();
+                let (first, second) = self.literal_characters(start, c);
+                match second {
+                    // The quantifier only repeats the second half
+                    Some(second) => {
+                        let first = QuantifiableElement::Character(self.alloc(first));
+                        elements.push(Element::QuantifiableElement(self.alloc(first)));
+                        Ok(QuantifiableElement::Character(self.alloc(second)))
+                    }
+                    None => Ok(QuantifiableElement::Character(self.alloc(first))),
+                }
+            }
+            None => Err(self.error("Unexpected end", start)),
+        }
+    }
+
+    /// The character `c`, which starts at `start`. Outside of the Unicode mode, patterns match
+    /// UTF-16 code units, so the characters outside of the BMP are their two halves.
+    fn literal_characters(&self, start: usize, c: char) -> (Character, Option<Character>) {
+        let span = self.span(start);
+        let value = u32::from(c);
+        if self.options.unicode_mode || value <= 0xFFFF {
+            return (Character { span, value }, None);
+        }
+        let value = value - 0x10000;
+        let high = Character { span, value: 0xD800 + (value >> 10) };
+        let low = Character { span, value: 0xDC00 + (value & 0x3FF) };
+        (high, Some(low))
+    }
+
+    /// `(a)`, `(?<name>a)` or `(?:a)`
+    fn parse_group(&mut self, start: usize) -> Result<QuantifiableElement<'a>, OxcDiagnostic> {
+        self.reader.next();
+        if !self.reader.eat('?') {
+            let alternatives = self.parse_group_body(start)?;
+            let group = CapturingGroup { span: self.span(start), name: None, alternatives };
+            return Ok(QuantifiableElement::CapturingGroup(self.alloc(group)));
+        }
+        if self.reader.eat(':') {
+            let alternatives = self.parse_group_body(start)?;
+            let group = Group { span: self.span(start), alternatives };
+            return Ok(QuantifiableElement::Group(self.alloc(group)));
+        }
+        if !self.reader.eat('<') {
+            return Err(self.error("Invalid group", start));
+        }
+        let Some(name) = eat_group_name(&mut self.reader) else {
+            return Err(self.error("Invalid capture group name", start));
+        };
+        // Groups can have the same name in different alternatives, e.g. `/(?<a>x)|(?<a>y)/`
+        let duplicate = self.named_groups.iter().any(|(other, path)| {
+            *other == name
+                && !path.iter().any(|(disjunction, index)| {
+                    self.alternative_path.iter().any(|(other_disjunction, other_index)| {
+                        disjunction == other_disjunction && index != other_index
+                    })
+                })
+        });
+        if duplicate {
+            return Err(self.error("Duplicate capture group name", start));
+        }
+        self.named_groups.push((name.clone(), self.alternative_path.clone()));
+        let alternatives = self.parse_group_body(start)?;
+        let group = CapturingGroup { span: self.span(start), name: Some(name), alternatives };
+        Ok(QuantifiableElement::CapturingGroup(self.alloc(group)))
+    }
+
+    /// The alternatives of a group which starts at `start`, and its `)`.
+    fn parse_group_body(
+        &mut self,
+        start: usize,
+    ) -> Result<Vec<'a, Alternative<'a>>, OxcDiagnostic> {
+        let alternatives = self.parse_disjunction()?;
+        if !self.reader.eat(')') {
+            return Err(self.error("Unterminated group", start));
+        }
+        Ok(alternatives)
+    }
+
+    /// An escape which is not an assertion, e.g. `\d`, `\1` or `\n`.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn parse_atom_escape(
+        &mut self,
+        start: usize,
+    ) -> Result<QuantifiableElement<'a>, OxcDiagnostic> {
+        self.reader.next();
+        let Some(c) = self.reader.peek() else {
+            return Err(self.error("\\ at end of pattern", start));
+        };
+        if let Some(set) = self.parse_escape_character_set(start) {
+            let set = CharacterSet::EscapeCharacterSet(self.alloc(set));
+            return Ok(QuantifiableElement::CharacterSet(self.alloc(set)));
+        }
+        if let Some(property) = self.parse_property_escape(start)? {
+            let set = CharacterSet::UnicodePropertyCharacterSet(self.alloc(property));
+            return Ok(QuantifiableElement::CharacterSet(self.alloc(set)));
+        }
+        // `\k` is a character outside of the Unicode mode when there are no named groups
+        if c == 'k' && (self.options.unicode_mode || !self.group_names.is_empty()) {
+            self.reader.next();
+            let name = if self.reader.eat('<') { eat_group_name(&mut self.reader) } else { None };
+            return match name {
+                Some(name) if self.group_names.contains(&name) => {
+                    let reference = BackreferenceRef::Named(name);
+                    let backreference = Backreference { span: self.span(start), reference };
+                    Ok(QuantifiableElement::Backreference(self.alloc(backreference)))
+                }
+                _ => Err(self.error("Invalid named reference", start)),
+            };
+        }
+        if matches!(c, '1'..='9') {
+            let digits_start = self.reader.offset();
+            let number = self.reader.eat_digits(10).unwrap_or_default();
+            if number <= f64::from(self.group_count) {
+                let reference = BackreferenceRef::Number(number as u32);
+                let backreference = Backreference { span: self.span(start), reference };
+                return Ok(QuantifiableElement::Backreference(self.alloc(backreference)));
+            }
+            if self.options.unicode_mode {
+                return Err(self.error("Invalid escape", start));
+            }
+            // An octal escape or a digit without enough groups, see Annex B
+            self.reader.rewind(digits_start);
+        }
+        let character = self.parse_character_escape(start, false)?;
+        Ok(QuantifiableElement::Character(self.alloc(character)))
+    }
+
+    /// `\d`, `\D`, `\s`, `\S`, `\w` or `\W` after the backslash at `start`.
+    fn parse_escape_character_set(&mut self, start: usize) -> Option<EscapeCharacterSet> {
+        let (kind, negate) = match self.reader.peek()? {
+            'd' => (EscapeCharacterSetKind::Digit, false),
+            'D' => (EscapeCharacterSetKind::Digit, true),
+            's' => (EscapeCharacterSetKind::Space, false),
+            'S' => (EscapeCharacterSetKind::Space, true),
+            'w' => (EscapeCharacterSetKind::Word, false),
+            'W' => (EscapeCharacterSetKind::Word, true),
+            _ => return None,
+        };
+        self.reader.next();
+        Some(EscapeCharacterSet { span: self.span(start), kind, negate })
+    }
+
+    /// `\p{name}`, `\p{key=value}` or `\P{...}` after the backslash at `start`, only in the
+    /// Unicode mode.
+    fn parse_property_escape(
+        &mut self,
+        start: usize,
+    ) -> Result<Option<UnicodePropertyCharacterSet<'a>>, OxcDiagnostic> {
+        if !self.options.unicode_mode {
+            return Ok(None);
+        }
+        let negate = match self.reader.peek() {
+            Some('p') => false,
+            Some('P') => true,
+            _ => return Ok(None),
+        };
+        self.reader.next();
+        if !self.reader.eat('{') {
+            return Err(self.error("Invalid property name", start));
+        }
+        let key = self.eat_property_word();
+        let value = if self.reader.eat('=') { Some(self.eat_property_word()) } else { None };
+        if !self.reader.eat('}')
+            || key.is_empty()
+            || value.is_some_and(|value| value.is_empty() || !NON_BINARY_PROPERTIES.contains(&key))
+        {
+            return Err(self.error("Invalid property name", start));
+        }
+        let span = self.span(start);
+        if value.is_none() && PROPERTIES_OF_STRINGS.contains(&key) {
+            if !self.options.unicode_sets_mode {
+                return Err(self.error("Invalid property name", start));
+            }
+            if negate {
+                return Err(self.error("Negated character class may contain strings", start));
+            }
+            let property = StringsUnicodePropertyCharacterSet { span, key: key.into() };
+            return Ok(Some(UnicodePropertyCharacterSet::StringsUnicodePropertyCharacterSet(
+                self.alloc(property),
+            )));
+        }
+        let property = CharacterUnicodePropertyCharacterSet {
+            span,
+            key: key.into(),
+            value: value.map(CompactStr::from),
+            negate,
+        };
+        Ok(Some(UnicodePropertyCharacterSet::CharacterUnicodePropertyCharacterSet(
+            self.alloc(property),
+        )))
+    }
+
+    fn eat_property_word(&mut self) -> &'a str {
+        let start = self.reader.offset();
+        while self.reader.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') {
+            self.reader.next();
+        }
+        &self.source_text[start..self.reader.offset()]
+    }
+
+    /// An escape which is a character after the backslash at `start`, e.g. `\n`, `\x41` or
+    /// `\.`.
+    fn parse_character_escape(
+        &mut self,
+        start: usize,
+        in_class: bool,
+    ) -> Result<Character, OxcDiagnostic> {
+        let unicode_mode = self.options.unicode_mode;
+        let Some(c) = self.reader.next() else {
+            return Err(self.error("\\ at end of pattern", start));
+        };
+        let value = match c {
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'c' => match self.reader.peek() {
+                Some(letter)
+                    if letter.is_ascii_alphabetic()
+                        || (in_class
+                            && !unicode_mode
+                            && (letter.is_ascii_digit() || letter == '_')) =>
+                {
+                    self.reader.next();
+                    u32::from(letter) % 32
+                }
+                _ if unicode_mode => return Err(self.error("Invalid unicode escape", start)),
+                // The backslash is a character followed by `c`, see Annex B
+                _ => {
+                    self.reader.rewind(start + 1);
+                    u32::from('\\')
+                }
+            },
+            '0' if !self.reader.peek().is_some_and(|c| c.is_ascii_digit()) => 0,
+            '0'..='7' if !unicode_mode => {
+                // A legacy octal escape up to `\377`, see Annex B
+                let mut value = c.to_digit(8).unwrap_or_default();
+                let max_length = if value <= 3 { 3 } else { 2 };
+                for _ in 1..max_length {
+                    match self.reader.peek().and_then(|c| c.to_digit(8)) {
+                        Some(digit) => {
+                            self.reader.next();
+                            value = value * 8 + digit;
+                        }
+                        None => break,
+                    }
+                }
+                value
+            }
+            '0'..='9' if unicode_mode => return Err(self.error("Invalid decimal escape", start)),
+            'x' => match self.reader.eat_hex_digits(2) {
+                Some(value) => value,
+                None if unicode_mode => return Err(self.error("Invalid escape", start)),
+                None => u32::from(c),
+            },
+            'u' => match eat_unicode_escape(&mut self.reader, unicode_mode) {
+                Some(value) => value,
+                None if unicode_mode => return Err(self.error("Invalid unicode escape", start)),
+                None => u32::from(c),
+            },
+            'k' if !unicode_mode && !self.group_names.is_empty() => {
+                return Err(self.error("Invalid named reference", start));
+            }
+            // Only the syntax characters can be escaped in the Unicode mode
+            c if unicode_mode => {
+                let valid = SYNTAX_CHARACTERS.contains(c)
+                    || c == '/'
+                    || (in_class && c == '-')
+                    || (in_class
+                        && self.options.unicode_sets_mode
+                        && CLASS_SET_RESERVED_PUNCTUATORS.contains(c));
+                if !valid {
+                    return Err(self.error("Invalid escape", start));
+                }
+                u32::from(c)
+            }
+            c => u32::from(c),
+        };
+        Ok(Character { span: self.span(start), value })
+    }
+
+    /// `[a-z]` without the `v` flag, or `[a&&b]` with it.
+    fn parse_class(&mut self, start: usize) -> Result<QuantifiableElement<'a>, OxcDiagnostic> {
+        self.reader.next();
+        let negate = self.reader.eat('^');
+        if !self.options.unicode_sets_mode {
+            let class = self.parse_class_ranges(start, negate)?;
+            let class = CharacterClass::ClassRangesCharacterClass(self.alloc(class));
+            return Ok(QuantifiableElement::CharacterClass(self.alloc(class)));
+        }
+        Ok(match self.parse_class_set(start, negate)?.0 {
+            ClassSet::Union(class) => {
+                let class = CharacterClass::UnicodeSetsCharacterClass(self.alloc(class));
+                QuantifiableElement::CharacterClass(self.alloc(class))
+            }
+            ClassSet::Expression(class) => {
+                QuantifiableElement::ExpressionCharacterClass(self.alloc(class))
+            }
+        })
+    }
+
+    /// The elements of a class without the `v` flag and its `]`.
+    fn parse_class_ranges(
+        &mut self,
+        start: usize,
+        negate: bool,
+    ) -> Result<ClassRangesCharacterClass<'a>, OxcDiagnostic> {
+        let mut elements = Vec::new_in(self.allocator);
+        loop {
+            match self.reader.peek() {
+                Some(']') => break,
+                Some(_) => {}
+                None => return Err(self.error("Unterminated character class", start)),
+            }
+            let atom_start = self.reader.offset();
+            let left = match self.parse_class_atom()? {
+                ClassAtom::Surrogates(high, low) => {
+                    self.push_class_atom(&mut elements, ClassAtom::Character(high));
+                    ClassAtom::Character(low)
+                }
+                atom => atom,
+            };
+            if self.reader.peek() != Some('-') || self.reader.peek2().map_or(true, |c| c == ']') {
+                self.push_class_atom(&mut elements, left);
+                continue;
+            }
+            let dash_start = self.reader.offset();
+            self.reader.next();
+            let dash = Character { span: self.span(dash_start), value: u32::from('-') };
+            let right = self.parse_class_atom()?;
+            let (max, low) = match right {
+                ClassAtom::Character(max) => (max, None),
+                ClassAtom::Surrogates(high, low) => (high, Some(low)),
+                ClassAtom::Set(_) => {
+                    // `[\d-z]` is `\d`, `-` and `z` outside of the Unicode mode, see Annex B
+                    if self.options.unicode_mode {
+                        return Err(self.error("Invalid character class", atom_start));
+                    }
+                    self.push_class_atom(&mut elements, left);
+                    self.push_class_atom(&mut elements, ClassAtom::Character(dash));
+                    self.push_class_atom(&mut elements, right);
+                    continue;
+                }
+            };
+            let ClassAtom::Character(min) = left else {
+                if self.options.unicode_mode {
+                    return Err(self.error("Invalid character class", atom_start));
+                }
+                self.push_class_atom(&mut elements, left);
+                self.push_class_atom(&mut elements, ClassAtom::Character(dash));
+                self.push_class_atom(&mut elements, ClassAtom::Character(max));
+                if let Some(low) = low {
+                    self.push_class_atom(&mut elements, ClassAtom::Character(low));
+                }
+                continue;
+            };
+            if min.value > max.value {
+                return Err(self.error("Range out of order in character class", atom_start));
+            }
+            let range =
+                CharacterClassRange { span: Span::new(min.span.start, max.span.end), min, max };
+            elements.push(ClassRangesCharacterClassElement::CharacterClassRange(self.alloc(range)));
+            if let Some(low) = low {
+                self.push_class_atom(&mut elements, ClassAtom::Character(low));
+            }
+        }
+        self.reader.next();
+        Ok(ClassRangesCharacterClass { span: self.span(start), negate, elements })
+    }
+
+    fn push_class_atom(
+        &self,
+        elements: &mut Vec<'a, ClassRangesCharacterClassElement<'a>>,
+        atom: ClassAtom<'a>,
+    ) {
+        match atom {
+            ClassAtom::Character(character) => {
+                elements.push(ClassRangesCharacterClassElement::Character(self.alloc(character)));
+            }
+            ClassAtom::Surrogates(high, low) => {
+                elements.push(ClassRangesCharacterClassElement::Character(self.alloc(high)));
+                elements.push(ClassRangesCharacterClassElement::Character(self.alloc(low)));
+            }
+            ClassAtom::Set(set) => elements.push(set),
+        }
+    }
+
+    /// A character or a set of a class without the `v` flag.
+    fn parse_class_atom(&mut self) -> Result<ClassAtom<'a>, OxcDiagnostic> {
+        let start = self.reader.offset();
+        let Some(c) = self.reader.next() else {
+            return Err(self.error("Unterminated character class", start));
+        };
+        if c != '\\' {
+            return Ok(match self.literal_characters(start, c) {
+                (high, Some(low)) => ClassAtom::Surrogates(high, low),
+                (character, None) => ClassAtom::Character(character),
+            });
+        }
+        if self.reader.eat('b') {
+            return Ok(ClassAtom::Character(Character { span: self.span(start), value: 0x08 }));
+        }
+        if let Some(set) = self.parse_escape_character_set(start) {
+            let set = ClassRangesCharacterClassElement::EscapeCharacterSet(self.alloc(set));
+            return Ok(ClassAtom::Set(set));
+        }
+        if let Some(property) = self.parse_property_escape(start)? {
+            return match property {
+                UnicodePropertyCharacterSet::CharacterUnicodePropertyCharacterSet(property) => {
+                    Ok(ClassAtom::Set(
+                        ClassRangesCharacterClassElement::CharacterUnicodePropertyCharacterSet(
+                            property,
+                        ),
+                    ))
+                }
+                UnicodePropertyCharacterSet::StringsUnicodePropertyCharacterSet(_) => {
+                    Err(self.error("Invalid property name in character class", start))
+                }
+            };
+        }
+        Ok(ClassAtom::Character(self.parse_character_escape(start, true)?))
+    }
+
+    /// The contents of a class with the `v` flag and its `]`, and whether it may contain
+    /// strings.
+    fn parse_class_set(
+        &mut self,
+        start: usize,
+        negate: bool,
+    ) -> Result<(ClassSet<'a>, bool), OxcDiagnostic> {
+        let mut elements = Vec::new_in(self.allocator);
+        let mut strings = false;
+        loop {
+            match self.reader.peek() {
+                Some(']') => break,
+                Some(_) => {}
+                None => return Err(self.error("Unterminated character class", start)),
+            }
+            let operand_start = self.reader.offset();
+            if !elements.is_empty()
+                && (self.reader.starts_with("&&") || self.reader.starts_with("--"))
+            {
+                return Err(self.error("Invalid set operation in character class", operand_start));
+            }
+            let (operand, operand_strings) = self.parse_class_set_operand(start)?;
+            if elements.is_empty()
+                && (self.reader.starts_with("&&") || self.reader.starts_with("--"))
+            {
+                let class = self.parse_class_set_expression(
+                    start,
+                    negate,
+                    operand_start,
+                    operand,
+                    operand_strings,
+                )?;
+                return Ok(class);
+            }
+            let operand = match operand {
+                ClassSetOperand::Character(min)
+                    if self.reader.peek() == Some('-') && !self.reader.starts_with("--") =>
+                {
+                    self.reader.next();
+                    let ClassSetOperand::Character(max) = self.parse_class_set_operand(start)?.0
+                    else {
+                        return Err(self.error("Invalid character class", operand_start));
+                    };
+                    if min.value > max.value {
+                        return Err(
+                            self.error("Range out of order in character class", operand_start)
+                        );
+                    }
+                    let range = CharacterClassRange {
+                        span: self.span(operand_start),
+                        min: min.unbox(),
+                        max: max.unbox(),
+                    };
+                    elements.push(UnicodeSetsCharacterClassElement::CharacterClassRange(
+                        self.alloc(range),
+                    ));
+                    continue;
+                }
+                operand => operand,
+            };
+            strings |= operand_strings;
+            elements.push(match operand {
+                ClassSetOperand::Character(it) => UnicodeSetsCharacterClassElement::Character(it),
+                ClassSetOperand::ClassStringDisjunction(it) => {
+                    UnicodeSetsCharacterClassElement::ClassStringDisjunction(it)
+                }
+                ClassSetOperand::EscapeCharacterSet(it) => {
+                    UnicodeSetsCharacterClassElement::EscapeCharacterSet(it)
+                }
+                ClassSetOperand::ExpressionCharacterClass(it) => {
+                    UnicodeSetsCharacterClassElement::ExpressionCharacterClass(it)
+                }
+                ClassSetOperand::UnicodePropertyCharacterSet(it) => {
+                    UnicodeSetsCharacterClassElement::UnicodePropertyCharacterSet(it)
+                }
+                ClassSetOperand::UnicodeSetsCharacterClass(it) => {
+                    UnicodeSetsCharacterClassElement::UnicodeSetsCharacterClass(it)
+                }
+            });
+        }
+        self.reader.next();
+        if negate && strings {
+            return Err(self.error("Negated character class may contain strings", start));
+        }
+        let class = UnicodeSetsCharacterClass { span: self.span(start), negate, elements };
+        Ok((ClassSet::Union(class), strings))
+    }
+
+    /// `a&&b` or `a--b` after the first operand, and the `]` of the class.
+    fn parse_class_set_expression(
+        &mut self,
+        start: usize,
+        negate: bool,
+        first_start: usize,
+        first: ClassSetOperand<'a>,
+        mut strings: bool,
+    ) -> Result<(ClassSet<'a>, bool), OxcDiagnostic> {
+        let expression = if self.reader.starts_with("&&") {
+            let mut left = ClassIntersectionLeft::ClassSetOperand(self.alloc(first));
+            loop {
+                let operator_start = self.reader.offset();
+                self.reader.eat_str("&&");
+                if self.reader.peek() == Some('&') {
+                    self.reader.next();
+                    return Err(self.error("Invalid character in character class", operator_start));
+                }
+                let (right, right_strings) = self.parse_class_set_operand(start)?;
+                strings &= right_strings;
+                let intersection = ClassIntersection { span: self.span(first_start), left, right };
+                if !self.reader.starts_with("&&") {
+                    break ExpressionCharacterClassExpr::ClassIntersection(
+                        self.alloc(intersection),
+                    );
+                }
+                left = ClassIntersectionLeft::ClassIntersection(self.alloc(intersection));
+            }
+        } else {
+            let mut left = ClassSubtractionLeft::ClassSetOperand(self.alloc(first));
+            loop {
+                self.reader.eat_str("--");
+                let right = self.parse_class_set_operand(start)?.0;
+                let subtraction = ClassSubtraction { span: self.span(first_start), left, right };
+                if !self.reader.starts_with("--") {
+                    break ExpressionCharacterClassExpr::ClassSubtraction(self.alloc(subtraction));
+                }
+                left = ClassSubtractionLeft::ClassSubtraction(self.alloc(subtraction));
+            }
+        };
+        let end = self.reader.offset();
+        if !self.reader.eat(']') {
+            return Err(match self.reader.peek() {
+                Some(_) => self.error("Invalid set operation in character class", end),
+                None => self.error("Unterminated character class", start),
+            });
+        }
+        if negate && strings {
+            return Err(self.error("Negated character class may contain strings", start));
+        }
+        let class = ExpressionCharacterClass { span: self.span(start), negate, expression };
+        Ok((ClassSet::Expression(class), strings))
+    }
+
+    /// A nested class, a set, `\q{...}` or a character of the class at `class_start` with the
+    /// `v` flag, and whether it may contain strings.
+    fn parse_class_set_operand(
+        &mut self,
+        class_start: usize,
+    ) -> Result<(ClassSetOperand<'a>, bool), OxcDiagnostic> {
+        let start = self.reader.offset();
+        match self.reader.peek() {
+            Some('[') => {
+                self.reader.next();
+                let negate = self.reader.eat('^');
+                let (class, strings) = self.parse_class_set(start, negate)?;
+                let operand = match class {
+                    ClassSet::Union(class) => {
+                        ClassSetOperand::UnicodeSetsCharacterClass(self.alloc(class))
+                    }
+                    ClassSet::Expression(class) => {
+                        ClassSetOperand::ExpressionCharacterClass(self.alloc(class))
+                    }
+                };
+                return Ok((operand, strings));
+            }
+            Some('\\') => {
+                self.reader.next();
+                if self.reader.eat_str("q{") {
+                    return self.parse_class_string_disjunction(start);
+                }
+                if let Some(set) = self.parse_escape_character_set(start) {
+                    return Ok((ClassSetOperand::EscapeCharacterSet(self.alloc(set)), false));
+                }
+                if let Some(property) = self.parse_property_escape(start)? {
+                    let strings = matches!(
+                        property,
+                        UnicodePropertyCharacterSet::StringsUnicodePropertyCharacterSet(_)
+                    );
+                    let operand =
+                        ClassSetOperand::UnicodePropertyCharacterSet(self.alloc(property));
+                    return Ok((operand, strings));
+                }
+                self.reader.rewind(start);
+            }
+            Some(_) => {}
+            None => return Err(self.error("Unterminated character class", class_start)),
+        }
+        let character = self.parse_class_set_character()?;
+        Ok((ClassSetOperand::Character(self.alloc(character)), false))
+    }
+
+    /// A character of a class with the `v` flag, e.g. `a`, `\n` or `\&`.
+    fn parse_class_set_character(&mut self) -> Result<Character, OxcDiagnostic> {
+        let start = self.reader.offset();
+        let Some(c) = self.reader.next() else {
+            return Err(self.error("Unterminated character class", start));
+        };
+        if c == '\\' {
+            if self.reader.eat('b') {
+                return Ok(Character { span: self.span(start), value: 0x08 });
+            }
+            return self.parse_character_escape(start, true);
+        }
+        if CLASS_SET_SYNTAX_CHARACTERS.contains(c) {
+            return Err(self.error("Invalid character in character class", start));
+        }
+        if self.reader.peek() == Some(c) && CLASS_SET_RESERVED_DOUBLE_PUNCTUATORS.contains(c) {
+            self.reader.next();
+            return Err(self.error("Invalid set operation in character class", start));
+        }
+        Ok(Character { span: self.span(start), value: u32::from(c) })
+    }
+
+    /// `\q{a|bc}` after `\q{` at `start`, and whether it contains strings.
+    fn parse_class_string_disjunction(
+        &mut self,
+        start: usize,
+    ) -> Result<(ClassSetOperand<'a>, bool), OxcDiagnostic> {
+        let mut alternatives = Vec::new_in(self.allocator);
+        let mut strings = false;
+        loop {
+            let alternative_start = self.reader.offset();
+            let mut elements = Vec::new_in(self.allocator);
+            loop {
+                match self.reader.peek() {
+                    Some('|' | '}') => break,
+                    Some(_) => elements.push(self.parse_class_set_character()?),
+                    None => return Err(self.error("Unterminated class string disjunction", start)),
+                }
+            }
+            strings |= elements.len() != 1;
+            alternatives.push(StringAlternative { span: self.span(alternative_start), elements });
+            if self.reader.next() == Some('}') {
+                break;
+            }
+        }
+        let disjunction = ClassStringDisjunction { span: self.span(start), alternatives };
+        Ok((ClassSetOperand::ClassStringDisjunction(self.alloc(disjunction)), strings))
+    }
+}
+
+/// The range of `{min}`, `{min,}` or `{min,max}`.
+fn eat_quantifier_range(reader: &mut Reader) -> Option<(f64, f64)> {
+    if !reader.eat('{') {
+        return None;
+    }
+    let min = reader.eat_digits(10)?;
+    let max = if reader.eat(',') { reader.eat_digits(10).unwrap_or(f64::INFINITY) } else { min };
+    reader.eat('}').then_some((min, max))
+}
+
+/// `name>` of `(?<name>` or `\k<name>`, with the escapes of the name decoded.
+fn eat_group_name(reader: &mut Reader) -> Option<CompactStr> {
+    let mut name = String::new();
+    loop {
+        let c = match reader.next()? {
+            '>' => break,
+            '\\' => {
+                if !reader.eat('u') {
+                    return None;
+                }
+                char::from_u32(eat_unicode_escape(reader, true)?)?
+            }
+            c => c,
+        };
+        let valid = if name.is_empty() { is_identifier_start(c) } else { is_identifier_part(c) };
+        if !valid {
+            return None;
+        }
+        name.push(c);
+    }
+    (!name.is_empty()).then(|| CompactStr::from(name))
+}
+
+/// The value of a `\u` escape after `\u`: `XXXX`, and in the Unicode mode `{X...}` or a
+/// surrogate pair like `\uD83D\uDE00`. Nothing is eaten when there is none.
+fn eat_unicode_escape(reader: &mut Reader, unicode_mode: bool) -> Option<u32> {
+    let start = reader.offset();
+    if unicode_mode && reader.eat('{') {
+        let mut value = 0u32;
+        let mut digits = 0;
+        while let Some(digit) = reader.peek().and_then(|c| c.to_digit(16)) {
+            reader.next();
+            value = value.saturating_mul(16).saturating_add(digit);
+            digits += 1;
+        }
+        if digits == 0 || value > 0x0010_FFFF || !reader.eat('}') {
+            reader.rewind(start);
+            return None;
+        }
+        return Some(value);
+    }
+    let high = reader.eat_hex_digits(4)?;
+    if unicode_mode && (0xD800..0xDC00).contains(&high) {
+        let low_start = reader.offset();
+        if reader.eat_str("\\u") {
+            if let Some(low) = reader.eat_hex_digits(4).filter(|low| (0xDC00..0xE000).contains(low))
+            {
+                return Some(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00));
+            }
+        }
+        reader.rewind(low_start);
+    }
+    Some(high)
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use super::{Parser, PatternParser};
+    #[allow(clippy::wildcard_imports)]
+    use crate::ast::*;
+
+    fn parse(source_text: &str) -> Result<(), String> {
+        let allocator = Allocator::default();
+        Parser::new(&allocator, source_text).parse().map(|_| ()).map_err(|error| error.to_string())
+    }
+
+    #[test]
+    fn test_valid() {
+        for source_text in [
+            "/a/",
+            "/(?:)/",
+            "/a|b|/",
+            "/^ab*c+d?$/gimsy",
+            "/a{1}b{1,}c{1,2}?/d",
+            "/(a)(?<name>b)\\1\\k<name>/",
+            "/\\k<a>(?<a>b)/",
+            "/(?<a>x)|(?<a>y)/",
+            "/(?=a)(?!b)(?<=c)(?<!d)/",
+            "/\\b\\B\\d\\D\\s\\S\\w\\W./",
+            "/[a-z\\d-]/",
+            "/[^\\]-]/",
+            "/\\x41\\u0041\\u{41}\\n\\cA\\0/u",
+            "/\\p{L}\\P{Script=Greek}/u",
+            "/[\\p{Basic_Emoji}--\\q{x|yz}]/v",
+            "/[[a-z]&&[^aeiou]]/v",
+            "/[\\w--[\\d_]]/v",
+            "/[\\&\\-]/v",
+            // Annex B
+            "/]{}/",
+            "/{a}/",
+            "/\\1/",
+            "/\\8\\9/",
+            "/\\c/",
+            "/[\\c_]/",
+            "/\\k/",
+            "/\\p{/",
+            "/\\a/",
+            "/(?=a)*/",
+            "/[\\d-z]/",
+            "/😀+/",
+        ] {
+            assert_eq!(parse(source_text), Ok(()), "{source_text}");
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        for source_text in [
+            "a",
+            "/a/gg",
+            "/a/x",
+            "/a/uv",
+            "/(/",
+            "/)/",
+            "/[a/",
+            "/*/",
+            "/a**/",
+            "/^*/",
+            "/(?<=a)+/",
+            "/a{2,1}/",
+            "/{1}/",
+            "/{/u",
+            "/]/u",
+            "/\\1/u",
+            "/\\a/u",
+            "/(?a)/",
+            "/(?<1>a)/",
+            "/(?<a>x)(?<a>y)/",
+            "/\\k<b>(?<a>x)/",
+            "/(?=a)*/u",
+            "/[z-a]/",
+            "/[\\d-z]/u",
+            "/\\p{Basic_Emoji}/u",
+            "/\\p{Letter=L}/u",
+            "/[^\\q{ab}]/v",
+            "/[a&&&b]/v",
+            "/[a&&b--c]/v",
+            "/[(]/v",
+            "/\\/",
+        ] {
+            assert!(parse(source_text).is_err(), "{source_text}");
+        }
+    }
+
+    #[test]
+    fn test_ast() {
+        let allocator = Allocator::default();
+        let literal = Parser::new(&allocator, "/a(?:b|[^c-e])+?/g").with_span_offset(10).parse();
+        let literal = literal.unwrap();
+        assert!(literal.flags.global);
+        assert_eq!((literal.pattern.span.start, literal.pattern.span.end), (11, 26));
+        let elements = &literal.pattern.alternatives[0].elements;
+        assert_eq!(elements.len(), 2);
+        let Element::Quantifier(quantifier) = &elements[1] else { panic!() };
+        assert_eq!(
+            (quantifier.min, quantifier.max, quantifier.greedy),
+            (1.0, f64::INFINITY, false)
+        );
+        let QuantifiableElement::Group(group) = &quantifier.element else { panic!() };
+        assert_eq!(group.alternatives.len(), 2);
+
+        // The halves of the characters outside of the BMP without the `u` flag
+        let options = super::ParserOptions::default();
+        let pattern = PatternParser::new(&allocator, "😀", options).parse().unwrap();
+        assert_eq!(pattern.alternatives[0].elements.len(), 2);
+        let options = super::ParserOptions { unicode_mode: true, ..options };
+        let pattern = PatternParser::new(&allocator, "😀", options).parse().unwrap();
+        assert_eq!(pattern.alternatives[0].elements.len(), 1);
+    }
+}
@@ -1 +1,381 @@
+//! Visitor of the AST of a regular expression.
+//!
+//! Each `visit_*` method walks the children of its node by default, so implementations only
+//! override the methods of the nodes they look at and call the `walk_*` function to continue.
 
+use oxc_allocator::Vec;
+
+#[allow(clippy::wildcard_imports)]
+use crate::ast::*;
+
+#[allow(clippy::wildcard_imports)]
+use self::walk::*;
+
+pub trait Visit<'a>: Sized {
+    fn visit_regexp_literal(&mut self, it: &RegExpLiteral<'a>) {
+        walk_regexp_literal(self, it);
+    }
+
+    fn visit_pattern(&mut self, it: &Pattern<'a>) {
+        walk_pattern(self, it);
+    }
+
+    fn visit_alternatives(&mut self, it: &Vec<'a, Alternative<'a>>) {
+        walk_alternatives(self, it);
+    }
+
+    fn visit_alternative(&mut self, it: &Alternative<'a>) {
+        walk_alternative(self, it);
+    }
+
+    fn visit_element(&mut self, it: &Element<'a>) {
+        walk_element(self, it);
+    }
+
+    fn visit_assertion(&mut self, it: &Assertion<'a>) {
+        walk_assertion(self, it);
+    }
+
+    fn visit_edge_assertion(&mut self, _it: &EdgeAssertion) {}
+
+    fn visit_word_boundary_assertion(&mut self, _it: &WordBoundaryAssertion) {}
+
+    fn visit_lookahead_assertion(&mut self, it: &LookaheadAssertion<'a>) {
+        walk_lookahead_assertion(self, it);
+    }
+
+    fn visit_lookbehind_assertion(&mut self, it: &LookbehindAssertion<'a>) {
+        walk_lookbehind_assertion(self, it);
+    }
+
+    fn visit_quantifier(&mut self, it: &Quantifier<'a>) {
+        walk_quantifier(self, it);
+    }
+
+    fn visit_quantifiable_element(&mut self, it: &QuantifiableElement<'a>) {
+        walk_quantifiable_element(self, it);
+    }
+
+    fn visit_group(&mut self, it: &Group<'a>) {
+        walk_group(self, it);
+    }
+
+    fn visit_capturing_group(&mut self, it: &CapturingGroup<'a>) {
+        walk_capturing_group(self, it);
+    }
+
+    fn visit_backreference(&mut self, _it: &Backreference) {}
+
+    fn visit_character(&mut self, _it: &Character) {}
+
+    fn visit_character_set(&mut self, it: &CharacterSet<'a>) {
+        walk_character_set(self, it);
+    }
+
+    fn visit_escape_character_set(&mut self, _it: &EscapeCharacterSet) {}
+
+    fn visit_unicode_property_character_set(&mut self, it: &UnicodePropertyCharacterSet<'a>) {
+        walk_unicode_property_character_set(self, it);
+    }
+
+    fn visit_character_unicode_property_character_set(
+        &mut self,
+        _it: &CharacterUnicodePropertyCharacterSet,
+    ) {
+    }
+
+    fn visit_strings_unicode_property_character_set(
+        &mut self,
+        _it: &StringsUnicodePropertyCharacterSet,
+    ) {
+    }
+
+    fn visit_character_class(&mut self, it: &CharacterClass<'a>) {
+        walk_character_class(self, it);
+    }
+
+    fn visit_class_ranges_character_class(&mut self, it: &ClassRangesCharacterClass<'a>) {
+        walk_class_ranges_character_class(self, it);
+    }
+
+    fn visit_unicode_sets_character_class(&mut self, it: &UnicodeSetsCharacterClass<'a>) {
+        walk_unicode_sets_character_class(self, it);
+    }
+
+    fn visit_character_class_range(&mut self, it: &CharacterClassRange) {
+        walk_character_class_range(self, it);
+    }
+
+    fn visit_expression_character_class(&mut self, it: &ExpressionCharacterClass<'a>) {
+        walk_expression_character_class(self, it);
+    }
+
+    fn visit_class_intersection(&mut self, it: &ClassIntersection<'a>) {
+        walk_class_intersection(self, it);
+    }
+
+    fn visit_class_subtraction(&mut self, it: &ClassSubtraction<'a>) {
+        walk_class_subtraction(self, it);
+    }
+
+    fn visit_class_set_operand(&mut self, it: &ClassSetOperand<'a>) {
+        walk_class_set_operand(self, it);
+    }
+
+    fn visit_class_string_disjunction(&mut self, it: &ClassStringDisjunction<'a>) {
+        walk_class_string_disjunction(self, it);
+    }
+
+    fn visit_string_alternative(&mut self, it: &StringAlternative<'a>) {
+        walk_string_alternative(self, it);
+    }
+}
+
+pub mod walk {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+
+    pub fn walk_regexp_literal<'a, V: Visit<'a>>(visitor: &mut V, it: &RegExpLiteral<'a>) {
+        visitor.visit_pattern(&it.pattern);
+    }
+
+    pub fn walk_pattern<'a, V: Visit<'a>>(visitor: &mut V, it: &Pattern<'a>) {
+        visitor.visit_alternatives(&it.alternatives);
+    }
+
+    pub fn walk_alternatives<'a, V: Visit<'a>>(visitor: &mut V, it: &Vec<'a, Alternative<'a>>) {
+        for alternative in it {
+            visitor.visit_alternative(alternative);
+        }
+    }
+
+    pub fn walk_alternative<'a, V: Visit<'a>>(visitor: &mut V, it: &Alternative<'a>) {
+        for element in &it.elements {
+            visitor.visit_element(element);
+        }
+    }
+
+    pub fn walk_element<'a, V: Visit<'a>>(visitor: &mut V, it: &Element<'a>) {
+        match it {
+            Element::Assertion(it) => visitor.visit_assertion(it),
+            Element::QuantifiableElement(it) => visitor.visit_quantifiable_element(it),
+            Element::Quantifier(it) => visitor.visit_quantifier(it),
+        }
+    }
+
+    pub fn walk_assertion<'a, V: Visit<'a>>(visitor: &mut V, it: &Assertion<'a>) {
+        match it {
+            Assertion::BoundaryAssertion(it) => match &**it {
+                BoundaryAssertion::EdgeAssertion(it) => visitor.visit_edge_assertion(it),
+                BoundaryAssertion::WordBoundaryAssertion(it) => {
+                    visitor.visit_word_boundary_assertion(it);
+                }
+            },
+            Assertion::LookaroundAssertion(it) => match &**it {
+                LookaroundAssertion::LookaheadAssertion(it) => {
+                    visitor.visit_lookahead_assertion(it);
+                }
+                LookaroundAssertion::LookbehindAssertion(it) => {
+                    visitor.visit_lookbehind_assertion(it);
+                }
+            },
+        }
+    }
+
+    pub fn walk_lookahead_assertion<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &LookaheadAssertion<'a>,
+    ) {
+        visitor.visit_alternatives(&it.alternatives);
+    }
+
+    pub fn walk_lookbehind_assertion<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &LookbehindAssertion<'a>,
+    ) {
+        visitor.visit_alternatives(&it.alternatives);
+    }
+
+    pub fn walk_quantifier<'a, V: Visit<'a>>(visitor: &mut V, it: &Quantifier<'a>) {
+        visitor.visit_quantifiable_element(&it.element);
+    }
+
+    pub fn walk_quantifiable_element<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &QuantifiableElement<'a>,
+    ) {
+        match it {
+            QuantifiableElement::Backreference(it) => visitor.visit_backreference(it),
+            QuantifiableElement::CapturingGroup(it) => visitor.visit_capturing_group(it),
+            QuantifiableElement::Character(it) => visitor.visit_character(it),
+            QuantifiableElement::CharacterClass(it) => visitor.visit_character_class(it),
+            QuantifiableElement::CharacterSet(it) => visitor.visit_character_set(it),
+            QuantifiableElement::ExpressionCharacterClass(it) => {
+                visitor.visit_expression_character_class(it);
+            }
+            QuantifiableElement::Group(it) => visitor.visit_group(it),
+            QuantifiableElement::LookaheadAssertion(it) => visitor.visit_lookahead_assertion(it),
+        }
+    }
+
+    pub fn walk_group<'a, V: Visit<'a>>(visitor: &mut V, it: &Group<'a>) {
+        visitor.visit_alternatives(&it.alternatives);
+    }
+
+    pub fn walk_capturing_group<'a, V: Visit<'a>>(visitor: &mut V, it: &CapturingGroup<'a>) {
+        visitor.visit_alternatives(&it.alternatives);
+    }
+
+    pub fn walk_character_set<'a, V: Visit<'a>>(visitor: &mut V, it: &CharacterSet<'a>) {
+        match it {
+            CharacterSet::AnyCharacterSet(_) => {}
+            CharacterSet::EscapeCharacterSet(it) => visitor.visit_escape_character_set(it),
+            CharacterSet::UnicodePropertyCharacterSet(it) => {
+                visitor.visit_unicode_property_character_set(it);
+            }
+        }
+    }
+
+    pub fn walk_unicode_property_character_set<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &UnicodePropertyCharacterSet<'a>,
+    ) {
+        match it {
+            UnicodePropertyCharacterSet::CharacterUnicodePropertyCharacterSet(it) => {
+                visitor.visit_character_unicode_property_character_set(it);
+            }
+            UnicodePropertyCharacterSet::StringsUnicodePropertyCharacterSet(it) => {
+                visitor.visit_strings_unicode_property_character_set(it);
+            }
+        }
+    }
+
+    pub fn walk_character_class<'a, V: Visit<'a>>(visitor: &mut V, it: &CharacterClass<'a>) {
+        match it {
+            CharacterClass::ClassRangesCharacterClass(it) => {
+                visitor.visit_class_ranges_character_class(it);
+            }
+            CharacterClass::UnicodeSetsCharacterClass(it) => {
+                visitor.visit_unicode_sets_character_class(it);
+            }
+        }
+    }
+
+    pub fn walk_class_ranges_character_class<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &ClassRangesCharacterClass<'a>,
+    ) {
+        for element in &it.elements {
+            match element {
+                ClassRangesCharacterClassElement::Character(it) => visitor.visit_character(it),
+                ClassRangesCharacterClassElement::CharacterClassRange(it) => {
+                    visitor.visit_character_class_range(it);
+                }
+                ClassRangesCharacterClassElement::CharacterUnicodePropertyCharacterSet(it) => {
+                    visitor.visit_character_unicode_property_character_set(it);
+                }
+                ClassRangesCharacterClassElement::EscapeCharacterSet(it) => {
+                    visitor.visit_escape_character_set(it);
+                }
+            }
+        }
+    }
+
+    pub fn walk_unicode_sets_character_class<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &UnicodeSetsCharacterClass<'a>,
+    ) {
+        for element in &it.elements {
+            match element {
+                UnicodeSetsCharacterClassElement::Character(it) => visitor.visit_character(it),
+                UnicodeSetsCharacterClassElement::CharacterClassRange(it) => {
+                    visitor.visit_character_class_range(it);
+                }
+                UnicodeSetsCharacterClassElement::ClassStringDisjunction(it) => {
+                    visitor.visit_class_string_disjunction(it);
+                }
+                UnicodeSetsCharacterClassElement::EscapeCharacterSet(it) => {
+                    visitor.visit_escape_character_set(it);
+                }
+                UnicodeSetsCharacterClassElement::ExpressionCharacterClass(it) => {
+                    visitor.visit_expression_character_class(it);
+                }
+                UnicodeSetsCharacterClassElement::UnicodePropertyCharacterSet(it) => {
+                    visitor.visit_unicode_property_character_set(it);
+                }
+                UnicodeSetsCharacterClassElement::UnicodeSetsCharacterClass(it) => {
+                    visitor.visit_unicode_sets_character_class(it);
+                }
+            }
+        }
+    }
+
+    pub fn walk_character_class_range<'a, V: Visit<'a>>(visitor: &mut V, it: &CharacterClassRange) {
+        visitor.visit_character(&it.min);
+        visitor.visit_character(&it.max);
+    }
+
+    pub fn walk_expression_character_class<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &ExpressionCharacterClass<'a>,
+    ) {
+        match &it.expression {
+            ExpressionCharacterClassExpr::ClassIntersection(it) => {
+                visitor.visit_class_intersection(it);
+            }
+            ExpressionCharacterClassExpr::ClassSubtraction(it) => {
+                visitor.visit_class_subtraction(it);
+            }
+        }
+    }
+
+    pub fn walk_class_intersection<'a, V: Visit<'a>>(visitor: &mut V, it: &ClassIntersection<'a>) {
+        match &it.left {
+            ClassIntersectionLeft::ClassIntersection(it) => visitor.visit_class_intersection(it),
+            ClassIntersectionLeft::ClassSetOperand(it) => visitor.visit_class_set_operand(it),
+        }
+        visitor.visit_class_set_operand(&it.right);
+    }
+
+    pub fn walk_class_subtraction<'a, V: Visit<'a>>(visitor: &mut V, it: &ClassSubtraction<'a>) {
+        match &it.left {
+            ClassSubtractionLeft::ClassSetOperand(it) => visitor.visit_class_set_operand(it),
+            ClassSubtractionLeft::ClassSubtraction(it) => visitor.visit_class_subtraction(it),
+        }
+        visitor.visit_class_set_operand(&it.right);
+    }
+
+    pub fn walk_class_set_operand<'a, V: Visit<'a>>(visitor: &mut V, it: &ClassSetOperand<'a>) {
+        match it {
+            ClassSetOperand::Character(it) => visitor.visit_character(it),
+            ClassSetOperand::ClassStringDisjunction(it) => {
+                visitor.visit_class_string_disjunction(it);
+            }
+            ClassSetOperand::EscapeCharacterSet(it) => visitor.visit_escape_character_set(it),
+            ClassSetOperand::ExpressionCharacterClass(it) => {
+                visitor.visit_expression_character_class(it);
+            }
+            ClassSetOperand::UnicodePropertyCharacterSet(it) => {
+                visitor.visit_unicode_property_character_set(it);
+            }
+            ClassSetOperand::UnicodeSetsCharacterClass(it) => {
+                visitor.visit_unicode_sets_character_class(it);
+            }
+        }
+    }
+
+    pub fn walk_class_string_disjunction<'a, V: Visit<'a>>(
+        visitor: &mut V,
+        it: &ClassStringDisjunction<'a>,
+    ) {
+        for alternative in &it.alternatives {
+            visitor.visit_string_alternative(alternative);
+        }
+    }
+
+    pub fn walk_string_alternative<'a, V: Visit<'a>>(visitor: &mut V, it: &StringAlternative<'a>) {
+        for character in &it.elements {
+            visitor.visit_character(character);
+        }
+    }
+}
@@ -7,20 +7,22 @@ use std::{
 
 use log::debug;
 use oxc_allocator::Allocator;
-use oxc_diagnostics::{Error, NamedSource, Severity};
+use oxc_diagnostics::{eslint_rule_id, Error, NamedSource, RelatedLabel, Severity};
 use oxc_linter::{
+    docs::rule_docs_url,
     partial_loader::{
-        AstroPartialLoader, JavaScriptSource, SveltePartialLoader, VuePartialLoader,
-        LINT_PARTIAL_LOADER_EXT,
+        AstroPartialLoader, HtmlPartialLoader, JavaScriptSource, SveltePartialLoader,
+        VuePartialLoader, LINT_PARTIAL_LOADER_EXT, NOTEBOOK_EXT,
     },
-    LintContext, Linter,
+    Fix, LintContext, Linter, Message,
 };
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
-use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
 use ropey::Rope;
 use tower_lsp::lsp_types::{
-    self, DiagnosticRelatedInformation, DiagnosticSeverity, Position, Range, Url,
+    self, CodeDescription, DiagnosticRelatedInformation, DiagnosticSeverity, NumberOrString,
+    Position, Range, Url,
 };
 
 #[derive(Debug)]
@@ -29,7 +31,11 @@ struct ErrorWithPosition {
     pub end_pos: Position,
     pub miette_err: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestionContent>,
+    pub rule: Option<RuleReport>,
     pub labels_with_pos: Vec<LabeledSpanWithPosition>,
+    /// The labels in other files
+    pub related: Vec<DiagnosticRelatedInformation>,
 }
 
 #[derive(Debug)]
@@ -40,12 +46,8 @@ struct LabeledSpanWithPosition {
 }
 
 impl ErrorWithPosition {
-    pub fn new(
-        error: Error,
-        text: &str,
-        fixed_content: Option<FixedContent>,
-        start: usize,
-    ) -> Self {
+    pub fn new(report: ErrorReport, text: &str, start: usize) -> Self {
+        let ErrorReport { error, fixed_content, suggestions, rule, related } = report;
         let labels = error.labels().map_or(vec![], Iterator::collect);
         let labels_with_pos: Vec<LabeledSpanWithPosition> = labels
             .iter()
@@ -64,7 +66,16 @@ impl ErrorWithPosition {
         let start_pos = labels_with_pos[0].start_pos;
         let end_pos = labels_with_pos[labels_with_pos.len() - 1].end_pos;
 
-        Self { miette_err: error, start_pos, end_pos, labels_with_pos, fixed_content }
+        Self {
+            miette_err: error,
+            start_pos,
+            end_pos,
+            labels_with_pos,
+            fixed_content,
+            suggestions,
+            rule,
+            related,
+        }
     }
 
     fn to_lsp_diagnostic(&self, path: &PathBuf) -> lsp_types::Diagnostic {
@@ -72,7 +83,7 @@ impl ErrorWithPosition {
             Some(Severity::Error) => Some(lsp_types::DiagnosticSeverity::ERROR),
             _ => Some(lsp_types::DiagnosticSeverity::WARNING),
         };
-        let related_information = Some(
+        let labels_information = Some(
             self.labels_with_pos
                 .iter()
                 .map(|labeled_span| lsp_types::DiagnosticRelatedInformation {
@@ -93,7 +104,7 @@ impl ErrorWithPosition {
                 })
                 .collect(),
         );
-        let range = related_information.as_ref().map_or(
+        let range = labels_information.as_ref().map_or(
             Range { start: self.start_pos, end: self.end_pos },
             |infos: &Vec<DiagnosticRelatedInformation>| {
                 let mut ret_range = Range {
@@ -109,18 +120,29 @@ impl ErrorWithPosition {
             },
         );
 
+        let related_information = labels_information.map(|mut information| {
+            information.extend(self.related.iter().cloned());
+            information
+        });
+
         let message = self.miette_err.help().map_or_else(
             || self.miette_err.to_string(),
             |help| format!("{}\nhelp: {}", self.miette_err, help),
         );
 
+        let code = self.rule.as_ref().map(|rule| NumberOrString::String(rule.id.clone()));
+        let code_description = self
+            .rule
+            .as_ref()
+            .and_then(|rule| Url::parse(&rule.docs_url).ok().map(|href| CodeDescription { href }));
+
         lsp_types::Diagnostic {
             range,
             severity,
-            code: None,
+            code,
             message,
             source: Some("oxc".into()),
-            code_description: None,
+            code_description,
             related_information,
             tags: None,
             data: None,
@@ -131,6 +153,8 @@ impl ErrorWithPosition {
         DiagnosticReport {
             diagnostic: self.to_lsp_diagnostic(path),
             fixed_content: self.fixed_content,
+            suggestions: self.suggestions,
+            rule: self.rule,
         }
     }
 }
@@ -139,11 +163,23 @@ impl ErrorWithPosition {
 pub struct DiagnosticReport {
     pub diagnostic: lsp_types::Diagnostic,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestionContent>,
+    /// The rule which reported the problem, `None` for syntax errors
+    pub rule: Option<RuleReport>,
 }
 #[derive(Debug)]
 struct ErrorReport {
     pub error: Error,
     pub fixed_content: Option<FixedContent>,
+    pub suggestions: Vec<SuggestionContent>,
+    pub rule: Option<RuleReport>,
+    pub related: Vec<DiagnosticRelatedInformation>,
+}
+
+impl ErrorReport {
+    fn syntax_error(error: Error) -> Self {
+        Self { error, fixed_content: None, suggestions: vec![], rule: None, related: vec![] }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -152,6 +188,25 @@ pub struct FixedContent {
     pub range: Range,
 }
 
+#[derive(Debug, Clone)]
+pub struct SuggestionContent {
+    pub description: String,
+    pub fixed_content: FixedContent,
+}
+
+#[derive(Debug, Clone)]
+pub struct RuleReport {
+    /// The ESLint id of the rule, e.g. `no-debugger` or `unicorn/no-null`
+    pub id: String,
+    /// The name of the rule with its plugin, e.g. `eslint/no-debugger`
+    pub name: String,
+    /// The URL of the documentation of the rule
+    pub docs_url: String,
+    /// The `eslint-disable-next-line` comment disabling the rule on the line of the problem, with
+    /// a `-- TODO` placeholder for the reason
+    pub disable_comment: FixedContent,
+}
+
 pub struct IsolatedLintHandler {
     linter: Arc<Linter>,
 }
@@ -177,15 +232,17 @@ impl IsolatedLintHandler {
                         continue;
                     };
 
+                    let uri = lsp_types::Url::from_file_path(path).unwrap();
                     let related_information = Some(vec![DiagnosticRelatedInformation {
                         location: lsp_types::Location {
-                            uri: lsp_types::Url::from_file_path(path).unwrap(),
+                            uri: uri.clone(),
                             range: d.diagnostic.range,
                         },
                         message: "original diagnostic".to_string(),
                     }]);
                     for r in related_info {
-                        if r.location.range == d.diagnostic.range {
+                        // The labels in other files are only shown as related information
+                        if r.location.range == d.diagnostic.range || r.location.uri != uri {
                             continue;
                         }
                         inverted_diagnostics.push(DiagnosticReport {
@@ -201,6 +258,8 @@ impl IsolatedLintHandler {
                                 data: None,
                             },
                             fixed_content: None,
+                            suggestions: vec![],
+                            rule: None,
                         });
                     }
                 }
@@ -246,10 +305,12 @@ impl IsolatedLintHandler {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "html" | "htm" => Some(HtmlPartialLoader::new(source_text).parse()),
             _ => None,
         }
     }
 
+    #[allow(clippy::cast_possible_truncation)]
     fn lint_path(
         linter: &Linter,
         path: &Path,
@@ -277,10 +338,7 @@ impl IsolatedLintHandler {
                 let reports = ret
                     .errors
                     .into_iter()
-                    .map(|diagnostic| ErrorReport {
-                        error: Error::from(diagnostic),
-                        fixed_content: None,
-                    })
+                    .map(|diagnostic| ErrorReport::syntax_error(Error::from(diagnostic)))
                     .collect();
                 return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
             };
@@ -296,10 +354,7 @@ impl IsolatedLintHandler {
                 let reports = semantic_ret
                     .errors
                     .into_iter()
-                    .map(|diagnostic| ErrorReport {
-                        error: Error::from(diagnostic),
-                        fixed_content: None,
-                    })
+                    .map(|diagnostic| ErrorReport::syntax_error(Error::from(diagnostic)))
                     .collect();
                 return Some(Self::wrap_diagnostics(path, &original_source_text, reports, start));
             };
@@ -314,27 +369,30 @@ impl IsolatedLintHandler {
             let reports = result
                 .into_iter()
                 .map(|msg| {
-                    let fixed_content = msg.fix.map(|f| FixedContent {
-                        code: f.content.to_string(),
-                        range: Range {
-                            start: offset_to_position(
-                                f.span.start as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                            end: offset_to_position(
-                                f.span.end as usize + start,
-                                javascript_source_text,
-                            )
-                            .unwrap_or_default(),
-                        },
-                    });
-
-                    ErrorReport { error: Error::from(msg.error), fixed_content }
+                    let msg = msg.move_offset(start as u32);
+                    let fixed_content =
+                        msg.fix.as_ref().map(|fix| FixedContent::new(fix, &original_source_text));
+                    let suggestions = msg
+                        .suggestions
+                        .iter()
+                        .map(|suggestion| SuggestionContent {
+                            description: suggestion.description.to_string(),
+                            fixed_content: FixedContent::new(
+                                &suggestion.fix,
+                                &original_source_text,
+                            ),
+                        })
+                        .collect();
+                    let rule = RuleReport::new(&msg, &original_source_text);
+                    // The spans of the messages are relative to the file now
+                    let related =
+                        msg.error.related.iter().filter_map(related_information).collect();
+                    let error = Error::from(msg.error);
+                    ErrorReport { error, fixed_content, suggestions, rule, related }
                 })
                 .collect::<Vec<ErrorReport>>();
             let (_, errors_with_position) =
-                Self::wrap_diagnostics(path, &original_source_text, reports, start);
+                Self::wrap_diagnostics(path, &original_source_text, reports, 0);
             diagnostics.extend(errors_with_position);
         }
 
@@ -351,26 +409,77 @@ impl IsolatedLintHandler {
         let diagnostics = reports
             .into_iter()
             .map(|report| {
-                ErrorWithPosition::new(
-                    report.error.with_source_code(Arc::clone(&source)),
-                    source_text,
-                    report.fixed_content,
-                    start,
-                )
+                let error = report.error.with_source_code(Arc::clone(&source));
+                ErrorWithPosition::new(ErrorReport { error, ..report }, source_text, start)
             })
             .collect();
         (path.to_path_buf(), diagnostics)
     }
 }
 
+impl FixedContent {
+    fn new(fix: &Fix, source_text: &str) -> Self {
+        let start = offset_to_position(fix.span.start as usize, source_text).unwrap_or_default();
+        let end = offset_to_position(fix.span.end as usize, source_text).unwrap_or_default();
+        Self { code: fix.content.to_string(), range: Range { start, end } }
+    }
+}
+
+impl RuleReport {
+    fn new(message: &Message, source_text: &str) -> Option<Self> {
+        let rule_name = message.rule_name()?;
+        let plugin_name = message.plugin_name()?;
+        // The messages of the rules start with their id, e.g. `eslint(no-debugger): `
+        let id = message
+            .error
+            .message
+            .split_once(':')
+            .map(|(id, _)| id)
+            .filter(|id| id.ends_with(&format!("({rule_name})")))
+            .map_or_else(|| rule_name.to_string(), eslint_rule_id);
+
+        let line_start = source_text[..message.start() as usize].rfind('\n').map_or(0, |i| i + 1);
+        let line = &source_text[line_start..];
+        let indent = &line[..line.len() - line.trim_start_matches([' ', '\t']).len()];
+        let newline = if source_text.contains("\r\n") { "\r\n" } else { "\n" };
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(line_start as u32, line_start as u32);
+        let comment = format!("{indent}// eslint-disable-next-line {id} -- TODO{newline}");
+        let disable_comment = FixedContent::new(&Fix::new(comment, span), source_text);
+        Some(Self {
+            id,
+            name: format!("{plugin_name}/{rule_name}"),
+            docs_url: rule_docs_url(plugin_name, rule_name),
+            disable_comment,
+        })
+    }
+}
+
 fn get_valid_extensions() -> Vec<&'static str> {
     VALID_EXTENSIONS
         .iter()
         .chain(LINT_PARTIAL_LOADER_EXT.iter())
         .copied()
+        // Notebooks are only supported by the CLI, which maps diagnostics back to their cells
+        .filter(|ext| *ext != NOTEBOOK_EXT)
         .collect::<Vec<&'static str>>()
 }
 
+/// The related information of a label in another file, `None` when the file cannot be read.
+fn related_information(related: &RelatedLabel) -> Option<DiagnosticRelatedInformation> {
+    let RelatedLabel { path, label } = related;
+    let source_text = fs::read_to_string(path).ok()?;
+    let start = offset_to_position(label.offset(), &source_text)?;
+    let end = offset_to_position(label.offset() + label.len(), &source_text)?;
+    Some(DiagnosticRelatedInformation {
+        location: lsp_types::Location {
+            uri: Url::from_file_path(path).ok()?,
+            range: Range { start, end },
+        },
+        message: label.label().unwrap_or_default().to_string(),
+    })
+}
+
 #[allow(clippy::cast_possible_truncation)]
 fn offset_to_position(offset: usize, source_text: &str) -> Option<Position> {
     let rope = Rope::from_str(source_text);
@@ -1,36 +1,53 @@
 mod linter;
 
-use std::{collections::HashMap, fmt::Debug, path::PathBuf, str::FromStr};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Write},
+    path::PathBuf,
+    str::FromStr,
+};
 
 use dashmap::DashMap;
 use futures::future::join_all;
 use globset::Glob;
 use ignore::gitignore::Gitignore;
 use log::{debug, error, info};
-use oxc_linter::{LintOptions, Linter};
+use oxc_linter::{docs::RuleDocs, LintOptions, Linter, CONFIG_FILE_NAME};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use tokio::sync::{Mutex, OnceCell, RwLock, SetError};
 use tower_lsp::{
     jsonrpc::{Error, ErrorCode, Result},
     lsp_types::{
         CodeAction, CodeActionKind, CodeActionOptions, CodeActionOrCommand, CodeActionParams,
-        CodeActionProviderCapability, CodeActionResponse, ConfigurationItem, Diagnostic,
-        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidCloseTextDocumentParams,
-        DidOpenTextDocumentParams, DidSaveTextDocumentParams, InitializeParams, InitializeResult,
-        InitializedParams, OneOf, ServerCapabilities, ServerInfo, TextDocumentSyncCapability,
-        TextDocumentSyncKind, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
-        WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
+        CodeActionProviderCapability, CodeActionResponse, Command, ConfigurationItem, Diagnostic,
+        DiagnosticOptions, DiagnosticServerCapabilities, DidChangeConfigurationParams,
+        DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+        DidChangeWatchedFilesRegistrationOptions, DidCloseTextDocumentParams,
+        DidOpenTextDocumentParams, DidSaveTextDocumentParams, DocumentDiagnosticParams,
+        DocumentDiagnosticReport, DocumentDiagnosticReportResult, ExecuteCommandOptions,
+        ExecuteCommandParams, FileSystemWatcher, FullDocumentDiagnosticReport, GlobPattern, Hover,
+        HoverContents, HoverParams, HoverProviderCapability, InitializeParams, InitializeResult,
+        InitializedParams, MarkupContent, MarkupKind, MessageType, OneOf, Range, Registration,
+        RelatedFullDocumentDiagnosticReport, ServerCapabilities, ServerInfo, ShowDocumentParams,
+        TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Unregistration, Url,
+        WorkDoneProgressOptions, WorkspaceDiagnosticParams, WorkspaceDiagnosticReport,
+        WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport, WorkspaceEdit,
+        WorkspaceFoldersServerCapabilities, WorkspaceFullDocumentDiagnosticReport,
+        WorkspaceServerCapabilities,
     },
     Client, LanguageServer, LspService, Server,
 };
 
-use crate::linter::{DiagnosticReport, ServerLinter};
+use crate::linter::{DiagnosticReport, FixedContent, RuleReport, ServerLinter};
 
 struct Backend {
     client: Client,
     root_uri: OnceCell<Option<Url>>,
     server_linter: RwLock<ServerLinter>,
     diagnostics_report_map: DashMap<String, Vec<DiagnosticReport>>,
+    /// The text of the open documents
+    documents: DashMap<String, String>,
     options: Mutex<Options>,
     gitignore_glob: Mutex<Option<Gitignore>>,
 }
@@ -115,13 +132,30 @@ impl LanguageServer for Backend {
                 }),
                 code_action_provider: Some(CodeActionProviderCapability::Options(
                     CodeActionOptions {
-                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX, FIX_ALL_KIND]),
                         work_done_progress_options: WorkDoneProgressOptions {
                             work_done_progress: None,
                         },
                         resolve_provider: None,
                     },
                 )),
+                hover_provider: Some(HoverProviderCapability::Simple(true)),
+                diagnostic_provider: Some(DiagnosticServerCapabilities::Options(
+                    DiagnosticOptions {
+                        identifier: Some("oxc".into()),
+                        inter_file_dependencies: false,
+                        workspace_diagnostics: true,
+                        work_done_progress_options: WorkDoneProgressOptions {
+                            work_done_progress: None,
+                        },
+                    },
+                )),
+                execute_command_provider: Some(ExecuteCommandOptions {
+                    commands: vec![SHOW_RULE_DOCS_COMMAND.into()],
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: None,
+                    },
+                }),
                 ..ServerCapabilities::default()
             },
         })
@@ -169,11 +203,35 @@ impl LanguageServer for Backend {
                 .collect::<Vec<_>>();
             self.publish_all_diagnostics(&cleared_diagnostics).await;
         }
-        *self.options.lock().await = changed_options;
+        let config_path_changed = {
+            let mut options = self.options.lock().await;
+            let changed = options.config_path != changed_options.config_path;
+            *options = changed_options;
+            changed
+        };
+        if config_path_changed {
+            let unregistration = Unregistration {
+                id: CONFIG_FILES_WATCHER_ID.into(),
+                method: "workspace/didChangeWatchedFiles".into(),
+            };
+            if let Err(err) = self.client.unregister_capability(vec![unregistration]).await {
+                debug!("failed to stop watching the configuration files: {err:?}");
+            }
+            self.watch_config_files().await;
+            self.init_linter_config().await;
+            self.revalidate().await;
+        }
     }
 
     async fn initialized(&self, _params: InitializedParams) {
         debug!("oxc initialized.");
+        self.watch_config_files().await;
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        debug!("configuration files changed: {:?}", params.changes);
+        self.init_linter_config().await;
+        self.revalidate().await;
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -197,6 +255,9 @@ impl LanguageServer for Backend {
     /// When the document changed, it may not be written to disk, so we should
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
+        if let Some(change) = params.content_changes.first() {
+            self.documents.insert(params.text_document.uri.to_string(), change.text.clone());
+        }
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -216,6 +277,8 @@ impl LanguageServer for Backend {
     }
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
+        self.documents
+            .insert(params.text_document.uri.to_string(), params.text_document.text.clone());
         let run_level = { self.options.lock().await.get_lint_level() };
         if run_level < SyntheticRunLevel::OnType {
             return;
@@ -230,47 +293,178 @@ impl LanguageServer for Backend {
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = params.text_document.uri.to_string();
         self.diagnostics_report_map.remove(&uri);
+        self.documents.remove(&uri);
+    }
+
+    async fn diagnostic(
+        &self,
+        params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        let uri = params.text_document.uri;
+        // The diagnostics of the open documents are published when they change
+        let items = if self.documents.contains_key(&uri.to_string()) {
+            vec![]
+        } else {
+            self.pull_diagnostics(&uri).await.unwrap_or_default()
+        };
+        Ok(DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+            RelatedFullDocumentDiagnosticReport {
+                related_documents: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: None,
+                    items,
+                },
+            },
+        )))
+    }
+
+    /// The diagnostics of all the files of the workspace which are not open, the diagnostics of
+    /// the open documents are published when they change.
+    async fn workspace_diagnostic(
+        &self,
+        _params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let mut items = vec![];
+        if let Some(root_path) =
+            self.root_uri.get().cloned().flatten().and_then(|uri| uri.to_file_path().ok())
+        {
+            for entry in ignore::WalkBuilder::new(root_path).build().flatten() {
+                if entry.file_type().map_or(true, |file_type| file_type.is_dir()) {
+                    continue;
+                }
+                let Ok(uri) = Url::from_file_path(entry.path()) else {
+                    continue;
+                };
+                if self.documents.contains_key(&uri.to_string()) {
+                    continue;
+                }
+                let Some(diagnostics) = self.pull_diagnostics(&uri).await else {
+                    continue;
+                };
+                items.push(WorkspaceDocumentDiagnosticReport::Full(
+                    WorkspaceFullDocumentDiagnosticReport {
+                        uri,
+                        version: None,
+                        full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                            result_id: None,
+                            items: diagnostics,
+                        },
+                    },
+                ));
+            }
+        }
+        Ok(WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items }))
+    }
+
+    async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params.position;
+        let uri = params.text_document_position_params.text_document.uri;
+        let Some(reports) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+        let reports = reports
+            .iter()
+            .filter(|report| {
+                let range = report.diagnostic.range;
+                range.start <= position && position <= range.end
+            })
+            .filter_map(|report| Some((report.rule.as_ref()?, report.diagnostic.range)))
+            .collect::<Vec<_>>();
+        let Some((_, range)) = reports.first() else {
+            return Ok(None);
+        };
+        let value = reports
+            .iter()
+            .map(|(rule, _)| rule_docs_markdown(rule))
+            .collect::<Vec<_>>()
+            .join("\n\n---\n\n");
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value }),
+            range: Some(*range),
+        }))
+    }
+
+    async fn execute_command(&self, params: ExecuteCommandParams) -> Result<Option<Value>> {
+        if params.command != SHOW_RULE_DOCS_COMMAND {
+            return Err(Error::method_not_found());
+        }
+        let Some(uri) =
+            params.arguments.first().and_then(Value::as_str).and_then(|url| Url::parse(url).ok())
+        else {
+            return Err(Error::invalid_params("expected the URL of the documentation"));
+        };
+        let params =
+            ShowDocumentParams { uri, external: Some(true), take_focus: None, selection: None };
+        if let Err(err) = self.client.show_document(params).await {
+            error!("failed to show the documentation: {err:?}");
+        }
+        Ok(None)
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = params.text_document.uri;
+        let Some(reports) = self.diagnostics_report_map.get(&uri.to_string()) else {
+            return Ok(None);
+        };
+        let only = params.context.only.as_deref();
+        let wants = |kind: &CodeActionKind| {
+            only.map_or(true, |only| only.iter().any(|o| kind.as_str().starts_with(o.as_str())))
+        };
 
-        if let Some(value) = self.diagnostics_report_map.get(&uri.to_string()) {
-            if let Some(report) = value
+        let mut actions = vec![];
+        if wants(&CodeActionKind::QUICKFIX) {
+            let reports = reports
                 .iter()
-                .find(|r| r.diagnostic.range == params.range && r.fixed_content.is_some())
-            {
-                let title =
-                    report.diagnostic.message.split(':').next().map_or_else(
+                .filter(|report| report.rule.is_some() || report.fixed_content.is_some())
+                .filter(|report| ranges_overlap(&report.diagnostic.range, &params.range));
+            for report in reports {
+                let diagnostic = &report.diagnostic;
+                if let Some(fixed_content) = &report.fixed_content {
+                    let title = diagnostic.message.split(':').next().map_or_else(
                         || "Fix this problem".into(),
                         |s| format!("Fix this {s} problem"),
                     );
-
-                let fixed_content = report.fixed_content.clone().unwrap();
-
-                return Ok(Some(vec![CodeActionOrCommand::CodeAction(CodeAction {
-                    title,
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    is_preferred: Some(true),
-                    edit: Some(WorkspaceEdit {
-                        changes: Some(HashMap::from([(
-                            uri,
-                            vec![TextEdit {
-                                range: fixed_content.range,
-                                new_text: fixed_content.code,
-                            }],
-                        )])),
-                        ..WorkspaceEdit::default()
-                    }),
-                    disabled: None,
-                    data: None,
-                    diagnostics: None,
-                    command: None,
-                })]));
+                    actions.push(
+                        code_action(title, CodeActionKind::QUICKFIX, &uri, vec![fixed_content])
+                            .preferred()
+                            .with_diagnostic(diagnostic),
+                    );
+                }
+                for suggestion in &report.suggestions {
+                    let edits = vec![&suggestion.fixed_content];
+                    let title = suggestion.description.clone();
+                    actions.push(
+                        code_action(title, CodeActionKind::QUICKFIX, &uri, edits)
+                            .with_diagnostic(diagnostic),
+                    );
+                }
+                if let Some(rule) = &report.rule {
+                    let title = format!("Disable {} for this line", rule.id);
+                    let edits = vec![&rule.disable_comment];
+                    actions.push(
+                        code_action(title, CodeActionKind::QUICKFIX, &uri, edits)
+                            .with_diagnostic(diagnostic),
+                    );
+                    actions.push(show_rule_docs_action(&rule.id, &rule.docs_url));
+                }
             }
         }
 
-        Ok(None)
+        // Offered with the quick fixes of a fixable problem, and as a source action which editors
+        // may run on save, e.g. with `"editor.codeActionsOnSave": { "source.fixAll.oxc": true }`
+        let fix_all = fix_all_edits(&reports);
+        let has_quick_fix = actions.iter().any(|action| action.0.is_preferred == Some(true));
+        if !fix_all.is_empty() && (has_quick_fix || (only.is_some() && wants(&FIX_ALL_KIND))) {
+            let kind = if has_quick_fix { CodeActionKind::QUICKFIX } else { FIX_ALL_KIND };
+            actions.push(code_action("Fix all auto-fixable problems".into(), kind, &uri, fix_all));
+        }
+
+        if actions.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(
+            actions.into_iter().map(|action| CodeActionOrCommand::CodeAction(action.0)).collect(),
+        ))
     }
 }
 
@@ -329,6 +523,9 @@ impl Backend {
         .await;
     }
 
+    /// Create the linter with the configuration file at `configPath` in the workspace, or with
+    /// the nearest `.oxlintrc.json` files of the linted files when it does not exist.
+    /// The linter is kept when the configuration is invalid.
     async fn init_linter_config(&self) {
         let Some(Some(uri)) = self.root_uri.get() else {
             return;
@@ -336,22 +533,91 @@ impl Backend {
         let Ok(root_path) = uri.to_file_path() else {
             return;
         };
-        let mut config_path = None;
-        let config = root_path.join(self.options.lock().await.get_config_path().unwrap());
-        if config.exists() {
-            config_path = Some(config);
+        let config_path = self
+            .options
+            .lock()
+            .await
+            .get_config_path()
+            .map(|config_path| root_path.join(config_path))
+            .filter(|config_path| config_path.exists());
+        let nested_configs = config_path.is_none();
+        match Linter::from_options(
+            LintOptions::default().with_fix(true).with_config_path(config_path),
+        ) {
+            Ok(linter) => {
+                *self.server_linter.write().await =
+                    ServerLinter::new_with_linter(linter.with_nested_configs(nested_configs));
+            }
+            Err(err) => {
+                error!("invalid configuration: {err}");
+                self.client.show_message(MessageType::ERROR, format!("oxc: {err}")).await;
+            }
         }
-        if let Some(config_path) = config_path {
-            let mut linter = self.server_linter.write().await;
-            *linter = ServerLinter::new_with_linter(
-                Linter::from_options(
-                    LintOptions::default().with_fix(true).with_config_path(Some(config_path)),
-                )
-                .expect("should have initialized linter with new options"),
-            );
+    }
+
+    /// Watch the configuration files, to create the linter again when they change.
+    async fn watch_config_files(&self) {
+        let config_path = self.options.lock().await.get_config_path();
+        let mut patterns = vec![format!("**/{CONFIG_FILE_NAME}")];
+        if let Some(file_name) = config_path.as_ref().and_then(|path| path.file_name()) {
+            patterns.push(format!("**/{}", file_name.to_string_lossy()));
+        }
+        let watchers = patterns
+            .into_iter()
+            .map(|pattern| FileSystemWatcher {
+                glob_pattern: GlobPattern::String(pattern),
+                kind: None,
+            })
+            .collect();
+        let registration = Registration {
+            id: CONFIG_FILES_WATCHER_ID.into(),
+            method: "workspace/didChangeWatchedFiles".into(),
+            register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
+                watchers,
+            })
+            .ok(),
+        };
+        if let Err(err) = self.client.register_capability(vec![registration]).await {
+            debug!("failed to watch the configuration files: {err:?}");
         }
     }
 
+    /// Lint the open documents again, and ask the client to pull the diagnostics of the
+    /// workspace again, after the configuration changed.
+    async fn revalidate(&self) {
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level == SyntheticRunLevel::Disable {
+            return;
+        }
+        let documents = self
+            .documents
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect::<Vec<_>>();
+        for (uri, content) in documents {
+            let Ok(uri) = Url::from_str(&uri) else {
+                continue;
+            };
+            if self.is_ignored(&uri).await {
+                continue;
+            }
+            self.handle_file_update(uri, Some(content), None).await;
+        }
+        if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+            debug!("failed to refresh the workspace diagnostics: {err:?}");
+        }
+    }
+
+    /// The diagnostics of the file at `uri`, read from the disk, `None` when it is not linted.
+    async fn pull_diagnostics(&self, uri: &Url) -> Option<Vec<Diagnostic>> {
+        let run_level = { self.options.lock().await.get_lint_level() };
+        if run_level == SyntheticRunLevel::Disable || self.is_ignored(uri).await {
+            return None;
+        }
+        let reports = self.server_linter.read().await.run_single(uri, None)?;
+        Some(reports.into_iter().map(|report| report.diagnostic).collect())
+    }
+
     async fn handle_file_update(&self, uri: Url, content: Option<String>, version: Option<i32>) {
         if let Some(Some(_root_uri)) = self.root_uri.get() {
             if let Some(diagnostics) = self.server_linter.read().await.run_single(&uri, content) {
@@ -389,6 +655,103 @@ impl Backend {
     }
 }
 
+/// The kind of the code action fixing all auto-fixable problems of a file.
+const FIX_ALL_KIND: CodeActionKind = CodeActionKind::new("source.fixAll.oxc");
+
+/// The id of the registration watching the configuration files.
+const CONFIG_FILES_WATCHER_ID: &str = "oxc-config-files";
+
+/// The command opening the documentation of a rule, with its URL as argument.
+const SHOW_RULE_DOCS_COMMAND: &str = "oxc.showRuleDocs";
+
+struct Action(CodeAction);
+
+impl Action {
+    fn preferred(mut self) -> Self {
+        self.0.is_preferred = Some(true);
+        self
+    }
+
+    fn with_diagnostic(mut self, diagnostic: &Diagnostic) -> Self {
+        self.0.diagnostics = Some(vec![diagnostic.clone()]);
+        self
+    }
+}
+
+fn code_action(
+    title: String,
+    kind: CodeActionKind,
+    uri: &Url,
+    edits: Vec<&FixedContent>,
+) -> Action {
+    let edits = edits
+        .into_iter()
+        .map(|fixed_content| TextEdit {
+            range: fixed_content.range,
+            new_text: fixed_content.code.clone(),
+        })
+        .collect();
+    Action(CodeAction {
+        title,
+        kind: Some(kind),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..WorkspaceEdit::default()
+        }),
+        ..CodeAction::default()
+    })
+}
+
+fn show_rule_docs_action(id: &str, docs_url: &str) -> Action {
+    let title = format!("Show documentation for {id}");
+    Action(CodeAction {
+        title: title.clone(),
+        kind: Some(CodeActionKind::QUICKFIX),
+        command: Some(Command {
+            title,
+            command: SHOW_RULE_DOCS_COMMAND.into(),
+            arguments: Some(vec![Value::String(docs_url.into())]),
+        }),
+        ..CodeAction::default()
+    })
+}
+
+/// The `What it does` and `Why is this bad?` sections of the documentation of `rule`, with a link
+/// to the full documentation.
+fn rule_docs_markdown(rule: &RuleReport) -> String {
+    let mut markdown = format!("**{}**", rule.id);
+    for docs in RuleDocs::find(&rule.name).iter().take(1) {
+        for section in &docs.sections {
+            if matches!(section.title.as_str(), "What it does" | "Why is this bad?") {
+                write!(markdown, "\n\n### {}\n\n{}", section.title, section.body).unwrap();
+            }
+        }
+    }
+    write!(markdown, "\n\n[Documentation]({})", rule.docs_url).unwrap();
+    markdown
+}
+
+/// The fixes of all the problems which do not overlap, the first fix wins like with `--fix`,
+/// so the edits can be applied at once.
+fn fix_all_edits(reports: &[DiagnosticReport]) -> Vec<&FixedContent> {
+    let mut fixes =
+        reports.iter().filter_map(|report| report.fixed_content.as_ref()).collect::<Vec<_>>();
+    fixes.sort_by_key(|fixed_content| (fixed_content.range.start, fixed_content.range.end));
+    let mut last_end = None;
+    fixes.retain(|fixed_content| {
+        if last_end.is_some_and(|end| fixed_content.range.start < end) {
+            return false;
+        }
+        last_end = Some(fixed_content.range.end);
+        true
+    });
+    fixes
+}
+
+fn ranges_overlap(a: &Range, b: &Range) -> bool {
+    a.start <= b.end && b.start <= a.end
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::init();
@@ -404,6 +767,7 @@ async fn main() {
         root_uri: OnceCell::new(),
         server_linter: RwLock::new(server_linter),
         diagnostics_report_map,
+        documents: DashMap::new(),
         options: Mutex::new(Options::default()),
         gitignore_glob: Mutex::new(None),
     })
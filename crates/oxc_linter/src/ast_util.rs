@@ -139,9 +139,7 @@ impl<'a, 'b> IsConstant<'a, 'b> for Expression<'a> {
             Self::ParenthesizedExpression(paren_expr) => {
                 paren_expr.expression.is_constant(in_boolean_position, ctx)
             }
-            Self::Identifier(ident) => {
-                ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident)
-            }
+            Self::Identifier(ident) => ctx.is_global_reference(ident, "undefined"),
             _ if self.is_literal() => true,
             _ => false,
         }
@@ -154,7 +152,7 @@ impl<'a, 'b> IsConstant<'a, 'b> for CallExpression<'a> {
             if ident.name == "Boolean"
                 && self.arguments.iter().next().map_or(true, |first| first.is_constant(true, ctx))
             {
-                return ctx.semantic().is_reference_to_global_variable(ident);
+                return ctx.is_unresolved_reference(ident);
             }
         }
         false
@@ -372,24 +370,13 @@ pub fn get_new_expr_ident_name<'a>(new_expr: &'a NewExpression<'a>) -> Option<&'
     Some(ident.name.as_str())
 }
 
-/// Check if the given [IdentifierReference] is a global reference.
-/// Such as `window`, `document`, `globalThis`, etc.
-pub fn is_global_reference(ident: &IdentifierReference, ctx: &LintContext) -> bool {
-    let symbol_table = ctx.semantic().symbols();
-    let Some(reference_id) = ident.reference_id.get() else {
-        return false;
-    };
-    let reference = symbol_table.get_reference(reference_id);
-    reference.symbol_id().is_none()
-}
-
 pub fn is_global_require_call(call_expr: &CallExpression, ctx: &LintContext) -> bool {
     if call_expr.arguments.len() != 1 {
         return false;
     }
 
     if let Expression::Identifier(id_ref) = &call_expr.callee {
-        id_ref.name == "require" && is_global_reference(id_ref, ctx)
+        ctx.is_global_reference(id_ref, "require")
     } else {
         false
     }
@@ -0,0 +1,243 @@
+//! The lint cache, which lets the files linted without diagnostics by a previous run be skipped
+//! until they or their configuration change, and keeps the module records of the dependencies of
+//! the linted files for the import plugin.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_semantic::ModuleRecord;
+use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
+
+use crate::{module_cache::CachedModule, rules::RULES};
+
+/// Default location of the cache file, relative to the current working directory.
+pub const DEFAULT_CACHE_LOCATION: &str = ".oxlintcache";
+
+/// The files linted without diagnostics, with the key of their contents and configuration.
+///
+/// A cache written by another version of oxlint, or by a build with other rules, is discarded as
+/// a whole. Entries of files which are linted with diagnostics or which no longer exist are
+/// evicted when the cache is saved.
+///
+/// With the import plugin, whose rules depend on other files, the files are always linted, but the
+/// module records of their dependencies, e.g. the files of other packages of a monorepo, are
+/// restored from the cache instead of being parsed when the dependencies did not change.
+pub struct LintCache {
+    location: PathBuf,
+    entries: DashMap<Box<Path>, u64>,
+    modules: DashMap<Box<Path>, CachedModule>,
+}
+
+/// The contents of the cache file.
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    version: String,
+    files: BTreeMap<PathBuf, u64>,
+    #[serde(default)]
+    modules: BTreeMap<PathBuf, CachedModule>,
+}
+
+impl LintCache {
+    /// Load the cache file at `location`, starting with an empty cache when it does not exist,
+    /// cannot be read or was written by another rule set.
+    pub fn load(location: PathBuf) -> Self {
+        let version = Self::version();
+        let file = fs::read_to_string(&location)
+            .ok()
+            .and_then(|json| serde_json::from_str::<CacheFile>(&json).ok())
+            .filter(|file| file.version == version)
+            .unwrap_or_default();
+        let entries =
+            file.files.into_iter().map(|(path, key)| (path.into_boxed_path(), key)).collect();
+        let modules = file
+            .modules
+            .into_iter()
+            .map(|(path, module)| (path.into_boxed_path(), module))
+            .collect();
+        Self { location, entries, modules }
+    }
+
+    pub fn location(&self) -> &Path {
+        &self.location
+    }
+
+    /// Number of files in the cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Number of module records in the cache.
+    pub fn number_of_modules(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// Write the cache file, without the entries of the files which no longer exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` if the cache file cannot be written.
+    pub fn save(&self) -> Result<(), OxcDiagnostic> {
+        self.entries.retain(|path, _| path.is_file());
+        self.modules.retain(|path, _| path.is_file());
+        let files =
+            self.entries.iter().map(|entry| (entry.key().to_path_buf(), *entry.value())).collect();
+        let modules = self
+            .modules
+            .iter()
+            .map(|entry| (entry.key().to_path_buf(), entry.value().clone()))
+            .collect();
+        let file = CacheFile { version: Self::version(), files, modules };
+        let json = serde_json::to_string(&file).map_err(|err| {
+            OxcDiagnostic::warn(format!("Failed to serialize the lint cache: {err}"))
+        })?;
+        fs::write(&self.location, json).map_err(|err| {
+            OxcDiagnostic::warn(format!(
+                "Failed to write the lint cache {} with error \"{err}\"",
+                self.location.display()
+            ))
+        })
+    }
+
+    /// The key of a file with `source_text`, linted with the configuration hashed to `config_hash`.
+    pub(crate) fn key(source_text: &str, config_hash: u64) -> u64 {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        config_hash.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Whether the file at `path` was linted without diagnostics with the same contents and
+    /// configuration.
+    pub(crate) fn is_clean(&self, path: &Path, key: u64) -> bool {
+        self.entries.get(path).is_some_and(|entry| *entry == key)
+    }
+
+    /// Record whether the file at `path` was linted without diagnostics.
+    pub(crate) fn set_clean(&self, path: &Path, key: u64, clean: bool) {
+        if clean {
+            self.entries.insert(path.to_path_buf().into_boxed_path(), key);
+        } else {
+            self.entries.remove(path);
+        }
+    }
+
+    /// The key of the module record of a file with `source_text`.
+    pub(crate) fn module_key(source_text: &str) -> u64 {
+        let mut hasher = FxHasher::default();
+        source_text.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// The module record of the file at `path`, if it was built from a source text with the same
+    /// key. The record is not linked to the records of its dependencies.
+    pub(crate) fn module(&self, path: &Path, key: u64) -> Option<ModuleRecord> {
+        let module = self.modules.get(path).filter(|module| module.key == key)?;
+        Some(module.to_module_record(path))
+    }
+
+    /// Keep the module record of the file at `path`, built from a source text with the key `key`.
+    pub(crate) fn set_module(&self, path: &Path, key: u64, module_record: &ModuleRecord) {
+        let module = CachedModule::new(key, module_record);
+        self.modules.insert(path.to_path_buf().into_boxed_path(), module);
+    }
+
+    /// The version of oxlint and of its rule set, which invalidates the cache when rules are
+    /// added, removed or gain fixes.
+    fn version() -> String {
+        let mut hasher = FxHasher::default();
+        for rule in RULES.iter() {
+            rule.plugin_name().hash(&mut hasher);
+            rule.name().hash(&mut hasher);
+            format!("{:?}", rule.fix()).hash(&mut hasher);
+        }
+        format!("{}-{:x}", env!("CARGO_PKG_VERSION"), hasher.finish())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::LintCache;
+
+    #[test]
+    fn test_lint_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join(".oxlintcache");
+        let a = dir.path().join("a.js");
+        let b = dir.path().join("b.js");
+        fs::write(&a, "").unwrap();
+        fs::write(&b, "").unwrap();
+
+        let cache = LintCache::load(location.clone());
+        assert!(cache.is_empty());
+        let key = LintCache::key("", 0);
+        assert_ne!(key, LintCache::key("", 1));
+        assert_ne!(key, LintCache::key("debugger", 0));
+        cache.set_clean(&a, key, true);
+        cache.set_clean(&b, key, true);
+        assert!(cache.is_clean(&a, key));
+        assert!(!cache.is_clean(&a, LintCache::key("debugger", 0)));
+        cache.save().unwrap();
+
+        let cache = LintCache::load(location.clone());
+        assert_eq!(cache.len(), 2);
+        assert!(cache.is_clean(&a, key));
+
+        // Files linted with diagnostics and deleted files are evicted
+        cache.set_clean(&a, key, false);
+        fs::remove_file(&b).unwrap();
+        cache.save().unwrap();
+        assert!(LintCache::load(location.clone()).is_empty());
+
+        // An invalid cache file is discarded
+        fs::write(&location, "{").unwrap();
+        assert!(LintCache::load(location).is_empty());
+    }
+
+    #[test]
+    fn test_module_records() {
+        let dir = tempfile::tempdir().unwrap();
+        let location = dir.path().join(".oxlintcache");
+        let a = dir.path().join("a.js");
+        let source_text = "export const a = 1;";
+        fs::write(&a, source_text).unwrap();
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let module_record = SemanticBuilder::new(source_text, source_type)
+            .build_module_record(a.clone(), &program)
+            .module_record();
+
+        let cache = LintCache::load(location.clone());
+        let key = LintCache::module_key(source_text);
+        cache.set_module(&a, key, &module_record);
+        cache.set_module(&dir.path().join("deleted.js"), key, &module_record);
+        cache.save().unwrap();
+
+        let cache = LintCache::load(location);
+        assert_eq!(cache.number_of_modules(), 1);
+        let restored = cache.module(&a, key).unwrap();
+        assert_eq!(restored.resolved_absolute_path, a);
+        assert!(restored.exported_bindings.contains_key("a"));
+        // The module record of a changed file is built again
+        assert!(cache.module(&a, LintCache::module_key("export const b = 1;")).is_none());
+    }
+}
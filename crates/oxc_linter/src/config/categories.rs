@@ -0,0 +1,95 @@
+use rustc_hash::FxHashSet;
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+use crate::{rules::RuleEnum, AllowWarnDeny, RuleCategory, RuleWithSeverity};
+
+/// Severity of the rules of each category, e.g. `{ "suspicious": "warn", "style": "off" }`.
+///
+/// Categories are applied after the `-A`, `-W` and `-D` options, and before `rules`, which take
+/// precedence over them. Only the rules of the enabled plugins are enabled, naming `nursery`
+/// enables the rules which are still under development.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct OxlintCategories {
+    /// Code that is outright wrong or useless
+    correctness: Option<AllowWarnDeny>,
+    /// Code that is most likely wrong or useless
+    suspicious: Option<AllowWarnDeny>,
+    /// Lints which are rather strict or have occasional false positives
+    pedantic: Option<AllowWarnDeny>,
+    /// Code that can be written to run faster
+    perf: Option<AllowWarnDeny>,
+    /// Code that should be written in a more idiomatic way
+    style: Option<AllowWarnDeny>,
+    /// Lints which prevent the use of language and library features, which should be enabled
+    /// one by one rather than as a whole
+    restriction: Option<AllowWarnDeny>,
+    /// New lints that are still under development
+    nursery: Option<AllowWarnDeny>,
+}
+
+impl OxlintCategories {
+    /// The configured categories with their severities.
+    pub fn iter(&self) -> impl Iterator<Item = (RuleCategory, AllowWarnDeny)> + '_ {
+        [
+            (RuleCategory::Correctness, self.correctness),
+            (RuleCategory::Suspicious, self.suspicious),
+            (RuleCategory::Pedantic, self.pedantic),
+            (RuleCategory::Perf, self.perf),
+            (RuleCategory::Style, self.style),
+            (RuleCategory::Restriction, self.restriction),
+            (RuleCategory::Nursery, self.nursery),
+        ]
+        .into_iter()
+        .filter_map(|(category, severity)| Some((category, severity?)))
+    }
+
+    /// Enable the rules of `all_rules` in the categories turned on, and disable the rules in the
+    /// categories turned off.
+    #[allow(clippy::mutable_key_type)]
+    pub(crate) fn override_rules(
+        &self,
+        rules: &mut FxHashSet<RuleWithSeverity>,
+        all_rules: &[RuleEnum],
+    ) {
+        for (category, severity) in self.iter() {
+            if severity.is_allow() {
+                rules.retain(|rule| rule.category() != category);
+                continue;
+            }
+            for rule in all_rules.iter().filter(|rule| rule.category() == category) {
+                rules.replace(RuleWithSeverity::new(rule.clone(), severity));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::OxlintCategories;
+    use crate::{AllowWarnDeny, RuleCategory};
+
+    #[test]
+    fn test_deserialize() {
+        let categories = OxlintCategories::deserialize(&serde_json::json!({
+            "correctness": "error",
+            "style": "off",
+            "nursery": 1,
+        }))
+        .unwrap();
+        assert_eq!(
+            categories.iter().collect::<Vec<_>>(),
+            [
+                (RuleCategory::Correctness, AllowWarnDeny::Deny),
+                (RuleCategory::Style, AllowWarnDeny::Allow),
+                (RuleCategory::Nursery, AllowWarnDeny::Warn),
+            ]
+        );
+
+        assert!(OxlintCategories::deserialize(&serde_json::json!({ "pedantics": "warn" })).is_err());
+        assert!(OxlintCategories::deserialize(&serde_json::json!({ "perf": "on" })).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+//! Resolution of `extends`, layering a configuration file on top of the ones it extends.
+
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::OxcDiagnostic;
+use serde_json::{Map, Value};
+
+use super::{
+    presets::{self, PRESETS, PRESET_PREFIX},
+    OxlintConfig,
+};
+
+/// Read the configuration file at `path` merged with the configuration files it extends,
+/// see [`merge`]. `stack` holds the files being read, to detect circular `extends`.
+pub(super) fn read_extended_json(
+    path: &Path,
+    stack: &mut Vec<PathBuf>,
+) -> Result<Value, OxcDiagnostic> {
+    let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if stack.contains(&canonical_path) {
+        return Err(OxcDiagnostic::error(format!(
+            "Circular `extends` in config {}",
+            path.display()
+        )));
+    }
+
+    let json = OxlintConfig::read_json(path)?;
+    let names = match json.get("extends") {
+        Some(Value::String(name)) => vec![name.clone()],
+        Some(Value::Array(names)) => {
+            names.iter().filter_map(Value::as_str).map(ToString::to_string).collect()
+        }
+        // Invalid values are reported when deserializing the config
+        _ => return Ok(json),
+    };
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut merged = Value::Object(Map::new());
+    stack.push(canonical_path);
+    for name in names {
+        if let Some(preset_name) = name.strip_prefix(PRESET_PREFIX) {
+            let preset = presets::preset(preset_name).ok_or_else(|| {
+                let presets = PRESETS.map(|preset| format!("`{PRESET_PREFIX}{preset}`")).join(", ");
+                OxcDiagnostic::error(format!(
+                    "Unknown preset `{name}` in `extends` of config {}, the presets are {presets}",
+                    path.display()
+                ))
+            })?;
+            merge(&mut merged, preset);
+            continue;
+        }
+        let base_path = resolve(dir, &name).ok_or_else(|| {
+            OxcDiagnostic::error(format!(
+                "Failed to resolve `extends` {name:?} of config {}",
+                path.display()
+            ))
+        })?;
+        merge(&mut merged, read_extended_json(&base_path, stack)?);
+    }
+    stack.pop();
+    merge(&mut merged, json);
+    Ok(merged)
+}
+
+/// Resolve an entry of `extends` of a configuration file in `dir`, other than a preset such as
+/// `oxlint:recommended`, which is either
+/// * a path relative to `dir`, e.g. `./base.json`
+/// * a file in a package, e.g. `@company/lint-config/oxlintrc.json`
+/// * a package, e.g. `@company/lint-config`, providing the file referenced by the `main` field
+///   of its `package.json`, or `.oxlintrc.json`
+fn resolve(dir: &Path, name: &str) -> Option<PathBuf> {
+    if name.starts_with('.') || Path::new(name).is_absolute() {
+        let path = dir.join(name);
+        return path.is_file().then_some(path);
+    }
+
+    let package_path = dir
+        .ancestors()
+        .map(|dir| dir.join("node_modules").join(name))
+        .find(|path| path.exists())?;
+    if package_path.is_file() {
+        return Some(package_path);
+    }
+    let main = std::fs::read_to_string(package_path.join("package.json"))
+        .ok()
+        .and_then(|package_json| serde_json::from_str::<Value>(&package_json).ok())
+        .and_then(|package_json| package_json.get("main")?.as_str().map(ToString::to_string));
+    let path = package_path.join(main.as_deref().unwrap_or(".oxlintrc.json"));
+    path.is_file().then_some(path)
+}
+
+/// Merge `config` into `base`, `config` taking precedence:
+/// * `rules` are merged by rule, a rule configured with a severity only keeps the options of `base`
+/// * `severityOverrides` of `config` are appended to the ones of `base`
+/// * `plugins` are combined
+/// * other objects, e.g. `settings`, `env` and `globals`, are merged recursively
+pub(super) fn merge(base: &mut Value, config: Value) {
+    let (Value::Object(base), Value::Object(config)) = (base, config) else {
+        return;
+    };
+    for (key, value) in config {
+        let Some(base_value) = base.get_mut(&key) else {
+            base.insert(key, value);
+            continue;
+        };
+        match (key.as_str(), base_value, value) {
+            ("rules", Value::Object(base_rules), Value::Object(rules)) => {
+                merge_rules(base_rules, rules);
+            }
+            ("severityOverrides", Value::Array(base_overrides), Value::Array(overrides)) => {
+                base_overrides.extend(overrides);
+            }
+            ("plugins", Value::Array(base_plugins), Value::Array(plugins)) => {
+                for plugin in plugins {
+                    if !base_plugins.contains(&plugin) {
+                        base_plugins.push(plugin);
+                    }
+                }
+            }
+            (_, base_value, value) => merge_recursively(base_value, value),
+        }
+    }
+}
+
+fn merge_rules(base: &mut Map<String, Value>, rules: Map<String, Value>) {
+    for (key, value) in rules {
+        match (base.get_mut(&key), value) {
+            // `"eqeqeq": "warn"` extending `"eqeqeq": ["error", "smart"]`
+            (Some(Value::Array(base_value)), severity @ (Value::String(_) | Value::Number(_)))
+                if !base_value.is_empty() =>
+            {
+                base_value[0] = severity;
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn merge_recursively(base: &mut Value, value: Value) {
+    match (base, value) {
+        (Value::Object(base), Value::Object(object)) => {
+            for (key, value) in object {
+                match base.get_mut(&key) {
+                    Some(base_value) => merge_recursively(base_value, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, value) => *base = value,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use super::merge;
+
+    #[test]
+    fn test_merge() {
+        let mut base = json!({
+            "extends": ["./base.json"],
+            "plugins": ["import"],
+            "rules": { "eqeqeq": ["error", "smart"], "no-debugger": "error", "no-alert": "warn" },
+            "settings": { "jsx-a11y": { "polymorphicPropName": "as", "components": { "Link": "a" } } },
+            "env": { "browser": true },
+            "severityOverrides": [{ "files": ["*.test.js"], "severity": "off" }],
+        });
+        merge(
+            &mut base,
+            json!({
+                "plugins": ["jsdoc", "import"],
+                "rules": { "eqeqeq": "warn", "no-debugger": ["warn"], "no-console": "error" },
+                "settings": { "jsx-a11y": { "components": { "Button": "button" } } },
+                "env": { "node": true },
+                "severityOverrides": [{ "files": ["legacy/**"], "severity": "warn" }],
+            }),
+        );
+        assert_eq!(
+            base,
+            json!({
+                "extends": ["./base.json"],
+                "plugins": ["import", "jsdoc"],
+                "rules": {
+                    "eqeqeq": ["warn", "smart"],
+                    "no-debugger": ["warn"],
+                    "no-alert": "warn",
+                    "no-console": "error",
+                },
+                "settings": {
+                    "jsx-a11y": {
+                        "polymorphicPropName": "as",
+                        "components": { "Link": "a", "Button": "button" },
+                    },
+                },
+                "env": { "browser": true, "node": true },
+                "severityOverrides": [
+                    { "files": ["*.test.js"], "severity": "off" },
+                    { "files": ["legacy/**"], "severity": "warn" },
+                ],
+            })
+        );
+    }
+}
@@ -1,14 +1,18 @@
 use rustc_hash::FxHashMap;
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{de, Deserialize, Deserializer};
+use serde_json::Value;
 
 /// Add or remove global variables.
 // <https://eslint.org/docs/v8.x/use/configure/language-options#using-configuration-files-1>
 #[derive(Debug, Default, Deserialize, JsonSchema)]
 pub struct OxlintGlobals(FxHashMap<String, GlobalValue>);
 
-// TODO: support deprecated `false`
-#[derive(Debug, Eq, PartialEq, Deserialize, JsonSchema)]
+/// Whether a global variable can be assigned, `"off"` removes a global variable of an environment.
+///
+/// The deprecated `true` and `"writable"` are aliases of `"writeable"`, `false` and `"readable"`
+/// of `"readonly"`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum GlobalValue {
     Readonly,
@@ -16,8 +20,80 @@ pub enum GlobalValue {
     Off,
 }
 
+impl<'de> Deserialize<'de> for GlobalValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Value::deserialize(deserializer)? {
+            Value::Bool(true) => Ok(Self::Writeable),
+            Value::Bool(false) => Ok(Self::Readonly),
+            Value::String(value) => match value.as_str() {
+                "readonly" | "readable" => Ok(Self::Readonly),
+                "writeable" | "writable" => Ok(Self::Writeable),
+                "off" => Ok(Self::Off),
+                _ => Err(de::Error::custom(format!(
+                    r#"Failed to parse global value, expected one of "readonly", "writeable" or "off", but got {value:?}"#
+                ))),
+            },
+            value => Err(de::Error::custom(format!(
+                "Failed to parse global value, expected a string or a boolean, but got {value}"
+            ))),
+        }
+    }
+}
+
 impl OxlintGlobals {
     pub fn is_enabled(&self, name: &str) -> bool {
         self.0.get(name).is_some_and(|value| *value != GlobalValue::Off)
     }
+
+    /// The configured value of the global variable `name`, including `"off"`.
+    pub fn get(&self, name: &str) -> Option<GlobalValue> {
+        self.0.get(name).copied()
+    }
+
+    /// The configured global variables and their value, including `"off"`.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, GlobalValue)> + '_ {
+        self.0.iter().map(|(name, value)| (name.as_str(), *value))
+    }
+}
+
+impl GlobalValue {
+    /// The value as written in configuration files, e.g. `"readonly"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Readonly => "readonly",
+            Self::Writeable => "writeable",
+            Self::Off => "off",
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::{GlobalValue, OxlintGlobals};
+
+    #[test]
+    fn test_parse_globals() {
+        let globals = OxlintGlobals::deserialize(&serde_json::json!({
+            "a": "readonly", "b": "readable", "c": false,
+            "d": "writeable", "e": "writable", "f": true,
+            "g": "off",
+        }))
+        .unwrap();
+        for name in ["a", "b", "c"] {
+            assert_eq!(globals.get(name), Some(GlobalValue::Readonly));
+        }
+        for name in ["d", "e", "f"] {
+            assert_eq!(globals.get(name), Some(GlobalValue::Writeable));
+        }
+        assert_eq!(globals.get("g"), Some(GlobalValue::Off));
+        assert!(!globals.is_enabled("g"));
+        assert!(globals.get("h").is_none());
+
+        assert!(OxlintGlobals::deserialize(&serde_json::json!({ "a": "on" })).is_err());
+    }
 }
@@ -0,0 +1,80 @@
+use std::{env, path::Path};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer};
+
+use super::severity_overrides::normalize_path;
+
+/// Patterns of the files to ignore, with the syntax of `.gitignore` files, relative to the current
+/// working directory, e.g. `["dist/", "**/*.generated.ts", "!dist/keep.js"]`.
+///
+/// A pattern starting with `!` lints again the files ignored by the previous patterns.
+#[derive(Debug, JsonSchema)]
+#[schemars(transparent)]
+pub struct OxlintIgnorePatterns(#[schemars(with = "Vec<String>")] Gitignore);
+
+impl Default for OxlintIgnorePatterns {
+    fn default() -> Self {
+        Self(Gitignore::empty())
+    }
+}
+
+impl OxlintIgnorePatterns {
+    /// Whether the file at `path`, or one of its parent directories, is ignored.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        if self.0.is_empty() {
+            return false;
+        }
+        let path = normalize_path(path);
+        // Files outside of the current working directory are never matched
+        if path.has_root() {
+            return false;
+        }
+        self.0.matched_path_or_any_parents(&path, false).is_ignore()
+    }
+}
+
+impl<'de> Deserialize<'de> for OxlintIgnorePatterns {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let patterns = Vec::<String>::deserialize(deserializer)?;
+        let mut builder = GitignoreBuilder::new(env::current_dir().unwrap_or_default());
+        for pattern in &patterns {
+            builder.add_line(None, pattern).map_err(de::Error::custom)?;
+        }
+        builder.build().map(Self).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::OxlintIgnorePatterns;
+
+    #[test]
+    fn test_is_ignored() {
+        let patterns = OxlintIgnorePatterns::deserialize(&serde_json::json!([
+            "dist/",
+            "*.generated.ts",
+            "!dist/keep.js",
+            "/vendor",
+        ]))
+        .unwrap();
+
+        let is_ignored = |path: &str| patterns.is_ignored(Path::new(path));
+        assert!(is_ignored("dist/index.js"));
+        assert!(is_ignored("./packages/a/dist/index.js"));
+        assert!(!is_ignored("dist/keep.js"));
+        assert!(is_ignored("src/api.generated.ts"));
+        assert!(!is_ignored("src/api.ts"));
+        // Anchored patterns only match relative to the current working directory
+        assert!(is_ignored("vendor/lib.js"));
+        assert!(!is_ignored("src/vendor/lib.js"));
+        assert!(!is_ignored("/outside/dist/index.js"));
+
+        assert!(!OxlintIgnorePatterns::default().is_ignored(Path::new("dist/index.js")));
+    }
+}
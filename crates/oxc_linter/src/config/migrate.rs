@@ -0,0 +1,411 @@
+//! Conversion of ESLint configurations to oxlint configurations, see [`migrate_eslint_config`].
+
+use serde_json::{Map, Value};
+
+use super::oxlint_plugin_name;
+use crate::{rule_names::is_known_rule, rules::RULES, AllowWarnDeny};
+
+/// An ESLint configuration converted to an oxlint configuration by [`migrate_eslint_config`].
+#[derive(Debug)]
+pub struct MigratedConfig {
+    /// The oxlint configuration, in the format of [`OxlintConfig`](crate::OxlintConfig)
+    pub config: Value,
+    /// Parts of the ESLint configuration which have no oxlint equivalent yet
+    pub unsupported: Vec<String>,
+}
+
+/// Convert an ESLint configuration to an oxlint configuration.
+///
+/// `eslint_config` is either a legacy `.eslintrc` configuration object, or a flat configuration
+/// array of `eslint.config.js` whose `plugins` are replaced by their names.
+///
+/// Rules which exist in oxlint are kept with their options. Rule severities configured for a
+/// subset of files, by `overrides` or by flat configurations with `files`, are converted to
+/// `severityOverrides`.
+pub fn migrate_eslint_config(eslint_config: &Value) -> MigratedConfig {
+    let mut migration = Migration::default();
+    match eslint_config {
+        Value::Array(configs) => {
+            for config in configs {
+                migration.migrate_flat_config(config);
+            }
+        }
+        Value::Object(_) => migration.migrate_legacy_config(eslint_config),
+        _ => migration.unsupported.push(format!("Configuration {eslint_config}")),
+    }
+    migration.finish()
+}
+
+/// A rule severity configured for a subset of files
+struct FileRule {
+    files: Value,
+    key: String,
+    severity: AllowWarnDeny,
+    options: Option<Vec<Value>>,
+}
+
+#[derive(Default)]
+struct Migration {
+    config: Map<String, Value>,
+    file_rules: Vec<FileRule>,
+    unsupported: Vec<String>,
+}
+
+impl Migration {
+    fn migrate_legacy_config(&mut self, config: &Value) {
+        let Some(config) = config.as_object() else { return };
+        for (key, value) in config {
+            match key.as_str() {
+                "root" => {
+                    self.config.insert(key.clone(), value.clone());
+                }
+                "env" => self.merge_object("env", value),
+                "globals" => self.migrate_globals(value),
+                "settings" => self.migrate_settings(value),
+                "plugins" => {
+                    let plugins = value.as_array().into_iter().flatten().filter_map(Value::as_str);
+                    for plugin in plugins {
+                        self.migrate_plugin(plugin);
+                    }
+                }
+                "extends" => {
+                    let extends = match value {
+                        Value::Array(extends) => extends.clone(),
+                        value => vec![value.clone()],
+                    };
+                    for name in extends.iter().filter_map(Value::as_str) {
+                        self.unsupported.push(format!("`extends` of `{name}`"));
+                    }
+                }
+                "rules" => self.migrate_rules(value),
+                "overrides" => {
+                    for config in value.as_array().into_iter().flatten() {
+                        self.migrate_file_config(config, &["files", "excludedFiles"]);
+                    }
+                }
+                // Parsing is handled by oxlint itself
+                "$schema" | "parser" | "parserOptions" => {}
+                _ => self.unsupported.push(format!("`{key}`")),
+            }
+        }
+    }
+
+    fn migrate_flat_config(&mut self, config: &Value) {
+        let Some(config) = config.as_object() else { return };
+        if config.contains_key("files") {
+            self.migrate_file_config(&Value::Object(config.clone()), &["files", "name"]);
+            return;
+        }
+        for (key, value) in config {
+            match key.as_str() {
+                "languageOptions" => {
+                    let language_options = value.as_object().into_iter().flatten();
+                    for (key, value) in language_options {
+                        match key.as_str() {
+                            "globals" => self.migrate_globals(value),
+                            // Parsing is handled by oxlint itself
+                            "ecmaVersion" | "sourceType" | "parser" | "parserOptions" => {}
+                            _ => self.unsupported.push(format!("`languageOptions.{key}`")),
+                        }
+                    }
+                }
+                "settings" => self.migrate_settings(value),
+                "plugins" => {
+                    let plugins = match value {
+                        Value::Object(plugins) => plugins.keys().cloned().collect(),
+                        Value::Array(plugins) => plugins
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(ToString::to_string)
+                            .collect(),
+                        _ => vec![],
+                    };
+                    for plugin in plugins {
+                        self.migrate_plugin(&plugin);
+                    }
+                }
+                "rules" => self.migrate_rules(value),
+                "name" => {}
+                _ => self.unsupported.push(format!("`{key}`")),
+            }
+        }
+    }
+
+    /// Migrate a configuration which applies to the files matching its `files` globs,
+    /// only the severities of its rules can be migrated.
+    fn migrate_file_config(&mut self, config: &Value, ignored_keys: &[&str]) {
+        let Some(config) = config.as_object() else { return };
+        let files = match config.get("files") {
+            Some(Value::String(files)) => Value::Array(vec![Value::String(files.clone())]),
+            Some(files) => files.clone(),
+            None => return,
+        };
+        for (key, value) in config {
+            if ignored_keys.contains(&key.as_str()) {
+                continue;
+            }
+            if key != "rules" {
+                self.unsupported.push(format!("`{key}` for files {files}"));
+                continue;
+            }
+            for (key, value) in value.as_object().into_iter().flatten() {
+                let Some((severity, options)) = self.parse_rule(key, value) else { continue };
+                self.file_rules.push(FileRule {
+                    files: files.clone(),
+                    key: key.clone(),
+                    severity,
+                    options,
+                });
+            }
+        }
+    }
+
+    fn migrate_rules(&mut self, rules: &Value) {
+        for (key, value) in rules.as_object().into_iter().flatten() {
+            if self.parse_rule(key, value).is_some() {
+                self.merge_object(
+                    "rules",
+                    &Value::Object(Map::from_iter([(key.clone(), value.clone())])),
+                );
+            }
+        }
+    }
+
+    /// The severity and options of a rule which exists in oxlint.
+    fn parse_rule(
+        &mut self,
+        key: &str,
+        value: &Value,
+    ) -> Option<(AllowWarnDeny, Option<Vec<Value>>)> {
+        let (severity, options) = match value {
+            Value::Array(values) => (
+                values.first().and_then(|severity| AllowWarnDeny::try_from(severity).ok()),
+                Some(values[1..].to_vec()),
+            ),
+            value => (AllowWarnDeny::try_from(value).ok(), None),
+        };
+        let Some(severity) = severity else {
+            self.unsupported.push(format!("Rule `{key}` configured with {value}"));
+            return None;
+        };
+        if !is_known_rule(key) {
+            // Rules which are turned off do not need to be migrated
+            if severity.is_warn_deny() {
+                self.unsupported.push(format!("Rule `{key}`"));
+            }
+            return None;
+        }
+        Some((severity, options.filter(|options| !options.is_empty())))
+    }
+
+    fn migrate_plugin(&mut self, name: &str) {
+        // e.g. `eslint-plugin-react` and `@typescript-eslint/eslint-plugin`
+        let name = name.strip_prefix("eslint-plugin-").unwrap_or(name);
+        let name = name.strip_suffix("/eslint-plugin").unwrap_or(name);
+        let plugin_name = oxlint_plugin_name(name);
+        if plugin_name != "vitest" && !RULES.iter().any(|rule| rule.plugin_name() == plugin_name) {
+            self.unsupported.push(format!("Plugin `{name}`"));
+            return;
+        }
+        let plugins = self.config.entry("plugins").or_insert_with(|| Value::Array(vec![]));
+        if let Value::Array(plugins) = plugins {
+            let name = Value::String(name.to_string());
+            if !plugins.contains(&name) {
+                plugins.push(name);
+            }
+        }
+    }
+
+    /// `true` and `"writable"` are aliases of `"writeable"`, `false` and `"readable"` of `"readonly"`.
+    fn migrate_globals(&mut self, globals: &Value) {
+        let globals = globals
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(name, value)| {
+                let value = match value {
+                    Value::Bool(true) => "writeable",
+                    Value::String(value) if value == "writable" || value == "writeable" => {
+                        "writeable"
+                    }
+                    Value::String(value) if value == "off" => "off",
+                    _ => "readonly",
+                };
+                (name.clone(), Value::String(value.to_string()))
+            })
+            .collect();
+        self.merge_object("globals", &Value::Object(globals));
+    }
+
+    /// Only the settings of the supported plugins are kept.
+    fn migrate_settings(&mut self, settings: &Value) {
+        let settings = settings
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(key, _)| {
+                matches!(
+                    key.as_str(),
+                    "jsx-a11y" | "next" | "react" | "jsdoc" | "jest" | "import/resolver"
+                )
+            })
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        self.merge_object("settings", &Value::Object(settings));
+    }
+
+    fn merge_object(&mut self, key: &str, value: &Value) {
+        let Value::Object(value) = value else { return };
+        if value.is_empty() {
+            return;
+        }
+        let object = self.config.entry(key).or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(object) = object {
+            object.extend(value.iter().map(|(key, value)| (key.clone(), value.clone())));
+        }
+    }
+
+    fn finish(mut self) -> MigratedConfig {
+        let mut severity_overrides = vec![];
+        for FileRule { files, key, severity, options } in std::mem::take(&mut self.file_rules) {
+            let configured = self.config.get("rules").and_then(|rules| rules.get(&key));
+            let (enabled, configured_options) = match configured {
+                Some(Value::Array(values)) => (
+                    values
+                        .first()
+                        .and_then(|severity| AllowWarnDeny::try_from(severity).ok())
+                        .is_some_and(AllowWarnDeny::is_warn_deny),
+                    values.get(1..).map(<[Value]>::to_vec).unwrap_or_default(),
+                ),
+                Some(value) => {
+                    (AllowWarnDeny::try_from(value).is_ok_and(AllowWarnDeny::is_warn_deny), vec![])
+                }
+                None => (false, vec![]),
+            };
+            // Severity overrides only change the severity of enabled rules
+            if severity.is_warn_deny() && !enabled {
+                self.unsupported.push(format!("Rule `{key}` enabled for files {files} only"));
+                continue;
+            }
+            if options.is_some_and(|options| options != configured_options) {
+                self.unsupported.push(format!("Options of rule `{key}` for files {files}"));
+            }
+            let severity = severity.as_str();
+            // Group the rules of the same files and severity
+            let existing = severity_overrides
+                .iter_mut()
+                .find(|o: &&mut Value| o["files"] == files && o["severity"] == severity);
+            match existing {
+                Some(Value::Object(o)) => {
+                    if let Some(Value::Array(rules)) = o.get_mut("rules") {
+                        rules.push(Value::String(key));
+                    }
+                }
+                _ => severity_overrides.push(serde_json::json!({
+                    "files": files,
+                    "rules": [key],
+                    "severity": severity,
+                })),
+            }
+        }
+        if !severity_overrides.is_empty() {
+            self.config.insert("severityOverrides".to_string(), Value::Array(severity_overrides));
+        }
+        MigratedConfig { config: Value::Object(self.config), unsupported: self.unsupported }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::migrate_eslint_config;
+    use crate::OxlintConfig;
+
+    #[test]
+    fn test_migrate_legacy_config() {
+        let migrated = migrate_eslint_config(&json!({
+            "root": true,
+            "parser": "@typescript-eslint/parser",
+            "extends": ["eslint:recommended"],
+            "plugins": ["@typescript-eslint", "eslint-plugin-import", "prettier"],
+            "env": { "browser": true },
+            "globals": { "foo": true, "bar": false, "baz": "readable" },
+            "settings": { "react": { "linkComponents": ["Link"] }, "import/extensions": [".js"] },
+            "rules": {
+                "eqeqeq": ["error", "smart"],
+                "no-debugger": "warn",
+                "@typescript-eslint/no-explicit-any": 2,
+                "prettier/prettier": "error",
+                "no-unknown-rule": "off",
+            },
+            "overrides": [
+                {
+                    "files": "*.test.js",
+                    "env": { "jest": true },
+                    "rules": { "no-debugger": "off", "eqeqeq": "off", "no-empty": "error" },
+                },
+                { "files": ["legacy/**"], "rules": { "eqeqeq": ["warn", "always"] } },
+            ],
+        }));
+        assert_eq!(
+            migrated.config,
+            json!({
+                "root": true,
+                "plugins": ["@typescript-eslint", "import"],
+                "env": { "browser": true },
+                "globals": { "foo": "writeable", "bar": "readonly", "baz": "readonly" },
+                "settings": { "react": { "linkComponents": ["Link"] } },
+                "rules": {
+                    "eqeqeq": ["error", "smart"],
+                    "no-debugger": "warn",
+                    "@typescript-eslint/no-explicit-any": 2,
+                },
+                "severityOverrides": [
+                    { "files": ["*.test.js"], "rules": ["no-debugger", "eqeqeq"], "severity": "off" },
+                    { "files": ["legacy/**"], "rules": ["eqeqeq"], "severity": "warn" },
+                ],
+            })
+        );
+        assert_eq!(
+            migrated.unsupported,
+            [
+                "`extends` of `eslint:recommended`",
+                "Plugin `prettier`",
+                "Rule `prettier/prettier`",
+                "`env` for files [\"*.test.js\"]",
+                "Rule `no-empty` enabled for files [\"*.test.js\"] only",
+                "Options of rule `eqeqeq` for files [\"legacy/**\"]",
+            ]
+        );
+        assert!(OxlintConfig::deserialize(&migrated.config).is_ok());
+    }
+
+    #[test]
+    fn test_migrate_flat_config() {
+        let migrated = migrate_eslint_config(&json!([
+            { "ignores": ["dist/**"] },
+            {
+                "name": "base",
+                "languageOptions": { "ecmaVersion": 2022, "globals": { "process": "readonly" } },
+                "plugins": { "react": {}, "unicorn": {} },
+                "rules": { "react/jsx-key": "error", "unicorn/no-null": "warn" },
+            },
+            { "files": ["**/*.spec.js"], "rules": { "unicorn/no-null": "off" } },
+        ]));
+        assert_eq!(
+            migrated.config,
+            json!({
+                "plugins": ["react", "unicorn"],
+                "globals": { "process": "readonly" },
+                "rules": { "react/jsx-key": "error", "unicorn/no-null": "warn" },
+                "severityOverrides": [
+                    { "files": ["**/*.spec.js"], "rules": ["unicorn/no-null"], "severity": "off" },
+                ],
+            })
+        );
+        assert_eq!(migrated.unsupported, ["`ignores`"]);
+        assert!(OxlintConfig::deserialize(&migrated.config).is_ok());
+    }
+}
@@ -1,23 +1,45 @@
+mod categories;
 mod env;
+mod extends;
 mod globals;
+mod ignore_patterns;
+mod migrate;
+mod nested;
+mod presets;
+mod rule_ignore_patterns;
 mod rules;
 mod settings;
+mod severity_overrides;
 
 use std::path::Path;
 
 use oxc_diagnostics::OxcDiagnostic;
 use rustc_hash::FxHashSet;
 use schemars::JsonSchema;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 
 pub use self::{
+    categories::OxlintCategories,
     env::OxlintEnv,
-    globals::OxlintGlobals,
-    rules::OxlintRules,
+    globals::{GlobalValue, OxlintGlobals},
+    ignore_patterns::OxlintIgnorePatterns,
+    migrate::{migrate_eslint_config, MigratedConfig},
+    nested::CONFIG_FILE_NAME,
+    rule_ignore_patterns::OxlintRuleIgnorePatterns,
+    rules::{ESLintRule, OxlintRules},
     settings::{jsdoc::JSDocPluginSettings, OxlintSettings},
+    severity_overrides::OxlintSeverityOverrides,
+};
+pub(crate) use self::{
+    nested::NestedConfigs,
+    rules::{oxlint_plugin_name, parse_rule_key},
+    severity_overrides::{deserialize_globs, normalize_path},
 };
 use crate::{
-    rules::RuleEnum, utils::is_jest_rule_adapted_to_vitest, AllowWarnDeny, RuleWithSeverity,
+    rule_names::{is_known_rule, rule_key, unknown_rule_diagnostic},
+    rules::{RuleEnum, RULES},
+    utils::is_jest_rule_adapted_to_vitest,
+    AllowWarnDeny, RuleWithSeverity,
 };
 
 /// Oxlint Configuration File
@@ -26,6 +48,9 @@ use crate::{
 ///
 /// Usage: `oxlint -c oxlintrc.json`
 ///
+/// Without `-c`, the `.oxlintrc.json` files in the directory of each linted file and its ancestors
+/// are merged, with nearer files taking precedence, up to the first file with `"root": true`.
+///
 /// ::: danger NOTE
 ///
 /// Only the `.json` format is supported.
@@ -38,6 +63,11 @@ use crate::{
 ///
 /// ```json
 /// {
+///   "extends": ["oxlint:recommended", "./base.oxlintrc.json"],
+///   "plugins": ["import"],
+///   "categories": {
+///     "suspicious": "warn"
+///   },
 ///   "env": {
 ///       "browser": true
 ///   },
@@ -48,24 +78,80 @@ use crate::{
 ///   },
 ///   "rules": {
 ///       "eqeqeq": "warn"
-///   }
+///   },
+///   "severityOverrides": [
+///     { "files": ["src/legacy/**"], "severity": "warn" }
+///   ],
+///   "ruleIgnorePatterns": {
+///     "no-console": ["scripts/**"]
+///   },
+///   "ignorePatterns": ["dist/", "**/*.generated.ts"],
+///   "denyWarnings": true,
+///   "requireSuppressionReasons": true
 ///  }
 /// ```
 #[derive(Debug, Default, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct OxlintConfig {
+    /// Stop looking for `.oxlintrc.json` files in parent directories
+    pub(crate) root: bool,
+    /// Configuration files extended by this configuration, either paths relative to this file,
+    /// packages in `node_modules` or the presets `oxlint:recommended`, `oxlint:strict`,
+    /// `oxlint:all` and `oxlint:security`. Rules, categories, settings, environments, globals and
+    /// plugins are merged in order, with this configuration taking precedence. The severity of a
+    /// rule in `rules` takes precedence over the one of its category in `categories`, e.g.
+    /// `"no-empty": "off"` turns the rule off when extending `oxlint:all`.
+    #[serde(deserialize_with = "deserialize_extends")]
+    pub(crate) extends: Vec<String>,
+    /// Plugins enabled in addition to the ones enabled by the command line, e.g. `["import", "jsx-a11y"]`.
+    /// The optional plugins are `import`, `jsdoc`, `jest`, `vitest`, `jsx-a11y`, `nextjs`,
+    /// `react-perf`, `react-refresh` and `json`.
+    pub(crate) plugins: Vec<String>,
+    pub(crate) categories: OxlintCategories,
     /// See [Oxlint Rules](./rules)
     pub(crate) rules: OxlintRules,
     pub(crate) settings: OxlintSettings,
     pub(crate) env: OxlintEnv,
     pub(crate) globals: OxlintGlobals,
+    #[serde(rename = "severityOverrides")]
+    pub(crate) severity_overrides: OxlintSeverityOverrides,
+    /// Files which are not linted by specific rules, with glob patterns relative to the current
+    /// working directory keyed by rule, e.g. `{ "no-console": ["scripts/**"] }`. A shorthand for
+    /// `severityOverrides` turning rules off.
+    #[serde(rename = "ruleIgnorePatterns")]
+    pub(crate) rule_ignore_patterns: OxlintRuleIgnorePatterns,
+    /// Files which are not linted, in addition to the ones ignored by the `.gitignore`,
+    /// `.eslintignore` and `.oxlintignore` files. The patterns have the syntax of `.gitignore`
+    /// files and are relative to the current working directory, e.g. `["dist/", "!dist/keep.js"]`.
+    #[serde(rename = "ignorePatterns")]
+    pub(crate) ignore_patterns: OxlintIgnorePatterns,
+    /// Exit with an error code when warnings are reported, like the `--deny-warnings` flag. Only
+    /// the configuration of the current directory is used, `--allow-warnings` overrides it.
+    #[serde(rename = "denyWarnings")]
+    pub(crate) deny_warnings: bool,
+    /// Warn about the `eslint-disable` and `oxlint-disable` comments which do not describe why
+    /// the problems are expected after `--`, e.g.
+    /// `// eslint-disable-next-line no-console -- logging the errors`.
+    #[serde(rename = "requireSuppressionReasons")]
+    pub(crate) require_suppression_reasons: bool,
 }
 
 impl OxlintConfig {
     /// # Errors
     ///
     /// * Parse Failure
+    /// * Failure to resolve `extends`
     pub fn from_file(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let json = extends::read_extended_json(path, &mut vec![])?;
+
+        let config = Self::deserialize(&json).map_err(|err| {
+            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
+        })?;
+
+        Ok(config)
+    }
+
+    fn read_json(path: &Path) -> Result<serde_json::Value, OxcDiagnostic> {
         let mut string = std::fs::read_to_string(path).map_err(|e| {
             OxcDiagnostic::error(format!("Failed to parse config {path:?} with error {e:?}"))
         })?;
@@ -90,11 +176,80 @@ impl OxlintConfig {
             OxcDiagnostic::error(format!("Failed to parse eslint config {path:?}.\n{err}"))
         })?;
 
-        let config = Self::deserialize(&json).map_err(|err| {
-            OxcDiagnostic::error(format!("Failed to parse config with error {err:?}"))
-        })?;
+        Ok(json)
+    }
 
-        Ok(config)
+    /// Whether [`OxlintConfig::plugins`] enables the plugin of `rule`.
+    pub(crate) fn enables_plugin_of(&self, rule: &RuleEnum) -> bool {
+        self.plugins.iter().any(|plugin| match plugin.as_str() {
+            "vitest" => rule.plugin_name() == "jest" && is_jest_rule_adapted_to_vitest(rule.name()),
+            plugin => oxlint_plugin_name(plugin) == rule.plugin_name(),
+        })
+    }
+
+    /// Warnings for rules referenced by this configuration which do not exist,
+    /// see [`crate::rule_names::unknown_rule_diagnostic`].
+    pub fn unknown_rules(&self) -> Vec<OxcDiagnostic> {
+        self.unknown_rules_except(|_| false)
+    }
+
+    /// [`OxlintConfig::unknown_rules`], except the rules for which `is_plugin_rule` is `true`,
+    /// the rules of the plugins loaded with [`crate::Linter::with_wasm_plugins`].
+    pub(crate) fn unknown_rules_except(
+        &self,
+        is_plugin_rule: impl Fn(&str) -> bool,
+    ) -> Vec<OxcDiagnostic> {
+        self.rules
+            .iter()
+            .map(|rule| rule_key(&rule.plugin_name, &rule.rule_name))
+            .chain(self.severity_overrides.rule_keys().map(ToString::to_string))
+            .chain(self.rule_ignore_patterns.rule_keys().map(ToString::to_string))
+            .filter(|key| !is_known_rule(key) && !is_plugin_rule(key))
+            .filter_map(|key| unknown_rule_diagnostic(&key))
+            .collect()
+    }
+
+    /// The options `rule` is configured with, the items following the severity in the `rules`
+    /// of the configuration, e.g. `["smart"]` for `"eqeqeq": ["error", "smart"]`.
+    pub(crate) fn rule_options(&self, rule: &RuleEnum) -> Option<&serde_json::Value> {
+        self.rule_config(rule)?.config.as_ref()
+    }
+
+    /// The entry of the `rules` of the configuration turning `rule` on, if any.
+    pub(crate) fn rule_config(&self, rule: &RuleEnum) -> Option<&ESLintRule> {
+        self.rules.iter().filter(|rule_config| rule_config.severity.is_warn_deny()).find(
+            |rule_config| {
+                let (rule_name, plugin_name) = transform_rule_and_plugin_name(
+                    &rule_config.rule_name,
+                    &rule_config.plugin_name,
+                );
+                rule.name() == rule_name && rule.plugin_name() == plugin_name
+            },
+        )
+    }
+
+    /// Warnings for rules configured with invalid options, see
+    /// [`Rule::validate_configuration`](crate::rule::Rule::validate_configuration).
+    pub fn invalid_rule_options(&self) -> Vec<OxcDiagnostic> {
+        self.rules
+            .iter()
+            .filter_map(|rule_config| {
+                let config = rule_config.config.as_ref()?;
+                let (rule_name, plugin_name) = transform_rule_and_plugin_name(
+                    &rule_config.rule_name,
+                    &rule_config.plugin_name,
+                );
+                let rule = RULES
+                    .iter()
+                    .find(|rule| rule.name() == rule_name && rule.plugin_name() == plugin_name)?;
+                let error = rule.validate_json(config).err()?;
+                let key = rule_key(&rule_config.plugin_name, &rule_config.rule_name);
+                Some(OxcDiagnostic::warn(format!(
+                    "Invalid options for rule `{key}`, using the defaults: {}",
+                    error.message
+                )))
+            })
+            .collect()
     }
 
     #[allow(clippy::option_if_let_else)]
@@ -172,7 +327,23 @@ impl OxlintConfig {
     }
 }
 
-fn transform_rule_and_plugin_name<'a>(
+/// `extends` is either a single configuration file or a list of them.
+fn deserialize_extends<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Extends {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match Extends::deserialize(deserializer)? {
+        Extends::One(name) => vec![name],
+        Extends::Many(names) => names,
+    })
+}
+
+pub(crate) fn transform_rule_and_plugin_name<'a>(
     rule_name: &'a str,
     plugin_name: &'a str,
 ) -> (&'a str, &'a str) {
@@ -190,7 +361,7 @@ mod test {
     use rustc_hash::FxHashSet;
     use serde::Deserialize;
 
-    use crate::rules::RULES;
+    use crate::{rules::RULES, AllowWarnDeny, LintOptions};
 
     use super::OxlintConfig;
 
@@ -201,6 +372,63 @@ mod test {
         assert!(!config.rules.is_empty());
     }
 
+    #[test]
+    fn test_extends() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/.oxlintrc.json");
+        let config = OxlintConfig::from_file(&fixture_path).unwrap();
+        assert_eq!(config.extends, ["./base.json", "shared-config"]);
+        assert_eq!(config.plugins, ["import", "jsx-a11y"]);
+        assert_eq!(config.settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
+
+        let rules = config
+            .rules
+            .iter()
+            .map(|rule| (rule.rule_name.as_str(), rule.severity, rule.config.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rules,
+            [
+                ("eqeqeq", AllowWarnDeny::Warn, Some(serde_json::json!(["smart"]))),
+                ("no-debugger", AllowWarnDeny::Deny, None),
+                ("no-cycle", AllowWarnDeny::Deny, None),
+            ]
+        );
+
+        // The import plugin is enabled by the shared config
+        let options = LintOptions::default().with_config_path(Some(fixture_path));
+        let (rules, _) = options.derive_rules_and_config().unwrap();
+        assert!(rules
+            .iter()
+            .any(|rule| rule.plugin_name() == "import" && rule.name() == "no-cycle"));
+
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/circular/a.json");
+        let error = OxlintConfig::from_file(&fixture_path).unwrap_err();
+        assert!(error.to_string().starts_with("Circular `extends` in config"));
+    }
+
+    #[test]
+    fn test_extends_presets() {
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/presets.json");
+        let options = LintOptions::default().with_config_path(Some(fixture_path));
+        let (rules, _) = options.derive_rules_and_config().unwrap();
+        let severity =
+            |name: &str| rules.iter().find(|rule| rule.name() == name).map(|rule| rule.severity);
+        // `correctness` of `recommended`
+        assert_eq!(severity("no-unsafe-finally"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("no-debugger"), Some(AllowWarnDeny::Warn));
+        assert_eq!(severity("no-empty"), None);
+        // `pedantic` of `strict` is turned off, except for the rules of `recommended`
+        assert_eq!(severity("eqeqeq"), None);
+        assert_eq!(severity("no-fallthrough"), Some(AllowWarnDeny::Deny));
+        // `security` of `strict`
+        assert_eq!(severity("no-eval"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("no-var"), Some(AllowWarnDeny::Warn));
+
+        let fixture_path = env::current_dir().unwrap().join("fixtures/extends/unknown_preset.json");
+        let error = OxlintConfig::from_file(&fixture_path).unwrap_err();
+        assert!(error.to_string().starts_with("Unknown preset `oxlint:recomended` in `extends`"));
+    }
+
     #[test]
     fn test_deserialize() {
         let config = OxlintConfig::deserialize(&serde_json::json!({
@@ -229,15 +457,46 @@ mod test {
                 },
             },
             "env": { "browser": true, },
-            "globals": { "foo": "readonly", }
+            "globals": { "foo": "readonly", },
+            "denyWarnings": true,
+            "requireSuppressionReasons": true
         }));
         assert!(config.is_ok());
 
-        let OxlintConfig { rules, settings, env, globals } = config.unwrap();
+        let OxlintConfig {
+            rules,
+            settings,
+            env,
+            globals,
+            deny_warnings,
+            require_suppression_reasons,
+            ..
+        } = config.unwrap();
         assert!(!rules.is_empty());
         assert_eq!(settings.jsx_a11y.polymorphic_prop_name, Some("role".to_string()));
         assert_eq!(env.iter().count(), 1);
         assert!(globals.is_enabled("foo"));
+        assert!(deny_warnings);
+        assert!(require_suppression_reasons);
+    }
+
+    #[test]
+    fn test_categories() {
+        let config = OxlintConfig::deserialize(&serde_json::json!({
+            "categories": { "correctness": "off", "suspicious": "error" },
+            "rules": { "no-debugger": "warn", "no-new": "off" },
+        }))
+        .unwrap();
+        let rules = LintOptions::default().derive_rules(Some(&config));
+        let severity =
+            |name: &str| rules.iter().find(|rule| rule.name() == name).map(|rule| rule.severity);
+        // `rules` take precedence over `categories`
+        assert_eq!(severity("no-debugger"), Some(AllowWarnDeny::Warn));
+        assert_eq!(severity("no-new"), None);
+        assert_eq!(severity("no-unsafe-finally"), None);
+        assert_eq!(severity("no-useless-concat"), Some(AllowWarnDeny::Deny));
+        // The rules of the plugins which are not enabled are not enabled
+        assert!(rules.iter().all(|rule| rule.plugin_name() != "import"));
     }
 
     #[test]
@@ -252,4 +511,58 @@ mod test {
         assert_eq!(rule.name(), "no-disabled-tests");
         assert_eq!(rule.plugin_name(), "jest");
     }
+
+    #[test]
+    fn test_unknown_rules() {
+        let config = OxlintConfig::deserialize(&serde_json::json!({
+            "rules": {
+                "no-debuger": "error",
+                "@typescript-eslint/no-explicit-any": "error",
+                "@typescript-eslint/no-explict-any": "error",
+                "vue/valid-template-root": "error",
+            },
+            "severityOverrides": [
+                { "files": ["*.js"], "rules": ["eqeqeq", "eqeqe"], "severity": "off" },
+            ],
+            "ruleIgnorePatterns": { "no-consle": ["scripts/**"], "no-console": ["scripts/**"] },
+        }))
+        .unwrap();
+        let unknown_rules = config
+            .unknown_rules()
+            .into_iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unknown_rules,
+            vec![
+                "Rule `no-debuger` was not found.",
+                "Rule `@typescript-eslint/no-explict-any` was not found.",
+                "Rule `eqeqe` was not found.",
+                "Rule `no-consle` was not found.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_rule_options() {
+        let config = OxlintConfig::deserialize(&serde_json::json!({
+            "rules": {
+                "unicorn/no-useless-undefined": ["error", { "checkArguments": "no" }],
+                "no-useless-undefined": ["error", { "checkArrowFunctionBody": false }],
+                "eqeqeq": ["error", "always"],
+            },
+        }))
+        .unwrap();
+        let invalid_rule_options = config
+            .invalid_rule_options()
+            .into_iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            invalid_rule_options,
+            vec![
+                "Invalid options for rule `unicorn/no-useless-undefined`, using the defaults: invalid type: string \"no\", expected a boolean",
+            ]
+        );
+    }
 }
@@ -0,0 +1,92 @@
+//! Discovery of the configuration of each linted file, cascading like ESLint's `.eslintrc` files:
+//! the `.oxlintrc.json` files in the directory of a file and its ancestors are merged, with nearer
+//! files taking precedence, up to the first file with `"root": true`.
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::OxcDiagnostic;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use super::{extends, OxlintConfig};
+use crate::RuleWithSeverity;
+
+/// Name of the configuration files discovered in the directories of linted files.
+pub const CONFIG_FILE_NAME: &str = ".oxlintrc.json";
+
+/// The rules and configuration derived from the configuration files of a directory.
+pub(crate) struct NestedConfig {
+    /// The nearest configuration file, merged with the ones of its ancestors
+    pub path: PathBuf,
+    pub rules: Vec<RuleWithSeverity>,
+    pub config: Arc<OxlintConfig>,
+}
+
+/// Cache of the configuration of the directories containing linted files.
+#[derive(Default)]
+pub(crate) struct NestedConfigs {
+    /// The nearest configuration file of each directory, if any
+    nearest: DashMap<PathBuf, Option<PathBuf>>,
+    /// The configuration of each nearest configuration file, merged with its ancestors
+    resolved: DashMap<PathBuf, Result<Arc<NestedConfig>, OxcDiagnostic>>,
+}
+
+impl NestedConfigs {
+    /// The configuration of the file at `path`, `None` when there are no configuration files
+    /// in its directory or any of its ancestors. `derive_rules` derives the rules to run from
+    /// the merged configuration.
+    pub fn resolve<F>(
+        &self,
+        path: &Path,
+        derive_rules: F,
+    ) -> Option<Result<Arc<NestedConfig>, OxcDiagnostic>>
+    where
+        F: FnOnce(&OxlintConfig) -> Vec<RuleWithSeverity>,
+    {
+        let dir = path.parent()?;
+        // Relative paths are relative to the current directory, whose ancestors are searched too
+        let dir =
+            if dir.is_absolute() { dir.to_path_buf() } else { env::current_dir().ok()?.join(dir) };
+        let nearest = self
+            .nearest
+            .entry(dir.clone())
+            .or_insert_with(|| {
+                dir.ancestors().map(|dir| dir.join(CONFIG_FILE_NAME)).find(|path| path.is_file())
+            })
+            .clone()?;
+        let resolved = self.resolved.entry(nearest.clone()).or_insert_with(|| {
+            let config = read_cascading_config(&nearest)?;
+            let rules = derive_rules(&config);
+            Ok(Arc::new(NestedConfig { path: nearest.clone(), rules, config: Arc::new(config) }))
+        });
+        Some(resolved.clone())
+    }
+}
+
+/// Read the configuration file at `path` merged with the configuration files in the ancestors
+/// of its directory.
+fn read_cascading_config(path: &Path) -> Result<OxlintConfig, OxcDiagnostic> {
+    let mut configs = vec![];
+    let ancestors = path.parent().into_iter().flat_map(Path::ancestors);
+    for path in ancestors.map(|dir| dir.join(CONFIG_FILE_NAME)).filter(|path| path.is_file()) {
+        let json = extends::read_extended_json(&path, &mut vec![])?;
+        let root = json.get("root").and_then(Value::as_bool).unwrap_or_default();
+        configs.push(json);
+        if root {
+            break;
+        }
+    }
+
+    let mut merged = Value::Object(Map::new());
+    for json in configs.into_iter().rev() {
+        extends::merge(&mut merged, json);
+    }
+    OxlintConfig::deserialize(&merged).map_err(|err| {
+        OxcDiagnostic::error(format!("Failed to parse config {} with error {err}", path.display()))
+    })
+}
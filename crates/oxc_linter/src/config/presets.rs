@@ -0,0 +1,136 @@
+//! The presets of `extends`, e.g. `"extends": ["oxlint:recommended"]`, which are built in
+//! configurations enabling the rules of some categories and a curated list of rules.
+//!
+//! A preset is layered like a configuration file: the entries of `extends` are merged in order,
+//! the later ones taking precedence, and the configuration extending them is merged last. Within
+//! the merged configuration, the severity of a rule in `rules` takes precedence over the severity
+//! of its category in `categories`, whichever of the configurations they come from.
+
+use serde_json::{json, Map, Value};
+
+use super::extends::merge;
+use crate::security::SECURITY_RULES;
+
+/// The prefix of the presets in `extends`, which are not paths or packages.
+pub(super) const PRESET_PREFIX: &str = "oxlint:";
+
+/// The names of the presets, without [`PRESET_PREFIX`].
+pub(super) const PRESETS: [&str; 4] = ["recommended", "strict", "all", "security"];
+
+/// The rules of `recommended` outside of the `correctness` category, from `eslint:recommended`.
+const RECOMMENDED_RULES: [&str; 6] = [
+    "no-case-declarations",
+    "no-empty",
+    "no-fallthrough",
+    "no-prototype-builtins",
+    "no-redeclare",
+    "no-unsafe-optional-chaining",
+];
+
+/// The rules of `strict` outside of its categories, in addition to the ones of `recommended` and
+/// `security`.
+const STRICT_RULES: [&str; 2] = ["no-var", "typescript/no-explicit-any"];
+
+/// The configuration of the preset `name`, e.g. `recommended` for `oxlint:recommended`.
+///
+/// * `recommended`: the rules of the `correctness` category and the rules of
+///   [`RECOMMENDED_RULES`] as errors
+/// * `strict`: `recommended` and `security`, with the `suspicious` category as errors, the
+///   `pedantic` and `perf` categories and the rules of [`STRICT_RULES`] as warnings
+/// * `all`: the rules of all the categories but the nursery, the `correctness` category as
+///   errors and the others as warnings
+/// * `security`: the rules of [`SECURITY_RULES`] as errors, as with `--security`
+pub(super) fn preset(name: &str) -> Option<Value> {
+    let preset = match name {
+        "recommended" => json!({
+            "categories": { "correctness": "error" },
+            "rules": rules(RECOMMENDED_RULES, "error"),
+        }),
+        "strict" => {
+            let mut strict = preset("recommended")?;
+            merge(&mut strict, preset("security")?);
+            merge(
+                &mut strict,
+                json!({
+                    "categories": { "suspicious": "error", "pedantic": "warn", "perf": "warn" },
+                    "rules": rules(STRICT_RULES, "warn"),
+                }),
+            );
+            strict
+        }
+        "all" => json!({
+            "categories": {
+                "correctness": "error",
+                "suspicious": "warn",
+                "pedantic": "warn",
+                "perf": "warn",
+                "style": "warn",
+                "restriction": "warn",
+            },
+        }),
+        "security" => {
+            let keys = SECURITY_RULES.iter().map(|rule| {
+                if rule.plugin_name == "eslint" {
+                    rule.rule_name.to_string()
+                } else {
+                    format!("{}/{}", rule.plugin_name, rule.rule_name)
+                }
+            });
+            json!({ "rules": rules(keys, "error") })
+        }
+        _ => return None,
+    };
+    Some(preset)
+}
+
+fn rules<K: Into<String>>(keys: impl IntoIterator<Item = K>, severity: &str) -> Value {
+    Value::Object(
+        keys.into_iter().map(|key| (key.into(), Value::from(severity))).collect::<Map<_, _>>(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use super::{preset, PRESETS};
+    use crate::{
+        config::OxlintConfig,
+        rule_names::{find_rule, rule_key},
+        AllowWarnDeny, RuleCategory,
+    };
+
+    #[test]
+    fn test_presets() {
+        for name in PRESETS {
+            let config = OxlintConfig::deserialize(&preset(name).unwrap()).unwrap();
+            // The curated rules exist and are not in the nursery, which presets don't enable
+            for rule in config.rules.iter() {
+                let rule = find_rule(&rule_key(&rule.plugin_name, &rule.rule_name)).unwrap();
+                assert_ne!(rule.category(), RuleCategory::Nursery, "{name}");
+            }
+            assert!(config
+                .categories
+                .iter()
+                .all(|(category, _)| category != RuleCategory::Nursery));
+        }
+        assert_eq!(preset("unknown"), None);
+
+        let strict = preset("strict").unwrap();
+        assert_eq!(strict["categories"]["correctness"], json!("error"));
+        assert_eq!(strict["categories"]["suspicious"], json!("error"));
+        assert_eq!(strict["rules"]["no-fallthrough"], json!("error"));
+        assert_eq!(strict["rules"]["no-eval"], json!("error"));
+        assert_eq!(strict["rules"]["react/no-danger"], json!("error"));
+        assert_eq!(strict["rules"]["no-var"], json!("warn"));
+
+        let all = OxlintConfig::deserialize(&preset("all").unwrap()).unwrap();
+        assert_eq!(all.categories.iter().count(), 6);
+        assert!(all.categories.iter().all(|(_, severity)| severity.is_warn_deny()));
+        assert_eq!(
+            all.categories.iter().next(),
+            Some((RuleCategory::Correctness, AllowWarnDeny::Deny))
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::path::Path;
+
+use globset::GlobSet;
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer};
+
+use super::{
+    rules::parse_rule_key,
+    severity_overrides::{deserialize_globs, normalize_path},
+    transform_rule_and_plugin_name,
+};
+use crate::RuleWithSeverity;
+
+/// Files which are not linted by specific rules, keyed by rule, with glob patterns relative to the
+/// current working directory, e.g. `{ "no-console": ["scripts/**"] }`.
+///
+/// A shorthand for a `severityOverrides` entry turning the rule off for the files.
+#[derive(Debug, Default, JsonSchema)]
+#[schemars(transparent)]
+pub struct OxlintRuleIgnorePatterns(
+    #[schemars(with = "std::collections::HashMap<String, Vec<String>>")] Vec<(String, GlobSet)>,
+);
+
+impl OxlintRuleIgnorePatterns {
+    /// Rules referenced by the patterns, as written in the configuration.
+    pub(super) fn rule_keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(|(key, _)| key.as_str())
+    }
+
+    /// Collect the rules ignoring the file at `path`.
+    pub fn matching(&self, path: &Path) -> MatchedRuleIgnorePatterns {
+        if self.0.is_empty() {
+            return MatchedRuleIgnorePatterns(vec![]);
+        }
+        let path = normalize_path(path);
+        let rules = self
+            .0
+            .iter()
+            .filter(|(_, globs)| globs.is_match(&path))
+            .map(|(key, _)| {
+                let (plugin_name, rule_name) = parse_rule_key(key);
+                let (rule_name, plugin_name) =
+                    transform_rule_and_plugin_name(&rule_name, &plugin_name);
+                (plugin_name.to_string(), rule_name.to_string())
+            })
+            .collect();
+        MatchedRuleIgnorePatterns(rules)
+    }
+}
+
+/// The rules ignoring a single file, see [`OxlintRuleIgnorePatterns::matching`].
+pub struct MatchedRuleIgnorePatterns(Vec<(String, String)>);
+
+impl MatchedRuleIgnorePatterns {
+    /// Whether `rule` does not lint the file.
+    pub fn is_ignored(&self, rule: &RuleWithSeverity) -> bool {
+        self.0.iter().any(|(plugin_name, rule_name)| {
+            rule.name() == rule_name && rule.plugin_name() == plugin_name
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for OxlintRuleIgnorePatterns {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let patterns = FxHashMap::<String, serde_json::Value>::deserialize(deserializer)?;
+        let mut patterns = patterns
+            .into_iter()
+            .map(|(key, globs)| {
+                let globs = deserialize_globs(globs).map_err(de::Error::custom)?;
+                Ok((key, globs))
+            })
+            .collect::<Result<Vec<_>, D::Error>>()?;
+        // The order of the keys does not matter, sorted for a stable configuration hash
+        patterns.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        Ok(Self(patterns))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::OxlintRuleIgnorePatterns;
+    use crate::{rules::RULES, AllowWarnDeny, RuleWithSeverity};
+
+    fn rule(name: &str) -> RuleWithSeverity {
+        let rule = RULES.iter().find(|r| r.name() == name).unwrap().clone();
+        RuleWithSeverity::new(rule, AllowWarnDeny::Deny)
+    }
+
+    #[test]
+    fn test_matching() {
+        let patterns = OxlintRuleIgnorePatterns::deserialize(&serde_json::json!({
+            "no-console": ["scripts/**", "./bin/*.js"],
+            "@typescript-eslint/no-explicit-any": ["src/generated/**"],
+        }))
+        .unwrap();
+        assert_eq!(
+            patterns.rule_keys().collect::<Vec<_>>(),
+            ["@typescript-eslint/no-explicit-any", "no-console"]
+        );
+
+        let is_ignored =
+            |name: &str, path: &str| patterns.matching(Path::new(path)).is_ignored(&rule(name));
+        assert!(is_ignored("no-console", "scripts/release.js"));
+        assert!(is_ignored("no-console", "bin/cli.js"));
+        assert!(!is_ignored("no-console", "src/index.js"));
+        assert!(!is_ignored("no-debugger", "scripts/release.js"));
+        assert!(is_ignored("no-explicit-any", "src/generated/api.ts"));
+        assert!(!is_ignored("no-explicit-any", "scripts/release.js"));
+
+        let result = OxlintRuleIgnorePatterns::deserialize(&serde_json::json!({ "eqeqeq": "**" }));
+        assert!(result.is_err());
+    }
+}
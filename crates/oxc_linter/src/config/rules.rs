@@ -2,13 +2,20 @@ use std::{borrow::Cow, fmt, ops::Deref};
 
 use oxc_diagnostics::{Error, OxcDiagnostic};
 use rustc_hash::FxHashMap;
-use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{
+        ArrayValidation, InstanceType, Metadata, ObjectValidation, RootSchema, Schema,
+        SchemaObject, SingleOrVec, SubschemaValidation,
+    },
+    JsonSchema,
+};
 use serde::{
     de::{self, Deserializer, Visitor},
     Deserialize,
 };
 
-use crate::AllowWarnDeny;
+use crate::{rule_names::rule_key, rules::RULES, AllowWarnDeny};
 
 // TS type is `Record<string, RuleConf>`
 //   - type SeverityConf = 0 | 1 | 2 | "off" | "warn" | "error";
@@ -48,6 +55,55 @@ impl JsonSchema for OxlintRules {
     }
 }
 
+impl OxlintRules {
+    /// Schema of `rules` with an entry for every known rule.
+    /// The options of rules which declare their schema with `config = Type` in
+    /// `declare_oxc_lint!` are validated against it.
+    pub fn known_rules_schema() -> RootSchema {
+        let mut gen = SchemaGenerator::default();
+        let severity = gen.subschema_for::<AllowWarnDeny>();
+        let properties = RULES
+            .iter()
+            .map(|rule| {
+                let mut items = vec![severity.clone()];
+                items.extend(rule.config_schema(&mut gen));
+                let severity_and_options = SchemaObject {
+                    instance_type: Some(InstanceType::Array.into()),
+                    array: Some(Box::new(ArrayValidation {
+                        items: Some(SingleOrVec::Vec(items)),
+                        min_items: Some(1),
+                        ..ArrayValidation::default()
+                    })),
+                    ..SchemaObject::default()
+                };
+                let schema = SchemaObject {
+                    subschemas: Some(Box::new(SubschemaValidation {
+                        any_of: Some(vec![severity.clone(), severity_and_options.into()]),
+                        ..SubschemaValidation::default()
+                    })),
+                    ..SchemaObject::default()
+                };
+                (rule_key(rule.plugin_name(), rule.name()), schema.into())
+            })
+            .collect();
+
+        let schema = SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                title: Some("OxlintRules".to_string()),
+                ..Metadata::default()
+            })),
+            instance_type: Some(InstanceType::Object.into()),
+            object: Some(Box::new(ObjectValidation { properties, ..ObjectValidation::default() })),
+            ..SchemaObject::default()
+        };
+        RootSchema {
+            meta_schema: gen.settings().meta_schema.clone(),
+            schema,
+            definitions: gen.take_definitions(),
+        }
+    }
+}
+
 // Manually implement Deserialize because the type is a bit complex...
 // - Handle single value form and array form
 // - SeverityConf into AllowWarnDeny
@@ -85,25 +141,33 @@ impl<'de> Deserialize<'de> for OxlintRules {
     }
 }
 
-fn parse_rule_key(name: &str) -> (String, String) {
+pub(crate) fn parse_rule_key(name: &str) -> (String, String) {
     let Some((plugin_name, rule_name)) = name.split_once('/') else {
         return ("eslint".to_string(), name.to_string());
     };
 
-    let (oxlint_plugin_name, rule_name) = match plugin_name {
-        "@typescript-eslint" => ("typescript", rule_name),
-        "jsx-a11y" => ("jsx_a11y", rule_name),
-        "react-perf" => ("react_perf", rule_name),
-        // e.g. "@next/next/google-font-display"
-        "@next" => ("nextjs", rule_name.trim_start_matches("next/")),
+    // e.g. "@next/next/google-font-display"
+    let rule_name =
+        if plugin_name == "@next" { rule_name.trim_start_matches("next/") } else { rule_name };
+
+    (oxlint_plugin_name(plugin_name).to_string(), rule_name.to_string())
+}
+
+/// The name of a plugin as written in ESLint configuration files, e.g. `@typescript-eslint`,
+/// aligned with the plugin names of oxlint.
+pub(crate) fn oxlint_plugin_name(plugin_name: &str) -> &str {
+    match plugin_name {
+        "@typescript-eslint" => "typescript",
+        "jsx-a11y" => "jsx_a11y",
+        "react-perf" => "react_perf",
+        "react-refresh" => "react_refresh",
+        "@next" | "@next/next" => "nextjs",
         // For backwards compatibility, react hook rules reside in the react plugin.
-        "react-hooks" => ("react", rule_name),
+        "react-hooks" => "react",
         // For backwards compatibility, deepscan rules reside in the oxc plugin.
-        "deepscan" => ("oxc", rule_name),
-        _ => (plugin_name, rule_name),
-    };
-
-    (oxlint_plugin_name.to_string(), rule_name.to_string())
+        "deepscan" => "oxc",
+        _ => plugin_name,
+    }
 }
 
 fn parse_rule_value(
@@ -204,4 +268,18 @@ mod test {
         let rules = OxlintRules::default();
         assert!(rules.is_empty());
     }
+
+    #[test]
+    fn test_known_rules_schema() {
+        let schema = serde_json::to_value(OxlintRules::known_rules_schema()).unwrap();
+        let options = &schema["properties"]["unicorn/no-useless-undefined"]["anyOf"][1]["items"];
+        assert_eq!(options[1], serde_json::json!({ "$ref": "#/definitions/NoUselessUndefined" }));
+        let config = &schema["definitions"]["NoUselessUndefined"];
+        assert_eq!(config["properties"]["checkArguments"]["type"], "boolean");
+        assert_eq!(config["additionalProperties"], false);
+
+        // Options of rules without a schema are not validated
+        let options = &schema["properties"]["eqeqeq"]["anyOf"][1]["items"];
+        assert_eq!(options.as_array().unwrap().len(), 1);
+    }
 }
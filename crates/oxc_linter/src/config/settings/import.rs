@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+/// `import/resolver`, configuring how the rules of the import plugin resolve modules
+// <https://github.com/import-js/eslint-plugin-import#resolvers>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct ImportResolverSettings {
+    #[serde(default, deserialize_with = "deserialize_resolver")]
+    pub node: NodeResolverSettings,
+
+    #[serde(default, deserialize_with = "deserialize_resolver")]
+    pub typescript: TypeScriptResolverSettings,
+}
+
+// <https://github.com/import-js/eslint-plugin-import/tree/main/resolvers/node>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct NodeResolverSettings {
+    /// Extensions of the resolved files, e.g. `[".js", ".jsx"]`
+    #[serde(default)]
+    pub extensions: Vec<String>,
+}
+
+// <https://github.com/import-js/eslint-import-resolver-typescript#configuration>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct TypeScriptResolverSettings {
+    /// Paths of the `tsconfig.json` files used for path aliases
+    #[serde(default, deserialize_with = "deserialize_one_or_many")]
+    pub project: Vec<PathBuf>,
+}
+
+impl ImportResolverSettings {
+    /// The `tsconfig.json` configured for the `typescript` resolver, the first one if there are many.
+    pub fn tsconfig(&self) -> Option<&PathBuf> {
+        self.typescript.project.first()
+    }
+}
+
+/// Resolvers are enabled without options with `true`, e.g. `{ "typescript": true }`.
+fn deserialize_resolver<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    match Value::deserialize(deserializer)? {
+        value @ Value::Object(_) => T::deserialize(value).map_err(serde::de::Error::custom),
+        _ => Ok(T::default()),
+    }
+}
+
+fn deserialize_one_or_many<'de, D>(deserializer: D) -> Result<Vec<PathBuf>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(PathBuf),
+        Many(Vec<PathBuf>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(path) => vec![path],
+        OneOrMany::Many(paths) => paths,
+    })
+}
@@ -0,0 +1,30 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+
+// <https://github.com/jest-community/eslint-plugin-jest#jest-version-setting>
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct JestPluginSettings {
+    /// The version of Jest used by the project, e.g. `29` or `"29.7.0"`
+    #[serde(default)]
+    version: Option<JestVersion>,
+}
+
+impl JestPluginSettings {
+    /// The major version of Jest, if configured.
+    pub fn major_version(&self) -> Option<usize> {
+        match &self.version {
+            Some(JestVersion::Number(version)) => Some(*version),
+            Some(JestVersion::String(version)) => version.split('.').next()?.parse().ok(),
+            None => None,
+        }
+    }
+}
+
+// Deserialize helper types
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum JestVersion {
+    Number(usize),
+    String(String),
+}
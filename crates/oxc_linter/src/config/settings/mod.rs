@@ -1,14 +1,18 @@
+mod import;
+mod jest;
 pub mod jsdoc;
 mod jsx_a11y;
 mod next;
 mod react;
+mod test_files;
 
 use schemars::JsonSchema;
 use serde::Deserialize;
 
 use self::{
-    jsdoc::JSDocPluginSettings, jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings,
-    react::ReactPluginSettings,
+    import::ImportResolverSettings, jest::JestPluginSettings, jsdoc::JSDocPluginSettings,
+    jsx_a11y::JSXA11yPluginSettings, next::NextPluginSettings, react::ReactPluginSettings,
+    test_files::TestSettings,
 };
 
 /// Shared settings for plugins
@@ -26,6 +30,16 @@ pub struct OxlintSettings {
 
     #[serde(default)]
     pub jsdoc: JSDocPluginSettings,
+
+    #[serde(default)]
+    pub jest: JestPluginSettings,
+
+    #[serde(default)]
+    #[serde(rename = "import/resolver")]
+    pub import_resolver: ImportResolverSettings,
+
+    #[serde(default)]
+    pub test: TestSettings,
 }
 
 #[cfg(test)]
@@ -47,7 +61,18 @@ mod test {
             "next": {
                 "rootDir": "app"
             },
+            "jest": {
+                "version": "27.5.1"
+            },
+            "import/resolver": {
+                "node": true,
+                "typescript": { "project": "./tsconfig.base.json" }
+            },
             "react": {
+                "version": "18.2",
+                "pragma": "h",
+                "jsxFragmentFactory": "Fragment",
+                "componentWrapperFunctions": ["observer", {"property": "styled"}, {"property": "observer", "object": "Mobx"}],
                 "formComponents": [
                     "CustomForm",
                     {"name": "SimpleForm", "formAttribute": "endpoint"},
@@ -79,6 +104,19 @@ mod test {
             Some(vec!["to".to_string(), "href".to_string()])
         );
         assert_eq!(settings.react.get_link_component_attrs("Noop"), None);
+        assert_eq!(settings.react.version(), Some((18, 2, 0)));
+        assert_eq!(settings.react.pragma(), "h");
+        assert_eq!(settings.react.fragment(), "Fragment");
+        assert!(settings.react.is_component_wrapper_function(None, "observer"));
+        assert!(settings.react.is_component_wrapper_function(Some("Mobx"), "observer"));
+        assert!(settings.react.is_component_wrapper_function(Some("emotion"), "styled"));
+        assert!(!settings.react.is_component_wrapper_function(Some("other"), "observer"));
+        assert_eq!(settings.jest.major_version(), Some(27));
+        assert!(settings.import_resolver.node.extensions.is_empty());
+        assert_eq!(
+            settings.import_resolver.tsconfig(),
+            Some(&std::path::PathBuf::from("./tsconfig.base.json"))
+        );
     }
 
     #[test]
@@ -86,5 +124,9 @@ mod test {
         let settings = OxlintSettings::default();
         assert!(settings.jsx_a11y.polymorphic_prop_name.is_none());
         assert!(settings.jsx_a11y.components.is_empty());
+        assert!(settings.react.version().is_none());
+        assert_eq!(settings.react.pragma(), "React");
+        assert!(settings.jest.major_version().is_none());
+        assert!(settings.import_resolver.tsconfig().is_none());
     }
 }
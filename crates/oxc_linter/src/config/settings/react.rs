@@ -11,10 +11,61 @@ pub struct ReactPluginSettings {
     #[serde(default)]
     #[serde(rename = "linkComponents")]
     link_components: Vec<CustomComponent>,
+
+    /// The version of React used by the project, e.g. `"18.2.0"`. `"detect"` is not supported yet.
+    #[serde(default)]
+    version: Option<String>,
+
+    /// Functions wrapping components, e.g. `observer` or `{ "property": "styled" }`
+    #[serde(default)]
+    #[serde(rename = "componentWrapperFunctions")]
+    component_wrapper_functions: Vec<ComponentWrapperFunction>,
+
+    /// The object of the `createElement` function JSX is compiled to, e.g. `h` for Preact.
+    /// Defaults to `React`, overridden in a file by a `/** @jsx h */` comment.
+    #[serde(default)]
+    pragma: Option<String>,
+
+    /// The component of the JSX fragments, e.g. `Fragment`. Defaults to `Fragment`, overridden in
+    /// a file by a `/** @jsxFrag Fragment */` comment.
+    #[serde(default)]
+    #[serde(alias = "jsxFragmentFactory")]
+    fragment: Option<String>,
     // TODO: More properties should be added
 }
 
 impl ReactPluginSettings {
+    /// The configured React version as `(major, minor, patch)`, missing parts defaulting to `0`.
+    pub fn version(&self) -> Option<(u32, u32, u32)> {
+        let mut parts = self.version.as_deref()?.split('.').map(str::parse::<u32>);
+        let major = parts.next()?.ok()?;
+        let minor = parts.next().and_then(Result::ok).unwrap_or_default();
+        let patch = parts.next().and_then(Result::ok).unwrap_or_default();
+        Some((major, minor, patch))
+    }
+
+    /// The configured JSX pragma, `React` by default.
+    pub fn pragma(&self) -> &str {
+        self.pragma.as_deref().unwrap_or("React")
+    }
+
+    /// The configured JSX fragment component, `Fragment` by default.
+    pub fn fragment(&self) -> &str {
+        self.fragment.as_deref().unwrap_or("Fragment")
+    }
+
+    /// Whether calling `property`, or `object.property` when `object` is given, wraps a component
+    /// according to `componentWrapperFunctions`.
+    pub fn is_component_wrapper_function(&self, object: Option<&str>, property: &str) -> bool {
+        self.component_wrapper_functions.iter().any(|function| match function {
+            ComponentWrapperFunction::Name(name) => object.is_none() && name == property,
+            ComponentWrapperFunction::Property { object: wrapper_object, property: name } => {
+                name == property
+                    && wrapper_object.as_ref().map_or(true, |o| object == Some(o.as_str()))
+            }
+        })
+    }
+
     pub fn get_form_component_attrs(&self, name: &str) -> Option<Vec<String>> {
         get_component_attrs_by_name(&self.form_components, name)
     }
@@ -26,6 +77,13 @@ impl ReactPluginSettings {
 
 // Deserialize helper types
 
+#[derive(Clone, Debug, Deserialize, JsonSchema)]
+#[serde(untagged)]
+enum ComponentWrapperFunction {
+    Name(String),
+    Property { property: String, object: Option<String> },
+}
+
 #[derive(Clone, Debug, Deserialize, JsonSchema)]
 #[serde(untagged)]
 enum CustomComponent {
@@ -0,0 +1,94 @@
+use std::path::Path;
+
+use globset::GlobSet;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer};
+
+use crate::config::severity_overrides::{deserialize_globs, normalize_path};
+
+/// The test files, which the rules of the `jest` and `vitest` plugins are written for.
+#[derive(Debug, Deserialize, Default, JsonSchema)]
+pub struct TestSettings {
+    /// Glob patterns of the test files, relative to the current working directory, e.g.
+    /// `["**/*.spec.ts", "e2e/**"]`. The rules of the `jest` and `vitest` plugins only run on
+    /// the test files when set.
+    ///
+    /// Files named `*.test.*` or `*.spec.*`, and files in `__tests__` directories, are test files
+    /// by default.
+    #[serde(default, deserialize_with = "deserialize_patterns")]
+    #[schemars(with = "Option<Vec<String>>")]
+    patterns: Option<GlobSet>,
+}
+
+impl TestSettings {
+    /// Whether the test files are configured with `patterns`.
+    pub fn has_patterns(&self) -> bool {
+        self.patterns.is_some()
+    }
+
+    /// Whether the file at `path` is a test file.
+    pub fn is_test_file(&self, path: &Path) -> bool {
+        match &self.patterns {
+            Some(patterns) => patterns.is_match(normalize_path(path)),
+            None => is_default_test_file(path),
+        }
+    }
+}
+
+fn is_default_test_file(path: &Path) -> bool {
+    if path.components().any(|component| component.as_os_str() == "__tests__") {
+        return true;
+    }
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    // The extensions, e.g. `test` and `ts` of `foo.test.ts`
+    let mut extensions = file_name.split('.').skip(1).collect::<Vec<_>>();
+    extensions.pop();
+    extensions.iter().any(|extension| matches!(*extension, "test" | "spec"))
+}
+
+fn deserialize_patterns<'de, D>(deserializer: D) -> Result<Option<GlobSet>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_globs(deserializer).map(Some)
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::TestSettings;
+
+    #[test]
+    fn test_default_test_files() {
+        let settings = TestSettings::default();
+        assert!(!settings.has_patterns());
+        assert!(settings.is_test_file(Path::new("src/foo.test.ts")));
+        assert!(settings.is_test_file(Path::new("src/foo.spec.jsx")));
+        assert!(settings.is_test_file(Path::new("src/foo.unit.test.js")));
+        assert!(settings.is_test_file(Path::new("src/__tests__/foo.js")));
+        assert!(!settings.is_test_file(Path::new("src/foo.ts")));
+        assert!(!settings.is_test_file(Path::new("src/test.ts")));
+        assert!(!settings.is_test_file(Path::new("src/testing/foo.ts")));
+        assert!(!settings.is_test_file(Path::new("src/foo.test")));
+    }
+
+    #[test]
+    fn test_patterns() {
+        let settings = TestSettings::deserialize(&serde_json::json!({
+            "patterns": ["e2e/**", "**/*.check.ts"]
+        }))
+        .unwrap();
+        assert!(settings.has_patterns());
+        assert!(settings.is_test_file(Path::new("e2e/login.ts")));
+        assert!(settings.is_test_file(Path::new("./src/foo.check.ts")));
+        // The patterns replace the default test files
+        assert!(!settings.is_test_file(Path::new("src/foo.test.ts")));
+
+        assert!(TestSettings::deserialize(&serde_json::json!({ "patterns": ["[**"] })).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+};
+
+use globset::{Glob, GlobBuilder, GlobSet, GlobSetBuilder};
+use schemars::JsonSchema;
+use serde::{de, Deserialize, Deserializer};
+
+use super::{rules::parse_rule_key, transform_rule_and_plugin_name};
+use crate::{AllowWarnDeny, RuleWithSeverity};
+
+/// Change the severity of enabled rules for files matching a set of glob patterns.
+///
+/// This allows a codebase to adopt stricter rules progressively, e.g. by downgrading rules to
+/// warnings for legacy code (`"files": ["src/legacy/**"], "severity": "warn"`) while escalating
+/// them to errors for new code (`"files": ["src/new/**"], "severity": "error"`).
+/// When several entries match a file, the last one wins.
+#[derive(Debug, Default, Deserialize, JsonSchema)]
+pub struct OxlintSeverityOverrides(Vec<SeverityOverride>);
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SeverityOverride {
+    /// Glob patterns of the files to apply this override to,
+    /// relative to the current working directory.
+    #[serde(deserialize_with = "deserialize_globs")]
+    #[schemars(with = "Vec<String>")]
+    files: GlobSet,
+    /// Rules affected by this override, e.g. `"eqeqeq"` or `"@typescript-eslint/ban-types"`.
+    /// All enabled rules are affected when omitted.
+    #[serde(default)]
+    #[schemars(with = "Vec<String>")]
+    rules: Option<Vec<String>>,
+    /// The severity to use. `"off"` disables the rules for the matching files.
+    severity: AllowWarnDeny,
+}
+
+impl OxlintSeverityOverrides {
+    /// Rules referenced by the overrides, as written in the configuration.
+    pub(super) fn rule_keys(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().filter_map(|o| o.rules.as_ref()).flatten().map(String::as_str)
+    }
+
+    /// Collect the overrides applying to the file at `path`.
+    pub fn matching(&self, path: &Path) -> MatchedSeverityOverrides<'_> {
+        if self.0.is_empty() {
+            return MatchedSeverityOverrides(vec![]);
+        }
+        let path = normalize_path(path);
+        MatchedSeverityOverrides(
+            self.0.iter().enumerate().filter(|(_, o)| o.files.is_match(&path)).collect(),
+        )
+    }
+}
+
+/// Severity overrides applying to a single file with their index in the configuration, see
+/// [`OxlintSeverityOverrides::matching`].
+pub struct MatchedSeverityOverrides<'a>(Vec<(usize, &'a SeverityOverride)>);
+
+impl MatchedSeverityOverrides<'_> {
+    /// Resolve the severity of `rule`, the last matching override wins.
+    pub fn resolve(&self, rule: &RuleWithSeverity) -> AllowWarnDeny {
+        self.find(rule).map_or(rule.severity, |(_, o)| o.severity)
+    }
+
+    /// The index in the configuration of the override deciding the severity of `rule`, if any.
+    pub fn index_of(&self, rule: &RuleWithSeverity) -> Option<usize> {
+        self.find(rule).map(|(index, _)| *index)
+    }
+
+    fn find(&self, rule: &RuleWithSeverity) -> Option<&(usize, &SeverityOverride)> {
+        self.0.iter().rev().find(|(_, o)| o.matches_rule(rule))
+    }
+}
+
+impl SeverityOverride {
+    fn matches_rule(&self, rule: &RuleWithSeverity) -> bool {
+        let Some(rules) = &self.rules else { return true };
+        rules.iter().any(|key| {
+            let (plugin_name, rule_name) = parse_rule_key(key);
+            let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+            rule.name() == rule_name && rule.plugin_name() == plugin_name
+        })
+    }
+}
+
+/// Make `path` relative to the current working directory and strip `./` components,
+/// so patterns such as `src/**` match regardless of how the path was passed in.
+pub(crate) fn normalize_path(path: &Path) -> PathBuf {
+    let path = env::current_dir().ok().and_then(|cwd| path.strip_prefix(cwd).ok()).unwrap_or(path);
+    path.components().filter(|c| !matches!(c, Component::CurDir)).collect()
+}
+
+pub(crate) fn deserialize_globs<'de, D>(deserializer: D) -> Result<GlobSet, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let patterns = Vec::<String>::deserialize(deserializer)?;
+    let mut builder = GlobSetBuilder::new();
+    for pattern in &patterns {
+        builder.add(build_glob(pattern).map_err(de::Error::custom)?);
+    }
+    builder.build().map_err(de::Error::custom)
+}
+
+fn build_glob(pattern: &str) -> Result<Glob, globset::Error> {
+    let pattern = pattern.trim_start_matches("./");
+    GlobBuilder::new(pattern).literal_separator(true).build()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use serde::Deserialize;
+
+    use super::OxlintSeverityOverrides;
+    use crate::{rules::RULES, AllowWarnDeny, RuleWithSeverity};
+
+    fn rule(name: &str) -> RuleWithSeverity {
+        let rule = RULES.iter().find(|r| r.name() == name).unwrap().clone();
+        RuleWithSeverity::new(rule, AllowWarnDeny::Warn)
+    }
+
+    #[test]
+    fn test_resolve() {
+        let overrides = OxlintSeverityOverrides::deserialize(&serde_json::json!([
+            { "files": ["src/legacy/**"], "rules": ["no-debugger"], "severity": "off" },
+            { "files": ["src/new/**", "*.config.js"], "severity": 2 },
+            { "files": ["src/new/vendor/**"], "rules": ["eslint/eqeqeq"], "severity": "warn" },
+        ]))
+        .unwrap();
+
+        let no_debugger = rule("no-debugger");
+        let eqeqeq = rule("eqeqeq");
+
+        let resolve =
+            |rule: &RuleWithSeverity, path: &str| overrides.matching(Path::new(path)).resolve(rule);
+
+        assert_eq!(resolve(&no_debugger, "src/legacy/a/b.js"), AllowWarnDeny::Allow);
+        assert_eq!(resolve(&eqeqeq, "src/legacy/a/b.js"), AllowWarnDeny::Warn);
+        assert_eq!(resolve(&no_debugger, "./src/new/a.ts"), AllowWarnDeny::Deny);
+        assert_eq!(resolve(&eqeqeq, "src/new/a.ts"), AllowWarnDeny::Deny);
+        assert_eq!(resolve(&eqeqeq, "src/new/vendor/a.ts"), AllowWarnDeny::Warn);
+        assert_eq!(resolve(&no_debugger, "src/new/vendor/a.ts"), AllowWarnDeny::Deny);
+        assert_eq!(resolve(&eqeqeq, "vite.config.js"), AllowWarnDeny::Deny);
+        // `*` does not cross directory boundaries
+        assert_eq!(resolve(&eqeqeq, "src/vite.config.js"), AllowWarnDeny::Warn);
+        assert_eq!(resolve(&eqeqeq, "src/other.js"), AllowWarnDeny::Warn);
+
+        let index_of = |rule: &RuleWithSeverity, path: &str| {
+            overrides.matching(Path::new(path)).index_of(rule)
+        };
+        assert_eq!(index_of(&no_debugger, "src/new/vendor/a.ts"), Some(1));
+        assert_eq!(index_of(&eqeqeq, "src/new/vendor/a.ts"), Some(2));
+        assert_eq!(index_of(&eqeqeq, "src/other.js"), None);
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(OxlintSeverityOverrides::deserialize(&serde_json::json!([
+            { "files": ["src/**"], "severity": "bogus" }
+        ]))
+        .is_err());
+        assert!(OxlintSeverityOverrides::deserialize(&serde_json::json!([
+            { "files": ["src/[**"], "severity": "warn" }
+        ]))
+        .is_err());
+    }
+}
@@ -1,17 +1,35 @@
-use std::{cell::RefCell, path::Path, rc::Rc, sync::Arc};
+use std::{
+    cell::RefCell,
+    env,
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::Arc,
+};
 
-use oxc_cfg::ControlFlowGraph;
+use oxc_ast::{
+    ast::{self, IdentifierReference},
+    AstKind, CommentKind,
+};
+use oxc_cfg::{ControlFlowGraph, FunctionCfg};
 use oxc_diagnostics::{OxcDiagnostic, Severity};
-use oxc_semantic::{AstNodes, JSDocFinder, ScopeTree, Semantic, SymbolTable};
+use oxc_resolver::{Resolution, Resolver};
+use oxc_semantic::{AstNode, AstNodes, JSDocFinder, ScopeTree, Semantic, SymbolTable};
 use oxc_span::{SourceType, Span};
 use oxc_syntax::module_record::ModuleRecord;
 
 use crate::{
     config::OxlintRules,
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{CompositeFix, Message, RuleFixer},
+    fixer::{CompositeFix, Message, RuleFixer, Suggestion},
+    frameworks::FrameworkFlags,
     javascript_globals::GLOBALS,
-    AllowWarnDeny, OxlintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
+    module_format::ModuleFormat,
+    module_graph::ModuleGraph,
+    package_json::PackageJson,
+    security,
+    tsconfig::Tsconfig,
+    utils::{get_function_nearest_jsdoc_node, JSDocSummary},
+    AllowWarnDeny, GlobalValue, OxlintConfig, OxlintEnv, OxlintGlobals, OxlintSettings,
 };
 
 #[derive(Clone)]
@@ -25,13 +43,35 @@ pub struct LintContext<'a> {
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
+    /// Whether or not to apply code fixes which may change the behavior of the code.
+    fix_dangerously: bool,
+
+    /// Whether the rules of the security preset attach the CWE id of their weakness to their
+    /// diagnostics, see [`LintOptions::security`](crate::LintOptions::security).
+    security: bool,
+
     file_path: Rc<Path>,
 
     eslint_config: Arc<OxlintConfig>,
 
+    module_graph: Arc<ModuleGraph>,
+
+    resolver: Option<Arc<Resolver>>,
+
+    /// The nearest `package.json` of the file
+    package_json: Option<Arc<PackageJson>>,
+
+    /// The `tsconfig.json` of the file
+    tsconfig: Option<Arc<Tsconfig>>,
+
+    /// Frameworks the file is written for, detected once per file.
+    frameworks: FrameworkFlags,
+
     // states
     current_rule_name: &'static str,
 
+    current_plugin_name: &'static str,
+
     severity: Severity,
 }
 
@@ -53,9 +93,17 @@ impl<'a> LintContext<'a> {
             diagnostics: RefCell::new(vec![]),
             disable_directives: Rc::new(disable_directives),
             fix: false,
+            fix_dangerously: false,
+            security: false,
             file_path: file_path.into(),
             eslint_config: Arc::new(OxlintConfig::default()),
+            module_graph: Arc::default(),
+            resolver: None,
+            package_json: None,
+            tsconfig: None,
+            frameworks: FrameworkFlags::None,
             current_rule_name: "",
+            current_plugin_name: "",
             severity: Severity::Warning,
         }
     }
@@ -66,18 +114,70 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, fix_dangerously: bool) -> Self {
+        self.fix_dangerously = fix_dangerously;
+        self
+    }
+
+    #[must_use]
+    pub fn with_security(mut self, security: bool) -> Self {
+        self.security = security;
+        self
+    }
+
     #[must_use]
     pub fn with_eslint_config(mut self, eslint_config: &Arc<OxlintConfig>) -> Self {
         self.eslint_config = Arc::clone(eslint_config);
         self
     }
 
+    /// Detect the frameworks of the file, see [`LintContext::frameworks`].
+    ///
+    /// Must be called after [`LintContext::with_eslint_config`], as the configuration is used
+    /// for detection.
+    #[must_use]
+    pub fn with_module_graph(mut self, module_graph: &Arc<ModuleGraph>) -> Self {
+        self.module_graph = Arc::clone(module_graph);
+        self
+    }
+
+    #[must_use]
+    pub fn with_resolver(mut self, resolver: &Arc<Resolver>) -> Self {
+        self.resolver = Some(Arc::clone(resolver));
+        self
+    }
+
+    #[must_use]
+    pub fn with_package_json(mut self, package_json: Option<Arc<PackageJson>>) -> Self {
+        self.package_json = package_json;
+        self
+    }
+
+    #[must_use]
+    pub fn with_tsconfig(mut self, tsconfig: Option<Arc<Tsconfig>>) -> Self {
+        self.tsconfig = tsconfig;
+        self
+    }
+
+    #[must_use]
+    pub fn with_frameworks_detected(mut self) -> Self {
+        self.frameworks = FrameworkFlags::detect(&self);
+        self
+    }
+
     #[must_use]
     pub fn with_rule_name(mut self, name: &'static str) -> Self {
         self.current_rule_name = name;
         self
     }
 
+    #[must_use]
+    pub fn with_plugin_name(mut self, name: &'static str) -> Self {
+        self.current_plugin_name = name;
+        self
+    }
+
     #[must_use]
     pub fn with_severity(mut self, severity: AllowWarnDeny) -> Self {
         self.severity = Severity::from(severity);
@@ -97,6 +197,18 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// The control flow graph of the function containing `node`, or of the program when `node` is
+    /// not in a function. The graph of a function node is the graph of its own body.
+    pub fn function_cfg(&self, node: &AstNode<'a>) -> FunctionCfg<'_> {
+        let function = self.nodes().iter_parents(node.id()).find(|node| {
+            matches!(
+                node.kind(),
+                AstKind::Function(_) | AstKind::ArrowFunctionExpression(_) | AstKind::Program(_)
+            )
+        });
+        FunctionCfg::new(self.cfg(), function.unwrap_or(node).cfg_id())
+    }
+
     pub fn disable_directives(&self) -> &DisableDirectives<'a> {
         &self.disable_directives
     }
@@ -120,6 +232,7 @@ impl<'a> LintContext<'a> {
         &self.file_path
     }
 
+    /// Shared settings of the plugins, e.g. `react.version` or `jsx-a11y.components`.
     pub fn settings(&self) -> &OxlintSettings {
         &self.eslint_config.settings
     }
@@ -136,18 +249,101 @@ impl<'a> LintContext<'a> {
         &self.eslint_config.rules
     }
 
+    /// Frameworks and environments the file is written for, detected from its imports,
+    /// its path and the configuration.
+    /// The object of the `createElement` function JSX is compiled to, `React` unless set by a
+    /// `/** @jsx h */` comment or `settings.react.pragma`, e.g. `h` for Preact. Only the object
+    /// of a member expression is returned, `preact` for `@jsx preact.h`.
+    pub fn jsx_pragma(&self) -> &str {
+        match self.semantic().jsx_pragmas().factory {
+            Some(factory) => factory.split('.').next().unwrap_or(factory),
+            None => self.settings().react.pragma(),
+        }
+    }
+
+    /// The component of the JSX fragments, `Fragment` unless set by a `/** @jsxFrag */` comment or
+    /// `settings.react.fragment`. Only the property of a member expression is returned,
+    /// `Fragment` for `@jsxFrag preact.Fragment`.
+    pub fn jsx_fragment(&self) -> &str {
+        match self.semantic().jsx_pragmas().fragment {
+            Some(fragment) => fragment.rsplit('.').next().unwrap_or(fragment),
+            None => self.settings().react.fragment(),
+        }
+    }
+
+    pub fn frameworks(&self) -> FrameworkFlags {
+        self.frameworks
+    }
+
+    /// Whether the file is a test file, matching `settings.test.patterns` or named like a test
+    /// file by default, e.g. `foo.test.ts` or `__tests__/foo.ts`.
+    pub fn is_test_file(&self) -> bool {
+        self.settings().test.is_test_file(self.file_path())
+    }
+
+    /// Whether the file is a TypeScript declaration file, e.g. `index.d.ts`, on which the rules
+    /// which report the way declarations are written don't run.
+    pub fn is_declaration_file(&self) -> bool {
+        self.source_type().is_typescript_definition()
+    }
+
+    /// Whether `var` is a global variable of the builtin environment or one of the configured `env`.
     pub fn env_contains_var(&self, var: &str) -> bool {
-        if GLOBALS["builtin"].contains_key("var") {
-            return true;
+        self.env_var(var).is_some()
+    }
+
+    /// Whether the global variable `var` of the environments is writeable.
+    fn env_var(&self, var: &str) -> Option<bool> {
+        // The builtin globals are always available, and the `lib` of the tsconfig declares the
+        // globals of its environments
+        let lib_envs = self.tsconfig().into_iter().flat_map(|t| t.compiler_options.lib_envs());
+        std::iter::once("builtin")
+            .chain(self.env().iter())
+            .chain(lib_envs)
+            .filter_map(|env| GLOBALS.get(env)?.get(var).copied())
+            .reduce(|a, b| a || b)
+    }
+
+    /// The global variable `name` declared by the configured `globals` or `env`, `None` when it is
+    /// not a global variable or when it is shadowed by a declaration in the root scope of the file.
+    /// `globals` take precedence over `env`, e.g. `{ "window": "off" }` removes `window` of the
+    /// browser environment.
+    pub fn global(&self, name: &str) -> Option<GlobalValue> {
+        if self.scopes().get_binding(self.scopes().root_scope_id(), name).is_some() {
+            return None;
         }
-        for env in self.env().iter() {
-            if let Some(env) = GLOBALS.get(env) {
-                if env.contains_key(var) {
-                    return true;
+        match self.globals().get(name) {
+            Some(GlobalValue::Off) => None,
+            Some(value) => Some(value),
+            None => self.env_var(name).map(|writeable| {
+                if writeable {
+                    GlobalValue::Writeable
+                } else {
+                    GlobalValue::Readonly
                 }
-            }
+            }),
         }
-        false
+    }
+
+    /// Whether `name` is a global variable, see [`LintContext::global`].
+    pub fn is_global(&self, name: &str) -> bool {
+        self.global(name).is_some()
+    }
+
+    /// Whether `ident` refers to the global variable `name`, e.g. `undefined`, `globalThis` or
+    /// `require`, and not to a declaration which shadows it, like the parameter of
+    /// `function (undefined) { return undefined }`.
+    pub fn is_global_reference(&self, ident: &IdentifierReference, name: &str) -> bool {
+        ident.name == name && self.is_unresolved_reference(ident)
+    }
+
+    /// Whether `ident` does not refer to a declaration of the file, i.e. it refers to a global
+    /// variable, which is not necessarily declared by the configured `globals` or `env`.
+    pub fn is_unresolved_reference(&self, ident: &IdentifierReference) -> bool {
+        ident.reference_id.get().map_or_else(
+            || self.semantic().is_reference_to_global_variable(ident),
+            |reference_id| self.symbols().is_global_reference(reference_id),
+        )
     }
 
     /* Diagnostics */
@@ -158,10 +354,17 @@ impl<'a> LintContext<'a> {
 
     fn add_diagnostic(&self, message: Message<'a>) {
         if !self.disable_directives.contains(self.current_rule_name, message.start()) {
-            let mut message = message;
+            let mut message = message
+                .with_rule_name(self.current_rule_name)
+                .with_plugin_name(self.current_plugin_name);
             if message.error.severity != self.severity {
                 message.error = message.error.with_severity(self.severity);
             }
+            if self.security {
+                if let Some(cwe) = security::cwe(self.current_plugin_name, self.current_rule_name) {
+                    message.error = message.error.with_cwe(cwe);
+                }
+            }
             self.diagnostics.borrow_mut().push(message);
         }
     }
@@ -189,10 +392,57 @@ impl<'a> LintContext<'a> {
         }
     }
 
+    /// Report a lint rule violation and provide an automatic fix which may change the behavior of
+    /// the code, e.g. by removing code with side effects. It is only applied with `--fix-dangerously`.
+    pub fn diagnostic_with_dangerous_fix<C, F>(&self, diagnostic: OxcDiagnostic, fix: F)
+    where
+        C: Into<CompositeFix<'a>>,
+        F: FnOnce(RuleFixer<'_, 'a>) -> C,
+    {
+        if self.fix_dangerously {
+            self.diagnostic_with_fix(diagnostic, fix);
+        } else {
+            self.diagnostic(diagnostic);
+        }
+    }
+
+    /// Report a lint rule violation and provide fixes for the user to choose from.
+    ///
+    /// Unlike [`LintContext::diagnostic_with_fix`], suggestions are never applied automatically,
+    /// use them for fixes which may change the behavior of the code.
+    pub fn diagnostic_with_suggestions<F>(&self, diagnostic: OxcDiagnostic, suggest: F)
+    where
+        F: FnOnce(RuleFixer<'_, 'a>) -> Vec<Suggestion<'a>>,
+    {
+        let suggestions = suggest(RuleFixer::new(self));
+        self.add_diagnostic(Message::new(diagnostic, None).with_suggestions(suggestions));
+    }
+
     pub fn nodes(&self) -> &AstNodes<'a> {
         self.semantic().nodes()
     }
 
+    /// The parent of `node`, `None` for the `Program`.
+    pub fn parent_node(&self, node: &AstNode<'a>) -> Option<&AstNode<'a>> {
+        self.nodes().parent_node(node.id())
+    }
+
+    /// The ancestors of `node`, from its parent to the `Program`.
+    pub fn ancestors(&self, node: &AstNode<'a>) -> impl Iterator<Item = &AstNode<'a>> + '_ {
+        self.nodes().iter_parents(node.id()).skip(1)
+    }
+
+    /// The nearest ancestor of `node` which is a `K`, e.g.
+    /// `ctx.nearest_ancestor_of_kind::<ArrowFunctionExpression>(node)` for the arrow function
+    /// containing a `return` statement. Note that it may be outside of the function containing
+    /// `node`.
+    pub fn nearest_ancestor_of_kind<K: FromAstKind<'a>>(
+        &self,
+        node: &AstNode<'a>,
+    ) -> Option<&'a K> {
+        self.ancestors(node).find_map(|node| K::from_ast_kind(node.kind()))
+    }
+
     pub fn scopes(&self) -> &ScopeTree {
         self.semantic().scopes()
     }
@@ -205,8 +455,323 @@ impl<'a> LintContext<'a> {
         self.semantic().module_record()
     }
 
+    /// The modules of the project, populated when the import plugin is enabled.
+    pub fn module_graph(&self) -> &ModuleGraph {
+        &self.module_graph
+    }
+
+    /// The `package.json` in the directory of the file or the nearest of its ancestors, e.g. for
+    /// the declared dependencies, `engines.node` or `"type": "module"`. `None` when there is
+    /// none, or when the file is not on disk.
+    pub fn package_json(&self) -> Option<&PackageJson> {
+        self.package_json.as_deref()
+    }
+
+    /// The `tsconfig.json` of the file, the one given with `--tsconfig` or the `import/resolver`
+    /// settings, else the nearest one, e.g. for the `target`, the `lib` or the `strict` flags of
+    /// its `compilerOptions`, including the inherited ones. `None` when there is none, or when
+    /// the file is not on disk.
+    pub fn tsconfig(&self) -> Option<&Tsconfig> {
+        self.tsconfig.as_deref()
+    }
+
+    /// Whether the file is an ES module or a CommonJS module, from its extension and the `"type"`
+    /// of its `package.json`. `None` when they do not tell, see [`ModuleFormat::infer`].
+    pub fn module_format(&self) -> Option<ModuleFormat> {
+        ModuleFormat::infer(self.file_path(), self.package_json())
+    }
+
+    /// Resolve the import specifier `specifier`, e.g. `./foo` or `lodash/fp`, from the directory
+    /// of the file, following tsconfig `paths`, the `exports` of packages and the extensions of
+    /// the `import/resolver` settings.
+    /// Returns `None` when the specifier does not resolve, or when the file is not linted by a
+    /// [`LintService`](crate::LintService).
+    pub fn resolve(&self, specifier: &str) -> Option<PathBuf> {
+        let resolver = self.resolver.as_ref()?;
+        let path = self.absolute_file_path()?;
+        resolver.resolve(path.parent()?, specifier).ok().map(Resolution::into_path_buf)
+    }
+
+    /// The path of the file joined to the current working directory, like the paths returned by
+    /// [`LintContext::resolve`]. [`LintContext::file_path`] is relative when oxlint is given
+    /// relative paths.
+    pub fn absolute_file_path(&self) -> Option<PathBuf> {
+        if self.file_path.is_absolute() {
+            Some(self.file_path.to_path_buf())
+        } else {
+            Some(env::current_dir().ok()?.join(&self.file_path))
+        }
+    }
+
+    /* Comments */
+
+    /// The comments of the file in source order, with the spans of their text without the `//`,
+    /// `/*` and `*/` delimiters.
+    pub fn comments(&self) -> impl Iterator<Item = (CommentKind, Span)> + '_ {
+        self.semantic().trivias().comments()
+    }
+
+    /// The comments within `span`, e.g. in the body of a function.
+    pub fn comments_in_span(&self, span: Span) -> impl Iterator<Item = (CommentKind, Span)> + '_ {
+        self.semantic()
+            .trivias()
+            .comments_range(span.start..span.end.max(span.start))
+            .filter(move |(_, comment)| comment.end <= span.end)
+            .map(|(start, comment)| (comment.kind, Span::new(*start, comment.end)))
+    }
+
+    /// Whether there is a comment between the end of `a` and the start of `b`, which a fix
+    /// replacing both would remove, e.g. in `return /* comment */ undefined;`.
+    pub fn has_comment_between(&self, a: Span, b: Span) -> bool {
+        self.comments_in_span(Span::new(a.end, b.start)).next().is_some()
+    }
+
     /* JSDoc */
     pub fn jsdoc(&self) -> &JSDocFinder<'a> {
         self.semantic().jsdoc()
     }
+
+    /// The tags of the JSDoc nearest to `node`, which is attached to the parent of functions in
+    /// cases like `/** ... */ const foo = () => {}`.
+    pub fn jsdoc_of(&self, node: &AstNode<'a>) -> Option<JSDocSummary<'a>> {
+        let jsdoc_node = get_function_nearest_jsdoc_node(node, self)?;
+        let jsdoc = self.jsdoc().get_one_by_node(jsdoc_node)?;
+        Some(JSDocSummary::new(&jsdoc, &self.settings().jsdoc))
+    }
+}
+
+/// An AST node which can be found with [`LintContext::nearest_ancestor_of_kind`].
+pub trait FromAstKind<'a>: Sized {
+    /// The node of `kind`, `None` when it is another kind of node.
+    fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self>;
+}
+
+macro_rules! impl_from_ast_kind {
+    ($($kind:ident),* $(,)?) => {
+        $(
+            impl<'a> FromAstKind<'a> for ast::$kind<'a> {
+                fn from_ast_kind(kind: AstKind<'a>) -> Option<&'a Self> {
+                    match kind {
+                        AstKind::$kind(node) => Some(node),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_ast_kind!(
+    Program,
+    Function,
+    ArrowFunctionExpression,
+    Class,
+    MethodDefinition,
+    PropertyDefinition,
+    ObjectProperty,
+    CallExpression,
+    NewExpression,
+    AssignmentExpression,
+    VariableDeclaration,
+    VariableDeclarator,
+    ReturnStatement,
+    IfStatement,
+    SwitchStatement,
+    TryStatement,
+    ForStatement,
+    ForInStatement,
+    ForOfStatement,
+    WhileStatement,
+    DoWhileStatement,
+    BlockStatement,
+    ImportDeclaration,
+    ExportNamedDeclaration,
+    ExportDefaultDeclaration,
+    JSXElement,
+    TSInterfaceDeclaration,
+    TSTypeAliasDeclaration,
+    TSModuleDeclaration,
+);
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path, rc::Rc, sync::Arc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::{
+        ast::{ArrowFunctionExpression, Class, Function, ReturnStatement},
+        AstKind, CommentKind,
+    };
+    use oxc_cfg::EdgeType;
+    use oxc_parser::Parser;
+    use oxc_resolver::{ResolveOptions, Resolver};
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{SourceType, Span};
+
+    use super::LintContext;
+
+    #[test]
+    fn test_comments() {
+        let source_text = "// a\nfunction foo() { return /* b */ undefined; }";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_cfg(true)
+            .with_trivias(ret.trivias)
+            .build(program)
+            .semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), Rc::new(semantic));
+
+        let comments = ctx.comments().collect::<Vec<_>>();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(ctx.source_range(comments[0].1), " a");
+        assert_eq!(comments[1].0, CommentKind::MultiLine);
+        assert_eq!(ctx.source_range(comments[1].1), " b ");
+
+        let body = Span::new(20, 49);
+        assert_eq!(ctx.source_range(body), "{ return /* b */ undefined; }");
+        assert_eq!(ctx.comments_in_span(body).count(), 1);
+        assert_eq!(ctx.comments_in_span(Span::new(0, 5)).count(), 1);
+        assert_eq!(ctx.comments_in_span(Span::new(0, 3)).count(), 0);
+
+        let return_keyword = Span::new(22, 28);
+        let undefined = Span::new(37, 46);
+        assert!(ctx.has_comment_between(return_keyword, undefined));
+        assert!(!ctx.has_comment_between(undefined, body));
+    }
+
+    #[test]
+    fn test_function_cfg() {
+        let source_text =
+            "foo(); function a() { if (b) { return; } c(); return; d(); } () => { e(); throw f; }";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), Rc::new(semantic));
+        let call = |name: &str| {
+            ctx.nodes()
+                .iter()
+                .find(|node| {
+                    matches!(node.kind(), AstKind::CallExpression(call) if call.callee_name() == Some(name))
+                })
+                .unwrap()
+        };
+
+        let program_cfg = ctx.function_cfg(call("foo"));
+        assert_eq!(program_cfg.entry(), ctx.nodes().root_node().unwrap().cfg_id());
+        // The blocks of the functions are not part of the program
+        assert!(!program_cfg.basic_blocks().contains(&call("c").cfg_id()));
+
+        let function = ctx.nodes().iter().find(|node| node.kind().is_function_like()).unwrap();
+        let function_cfg = ctx.function_cfg(call("c"));
+        assert_eq!(function_cfg.entry(), function.cfg_id());
+        assert_eq!(ctx.function_cfg(function).entry(), function.cfg_id());
+        let blocks = function_cfg.basic_blocks();
+        assert_eq!(blocks[0], function.cfg_id());
+        assert!(blocks.contains(&call("c").cfg_id()));
+        assert!(blocks.contains(&call("d").cfg_id()));
+        assert!(function_cfg.is_reachable(call("c").cfg_id()));
+        assert!(!function_cfg.is_reachable(call("d").cfg_id()));
+        assert!(function_cfg
+            .predecessors(call("d").cfg_id())
+            .any(|(_, edge)| edge == EdgeType::Unreachable));
+
+        let arrow_cfg = ctx.function_cfg(call("e"));
+        assert_ne!(arrow_cfg.entry(), function_cfg.entry());
+        assert!(arrow_cfg.is_reachable(call("e").cfg_id()));
+        assert!(arrow_cfg
+            .successors(arrow_cfg.entry())
+            .all(|(_, edge)| edge != EdgeType::NewFunction));
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let source_text = "const foo = (): undefined => { function bar() { return undefined; } };";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_typescript(true);
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.ts").into(), Rc::new(semantic));
+        let return_statement = ctx
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.kind(), AstKind::ReturnStatement(_)))
+            .unwrap();
+
+        let parent = ctx.parent_node(return_statement).unwrap();
+        assert!(matches!(parent.kind(), AstKind::FunctionBody(_)));
+        assert!(ctx.parent_node(ctx.nodes().root_node().unwrap()).is_none());
+        let ancestors = ctx.ancestors(return_statement).collect::<Vec<_>>();
+        assert_eq!(ancestors[0].id(), parent.id());
+        assert!(matches!(ancestors.last().unwrap().kind(), AstKind::Program(_)));
+
+        let function = ctx.nearest_ancestor_of_kind::<Function>(return_statement).unwrap();
+        assert_eq!(function.id.as_ref().unwrap().name, "bar");
+        let arrow =
+            ctx.nearest_ancestor_of_kind::<ArrowFunctionExpression>(return_statement).unwrap();
+        assert!(arrow.return_type.is_some());
+        assert!(ctx.nearest_ancestor_of_kind::<Class>(return_statement).is_none());
+        // Not the node itself
+        assert!(ctx.nearest_ancestor_of_kind::<ReturnStatement>(return_statement).is_none());
+    }
+
+    #[test]
+    fn test_global_reference() {
+        let source_text = "undefined; function foo(undefined) { undefined; require; }";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type).with_cfg(true).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), Rc::new(semantic));
+
+        let references = ctx
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::IdentifierReference(ident) => Some(ident),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(references.len(), 3);
+        assert!(ctx.is_global_reference(references[0], "undefined"));
+        // Shadowed by the parameter
+        assert!(!ctx.is_global_reference(references[1], "undefined"));
+        assert!(!ctx.is_unresolved_reference(references[1]));
+        assert!(ctx.is_global_reference(references[2], "require"));
+        assert!(!ctx.is_global_reference(references[2], "undefined"));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let source_text = "";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic =
+            SemanticBuilder::new(source_text, source_type).with_cfg(true).build(program).semantic;
+        // A relative path, like the paths given to oxlint
+        let ctx = LintContext::new(Path::new("fixtures/import/index.js").into(), Rc::new(semantic));
+        let fixtures = env::current_dir().unwrap().join("fixtures/import");
+        assert_eq!(ctx.absolute_file_path(), Some(fixtures.join("index.js")));
+
+        // Outside of a `LintService`
+        assert!(ctx.resolve("./bar").is_none());
+
+        let options =
+            ResolveOptions { extensions: vec![".js".into()], ..ResolveOptions::default() };
+        let ctx = ctx.with_resolver(&Arc::new(Resolver::new(options)));
+        assert_eq!(ctx.resolve("./bar"), Some(fixtures.join("bar.js")));
+        assert_eq!(ctx.resolve("./bar/index"), Some(fixtures.join("bar/index.js")));
+        assert!(ctx.resolve("./no-such-file").is_none());
+    }
 }
@@ -0,0 +1,55 @@
+//! TypeScript declaration files, e.g. `index.d.ts`, only describe the types of code which is
+//! implemented elsewhere. Some rules report the way declarations have to be written, so they are
+//! not run on these files.
+
+use crate::rules::RuleEnum;
+
+/// The `plugin/rule` names of the rules which are not run on declaration files.
+const SKIPPED_RULES: &[&str] = &[
+    // `declare var` declares the globals of scripts
+    "eslint/no-var",
+    // Overloads and declaration merging declare the same name several times
+    "eslint/no-redeclare",
+    "eslint/no-dupe-class-members",
+    "typescript/no-unsafe-declaration-merging",
+    // Private members are used by the implementation
+    "eslint/no-unused-private-class-members",
+    // The parameters and the size of declarations follow the implementation, or are generated
+    "eslint/max-params",
+    "eslint/max-classes-per-file",
+    "eslint/max-lines",
+    // `export {}` turns a declaration file into a module, and a file of `/// <reference />`
+    // comments or an empty interface augment other declarations
+    "typescript/no-useless-empty-export",
+    "typescript/no-empty-interface",
+    "unicorn/no-empty-file",
+    // `undefined` is part of the signatures of overloads
+    "unicorn/no-useless-undefined",
+    // Classes of static members describe existing APIs
+    "unicorn/no-static-only-class",
+];
+
+/// Whether `rule` is run on declaration files, see [`SKIPPED_RULES`].
+pub(crate) fn runs_on_declaration_files(rule: &RuleEnum) -> bool {
+    !SKIPPED_RULES
+        .iter()
+        .any(|skipped| skipped.split_once('/') == Some((rule.plugin_name(), rule.name())))
+}
+
+#[cfg(test)]
+mod test {
+    use super::SKIPPED_RULES;
+    use crate::rules::RULES;
+
+    #[test]
+    fn test_skipped_rules_exist() {
+        for skipped in SKIPPED_RULES {
+            assert!(
+                RULES
+                    .iter()
+                    .any(|rule| format!("{}/{}", rule.plugin_name(), rule.name()) == *skipped),
+                "unknown rule {skipped}"
+            );
+        }
+    }
+}
@@ -1,14 +1,30 @@
+use std::cell::RefCell;
+
 use oxc_ast::Trivias;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 use rust_lapper::{Interval, Lapper};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    fixer::Fix,
+    rule_names::{is_known_rule_name, strip_plugin_name, unknown_rule_diagnostic},
+};
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 enum DisabledRule<'a> {
     All,
     Single(&'a str),
 }
 
+/// A rule disabled by a comment
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct DisableDirective<'a> {
+    /// Span of the comment
+    comment: Span,
+    rule: DisabledRule<'a>,
+}
+
 /// A comment which disables one or more specific rules
 pub struct DisableRuleComment<'a> {
     /// Span of the comment
@@ -19,7 +35,9 @@ pub struct DisableRuleComment<'a> {
 
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
+    intervals: Lapper<u32, DisableDirective<'a>>,
+    /// Directives which disabled a reported problem
+    used: RefCell<FxHashSet<DisableDirective<'a>>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -27,14 +45,25 @@ pub struct DisableDirectives<'a> {
 }
 
 impl<'a> DisableDirectives<'a> {
+    /// Whether `rule_name` is disabled at `start`.
+    /// The directives disabling it are marked as used, see [`DisableDirectives::unused`].
     pub fn contains(&self, rule_name: &'static str, start: u32) -> bool {
-        self.intervals.find(start, start + 1).any(|interval| {
-            interval.val == DisabledRule::All
-                // Our rule name currently does not contain the prefix.
+        let mut used = self.used.borrow_mut();
+        let mut contains = false;
+        for interval in self.intervals.find(start, start + 1) {
+            let disabled = match interval.val.rule {
+                DisabledRule::All => true,
+                // Our rule name does not contain the plugin prefix.
                 // For example, this will match `@typescript-eslint/no-var-requires` given
-                // our rule_name is `no-var-requires`.
-                || matches!(interval.val, DisabledRule::Single(name) if name.contains(rule_name))
-        })
+                // our rule_name is `no-var-requires`, but not `no-var`.
+                DisabledRule::Single(name) => strip_plugin_name(name) == rule_name,
+            };
+            if disabled {
+                used.insert(interval.val);
+                contains = true;
+            }
+        }
+        contains
     }
 
     pub fn disable_all_comments(&self) -> &Vec<Span> {
@@ -44,17 +73,149 @@ impl<'a> DisableDirectives<'a> {
     pub fn disable_rule_comments(&self) -> &Vec<DisableRuleComment<'a>> {
         &self.disable_rule_comments
     }
+
+    /// Warnings for rules referenced by disable comments which do not exist,
+    /// see [`unknown_rule_diagnostic`].
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    pub fn unknown_rules(&self, source_text: &str) -> Vec<OxcDiagnostic> {
+        self.disable_rule_comments
+            .iter()
+            .flat_map(|comment| {
+                let text = comment.span.source_text(source_text);
+                comment.rules.iter().filter(|rule| !is_known_rule_name(rule)).filter_map(
+                    move |rule| {
+                        let diagnostic = unknown_rule_diagnostic(rule)?;
+                        let start = comment.span.start + text.find(rule).unwrap_or_default() as u32;
+                        Some(diagnostic.with_label(Span::new(start, start + rule.len() as u32)))
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Warnings for disable comments which did not disable any reported problem, with a fix
+    /// removing the comment, or the unused rules when some of its rules were used.
+    /// Only meaningful after all rules have run.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    pub fn unused(&self, source_text: &'a str) -> Vec<(OxcDiagnostic, Fix<'a>)> {
+        let used = self.used.borrow();
+        let is_used = |comment: Span, rule: DisabledRule<'a>| {
+            used.contains(&DisableDirective { comment, rule })
+        };
+        let mut unused = vec![];
+
+        for &span in &self.disable_all_comments {
+            if !is_used(span, DisabledRule::All) {
+                let diagnostic = unused_directive_diagnostic(span, source_text, &[]);
+                unused.push((diagnostic, Fix::delete(comment_line_span(span, source_text))));
+            }
+        }
+
+        for comment in &self.disable_rule_comments {
+            let rules =
+                comment.rules.iter().copied().filter(|rule| !rule.is_empty()).collect::<Vec<_>>();
+            let (unused_rules, used_rules): (Vec<&str>, Vec<&str>) =
+                rules.iter().partition(|rule| !is_used(comment.span, DisabledRule::Single(rule)));
+            if unused_rules.is_empty() {
+                continue;
+            }
+            let diagnostic = unused_directive_diagnostic(comment.span, source_text, &unused_rules);
+            let fix = if used_rules.is_empty() {
+                Fix::delete(comment_line_span(comment.span, source_text))
+            } else {
+                // Rewrite the list of rules, keeping the description after `--`
+                let text = comment.span.source_text(source_text);
+                let first = rules[0];
+                let last = rules[rules.len() - 1];
+                let start = comment.span.start + text.find(first).unwrap_or_default() as u32;
+                let end = comment.span.start + text.rfind(last).unwrap_or_default() as u32;
+                Fix::new(used_rules.join(", "), Span::new(start, end + last.len() as u32))
+            };
+            unused.push((diagnostic, fix));
+        }
+
+        unused.sort_unstable_by_key(|(_, fix)| fix.span);
+        unused
+    }
+
+    /// Warnings for disable comments which do not describe why the problems are expected after
+    /// `--`, e.g. `// eslint-disable-next-line no-console -- logging the errors`.
+    pub fn missing_reasons(&self, source_text: &str) -> Vec<OxcDiagnostic> {
+        let mut spans = self
+            .disable_all_comments
+            .iter()
+            .copied()
+            .chain(self.disable_rule_comments.iter().map(|comment| comment.span))
+            .filter(|span| {
+                let reason =
+                    span.source_text(source_text).split_once("--").map(|(_, reason)| reason);
+                reason.map_or(true, |reason| reason.trim().is_empty())
+            })
+            .collect::<Vec<_>>();
+        spans.sort_unstable();
+        spans
+            .into_iter()
+            .map(|span| {
+                let directive = directive_name(span, source_text);
+                OxcDiagnostic::warn(format!("`{directive}` directive without a reason."))
+                    .with_help(format!(
+                        "Describe why the problems are expected after `--`: `{directive} -- reason`"
+                    ))
+                    .with_label(span)
+            })
+            .collect()
+    }
+}
+
+/// The directive of the disable comment whose content is `comment`, e.g. `eslint-disable-line`.
+pub(crate) fn directive_name(comment: Span, source_text: &str) -> &str {
+    comment.source_text(source_text).split_whitespace().next().unwrap_or_default()
+}
+
+fn unused_directive_diagnostic(comment: Span, source_text: &str, rules: &[&str]) -> OxcDiagnostic {
+    let directive = directive_name(comment, source_text);
+    let message = if rules.is_empty() {
+        format!("Unused `{directive}` directive (no problems were reported).")
+    } else {
+        let rules = rules.iter().map(|rule| format!("`{rule}`")).collect::<Vec<_>>().join(" or ");
+        format!("Unused `{directive}` directive (no problems were reported from {rules}).")
+    };
+    OxcDiagnostic::warn(message).with_label(comment)
+}
+
+/// Span of the comment whose content is `comment`, including `//` or `/* */`.
+/// Extended to the whole line when the comment is the only thing on its line.
+#[allow(clippy::cast_possible_truncation)] // for `as u32`
+fn comment_line_span(comment: Span, source_text: &str) -> Span {
+    let start = comment.start - 2;
+    let end = if source_text[..comment.start as usize].ends_with("/*") {
+        comment.end + 2
+    } else {
+        comment.end
+    };
+    let before = &source_text[..start as usize];
+    let after = &source_text[end as usize..];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let line_end = after.find('\n').map_or(source_text.len(), |i| end as usize + i + 1);
+    let indent = &before[line_start..];
+    if indent.trim().is_empty() && source_text[end as usize..line_end].trim().is_empty() {
+        Span::new(line_start as u32, line_end as u32)
+    } else {
+        // Remove the whitespace separating the comment from the code before it
+        Span::new(before.trim_end_matches([' ', '\t']).len() as u32, end)
+    }
 }
 
 pub struct DisableDirectivesBuilder<'a> {
     source_text: &'a str,
     trivias: Trivias,
     /// All the disabled rules with their corresponding covering spans
-    intervals: Lapper<u32, DisabledRule<'a>>,
-    /// Start of `eslint-disable` or `oxlint-disable`
-    disable_all_start: Option<u32>,
-    /// Start of `eslint-disable` or `oxlint-disable` rule_name`
-    disable_start_map: FxHashMap<&'a str, u32>,
+    intervals: Lapper<u32, DisableDirective<'a>>,
+    /// `eslint-disable` or `oxlint-disable` comments which are not closed by an `eslint-enable`
+    disable_all_start: Vec<Span>,
+    /// `eslint-disable rule_name` or `oxlint-disable rule_name` comments
+    /// which are not closed by an `eslint-enable rule_name`
+    disable_start_map: FxHashMap<&'a str, Vec<Span>>,
     /// Spans of comments that disable all rules
     disable_all_comments: Vec<Span>,
     /// All comments that disable one or more specific rules
@@ -67,7 +228,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
             source_text,
             trivias,
             intervals: Lapper::new(vec![]),
-            disable_all_start: None,
+            disable_all_start: vec![],
             disable_start_map: FxHashMap::default(),
             disable_all_comments: vec![],
             disable_rule_comments: vec![],
@@ -78,13 +239,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.build_impl();
         DisableDirectives {
             intervals: self.intervals,
+            used: RefCell::default(),
             disable_all_comments: self.disable_all_comments,
             disable_rule_comments: self.disable_rule_comments,
         }
     }
 
-    fn add_interval(&mut self, start: u32, stop: u32, val: DisabledRule<'a>) {
-        self.intervals.insert(Interval { start, stop, val });
+    fn add_interval(&mut self, start: u32, stop: u32, comment: Span, rule: DisabledRule<'a>) {
+        self.intervals.insert(Interval { start, stop, val: DisableDirective { comment, rule } });
     }
 
     #[allow(clippy::cast_possible_truncation)] // for `as u32`
@@ -97,33 +259,36 @@ impl<'a> DisableDirectivesBuilder<'a> {
             let text = span.source_text(self.source_text);
             let text = text.trim_start();
 
-            if let Some(text) =
-                text.strip_prefix("eslint-disable").or_else(|| text.strip_prefix("oxlint-disable"))
-            {
-                // `eslint-disable`
-                if text.trim().is_empty() {
-                    if self.disable_all_start.is_none() {
-                        self.disable_all_start = Some(span.end);
-                    }
+            if let Some(text) = Self::strip_directive(text, "disable") {
+                // `eslint-disable`, or `eslint-disable -- reason`
+                if Self::strip_keyword(text, "").is_some_and(Self::has_no_rules) {
+                    self.disable_all_start.push(span);
                     self.disable_all_comments.push(span);
                     continue;
                 }
 
                 // `eslint-disable-next-line`
-                if let Some(text) = text.strip_prefix("-next-line") {
-                    // Get the span up to the next new line
-                    let stop = self.source_text[span.end as usize..]
-                        .lines()
-                        .take(2)
-                        .fold(span.end, |acc, line| acc + line.len() as u32);
-                    if text.trim().is_empty() {
-                        self.add_interval(span.end, stop, DisabledRule::All);
+                if let Some(text) = Self::strip_keyword(text, "-next-line") {
+                    // Get the span up to the end of the next line
+                    let rest = &self.source_text[span.end as usize..];
+                    let stop = rest.find('\n').map_or(source_len, |newline| {
+                        let next_line = &rest[newline + 1..];
+                        let len = next_line.find('\n').unwrap_or(next_line.len());
+                        span.end + (newline + 1 + len) as u32
+                    });
+                    if Self::has_no_rules(text) {
+                        self.add_interval(span.end, stop, span, DisabledRule::All);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(span.end, stop, DisabledRule::Single(rule_name));
+                            self.add_interval(
+                                span.end,
+                                stop,
+                                span,
+                                DisabledRule::Single(rule_name),
+                            );
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -132,7 +297,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 }
 
                 // `eslint-disable-line`
-                if let Some(text) = text.strip_prefix("-line") {
+                if let Some(text) = Self::strip_keyword(text, "-line") {
                     // Get the span between the preceding newline to this comment
                     let start = self.source_text[..=span.start as usize]
                         .lines()
@@ -141,14 +306,14 @@ impl<'a> DisableDirectivesBuilder<'a> {
                     let stop = span.start;
 
                     // `eslint-disable-line`
-                    if text.trim().is_empty() {
-                        self.add_interval(start, stop, DisabledRule::All);
+                    if Self::has_no_rules(text) {
+                        self.add_interval(start, stop, span, DisabledRule::All);
                         self.disable_all_comments.push(span);
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
                         Self::get_rule_names(text, |rule_name| {
-                            self.add_interval(start, stop, DisabledRule::Single(rule_name));
+                            self.add_interval(start, stop, span, DisabledRule::Single(rule_name));
                             rules.push(rule_name);
                         });
                         self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -157,9 +322,10 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 }
 
                 // `eslint-disable rule-name1, rule-name2`
+                let Some(text) = Self::strip_keyword(text, "") else { continue };
                 let mut rules = vec![];
                 Self::get_rule_names(text, |rule_name| {
-                    self.disable_start_map.entry(rule_name).or_insert(span.end);
+                    self.disable_start_map.entry(rule_name).or_default().push(span);
                     rules.push(rule_name);
                 });
                 self.disable_rule_comments.push(DisableRuleComment { span, rules });
@@ -168,18 +334,20 @@ impl<'a> DisableDirectivesBuilder<'a> {
             }
 
             if let Some(text) =
-                text.strip_prefix("eslint-enable").or_else(|| text.strip_prefix("oxlint-enable"))
+                Self::strip_directive(text, "enable").and_then(|text| Self::strip_keyword(text, ""))
             {
                 // `eslint-enable`
-                if text.trim().is_empty() {
-                    if let Some(start) = self.disable_all_start.take() {
-                        self.add_interval(start, span.start, DisabledRule::All);
+                if Self::has_no_rules(text) {
+                    for comment in std::mem::take(&mut self.disable_all_start) {
+                        self.add_interval(comment.end, span.start, comment, DisabledRule::All);
                     }
                 } else {
                     // `eslint-enable rule-name1, rule-name2`
                     Self::get_rule_names(text, |rule_name| {
-                        if let Some(start) = self.disable_start_map.remove(rule_name) {
-                            self.add_interval(start, span.start, DisabledRule::Single(rule_name));
+                        for comment in self.disable_start_map.remove(rule_name).unwrap_or_default()
+                        {
+                            let rule = DisabledRule::Single(rule_name);
+                            self.add_interval(comment.end, span.start, comment, rule);
                         }
                     });
                 }
@@ -188,21 +356,49 @@ impl<'a> DisableDirectivesBuilder<'a> {
         }
 
         // Lone `eslint-disable`
-        if let Some(start) = self.disable_all_start {
-            self.add_interval(start, source_len, DisabledRule::All);
+        for comment in std::mem::take(&mut self.disable_all_start) {
+            self.add_interval(comment.end, source_len, comment, DisabledRule::All);
         }
 
         // Lone `eslint-disable rule_name`
         let disable_start_map = self.disable_start_map.drain().collect::<Vec<_>>();
-        for (rule_name, start) in disable_start_map {
-            self.add_interval(start, source_len, DisabledRule::Single(rule_name));
+        for (rule_name, comments) in disable_start_map {
+            for comment in comments {
+                self.add_interval(
+                    comment.end,
+                    source_len,
+                    comment,
+                    DisabledRule::Single(rule_name),
+                );
+            }
         }
     }
 
+    /// Strip `eslint-{directive}` or `oxlint-{directive}` from the start of a comment.
+    fn strip_directive(text: &'a str, directive: &str) -> Option<&'a str> {
+        let text = text.strip_prefix("eslint-").or_else(|| text.strip_prefix("oxlint-"))?;
+        text.strip_prefix(directive)
+    }
+
+    /// Strip `keyword` from the start of `text` when it is followed by whitespace or nothing,
+    /// so that e.g. `eslint-disabled` and `eslint-disable-lines` are not directives.
+    fn strip_keyword(text: &'a str, keyword: &str) -> Option<&'a str> {
+        let text = text.strip_prefix(keyword)?;
+        (text.is_empty() || text.starts_with(char::is_whitespace)).then_some(text)
+    }
+
+    /// The list of rules of a directive, before the reason after `--`.
+    fn rule_list(text: &'a str) -> &'a str {
+        text.split_once("--").map_or(text, |(rules, _)| rules)
+    }
+
+    /// Whether a directive applies to all rules, e.g. `eslint-disable-line -- reason`.
+    fn has_no_rules(text: &'a str) -> bool {
+        Self::rule_list(text).trim().is_empty()
+    }
+
     fn get_rule_names<F: FnMut(&'a str)>(text: &'a str, cb: F) {
-        if let Some(text) = text.split_terminator("--").next() {
-            text.split(',').map(str::trim).for_each(cb);
-        }
+        Self::rule_list(text).split(',').map(str::trim).for_each(cb);
     }
 }
 
@@ -328,6 +524,21 @@ fn test() {
              * along with some additional information
             **/
             debugger;
+        "
+            ),
+            // Descriptions of the directives disabling all rules
+            format!(
+                "
+            // {prefix}-disable-next-line -- Here's a description
+            debugger;
+            debugger; // {prefix}-disable-line -- Here's a description
+        "
+            ),
+            format!(
+                "
+        /* {prefix}-disable -- Here's a description */
+            debugger;
+        /* {prefix}-enable -- Here's a description */
         "
             ),
         ];
@@ -389,3 +600,139 @@ fn test() {
         Tester::new("no-debugger", pass, fail).test();
     }
 }
+
+#[test]
+fn test_rule_names() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var a = 1; // eslint-disable-line no-var",
+        "var a = 1; // eslint-disable-line @typescript-eslint/no-var",
+        "// eslint-disable-next-line no-var\nvar a = 1;",
+    ];
+
+    let fail = vec![
+        // Rules are matched by their full name
+        "var a = require('a'); // eslint-disable-line @typescript-eslint/no-var-requires",
+        // Not directives
+        "var a = 1; // eslint-disabled",
+        "var a = 1; /* eslint-disable */ /* eslint-enabled */\nvar b = 1;",
+        "// eslint-disable-next-lines\nvar a = 1;",
+        // Only the next line is disabled
+        "// eslint-disable-next-line no-var\nvar a = 1;\nvar b = 1;",
+    ];
+
+    Tester::new("no-var", pass, fail).test();
+}
+
+#[test]
+fn test_unknown_rules() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "
+        // eslint-disable-next-line no-debuger, no-alert
+        debugger;
+        /* oxlint-disable @typescript-eslint/no-explict-any, vue/valid-template-root */
+    ";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let directives = DisableDirectivesBuilder::new(source_text, ret.trivias).build();
+    let unknown_rules = directives
+        .unknown_rules(source_text)
+        .into_iter()
+        .map(|diagnostic| {
+            let span = &diagnostic.labels.as_ref().unwrap()[0];
+            let start = span.offset();
+            (diagnostic.to_string(), &source_text[start..start + span.len()])
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        unknown_rules,
+        vec![
+            ("Rule `no-debuger` was not found.".to_string(), "no-debuger"),
+            (
+                "Rule `@typescript-eslint/no-explict-any` was not found.".to_string(),
+                "@typescript-eslint/no-explict-any"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_unused() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::fixer::{Fixer, Message};
+
+    let source_text = "// eslint-disable-next-line no-debugger, no-alert -- reason
+debugger;
+debugger; // eslint-disable-line no-console
+/* eslint-disable */
+/* eslint-enable */
+foo(); /* oxlint-disable-line */
+";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let directives = DisableDirectivesBuilder::new(source_text, ret.trivias).build();
+    let disabled = source_text
+        .match_indices("debugger;")
+        .map(|(start, _)| directives.contains("no-debugger", u32::try_from(start).unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(disabled, [true, false]);
+
+    let unused = directives.unused(source_text);
+    let messages = unused.iter().map(|(diagnostic, _)| diagnostic.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "Unused `eslint-disable-next-line` directive (no problems were reported from `no-alert`).",
+            "Unused `eslint-disable-line` directive (no problems were reported from `no-console`).",
+            "Unused `eslint-disable` directive (no problems were reported).",
+            "Unused `oxlint-disable-line` directive (no problems were reported).",
+        ]
+    );
+
+    let messages =
+        unused.into_iter().map(|(diagnostic, fix)| Message::new(diagnostic, Some(fix))).collect();
+    let result = Fixer::new(source_text, messages).fix();
+    assert_eq!(
+        result.fixed_code,
+        "// eslint-disable-next-line no-debugger -- reason
+debugger;
+debugger;
+/* eslint-enable */
+foo();
+"
+    );
+}
+
+#[test]
+fn test_missing_reasons() {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    let source_text = "// eslint-disable-next-line no-debugger -- reason
+debugger;
+debugger; // eslint-disable-line no-debugger
+/* eslint-disable -- reason */
+/* eslint-enable */
+/* oxlint-disable no-alert -- */
+";
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+    let directives = DisableDirectivesBuilder::new(source_text, ret.trivias).build();
+    let messages =
+        directives.missing_reasons(source_text).iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "`eslint-disable-line` directive without a reason.",
+            "`oxlint-disable` directive without a reason.",
+        ]
+    );
+}
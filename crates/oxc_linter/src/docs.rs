@@ -0,0 +1,175 @@
+//! The documentation of the rules, extracted from the doc comments of
+//! [`declare_oxc_lint`](oxc_macros::declare_oxc_lint), for `oxlint explain` and the website.
+
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::{
+    rules::{RuleEnum, RULES},
+    RuleCategory,
+};
+
+/// The documentation of a rule, split into its `### ` sections.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleDocs {
+    pub name: &'static str,
+    pub plugin: &'static str,
+    /// The category of the rule, e.g. `correctness`
+    pub category: String,
+    /// The kind of fixes of the rule, `fix`, `dangerous_fix` or `suggestion`
+    pub fix: Option<&'static str>,
+    pub sections: Vec<RuleDocsSection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleDocsSection {
+    /// The title of the section, e.g. `What it does`
+    pub title: String,
+    /// The markdown of the section, without leading and trailing blank lines
+    pub body: String,
+}
+
+impl RuleDocs {
+    /// The documentation of all the rules, sorted by plugin and name.
+    pub fn all() -> Vec<Self> {
+        let mut docs = RULES.iter().map(Self::new).collect::<Vec<_>>();
+        docs.sort_unstable_by_key(|docs| (docs.plugin, docs.name));
+        docs
+    }
+
+    /// The documentation of the rules named `rule`, either `name` or `plugin/name`, e.g.
+    /// `no-debugger` or `eslint/no-debugger`. Several plugins may have a rule with the same name.
+    /// Only the documentation of the matching rules is parsed, as it is looked up on every hover
+    /// by the language server.
+    pub fn find(rule: &str) -> Vec<Self> {
+        let (plugin, name) = match rule.split_once('/') {
+            Some((plugin, name)) => (Some(plugin), name),
+            None => (None, rule),
+        };
+        let mut docs = RULES
+            .iter()
+            .filter(|rule| {
+                rule.name() == name && plugin.map_or(true, |plugin| rule.plugin_name() == plugin)
+            })
+            .map(Self::new)
+            .collect::<Vec<_>>();
+        docs.sort_unstable_by_key(|docs| docs.plugin);
+        docs
+    }
+
+    fn new(rule: &RuleEnum) -> Self {
+        Self {
+            name: rule.name(),
+            plugin: rule.plugin_name(),
+            category: category_name(rule.category()),
+            fix: rule.fix().name(),
+            sections: rule.documentation().map(parse_sections).unwrap_or_default(),
+        }
+    }
+
+    /// The URL of the documentation page of the rule on the website.
+    pub fn url(&self) -> String {
+        rule_docs_url(self.plugin, self.name)
+    }
+
+    /// Render the documentation as a markdown page, with the sections as second level headings.
+    pub fn render_markdown(&self) -> String {
+        let mut s = String::new();
+        writeln!(s, "# {}/{}\n", self.plugin, self.name).unwrap();
+        write!(s, "Category: `{}`", self.category).unwrap();
+        if let Some(fix) = self.fix {
+            write!(s, ", fix: `{fix}`").unwrap();
+        }
+        writeln!(s).unwrap();
+        for section in &self.sections {
+            writeln!(s, "\n## {}\n", section.title).unwrap();
+            if !section.body.is_empty() {
+                writeln!(s, "{}", section.body).unwrap();
+            }
+        }
+        s
+    }
+}
+
+/// The URL of the documentation page of the rule `name` of `plugin` on the website, e.g.
+/// `https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html`.
+pub fn rule_docs_url(plugin: &str, name: &str) -> String {
+    format!("https://oxc.rs/docs/guide/usage/linter/rules/{plugin}/{name}.html")
+}
+
+/// Split `documentation` into its `### ` sections, ignoring headings in code blocks and the text
+/// before the first section.
+pub fn parse_sections(documentation: &str) -> Vec<RuleDocsSection> {
+    let mut sections = Vec::<(&str, Vec<&str>)>::new();
+    let mut in_code_block = false;
+    for line in documentation.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            if let Some(title) = line.strip_prefix("### ") {
+                sections.push((title.trim(), vec![]));
+                continue;
+            }
+        }
+        if let Some((_, lines)) = sections.last_mut() {
+            lines.push(line);
+        }
+    }
+    sections
+        .into_iter()
+        .map(|(title, lines)| RuleDocsSection {
+            title: title.to_string(),
+            body: lines.join("\n").trim_matches('\n').trim_end().to_string(),
+        })
+        .collect()
+}
+
+fn category_name(category: RuleCategory) -> String {
+    category.to_string().to_lowercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_sections, RuleDocs, RuleDocsSection};
+
+    #[test]
+    fn test_parse_sections() {
+        let documentation = "### What it does\nDisallow `debugger`.\n\n### Example\n```js\n### debugger\ndebugger;\n```\n";
+        assert_eq!(
+            parse_sections(documentation),
+            [
+                RuleDocsSection {
+                    title: "What it does".to_string(),
+                    body: "Disallow `debugger`.".to_string()
+                },
+                RuleDocsSection {
+                    title: "Example".to_string(),
+                    body: "```js\n### debugger\ndebugger;\n```".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let docs = RuleDocs::find("eslint/no-debugger");
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0].plugin, "eslint");
+        assert_eq!(docs[0].category, "correctness");
+        assert_eq!(docs[0].fix, Some("fix"));
+        assert_eq!(docs[0].sections[0].title, "What it does");
+        assert!(docs[0].render_markdown().starts_with(
+            "# eslint/no-debugger\n\nCategory: `correctness`, fix: `fix`\n\n## What it does\n\n"
+        ));
+
+        assert_eq!(
+            docs[0].url(),
+            "https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html"
+        );
+
+        assert_eq!(RuleDocs::find("no-debugger").len(), 1);
+        assert!(RuleDocs::find("unicorn/no-debugger").is_empty());
+        assert!(RuleDocs::find("no-such-rule").is_empty());
+    }
+}
@@ -0,0 +1,308 @@
+//! The explanations following the help of the problems reported by rules with `--explain`, see
+//! [`LintOptions::explain`], for the developers new to a codebase wondering why a rule reports a
+//! problem in a file.
+
+use std::{fmt::Write, path::Path};
+
+use oxc_diagnostics::{OxcDiagnostic, Severity};
+
+use crate::{
+    config::OxlintConfig,
+    docs::{parse_sections, rule_docs_url},
+    inline_config::InlineConfig,
+    options::{matches_filter, matches_rule_filter},
+    rule_names::rule_key,
+    security::is_security_rule,
+    AllowWarnDeny, LintOptions, RuleCategory, RuleFixMeta, RuleWithSeverity,
+};
+
+/// What turns a rule on for a file, the sources of the configuration from the highest precedence
+/// to the lowest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RuleSource<'a> {
+    /// A `/* eslint */` or `/* oxlint */` comment of the file
+    InlineConfig,
+    /// The entry at this index of the `severityOverrides` of the configuration file
+    SeverityOverride(usize),
+    /// The `rules` of the configuration file
+    Rules,
+    /// The `categories` of the configuration file
+    Category(RuleCategory),
+    /// A `-W` or `-D` option, `default` for the `-W correctness` used when none is given
+    Filter { severity: AllowWarnDeny, filter: &'a str, default: bool },
+    /// The `--nursery` option
+    Nursery,
+    /// The `--filter` option, which runs the rule regardless of the configuration
+    RuleFilter,
+    /// The `--security` option, which runs the rules of the security preset as errors
+    /// regardless of the configuration
+    Security,
+}
+
+impl<'a> RuleSource<'a> {
+    /// The source deciding the severity of `rule` in the file at `path`, `None` when the rule is
+    /// not turned on by any of them.
+    pub fn find(
+        rule: &RuleWithSeverity,
+        path: &Path,
+        options: &'a LintOptions,
+        config: &OxlintConfig,
+        inline_config: Option<&InlineConfig>,
+    ) -> Option<Self> {
+        if options.security && is_security_rule(rule) {
+            return Some(Self::Security);
+        }
+        if inline_config.is_some_and(|inline_config| inline_config.configures(rule)) {
+            return Some(Self::InlineConfig);
+        }
+        if let Some(index) = config.severity_overrides.matching(path).index_of(rule) {
+            return Some(Self::SeverityOverride(index));
+        }
+        if config.rule_config(rule).is_some() {
+            return Some(Self::Rules);
+        }
+        let category = rule.category();
+        if config.categories.iter().any(|(c, severity)| c == category && severity.is_warn_deny()) {
+            return Some(Self::Category(category));
+        }
+        // Later filters take precedence
+        let filter = options
+            .filter
+            .iter()
+            .rev()
+            .find(|(severity, filter)| severity.is_warn_deny() && matches_filter(filter, rule));
+        if let Some((severity, filter)) = filter {
+            let default = options.filter == LintOptions::default().filter;
+            return Some(Self::Filter { severity: *severity, filter, default });
+        }
+        if options.nursery && category == RuleCategory::Nursery {
+            return Some(Self::Nursery);
+        }
+        options
+            .rule_filter
+            .iter()
+            .any(|filter| matches_rule_filter(filter, rule))
+            .then_some(Self::RuleFilter)
+    }
+
+    /// A description of the source, `config_path` is the configuration file the file is linted
+    /// with.
+    fn describe(self, config_path: Option<&Path>) -> String {
+        let config = config_path.map_or_else(
+            || "the configuration".to_string(),
+            |path| format!("`{}`", path.display()),
+        );
+        match self {
+            Self::InlineConfig => "a configuration comment of the file".to_string(),
+            Self::SeverityOverride(index) => {
+                format!("the entry {} of `severityOverrides` in {config}", index + 1)
+            }
+            Self::Rules => format!("`rules` in {config}"),
+            Self::Category(category) => {
+                let category = category.to_string().to_lowercase();
+                format!("the `{category}` category of `categories` in {config}")
+            }
+            Self::Filter { filter, default: true, .. } => {
+                format!("the `{filter}` category, which is enabled by default")
+            }
+            Self::Filter { severity, filter, default: false } => {
+                let flag = if severity == AllowWarnDeny::Deny { "-D" } else { "-W" };
+                format!("the `{flag} {filter}` option")
+            }
+            Self::Nursery => "the `--nursery` option".to_string(),
+            Self::RuleFilter => "the `--filter` option".to_string(),
+            Self::Security => "the `--security` option".to_string(),
+        }
+    }
+}
+
+/// Follow the help of `diagnostic`, a problem reported by `rule` turned on by `source`, with its
+/// explanation, see [`explain`].
+pub(crate) fn with_explanation(
+    diagnostic: OxcDiagnostic,
+    rule: &RuleWithSeverity,
+    source: Option<RuleSource>,
+    config_path: Option<&Path>,
+) -> OxcDiagnostic {
+    let explanation = explain(rule, diagnostic.severity, source, config_path);
+    let help = match &diagnostic.help {
+        Some(help) => format!("{help}\n{explanation}"),
+        None => explanation,
+    };
+    diagnostic.with_help(help)
+}
+
+/// The explanation of a problem reported by `rule` with `severity`: the rationale of the rule,
+/// the source of the configuration turning it on, and how to fix or suppress the problem.
+fn explain(
+    rule: &RuleWithSeverity,
+    severity: Severity,
+    source: Option<RuleSource>,
+    config_path: Option<&Path>,
+) -> String {
+    let (plugin_name, rule_name) = (rule.plugin_name(), rule.name());
+    let key = rule_key(plugin_name, rule_name);
+    let mut s = String::new();
+    if let Some(rationale) = rule.documentation().and_then(rationale) {
+        writeln!(s, "Why: {rationale}").unwrap();
+    }
+    let severity = if severity == Severity::Error { "an error" } else { "a warning" };
+    match source {
+        Some(source) => {
+            let source = source.describe(config_path);
+            writeln!(s, "Configured: `{key}` is {severity} because of {source}").unwrap();
+        }
+        None => writeln!(s, "Configured: `{key}` is {severity}").unwrap(),
+    }
+    match rule.fix() {
+        RuleFixMeta::None => {}
+        RuleFixMeta::Fix => writeln!(s, "Fix: run with `--fix` to fix it automatically").unwrap(),
+        RuleFixMeta::DangerousFix => writeln!(
+            s,
+            "Fix: run with `--fix` to fix it automatically, some fixes need `--fix-dangerously`"
+        )
+        .unwrap(),
+        RuleFixMeta::Suggestion => {
+            writeln!(s, "Fix: apply one of the suggested fixes in your editor").unwrap();
+        }
+    }
+    writeln!(
+        s,
+        "Suppress: add `// eslint-disable-next-line {key} -- <reason>` before the line if the code is intended"
+    )
+    .unwrap();
+    write!(
+        s,
+        "Docs: run `oxlint explain {plugin_name}/{rule_name}` or see {}",
+        rule_docs_url(plugin_name, rule_name)
+    )
+    .unwrap();
+    s
+}
+
+/// The first paragraph of the `Why is this bad?` section of the documentation of a rule, on a
+/// single line.
+fn rationale(documentation: &str) -> Option<String> {
+    let section = parse_sections(documentation)
+        .into_iter()
+        .find(|section| section.title == "Why is this bad?")?;
+    let mut paragraph = vec![];
+    let mut in_code_block = false;
+    for line in section.body.lines().map(str::trim) {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        if !line.is_empty() {
+            paragraph.push(line);
+        } else if !paragraph.is_empty() {
+            break;
+        }
+    }
+    (!paragraph.is_empty()).then(|| paragraph.join(" "))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_diagnostics::Severity;
+    use serde::Deserialize;
+
+    use super::{explain, rationale, RuleSource};
+    use crate::{
+        config::OxlintConfig, rules::RULES, AllowWarnDeny, LintOptions, RuleCategory,
+        RuleWithSeverity,
+    };
+
+    fn rule(name: &str) -> RuleWithSeverity {
+        let rule = RULES.iter().find(|r| r.name() == name).unwrap().clone();
+        RuleWithSeverity::new(rule, AllowWarnDeny::Warn)
+    }
+
+    #[test]
+    fn test_rationale() {
+        let documentation = "### What it does\nDisallow `debugger`.\n\n### Why is this bad?\n\n```js\ndebugger;\n```\n\nIt stops\nthe execution.\n\nMore.\n";
+        assert_eq!(rationale(documentation).as_deref(), Some("It stops the execution."));
+        assert_eq!(rationale("### What it does\nDisallow `debugger`."), None);
+    }
+
+    #[test]
+    fn test_find_source() {
+        let config = OxlintConfig::deserialize(&serde_json::json!({
+            "rules": { "eqeqeq": "error" },
+            "categories": { "style": "warn" },
+            "severityOverrides": [
+                { "files": ["src/legacy/**"], "rules": ["eqeqeq"], "severity": "warn" }
+            ],
+        }))
+        .unwrap();
+        let options = LintOptions::default();
+        let find = |name: &str, path: &str| {
+            RuleSource::find(&rule(name), Path::new(path), &options, &config, None)
+        };
+
+        assert_eq!(find("eqeqeq", "src/legacy/a.js"), Some(RuleSource::SeverityOverride(0)));
+        assert_eq!(find("eqeqeq", "src/a.js"), Some(RuleSource::Rules));
+        assert_eq!(
+            find("guard-for-in", "src/a.js"),
+            Some(RuleSource::Category(RuleCategory::Style))
+        );
+        assert_eq!(
+            find("no-debugger", "src/a.js"),
+            Some(RuleSource::Filter {
+                severity: AllowWarnDeny::Warn,
+                filter: "correctness",
+                default: true
+            })
+        );
+        assert_eq!(find("no-console", "src/a.js"), None);
+
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Deny, "no-console".to_string())]);
+        let source = RuleSource::find(
+            &rule("no-console"),
+            Path::new("src/a.js"),
+            &options,
+            &OxlintConfig::default(),
+            None,
+        );
+        assert_eq!(
+            source,
+            Some(RuleSource::Filter {
+                severity: AllowWarnDeny::Deny,
+                filter: "no-console",
+                default: false
+            })
+        );
+
+        let options = LintOptions::default().with_security(true);
+        let source =
+            RuleSource::find(&rule("no-eval"), Path::new("src/a.js"), &options, &config, None);
+        assert_eq!(source, Some(RuleSource::Security));
+    }
+
+    #[test]
+    fn test_explain() {
+        let explanation = explain(
+            &rule("no-debugger"),
+            Severity::Error,
+            Some(RuleSource::Rules),
+            Some(Path::new(".oxlintrc.json")),
+        );
+        let lines = explanation.lines().collect::<Vec<_>>();
+        assert!(lines[0].starts_with("Why: "));
+        assert_eq!(
+            &lines[1..],
+            [
+                "Configured: `no-debugger` is an error because of `rules` in `.oxlintrc.json`",
+                "Fix: run with `--fix` to fix it automatically",
+                "Suppress: add `// eslint-disable-next-line no-debugger -- <reason>` before the line if the code is intended",
+                "Docs: run `oxlint explain eslint/no-debugger` or see https://oxc.rs/docs/guide/usage/linter/rules/eslint/no-debugger.html",
+            ]
+        );
+    }
+}
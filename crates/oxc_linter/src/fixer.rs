@@ -1,11 +1,15 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, path::Path};
 
 use oxc_codegen::Codegen;
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{DiagnosticFix, LabeledSpan, OxcDiagnostic};
 use oxc_span::{GetSpan, Span};
 
 use crate::LintContext;
 
+// Not all the helpers are used by the rules yet
+#[allow(dead_code)]
+pub mod helpers;
+
 #[derive(Debug, Clone, Default)]
 pub struct Fix<'a> {
     pub content: Cow<'a, str>,
@@ -22,6 +26,24 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// A fix which is offered to the user but never applied by `--fix`, e.g. because it may change
+/// the behavior of the code. Editors present suggestions by their description.
+///
+/// Inspired by ESLint's [suggestions].
+///
+/// [suggestions]: https://eslint.org/docs/latest/extend/custom-rules#providing-suggestions
+#[derive(Debug, Clone)]
+pub struct Suggestion<'a> {
+    pub description: Cow<'a, str>,
+    pub fix: Fix<'a>,
+}
+
+impl<'a> Suggestion<'a> {
+    pub fn new<T: Into<Cow<'a, str>>>(description: T, fix: Fix<'a>) -> Self {
+        Self { description: description.into(), fix }
+    }
+}
+
 pub enum CompositeFix<'a> {
     Single(Fix<'a>),
     Multiple(Vec<Fix<'a>>),
@@ -121,6 +143,11 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
         Self { ctx }
     }
 
+    /// The source text of the file, for the fixes of [`helpers`].
+    pub fn source_text(self) -> &'a str {
+        self.ctx.source_text()
+    }
+
     pub fn source_range(self, span: Span) -> &'a str {
         self.ctx.source_range(span)
     }
@@ -155,7 +182,6 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
 }
 
 pub struct FixResult<'a> {
-    #[allow(unused)]
     pub fixed: bool,
     pub fixed_code: Cow<'a, str>,
     pub messages: Vec<Message<'a>>,
@@ -167,6 +193,10 @@ pub struct Message<'a> {
     pub start: u32,
     pub end: u32,
     pub fix: Option<Fix<'a>>,
+    /// Fixes for the user to choose from, never applied by [`Fixer`]
+    pub suggestions: Vec<Suggestion<'a>>,
+    rule_name: Option<&'static str>,
+    plugin_name: Option<&'static str>,
     fixed: bool,
 }
 
@@ -186,7 +216,90 @@ impl<'a> Message<'a> {
         } else {
             (0, 0)
         };
-        Self { error, start, end, fix, fixed: false }
+        Self {
+            error,
+            start,
+            end,
+            fix,
+            suggestions: vec![],
+            rule_name: None,
+            plugin_name: None,
+            fixed: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_suggestions(mut self, suggestions: Vec<Suggestion<'a>>) -> Self {
+        self.suggestions = suggestions;
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn with_rule_name(mut self, rule_name: &'static str) -> Self {
+        self.rule_name = Some(rule_name);
+        self
+    }
+
+    #[must_use]
+    pub(crate) fn with_plugin_name(mut self, plugin_name: &'static str) -> Self {
+        self.plugin_name = Some(plugin_name);
+        self
+    }
+
+    /// Name of the rule which reported this message, `None` for syntax errors.
+    pub fn rule_name(&self) -> Option<&'static str> {
+        self.rule_name
+    }
+
+    /// Name of the plugin of the rule which reported this message, e.g. `eslint` or `unicorn`,
+    /// `None` for syntax errors.
+    pub fn plugin_name(&self) -> Option<&'static str> {
+        self.plugin_name
+    }
+
+    /// The diagnostic of this message, along with its fix for reporters such as `--format json`.
+    pub fn into_diagnostic(self) -> OxcDiagnostic {
+        match self.fix {
+            Some(fix) => self.error.with_fix(DiagnosticFix {
+                start: fix.span.start,
+                end: fix.span.end,
+                content: fix.content.into_owned(),
+            }),
+            None => self.error,
+        }
+    }
+
+    /// Shift the spans of this message by `offset`, e.g. to map a message reported for a script
+    /// embedded in a `.vue` file back to the file.
+    #[must_use]
+    pub fn move_offset(mut self, offset: u32) -> Self {
+        if offset == 0 {
+            return self;
+        }
+        let move_span = |span: Span| Span::new(span.start + offset, span.end + offset);
+        self.start += offset;
+        self.end += offset;
+        if let Some(fix) = &mut self.fix {
+            fix.span = move_span(fix.span);
+        }
+        for suggestion in &mut self.suggestions {
+            suggestion.fix.span = move_span(suggestion.fix.span);
+        }
+        if let Some(labels) = &self.error.labels {
+            let labels = labels
+                .iter()
+                .map(|label| {
+                    let label_offset = label.offset() + offset as usize;
+                    LabeledSpan::new(
+                        label.label().map(ToString::to_string),
+                        label_offset,
+                        label.len(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            self.error = self.error.with_labels(labels);
+        }
+        self
     }
 
     pub fn start(&self) -> u32 {
@@ -198,6 +311,18 @@ impl<'a> Message<'a> {
     }
 }
 
+/// Decides which fixes are applied when fixing files, e.g. by asking the user.
+///
+/// Files are linted in parallel, so implementations are responsible for serializing any
+/// interaction with the user.
+pub trait FixReviewer: Send + Sync {
+    /// Review the fixes of `messages` reported for the file at `path`.
+    /// Rejected fixes are removed by setting [`Message::fix`] to `None`,
+    /// their messages are reported as unfixed problems. Fixes may also be replaced, e.g. by
+    /// comments suppressing the problems.
+    fn review(&self, path: &Path, source_text: &str, messages: &mut [Message]);
+}
+
 /// The fixer of the code.
 /// Note that our parser has handled the BOM, so we don't need to port the BOM test cases from `ESLint`.
 pub struct Fixer<'a> {
@@ -225,14 +350,24 @@ impl<'a> Fixer<'a> {
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
+        let mut last_fix: Option<Fix> = None;
         self.messages.iter_mut().filter(|m| m.fix.is_some()).for_each(|m| {
-            let Fix { content, span } = m.fix.as_ref().unwrap();
+            let fix = m.fix.as_ref().unwrap();
+            let Fix { content, span } = fix;
             let start = span.start;
             let end = span.end;
-            if start > end {
+            // Fixes splitting a multi-byte character would produce invalid UTF-8
+            if start > end || span.try_source_text(source_text).is_none() {
                 return;
             }
             if i64::from(start) <= last_pos {
+                // The same edit reported for several problems fixes all of them
+                if last_fix
+                    .as_ref()
+                    .is_some_and(|last| last.span == *span && last.content == *content)
+                {
+                    m.fixed = true;
+                }
                 return;
             }
 
@@ -242,6 +377,7 @@ impl<'a> Fixer<'a> {
             output.push_str(&source_text[offset..start as usize]);
             output.push_str(content);
             last_pos = i64::from(end);
+            last_fix = Some(fix.clone());
         });
 
         let offset = usize::try_from(last_pos.max(0)).ok().unwrap();
@@ -260,7 +396,7 @@ mod test {
     use oxc_diagnostics::OxcDiagnostic;
     use oxc_span::Span;
 
-    use super::{CompositeFix, Fix, FixResult, Fixer, Message};
+    use super::{CompositeFix, Fix, FixResult, Fixer, Message, Suggestion};
 
     fn insert_at_end() -> OxcDiagnostic {
         OxcDiagnostic::warn("End")
@@ -385,6 +521,16 @@ mod test {
         assert_eq!(result.fixed_code, TEST_CODE);
     }
 
+    #[test]
+    fn ignore_fix_splitting_character() {
+        let source_text = "'🎉' + a";
+        let fix = Fix::new("", Span::new(1, 2));
+        let result = Fixer::new(source_text, vec![create_message(replace_var(), Some(fix))]).fix();
+        assert_eq!(result.fixed_code, source_text);
+        assert_eq!(result.messages.len(), 1);
+        assert!(!result.fixed);
+    }
+
     #[test]
     fn replace_at_the_start() {
         let result = get_fix_result(vec![create_message(replace_var(), Some(REPLACE_VAR))]);
@@ -499,6 +645,17 @@ mod test {
         assert!(result.fixed);
     }
 
+    #[test]
+    fn apply_identical_fixes_once() {
+        let result = get_fix_result(vec![
+            create_message(insert_at_start(), Some(INSERT_AT_START)),
+            create_message(insert_at_middle(), Some(INSERT_AT_START)),
+        ]);
+        assert_eq!(result.fixed_code, INSERT_AT_START.content.to_string() + TEST_CODE);
+        assert_eq!(result.messages.len(), 0);
+        assert!(result.fixed);
+    }
+
     #[test]
     fn apply_same_fix_when_span_overlap_regardless_of_order() {
         let result1 = get_fix_result(vec![
@@ -535,6 +692,41 @@ mod test {
         assert!(result.fixed);
     }
 
+    #[test]
+    fn should_not_apply_suggestions() {
+        let message = create_message(replace_id(), None)
+            .with_suggestions(vec![Suggestion::new("Rename to `foo`", REPLACE_ID)]);
+        let result = get_fix_result(vec![message]);
+        assert_eq!(result.fixed_code, TEST_CODE);
+        assert!(!result.fixed);
+        assert_eq!(result.messages.len(), 1);
+        assert_eq!(result.messages[0].suggestions[0].description, "Rename to `foo`");
+    }
+
+    #[test]
+    fn move_offset() {
+        let error = replace_id().with_label(Span::new(4, 10));
+        let message = create_message(error, Some(REPLACE_ID))
+            .with_suggestions(vec![Suggestion::new("Rename to `foo`", REPLACE_ID)]);
+        let source_text = format!("<script>{TEST_CODE}</script>");
+        let message = message.move_offset(8);
+        assert_eq!((message.start(), message.end()), (12, 18));
+        assert_eq!(message.suggestions[0].fix.span, Span::new(12, 18));
+        let label = &message.error.labels.as_ref().unwrap()[0];
+        assert_eq!((label.offset(), label.len()), (12, 6));
+        let result = Fixer::new(&source_text, vec![message]).fix();
+        assert_eq!(result.fixed_code, "<script>var foo = 6 * 7;</script>");
+    }
+
+    #[test]
+    fn into_diagnostic() {
+        let error = replace_id().with_label(Span::new(4, 10));
+        let diagnostic = create_message(error.clone(), Some(REPLACE_ID)).into_diagnostic();
+        let fix = diagnostic.fix.as_ref().unwrap();
+        assert_eq!((fix.start, fix.end, fix.content.as_str()), (4, 10, "foo"));
+        assert!(create_message(error, None).into_diagnostic().fix.is_none());
+    }
+
     fn assert_fixed_corrected(source_text: &str, expected: &str, composite_fix: CompositeFix) {
         let mut source_text = source_text.to_string();
         let fix = composite_fix.normalize_fixes(&source_text);
@@ -0,0 +1,261 @@
+//! Fixes needed by many rules, e.g. removing an argument of a call along with its comma.
+//!
+//! The helpers work on the source text, [`RuleFixer::source_text`], and the spans of the nodes
+//! so that they compose with the other fixes of a rule.
+//!
+//! [`RuleFixer::source_text`]: super::RuleFixer::source_text
+
+use std::ops::Range;
+
+use oxc_ast::{
+    ast::{Argument, VariableDeclaration},
+    CommentKind,
+};
+use oxc_span::{GetSpan, Span};
+
+use super::Fix;
+
+/// Remove the `arguments` of a call, or of a `new` expression, at `range`, along with the
+/// commas separating them from the other arguments.
+///
+/// A trailing comma is kept after the last argument, unless all the arguments are removed:
+/// `foo(a, b, c,)` gives `foo(a,)` when removing `b` and `c`, and `foo()` when removing all of
+/// them.
+pub fn remove_arguments<'a>(
+    source_text: &'a str,
+    arguments: &[Argument<'_>],
+    range: Range<usize>,
+) -> Fix<'a> {
+    let spans = arguments.iter().map(GetSpan::span).collect::<Vec<_>>();
+    remove_list_elements(source_text, &spans, range)
+}
+
+/// Remove the declarator at `index` of `declaration`, along with the comma separating it from
+/// the other declarators: `let a = 1, b = 2;` gives `let a = 1;` when removing `b`.
+///
+/// The whole declaration is removed when it has a single declarator, the caller must check that
+/// it is a statement which can be removed, and not the head of a `for` loop or an export.
+pub fn remove_declarator<'a>(
+    source_text: &'a str,
+    declaration: &VariableDeclaration<'_>,
+    index: usize,
+) -> Fix<'a> {
+    if declaration.declarations.len() == 1 {
+        return Fix::delete(declaration.span);
+    }
+    let spans =
+        declaration.declarations.iter().map(|declarator| declarator.span).collect::<Vec<_>>();
+    remove_list_elements(source_text, &spans, index..index + 1)
+}
+
+/// Remove the `elements` at `range` of a comma separated list, e.g. arguments or array elements.
+fn remove_list_elements<'a>(
+    source_text: &'a str,
+    elements: &[Span],
+    range: Range<usize>,
+) -> Fix<'a> {
+    debug_assert!(!range.is_empty() && range.end <= elements.len(), "Invalid range: {range:?}");
+    let first = elements[range.start];
+    let last = elements[range.end - 1];
+    if range.start > 0 {
+        // From the comma after the previous element, `a, b` gives `a`
+        let before = source_text[..first.start as usize].trim_end();
+        let start = match before.strip_suffix(',') {
+            #[allow(clippy::cast_possible_truncation)]
+            Some(before) => before.len() as u32,
+            None => elements[range.start - 1].end,
+        };
+        return Fix::delete(Span::new(start, last.end));
+    }
+    // To the next element, `a, b` gives `b`
+    let with_comma = last.expand_to_include_trailing_comma(source_text);
+    let end = if with_comma == last {
+        elements.get(range.end).map_or(last.end, |next| next.start)
+    } else {
+        let after = &source_text[with_comma.end as usize..];
+        #[allow(clippy::cast_possible_truncation)]
+        let whitespace = (after.len() - after.trim_start().len()) as u32;
+        with_comma.end + whitespace
+    };
+    Fix::delete(Span::new(first.start, end))
+}
+
+/// Replace `target` with `replacement`, moving the comments within `target` before it so that
+/// they are not removed: `foo(/* bar */ baz)` gives `/* bar */ qux` when replaced with `qux`.
+///
+/// `comments` are the comments within `target`, [`LintContext::comments_in_span`].
+///
+/// [`LintContext::comments_in_span`]: crate::LintContext::comments_in_span
+pub fn replace_preserving_comments<'a, I>(
+    source_text: &'a str,
+    comments: I,
+    target: Span,
+    replacement: &str,
+) -> Fix<'a>
+where
+    I: IntoIterator<Item = (CommentKind, Span)>,
+{
+    let indentation = indentation(source_text, target.start);
+    let mut content = String::new();
+    for (kind, span) in comments {
+        // The spans of the comments do not include the delimiters
+        let (start, end) = match kind {
+            CommentKind::SingleLine => (span.start - 2, span.end),
+            CommentKind::MultiLine => (span.start - 2, span.end + 2),
+        };
+        content.push_str(&source_text[start as usize..end as usize]);
+        // A line comment would comment out the replacement
+        if kind.is_single_line() {
+            content.push('\n');
+            content.push_str(indentation);
+        } else {
+            content.push(' ');
+        }
+    }
+    if content.is_empty() {
+        return Fix::new(replacement.to_string(), target);
+    }
+    content.push_str(replacement);
+    Fix::new(content, target)
+}
+
+/// Insert the statements of `text` on a new line before the statement at `statement`, with the
+/// same indentation.
+pub fn insert_before_statement<'a>(source_text: &str, statement: Span, text: &str) -> Fix<'a> {
+    let indentation = indentation(source_text, statement.start);
+    let text = indent(text, indentation);
+    Fix::new(format!("{text}\n{indentation}"), Span::new(statement.start, statement.start))
+}
+
+/// Insert the statements of `text` on a new line after the statement at `statement`, with the
+/// same indentation.
+pub fn insert_after_statement<'a>(source_text: &str, statement: Span, text: &str) -> Fix<'a> {
+    let indentation = indentation(source_text, statement.start);
+    let text = indent(text, indentation);
+    Fix::new(format!("\n{indentation}{text}"), Span::new(statement.end, statement.end))
+}
+
+/// The whitespace at the start of the line of `offset`.
+fn indentation(source_text: &str, offset: u32) -> &str {
+    let line_start = source_text[..offset as usize].rfind('\n').map_or(0, |i| i + 1);
+    let line = &source_text[line_start..];
+    let end = line.find(|c| c != ' ' && c != '\t').unwrap_or(line.len());
+    &line[..end]
+}
+
+/// Indent the lines of `text` after the first one with `indentation`.
+fn indent(text: &str, indentation: &str) -> String {
+    text.lines().collect::<Vec<_>>().join(&format!("\n{indentation}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Range;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::{Parser, ParserReturn};
+    use oxc_span::{GetSpan, SourceType, Span};
+
+    use super::{
+        insert_after_statement, insert_before_statement, remove_arguments, remove_declarator,
+        replace_preserving_comments,
+    };
+    use crate::fixer::{Fix, Fixer, Message};
+
+    fn apply(source_text: &str, fix: Fix) -> String {
+        let message = Message::new(oxc_diagnostics::OxcDiagnostic::warn(""), Some(fix));
+        Fixer::new(source_text, vec![message]).fix().fixed_code.into_owned()
+    }
+
+    fn with_program<F: FnOnce(&ParserReturn) -> Fix<'static>>(
+        source_text: &'static str,
+        f: F,
+    ) -> String {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default()).parse();
+        apply(source_text, f(&ret))
+    }
+
+    fn remove(source_text: &'static str, range: Range<usize>) -> String {
+        with_program(source_text, |ret| {
+            let Statement::ExpressionStatement(statement) = &ret.program.body[0] else {
+                unreachable!()
+            };
+            let Expression::CallExpression(call) = &statement.expression else { unreachable!() };
+            remove_arguments(source_text, &call.arguments, range)
+        })
+    }
+
+    #[test]
+    fn test_remove_arguments() {
+        assert_eq!(remove("foo(a)", 0..1), "foo()");
+        assert_eq!(remove("foo(a,)", 0..1), "foo()");
+        assert_eq!(remove("foo(\n  a,\n)", 0..1), "foo(\n  )");
+        assert_eq!(remove("foo(a, b)", 0..1), "foo(b)");
+        assert_eq!(remove("foo(a, b)", 1..2), "foo(a)");
+        assert_eq!(remove("foo(a, b, c)", 1..2), "foo(a, c)");
+        assert_eq!(remove("foo(a, b, c,)", 1..3), "foo(a,)");
+        assert_eq!(remove("foo(a, b, c,)", 0..3), "foo()");
+        assert_eq!(remove("foo(a, ...b)", 1..2), "foo(a)");
+        assert_eq!(remove("foo((a), b)", 0..1), "foo(b)");
+        assert_eq!(remove("foo(a /* a */, b)", 0..1), "foo(b)");
+        assert_eq!(remove("foo(a, /* b */ b)", 1..2), "foo(a)");
+        assert_eq!(remove("foo('ü', b)", 1..2), "foo('ü')");
+        assert_eq!(remove("foo(`🎉${a}` , b)", 0..1), "foo(b)");
+    }
+
+    #[test]
+    fn test_remove_declarator() {
+        let remove = |source_text: &'static str, index: usize| {
+            with_program(source_text, |ret| {
+                let Statement::VariableDeclaration(declaration) = &ret.program.body[0] else {
+                    unreachable!()
+                };
+                remove_declarator(source_text, declaration, index)
+            })
+        };
+        assert_eq!(remove("let a = 1;\nfoo();", 0), "\nfoo();");
+        assert_eq!(remove("let a = 1, b = 2;", 0), "let b = 2;");
+        assert_eq!(remove("let a = 1, b = 2;", 1), "let a = 1;");
+        assert_eq!(remove("let a,\n  b,\n  c;", 1), "let a,\n  c;");
+    }
+
+    #[test]
+    fn test_replace_preserving_comments() {
+        let replace = |source_text: &'static str| {
+            with_program(source_text, |ret| {
+                let Statement::ExpressionStatement(statement) = &ret.program.body[0] else {
+                    unreachable!()
+                };
+                let span = statement.expression.span();
+                let comments = ret
+                    .trivias
+                    .comments()
+                    .filter(|(_, comment)| span.start <= comment.start && comment.end <= span.end);
+                replace_preserving_comments(source_text, comments, span, "qux")
+            })
+        };
+        assert_eq!(replace("foo(baz);"), "qux;");
+        assert_eq!(replace("foo(/* bar */ baz);"), "/* bar */ qux;");
+        assert_eq!(replace("  foo(// bar\n    baz);"), "  // bar\n  qux;");
+    }
+
+    #[test]
+    fn test_insert_statement() {
+        let source_text = "if (a) {\n    foo();\n}";
+        let statement = Span::new(13, 19);
+        assert_eq!(
+            apply(source_text, insert_before_statement(source_text, statement, "bar();\nbaz();")),
+            "if (a) {\n    bar();\n    baz();\n    foo();\n}"
+        );
+        assert_eq!(
+            apply(source_text, insert_after_statement(source_text, statement, "bar();")),
+            "if (a) {\n    foo();\n    bar();\n}"
+        );
+        assert_eq!(
+            apply("foo();", insert_before_statement("foo();", Span::new(0, 6), "bar();")),
+            "bar();\nfoo();"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use bitflags::bitflags;
+use oxc_ast::AstKind;
+
+use crate::{AstNode, LintContext};
+
+bitflags! {
+    /// Frameworks and environments a file is written for, see [`LintContext::frameworks`].
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct FrameworkFlags: u8 {
+        const None       = 0;
+        const React      = 1 << 0;
+        const Vue        = 1 << 1;
+        const Jest       = 1 << 2;
+        const Vitest     = 1 << 3;
+        /// A script meant to be executed by Node.js, e.g. a file with a `#!/usr/bin/env node`
+        /// hashbang or importing `node:` modules.
+        const NodeScript = 1 << 4;
+    }
+}
+
+impl FrameworkFlags {
+    /// Detect the frameworks of the file linted by `ctx` from its imports, its path and the
+    /// configuration.
+    pub fn detect(ctx: &LintContext) -> Self {
+        let mut flags = Self::from_path(ctx.file_path());
+
+        for module in ctx.module_record().requested_modules.keys() {
+            flags |= Self::from_module_request(module);
+        }
+
+        if let Some(AstKind::Program(program)) = ctx.nodes().root_node().map(AstNode::kind) {
+            if program.hashbang.as_ref().is_some_and(|hashbang| hashbang.value.contains("node")) {
+                flags |= Self::NodeScript;
+            }
+        }
+
+        if ctx.env().iter().any(|env| env == "jest") {
+            flags |= Self::Jest;
+        }
+        if ctx.rules().iter().any(|rule| rule.plugin_name == "vitest") {
+            flags |= Self::Vitest;
+        }
+
+        flags
+    }
+
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("jsx" | "tsx") => Self::React,
+            Some("vue") => Self::Vue,
+            _ => Self::None,
+        }
+    }
+
+    fn from_module_request(module: &str) -> Self {
+        let package = module.split('/').next().unwrap_or(module);
+        match package {
+            "react" | "react-dom" | "react-native" | "preact" | "next" => Self::React,
+            "vue" | "nuxt" => Self::Vue,
+            "@jest" | "jest" => Self::Jest,
+            "vitest" => Self::Vitest,
+            _ if module.starts_with("node:") => Self::NodeScript,
+            _ => Self::None,
+        }
+    }
+
+    pub fn is_react(self) -> bool {
+        self.contains(Self::React)
+    }
+
+    pub fn is_vue(self) -> bool {
+        self.contains(Self::Vue)
+    }
+
+    pub fn is_jest(self) -> bool {
+        self.contains(Self::Jest)
+    }
+
+    pub fn is_vitest(self) -> bool {
+        self.contains(Self::Vitest)
+    }
+
+    pub fn is_node_script(self) -> bool {
+        self.contains(Self::NodeScript)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::FrameworkFlags;
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(FrameworkFlags::from_path(Path::new("src/App.tsx")), FrameworkFlags::React);
+        assert_eq!(FrameworkFlags::from_path(Path::new("src/App.vue")), FrameworkFlags::Vue);
+        assert_eq!(FrameworkFlags::from_path(Path::new("src/app.ts")), FrameworkFlags::None);
+    }
+
+    #[test]
+    fn test_from_module_request() {
+        let detect = FrameworkFlags::from_module_request;
+        assert_eq!(detect("react"), FrameworkFlags::React);
+        assert_eq!(detect("react/jsx-runtime"), FrameworkFlags::React);
+        assert_eq!(detect("next/link"), FrameworkFlags::React);
+        assert_eq!(detect("vue"), FrameworkFlags::Vue);
+        assert_eq!(detect("@jest/globals"), FrameworkFlags::Jest);
+        assert_eq!(detect("vitest"), FrameworkFlags::Vitest);
+        assert_eq!(detect("node:fs"), FrameworkFlags::NodeScript);
+        assert_eq!(detect("react-router"), FrameworkFlags::None);
+        assert_eq!(detect("./react"), FrameworkFlags::None);
+    }
+}
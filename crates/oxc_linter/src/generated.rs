@@ -0,0 +1,80 @@
+//! Detection of the build artifacts which are linted by accident, e.g. the minified bundles of a
+//! `dist/` directory, so they don't blow up the run time and the number of diagnostics.
+
+use std::str::FromStr;
+
+/// How the files which look generated or minified are linted, see [`is_generated`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GeneratedFiles {
+    /// Don't lint them
+    #[default]
+    Skip,
+    /// Lint them without fixing them, reporting their errors as warnings
+    Warn,
+    /// Lint them like the other files
+    Lint,
+}
+
+impl FromStr for GeneratedFiles {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "skip" => Ok(Self::Skip),
+            "warn" => Ok(Self::Warn),
+            "lint" => Ok(Self::Lint),
+            _ => Err(format!("'{s}' is not one of skip, warn or lint")),
+        }
+    }
+}
+
+/// Number of lines at the start of a file searched for the `@generated` marker
+const MARKER_LINES: usize = 20;
+
+/// Files at least this long whose lines are this long on average are minified
+const MINIFIED_MIN_LENGTH: usize = 1024;
+const MINIFIED_MIN_AVERAGE_LINE_LENGTH: usize = 200;
+
+/// Whether `source_text` looks like a build artifact rather than source code:
+///
+/// * a `@generated` marker in its header, e.g. `// @generated by protoc`
+/// * a `//# sourceMappingURL=` comment at its end, added by bundlers and compilers
+/// * minified code, i.e. very long lines on average
+pub fn is_generated(source_text: &str) -> bool {
+    if source_text.lines().take(MARKER_LINES).any(|line| line.contains("@generated")) {
+        return true;
+    }
+
+    let last_line = source_text.trim_end().lines().last().unwrap_or_default();
+    if ["//# sourceMappingURL=", "//@ sourceMappingURL=", "/*# sourceMappingURL="]
+        .iter()
+        .any(|prefix| last_line.starts_with(prefix))
+    {
+        return true;
+    }
+
+    let lines = source_text.lines().count().max(1);
+    source_text.len() >= MINIFIED_MIN_LENGTH
+        && source_text.len() / lines >= MINIFIED_MIN_AVERAGE_LINE_LENGTH
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_generated;
+
+    #[test]
+    fn test_is_generated() {
+        assert!(is_generated("// @generated by protoc-gen-ts\nexport const a = 1;\n"));
+        assert!(is_generated("/**\n * This file is @generated, do not edit.\n */\nfoo();"));
+        assert!(is_generated("foo();\n//# sourceMappingURL=index.js.map\n"));
+        assert!(is_generated("foo();\n/*# sourceMappingURL=data:application/json;base64,e30= */"));
+        assert!(is_generated(&"a=b(c);".repeat(200)));
+
+        assert!(!is_generated("export const a = 1;\n"));
+        assert!(!is_generated(&format!("{}\n// @generated", "foo();\n".repeat(50))));
+        assert!(!is_generated("const url = '//# sourceMappingURL=';\nfoo();\n"));
+        // Short one-liners are not minified
+        assert!(!is_generated(&"a=b(c);".repeat(20)));
+        assert!(!is_generated(&"const a = b(c);\n".repeat(200)));
+    }
+}
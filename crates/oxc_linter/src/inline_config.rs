@@ -0,0 +1,342 @@
+//! Rules configured by comments of the linted file, e.g. `/* eslint eqeqeq: ["error", "smart"] */`
+//! or `/* oxlint no-console: "off" */`. Like ESLint's configuration comments, they apply to the
+//! whole file and take precedence over the configuration files, the severity overrides and the
+//! command line.
+
+use oxc_ast::CommentKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use serde_json::{Map, Value};
+
+use crate::{
+    rule_names::{find_rule, unknown_rule_diagnostic},
+    rules::RuleEnum,
+    AllowWarnDeny, RuleWithSeverity,
+};
+
+fn invalid_comment_diagnostic(message: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Failed to parse the configuration comment: {message}"))
+        .with_help(
+            r#"Configure rules like `/* eslint eqeqeq: ["error", "smart"], no-console: "off" */`"#,
+        )
+        .with_label(span)
+}
+
+fn invalid_rule_diagnostic(key: &str, message: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(format!("Invalid configuration comment for rule `{key}`: {message}"))
+        .with_label(span)
+}
+
+/// A rule configured by a comment.
+struct InlineRule {
+    /// The rule with the options of the comment, if any, and its severity
+    rule: RuleWithSeverity,
+    /// Whether the comment configures options, otherwise only the severity changes
+    has_options: bool,
+}
+
+#[derive(Default)]
+pub struct InlineConfig {
+    /// The rules in the order of the comments, later ones take precedence
+    rules: Vec<InlineRule>,
+    /// Errors for the malformed comments and the invalid rules
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl InlineConfig {
+    /// The configuration of the `/* eslint */` and `/* oxlint */` block comments of `source_text`.
+    #[allow(clippy::cast_possible_truncation)] // for `as u32`
+    pub fn parse<I>(source_text: &str, comments: I) -> Self
+    where
+        I: Iterator<Item = (CommentKind, Span)>,
+    {
+        let mut config = Self::default();
+        for (kind, span) in comments {
+            if kind.is_single_line() {
+                continue;
+            }
+            let text = span.source_text(source_text);
+            let Some(body) = strip_directive(text) else { continue };
+            // `/* eslint eqeqeq: "off" -- description */`
+            let body = strip_description(body);
+            let body_start = span.start + (body.as_ptr() as usize - text.as_ptr() as usize) as u32;
+
+            let entries = match parse_entries(body) {
+                Ok(entries) => entries,
+                Err(message) => {
+                    config.diagnostics.push(invalid_comment_diagnostic(&message, span));
+                    continue;
+                }
+            };
+            for (key, value) in entries {
+                let key_span = body
+                    .find(key.as_str())
+                    .map_or(span, |i| Span::sized(body_start + i as u32, key.len() as u32));
+                config.add_rule(&key, value, key_span);
+            }
+        }
+        config
+    }
+
+    fn add_rule(&mut self, key: &str, value: Value, span: Span) {
+        let Some(rule) = find_rule(key) else {
+            self.diagnostics.extend(unknown_rule_diagnostic(key).map(|d| d.with_label(span)));
+            return;
+        };
+        let (severity, options) = match value {
+            Value::Array(mut items) if !items.is_empty() => {
+                let severity = items.remove(0);
+                (severity, (!items.is_empty()).then_some(Value::Array(items)))
+            }
+            value => (value, None),
+        };
+        let severity = match AllowWarnDeny::try_from(&severity) {
+            Ok(severity) => severity,
+            Err(error) => {
+                self.diagnostics.push(invalid_rule_diagnostic(key, &error.message, span));
+                return;
+            }
+        };
+        let rule = match options {
+            Some(options) => {
+                if let Err(error) = rule.validate_json(&options) {
+                    self.diagnostics.push(invalid_rule_diagnostic(key, &error.message, span));
+                    return;
+                }
+                InlineRule {
+                    rule: RuleWithSeverity::new(rule.read_json(options), severity),
+                    has_options: true,
+                }
+            }
+            None => InlineRule {
+                rule: RuleWithSeverity::new(rule.clone(), severity),
+                has_options: false,
+            },
+        };
+        self.rules.push(rule);
+    }
+
+    /// Apply the configuration to `rules`, the rules which run on the file with their severity:
+    /// the rules turned off are removed, the others are added or change severity, and options
+    /// when the comments configure them.
+    pub fn apply<'r>(&'r self, rules: &mut Vec<(&'r RuleWithSeverity, AllowWarnDeny)>) {
+        for inline in &self.rules {
+            let position = rules.iter().position(|(rule, _)| rule.rule == inline.rule.rule);
+            let severity = inline.rule.severity;
+            match position {
+                Some(i) if severity.is_allow() => {
+                    rules.remove(i);
+                }
+                Some(i) => {
+                    let rule = if inline.has_options { &inline.rule } else { rules[i].0 };
+                    rules[i] = (rule, severity);
+                }
+                None if severity.is_warn_deny() => rules.push((&inline.rule, severity)),
+                None => {}
+            }
+        }
+    }
+
+    /// Whether the comments configure `rule`.
+    pub fn configures(&self, rule: &RuleEnum) -> bool {
+        self.rules.iter().any(|inline| inline.rule.rule == *rule)
+    }
+
+    /// Errors for the malformed comments and the rules configured with an invalid severity or
+    /// invalid options, and warnings for the misspelled rules.
+    pub fn diagnostics(&self) -> &[OxcDiagnostic] {
+        &self.diagnostics
+    }
+}
+
+/// The text following `eslint` or `oxlint` in the text of a comment.
+fn strip_directive(text: &str) -> Option<&str> {
+    let text = text.trim_start();
+    let rest = text.strip_prefix("eslint").or_else(|| text.strip_prefix("oxlint"))?;
+    // `eslint-disable` and `eslint-env` are other directives
+    rest.starts_with(char::is_whitespace).then_some(rest)
+}
+
+/// The text before the description of a comment, which follows `--`.
+fn strip_description(text: &str) -> &str {
+    let mut offset = 0;
+    while let Some(i) = text[offset..].find("--") {
+        let i = offset + i;
+        let before = text[..i].ends_with(char::is_whitespace);
+        let after = text[i..].trim_start_matches('-');
+        if before && (after.is_empty() || after.starts_with(char::is_whitespace)) {
+            return text[..i].trim();
+        }
+        offset = i + 2;
+    }
+    text.trim()
+}
+
+/// The rules and their configuration, e.g. `eqeqeq: ["error", "smart"], no-console: off`.
+///
+/// The body is a JSON object without its braces, in which the keys and the severities may be
+/// unquoted.
+fn parse_entries(body: &str) -> Result<Vec<(String, Value)>, String> {
+    if body.is_empty() {
+        return Err("no rules are configured".to_string());
+    }
+    if let Ok(object) = serde_json::from_str::<Map<String, Value>>(&format!("{{{body}}}")) {
+        return Ok(object.into_iter().collect());
+    }
+    split_top_level(body, ',')
+        .into_iter()
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let Some((key, value)) = entry.split_once(':') else {
+                return Err(format!("expected `rule: severity` but found `{}`", entry.trim()));
+            };
+            let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+            let value = value.trim();
+            let value = serde_json::from_str::<Value>(value).or_else(|_| {
+                // Unquoted severities, e.g. `off`
+                if value.chars().all(|c| c.is_ascii_alphanumeric()) && !value.is_empty() {
+                    Ok(Value::String(value.to_string()))
+                } else {
+                    Err(format!("invalid configuration `{value}` for rule `{key}`"))
+                }
+            })?;
+            Ok((key.to_string(), value))
+        })
+        .collect()
+}
+
+/// Split `text` at the `separator`s which are not in brackets, braces or strings.
+fn split_top_level(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0u32;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '[' | '{' => depth += 1,
+            ']' | '}' => depth = depth.saturating_sub(1),
+            c if c == separator && depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_ast::CommentKind;
+    use oxc_span::Span;
+    use serde_json::json;
+
+    use super::{parse_entries, strip_description, strip_directive, InlineConfig};
+    use crate::{rules::RULES, AllowWarnDeny, RuleWithSeverity};
+
+    #[test]
+    fn test_strip_directive() {
+        assert_eq!(strip_directive(" eslint eqeqeq: off"), Some(" eqeqeq: off"));
+        assert_eq!(strip_directive("oxlint\neqeqeq: off"), Some("\neqeqeq: off"));
+        assert_eq!(strip_directive("eslint-disable eqeqeq"), None);
+        assert_eq!(strip_directive("eslint-env node"), None);
+        assert_eq!(strip_directive("eslintrc"), None);
+        assert_eq!(strip_directive("global foo"), None);
+    }
+
+    #[test]
+    fn test_strip_description() {
+        assert_eq!(strip_description(" eqeqeq: off -- legacy code "), "eqeqeq: off");
+        assert_eq!(strip_description("eqeqeq: off --- legacy"), "eqeqeq: off");
+        assert_eq!(
+            strip_description(r#"no-restricted-syntax: ["error", "a--b"]"#),
+            r#"no-restricted-syntax: ["error", "a--b"]"#
+        );
+    }
+
+    #[test]
+    fn test_parse_entries() {
+        let entries =
+            parse_entries(r#""eqeqeq": ["error", "smart"], "no-console": "off""#).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("eqeqeq".to_string(), json!(["error", "smart"])),
+                ("no-console".to_string(), json!("off")),
+            ]
+        );
+
+        let entries = parse_entries(
+            r#"eqeqeq: ["error", { "null": "ignore" }], no-console: off, no-var: 2,"#,
+        )
+        .unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("eqeqeq".to_string(), json!(["error", { "null": "ignore" }])),
+                ("no-console".to_string(), json!("off")),
+                ("no-var".to_string(), json!(2)),
+            ]
+        );
+
+        assert!(parse_entries("").is_err());
+        assert!(parse_entries("eqeqeq").is_err());
+        assert!(parse_entries("eqeqeq: [error").is_err());
+    }
+
+    fn parse(source_text: &str) -> InlineConfig {
+        #[allow(clippy::cast_possible_truncation)]
+        let span = Span::new(2, source_text.len() as u32 - 2);
+        InlineConfig::parse(source_text, std::iter::once((CommentKind::MultiLine, span)))
+    }
+
+    #[test]
+    fn test_apply() {
+        let rule = |name: &str, severity| {
+            let rule = RULES.iter().find(|rule| rule.name() == name).unwrap().clone();
+            RuleWithSeverity::new(rule, severity)
+        };
+        let eqeqeq = rule("eqeqeq", AllowWarnDeny::Warn);
+        let no_debugger = rule("no-debugger", AllowWarnDeny::Warn);
+
+        let config =
+            parse(r#"/* eslint eqeqeq: ["error", "smart"], no-debugger: off, no-var: warn */"#);
+        assert!(config.diagnostics().is_empty());
+        let mut rules = vec![(&eqeqeq, AllowWarnDeny::Warn), (&no_debugger, AllowWarnDeny::Warn)];
+        config.apply(&mut rules);
+        let rules =
+            rules.iter().map(|(rule, severity)| (rule.name(), *severity)).collect::<Vec<_>>();
+        assert_eq!(rules, vec![("eqeqeq", AllowWarnDeny::Deny), ("no-var", AllowWarnDeny::Warn)]);
+
+        // Only the severity changes without options
+        let config = parse("/* oxlint eqeqeq: error */");
+        let mut rules = vec![(&eqeqeq, AllowWarnDeny::Warn)];
+        config.apply(&mut rules);
+        assert!(std::ptr::eq(rules[0].0, &eqeqeq));
+        assert_eq!(rules[0].1, AllowWarnDeny::Deny);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        assert_eq!(parse("/* eslint eqeqeq */").diagnostics().len(), 1);
+        assert_eq!(parse("/* eslint eqeqeq: loud */").diagnostics().len(), 1);
+        assert_eq!(parse(r#"/* eslint unicorn/no-useless-undefined: ["error", { "checkArguments": "no" }] */"#).diagnostics().len(), 1);
+        // Misspelled rules
+        assert_eq!(parse("/* eslint no-debuger: off */").diagnostics().len(), 1);
+        // Rules of plugins which are not supported
+        assert!(parse("/* eslint foo/bar: off */").diagnostics().is_empty());
+    }
+}
@@ -0,0 +1,83 @@
+//! Parsing of the JSON files linted by the rules of the `json` plugin.
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, Trivias};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{Parser, ParserReturn};
+use oxc_span::{GetSpan, SourceType, Span};
+
+/// Extensions of the files linted by the rules of the `json` plugin.
+pub const JSON_EXT: &[&str] = &["json", "jsonc"];
+
+/// Whether the file at `path` is linted by the rules of the `json` plugin instead of the
+/// JavaScript rules.
+pub fn is_json_path(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| JSON_EXT.contains(&ext))
+}
+
+/// Parse a JSON document as a program consisting of a single expression statement, so the rules
+/// of the `json` plugin can inspect its values as object, array and literal expressions with the
+/// spans of the original document. Comments are allowed, as in `tsconfig.json` files.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn parse_json<'a>(allocator: &'a Allocator, source_text: &'a str) -> ParserReturn<'a> {
+    let source_type = SourceType::default();
+    let ast = AstBuilder::new(allocator);
+    let mut body = ast.new_vec();
+    let mut errors = vec![];
+    match Parser::new(allocator, source_text, source_type).parse_expression() {
+        Ok(expression) => {
+            let end = expression.span().end as usize;
+            let rest = source_text[end..].trim_start();
+            if !rest.is_empty() && !rest.starts_with('/') {
+                let start = (source_text.len() - rest.len()) as u32;
+                let span = Span::new(start, source_text.len() as u32);
+                errors.push(
+                    OxcDiagnostic::error("Unexpected content after the JSON value")
+                        .with_label(span),
+                );
+            }
+            body.push(ast.expression_statement(expression.span(), expression));
+        }
+        Err(diagnostics) => errors = diagnostics,
+    }
+    let span = Span::new(0, source_text.len() as u32);
+    let program = ast.program(span, source_type, ast.new_vec(), None, body);
+    ParserReturn { program, errors, trivias: Trivias::default(), panicked: false }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+
+    use super::{is_json_path, parse_json};
+
+    #[test]
+    fn test_parse_json() {
+        let allocator = Allocator::default();
+        let source_text = "// comment\n{ \"name\": \"oxlint\", \"files\": [] }\n";
+        let ret = parse_json(&allocator, source_text);
+        assert!(ret.errors.is_empty());
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            panic!("Expected an expression statement");
+        };
+        let Expression::ObjectExpression(object) = &stmt.expression else {
+            panic!("Expected an object expression");
+        };
+        assert_eq!(object.span.source_text(source_text), "{ \"name\": \"oxlint\", \"files\": [] }");
+
+        assert!(!parse_json(&allocator, "{} {}").errors.is_empty());
+        assert!(!parse_json(&allocator, "{").errors.is_empty());
+    }
+
+    #[test]
+    fn test_is_json_path() {
+        assert!(is_json_path(Path::new("package.json")));
+        assert!(is_json_path(Path::new(".vscode/settings.jsonc")));
+        assert!(!is_json_path(Path::new("index.js")));
+    }
+}
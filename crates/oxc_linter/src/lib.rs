@@ -4,36 +4,82 @@
 mod tester;
 
 mod ast_util;
+mod cache;
 mod config;
 mod context;
+mod declaration_file;
 mod disable_directives;
+mod explain;
 mod fixer;
+mod frameworks;
+mod generated;
 mod globals;
+mod inline_config;
 mod javascript_globals;
+mod module_cache;
+mod module_format;
+mod module_graph;
 mod options;
+mod package_json;
+mod project;
+mod reduce;
 mod rule;
+mod rule_names;
 mod rules;
+mod security;
+mod selector;
 mod service;
+mod timing;
+mod tsconfig;
 mod utils;
+mod wasm_plugin;
 
+pub mod docs;
+pub mod json;
 pub mod partial_loader;
 pub mod table;
 
-use std::{io::Write, rc::Rc, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    hash::{Hash, Hasher},
+    io::Write,
+    path::Path,
+    rc::Rc,
+    sync::Arc,
+    time::Instant,
+};
 
-use oxc_diagnostics::Error;
+use oxc_allocator::Allocator;
+use oxc_ast::AstType;
+use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
+use oxc_parser::Parser;
 use oxc_semantic::AstNode;
+use oxc_span::SourceType;
+use rustc_hash::{FxHashMap, FxHasher};
 
 pub use crate::{
-    config::OxlintConfig,
-    context::LintContext,
+    cache::{LintCache, DEFAULT_CACHE_LOCATION},
+    config::{migrate_eslint_config, GlobalValue, MigratedConfig, OxlintConfig, CONFIG_FILE_NAME},
+    context::{FromAstKind, LintContext},
+    fixer::{Fix, FixResult, FixReviewer, Fixer, Message, Suggestion},
+    frameworks::FrameworkFlags,
+    generated::{is_generated, GeneratedFiles},
+    module_format::ModuleFormat,
+    module_graph::ModuleGraph,
     options::{AllowWarnDeny, LintOptions},
-    rule::{RuleCategory, RuleMeta, RuleWithSeverity},
-    service::{LintService, LintServiceOptions},
+    package_json::PackageJson,
+    project::{ProjectContext, ProjectFile},
+    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleWithSeverity},
+    service::{FixedFile, LintService, LintServiceOptions},
+    timing::{RuleTiming, RuleTimings},
+    tsconfig::{CompilerOptions, Tsconfig},
+    wasm_plugin::WasmPlugin,
 };
 use crate::{
-    config::{OxlintEnv, OxlintGlobals, OxlintSettings},
-    fixer::{Fix, Fixer, Message},
+    config::{NestedConfigs, OxlintEnv, OxlintGlobals, OxlintRules, OxlintSettings},
+    disable_directives::DisableDirectivesBuilder,
+    explain::{with_explanation, RuleSource},
+    inline_config::InlineConfig,
     rules::RuleEnum,
     table::RuleTable,
 };
@@ -53,6 +99,10 @@ pub struct Linter {
     rules: Vec<RuleWithSeverity>,
     options: LintOptions,
     eslint_config: Arc<OxlintConfig>,
+    timings: Option<Arc<RuleTimings>>,
+    fix_reviewer: Option<Arc<dyn FixReviewer>>,
+    nested_configs: Option<NestedConfigs>,
+    wasm_plugins: Vec<WasmPlugin>,
 }
 
 impl Default for Linter {
@@ -67,7 +117,15 @@ impl Linter {
     /// Returns `Err` if there are any errors parsing the configuration file.
     pub fn from_options(options: LintOptions) -> Result<Self, Error> {
         let (rules, eslint_config) = options.derive_rules_and_config()?;
-        Ok(Self { rules, options, eslint_config: Arc::new(eslint_config) })
+        Ok(Self {
+            rules,
+            options,
+            eslint_config: Arc::new(eslint_config),
+            timings: None,
+            fix_reviewer: None,
+            nested_configs: None,
+            wasm_plugins: vec![],
+        })
     }
 
     #[cfg(test)]
@@ -89,43 +147,463 @@ impl Linter {
         self
     }
 
+    /// Also apply fixes which may change the behavior of the code, requires [`Linter::with_fix`].
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, yes: bool) -> Self {
+        self.options.fix_dangerously = yes;
+        self
+    }
+
+    /// Record the time spent in each rule, see [`Linter::timings`].
+    #[must_use]
+    pub fn with_timing(mut self, yes: bool) -> Self {
+        self.timings = yes.then(Arc::default);
+        self
+    }
+
+    /// Let `reviewer` decide which fixes are applied when fixing, see [`FixReviewer`].
+    #[must_use]
+    pub fn with_fix_reviewer(mut self, reviewer: Arc<dyn FixReviewer>) -> Self {
+        self.fix_reviewer = Some(reviewer);
+        self
+    }
+
+    /// Lint each file with the [`CONFIG_FILE_NAME`] files in its directory and its ancestors,
+    /// merged the way ESLint merges `.eslintrc` files. Files without configuration files are
+    /// linted with the configuration of the linter.
+    #[must_use]
+    pub fn with_nested_configs(mut self, yes: bool) -> Self {
+        self.nested_configs = yes.then(NestedConfigs::default);
+        self
+    }
+
+    /// Also run the rules of `plugins`, configured as `{plugin}/{rule}` in the `rules` of the
+    /// configuration files, and as warnings when they are not configured. They don't run with
+    /// [`LintOptions::rule_filter`] and [`LintOptions::security`].
+    #[must_use]
+    pub fn with_wasm_plugins(mut self, plugins: Vec<WasmPlugin>) -> Self {
+        self.wasm_plugins = plugins;
+        self
+    }
+
+    pub fn fix_reviewer(&self) -> Option<&dyn FixReviewer> {
+        self.fix_reviewer.as_deref()
+    }
+
+    pub fn timings(&self) -> Option<&RuleTimings> {
+        self.timings.as_deref()
+    }
+
     pub fn options(&self) -> &LintOptions {
         &self.options
     }
 
+    pub(crate) fn settings(&self) -> &OxlintSettings {
+        &self.eslint_config.settings
+    }
+
     pub fn number_of_rules(&self) -> usize {
         self.rules.len()
     }
 
+    /// Warnings about the configuration, e.g. misspelled rule names.
+    pub fn config_warnings(&self) -> Vec<OxcDiagnostic> {
+        let mut warnings = self.eslint_config.unknown_rules_except(|key| {
+            self.wasm_plugins.iter().any(|plugin| plugin.has_rule(key))
+        });
+        warnings.extend(self.eslint_config.invalid_rule_options());
+        warnings
+    }
+
+    /// Whether the file at `path` is ignored by the `ignorePatterns` of its configuration.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        self.config_of(path).ignore_patterns.is_ignored(path)
+    }
+
+    /// Whether warnings fail the run, with the `denyWarnings` of the configuration of the
+    /// current directory.
+    pub fn deny_warnings(&self) -> bool {
+        self.config_of(Path::new(CONFIG_FILE_NAME)).deny_warnings
+    }
+
+    /// The configuration of the file at `path`, which is the configuration file of its directory
+    /// when no configuration is given explicitly.
+    fn config_of(&self, path: &Path) -> Arc<OxlintConfig> {
+        let nested_config = self.nested_configs.as_ref().and_then(|configs| {
+            configs.resolve(path, |config| self.options.derive_rules(Some(config)))
+        });
+        match nested_config {
+            Some(Ok(nested_config)) => Arc::clone(&nested_config.config),
+            _ => Arc::clone(&self.eslint_config),
+        }
+    }
+
+    /// Hash of the options, rules and configuration the file at `path` is linted with, and of the
+    /// `package_json` of the file, e.g. its `"type"` telling the [`ModuleFormat`], which changes
+    /// when the diagnostics of the file may change, see [`LintCache`].
+    pub(crate) fn config_hash(&self, path: &Path, package_json: Option<&PackageJson>) -> u64 {
+        let nested_config = self.nested_configs.as_ref().and_then(|configs| {
+            configs.resolve(path, |config| self.options.derive_rules(Some(config)))
+        });
+        let (rules, eslint_config) = match &nested_config {
+            Some(Ok(nested_config)) => (&nested_config.rules, &nested_config.config),
+            _ => (&self.rules, &self.eslint_config),
+        };
+        let mut hasher = FxHasher::default();
+        format!("{:?}", self.options).hash(&mut hasher);
+        format!("{eslint_config:?}").hash(&mut hasher);
+        for rule in rules {
+            rule.plugin_name().hash(&mut hasher);
+            rule.name().hash(&mut hasher);
+            format!("{:?}", rule.severity).hash(&mut hasher);
+        }
+        for plugin in &self.wasm_plugins {
+            plugin.hash().hash(&mut hasher);
+        }
+        ModuleFormat::infer(path, package_json).hash(&mut hasher);
+        if let Some(package_json) = package_json {
+            package_json.hash_fields(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// The rules of `all_rules` which run on the file at `path` linted with `config`, with their
+    /// severity in the file.
+    fn file_rules<'r>(
+        all_rules: &'r [RuleWithSeverity],
+        config: &'r OxlintConfig,
+        path: &'r Path,
+        is_declaration_file: bool,
+    ) -> impl Iterator<Item = (&'r RuleWithSeverity, AllowWarnDeny)> + 'r {
+        let severity_overrides = config.severity_overrides.matching(path);
+        let ignored_rules = config.rule_ignore_patterns.matching(path);
+        // JSON files are only linted by the rules of the json plugin, and vice versa
+        let is_json = json::is_json_path(path);
+        // The rules of the test frameworks only run on the test files configured in the settings
+        let test_files = &config.settings.test;
+        let skip_test_rules = test_files.has_patterns() && !test_files.is_test_file(path);
+        all_rules
+            .iter()
+            .filter(move |rule| (rule.plugin_name() == "json") == is_json)
+            .filter(move |rule| {
+                !(skip_test_rules && matches!(rule.plugin_name(), "jest" | "vitest"))
+            })
+            .filter(move |rule| {
+                !is_declaration_file || declaration_file::runs_on_declaration_files(rule)
+            })
+            .filter(move |rule| !ignored_rules.is_ignored(rule))
+            .filter_map(move |rule| {
+                let severity = severity_overrides.resolve(rule);
+                severity.is_warn_deny().then_some((rule, severity))
+            })
+    }
+
+    /// The configuration the file at `path` is linted with, after `extends`, the cascading
+    /// configuration files, the severity overrides and the rules enabled or disabled on the
+    /// command line, for debugging why a rule does or does not report problems in the file.
+    ///
+    /// The JSON object has the configuration file, whether the file is ignored by its
+    /// `ignorePatterns`, the environments, the globals and the rules which run on the file in the
+    /// format of the `rules` of configuration files, with their severity and options.
+    ///
+    /// # Errors
+    ///
+    /// If the configuration files of the directory of the file cannot be read.
+    pub fn resolved_config(&self, path: &Path) -> Result<serde_json::Value, OxcDiagnostic> {
+        let nested_config = self.nested_configs.as_ref().and_then(|configs| {
+            configs.resolve(path, |config| self.options.derive_rules(Some(config)))
+        });
+        let (config_path, all_rules, config) = match &nested_config {
+            Some(Ok(nested_config)) => {
+                (Some(nested_config.path.as_path()), &nested_config.rules, &nested_config.config)
+            }
+            Some(Err(error)) => return Err(error.clone()),
+            None => (self.options.config_path.as_deref(), &self.rules, &self.eslint_config),
+        };
+
+        let is_declaration_file =
+            SourceType::from_path(path).is_ok_and(SourceType::is_typescript_definition);
+        let rules = Self::file_rules(all_rules, config, path, is_declaration_file)
+            .map(|(rule, severity)| {
+                let key = rule_names::rule_key(rule.plugin_name(), rule.name());
+                let severity = serde_json::Value::from(severity.as_str());
+                let value = match config.rule_options(rule) {
+                    Some(serde_json::Value::Array(options)) => {
+                        std::iter::once(severity).chain(options.iter().cloned()).collect()
+                    }
+                    _ => severity,
+                };
+                (key, value)
+            })
+            .collect::<BTreeMap<_, _>>();
+        let env = config.env.iter().map(|env| (env, true)).collect::<BTreeMap<_, _>>();
+        let globals = config
+            .globals
+            .iter()
+            .map(|(name, value)| (name, value.as_str()))
+            .collect::<BTreeMap<_, _>>();
+
+        Ok(serde_json::json!({
+            "configFile": config_path,
+            "ignored": config.ignore_patterns.is_ignored(path),
+            "env": env,
+            "globals": globals,
+            "rules": rules,
+        }))
+    }
+
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
         let semantic = Rc::clone(ctx.semantic());
 
-        let ctx = ctx.with_fix(self.options.fix).with_eslint_config(&self.eslint_config);
-        let rules = self
-            .rules
-            .iter()
-            .map(|rule| {
-                (rule, ctx.clone().with_rule_name(rule.name()).with_severity(rule.severity))
+        let nested_config = self.nested_configs.as_ref().and_then(|configs| {
+            configs.resolve(ctx.file_path(), |config| self.options.derive_rules(Some(config)))
+        });
+        let mut config_error = None;
+        let (all_rules, eslint_config) = match &nested_config {
+            Some(Ok(nested_config)) => (&nested_config.rules, &nested_config.config),
+            Some(Err(error)) => {
+                config_error = Some(Message::new(error.clone(), None));
+                (&self.rules, &self.eslint_config)
+            }
+            None => (&self.rules, &self.eslint_config),
+        };
+
+        let ctx = ctx
+            .with_fix(self.options.fix || self.options.report_fixes)
+            .with_fix_dangerously(self.options.fix_dangerously)
+            .with_security(self.options.security)
+            .with_eslint_config(eslint_config)
+            .with_frameworks_detected();
+        let mut file_rules =
+            Self::file_rules(all_rules, eslint_config, ctx.file_path(), ctx.is_declaration_file())
+                .collect::<Vec<_>>();
+        let inline_config = InlineConfig::parse(ctx.source_text(), ctx.comments());
+        // The rules selected on the command line and the security preset run alone
+        if self.options.rule_filter.is_empty() && !self.options.security {
+            inline_config.apply(&mut file_rules);
+        }
+        let rules = file_rules
+            .into_iter()
+            .map(|(rule, severity)| {
+                let ctx = ctx
+                    .clone()
+                    .with_rule_name(rule.name())
+                    .with_plugin_name(rule.plugin_name())
+                    .with_severity(severity);
+                (rule, ctx)
             })
             .collect::<Vec<_>>();
 
-        for (rule, ctx) in &rules {
-            rule.run_once(ctx);
+        if let Some(timings) = &self.timings {
+            // Run rule by rule so the time spent in each of them can be measured.
+            for (rule, ctx) in &rules {
+                let start = Instant::now();
+                rule.run_once(ctx);
+                for symbol in semantic.symbols().iter() {
+                    rule.run_on_symbol(symbol, ctx);
+                }
+                let node_types = rule.node_types();
+                for node in semantic.nodes().iter().filter(|node| {
+                    node_types.map_or(true, |types| types.contains(&node.kind().ty()))
+                }) {
+                    rule.run(node, ctx);
+                }
+                let path = Some(ctx.file_path());
+                timings.add(rule.plugin_name(), rule.name(), path, start.elapsed());
+            }
+        } else {
+            for (rule, ctx) in &rules {
+                rule.run_once(ctx);
+            }
+
+            for symbol in semantic.symbols().iter() {
+                for (rule, ctx) in &rules {
+                    rule.run_on_symbol(symbol, ctx);
+                }
+            }
+
+            // Rules which declare the types of nodes they report on are only called with them
+            let mut any_node_rules = vec![];
+            let mut node_type_rules = FxHashMap::<AstType, Vec<_>>::default();
+            for rule_and_ctx in &rules {
+                match rule_and_ctx.0.node_types() {
+                    Some(types) => {
+                        for ty in types {
+                            node_type_rules.entry(*ty).or_default().push(rule_and_ctx);
+                        }
+                    }
+                    None => any_node_rules.push(rule_and_ctx),
+                }
+            }
+            for node in semantic.nodes().iter() {
+                for (rule, ctx) in &any_node_rules {
+                    rule.run(node, ctx);
+                }
+                for (rule, ctx) in node_type_rules.get(&node.kind().ty()).into_iter().flatten() {
+                    rule.run(node, ctx);
+                }
+            }
         }
 
-        for symbol in semantic.symbols().iter() {
-            for (rule, ctx) in &rules {
-                rule.run_on_symbol(symbol, ctx);
+        let mut messages = vec![];
+        for (rule, rule_ctx) in rules {
+            let rule_messages = rule_ctx.into_message();
+            if self.options.explain && !rule_messages.is_empty() {
+                let config_path = match &nested_config {
+                    Some(Ok(nested_config)) => Some(nested_config.path.as_path()),
+                    _ => self.options.config_path.as_deref(),
+                };
+                let inline_config = (self.options.rule_filter.is_empty() && !self.options.security)
+                    .then_some(&inline_config);
+                let source = RuleSource::find(
+                    rule,
+                    ctx.file_path(),
+                    &self.options,
+                    eslint_config,
+                    inline_config,
+                );
+                messages.extend(rule_messages.into_iter().map(|mut message| {
+                    message.error = with_explanation(message.error, rule, source, config_path);
+                    message
+                }));
+            } else {
+                messages.extend(rule_messages);
+            }
+        }
+        // The rules selected on the command line and the security preset run alone, and the
+        // plugins don't lint JSON files
+        if self.options.rule_filter.is_empty()
+            && !self.options.security
+            && !json::is_json_path(ctx.file_path())
+        {
+            for plugin in &self.wasm_plugins {
+                messages.extend(plugin.run(&ctx, eslint_config));
             }
         }
+        messages.extend(config_error);
+        messages.extend(
+            inline_config
+                .diagnostics()
+                .iter()
+                .map(|diagnostic| Message::new(diagnostic.clone(), None)),
+        );
+        messages.extend(
+            ctx.disable_directives()
+                .unknown_rules(ctx.source_text())
+                .into_iter()
+                .map(|diagnostic| Message::new(diagnostic, None)),
+        );
+        if eslint_config.require_suppression_reasons {
+            messages.extend(
+                ctx.disable_directives()
+                    .missing_reasons(ctx.source_text())
+                    .into_iter()
+                    .map(|diagnostic| Message::new(diagnostic, None)),
+            );
+        }
+        if self.options.report_unused_directives {
+            messages.extend(ctx.disable_directives().unused(ctx.source_text()).into_iter().map(
+                |(diagnostic, fix)| Message::new(diagnostic, self.options.fix.then_some(fix)),
+            ));
+        }
+        messages
+    }
 
-        for node in semantic.nodes().iter() {
-            for (rule, ctx) in &rules {
-                rule.run(node, ctx);
+    /// Whether an enabled rule runs on the project, which needs the data of the linted files.
+    pub(crate) fn has_project_rules(&self) -> bool {
+        self.rules.iter().any(|rule| rule.runs_on_project())
+    }
+
+    /// Run [`rule::Rule::run_on_project`] of all rules on the files collected while linting,
+    /// returning the diagnostics grouped by file with the source text of the file, read with
+    /// `read_source`. The disable directives and the inline configuration of each file apply to
+    /// its diagnostics.
+    pub fn run_on_project(
+        &self,
+        files: Vec<ProjectFile>,
+        module_graph: &Arc<ModuleGraph>,
+        read_source: impl Fn(&Path) -> Option<String>,
+    ) -> Vec<(Box<Path>, String, Vec<OxcDiagnostic>)> {
+        let ctx = ProjectContext::new(files).with_module_graph(module_graph);
+        let mut diagnostics = BTreeMap::<Box<Path>, Vec<_>>::new();
+        for rule in self.rules.iter().filter(|rule| rule.runs_on_project()) {
+            let start = Instant::now();
+            rule.run_on_project(&ctx);
+            if let Some(timings) = &self.timings {
+                timings.add(rule.plugin_name(), rule.name(), None, start.elapsed());
+            }
+            for (path, diagnostic) in ctx.take_diagnostics() {
+                diagnostics.entry(path).or_default().push((rule, diagnostic));
             }
         }
+        diagnostics
+            .into_iter()
+            .map(|(path, diagnostics)| {
+                let source_text = read_source(&path).unwrap_or_default();
+                let diagnostics = self.filter_project_diagnostics(&path, &source_text, diagnostics);
+                (path, source_text, diagnostics)
+            })
+            .collect()
+    }
 
-        rules.into_iter().flat_map(|(_, ctx)| ctx.into_message()).collect::<Vec<_>>()
+    /// The project diagnostics of the file at `path` which are not ignored, disabled by a
+    /// directive or turned off by the inline configuration, with their severity in the file.
+    fn filter_project_diagnostics(
+        &self,
+        path: &Path,
+        source_text: &str,
+        diagnostics: Vec<(&RuleWithSeverity, OxcDiagnostic)>,
+    ) -> Vec<OxcDiagnostic> {
+        // The comments are only available for the files the parser supports
+        let allocator = Allocator::default();
+        let trivias = ModuleFormat::source_type(path).ok().map(|source_type| {
+            Parser::new(&allocator, source_text, source_type)
+                .allow_return_outside_function(true)
+                .parse()
+                .trivias
+        });
+        let disable_directives = trivias
+            .as_ref()
+            .map(|trivias| DisableDirectivesBuilder::new(source_text, trivias.clone()).build());
+        // The rules selected on the command line and the security preset run alone
+        let inline_config = trivias
+            .filter(|_| self.options.rule_filter.is_empty() && !self.options.security)
+            .map(|trivias| InlineConfig::parse(source_text, trivias.comments()));
+
+        let ignored_rules = self.eslint_config.rule_ignore_patterns.matching(path);
+        let severity_overrides = self.eslint_config.severity_overrides.matching(path);
+        diagnostics
+            .into_iter()
+            .filter(|(rule, _)| !ignored_rules.is_ignored(rule))
+            .filter_map(|(rule, diagnostic)| {
+                let mut file_rules = vec![(rule, severity_overrides.resolve(rule))];
+                if let Some(inline_config) = &inline_config {
+                    inline_config.apply(&mut file_rules);
+                }
+                let (_, severity) =
+                    file_rules.pop().filter(|(_, severity)| severity.is_warn_deny())?;
+                let start = Message::new(diagnostic.clone(), None).start();
+                if disable_directives
+                    .as_ref()
+                    .is_some_and(|directives| directives.contains(rule.name(), start))
+                {
+                    return None;
+                }
+                let mut diagnostic = diagnostic.with_severity(Severity::from(severity));
+                if self.options.explain {
+                    let source = RuleSource::find(
+                        rule,
+                        path,
+                        &self.options,
+                        &self.eslint_config,
+                        inline_config.as_ref(),
+                    );
+                    let config_path = self.options.config_path.as_deref();
+                    diagnostic = with_explanation(diagnostic, rule, source, config_path);
+                }
+                Some(diagnostic)
+            })
+            .collect()
     }
 
     /// # Panics
@@ -137,6 +615,14 @@ impl Linter {
         writeln!(writer, "Default: {}", table.turned_on_by_default_count).unwrap();
         writeln!(writer, "Total: {}", table.total).unwrap();
     }
+
+    /// Print the JSON schema of the `rules` configuration, including the options of each rule.
+    ///
+    /// # Panics
+    pub fn print_rules_schema<W: Write>(writer: &mut W) {
+        let schema = OxlintRules::known_rules_schema();
+        writeln!(writer, "{}", serde_json::to_string_pretty(&schema).unwrap()).unwrap();
+    }
 }
 
 #[cfg(test)]
@@ -150,6 +636,36 @@ mod test {
         assert!(!writer.is_empty());
     }
 
+    #[test]
+    fn test_resolved_config() {
+        use std::path::{Path, PathBuf};
+
+        use crate::{AllowWarnDeny, LintOptions};
+
+        let options = LintOptions::default()
+            .with_filter(vec![(AllowWarnDeny::Allow, "all".to_string())])
+            .with_config_path(Some(PathBuf::from("fixtures/print_config.json")));
+        let linter = Linter::from_options(options).unwrap();
+
+        let config = linter.resolved_config(Path::new("src/foo.js")).unwrap();
+        assert_eq!(
+            config,
+            serde_json::json!({
+                "configFile": "fixtures/print_config.json",
+                "ignored": false,
+                "env": { "browser": true },
+                "globals": { "foo": "readonly" },
+                "rules": { "eqeqeq": ["error", "smart"], "no-debugger": "warn" },
+            })
+        );
+
+        let config = linter.resolved_config(Path::new("legacy/foo.js")).unwrap();
+        assert_eq!(config["rules"]["eqeqeq"], serde_json::json!(["warn", "smart"]));
+
+        let config = linter.resolved_config(Path::new("dist/foo.js")).unwrap();
+        assert_eq!(config["ignored"], true);
+    }
+
     #[test]
     fn test_schema_json() {
         use std::fs;
@@ -0,0 +1,391 @@
+//! The module records of the dependencies of the linted files, persisted in the [`LintCache`] so
+//! the dependencies which did not change are not parsed again to build the module graph.
+//!
+//! [`LintCache`]: crate::LintCache
+
+use std::path::Path;
+
+use oxc_semantic::ModuleRecord;
+use oxc_span::Span;
+use oxc_syntax::module_record::{
+    ExportBindingKind, ExportEntry, ExportExportName, ExportImportName, ExportLocalName,
+    ImportEntry, ImportImportName, NameSpan, RequestedModule,
+};
+use serde::{Deserialize, Serialize};
+
+/// A [`ModuleRecord`] without the modules it is linked to, `loaded_modules` and
+/// `exported_bindings_from_star_export`, which are linked again when it is restored.
+#[derive(Clone, Serialize, Deserialize)]
+pub(crate) struct CachedModule {
+    /// The key of the source text the record was built from, see [`LintCache::module_key`]
+    ///
+    /// [`LintCache::module_key`]: crate::LintCache::module_key
+    pub key: u64,
+    not_esm: bool,
+    requested_modules: Vec<(String, Vec<CachedRequestedModule>)>,
+    import_entries: Vec<CachedImportEntry>,
+    local_export_entries: Vec<CachedExportEntry>,
+    indirect_export_entries: Vec<CachedExportEntry>,
+    star_export_entries: Vec<CachedExportEntry>,
+    exported_bindings: Vec<(String, CachedSpan)>,
+    exported_bindings_duplicated: Vec<CachedNameSpan>,
+    export_default: Option<CachedSpan>,
+    export_default_duplicated: Vec<CachedSpan>,
+}
+
+type CachedSpan = (u32, u32);
+
+type CachedNameSpan = (String, CachedSpan);
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedRequestedModule {
+    span: CachedSpan,
+    is_type: bool,
+    is_import: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedImportEntry {
+    module_request: CachedNameSpan,
+    import_name: CachedImportName,
+    local_name: CachedNameSpan,
+    is_type: bool,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedImportName {
+    Name(CachedNameSpan),
+    NamespaceObject,
+    Default(CachedSpan),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedExportEntry {
+    span: CachedSpan,
+    statement_span: CachedSpan,
+    module_request: Option<CachedNameSpan>,
+    import_name: CachedExportImportName,
+    export_name: CachedExportName,
+    local_name: CachedLocalName,
+    binding_kind: CachedBindingKind,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedExportImportName {
+    Name(CachedNameSpan),
+    All,
+    AllButDefault,
+    Null,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedExportName {
+    Name(CachedNameSpan),
+    Default(CachedSpan),
+    Null,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedLocalName {
+    Name(CachedNameSpan),
+    Default(CachedNameSpan),
+    Null,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+enum CachedBindingKind {
+    Function,
+    Class,
+    Const,
+    Variable,
+    Expression,
+    Unknown,
+}
+
+impl CachedModule {
+    pub fn new(key: u64, module_record: &ModuleRecord) -> Self {
+        let mut requested_modules = module_record
+            .requested_modules
+            .iter()
+            .map(|(specifier, requests)| {
+                let requests = requests
+                    .iter()
+                    .map(|request| CachedRequestedModule {
+                        span: span(request.span()),
+                        is_type: request.is_type(),
+                        is_import: request.is_import(),
+                    })
+                    .collect();
+                (specifier.to_string(), requests)
+            })
+            .collect::<Vec<_>>();
+        // Sorted for a stable cache file
+        requested_modules.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let mut exported_bindings = module_record
+            .exported_bindings
+            .iter()
+            .map(|(name, binding_span)| (name.to_string(), span(*binding_span)))
+            .collect::<Vec<_>>();
+        exported_bindings.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        let export_entries =
+            |entries: &[ExportEntry]| entries.iter().map(CachedExportEntry::new).collect();
+        Self {
+            key,
+            not_esm: module_record.not_esm,
+            requested_modules,
+            import_entries: module_record
+                .import_entries
+                .iter()
+                .map(CachedImportEntry::new)
+                .collect(),
+            local_export_entries: export_entries(&module_record.local_export_entries),
+            indirect_export_entries: export_entries(&module_record.indirect_export_entries),
+            star_export_entries: export_entries(&module_record.star_export_entries),
+            exported_bindings,
+            exported_bindings_duplicated: module_record
+                .exported_bindings_duplicated
+                .iter()
+                .map(name_span)
+                .collect(),
+            export_default: module_record.export_default.map(span),
+            export_default_duplicated: module_record
+                .export_default_duplicated
+                .iter()
+                .copied()
+                .map(span)
+                .collect(),
+        }
+    }
+
+    /// The module record of the file at `path`, which is not linked to its dependencies.
+    pub fn to_module_record(&self, path: &Path) -> ModuleRecord {
+        let export_entries = |entries: &[CachedExportEntry]| {
+            entries.iter().map(CachedExportEntry::to_export_entry).collect()
+        };
+        ModuleRecord {
+            not_esm: self.not_esm,
+            requested_modules: self
+                .requested_modules
+                .iter()
+                .map(|(specifier, requests)| {
+                    let requests = requests
+                        .iter()
+                        .map(|request| {
+                            RequestedModule::new(
+                                to_span(request.span),
+                                request.is_type,
+                                request.is_import,
+                            )
+                        })
+                        .collect();
+                    (specifier.as_str().into(), requests)
+                })
+                .collect(),
+            import_entries: self
+                .import_entries
+                .iter()
+                .map(CachedImportEntry::to_import_entry)
+                .collect(),
+            local_export_entries: export_entries(&self.local_export_entries),
+            indirect_export_entries: export_entries(&self.indirect_export_entries),
+            star_export_entries: export_entries(&self.star_export_entries),
+            exported_bindings: self
+                .exported_bindings
+                .iter()
+                .map(|(name, span)| (name.as_str().into(), to_span(*span)))
+                .collect(),
+            exported_bindings_duplicated: self
+                .exported_bindings_duplicated
+                .iter()
+                .map(to_name_span)
+                .collect(),
+            export_default: self.export_default.map(to_span),
+            export_default_duplicated: self
+                .export_default_duplicated
+                .iter()
+                .copied()
+                .map(to_span)
+                .collect(),
+            ..ModuleRecord::new(path.to_path_buf())
+        }
+    }
+}
+
+impl CachedImportEntry {
+    fn new(entry: &ImportEntry) -> Self {
+        Self {
+            module_request: name_span(&entry.module_request),
+            import_name: match &entry.import_name {
+                ImportImportName::Name(name) => CachedImportName::Name(name_span(name)),
+                ImportImportName::NamespaceObject => CachedImportName::NamespaceObject,
+                ImportImportName::Default(default) => CachedImportName::Default(span(*default)),
+            },
+            local_name: name_span(&entry.local_name),
+            is_type: entry.is_type,
+        }
+    }
+
+    fn to_import_entry(&self) -> ImportEntry {
+        ImportEntry {
+            module_request: to_name_span(&self.module_request),
+            import_name: match &self.import_name {
+                CachedImportName::Name(name) => ImportImportName::Name(to_name_span(name)),
+                CachedImportName::NamespaceObject => ImportImportName::NamespaceObject,
+                CachedImportName::Default(default) => ImportImportName::Default(to_span(*default)),
+            },
+            local_name: to_name_span(&self.local_name),
+            is_type: self.is_type,
+        }
+    }
+}
+
+impl CachedExportEntry {
+    fn new(entry: &ExportEntry) -> Self {
+        Self {
+            span: span(entry.span),
+            statement_span: span(entry.statement_span),
+            module_request: entry.module_request.as_ref().map(name_span),
+            import_name: match &entry.import_name {
+                ExportImportName::Name(name) => CachedExportImportName::Name(name_span(name)),
+                ExportImportName::All => CachedExportImportName::All,
+                ExportImportName::AllButDefault => CachedExportImportName::AllButDefault,
+                ExportImportName::Null => CachedExportImportName::Null,
+            },
+            export_name: match &entry.export_name {
+                ExportExportName::Name(name) => CachedExportName::Name(name_span(name)),
+                ExportExportName::Default(default) => CachedExportName::Default(span(*default)),
+                ExportExportName::Null => CachedExportName::Null,
+            },
+            local_name: match &entry.local_name {
+                ExportLocalName::Name(name) => CachedLocalName::Name(name_span(name)),
+                ExportLocalName::Default(name) => CachedLocalName::Default(name_span(name)),
+                ExportLocalName::Null => CachedLocalName::Null,
+            },
+            binding_kind: match entry.binding_kind {
+                ExportBindingKind::Function => CachedBindingKind::Function,
+                ExportBindingKind::Class => CachedBindingKind::Class,
+                ExportBindingKind::Const => CachedBindingKind::Const,
+                ExportBindingKind::Variable => CachedBindingKind::Variable,
+                ExportBindingKind::Expression => CachedBindingKind::Expression,
+                ExportBindingKind::Unknown => CachedBindingKind::Unknown,
+            },
+        }
+    }
+
+    fn to_export_entry(&self) -> ExportEntry {
+        ExportEntry {
+            span: to_span(self.span),
+            statement_span: to_span(self.statement_span),
+            module_request: self.module_request.as_ref().map(to_name_span),
+            import_name: match &self.import_name {
+                CachedExportImportName::Name(name) => ExportImportName::Name(to_name_span(name)),
+                CachedExportImportName::All => ExportImportName::All,
+                CachedExportImportName::AllButDefault => ExportImportName::AllButDefault,
+                CachedExportImportName::Null => ExportImportName::Null,
+            },
+            export_name: match &self.export_name {
+                CachedExportName::Name(name) => ExportExportName::Name(to_name_span(name)),
+                CachedExportName::Default(default) => ExportExportName::Default(to_span(*default)),
+                CachedExportName::Null => ExportExportName::Null,
+            },
+            local_name: match &self.local_name {
+                CachedLocalName::Name(name) => ExportLocalName::Name(to_name_span(name)),
+                CachedLocalName::Default(name) => ExportLocalName::Default(to_name_span(name)),
+                CachedLocalName::Null => ExportLocalName::Null,
+            },
+            binding_kind: match self.binding_kind {
+                CachedBindingKind::Function => ExportBindingKind::Function,
+                CachedBindingKind::Class => ExportBindingKind::Class,
+                CachedBindingKind::Const => ExportBindingKind::Const,
+                CachedBindingKind::Variable => ExportBindingKind::Variable,
+                CachedBindingKind::Expression => ExportBindingKind::Expression,
+                CachedBindingKind::Unknown => ExportBindingKind::Unknown,
+            },
+        }
+    }
+}
+
+fn span(span: Span) -> CachedSpan {
+    (span.start, span.end)
+}
+
+fn to_span((start, end): CachedSpan) -> Span {
+    Span::new(start, end)
+}
+
+fn name_span(name: &NameSpan) -> CachedNameSpan {
+    (name.name().to_string(), span(name.span()))
+}
+
+fn to_name_span((name, span): &CachedNameSpan) -> NameSpan {
+    NameSpan::new(name.as_str().into(), to_span(*span))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::{ModuleRecord, SemanticBuilder};
+    use oxc_span::SourceType;
+
+    use super::CachedModule;
+
+    #[test]
+    fn test_cached_module() {
+        let source_text = r#"
+            import a, { b as c } from "./a";
+            import * as ns from "./ns";
+            import type { T } from "./types";
+            export { c };
+            export const d = 1, e = () => {};
+            export default class F {}
+            export * from "./star";
+            export * as all from "./all";
+            export { g as h } from "./g";
+        "#;
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true).with_typescript(true);
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let module_record = SemanticBuilder::new(source_text, source_type)
+            .build_module_record(PathBuf::from("index.ts"), &program)
+            .module_record();
+
+        let json = serde_json::to_string(&CachedModule::new(1, &module_record)).unwrap();
+        let cached = serde_json::from_str::<CachedModule>(&json).unwrap();
+        assert_eq!(cached.key, 1);
+        let restored = cached.to_module_record(Path::new("index.ts"));
+
+        // The records are compared without the order of their maps
+        let debug = |module_record: &ModuleRecord| {
+            let mut requested_modules = module_record
+                .requested_modules
+                .iter()
+                .map(|(specifier, requests)| format!("{specifier} {requests:?}"))
+                .collect::<Vec<_>>();
+            requested_modules.sort_unstable();
+            let mut exported_bindings = module_record
+                .exported_bindings
+                .iter()
+                .map(|(name, span)| format!("{name} {span:?}"))
+                .collect::<Vec<_>>();
+            exported_bindings.sort_unstable();
+            format!(
+                "{:?} {requested_modules:?} {:?} {:?} {:?} {:?} {exported_bindings:?} {:?} {:?} {:?}",
+                module_record.not_esm,
+                module_record.import_entries,
+                module_record.local_export_entries,
+                module_record.indirect_export_entries,
+                module_record.star_export_entries,
+                module_record.exported_bindings_duplicated,
+                module_record.export_default,
+                module_record.export_default_duplicated,
+            )
+        };
+        assert_eq!(restored.resolved_absolute_path, module_record.resolved_absolute_path);
+        assert_eq!(debug(&restored), debug(&module_record));
+        assert!(!restored.indirect_export_entries.is_empty());
+        assert!(!restored.star_export_entries.is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+//! Whether the linted files are ES modules or CommonJS modules, see [`LintContext::module_format`].
+//!
+//! [`LintContext::module_format`]: crate::LintContext::module_format
+
+use std::{ffi::OsStr, path::Path};
+
+use oxc_span::{SourceType, UnknownExtension};
+
+use crate::PackageJson;
+
+/// How a file is loaded by Node.js.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModuleFormat {
+    /// ES module, which imports with `import` and exports with `export`
+    Esm,
+    /// CommonJS module, which imports with `require()` and exports with `module.exports`
+    CommonJs,
+}
+
+impl ModuleFormat {
+    /// The format of the file at `path`, in the package of `package_json`.
+    ///
+    /// `.mjs` and `.mts` files are ES modules and `.cjs` and `.cts` files are CommonJS modules.
+    /// The other files follow the `"type"` of the package. `None` when the package has no
+    /// `"type"`: Node.js loads the files as CommonJS modules but they are often ES modules compiled
+    /// by a bundler, rules look at the `import` and `export` of the file instead.
+    pub fn infer(path: &Path, package_json: Option<&PackageJson>) -> Option<Self> {
+        match path.extension().and_then(OsStr::to_str)? {
+            "mjs" | "mts" => Some(Self::Esm),
+            "cjs" | "cts" => Some(Self::CommonJs),
+            "js" | "jsx" | "ts" | "tsx" => match package_json?.module_type.as_deref()? {
+                "module" => Some(Self::Esm),
+                "commonjs" => Some(Self::CommonJs),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The source type of the file at `path`, [`SourceType::from_path`] except that `.cjs` files
+    /// are parsed as scripts: `import` and `export` are syntax errors and the code is not strict.
+    ///
+    /// `.cts` files are parsed as modules, TypeScript compiles their `import` and `export` to
+    /// `require()` and `module.exports`.
+    ///
+    /// # Errors
+    ///
+    /// If the extension of `path` is not a JavaScript or TypeScript one.
+    pub fn source_type(path: &Path) -> Result<SourceType, UnknownExtension> {
+        let source_type = SourceType::from_path(path)?;
+        let is_cjs = path.extension().is_some_and(|extension| extension == "cjs");
+        Ok(source_type.with_script(is_cjs))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::ModuleFormat;
+    use crate::PackageJson;
+
+    #[test]
+    fn test_infer() {
+        let module = PackageJson::parse(r#"{ "type": "module" }"#).unwrap();
+        let commonjs = PackageJson::parse(r#"{ "type": "commonjs" }"#).unwrap();
+        let untyped = PackageJson::parse("{}").unwrap();
+
+        let infer = |path: &str, package_json| ModuleFormat::infer(Path::new(path), package_json);
+        assert_eq!(infer("foo.mjs", Some(&commonjs)), Some(ModuleFormat::Esm));
+        assert_eq!(infer("foo.mts", None), Some(ModuleFormat::Esm));
+        assert_eq!(infer("foo.cjs", Some(&module)), Some(ModuleFormat::CommonJs));
+        assert_eq!(infer("foo.cts", None), Some(ModuleFormat::CommonJs));
+        assert_eq!(infer("foo.js", Some(&module)), Some(ModuleFormat::Esm));
+        assert_eq!(infer("foo.tsx", Some(&commonjs)), Some(ModuleFormat::CommonJs));
+        assert_eq!(infer("foo.js", Some(&untyped)), None);
+        assert_eq!(infer("foo.js", None), None);
+        assert_eq!(infer("foo.vue", Some(&module)), None);
+    }
+
+    #[test]
+    fn test_source_type() {
+        let source_type = |path: &str| ModuleFormat::source_type(Path::new(path)).unwrap();
+        assert!(source_type("foo.cjs").is_script());
+        assert!(source_type("foo.mjs").is_module());
+        assert!(source_type("foo.js").is_module());
+        assert!(source_type("foo.cts").is_module());
+        assert!(ModuleFormat::source_type(Path::new("foo.vue")).is_err());
+    }
+}
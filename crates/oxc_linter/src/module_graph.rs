@@ -0,0 +1,235 @@
+//! The module graph of the files linted by a [`LintService`](crate::LintService).
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use oxc_semantic::ModuleRecord;
+use oxc_syntax::module_record::{ExportExportName, ExportImportName};
+use rustc_hash::FxHashSet;
+
+/// The module records of the linted files and their dependencies, keyed by canonicalized path.
+///
+/// The graph is populated while files are linted, when the import plugin is enabled: the
+/// dependencies of a file are resolved before its rules run, so [`ModuleGraph::get`] and
+/// [`ModuleGraph::resolve_export`] are complete for the file being linted. The importers of a
+/// file are only complete once all files are linted, e.g. in [`Rule::run_on_project`].
+///
+/// [`Rule::run_on_project`]: crate::rule::Rule::run_on_project
+#[derive(Default)]
+pub struct ModuleGraph {
+    modules: DashMap<Box<Path>, ModuleState>,
+    /// The files importing or re-exporting each file
+    importers: DashMap<Box<Path>, FxHashSet<Box<Path>>>,
+}
+
+#[derive(Clone)]
+pub(crate) enum ModuleState {
+    Resolved(Arc<ModuleRecord>),
+    Ignored,
+}
+
+impl ModuleGraph {
+    /// The module record of the file at `path`, `None` if it is not part of the graph or ignored.
+    pub fn get(&self, path: &Path) -> Option<Arc<ModuleRecord>> {
+        match self.modules.get(path)?.value() {
+            ModuleState::Resolved(module_record) => Some(Arc::clone(module_record)),
+            ModuleState::Ignored => None,
+        }
+    }
+
+    /// Number of files in the graph, including ignored files.
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    pub(crate) fn contains(&self, path: &Path) -> bool {
+        self.modules.contains_key(path)
+    }
+
+    pub(crate) fn insert(&self, path: &Path, state: ModuleState) {
+        self.modules.insert(path.to_path_buf().into_boxed_path(), state);
+    }
+
+    /// Record that `module_record` imports `target` with `specifier`.
+    pub(crate) fn add_dependency(
+        &self,
+        module_record: &ModuleRecord,
+        specifier: &str,
+        target: &Arc<ModuleRecord>,
+    ) {
+        module_record.loaded_modules.insert(specifier.into(), Arc::clone(target));
+        self.importers
+            .entry(target.resolved_absolute_path.clone().into_boxed_path())
+            .or_default()
+            .insert(module_record.resolved_absolute_path.clone().into_boxed_path());
+    }
+
+    /// The modules imported or re-exported by `module_record`, which could be resolved.
+    pub fn dependencies(&self, module_record: &ModuleRecord) -> Vec<Arc<ModuleRecord>> {
+        module_record.loaded_modules.iter().map(|entry| Arc::clone(entry.value())).collect()
+    }
+
+    /// The files importing or re-exporting the file at `path`.
+    pub fn importers(&self, path: &Path) -> Vec<Box<Path>> {
+        self.importers
+            .get(path)
+            .map(|importers| importers.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// The files importing or re-exporting the file at `path`, directly or through other files.
+    pub fn dependents(&self, path: &Path) -> FxHashSet<Box<Path>> {
+        let mut dependents = FxHashSet::default();
+        let mut stack = self.importers(path);
+        while let Some(importer) = stack.pop() {
+            if importer.as_ref() != path && !dependents.contains(&importer) {
+                stack.extend(self.importers(&importer));
+                dependents.insert(importer);
+            }
+        }
+        dependents
+    }
+
+    /// Remove the file at `path` from the graph, so it is parsed again the next time it is linted.
+    /// Only the edges of the file to its dependencies are updated, the files importing it are
+    /// still recorded as its importers.
+    pub(crate) fn remove(&self, path: &Path) {
+        let Some((_, ModuleState::Resolved(module_record))) = self.modules.remove(path) else {
+            return;
+        };
+        for dependency in &module_record.loaded_modules {
+            if let Some(mut importers) =
+                self.importers.get_mut(dependency.resolved_absolute_path.as_path())
+            {
+                importers.remove(path);
+            }
+        }
+    }
+
+    /// The path of the module declaring the export `name` of `module_record`, following
+    /// `export { name } from "mod"` and `export * from "mod"`. A re-export of a module which
+    /// could not be resolved is attributed to the re-exporting module, and any export of a CommonJS
+    /// module is attributed to it.
+    /// Returns `None` when `module_record` does not export `name`.
+    pub fn resolve_export(&self, module_record: &ModuleRecord, name: &str) -> Option<PathBuf> {
+        Self::resolve_export_impl(module_record, name, &mut FxHashSet::default())
+    }
+
+    fn resolve_export_impl(
+        module_record: &ModuleRecord,
+        name: &str,
+        visited: &mut FxHashSet<(PathBuf, String)>,
+    ) -> Option<PathBuf> {
+        let path = &module_record.resolved_absolute_path;
+        // Circular re-exports
+        if !visited.insert((path.clone(), name.to_string())) {
+            return None;
+        }
+
+        // The exports of CommonJS modules are unknown
+        if module_record.not_esm || (name == "default" && module_record.export_default.is_some()) {
+            return Some(path.clone());
+        }
+
+        for entry in &module_record.indirect_export_entries {
+            let ExportExportName::Name(export_name) = &entry.export_name else { continue };
+            if export_name.name().as_str() != name {
+                continue;
+            }
+            // `export * as name from "mod"` declares `name`
+            let ExportImportName::Name(import_name) = &entry.import_name else {
+                return Some(path.clone());
+            };
+            let remote = entry
+                .module_request
+                .as_ref()
+                .and_then(|request| module_record.loaded_modules.get(request.name()));
+            return match remote {
+                Some(remote) => Self::resolve_export_impl(&remote, import_name.name(), visited),
+                None => Some(path.clone()),
+            };
+        }
+
+        if module_record.exported_bindings.contains_key(name) {
+            return Some(path.clone());
+        }
+
+        // `export *` does not re-export `default`
+        if name == "default" {
+            return None;
+        }
+        module_record.star_export_entries.iter().find_map(|entry| {
+            let request = entry.module_request.as_ref()?;
+            let remote = module_record.loaded_modules.get(request.name())?;
+            Self::resolve_export_impl(&remote, name, visited)
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::{Path, PathBuf},
+        sync::Arc,
+    };
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::{ModuleRecord, SemanticBuilder};
+    use oxc_span::SourceType;
+
+    use super::{ModuleGraph, ModuleState};
+
+    fn module_record(path: &str, source_text: &str) -> Arc<ModuleRecord> {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true);
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+        SemanticBuilder::new(source_text, source_type)
+            .build_module_record(PathBuf::from(path), &program)
+            .module_record()
+    }
+
+    #[test]
+    fn test_module_graph() {
+        let graph = ModuleGraph::default();
+        let index = module_record(
+            "index.js",
+            "export * from './a'; export { a as b } from './a'; export { c } from './missing';",
+        );
+        let a = module_record("a.js", "export const a = 1; export default a;");
+        graph.insert(Path::new("index.js"), ModuleState::Resolved(Arc::clone(&index)));
+        graph.insert(Path::new("a.js"), ModuleState::Resolved(Arc::clone(&a)));
+        graph.insert(Path::new("ignored.js"), ModuleState::Ignored);
+        graph.add_dependency(&index, "./a", &a);
+
+        assert_eq!(graph.len(), 3);
+        assert!(graph.get(Path::new("a.js")).is_some());
+        assert!(graph.get(Path::new("ignored.js")).is_none());
+        assert_eq!(graph.dependencies(&index).len(), 1);
+        assert_eq!(graph.importers(Path::new("a.js")), [Path::new("index.js").into()]);
+        assert!(graph.importers(Path::new("index.js")).is_empty());
+        assert_eq!(
+            graph.dependents(Path::new("a.js")).into_iter().collect::<Vec<_>>(),
+            [Path::new("index.js").into()]
+        );
+
+        assert_eq!(graph.resolve_export(&index, "a"), Some(PathBuf::from("a.js")));
+        assert_eq!(graph.resolve_export(&index, "b"), Some(PathBuf::from("a.js")));
+        assert_eq!(graph.resolve_export(&index, "c"), Some(PathBuf::from("index.js")));
+        assert_eq!(graph.resolve_export(&a, "default"), Some(PathBuf::from("a.js")));
+        // `export *` does not re-export `default`
+        assert_eq!(graph.resolve_export(&index, "default"), None);
+        assert_eq!(graph.resolve_export(&index, "d"), None);
+
+        graph.remove(Path::new("index.js"));
+        assert_eq!(graph.len(), 2);
+        assert!(graph.importers(Path::new("a.js")).is_empty());
+    }
+}
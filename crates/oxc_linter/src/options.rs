@@ -1,12 +1,22 @@
-use std::{convert::From, path::PathBuf};
+use std::{borrow::Cow, convert::From, path::PathBuf};
 
 use oxc_diagnostics::{Error, OxcDiagnostic, Severity};
 use rustc_hash::FxHashSet;
+use schemars::{
+    gen::SchemaGenerator,
+    schema::{Schema, SchemaObject},
+    JsonSchema,
+};
+use serde::{de, Deserialize, Deserializer};
 use serde_json::{Number, Value};
 
 use crate::{
-    config::OxlintConfig, rules::RULES, utils::is_jest_rule_adapted_to_vitest, RuleCategory,
-    RuleEnum, RuleWithSeverity,
+    config::{parse_rule_key, transform_rule_and_plugin_name, OxlintConfig},
+    rule_names::{did_you_mean, fuzzy_match},
+    rules::RULES,
+    security::is_security_rule,
+    utils::is_jest_rule_adapted_to_vitest,
+    GeneratedFiles, RuleCategory, RuleEnum, RuleWithSeverity,
 };
 
 #[derive(Debug)]
@@ -15,7 +25,38 @@ pub struct LintOptions {
     /// Defaults to [("deny", "correctness")]
     pub filter: Vec<(AllowWarnDeny, String)>,
     pub config_path: Option<PathBuf>,
+    /// Only run rules matching these rule names, plugin names or `plugin/rule` pairs,
+    /// regardless of `filter` and the configuration.
+    pub rule_filter: Vec<String>,
+    /// Only run the rules of the security preset, as errors, regardless of `filter` and the
+    /// configuration, see [`SECURITY_RULES`](crate::security::SECURITY_RULES)
+    pub security: bool,
+    /// Enable the rules of the nursery with a warning before applying `filter`, and let the
+    /// patterns of `filter` match them
+    pub nursery: bool,
     pub fix: bool,
+    /// Also apply fixes which may change the behavior of the code, requires `fix`
+    pub fix_dangerously: bool,
+    /// Do not write the fixed files, collect them for [`LintService::fixed_files`] instead
+    ///
+    /// [`LintService::fixed_files`]: crate::LintService::fixed_files
+    pub dry_run: bool,
+    /// Attach the fixes to the reported diagnostics without applying them, e.g. for `--format json`
+    pub report_fixes: bool,
+    /// Report disable comments which do not disable any problem
+    pub report_unused_directives: bool,
+    /// How the linted files which look generated or minified are linted, see [`is_generated`]
+    ///
+    /// [`is_generated`]: crate::is_generated
+    pub generated_files: GeneratedFiles,
+    /// Maximum number of problems reported by each rule in a file, the others are summed up
+    pub max_problems_per_rule: Option<usize>,
+    /// Report the problems of a rule with the same message on one line as a single diagnostic
+    /// labelling all of them, for the output formats rendering every label
+    pub collapse_same_line: bool,
+    /// Follow the help of the problems reported by rules with the rationale of the rule, the
+    /// configuration enabling it for the file and the ways to fix or suppress the problem
+    pub explain: bool,
 
     pub react_plugin: bool,
     pub unicorn_plugin: bool,
@@ -29,6 +70,7 @@ pub struct LintOptions {
     pub nextjs_plugin: bool,
     pub react_perf_plugin: bool,
     pub react_refresh_plugin: bool,
+    pub json_plugin: bool,
 }
 
 impl Default for LintOptions {
@@ -36,9 +78,21 @@ impl Default for LintOptions {
         Self {
             filter: vec![(AllowWarnDeny::Warn, String::from("correctness"))],
             config_path: None,
+            rule_filter: vec![],
+            security: false,
+            nursery: false,
             fix: false,
+            fix_dangerously: false,
+            dry_run: false,
+            report_fixes: false,
+            report_unused_directives: false,
+            generated_files: GeneratedFiles::default(),
+            max_problems_per_rule: None,
+            collapse_same_line: false,
+            explain: false,
             react_plugin: true,
-            react_refresh_plugin: true,
+            react_refresh_plugin: false,
+            json_plugin: false,
             unicorn_plugin: true,
             typescript_plugin: true,
             oxc_plugin: true,
@@ -68,12 +122,78 @@ impl LintOptions {
         self
     }
 
+    #[must_use]
+    pub fn with_rule_filter(mut self, rule_filter: Vec<String>) -> Self {
+        self.rule_filter = rule_filter;
+        self
+    }
+
+    #[must_use]
+    pub fn with_security(mut self, yes: bool) -> Self {
+        self.security = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_nursery(mut self, yes: bool) -> Self {
+        self.nursery = yes;
+        self
+    }
+
     #[must_use]
     pub fn with_fix(mut self, yes: bool) -> Self {
         self.fix = yes;
         self
     }
 
+    #[must_use]
+    pub fn with_fix_dangerously(mut self, yes: bool) -> Self {
+        self.fix_dangerously = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_dry_run(mut self, yes: bool) -> Self {
+        self.dry_run = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_fixes(mut self, yes: bool) -> Self {
+        self.report_fixes = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_report_unused_directives(mut self, yes: bool) -> Self {
+        self.report_unused_directives = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_max_problems_per_rule(mut self, max: Option<usize>) -> Self {
+        self.max_problems_per_rule = max;
+        self
+    }
+
+    #[must_use]
+    pub fn with_collapse_same_line(mut self, yes: bool) -> Self {
+        self.collapse_same_line = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_explain(mut self, yes: bool) -> Self {
+        self.explain = yes;
+        self
+    }
+
+    #[must_use]
+    pub fn with_generated_files(mut self, generated_files: GeneratedFiles) -> Self {
+        self.generated_files = generated_files;
+        self
+    }
+
     #[must_use]
     pub fn with_react_plugin(mut self, yes: bool) -> Self {
         self.react_plugin = yes;
@@ -145,6 +265,12 @@ impl LintOptions {
         self.react_refresh_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_json_plugin(mut self, yes: bool) -> Self {
+        self.json_plugin = yes;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -162,6 +288,15 @@ impl AllowWarnDeny {
     pub fn is_allow(self) -> bool {
         self == Self::Allow
     }
+
+    /// The severity as written in configuration files, e.g. `"error"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "off",
+            Self::Warn => "warn",
+            Self::Deny => "error",
+        }
+    }
 }
 
 impl TryFrom<&str> for AllowWarnDeny {
@@ -208,6 +343,36 @@ impl TryFrom<&Number> for AllowWarnDeny {
     }
 }
 
+impl<'de> Deserialize<'de> for AllowWarnDeny {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        Self::try_from(&value).map_err(de::Error::custom)
+    }
+}
+
+impl JsonSchema for AllowWarnDeny {
+    fn schema_name() -> String {
+        "AllowWarnDeny".to_owned()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        Cow::Borrowed("AllowWarnDeny")
+    }
+
+    fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
+        // type SeverityConf = 0 | 1 | 2 | "off" | "warn" | "error";
+        let enum_values = ["allow", "off", "warn", "error", "deny"]
+            .into_iter()
+            .map(Value::from)
+            .chain((0..=2).map(Value::from))
+            .collect();
+        SchemaObject { enum_values: Some(enum_values), ..SchemaObject::default() }.into()
+    }
+}
+
 impl From<AllowWarnDeny> for Severity {
     fn from(value: AllowWarnDeny) -> Self {
         match value {
@@ -225,95 +390,215 @@ impl LintOptions {
     pub fn derive_rules_and_config(&self) -> Result<(Vec<RuleWithSeverity>, OxlintConfig), Error> {
         let config =
             self.config_path.as_ref().map(|path| OxlintConfig::from_file(path)).transpose()?;
+        let rules = self.derive_rules(config.as_ref());
+        Ok((rules, config.unwrap_or_default()))
+    }
 
+    /// The rules to run, enabled by [`LintOptions::filter`] and `config`.
+    pub(crate) fn derive_rules(&self, config: Option<&OxlintConfig>) -> Vec<RuleWithSeverity> {
         let mut rules: FxHashSet<RuleWithSeverity> = FxHashSet::default();
-        let all_rules = self.get_filtered_rules();
+        let all_rules = self.get_filtered_rules(config);
 
-        for (severity, name_or_category) in &self.filter {
-            let maybe_category = RuleCategory::from(name_or_category.as_str());
+        if self.nursery {
+            for rule in all_rules.iter().filter(|rule| rule.category() == RuleCategory::Nursery) {
+                rules.insert(RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn));
+            }
+        }
+
+        // Later filters take precedence, e.g. `-A all -D no-debugger`
+        for (severity, filter) in &self.filter {
             match severity {
                 AllowWarnDeny::Deny | AllowWarnDeny::Warn => {
-                    match maybe_category {
-                        Some(category) => rules.extend(
-                            all_rules
-                                .iter()
-                                .filter(|rule| rule.category() == category)
-                                .map(|rule| RuleWithSeverity::new(rule.clone(), *severity)),
-                        ),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.extend(
-                                    all_rules
-                                        .iter()
-                                        .filter(|rule| rule.category() != RuleCategory::Nursery)
-                                        .map(|rule| RuleWithSeverity::new(rule.clone(), *severity)),
-                                );
-                            } else {
-                                rules.extend(
-                                    all_rules
-                                        .iter()
-                                        .filter(|rule| rule.name() == name_or_category)
-                                        .map(|rule| RuleWithSeverity::new(rule.clone(), *severity)),
-                                );
-                            }
-                        }
-                    };
-                }
-                AllowWarnDeny::Allow => {
-                    match maybe_category {
-                        Some(category) => rules.retain(|rule| rule.category() != category),
-                        None => {
-                            if name_or_category == "all" {
-                                rules.clear();
-                            } else {
-                                rules.retain(|rule| rule.name() != name_or_category);
-                            }
-                        }
-                    };
+                    // Naming the plugin enables its rules even when the plugin is disabled.
+                    // Patterns don't enable the rules of the nursery, which have to be named,
+                    // unless the nursery is enabled.
+                    let candidates: &[RuleEnum] =
+                        if filter.contains('/') { &RULES } else { &all_rules };
+                    let is_pattern = filter == "all" || filter.contains('*');
+                    for rule in candidates.iter().filter(|rule| {
+                        matches_filter(filter, rule)
+                            && !(is_pattern
+                                && !self.nursery
+                                && rule.category() == RuleCategory::Nursery)
+                    }) {
+                        rules.replace(RuleWithSeverity::new(rule.clone(), *severity));
+                    }
                 }
+                AllowWarnDeny::Allow => rules.retain(|rule| !matches_filter(filter, rule)),
             }
         }
 
-        if let Some(config) = &config {
+        if let Some(config) = config {
+            config.categories.override_rules(&mut rules, &all_rules);
             config.override_rules(&mut rules, &all_rules);
         }
 
-        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        let mut rules = if self.rule_filter.is_empty() && !self.security {
+            rules.into_iter().collect::<Vec<_>>()
+        } else {
+            // Run the filtered rules or the rules of the security preset even when they are
+            // turned off or their plugin is disabled, keeping the options of the ones which are
+            // configured. The rules of the security preset are errors.
+            RULES
+                .iter()
+                .filter(|rule| {
+                    (self.rule_filter.is_empty()
+                        || self.rule_filter.iter().any(|f| matches_rule_filter(f, rule)))
+                        && (!self.security || is_security_rule(rule))
+                })
+                .map(|rule| {
+                    let mut rule = rules
+                        .iter()
+                        .find(|r| r.name() == rule.name() && r.plugin_name() == rule.plugin_name())
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            RuleWithSeverity::new(rule.clone(), AllowWarnDeny::Warn)
+                        });
+                    if self.security {
+                        rule.severity = AllowWarnDeny::Deny;
+                    }
+                    rule
+                })
+                .collect::<Vec<_>>()
+        };
 
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(|rule| rule.id());
+        rules
+    }
 
-        Ok((rules, config.unwrap_or_default()))
+    /// Check that the rules, categories and plugins referenced by [`LintOptions::filter`] and
+    /// [`LintOptions::rule_filter`] exist, suggesting similar names for the unknown ones.
+    ///
+    /// # Errors
+    ///
+    /// * Returns `Err` for the first unknown name.
+    pub fn check_filters(&self) -> Result<(), OxcDiagnostic> {
+        for (severity, name_or_category) in &self.filter {
+            let name = name_or_category.as_str();
+            if RULES.iter().any(|rule| matches_filter(name, rule)) {
+                continue;
+            }
+            let flag = match severity {
+                AllowWarnDeny::Allow => "-A",
+                AllowWarnDeny::Warn => "-W",
+                AllowWarnDeny::Deny => "-D",
+            };
+            let candidates = RULES
+                .iter()
+                .map(RuleEnum::name)
+                .chain(["all", "correctness", "suspicious", "pedantic", "perf", "style"])
+                .chain(["restriction", "nursery"]);
+            return Err(unknown_filter_diagnostic(
+                format!("`{flag} {name}` does not match any rule, plugin or category."),
+                &fuzzy_match(name, candidates),
+            ));
+        }
+
+        for filter in &self.rule_filter {
+            if RULES.iter().any(|rule| matches_rule_filter(filter, rule)) {
+                continue;
+            }
+            let candidates = RULES.iter().flat_map(|rule| [rule.name(), rule.plugin_name()]);
+            return Err(unknown_filter_diagnostic(
+                format!("`--filter {filter}` does not match any rule or plugin."),
+                &fuzzy_match(filter, candidates),
+            ));
+        }
+
+        Ok(())
     }
 
-    /// Get final filtered rules by reading `self.xxx_plugin`
-    fn get_filtered_rules(&self) -> Vec<RuleEnum> {
+    /// Get final filtered rules by reading `self.xxx_plugin` and the `plugins` of `config`
+    fn get_filtered_rules(&self, config: Option<&OxlintConfig>) -> Vec<RuleEnum> {
         RULES
             .iter()
-            .filter(|rule| match rule.plugin_name() {
-                "react" => self.react_plugin,
-                "unicorn" => self.unicorn_plugin,
-                "typescript" => self.typescript_plugin,
-                "import" => self.import_plugin,
-                "jsdoc" => self.jsdoc_plugin,
-                "jest" => {
-                    if self.jest_plugin {
-                        return true;
-                    }
-                    if self.vitest_plugin && is_jest_rule_adapted_to_vitest(rule.name()) {
-                        return true;
+            .filter(|rule| {
+                config.is_some_and(|config| config.enables_plugin_of(rule))
+                    || match rule.plugin_name() {
+                        "react" => self.react_plugin,
+                        "unicorn" => self.unicorn_plugin,
+                        "typescript" => self.typescript_plugin,
+                        "import" => self.import_plugin,
+                        "jsdoc" => self.jsdoc_plugin,
+                        "jest" => {
+                            if self.jest_plugin {
+                                return true;
+                            }
+                            if self.vitest_plugin && is_jest_rule_adapted_to_vitest(rule.name()) {
+                                return true;
+                            }
+                            false
+                        }
+                        "jsx_a11y" => self.jsx_a11y_plugin,
+                        "nextjs" => self.nextjs_plugin,
+                        "react_perf" => self.react_perf_plugin,
+                        "react_refresh" => self.react_refresh_plugin,
+                        "json" => self.json_plugin,
+                        "oxc" => self.oxc_plugin,
+                        "eslint" | "tree_shaking" => true,
+                        name => panic!("Unhandled plugin: {name}"),
                     }
-                    false
-                }
-                "jsx_a11y" => self.jsx_a11y_plugin,
-                "nextjs" => self.nextjs_plugin,
-                "react_perf" => self.react_perf_plugin,
-                "react_refresh" => self.react_refresh_plugin,
-                "oxc" => self.oxc_plugin,
-                "eslint" | "tree_shaking" => true,
-                name => panic!("Unhandled plugin: {name}"),
             })
             .cloned()
             .collect::<Vec<_>>()
     }
 }
+
+fn unknown_filter_diagnostic(message: String, suggestions: &[&str]) -> OxcDiagnostic {
+    let diagnostic = OxcDiagnostic::error(message);
+    match did_you_mean(suggestions) {
+        Some(help) => diagnostic.with_help(help),
+        None => diagnostic,
+    }
+}
+
+/// Whether the `-A`, `-W` or `-D` argument `filter` selects `rule`. The argument is `all`, a
+/// category, a rule name, or `plugin/name`, where `name` is a rule name or a category of the
+/// plugin. Rule names can contain `*` wildcards, e.g. `unicorn/*` or `react/jsx-no-*`.
+pub(crate) fn matches_filter(filter: &str, rule: &RuleEnum) -> bool {
+    if filter == "all" {
+        return true;
+    }
+    let rule_name = if filter.contains('/') {
+        let (plugin_name, rule_name) = parse_rule_key(filter);
+        let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+        if rule.plugin_name() != plugin_name {
+            return false;
+        }
+        rule_name.to_string()
+    } else {
+        filter.to_string()
+    };
+    match RuleCategory::from(&rule_name) {
+        Some(category) => rule.category() == category,
+        None if rule_name.contains('*') => matches_wildcard(&rule_name, rule.name()),
+        None => rule.name() == rule_name,
+    }
+}
+
+/// Whether `name` matches `pattern`, in which `*` matches any sequence of characters.
+fn matches_wildcard(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = name.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts = parts.collect::<Vec<_>>();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        let Some(index) = rest.find(part) else { return false };
+        rest = &rest[index + part.len()..];
+    }
+    rest.ends_with(last)
+}
+
+pub(crate) fn matches_rule_filter(filter: &str, rule: &RuleEnum) -> bool {
+    match filter.split_once('/') {
+        Some((plugin_name, rule_name)) => {
+            rule.plugin_name() == plugin_name && rule.name() == rule_name
+        }
+        None => rule.name() == filter || rule.plugin_name() == filter,
+    }
+}
@@ -0,0 +1,180 @@
+//! The nearest `package.json` of the linted files, see [`LintContext::package_json`].
+//!
+//! [`LintContext::package_json`]: crate::LintContext::package_json
+
+use std::{
+    env, fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use rustc_hash::FxHashMap;
+use serde::Deserialize;
+
+/// The fields of a `package.json` which rules look at.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PackageJson {
+    /// The path of the file
+    #[serde(skip)]
+    pub path: PathBuf,
+    pub name: Option<String>,
+    /// `"module"` or `"commonjs"`, how the `.js` files of the package are loaded
+    #[serde(rename = "type")]
+    pub module_type: Option<String>,
+    pub dependencies: FxHashMap<String, String>,
+    pub dev_dependencies: FxHashMap<String, String>,
+    pub peer_dependencies: FxHashMap<String, String>,
+    pub optional_dependencies: FxHashMap<String, String>,
+    /// The versions of the runtimes the package supports, e.g. `"node": ">=18"`
+    pub engines: FxHashMap<String, String>,
+}
+
+impl PackageJson {
+    /// Parse the `package.json` at `path`, `None` when it cannot be read or is not valid.
+    pub fn read(path: &Path) -> Option<Self> {
+        let json = fs::read_to_string(path).ok()?;
+        let mut package_json = Self::parse(&json)?;
+        package_json.path = path.to_path_buf();
+        Some(package_json)
+    }
+
+    /// Parse the content of a `package.json`, `None` when it is not valid.
+    pub fn parse(json: &str) -> Option<Self> {
+        serde_json::from_str(json).ok()
+    }
+
+    /// Whether the `.js` files of the package are ES modules, `"type": "module"`.
+    pub fn is_module(&self) -> bool {
+        self.module_type.as_deref() == Some("module")
+    }
+
+    /// Whether `name` is declared in any kind of dependencies.
+    pub fn has_dependency(&self, name: &str) -> bool {
+        [
+            &self.dependencies,
+            &self.dev_dependencies,
+            &self.peer_dependencies,
+            &self.optional_dependencies,
+        ]
+        .into_iter()
+        .any(|dependencies| dependencies.contains_key(name))
+    }
+
+    /// The range of versions of Node.js in `engines`, e.g. `>=18`.
+    pub fn engines_node(&self) -> Option<&str> {
+        self.engines.get("node").map(String::as_str)
+    }
+
+    /// Hash the fields rules look at, in the same order whatever the order of the maps, for the
+    /// key of the files of the package in the [`LintCache`](crate::LintCache).
+    pub(crate) fn hash_fields<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.module_type.hash(state);
+        for map in [
+            &self.dependencies,
+            &self.dev_dependencies,
+            &self.peer_dependencies,
+            &self.optional_dependencies,
+            &self.engines,
+        ] {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_unstable();
+            entries.hash(state);
+        }
+    }
+}
+
+/// Cache of the nearest `package.json` of the directories containing linted files.
+#[derive(Default)]
+pub(crate) struct PackageJsons {
+    /// The nearest `package.json` of each directory, if any
+    nearest: DashMap<PathBuf, Option<Arc<PackageJson>>>,
+    /// Each `package.json` read, `None` when it is not valid
+    parsed: DashMap<PathBuf, Option<Arc<PackageJson>>>,
+}
+
+impl PackageJsons {
+    /// The `package.json` in the directory of the file at `path` or the nearest of its ancestors.
+    /// Invalid files are skipped.
+    pub fn find(&self, path: &Path) -> Option<Arc<PackageJson>> {
+        let dir = path.parent()?;
+        // Relative paths are relative to the current directory, whose ancestors are searched too
+        let dir =
+            if dir.is_absolute() { dir.to_path_buf() } else { env::current_dir().ok()?.join(dir) };
+        self.nearest
+            .entry(dir.clone())
+            .or_insert_with(|| {
+                dir.ancestors()
+                    .map(|dir| dir.join("package.json"))
+                    .filter(|path| path.is_file())
+                    .find_map(|path| {
+                        self.parsed
+                            .entry(path.clone())
+                            .or_insert_with(|| PackageJson::read(&path).map(Arc::new))
+                            .clone()
+                    })
+            })
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{PackageJson, PackageJsons};
+
+    #[test]
+    fn test_parse() {
+        let package_json = PackageJson::parse(
+            r#"{
+                "name": "app",
+                "type": "module",
+                "dependencies": { "react": "^18.0.0" },
+                "devDependencies": { "vitest": "*" },
+                "engines": { "node": ">=18" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(package_json.name.as_deref(), Some("app"));
+        assert!(package_json.is_module());
+        assert!(package_json.has_dependency("react"));
+        assert!(package_json.has_dependency("vitest"));
+        assert!(!package_json.has_dependency("vue"));
+        assert_eq!(package_json.engines_node(), Some(">=18"));
+
+        let package_json = PackageJson::parse("{}").unwrap();
+        assert!(!package_json.is_module());
+        assert_eq!(package_json.engines_node(), None);
+        assert!(PackageJson::parse("{ invalid").is_none());
+    }
+
+    #[test]
+    fn test_find_nearest() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("packages/a/src")).unwrap();
+        fs::create_dir_all(root.join("packages/b/src")).unwrap();
+        fs::create_dir_all(root.join("packages/c")).unwrap();
+        fs::write(root.join("package.json"), r#"{ "name": "root" }"#).unwrap();
+        fs::write(root.join("packages/a/package.json"), r#"{ "name": "a" }"#).unwrap();
+        fs::write(root.join("packages/c/package.json"), "{ invalid").unwrap();
+
+        let package_jsons = PackageJsons::default();
+        let name = |path: &str| {
+            let package_json = package_jsons.find(&root.join(path)).unwrap();
+            package_json.name.clone().unwrap()
+        };
+        assert_eq!(name("packages/a/src/index.js"), "a");
+        assert_eq!(name("packages/a/index.js"), "a");
+        assert_eq!(name("packages/b/src/index.js"), "root");
+        assert_eq!(name("packages/c/index.js"), "root");
+        assert_eq!(
+            package_jsons.find(&root.join("packages/a/src/index.js")).unwrap().path,
+            root.join("packages/a/package.json")
+        );
+    }
+}
@@ -41,8 +41,8 @@ impl<'a> AstroPartialLoader<'a> {
             return None;
         };
 
-        let js_code =
-            Span::new(start + ASTRO_SPLIT.len() as u32, end).source_text(self.source_text);
+        let start = start + ASTRO_SPLIT.len() as u32;
+        let js_code = Span::new(start, end).source_text(self.source_text);
         Some(JavaScriptSource::new(
             js_code,
             SourceType::default().with_typescript(true).with_module(true),
@@ -140,6 +140,7 @@ mod test {
             sources[0].source_text.trim(),
             "const { message = 'Welcome, world!' } = Astro.props;"
         );
+        assert!(source_text[sources[0].start..].starts_with(sources[0].source_text));
         assert_eq!(sources[1].source_text.trim(), r#"console.log("Hi");"#);
     }
 
@@ -0,0 +1,131 @@
+use memchr::memmem::Finder;
+use oxc_span::SourceType;
+
+use super::{
+    find_script_closing_angle, find_script_start, script_attribute, JavaScriptSource, SCRIPT_END,
+};
+
+/// The values of the `type` attribute of the `<script>` tags containing classic scripts.
+/// <https://html.spec.whatwg.org/multipage/scripting.html#javascript-mime-type>
+const JAVASCRIPT_TYPES: &[&str] = &[
+    "",
+    "text/javascript",
+    "application/javascript",
+    "application/x-javascript",
+    "text/ecmascript",
+    "application/ecmascript",
+];
+
+pub struct HtmlPartialLoader<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> HtmlPartialLoader<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// The inline `<script>` blocks of the page, classic scripts and `type="module"` scripts.
+    /// Scripts with a `src`, and data blocks such as `type="application/json"` or
+    /// `type="importmap"`, are skipped.
+    ///
+    /// The classic scripts share the global scope of the page, but each block is linted on its
+    /// own.
+    pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
+        let mut results = vec![];
+        let mut pointer = 0;
+        while let Some((attributes, js_start, js_end)) = self.parse_script(&mut pointer) {
+            if let Some(source_type) = Self::source_type(attributes) {
+                let source_text = &self.source_text[js_start..js_end];
+                results.push(JavaScriptSource::new(source_text, source_type, js_start));
+            }
+        }
+        results
+    }
+
+    /// Find the next `<script>` block, returning its attributes and the offsets of its content.
+    fn parse_script(&self, pointer: &mut usize) -> Option<(&'a str, usize, usize)> {
+        let script_end_finder = Finder::new(SCRIPT_END);
+
+        // find opening "<script"
+        *pointer = find_script_start(self.source_text, *pointer)?;
+
+        // find closing ">"
+        let offset = find_script_closing_angle(self.source_text, *pointer)?;
+        let attributes = &self.source_text[*pointer..*pointer + offset];
+
+        *pointer += offset + 1;
+        let js_start = *pointer;
+
+        // find "</script>"
+        let offset = script_end_finder.find(&self.source_text.as_bytes()[*pointer..])?;
+        let js_end = *pointer + offset;
+        *pointer += offset + SCRIPT_END.len();
+
+        Some((attributes, js_start, js_end))
+    }
+
+    /// The source type of an inline script, `None` when it is not JavaScript.
+    fn source_type(attributes: &str) -> Option<SourceType> {
+        if script_attribute(attributes, "src").is_some() {
+            return None;
+        }
+        let script_type = script_attribute(attributes, "type").unwrap_or_default();
+        if script_type.eq_ignore_ascii_case("module") {
+            Some(SourceType::default().with_module(true))
+        } else if JAVASCRIPT_TYPES.iter().any(|t| script_type.eq_ignore_ascii_case(t)) {
+            Some(SourceType::default().with_script(true))
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{HtmlPartialLoader, JavaScriptSource};
+
+    fn parse_html(source_text: &str) -> Vec<JavaScriptSource<'_>> {
+        HtmlPartialLoader::new(source_text).parse()
+    }
+
+    #[test]
+    fn test_parse_html() {
+        let source_text = r#"
+        <!DOCTYPE html>
+        <html>
+          <head>
+            <!-- <script>commented</script> -->
+            <script src="app.js"></script>
+            <script>
+              var a = 1;
+            </script>
+            <script type="module">
+              import b from "./b.js";
+            </script>
+            <script type="application/json">{ "c": 1 }</script>
+            <script type="importmap">{ "imports": {} }</script>
+          </head>
+          <body>
+            <script type="TEXT/JAVASCRIPT" defer>console.log(a)</script>
+          </body>
+        </html>
+        "#;
+
+        let sources = parse_html(source_text);
+        assert_eq!(sources.len(), 3);
+        assert_eq!(sources[0].source_text.trim(), "var a = 1;");
+        assert!(sources[0].source_type.is_script());
+        assert_eq!(sources[1].source_text.trim(), r#"import b from "./b.js";"#);
+        assert!(sources[1].source_type.is_module());
+        assert_eq!(sources[2].source_text, "console.log(a)");
+        assert!(sources[2].source_type.is_script());
+        assert!(source_text[sources[2].start..].starts_with(sources[2].source_text));
+    }
+
+    #[test]
+    fn test_no_script() {
+        assert!(parse_html("<p>Hello</p>").is_empty());
+        assert!(parse_html("<script>console.log('unclosed')").is_empty());
+    }
+}
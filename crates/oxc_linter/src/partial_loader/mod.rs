@@ -1,15 +1,30 @@
 mod astro;
+mod html;
+mod notebook;
 mod svelte;
 mod vue;
 
+use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-pub use self::{astro::AstroPartialLoader, svelte::SveltePartialLoader, vue::VuePartialLoader};
+pub use self::{
+    astro::AstroPartialLoader,
+    html::HtmlPartialLoader,
+    notebook::{Notebook, NotebookCell},
+    svelte::SveltePartialLoader,
+    vue::VuePartialLoader,
+};
 
 const SCRIPT_START: &str = "<script";
 const SCRIPT_END: &str = "</script>";
+const COMMENT_START: &str = "<!--";
+const COMMENT_END: &str = "-->";
 
-pub const LINT_PARTIAL_LOADER_EXT: &[&str] = &["vue", "astro", "svelte"];
+pub const LINT_PARTIAL_LOADER_EXT: &[&str] =
+    &["vue", "astro", "svelte", "html", "htm", NOTEBOOK_EXT];
+
+/// Extension of Jupyter notebooks, which are linted as a whole, see [`Notebook`].
+pub const NOTEBOOK_EXT: &str = "ipynb";
 
 #[derive(Debug, Clone, Copy)]
 pub struct JavaScriptSource<'a> {
@@ -36,11 +51,62 @@ impl PartialLoader {
             "vue" => Some(VuePartialLoader::new(source_text).parse()),
             "astro" => Some(AstroPartialLoader::new(source_text).parse()),
             "svelte" => Some(SveltePartialLoader::new(source_text).parse()),
+            "html" | "htm" => Some(HtmlPartialLoader::new(source_text).parse()),
             _ => None,
         }
     }
 }
 
+/// Find the next `<script` tag from `pointer`, skipping HTML comments and tags such as `<scripts>`.
+/// Returns the offset of the end of `<script`.
+fn find_script_start(source_text: &str, mut pointer: usize) -> Option<usize> {
+    let script_start_finder = Finder::new(SCRIPT_START);
+    let comment_start_finder = Finder::new(COMMENT_START);
+    let comment_end_finder = Finder::new(COMMENT_END);
+    loop {
+        let rest = &source_text.as_bytes()[pointer..];
+        let script = script_start_finder.find(rest)?;
+        if let Some(comment) = comment_start_finder.find(rest).filter(|comment| *comment < script) {
+            pointer += comment + COMMENT_START.len();
+            pointer +=
+                comment_end_finder.find(&source_text.as_bytes()[pointer..])? + COMMENT_END.len();
+            continue;
+        }
+        pointer += script + SCRIPT_START.len();
+        match source_text[pointer..].chars().next() {
+            Some(c) if c == '>' || c.is_ascii_whitespace() => return Some(pointer),
+            _ => {}
+        }
+    }
+}
+
+/// The value of the `lang` attribute of a `<script>` tag, e.g. `ts` for `<script lang="ts" setup>`.
+fn script_lang(attributes: &str) -> Option<&str> {
+    script_attribute(attributes, "lang")
+}
+
+/// The value of the attribute `name` of a `<script>` tag, `Some("")` for an attribute without
+/// value such as `<script async>`.
+fn script_attribute<'a>(attributes: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = attributes;
+    while let Some(index) = rest.find(name) {
+        let is_attribute_name = rest[..index].chars().next_back().map_or(true, char::is_whitespace);
+        rest = &rest[index + name.len()..];
+        let is_attribute_end =
+            rest.chars().next().map_or(true, |c| c.is_whitespace() || c == '=' || c == '/');
+        if !is_attribute_name || !is_attribute_end {
+            continue;
+        }
+        let Some(value) = rest.trim_start().strip_prefix('=') else { return Some("") };
+        let value = value.trim_start();
+        return match value.chars().next()? {
+            quote @ ('"' | '\'') => value[1..].split(quote).next(),
+            _ => value.split(|c: char| c.is_whitespace() || c == '/').next(),
+        };
+    }
+    None
+}
+
 /// Find closing angle for situations where there is another `>` in between.
 /// e.g. `<script generic="T extends Record<string, string>">`
 fn find_script_closing_angle(source_text: &str, pointer: usize) -> Option<usize> {
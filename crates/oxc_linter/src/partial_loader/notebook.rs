@@ -0,0 +1,146 @@
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_span::{SourceType, Span};
+use serde_json::Value;
+
+/// A Jupyter notebook (`.ipynb`) with a JavaScript or TypeScript kernel, e.g. Deno or tslab.
+///
+/// Code cells share their top-level scope, so they are joined into a single source and linted
+/// together. Diagnostics are mapped back to their cell with [`Notebook::cell_at`] and
+/// [`Notebook::map_diagnostic`].
+#[derive(Debug)]
+pub struct Notebook {
+    /// All code cells, each followed by a newline
+    pub source_text: String,
+    pub source_type: SourceType,
+    pub cells: Vec<NotebookCell>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotebookCell {
+    /// Position of the cell in the notebook, counting all cells from 1 as notebook editors do
+    pub number: usize,
+    /// Span of the cell in [`Notebook::source_text`], excluding the newline separating cells
+    pub span: Span,
+}
+
+impl Notebook {
+    /// Extract the code cells of a notebook.
+    /// Returns `None` for invalid notebooks and notebooks of other languages, e.g. Python.
+    pub fn parse(source_text: &str) -> Option<Self> {
+        let notebook: Value = serde_json::from_str(source_text).ok()?;
+        let source_type = match Self::language(&notebook)? {
+            "javascript" => SourceType::default().with_module(true),
+            "typescript" => SourceType::default().with_module(true).with_typescript(true),
+            _ => return None,
+        };
+
+        let mut text = String::new();
+        let mut cells = vec![];
+        for (index, cell) in notebook.get("cells")?.as_array()?.iter().enumerate() {
+            if cell.get("cell_type").and_then(Value::as_str) != Some("code") {
+                continue;
+            }
+            let start = u32::try_from(text.len()).ok()?;
+            match cell.get("source") {
+                // The source is usually split into lines, each ending with a newline
+                Some(Value::Array(lines)) => {
+                    lines.iter().filter_map(Value::as_str).for_each(|line| text.push_str(line));
+                }
+                Some(Value::String(source)) => text.push_str(source),
+                _ => {}
+            }
+            let end = u32::try_from(text.len()).ok()?;
+            text.push('\n');
+            cells.push(NotebookCell { number: index + 1, span: Span::new(start, end) });
+        }
+
+        Some(Self { source_text: text, source_type, cells })
+    }
+
+    fn language(notebook: &Value) -> Option<&str> {
+        let metadata = notebook.get("metadata")?;
+        metadata
+            .get("kernelspec")
+            .and_then(|kernelspec| kernelspec.get("language"))
+            .or_else(|| metadata.get("language_info").and_then(|info| info.get("name")))
+            .and_then(Value::as_str)
+    }
+
+    /// The cell containing `offset` of [`Notebook::source_text`].
+    pub fn cell_at(&self, offset: u32) -> Option<&NotebookCell> {
+        let index = self.cells.partition_point(|cell| cell.span.end < offset);
+        self.cells.get(index).filter(|cell| cell.span.start <= offset)
+    }
+
+    /// Make the labels of a diagnostic reported in `cell` relative to the source text of the cell.
+    pub fn map_diagnostic(cell: &NotebookCell, diagnostic: OxcDiagnostic) -> OxcDiagnostic {
+        let Some(labels) = &diagnostic.labels else { return diagnostic };
+        let start = cell.span.start as usize;
+        let end = cell.span.end as usize;
+        let labels = labels
+            .iter()
+            .map(|label| {
+                let offset = label.offset().clamp(start, end);
+                let len = label.len().min(end - offset);
+                LabeledSpan::new(label.label().map(ToString::to_string), offset - start, len)
+            })
+            .collect::<Vec<_>>();
+        diagnostic.with_labels(labels)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::OxcDiagnostic;
+    use oxc_span::Span;
+    use serde_json::json;
+
+    use super::{Notebook, NotebookCell};
+
+    fn notebook_json(language: &str) -> String {
+        json!({
+            "cells": [
+                { "cell_type": "code", "source": ["const a = 1;\n", "a;"] },
+                { "cell_type": "markdown", "source": ["# Title"] },
+                { "cell_type": "code", "source": "debugger;" },
+            ],
+            "metadata": { "kernelspec": { "language": language, "name": "deno" } },
+            "nbformat": 4,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_parse() {
+        let notebook = Notebook::parse(&notebook_json("typescript")).unwrap();
+        assert_eq!(notebook.source_text, "const a = 1;\na;\ndebugger;\n");
+        assert!(notebook.source_type.is_typescript());
+        assert_eq!(
+            notebook.cells,
+            [
+                NotebookCell { number: 1, span: Span::new(0, 15) },
+                NotebookCell { number: 3, span: Span::new(16, 25) },
+            ]
+        );
+        assert!(!Notebook::parse(&notebook_json("javascript"))
+            .unwrap()
+            .source_type
+            .is_typescript());
+        assert!(Notebook::parse(&notebook_json("python")).is_none());
+        assert!(Notebook::parse("{").is_none());
+    }
+
+    #[test]
+    fn test_map_diagnostic() {
+        let notebook = Notebook::parse(&notebook_json("javascript")).unwrap();
+        assert_eq!(notebook.cell_at(0).unwrap().number, 1);
+        assert_eq!(notebook.cell_at(15).unwrap().number, 1);
+        assert_eq!(notebook.cell_at(16).unwrap().number, 3);
+        assert!(notebook.cell_at(26).is_none());
+
+        let diagnostic = OxcDiagnostic::warn("debugger").with_label(Span::new(16, 24));
+        let diagnostic = Notebook::map_diagnostic(notebook.cell_at(16).unwrap(), diagnostic);
+        let label = &diagnostic.labels.as_ref().unwrap()[0];
+        assert_eq!((label.offset(), label.len()), (0, 8));
+    }
+}
@@ -1,7 +1,9 @@
 use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-use super::{find_script_closing_angle, JavaScriptSource, SCRIPT_END, SCRIPT_START};
+use super::{
+    find_script_closing_angle, find_script_start, script_lang, JavaScriptSource, SCRIPT_END,
+};
 
 pub struct SveltePartialLoader<'a> {
     source_text: &'a str,
@@ -13,32 +15,43 @@ impl<'a> SveltePartialLoader<'a> {
     }
 
     pub fn parse(self) -> Vec<JavaScriptSource<'a>> {
-        self.parse_script().map_or_else(Vec::new, |source| vec![source])
+        self.parse_scripts()
     }
 
-    fn parse_script(&self) -> Option<JavaScriptSource<'a>> {
-        let script_start_finder = Finder::new(SCRIPT_START);
-        let script_end_finder = Finder::new(SCRIPT_END);
-
+    /// Each *.svelte file can contain at most one instance `<script>` block and one
+    /// `<script context="module">` block (`<script module>` in Svelte 5).
+    /// <https://svelte.dev/docs/svelte-components>
+    fn parse_scripts(&self) -> Vec<JavaScriptSource<'a>> {
         let mut pointer = 0;
+        let Some(result1) = self.parse_script(&mut pointer) else {
+            return vec![];
+        };
+        let Some(result2) = self.parse_script(&mut pointer) else {
+            return vec![result1];
+        };
+        vec![result1, result2]
+    }
+
+    fn parse_script(&self, pointer: &mut usize) -> Option<JavaScriptSource<'a>> {
+        let script_end_finder = Finder::new(SCRIPT_END);
 
         // find opening "<script"
-        let offset = script_start_finder.find(self.source_text[pointer..].as_bytes())?;
-        pointer += offset + SCRIPT_START.len();
+        *pointer = find_script_start(self.source_text, *pointer)?;
 
         // find closing ">"
-        let offset = find_script_closing_angle(self.source_text, pointer)?;
+        let offset = find_script_closing_angle(self.source_text, *pointer)?;
 
         // get lang="ts" attribute
-        let content = &self.source_text[pointer..pointer + offset];
-        let is_ts = content.contains("ts");
+        let lang = script_lang(&self.source_text[*pointer..*pointer + offset]);
+        let is_ts = lang == Some("ts");
 
-        pointer += offset + 1;
-        let js_start = pointer;
+        *pointer += offset + 1;
+        let js_start = *pointer;
 
         // find "</script>"
-        let offset = script_end_finder.find(self.source_text[pointer..].as_bytes())?;
-        let js_end = pointer + offset;
+        let offset = script_end_finder.find(&self.source_text.as_bytes()[*pointer..])?;
+        let js_end = *pointer + offset;
+        *pointer += offset + SCRIPT_END.len();
 
         let source_text = &self.source_text[js_start..js_end];
         let source_type = SourceType::default().with_module(true).with_typescript(is_ts);
@@ -80,4 +93,25 @@ mod test {
         let result = parse_svelte(source_text);
         assert_eq!(result.source_text.trim(), r#"console.log("hi");"#);
     }
+
+    #[test]
+    fn test_parse_svelte_module_context() {
+        let source_text = r#"
+        <!-- <script>commented</script> -->
+        <script context="module" lang="ts">
+          export const prerender = true;
+        </script>
+        <script lang="ts">
+          $: doubled = count * 2;
+        </script>
+        <h1>{doubled}</h1>
+        "#;
+
+        let sources = SveltePartialLoader::new(source_text).parse();
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0].source_text.trim(), "export const prerender = true;");
+        assert_eq!(sources[1].source_text.trim(), "$: doubled = count * 2;");
+        assert!(sources.iter().all(|source| source.source_type.is_typescript()));
+        assert!(source_text[sources[1].start..].starts_with(sources[1].source_text));
+    }
 }
@@ -1,7 +1,9 @@
 use memchr::memmem::Finder;
 use oxc_span::SourceType;
 
-use super::{find_script_closing_angle, JavaScriptSource, SCRIPT_END, SCRIPT_START};
+use super::{
+    find_script_closing_angle, find_script_start, script_lang, JavaScriptSource, SCRIPT_END,
+};
 
 pub struct VuePartialLoader<'a> {
     source_text: &'a str,
@@ -32,26 +34,24 @@ impl<'a> VuePartialLoader<'a> {
     }
 
     fn parse_script(&self, pointer: &mut usize) -> Option<JavaScriptSource<'a>> {
-        let script_start_finder = Finder::new(SCRIPT_START);
         let script_end_finder = Finder::new(SCRIPT_END);
 
         // find opening "<script"
-        let offset = script_start_finder.find(self.source_text[*pointer..].as_bytes())?;
-        *pointer += offset + SCRIPT_START.len();
+        *pointer = find_script_start(self.source_text, *pointer)?;
 
         // find closing ">"
         let offset = find_script_closing_angle(self.source_text, *pointer)?;
 
         // get ts and jsx attribute
-        let content = &self.source_text[*pointer..*pointer + offset];
-        let is_ts = content.contains("ts");
-        let is_jsx = content.contains("tsx") || content.contains("jsx");
+        let lang = script_lang(&self.source_text[*pointer..*pointer + offset]);
+        let is_ts = matches!(lang, Some("ts" | "tsx"));
+        let is_jsx = matches!(lang, Some("tsx" | "jsx"));
 
         *pointer += offset + 1;
         let js_start = *pointer;
 
         // find "</script>"
-        let offset = script_end_finder.find(self.source_text[*pointer..].as_bytes())?;
+        let offset = script_end_finder.find(&self.source_text.as_bytes()[*pointer..])?;
         let js_end = *pointer + offset;
         *pointer += offset + SCRIPT_END.len();
 
@@ -235,4 +235,29 @@ mod test {
             .trim()
         );
     }
+
+    #[test]
+    fn test_script_in_comment() {
+        let source_text = r"
+        <!-- <script>a</script> -->
+        <template><scripts>b</scripts></template>
+        <script>c</script>
+        ";
+        let sources = VuePartialLoader::new(source_text).parse();
+        assert_eq!(sources.len(), 1);
+        assert_eq!(sources[0].source_text, "c");
+        assert_eq!(&source_text[sources[0].start..], "c</script>\n        ");
+    }
+
+    #[test]
+    fn test_lang_attribute() {
+        let source_text = r#"
+        <script setup generic="T extends Attrs">a</script>
+        <script lang="jsx">b</script>
+        "#;
+        let sources = VuePartialLoader::new(source_text).parse();
+        assert!(!sources[0].source_type.is_typescript());
+        assert!(!sources[1].source_type.is_typescript());
+        assert!(sources[1].source_type.is_jsx());
+    }
 }
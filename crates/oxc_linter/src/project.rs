@@ -0,0 +1,98 @@
+use std::{
+    cell::RefCell,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_syntax::module_record::ModuleRecord;
+
+use crate::ModuleGraph;
+
+/// Data collected from a linted file for [`Rule::run_on_project`](crate::rule::Rule::run_on_project).
+#[derive(Debug, Clone)]
+pub struct ProjectFile {
+    pub path: Box<Path>,
+    pub module_record: Arc<ModuleRecord>,
+}
+
+impl ProjectFile {
+    pub fn new(path: &Path, module_record: Arc<ModuleRecord>) -> Self {
+        Self { path: path.to_path_buf().into_boxed_path(), module_record }
+    }
+}
+
+/// Context of [`Rule::run_on_project`](crate::rule::Rule::run_on_project),
+/// giving access to the data collected from all linted files.
+pub struct ProjectContext {
+    /// Sorted by path. Files with syntax errors are not included.
+    files: Vec<ProjectFile>,
+
+    module_graph: Arc<ModuleGraph>,
+
+    diagnostics: RefCell<Vec<(Box<Path>, OxcDiagnostic)>>,
+}
+
+impl ProjectContext {
+    pub fn new(mut files: Vec<ProjectFile>) -> Self {
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Self { files, module_graph: Arc::default(), diagnostics: RefCell::new(vec![]) }
+    }
+
+    #[must_use]
+    pub fn with_module_graph(mut self, module_graph: &Arc<ModuleGraph>) -> Self {
+        self.module_graph = Arc::clone(module_graph);
+        self
+    }
+
+    /// All linted files, sorted by path.
+    pub fn files(&self) -> &[ProjectFile] {
+        &self.files
+    }
+
+    /// The linted file at `path`.
+    pub fn file(&self, path: &Path) -> Option<&ProjectFile> {
+        let index = self.files.binary_search_by(|file| (*file.path).cmp(path)).ok()?;
+        Some(&self.files[index])
+    }
+
+    /// The modules of the project, populated when the import plugin is enabled.
+    pub fn module_graph(&self) -> &ModuleGraph {
+        &self.module_graph
+    }
+
+    /// Report a problem in the file at `path`. Labels refer to the source text of that file.
+    pub fn diagnostic(&self, path: &Path, diagnostic: OxcDiagnostic) {
+        self.diagnostics.borrow_mut().push((PathBuf::from(path).into_boxed_path(), diagnostic));
+    }
+
+    pub(crate) fn take_diagnostics(&self) -> Vec<(Box<Path>, OxcDiagnostic)> {
+        self.diagnostics.take()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use oxc_diagnostics::OxcDiagnostic;
+
+    use super::{ProjectContext, ProjectFile};
+
+    #[test]
+    fn test_project_context() {
+        let file = |path: &str| ProjectFile::new(Path::new(path), Arc::default());
+        let ctx = ProjectContext::new(vec![file("src/b.js"), file("src/a.js"), file("index.js")]);
+
+        let paths = ctx.files().iter().map(|file| &*file.path).collect::<Vec<_>>();
+        assert_eq!(paths, [Path::new("index.js"), Path::new("src/a.js"), Path::new("src/b.js")]);
+        assert!(ctx.file(Path::new("src/a.js")).is_some());
+        assert!(ctx.file(Path::new("src/c.js")).is_none());
+
+        ctx.diagnostic(Path::new("src/a.js"), OxcDiagnostic::warn("unused export"));
+        let diagnostics = ctx.take_diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(&*diagnostics[0].0, Path::new("src/a.js"));
+        assert!(ctx.take_diagnostics().is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+//! Reduction of the messages of a file before they are reported, so that a pathological file,
+//! e.g. a bundle which is linted by accident, does not produce megabytes of output.
+
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, RelatedLabel, Severity};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::Message;
+
+/// The diagnostics to report for the `messages` of `source_text`:
+///
+/// * identical messages of a rule at the same span are reported once
+/// * with `collapse_same_line`, messages of a rule with the same text on the same line are
+///   reported as one diagnostic labelling all of them, unless they have a fix
+/// * at most `max_per_rule` messages of each rule are reported, followed by an advice with the
+///   number of messages which are left out, which is not counted as a warning or an error
+pub(crate) fn reduce_messages(
+    messages: Vec<Message<'_>>,
+    source_text: &str,
+    max_per_rule: Option<usize>,
+    collapse_same_line: bool,
+) -> Vec<OxcDiagnostic> {
+    if messages.len() < 2 {
+        return messages.into_iter().map(Message::into_diagnostic).collect();
+    }
+
+    let line_starts = source_text
+        .bytes()
+        .enumerate()
+        .filter(|(_, byte)| *byte == b'\n')
+        .map(|(i, _)| i + 1)
+        .collect::<Vec<_>>();
+    let line_of = |offset: u32| line_starts.partition_point(|start| *start <= offset as usize);
+
+    let mut reported = Vec::<Message<'_>>::with_capacity(messages.len());
+    let mut identical = FxHashSet::default();
+    let mut same_line = FxHashMap::default();
+    let mut collapsed_labels = FxHashMap::<usize, Vec<LabeledSpan>>::default();
+    let mut collapsed_related = FxHashMap::<usize, Vec<RelatedLabel>>::default();
+    let mut counts = FxHashMap::<_, usize>::default();
+    let mut left_out = Vec::<(&str, &str, usize)>::new();
+
+    for message in messages {
+        let (Some(plugin_name), Some(rule_name)) = (message.plugin_name(), message.rule_name())
+        else {
+            reported.push(message);
+            continue;
+        };
+        let text = message.error.message.clone();
+        if !identical.insert((plugin_name, rule_name, message.start, message.end, text.clone())) {
+            continue;
+        }
+        if collapse_same_line && message.fix.is_none() {
+            let key = (plugin_name, rule_name, line_of(message.start), text);
+            if let Some(&index) = same_line.get(&key) {
+                let labels = message.error.labels.iter().flatten().cloned();
+                collapsed_labels.entry(index).or_default().extend(labels);
+                let related = message.error.related.iter().cloned();
+                collapsed_related.entry(index).or_default().extend(related);
+                continue;
+            }
+            same_line.insert(key, reported.len());
+        }
+
+        let count = counts.entry((plugin_name, rule_name)).or_default();
+        *count += 1;
+        if max_per_rule.is_some_and(|max| *count > max) {
+            match left_out.iter_mut().find(|(p, r, ..)| (*p, *r) == (plugin_name, rule_name)) {
+                Some((.., count)) => *count += 1,
+                None => left_out.push((plugin_name, rule_name, 1)),
+            }
+            continue;
+        }
+        reported.push(message);
+    }
+
+    let mut diagnostics = reported
+        .into_iter()
+        .enumerate()
+        .map(|(index, message)| {
+            let diagnostic = message.into_diagnostic();
+            let diagnostic = match collapsed_labels.remove(&index) {
+                Some(labels) => diagnostic.and_labels(labels),
+                None => diagnostic,
+            };
+            collapsed_related.remove(&index).into_iter().flatten().fold(
+                diagnostic,
+                |diagnostic, RelatedLabel { path, label }| {
+                    diagnostic.and_related_label(path, label)
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    diagnostics.extend(left_out.into_iter().map(|(plugin_name, rule_name, count)| {
+        let problems = if count == 1 { "problem" } else { "problems" };
+        OxcDiagnostic::warn(format!("{plugin_name}({rule_name}): ...and {count} more {problems}"))
+            .with_severity(Severity::Advice)
+    }));
+    diagnostics
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_diagnostics::{LabeledSpan, OxcDiagnostic, Severity};
+    use oxc_span::Span;
+
+    use super::reduce_messages;
+    use crate::Message;
+
+    fn message(rule_name: &'static str, text: &str, span: Span) -> Message<'static> {
+        Message::new(OxcDiagnostic::warn(text.to_string()).with_label(span), None)
+            .with_plugin_name("eslint")
+            .with_rule_name(rule_name)
+    }
+
+    #[test]
+    fn test_reduce_messages() {
+        let source_text = "debugger; debugger;\ndebugger;\ndebugger;\n";
+        let span = |start, end| Span::new(start, end);
+        let messages = vec![
+            message("no-debugger", "debugger", span(0, 9)),
+            message("no-debugger", "debugger", span(0, 9)),
+            message("no-debugger", "debugger", span(10, 19)),
+            message("no-debugger", "debugger", span(20, 29)),
+            message("no-debugger", "debugger", span(30, 39)),
+            message("max-lines", "lines", span(0, 1)),
+        ];
+
+        let labels = |diagnostic: &OxcDiagnostic| {
+            diagnostic.labels.iter().flatten().map(LabeledSpan::offset).collect::<Vec<_>>()
+        };
+        let diagnostics = reduce_messages(messages.clone(), source_text, None, false);
+        assert_eq!(diagnostics.len(), 5);
+        assert_eq!(labels(&diagnostics[0]), [0]);
+        assert_eq!(labels(&diagnostics[1]), [10]);
+
+        let diagnostics = reduce_messages(messages.clone(), source_text, None, true);
+        assert_eq!(diagnostics.len(), 4);
+        assert_eq!(labels(&diagnostics[0]), [0, 10]);
+        assert_eq!(labels(&diagnostics[1]), [20]);
+
+        let diagnostics = reduce_messages(messages, source_text, Some(1), true);
+        assert_eq!(
+            diagnostics.iter().map(ToString::to_string).collect::<Vec<_>>(),
+            ["debugger", "lines", "eslint(no-debugger): ...and 2 more problems"]
+        );
+        assert_eq!(diagnostics[2].severity, Severity::Advice);
+    }
+
+    #[test]
+    fn test_collapsed_related_labels() {
+        let source_text = "import './a'; import './a';";
+        let related = |path: &str, span: Span| {
+            let error = OxcDiagnostic::warn("cycle").with_label(span).and_related_label(path, span);
+            Message::new(error, None).with_plugin_name("import").with_rule_name("no-cycle")
+        };
+        let messages = vec![related("a.js", Span::new(0, 13)), related("b.js", Span::new(14, 27))];
+        let diagnostics = reduce_messages(messages, source_text, None, true);
+        assert_eq!(diagnostics.len(), 1);
+        let paths = diagnostics[0].related.iter().map(|related| related.path.to_str().unwrap());
+        assert_eq!(paths.collect::<Vec<_>>(), ["a.js", "b.js"]);
+    }
+}
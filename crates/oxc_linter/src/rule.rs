@@ -4,16 +4,33 @@ use std::{
     ops::Deref,
 };
 
+use oxc_ast::AstType;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_semantic::SymbolId;
+use schemars::{gen::SchemaGenerator, schema::Schema};
 
-use crate::{context::LintContext, AllowWarnDeny, AstNode, RuleEnum};
+use crate::{context::LintContext, project::ProjectContext, AllowWarnDeny, AstNode, RuleEnum};
 
 pub trait Rule: Sized + Default + fmt::Debug {
+    /// Types of the nodes [`Rule::run`] reports on, it is only called with these nodes. It is
+    /// called with every node when `None`.
+    const NODE_TYPES: Option<&'static [AstType]> = None;
+
     /// Initialize from eslint json configuration
     fn from_configuration(_value: serde_json::Value) -> Self {
         Self::default()
     }
 
+    /// Check the eslint json configuration before [`Rule::from_configuration`] is called.
+    /// Invalid options are reported to the user, the rule falls back to defaults for them.
+    ///
+    /// # Errors
+    ///
+    /// A description of the first invalid option.
+    fn validate_configuration(_value: &serde_json::Value) -> Result<(), OxcDiagnostic> {
+        Ok(())
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -22,6 +39,16 @@ pub trait Rule: Sized + Default + fmt::Debug {
 
     /// Run only once. Useful for inspecting scopes and trivias etc.
     fn run_once(&self, _ctx: &LintContext) {}
+
+    /// Run once after all files are linted, with access to data collected from every file.
+    /// Useful for checks across files, e.g. reporting exports which are never imported.
+    fn run_on_project(&self, _ctx: &ProjectContext) {}
+
+    /// Whether [`Rule::run_on_project`] reports anything with the options of the rule. The data
+    /// of the linted files is only collected when an enabled rule returns `true`.
+    fn runs_on_project(&self) -> bool {
+        false
+    }
 }
 
 pub trait RuleMeta {
@@ -29,9 +56,18 @@ pub trait RuleMeta {
 
     const CATEGORY: RuleCategory;
 
+    /// Kind of fixes the rule provides
+    const FIX: RuleFixMeta;
+
     fn documentation() -> Option<&'static str> {
         None
     }
+
+    /// JSON schema of the first option of the rule, declared with `config = Type` in
+    /// [`declare_oxc_lint`](oxc_macros::declare_oxc_lint).
+    fn config_schema(_gen: &mut SchemaGenerator) -> Option<Schema> {
+        None
+    }
 }
 
 /// Rule categories defined by rust-clippy
@@ -54,6 +90,15 @@ pub enum RuleCategory {
     /// Lints should be considered on a case-by-case basis before enabling.
     Restriction,
     /// New lints that are still under development
+    ///
+    /// They are released early, but only run with `--nursery`, when they are named by `-W` or
+    /// `-D`, or when they are enabled in the configuration, so that their false positives do not
+    /// break the users. Patterns such as `all` don't match them.
+    ///
+    /// A rule is promoted out of the nursery by changing its category in
+    /// [`declare_oxc_lint`](oxc_macros::declare_oxc_lint), once it is feature complete, it has
+    /// no known false positives, and it passes the lint conformance tests
+    /// (`cargo run -p lint_conformance`). Promotions are listed in the changelog.
     Nursery,
 }
 
@@ -81,7 +126,9 @@ impl RuleCategory {
             Self::Restriction => {
                 "Lints which prevent the use of language and library features. Must not be enabled as a whole, should be considered on a case-by-case basis before enabling."
             }
-            Self::Nursery => "New lints that are still under development.",
+            Self::Nursery => {
+                "New lints that are still under development. They only run with `--nursery`, or when enabled by name or in the configuration."
+            }
         }
     }
 }
@@ -100,6 +147,33 @@ impl fmt::Display for RuleCategory {
     }
 }
 
+/// Kind of fixes a rule provides, declared with [`declare_oxc_lint`](oxc_macros::declare_oxc_lint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleFixMeta {
+    /// The rule does not provide fixes
+    None,
+    /// Fixes which do not change the behavior of the code, applied with `--fix`
+    Fix,
+    /// Fixes of which some may change the behavior of the code, those are only applied with
+    /// `--fix-dangerously`
+    DangerousFix,
+    /// Suggestions, which are only applied by the user, e.g. in an editor
+    Suggestion,
+}
+
+impl RuleFixMeta {
+    /// The name of the kind of fixes in the rule metadata, `fix`, `dangerous_fix` or
+    /// `suggestion`, none when the rule does not provide fixes.
+    pub fn name(self) -> Option<&'static str> {
+        match self {
+            Self::None => None,
+            Self::Fix => Some("fix"),
+            Self::DangerousFix => Some("dangerous_fix"),
+            Self::Suggestion => Some("suggestion"),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct RuleWithSeverity {
     pub rule: RuleEnum,
@@ -136,13 +210,27 @@ impl RuleWithSeverity {
 
 #[cfg(test)]
 mod test {
-    use crate::rules::RULES;
+    use crate::{docs::parse_sections, rules::RULES};
 
     #[test]
     fn ensure_documentation() {
         assert!(!RULES.is_empty());
         for rule in RULES.iter() {
             assert!(rule.documentation().is_some_and(|s| !s.is_empty()), "{}", rule.name());
+            let sections = parse_sections(rule.documentation().unwrap());
+            assert_eq!(sections[0].title, "What it does", "{}", rule.name());
+            assert!(sections.iter().all(|section| !section.body.is_empty()), "{}", rule.name());
+        }
+    }
+
+    #[test]
+    fn options_schema() {
+        // The options of the rules which declare a schema are kept in their struct
+        let mut gen = schemars::gen::SchemaGenerator::default();
+        for rule in RULES.iter() {
+            if rule.config_schema(&mut gen).is_some() {
+                assert!(rule.has_options(), "{}", rule.name());
+            }
         }
     }
 }
@@ -0,0 +1,185 @@
+//! Resolution of rule names referenced by configuration files, CLI flags and disable comments.
+//!
+//! Unknown names are matched against the names of all rules, so a typo such as `no-debuger`
+//! can be reported with a "did you mean `no-debugger`?" suggestion instead of being ignored.
+
+use once_cell::sync::Lazy;
+use oxc_diagnostics::OxcDiagnostic;
+
+use crate::{
+    config::{parse_rule_key, transform_rule_and_plugin_name},
+    rules::{RuleEnum, RULES},
+    utils::{is_jest_rule_adapted_to_vitest, min_distance},
+};
+
+/// Maximum number of suggestions returned by [`fuzzy_match`].
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Names of all rules as written in ESLint configuration files and disable comments,
+/// including aliases such as `vitest/no-disabled-tests` and `react-hooks/rules-of-hooks`.
+static RULE_KEYS: Lazy<Vec<String>> = Lazy::new(|| {
+    let mut keys = RULES.iter().flat_map(rule_keys).collect::<Vec<_>>();
+    keys.sort_unstable();
+    keys.dedup();
+    keys
+});
+
+/// The name of a rule as written in ESLint configuration files, e.g. `no-debugger`,
+/// `@typescript-eslint/no-explicit-any` or `jsx-a11y/alt-text`.
+pub(crate) fn rule_key(plugin_name: &str, rule_name: &str) -> String {
+    match plugin_name {
+        "eslint" => rule_name.to_string(),
+        "typescript" => format!("@typescript-eslint/{rule_name}"),
+        "jsx_a11y" => format!("jsx-a11y/{rule_name}"),
+        "react_perf" => format!("react-perf/{rule_name}"),
+        "nextjs" => format!("@next/next/{rule_name}"),
+        _ => format!("{plugin_name}/{rule_name}"),
+    }
+}
+
+fn rule_keys(rule: &RuleEnum) -> impl Iterator<Item = String> {
+    let (plugin_name, rule_name) = (rule.plugin_name(), rule.name());
+    let alias = match plugin_name {
+        "jest" if is_jest_rule_adapted_to_vitest(rule_name) => Some(rule_key("vitest", rule_name)),
+        "react" if rule_name == "rules-of-hooks" => Some(rule_key("react-hooks", rule_name)),
+        _ => None,
+    };
+    std::iter::once(rule_key(plugin_name, rule_name)).chain(alias)
+}
+
+/// Whether `key`, e.g. `no-debugger` or `@typescript-eslint/no-explicit-any`, refers to a rule.
+pub(crate) fn is_known_rule(key: &str) -> bool {
+    find_rule(key).is_some()
+}
+
+/// The rule `key` refers to, e.g. `no-debugger` or `@typescript-eslint/no-explicit-any`.
+pub(crate) fn find_rule(key: &str) -> Option<&'static RuleEnum> {
+    let (plugin_name, rule_name) = parse_rule_key(key);
+    let (rule_name, plugin_name) = transform_rule_and_plugin_name(&rule_name, &plugin_name);
+    let mut rules = RULES.iter().filter(|rule| rule.name() == rule_name);
+    rules.clone().find(|rule| rule.plugin_name() == plugin_name).or_else(|| {
+        // typescript-eslint extension rules, e.g. `@typescript-eslint/no-loss-of-precision`,
+        // are configured together with the ESLint rule they extend.
+        rules.find(|rule| plugin_name == "typescript" && rule.plugin_name() == "eslint")
+    })
+}
+
+/// Whether the rule name part of `key` names a rule of any plugin,
+/// which is how rules referenced by disable comments are matched.
+pub(crate) fn is_known_rule_name(key: &str) -> bool {
+    let rule_name = strip_plugin_name(key);
+    RULES.iter().any(|rule| rule.name() == rule_name)
+}
+
+/// Rule names similar to the unknown rule name `key`, closest first.
+pub(crate) fn suggest_rule_keys(key: &str) -> Vec<&'static str> {
+    fuzzy_match(key, RULE_KEYS.iter().map(String::as_str))
+}
+
+/// Warning for a reference to the unknown rule `key` which is similar to an existing rule,
+/// i.e. most likely a typo. Unknown rules without similar rules are assumed to be rules of
+/// ESLint or of plugins which are not supported yet, and are ignored.
+pub(crate) fn unknown_rule_diagnostic(key: &str) -> Option<OxcDiagnostic> {
+    let help = did_you_mean(&suggest_rule_keys(key))?;
+    Some(OxcDiagnostic::warn(format!("Rule `{key}` was not found.")).with_help(help))
+}
+
+/// Find the candidates closest to `name`, at most three of them, closest first.
+///
+/// Candidates match when their edit distance to `name` is within a third of the length of the
+/// rule name part of `name`. A differing plugin prefix counts as a single edit, so
+/// `no-explicit-any` matches `@typescript-eslint/no-explicit-any`.
+pub(crate) fn fuzzy_match<'c>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'c str>,
+) -> Vec<&'c str> {
+    let max_distance = (strip_plugin_name(name).len() / 3).max(1);
+    let mut matches = candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = distance(name, candidate);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_unstable();
+    matches.dedup_by_key(|(_, candidate)| *candidate);
+    matches.into_iter().take(MAX_SUGGESTIONS).map(|(_, candidate)| candidate).collect()
+}
+
+/// Format suggestions returned by [`fuzzy_match`] as a help message.
+pub(crate) fn did_you_mean(suggestions: &[&str]) -> Option<String> {
+    let (last, rest) = suggestions.split_last()?;
+    let last = format!("`{last}`");
+    if rest.is_empty() {
+        return Some(format!("Did you mean {last}?"));
+    }
+    let rest = rest.iter().map(|s| format!("`{s}`")).collect::<Vec<_>>().join(", ");
+    Some(format!("Did you mean {rest} or {last}?"))
+}
+
+fn distance(a: &str, b: &str) -> usize {
+    let distance = min_distance(a, b);
+    let (a_name, b_name) = (strip_plugin_name(a), strip_plugin_name(b));
+    if a_name.len() == a.len() && b_name.len() == b.len() {
+        return distance;
+    }
+    distance.min(min_distance(a_name, b_name) + 1)
+}
+
+pub(crate) fn strip_plugin_name(key: &str) -> &str {
+    key.rsplit_once('/').map_or(key, |(_, rule_name)| rule_name)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{did_you_mean, fuzzy_match, is_known_rule, is_known_rule_name, suggest_rule_keys};
+
+    #[test]
+    fn test_is_known_rule() {
+        assert!(is_known_rule("no-debugger"));
+        assert!(is_known_rule("@typescript-eslint/no-explicit-any"));
+        assert!(is_known_rule("jsx-a11y/alt-text"));
+        assert!(is_known_rule("vitest/no-disabled-tests"));
+        assert!(is_known_rule("@typescript-eslint/no-loss-of-precision"));
+        assert!(!is_known_rule("no-debuger"));
+        assert!(!is_known_rule("no-explicit-any"));
+
+        assert!(is_known_rule_name("no-explicit-any"));
+        assert!(is_known_rule_name("unicorn/no-abusive-eslint-disable"));
+        assert!(!is_known_rule_name("@typescript-eslint/no-explict-any"));
+    }
+
+    #[test]
+    fn test_suggest_rule_keys() {
+        assert_eq!(suggest_rule_keys("no-debuger"), vec!["no-debugger"]);
+        assert_eq!(
+            suggest_rule_keys("no-explicit-any"),
+            vec!["@typescript-eslint/no-explicit-any"]
+        );
+        assert_eq!(
+            suggest_rule_keys("@typescript-esint/no-explicit-any"),
+            vec!["@typescript-eslint/no-explicit-any"]
+        );
+        assert_eq!(
+            suggest_rule_keys("react-hooks/rule-of-hooks"),
+            vec!["react-hooks/rules-of-hooks", "react/rules-of-hooks"]
+        );
+        assert!(suggest_rule_keys("no-unused-expressions").is_empty());
+        assert!(suggest_rule_keys("vue/valid-template-root").is_empty());
+    }
+
+    #[test]
+    fn test_fuzzy_match() {
+        let candidates = ["correctness", "suspicious", "pedantic", "perf", "style"];
+        assert_eq!(fuzzy_match("corectness", candidates), vec!["correctness"]);
+        assert_eq!(fuzzy_match("syle", candidates), vec!["style"]);
+        assert!(fuzzy_match("restriction", candidates).is_empty());
+    }
+
+    #[test]
+    fn test_did_you_mean() {
+        assert_eq!(did_you_mean(&[]), None);
+        assert_eq!(did_you_mean(&["a"]).unwrap(), "Did you mean `a`?");
+        assert_eq!(did_you_mean(&["a", "b", "c"]).unwrap(), "Did you mean `a`, `b` or `c`?");
+    }
+}
@@ -20,7 +20,7 @@ mod import {
     pub mod no_named_as_default;
     pub mod no_named_as_default_member;
     pub mod no_self_import;
-    // pub mod no_unused_modules;
+    pub mod no_unused_modules;
 }
 
 mod eslint {
@@ -86,6 +86,7 @@ mod eslint {
     pub mod no_redeclare;
     pub mod no_regex_spaces;
     pub mod no_restricted_globals;
+    pub mod no_restricted_syntax;
     pub mod no_script_url;
     pub mod no_self_assign;
     pub mod no_self_compare;
@@ -241,6 +242,13 @@ mod react_refresh {
     pub mod only_export_components;
 }
 
+/// Rules for JSON files, especially `package.json`, see [`crate::json`].
+mod json {
+    pub mod no_duplicate_keys;
+    pub mod sort_package_dependencies;
+    pub mod valid_package_exports;
+}
+
 mod unicorn {
     pub mod catch_error_name;
     pub mod empty_brace_spaces;
@@ -288,6 +296,7 @@ mod unicorn {
     pub mod number_literal_case;
     pub mod numeric_separators_style;
     pub mod prefer_add_event_listener;
+    pub mod prefer_array_find;
     pub mod prefer_array_flat;
     pub mod prefer_array_flat_map;
     pub mod prefer_array_some;
@@ -375,6 +384,7 @@ mod oxc {
     pub mod no_const_enum;
     pub mod no_optional_chaining;
     pub mod no_rest_spread_properties;
+    pub mod no_unsafe_regex;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
     pub mod uninvoked_array_callback;
@@ -523,6 +533,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_await_in_loop,
     eslint::no_new_native_nonconstructor,
     eslint::no_restricted_globals,
+    eslint::no_restricted_syntax,
     eslint::prefer_exponentiation_operator,
     eslint::no_constructor_return,
     typescript::adjacent_overload_signatures,
@@ -648,6 +659,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::number_literal_case,
     unicorn::numeric_separators_style,
     unicorn::prefer_add_event_listener,
+    unicorn::prefer_array_find,
     unicorn::prefer_array_flat_map,
     unicorn::prefer_array_flat,
     unicorn::prefer_array_some,
@@ -712,6 +724,9 @@ oxc_macros::declare_all_lint_rules! {
     react_perf::jsx_no_new_function_as_prop,
     react_perf::jsx_no_new_object_as_prop,
     react_refresh::only_export_components,
+    json::no_duplicate_keys,
+    json::sort_package_dependencies,
+    json::valid_package_exports,
     import::default,
     import::export,
     import::max_dependencies,
@@ -723,7 +738,7 @@ oxc_macros::declare_all_lint_rules! {
     import::no_named_as_default,
     import::no_named_as_default_member,
     import::no_self_import,
-    // import::no_unused_modules,
+    import::no_unused_modules,
     import::no_duplicates,
     import::no_default_export,
     jsx_a11y::alt_text,
@@ -770,6 +785,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_accumulating_spread,
     oxc::no_barrel_file,
     oxc::no_const_enum,
+    oxc::no_unsafe_regex,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::no_async_await,
@@ -25,6 +25,9 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Constructors of derived classes must call `super()` before using `this` or
+    /// returning, otherwise a `ReferenceError` is thrown. Constructors of non derived
+    /// classes must not call `super()`, which is a `SyntaxError`.
     ///
     /// ### Example
     /// ```javascript
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -53,6 +53,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for DefaultCaseLast {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::SwitchStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::SwitchStatement(switch) = node.kind() else {
             return;
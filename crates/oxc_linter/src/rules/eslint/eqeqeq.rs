@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BinaryExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -35,10 +35,13 @@ declare_oxc_lint!(
     /// a == b
     /// ```
     Eqeqeq,
-    pedantic
+    pedantic,
+    dangerous_fix
 );
 
 impl Rule for Eqeqeq {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::BinaryExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj1 = value.get(0);
         let obj2 = value.get(1);
@@ -127,7 +130,11 @@ impl Rule for Eqeqeq {
                 },
             );
         } else {
-            ctx.diagnostic(eqeqeq_diagnostic(operator, preferred_operator, operator_span));
+            // Otherwise the result may change, e.g. `1 == '1'` is `true` but `1 === '1'` is not.
+            ctx.diagnostic_with_dangerous_fix(
+                eqeqeq_diagnostic(operator, preferred_operator, operator_span),
+                |fixer| fixer.replace(operator_span, preferred_operator),
+            );
         }
     }
 }
@@ -252,5 +259,14 @@ fn test() {
         ("a == b", "a == b", None),
     ];
 
-    Tester::new(Eqeqeq::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+    let dangerous_fix = vec![
+        ("a == b", "a === b", None),
+        ("(1000 + 1)  !=  1000", "(1000 + 1)  !==  1000", None),
+        ("value == undefined", "value === undefined", None),
+    ];
+
+    Tester::new(Eqeqeq::NAME, pass, fail)
+        .expect_fix(fix)
+        .expect_dangerous_fix(dangerous_fix)
+        .test_and_snapshot();
 }
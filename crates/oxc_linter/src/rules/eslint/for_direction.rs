@@ -3,7 +3,7 @@ use oxc_ast::{
         AssignmentExpression, AssignmentTarget, Expression, IdentifierReference,
         SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -42,6 +42,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ForDirection {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ForStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ForStatement(for_loop) = node.kind() {
             if let Some(Expression::BinaryExpression(test)) = &for_loop.test {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Statement, AstKind};
+use oxc_ast::{ast::Statement, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -20,18 +20,23 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Looping over objects with a `for in` loop will include properties that are
+    /// inherited through the prototype chain, which can lead to iterating over
+    /// unexpected items.
     ///
     /// ### Example
     /// ```javascript
     /// for (key in foo) {
-    //     doSomething(key);
-    // }
+    ///     doSomething(key);
+    /// }
     /// ```
     GuardForIn,
     style
 );
 
 impl Rule for GuardForIn {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ForInStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ForInStatement(for_in_statement) = node.kind() {
             match &for_in_statement.body {
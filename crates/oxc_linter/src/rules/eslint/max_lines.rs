@@ -46,6 +46,10 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// // With `{ "max": 2 }`
+    /// let a = 1;
+    /// let b = 2;
+    /// let c = 3;
     /// ```
     MaxLines,
     pedantic
@@ -82,7 +86,7 @@ impl Rule for MaxLines {
     fn run_once(&self, ctx: &LintContext) {
         let comment_lines = if self.skip_comments {
             let mut comment_lines: usize = 0;
-            for (kind, span) in ctx.semantic().trivias().comments() {
+            for (kind, span) in ctx.comments() {
                 if kind.is_single_line() {
                     let comment_line =
                         ctx.source_text()[..span.start as usize].lines().next_back().unwrap_or("");
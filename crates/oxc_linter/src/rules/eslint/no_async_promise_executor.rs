@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -49,6 +49,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoAsyncPromiseExecutor {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(new_expression) = node.kind() else {
             return;
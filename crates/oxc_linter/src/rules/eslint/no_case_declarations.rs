@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Statement, VariableDeclarationKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -29,25 +29,27 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
-    // switch (foo) {
-    //   case 1:
-    //       let x = 1;
-    //       break;
-    //   case 2:
-    //       const y = 2;
-    //       break;
-    //   case 3:
-    //       function f() {}
-    //       break;
-    //   default:
-    //       class C {}
-    // }
+    /// switch (foo) {
+    ///   case 1:
+    ///       let x = 1;
+    ///       break;
+    ///   case 2:
+    ///       const y = 2;
+    ///       break;
+    ///   case 3:
+    ///       function f() {}
+    ///       break;
+    ///   default:
+    ///       class C {}
+    /// }
     /// ```
     NoCaseDeclarations,
     pedantic
 );
 
 impl Rule for NoCaseDeclarations {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::SwitchCase]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::SwitchCase(switch_case) = node.kind() {
             let consequent = &switch_case.consequent;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -51,6 +51,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoConsole {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self(Box::new(NoConsoleConfig {
             allow: value
@@ -71,8 +73,7 @@ impl Rule for NoConsole {
         if let AstKind::CallExpression(call_expr) = node.kind() {
             if let Some(mem) = call_expr.callee.as_member_expression() {
                 if let Expression::Identifier(ident) = mem.object() {
-                    if ctx.semantic().is_reference_to_global_variable(ident)
-                        && ident.name == "console"
+                    if ctx.is_global_reference(ident, "console")
                         && !self
                             .allow
                             .iter()
@@ -166,7 +166,7 @@ impl NoConstantBinaryExpression {
             Expression::CallExpression(call_expr) => {
                 if let Expression::Identifier(ident) = &call_expr.callee {
                     return ["Boolean", "String", "Number"].contains(&ident.name.as_str())
-                        && ctx.semantic().is_reference_to_global_variable(ident);
+                        && ctx.is_unresolved_reference(ident);
                 }
                 false
             }
@@ -290,15 +290,12 @@ impl NoConstantBinaryExpression {
             Expression::CallExpression(call_expr) => {
                 if let Expression::Identifier(ident) = &call_expr.callee {
                     if ident.name == "String"
-                        || ident.name == "Number"
-                            && ctx.semantic().is_reference_to_global_variable(ident)
+                        || ident.name == "Number" && ctx.is_unresolved_reference(ident)
                     {
                         return true;
                     }
 
-                    if ident.name == "Boolean"
-                        && ctx.semantic().is_reference_to_global_variable(ident)
-                    {
+                    if ctx.is_global_reference(ident, "Boolean") {
                         return call_expr
                             .arguments
                             .iter()
@@ -340,7 +337,7 @@ impl NoConstantBinaryExpression {
             Expression::NewExpression(call_expr) => {
                 if let Expression::Identifier(ident) = &call_expr.callee {
                     return ctx.env_contains_var(ident.name.as_str())
-                        && ctx.semantic().is_reference_to_global_variable(ident);
+                        && ctx.is_unresolved_reference(ident);
                 }
                 false
             }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{MethodDefinition, MethodDefinitionKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -48,6 +48,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoConstructorReturn {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ReturnStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ReturnStatement(ret) = node.kind() else { return };
         if ret.argument.is_none() {
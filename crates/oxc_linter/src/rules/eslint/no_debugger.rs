@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -28,10 +28,13 @@ declare_oxc_lint!(
     /// debugger;
     /// ```
     NoDebugger,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoDebugger {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::DebuggerStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::DebuggerStatement(stmt) = node.kind() {
             ctx.diagnostic_with_fix(no_debugger_diagnostic(stmt.span), |fixer| {
@@ -43,11 +46,20 @@ impl Rule for NoDebugger {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
+
+    let pass = vec![TestCase::new("var test = { debugger: 1 }; test.debugger;")];
 
-    let pass = vec![("var test = { debugger: 1 }; test.debugger;", None)];
+    let fail = vec![
+        TestCase::new("if (foo) debugger"),
+        TestCase::new("function foo() {\n  debugger;\n  return 1;\n}").with_name("in a function"),
+    ];
 
-    let fail = vec![("if (foo) debugger", None)];
+    Tester::new(NoDebugger::NAME, pass, fail).snapshot_fixes().test_and_snapshot();
 
-    Tester::new(NoDebugger::NAME, pass, fail).test_and_snapshot();
+    // The `<script>` blocks of `.vue` files are linted
+    let pass = vec![TestCase::new("<template><div>debugger</div></template>").with_path("App.vue")];
+    let fail = vec![TestCase::new("<template></template>\n<script setup>\ndebugger\n</script>")
+        .with_path("App.vue")];
+    Tester::new(NoDebugger::NAME, pass, fail).test();
 }
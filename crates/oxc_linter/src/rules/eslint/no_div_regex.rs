@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -32,9 +32,12 @@ declare_oxc_lint!(
     /// ```
     NoDivRegex,
     restriction,
+    fix
 );
 
 impl Rule for NoDivRegex {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::RegExpLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::RegExpLiteral(lit) = node.kind() {
             if lit.regex.pattern.starts_with('=') {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ObjectPropertyKind, PropertyKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -39,6 +39,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDupeKeys {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ObjectExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ObjectExpression(obj_expr) = node.kind() else {
             return;
@@ -1,12 +1,15 @@
 // Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-empty-character-class.js
-use lazy_static::lazy_static;
+use oxc_allocator::Allocator;
 use oxc_ast::AstKind;
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_js_regex::{
+    ast::{ClassRangesCharacterClass, UnicodeSetsCharacterClass},
+    visitor::{walk::walk_unicode_sets_character_class, Visit},
+};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
-use regex::Regex;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::parse_regexp, AstNode};
 
 fn no_empty_character_class_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint(no-empty-character-class): Empty character class")
@@ -34,25 +37,32 @@ declare_oxc_lint!(
 
 impl Rule for NoEmptyCharacterClass {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        lazy_static! {
-            /*
-            * plain-English description of the following regexp:
-            * 0. `^` fix the match at the beginning of the string
-            * 1. `([^\\[]|\\.|\[([^\\\]]|\\.)+\])*`: regexp contents; 0 or more of the following
-            * 1.0. `[^\\[]`: any character that's not a `\` or a `[` (anything but escape sequences and character classes)
-            * 1.1. `\\.`: an escape sequence
-            * 1.2. `\[([^\\\]]|\\.)+\]`: a character class that isn't empty
-            * 2. `$`: fix the match at the end of the string
-            */
-            static ref NO_EMPTY_CLASS_REGEX_PATTERN: Regex =
-                Regex::new(r"^([^\\\[]|\\.|\[([^\\\]]|\\.)+\])*$").unwrap();
+        let AstKind::RegExpLiteral(lit) = node.kind() else { return };
+        let allocator = Allocator::default();
+        // Invalid patterns are reported by `no-invalid-regexp`
+        let Some(Ok((pattern, _))) = parse_regexp(&allocator, node.kind(), ctx) else { return };
+        let mut finder = EmptyClassFinder::default();
+        finder.visit_pattern(&pattern);
+        if finder.found {
+            ctx.diagnostic(no_empty_character_class_diagnostic(lit.span));
         }
+    }
+}
 
-        if let AstKind::RegExpLiteral(lit) = node.kind() {
-            if !NO_EMPTY_CLASS_REGEX_PATTERN.is_match(&lit.regex.pattern) {
-                ctx.diagnostic(no_empty_character_class_diagnostic(lit.span));
-            }
-        }
+/// Finds the classes which match nothing, `[^]` matches any character.
+#[derive(Default)]
+struct EmptyClassFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for EmptyClassFinder {
+    fn visit_class_ranges_character_class(&mut self, class: &ClassRangesCharacterClass<'a>) {
+        self.found |= !class.negate && class.elements.is_empty();
+    }
+
+    fn visit_unicode_sets_character_class(&mut self, class: &UnicodeSetsCharacterClass<'a>) {
+        self.found |= !class.negate && class.elements.is_empty();
+        walk_unicode_sets_character_class(self, class);
     }
 }
 
@@ -75,6 +85,8 @@ fn test() {
         ("var foo = /[\\]]/s;", None),
         ("var foo = /[\\]]/d;", None),
         ("var foo = /\\[]/", None),
+        ("var foo = /[^]/;", None),
+        ("var foo = /[[a]&&b]/v;", None),
     ];
 
     let fail = vec![
@@ -86,6 +98,9 @@ fn test() {
         ("var foo = /\\[[]/;", None),
         ("var foo = /\\[\\[\\]a-z[]/;", None),
         ("var foo = /[]]/d;", None),
+        ("var foo = /a(?:[])/u;", None),
+        ("var foo = /[[]]/v;", None),
+        ("var foo = /[a--[]]/v;", None),
     ];
 
     Tester::new(NoEmptyCharacterClass::NAME, pass, fail).test_and_snapshot();
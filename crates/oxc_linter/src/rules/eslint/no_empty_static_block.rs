@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -36,6 +36,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyStaticBlock {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::StaticBlock]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StaticBlock(static_block) = node.kind() {
             if static_block.body.is_empty() {
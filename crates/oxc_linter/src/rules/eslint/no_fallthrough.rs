@@ -3,7 +3,7 @@ use std::ops::Range;
 use itertools::Itertools;
 use oxc_ast::{
     ast::{Statement, SwitchCase, SwitchStatement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_cfg::{
     graph::{
@@ -78,6 +78,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoFallthrough {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::SwitchStatement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let Some(value) = value.get(0) else { return Self::default() };
         let comment_pattern = value.get("commentPattern").and_then(serde_json::Value::as_str);
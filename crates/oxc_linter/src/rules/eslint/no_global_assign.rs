@@ -2,7 +2,7 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, GlobalValue};
 
 fn no_global_assign_diagnostic(x0: &str, span1: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -66,7 +66,9 @@ impl Rule for NoGlobalAssign {
                 let reference = symbol_table.get_reference(reference_id);
                 if reference.is_write() {
                     let name = reference.name();
-                    if !self.excludes.contains(name) && ctx.env_contains_var(name) {
+                    if !self.excludes.contains(name)
+                        && ctx.global(name) == Some(GlobalValue::Readonly)
+                    {
                         ctx.diagnostic(no_global_assign_diagnostic(name, reference.span()));
                     }
                 }
@@ -77,17 +79,15 @@ impl Rule for NoGlobalAssign {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         ("string='1';", None),
         ("var string;", None),
         ("Object = 0;", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
         ("top = 0;", None),
-        // ("onload = 0;", None), // env: { browser: true }
         ("require = 0;", None),
         ("window[parseInt('42', 10)] = 99;", None),
-        // ("a = 1", None), // globals: { a: true } },
         // ("/*global a:true*/ a = 1", None),
     ];
 
@@ -95,8 +95,6 @@ fn test() {
         ("String = 'hello world';", None),
         ("String++;", None),
         ("({Object = 0, String = 0} = {});", None),
-        // ("top = 0;", None), // env: { browser: true },
-        // ("require = 0;", None), // env: { node: true },
         ("function f() { Object = 1; }", None),
         // ("/*global b:false*/ function f() { b = 1; }", None),
         // ("/*global b:false*/ function f() { b++; }", None),
@@ -105,4 +103,17 @@ fn test() {
     ];
 
     Tester::new(NoGlobalAssign::NAME, pass, fail).test_and_snapshot();
+
+    let pass = vec![
+        TestCase::new("onload = 0;").with_env(serde_json::json!({ "browser": true })),
+        TestCase::new("a = 1").with_globals(serde_json::json!({ "a": true })),
+        TestCase::new("String = 1").with_globals(serde_json::json!({ "String": "off" })),
+    ];
+    let fail = vec![
+        TestCase::new("top = 0;").with_env(serde_json::json!({ "browser": true })),
+        TestCase::new("require = 0;").with_env(serde_json::json!({ "node": true })),
+        TestCase::new("function f() { b = 1; }").with_globals(serde_json::json!({ "b": false })),
+        TestCase::new("b++;").with_globals(serde_json::json!({ "b": "readonly" })),
+    ];
+    Tester::new(NoGlobalAssign::NAME, pass, fail).test();
 }
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -32,6 +32,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoIterator {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::MemberExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(member_expression) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -38,6 +38,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNewNativeNonconstructor {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(expr) = node.kind() else {
             return;
@@ -45,8 +47,7 @@ impl Rule for NoNewNativeNonconstructor {
         let Expression::Identifier(ident) = &expr.callee else {
             return;
         };
-        if matches!(ident.name.as_str(), "Symbol" | "BigInt")
-            && ctx.semantic().is_reference_to_global_variable(ident)
+        if matches!(ident.name.as_str(), "Symbol" | "BigInt") && ctx.is_unresolved_reference(ident)
         {
             let start = expr.span.start;
             let end = start + 3;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -34,6 +34,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNewWrappers {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(expr) = node.kind() else {
             return;
@@ -42,7 +44,7 @@ impl Rule for NoNewWrappers {
             return;
         };
         if (ident.name == "String" || ident.name == "Number" || ident.name == "Boolean")
-            && ctx.semantic().is_reference_to_global_variable(ident)
+            && ctx.is_unresolved_reference(ident)
         {
             ctx.diagnostic(no_new_wrappers_diagnostic(ident.name.as_str(), expr.span));
         }
@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -47,6 +47,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNonoctalDecimalEscape {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::StringLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StringLiteral(literal) = node.kind() {
             check_string(ctx, literal.span.source_text(ctx.source_text()));
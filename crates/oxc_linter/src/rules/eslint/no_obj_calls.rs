@@ -80,7 +80,7 @@ fn resolve_global_binding<'a, 'b: 'a>(
     scope_id: ScopeId,
     ctx: &LintContext<'a>,
 ) -> Option<&'a str> {
-    if ctx.semantic().is_reference_to_global_variable(ident) {
+    if ctx.is_unresolved_reference(ident) {
         Some(ident.name.as_str())
     } else {
         let scope = ctx.scopes();
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -56,6 +56,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoRestrictedGlobals {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::IdentifierReference]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let list = match value {
             Value::Array(arr) => arr.iter().fold(FxHashMap::default(), |mut acc, v| match v {
@@ -85,7 +87,7 @@ impl Rule for NoRestrictedGlobals {
                 return;
             };
 
-            if ctx.semantic().is_reference_to_global_variable(ident) {
+            if ctx.is_unresolved_reference(ident) {
                 ctx.diagnostic(no_restricted_globals(&ident.name, message, ident.span));
             }
         }
@@ -0,0 +1,171 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use serde_json::Value;
+
+use crate::{context::LintContext, rule::Rule, selector::Selector, AstNode};
+
+fn no_restricted_syntax_diagnostic(message: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint(no-restricted-syntax): {message}")).with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax(Box<NoRestrictedSyntaxConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntaxConfig {
+    restricted: Vec<RestrictedSyntax>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedSyntax {
+    selector: Selector,
+    message: String,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallows the syntax matched by the given selectors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some syntax is allowed by JavaScript but is not wanted in a code base, e.g. `with`
+    /// statements or a function which must not be called. The selectors ban it without
+    /// writing a rule. They follow the syntax of [ESQuery](https://github.com/estools/esquery)
+    /// and match the nodes of [ESTree](https://github.com/estree/estree), e.g.
+    /// `CallExpression[callee.name="require"]`, `FunctionDeclaration[params.length>3]` or
+    /// `:function:not(:has(ReturnStatement))`.
+    ///
+    /// The sibling combinators (`~` and `+`), the positional pseudo-classes like
+    /// `:first-child` and the field selectors like `.callee` are not supported.
+    ///
+    /// ### Example
+    ///
+    /// If we have options:
+    ///
+    /// ```json
+    /// "no-restricted-syntax": [
+    ///     "error",
+    ///     "WithStatement",
+    ///     { "selector": "CallExpression[callee.name='require']", "message": "Use imports." }
+    /// ]
+    /// ```
+    ///
+    /// The following patterns are considered problems:
+    ///
+    /// ```javascript
+    /// with (foo) {}
+    /// const bar = require("bar");
+    /// ```
+    NoRestrictedSyntax,
+    restriction,
+);
+
+/// The selector and the message of an option, which is a selector or
+/// `{ "selector": string, "message"?: string }`.
+fn option_selector(option: &Value) -> Option<(&str, Option<&str>)> {
+    match option {
+        Value::String(selector) => Some((selector, None)),
+        Value::Object(object) => {
+            Some((object.get("selector")?.as_str()?, object.get("message").and_then(Value::as_str)))
+        }
+        _ => None,
+    }
+}
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: Value) -> Self {
+        let restricted = value
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(option_selector)
+            .filter_map(|(source, message)| {
+                let selector = Selector::parse(source).ok()?;
+                let message = message.map_or_else(
+                    || format!("Using '{source}' is not allowed."),
+                    ToString::to_string,
+                );
+                Some(RestrictedSyntax { selector, message })
+            })
+            .collect();
+        Self(Box::new(NoRestrictedSyntaxConfig { restricted }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), OxcDiagnostic> {
+        for option in value.as_array().into_iter().flatten() {
+            let Some((source, _)) = option_selector(option) else {
+                return Err(OxcDiagnostic::warn(format!(
+                    "Expected a selector or an object with a selector but found {option}"
+                )));
+            };
+            if let Err(error) = Selector::parse(source) {
+                return Err(OxcDiagnostic::warn(format!("Invalid selector `{source}`: {error}")));
+            }
+        }
+        Ok(())
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        for restricted in &self.0.restricted {
+            if restricted.selector.matches(node, ctx.nodes(), ctx.source_text()) {
+                ctx.diagnostic(no_restricted_syntax_diagnostic(
+                    &restricted.message,
+                    node.kind().span(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(serde_json::json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(serde_json::json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(serde_json::json!(["Identifier[name=\"bar\"]"]))),
+        ("() => 5", Some(serde_json::json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("A: for (;;) break;", Some(serde_json::json!(["BreakStatement[label]"]))),
+        (
+            "function foo(bar, baz) {}",
+            Some(serde_json::json!(["FunctionDeclaration[params.length>2]"])),
+        ),
+        ("var foo = 42;", Some(serde_json::json!([{ "selector": "ConditionalExpression" }]))),
+        (
+            "({ foo: 1, bar: 2 })",
+            Some(serde_json::json!([{ "selector": "FunctionDeclaration[params.length>2]" }])),
+        ),
+        (
+            "import foo from 'foo';",
+            Some(serde_json::json!(["ImportDeclaration[source.value='bar']"])),
+        ),
+        (
+            "function foo() { return 1; }",
+            Some(serde_json::json!([":function:not(:has(ReturnStatement))"])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(serde_json::json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(serde_json::json!(["EmptyStatement"]))),
+        ("try { voila(); } catch (e) { oops(); }", Some(serde_json::json!(["TryStatement", "CallExpression", "CatchClause"]))),
+        ("bar;", Some(serde_json::json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(serde_json::json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("() => {}", Some(serde_json::json!(["ArrowFunctionExpression > BlockStatement"]))),
+        ("({ foo: 1, 'bar': 2 })", Some(serde_json::json!(["Property > Literal"]))),
+        ("A: for (;;) break A;", Some(serde_json::json!(["BreakStatement[label]"]))),
+        ("function foo(bar, baz, qux) {}", Some(serde_json::json!(["FunctionDeclaration[params.length>2]"]))),
+        ("var foo = 41;", Some(serde_json::json!([{ "selector": "VariableDeclaration" }]))),
+        ("function foo(bar, baz, qux) {}", Some(serde_json::json!([{ "selector": "FunctionDeclaration[params.length>2]", "message": "custom error message." }]))),
+        ("const foo = require('foo');", Some(serde_json::json!(["CallExpression[callee.name='require'][arguments.0.type=Literal]"]))),
+        ("import foo from 'foo';", Some(serde_json::json!(["ImportDeclaration[source.value=/^fo/]"]))),
+        ("function foo() { bar(); }", Some(serde_json::json!([":function:not(:has(ReturnStatement))"]))),
+        ("foo?.bar()", Some(serde_json::json!(["ChainExpression > CallExpression[optional!=true] > MemberExpression[optional=true]"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
@@ -4,7 +4,7 @@ use oxc_ast::{
         AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetProperty, Expression,
         MemberExpression, ObjectProperty, ObjectPropertyKind, SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -49,6 +49,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSelfAssign {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::AssignmentExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             props: value
@@ -21,6 +21,9 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// These names are not reserved keywords, but shadowing them is confusing:
+    /// code reading `undefined` or `NaN` expects the global values, not a local
+    /// binding with another value.
     ///
     /// ### Example
     /// ```javascript
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::ArrayExpressionElement, AstKind};
+use oxc_ast::{ast::ArrayExpressionElement, AstKind, AstType};
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 
@@ -26,6 +26,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSparseArrays {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ArrayExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ArrayExpression(array_expr) = node.kind() {
             let violations = array_expr
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -35,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoTemplateCurlyInString {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::StringLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::StringLiteral(literal) = node.kind() {
             let text = literal.value.as_str();
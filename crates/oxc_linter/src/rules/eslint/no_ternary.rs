@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -34,6 +34,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoTernary {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ConditionalExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::ConditionalExpression(cond_expr) = node.kind() {
             ctx.diagnostic(no_ternary_diagnostic(Span::new(
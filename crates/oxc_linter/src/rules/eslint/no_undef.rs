@@ -1,8 +1,12 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{AssignmentTarget, Expression, Statement},
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use oxc_syntax::operator::UnaryOperator;
+use rustc_hash::FxHashSet;
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -48,17 +52,20 @@ impl Rule for NoUndef {
 
     fn run_once(&self, ctx: &LintContext) {
         let symbol_table = ctx.symbols();
+        let is_svelte = ctx.file_path().extension().is_some_and(|ext| ext == "svelte");
+        let reactive_declarations =
+            if is_svelte { svelte_reactive_declarations(ctx) } else { FxHashSet::default() };
 
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
                 let name = reference.name();
 
-                if ctx.env_contains_var(name) {
+                if ctx.is_global(name) {
                     continue;
                 }
 
-                if ctx.globals().is_enabled(name.as_str()) {
+                if is_svelte && is_svelte_implicit_variable(name, &reactive_declarations, ctx) {
                     continue;
                 }
 
@@ -73,6 +80,61 @@ impl Rule for NoUndef {
     }
 }
 
+/// Variables declared by Svelte's compiler in components.
+/// <https://svelte.dev/docs/svelte-components>
+const SVELTE_GLOBALS: [&str; 10] = [
+    "$$props",
+    "$$restProps",
+    "$$slots",
+    // Svelte 5 runes
+    "$state",
+    "$derived",
+    "$effect",
+    "$props",
+    "$bindable",
+    "$inspect",
+    "$host",
+];
+
+/// Variables implicitly declared by top-level reactive assignments of Svelte components,
+/// e.g. `doubled` in `$: doubled = count * 2;`.
+fn svelte_reactive_declarations<'a>(ctx: &LintContext<'a>) -> FxHashSet<&'a str> {
+    let Some(AstKind::Program(program)) = ctx.nodes().root_node().map(AstNode::kind) else {
+        return FxHashSet::default();
+    };
+    program
+        .body
+        .iter()
+        .filter_map(|stmt| {
+            let Statement::LabeledStatement(stmt) = stmt else { return None };
+            if stmt.label.name != "$" {
+                return None;
+            }
+            let Statement::ExpressionStatement(stmt) = &stmt.body else { return None };
+            let Expression::AssignmentExpression(expr) = &stmt.expression else { return None };
+            match &expr.left {
+                AssignmentTarget::AssignmentTargetIdentifier(ident) => Some(ident.name.as_str()),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Whether `name` is declared by Svelte's compiler: a reactive declaration, a store subscription
+/// such as `$count` for a declared `count` store, or one of [`SVELTE_GLOBALS`].
+fn is_svelte_implicit_variable(
+    name: &str,
+    reactive_declarations: &FxHashSet<&str>,
+    ctx: &LintContext,
+) -> bool {
+    if reactive_declarations.contains(name) || SVELTE_GLOBALS.contains(&name) {
+        return true;
+    }
+    name.strip_prefix('$').is_some_and(|store| {
+        ctx.scopes().get_root_binding(store).is_some() || reactive_declarations.contains(store)
+    })
+}
+
 fn has_typeof_operator(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
     ctx.nodes().parent_node(node.id()).map_or(false, |parent| match parent.kind() {
         AstKind::UnaryExpression(expr) => expr.operator == UnaryOperator::Typeof,
@@ -83,7 +145,7 @@ fn has_typeof_operator(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         "var a = 1, b = 2; a;",
@@ -190,15 +252,44 @@ fn test() {
     Tester::new(NoUndef::NAME, pass, fail).test_and_snapshot();
 
     let pass = vec![];
-    let fail = vec![(
-        "if (typeof anUndefinedVar === 'string') {}",
-        Some(serde_json::json!([{ "typeof": true }])),
-    )];
+    let fail = vec![TestCase::new("if (typeof anUndefinedVar === 'string') {}")
+        .with_rule_config(serde_json::json!([{ "typeof": true }]))];
+
+    Tester::new(NoUndef::NAME, pass, fail).test();
+
+    let pass = vec![
+        TestCase::new("foo").with_globals(serde_json::json!({ "foo": "readonly" })),
+        TestCase::new("window").with_env(serde_json::json!({ "browser": true })),
+        TestCase::new("foo").with_globals(serde_json::json!({ "foo": false })),
+        TestCase::new("Promise; describe;").with_env(serde_json::json!({ "jest": true })),
+    ];
+    let fail = vec![
+        TestCase::new("foo").with_globals(serde_json::json!({ "foo": "off" })),
+        TestCase::new("window").with_env(serde_json::json!({ "browser": false })),
+        TestCase::new("window"),
+        TestCase::new("window")
+            .with_env(serde_json::json!({ "browser": true }))
+            .with_globals(serde_json::json!({ "window": "off" })),
+    ];
 
     Tester::new(NoUndef::NAME, pass, fail).test();
 
-    let pass = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "readonly" } })))];
-    let fail = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "off" } })))];
+    // Variables declared by Svelte's compiler
+    let pass = vec![
+        TestCase::new("<script>let count = 0; $: doubled = count * 2; doubled;</script>")
+            .with_path("App.svelte"),
+        TestCase::new(
+            "<script>import { count } from './stores'; $count; $doubled; $: doubled = 1;</script>",
+        )
+        .with_path("App.svelte"),
+        TestCase::new("<script>let { a } = $props(); let b = $state(0); $$restProps;</script>")
+            .with_path("App.svelte"),
+    ];
+    let fail = vec![
+        TestCase::new("<script>$count;</script>").with_path("App.svelte"),
+        TestCase::new("<script>function f() { $: a = 1; } a;</script>").with_path("App.svelte"),
+        TestCase::new("let count = 0; $: doubled = count * 2; doubled;"),
+    ];
 
     Tester::new(NoUndef::NAME, pass, fail).test();
 }
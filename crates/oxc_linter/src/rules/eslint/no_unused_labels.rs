@@ -35,17 +35,20 @@ declare_oxc_lint!(
     /// }
     /// ```
     NoUnusedLabels,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUnusedLabels {
     fn run_once(&self, ctx: &LintContext) {
-        if ctx.file_path().extension().is_some_and(|ext| ext == "svelte") {
-            return;
-        }
+        let is_svelte = ctx.file_path().extension().is_some_and(|ext| ext == "svelte");
         for id in ctx.semantic().unused_labels() {
             let node = ctx.semantic().nodes().get_node(*id);
             if let AstKind::LabeledStatement(stmt) = node.kind() {
+                // `$:` marks a reactive statement in Svelte components
+                if is_svelte && stmt.label.name == "$" {
+                    continue;
+                }
                 // TODO: Ignore fix where comments exist between label and statement
                 // e.g. A: /* Comment */ function foo(){}
                 ctx.diagnostic_with_fix(
@@ -59,7 +62,7 @@ impl Rule for NoUnusedLabels {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         ("A: break A;", None),
@@ -87,4 +90,12 @@ fn test() {
     ];
 
     Tester::new(NoUnusedLabels::NAME, pass, fail).test_and_snapshot();
+
+    let pass =
+        vec![TestCase::new("<script>$: doubled = count * 2;</script>").with_path("App.svelte")];
+    let fail = vec![
+        TestCase::new("<script>A: doubled = count * 2;</script>").with_path("App.svelte"),
+        TestCase::new("$: doubled = count * 2;"),
+    ];
+    Tester::new(NoUnusedLabels::NAME, pass, fail).test();
 }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPatternKind, Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -46,6 +46,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUselessCatch {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TryStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TryStatement(try_stmt) = node.kind() else {
             return;
@@ -3,7 +3,7 @@ use oxc_ast::{
         Argument, BindingPattern, BindingPatternKind, BindingRestElement, CallExpression,
         Expression, FormalParameters, FunctionBody, MethodDefinition, Statement, TSAccessibility,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -78,9 +78,12 @@ declare_oxc_lint!(
     ///```
     NoUselessConstructor,
     suspicious,
+    fix
 );
 
 impl Rule for NoUselessConstructor {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::MethodDefinition]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MethodDefinition(constructor) = node.kind() else {
             return;
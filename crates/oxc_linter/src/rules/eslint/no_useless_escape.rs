@@ -22,12 +22,21 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Escaping characters in strings, template literals and regular expressions
+    /// which have no special meaning doesn't change their value, and only makes the
+    /// code harder to read.
     ///
     /// ### Example
     /// ```javascript
+    /// "\'";
+    /// '\"';
+    /// "\#";
+    /// `\"${foo}\"`;
+    /// /\!/;
     /// ```
     NoUselessEscape,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessEscape {
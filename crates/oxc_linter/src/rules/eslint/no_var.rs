@@ -1,4 +1,4 @@
-use oxc_ast::{ast::VariableDeclarationKind, AstKind};
+use oxc_ast::{ast::VariableDeclarationKind, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -41,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoVar {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::VariableDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::VariableDeclaration(dec) = node.kind() {
             if dec.kind == VariableDeclarationKind::Var {
@@ -52,7 +54,7 @@ impl Rule for NoVar {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![("const JOE = 'schmoe';", None), ("let moo = 'car';", None)];
 
@@ -106,4 +108,9 @@ fn test() {
     ];
 
     Tester::new(NoVar::NAME, pass, fail).test_and_snapshot();
+
+    // `declare var` declares the globals of scripts in declaration files
+    let pass = vec![TestCase::new("declare var foo: string;").with_path("globals.d.ts")];
+    let fail = vec![TestCase::new("declare var foo: string;").with_path("globals.ts")];
+    Tester::new(NoVar::NAME, pass, fail).test();
 }
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -39,6 +39,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoVoid {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::UnaryExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let allow_as_statement = value
             .get(0)
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -32,6 +32,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoWith {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::WithStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::WithStatement(with_statement) = node.kind() {
             ctx.diagnostic(no_with_diagnostic(Span::new(
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, match_member_expression, AstKind};
+use oxc_ast::{ast::Expression, match_member_expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -38,6 +38,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferExponentiationOperator {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -55,9 +57,7 @@ impl Rule for PreferExponentiationOperator {
 
         match member_expor_obj {
             Expression::Identifier(ident) => {
-                if ident.name.as_str() == "Math"
-                    && ctx.semantic().is_reference_to_global_variable(ident)
-                {
+                if ctx.is_global_reference(ident, "Math") {
                     ctx.diagnostic(prefer_exponentian_operator_diagnostic(call_expr.span));
                 }
             }
@@ -73,7 +73,7 @@ impl Rule for PreferExponentiationOperator {
                 if let Expression::Identifier(ident) = member_expr.object().without_parenthesized()
                 {
                     if GLOBAL_OBJECT_NAMES.contains(ident.name.as_str())
-                        && ctx.semantic().is_reference_to_global_variable(ident)
+                        && ctx.is_unresolved_reference(ident)
                     {
                         ctx.diagnostic(prefer_exponentian_operator_diagnostic(call_expr.span));
                     }
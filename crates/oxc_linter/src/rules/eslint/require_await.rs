@@ -1,7 +1,7 @@
 use oxc_ast::{
     ast::{ArrowFunctionExpression, AwaitExpression, ForOfStatement, Function, PropertyKey},
     visit::walk::walk_for_of_statement,
-    AstKind, Visit,
+    AstKind, AstType, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -24,6 +24,9 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Async functions which don't use `await` might not need to be async, and are
+    /// likely the unintentional result of a refactoring. They still return a
+    /// promise, which the callers have to handle.
     ///
     /// ### Example
     /// ```javascript
@@ -36,6 +39,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for RequireAwait {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::FunctionBody]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::FunctionBody(body) = node.kind() {
             if body.is_empty() {
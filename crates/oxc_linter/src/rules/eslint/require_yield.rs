@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -33,6 +33,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for RequireYield {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::Function]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::Function(func) = node.kind() {
             if !node.flags().has_yield()
@@ -66,6 +66,9 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Unsorted imports are harder to scan, and lead to merge conflicts when
+    /// several changes add imports at the end of the list.
+    ///
     /// ### Example
     /// ```javascript
     /// import {b, a, c} from 'foo.js'
@@ -74,7 +77,8 @@ declare_oxc_lint!(
     /// import e from 'bar.js';
     /// ```
     SortImports,
-    style
+    style,
+    fix
 );
 
 impl Rule for SortImports {
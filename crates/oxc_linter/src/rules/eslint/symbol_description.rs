@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -31,6 +31,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for SymbolDescription {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -40,10 +42,7 @@ impl Rule for SymbolDescription {
             return;
         };
 
-        if ident.name == "Symbol"
-            && call_expr.arguments.len() == 0
-            && ctx.semantic().is_reference_to_global_variable(ident)
-        {
+        if call_expr.arguments.len() == 0 && ctx.is_global_reference(ident, "Symbol") {
             ctx.diagnostic(symbol_description_diagnostic(call_expr.span));
         }
     }
@@ -75,6 +75,7 @@ declare_oxc_lint!(
     /// ```
     UseIsnan,
     correctness,
+    fix
 );
 
 impl Rule for UseIsnan {
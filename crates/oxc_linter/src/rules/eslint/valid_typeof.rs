@@ -53,6 +53,7 @@ declare_oxc_lint!(
     /// ```
     ValidTypeof,
     correctness,
+    fix
 );
 
 impl Rule for ValidTypeof {
@@ -97,7 +98,7 @@ impl Rule for ValidTypeof {
         }
 
         if let Expression::Identifier(ident) = sibling {
-            if ident.name == "undefined" && ctx.semantic().is_reference_to_global_variable(ident) {
+            if ctx.is_global_reference(ident, "undefined") {
                 ctx.diagnostic_with_fix(
                     if self.require_string_literals {
                         not_string(
@@ -177,6 +178,7 @@ fn test() {
         ("typeof(foo) != 'string'", None),
         ("var oddUse = typeof foo + 'thing'", None),
         ("function f(undefined) { typeof x === undefined }", None),
+        ("function f(undefined) { typeof x === undefined } undefined;", None),
         ("typeof foo === `str${somethingElse}`", None),
         ("typeof foo === 'number'", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
         ("typeof foo === \"number\"", Some(serde_json::json!([{ "requireStringLiterals": true }]))),
@@ -18,10 +18,21 @@ pub struct Named;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Verifies that all named imports are part of the set of named exports in
+    /// the referenced module.
+    ///
     /// ### Why is this bad?
     ///
+    /// Importing or re-exporting a name which the module doesn't export results in
+    /// `undefined`, or in an error at runtime with ES modules.
+    ///
     /// ### Example
     /// ```javascript
+    /// // ./foo.js
+    /// export const foo = "I'm so foo";
+    ///
+    /// // ./bar.js
+    /// import { notFoo } from './foo'
     /// ```
     Named,
     correctness
@@ -54,20 +65,8 @@ impl Rule for Named {
             }
             let import_span = import_name.span();
             let import_name = import_name.name();
-            // Check `import { default as foo } from 'bar'`
-            if import_name.as_str() == "default" && remote_module_record.export_default.is_some() {
-                continue;
-            }
-            // Check remote bindings
-            if remote_module_record.exported_bindings.contains_key(import_name) {
-                continue;
-            }
-            // check re-export
-            if remote_module_record
-                .exported_bindings_from_star_export
-                .iter()
-                .any(|entry| entry.value().contains(import_name))
-            {
+            // Check remote bindings and re-exports, including `import { default as foo } from 'bar'`
+            if ctx.module_graph().resolve_export(remote_module_record, import_name).is_some() {
                 continue;
             }
 
@@ -90,13 +89,10 @@ impl Rule for Named {
             if remote_module_record.not_esm {
                 continue;
             }
-            // Check remote bindings
+            // Check remote bindings and re-exports,
+            // including `export { default as foo } from './source'` <> `export default xxx`
             let name = import_name.name();
-            // `export { default as foo } from './source'` <> `export default xxx`
-            if *name == "default" && remote_module_record.export_default.is_some() {
-                continue;
-            }
-            if remote_module_record.exported_bindings.contains_key(name) {
+            if ctx.module_graph().resolve_export(remote_module_record, name).is_some() {
                 continue;
             }
             ctx.diagnostic(named_diagnostic(name, specifier, import_name.span()));
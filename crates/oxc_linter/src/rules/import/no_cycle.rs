@@ -1,7 +1,7 @@
 #![allow(clippy::cast_possible_truncation)]
 use std::{ffi::OsStr, path::Component, sync::Arc};
 
-use oxc_diagnostics::OxcDiagnostic;
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{CompactStr, Span};
 use oxc_syntax::{
@@ -122,7 +122,7 @@ impl Rule for NoCycle {
             })
             .event(|event, (key, val), _| match event {
                 ModuleGraphVisitorEvent::Enter => {
-                    stack.push((key.clone(), val.resolved_absolute_path.clone()));
+                    stack.push((key.clone(), Arc::clone(val)));
                 }
                 ModuleGraphVisitorEvent::Leave => {
                     stack.pop();
@@ -141,7 +141,8 @@ impl Rule for NoCycle {
             let span = module_record.requested_modules[&stack[0].0][0].span();
             let help = stack
                 .iter()
-                .map(|(specifier, path)| {
+                .map(|(specifier, module)| {
+                    let path = &module.resolved_absolute_path;
                     let path = path
                         .strip_prefix(&cwd)
                         .unwrap_or(path)
@@ -151,7 +152,21 @@ impl Rule for NoCycle {
                 })
                 .collect::<Vec<_>>()
                 .join("\n");
-            ctx.diagnostic(no_cycle_diagnostic(span, &help));
+            // The imports of the other modules of the cycle
+            let related = stack.windows(2).filter_map(|pair| {
+                let [(_, importer), (specifier, _)] = pair else { return None };
+                let span = importer.requested_modules.get(specifier)?.first()?.span();
+                let label = LabeledSpan::new_with_span(
+                    Some("import cycle continues here".to_string()),
+                    span,
+                );
+                Some((importer.resolved_absolute_path.clone(), label))
+            });
+            let diagnostic = related
+                .fold(no_cycle_diagnostic(span, &help), |diagnostic, (path, label)| {
+                    diagnostic.and_related_label(path, label)
+                });
+            ctx.diagnostic(diagnostic);
         }
     }
 }
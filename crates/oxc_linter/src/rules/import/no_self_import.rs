@@ -1,8 +1,9 @@
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 fn no_self_import_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(
@@ -31,6 +32,19 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoSelfImport {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
+    /// `require("./self")`
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(specifier) = call.common_js_require() else { return };
+        // The file path is relative when oxlint is given relative paths, the resolved one is not
+        let Some(path) = ctx.resolve(&specifier.value) else { return };
+        if ctx.absolute_file_path().is_some_and(|file_path| path == file_path) {
+            ctx.diagnostic(no_self_import_diagnostic(call.span));
+        }
+    }
+
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.module_record();
         let resolved_absolute_path = &module_record.resolved_absolute_path;
@@ -72,8 +86,8 @@ fn test() {
 
         let fail = vec![
             "import bar from './no-self-import'",
-            // "var bar = require('./no-self-import')",
-            // "var bar = require('./no-self-import.js')",
+            "var bar = require('./no-self-import')",
+            "var bar = require('./no-self-import.js')",
         ];
 
         Tester::new(NoSelfImport::NAME, pass, fail)
@@ -82,37 +96,37 @@ fn test() {
             .test();
     }
 
-    // {
-    // let pass = vec!["var bar = require('./bar')"];
-    // let fail = vec![];
-
-    // Tester::new(NoSelfImport::NAME, pass, fail)
-    // .with_import_plugin(true)
-    // .change_rule_path("bar/index.js")
-    // .test();
-    // }
-
-    // {
-    // let pass = vec![];
-    // let fail = vec![
-    // "var bar = require('.')",
-    // "var bar = require('./')",
-    // "var bar = require('././././')",
-    // ];
-
-    // Tester::new(NoSelfImport::NAME, pass, fail)
-    // .with_import_plugin(true)
-    // .change_rule_path("index.js")
-    // .test();
-    // }
-
-    // {
-    // let pass = vec![];
-    // let fail = vec!["var bar = require('../no-self-import-folder')"];
-
-    // Tester::new(NoSelfImport::NAME, pass, fail)
-    // .with_import_plugin(true)
-    // .change_rule_path("no-self-import-folder/index.js")
-    // .test();
-    // }
+    {
+        let pass = vec!["var bar = require('./bar')"];
+        let fail = vec![];
+
+        Tester::new(NoSelfImport::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("bar/index.js")
+            .test();
+    }
+
+    {
+        let pass = vec![];
+        let fail = vec![
+            "var bar = require('.')",
+            "var bar = require('./')",
+            "var bar = require('././././')",
+        ];
+
+        Tester::new(NoSelfImport::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("index.js")
+            .test();
+    }
+
+    {
+        let pass = vec![];
+        let fail = vec!["var bar = require('../no-self-import-folder')"];
+
+        Tester::new(NoSelfImport::NAME, pass, fail)
+            .with_import_plugin(true)
+            .change_rule_path("no-self-import-folder/index.js")
+            .test();
+    }
 }
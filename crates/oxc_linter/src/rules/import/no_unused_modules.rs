@@ -1,44 +1,114 @@
+use std::path::{Path, PathBuf};
+
+use globset::GlobSet;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{CompactStr, Span};
+use oxc_syntax::module_record::{
+    ExportExportName, ExportImportName, ImportImportName, ModuleRecord,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{
+    config::{deserialize_globs, normalize_path},
+    context::LintContext,
+    project::ProjectContext,
+    rule::Rule,
+};
 
 fn no_exports_found(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-import(no-unused-modules): No exports found")
         .with_label(span0)
 }
 
+fn unused_export(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-import(no-unused-modules): Exported declaration '{name}' is not used within other modules"
+    ))
+    .with_help("Remove the export, or add the file to `ignoreExports` if it is an entry point or part of the public API")
+    .with_label(span)
+}
+
 /// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-unused-modules.md>
 #[derive(Debug, Default, Clone)]
-pub struct NoUnusedModules {
+pub struct NoUnusedModules(Box<NoUnusedModulesConfig>);
+
+// The fields are named after the options
+#[allow(clippy::struct_field_names)]
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedModulesConfig {
     missing_exports: bool,
     unused_exports: bool,
+    /// Files whose exports are not reported by `unusedExports`
+    ignore_exports: GlobSet,
+}
+
+impl std::ops::Deref for NoUnusedModules {
+    type Target = NoUnusedModulesConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
 
 declare_oxc_lint!(
     /// ### What it does
     ///
     /// Reports:
-    /// * modules without any exports
-    /// * individual exports not being statically imported or requireed from other modules in the same project
-    /// * dynamic imports are supported if argument is a literal string
+    /// * modules without any exports, with `missingExports`
+    /// * individual exports not being statically imported or re-exported from other linted
+    ///   modules, with `unusedExports`
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Unused exports are dead code which is not detected by `no-unused-vars`, as the exported
+    /// bindings are used by the `export` itself.
     ///
+    /// The imports of all the linted files are collected, so the whole project should be linted
+    /// at once. Dynamic `import()` and `require()` calls are not followed. The exports of entry
+    /// points, of the public API of a package and of modules loaded dynamically are excluded with
+    /// `ignoreExports`, glob patterns relative to the current working directory.
+    ///
+    /// ### Example
+    ///
+    /// Given `{ "unusedExports": true, "ignoreExports": ["src/index.js"] }`:
+    /// ```javascript
+    /// // src/index.js
+    /// import { used } from './utils';
+    /// export const main = used;
+    ///
+    /// // src/utils.js
+    /// export const used = 1;
+    /// export const unused = 2; // Exported declaration 'unused' is not used within other modules
+    /// ```
     NoUnusedModules,
     nursery
 );
 
 impl Rule for NoUnusedModules {
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            missing_exports: value
-                .get("missingExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-            unused_exports: value
-                .get("unusedExports")
-                .and_then(serde_json::Value::as_bool)
-                .unwrap_or(false),
-        }
+    fn from_configuration(value: Value) -> Self {
+        let config = value.get(0);
+        let flag = |name: &str| {
+            config.and_then(|config| config.get(name)).and_then(Value::as_bool).unwrap_or(false)
+        };
+        Self(Box::new(NoUnusedModulesConfig {
+            missing_exports: flag("missingExports"),
+            unused_exports: flag("unusedExports"),
+            ignore_exports: config
+                .and_then(|config| config.get("ignoreExports"))
+                .and_then(|patterns| deserialize_globs(patterns).ok())
+                .unwrap_or_default(),
+        }))
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), OxcDiagnostic> {
+        let Some(patterns) = value.get(0).and_then(|config| config.get("ignoreExports")) else {
+            return Ok(());
+        };
+        deserialize_globs(patterns).map(|_| ()).map_err(|error| {
+            OxcDiagnostic::warn(format!("Invalid `ignoreExports` patterns {patterns}: {error}"))
+        })
     }
 
     fn run_once(&self, ctx: &LintContext<'_>) {
@@ -46,10 +116,104 @@ impl Rule for NoUnusedModules {
         if self.missing_exports && module_record.local_export_entries.is_empty() {
             ctx.diagnostic(no_exports_found(Span::new(0, 0)));
         }
-        if self.unused_exports {
-            // TODO: implement unused exports
+    }
+
+    fn runs_on_project(&self) -> bool {
+        self.unused_exports
+    }
+
+    fn run_on_project(&self, ctx: &ProjectContext) {
+        let used = UsedExports::collect(ctx);
+        for file in ctx.files() {
+            let module_record = &file.module_record;
+            if module_record.not_esm || self.ignore_exports.is_match(normalize_path(&file.path)) {
+                continue;
+            }
+            let used = used.of(&module_record.resolved_absolute_path);
+            if used.is_some_and(|used| used.all) {
+                continue;
+            }
+            let exports = module_record
+                .local_export_entries
+                .iter()
+                .chain(&module_record.indirect_export_entries)
+                .filter_map(|entry| match &entry.export_name {
+                    ExportExportName::Name(name) => Some((name.name().as_str(), name.span())),
+                    ExportExportName::Default(span) => Some(("default", *span)),
+                    ExportExportName::Null => None,
+                });
+            for (name, span) in exports {
+                if !used.is_some_and(|used| used.names.contains(name)) {
+                    ctx.diagnostic(&file.path, unused_export(name, span));
+                }
+            }
+        }
+    }
+}
+
+/// The exports of each module imported or re-exported by the linted files.
+#[derive(Default)]
+struct UsedExports {
+    modules: FxHashMap<PathBuf, UsedNames>,
+}
+
+#[derive(Default)]
+struct UsedNames {
+    /// All the exports are used, e.g. by `import * as ns from "mod"` or `export * from "mod"`
+    all: bool,
+    names: FxHashSet<CompactStr>,
+}
+
+impl UsedExports {
+    fn collect(ctx: &ProjectContext) -> Self {
+        let mut used = Self::default();
+        for file in ctx.files() {
+            let module_record = &file.module_record;
+            for entry in &module_record.import_entries {
+                let name = match &entry.import_name {
+                    ImportImportName::Name(name) => Some(name.name().clone()),
+                    ImportImportName::Default(_) => Some(CompactStr::from("default")),
+                    ImportImportName::NamespaceObject => None,
+                };
+                used.add(module_record, entry.module_request.name(), name);
+            }
+            for entry in module_record
+                .indirect_export_entries
+                .iter()
+                .chain(&module_record.star_export_entries)
+            {
+                let Some(request) = &entry.module_request else { continue };
+                let name = match &entry.import_name {
+                    ExportImportName::Name(name) => Some(name.name().clone()),
+                    // The names re-exported by `export * from "mod"` are not tracked
+                    ExportImportName::All | ExportImportName::AllButDefault => None,
+                    ExportImportName::Null => continue,
+                };
+                used.add(module_record, request.name(), name);
+            }
+        }
+        used
+    }
+
+    /// Mark the export `name` of the module requested with `specifier` by `module_record` as
+    /// used, or all its exports when `name` is `None`.
+    fn add(&mut self, module_record: &ModuleRecord, specifier: &str, name: Option<CompactStr>) {
+        let Some(remote) = module_record.loaded_modules.get(specifier) else { return };
+        if remote.resolved_absolute_path == module_record.resolved_absolute_path {
+            return;
+        }
+        let used = self.modules.entry(remote.resolved_absolute_path.clone()).or_default();
+        match name {
+            Some(name) => {
+                used.names.insert(name);
+            }
+            None => used.all = true,
         }
     }
+
+    fn of(&self, path: &Path) -> Option<&UsedNames> {
+        self.modules.get(path)
+    }
 }
 
 #[test]
@@ -57,9 +221,14 @@ fn test() {
     use crate::tester::Tester;
     use serde_json::json;
 
-    let missing_exports_options = json!({
+    let missing_exports_options = json!([{
       "missingExports": true,
-    });
+    }]);
+    // The linted file is the only file of the project, none of its exports are imported
+    let unused_exports_options = json!([{
+      "unusedExports": true,
+      "ignoreExports": ["**/no-unused-modules/*ignored*.js"],
+    }]);
 
     let pass = vec![
         ("export default function noOptions() {}", None),
@@ -83,11 +252,30 @@ fn test() {
           }",
             Some(missing_exports_options.clone()),
         ),
+        ("const a = 1", Some(unused_exports_options.clone())),
+        (
+            "import { a } from './no-unused-modules/file-a'; a();",
+            Some(unused_exports_options.clone()),
+        ),
+        // The names re-exported by `export *` are unknown
+        ("export * from './no-unused-modules/file-a'", Some(unused_exports_options.clone())),
+        (
+            "// eslint-disable-next-line import/no-unused-modules
+            export const a = 1;",
+            Some(unused_exports_options.clone()),
+        ),
     ];
 
     let fail = vec![
         ("const a = 1", Some(missing_exports_options.clone())),
         ("/* const a = 1 */", Some(missing_exports_options.clone())),
+        ("export default () => 1", Some(unused_exports_options.clone())),
+        ("export const a = 1, b = 2;", Some(unused_exports_options.clone())),
+        ("const a = 1; export { a as b }", Some(unused_exports_options.clone())),
+        (
+            "export { a } from './no-unused-modules/file-a'; export * as ns from './no-unused-modules/file-b'",
+            Some(unused_exports_options),
+        ),
     ];
 
     Tester::new(NoUnusedModules::NAME, pass, fail)
@@ -95,22 +283,13 @@ fn test() {
         .with_import_plugin(true)
         .test_and_snapshot();
 
-    // TODO: support unused exports
-    // let unused_exports_options = json!({
-    //   "unusedExports": true,
-    //   "src": ["./no-unused-modules/**/*.js"],
-    //   "ignoreExports": ["./no-unused-modules/*ignored*.js"],
-    // });
-
-    // let pass = vec![
-    //     ("export default function noOptions() {}", None),
-    //     ("export default () => 1", Some(unused_exports_options)),
-    // ];
+    let pass = vec![(
+        "export const a = 1;",
+        Some(json!([{ "unusedExports": true, "ignoreExports": ["**/*ignored*.js"] }])),
+    )];
 
-    // let fail = vec![];
-
-    // Tester::new(NoUnusedModules::NAME, pass, fail)
-    //     .change_rule_path("unused-exports.js")
-    //     .with_import_plugin(true)
-    //     .test_and_snapshot();
+    Tester::new(NoUnusedModules::NAME, pass, vec![])
+        .change_rule_path("no-unused-modules/file-ignored-a.js")
+        .with_import_plugin(true)
+        .test();
 }
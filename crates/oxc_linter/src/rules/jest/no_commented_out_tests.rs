@@ -50,7 +50,7 @@ impl Rule for NoCommentedOutTests {
             static ref RE: Regex =
             Regex::new(r#"(?mu)^\s*[xf]?(test|it|describe)(\.\w+|\[['"]\w+['"]\])?\s*\("#).unwrap();
         }
-        let comments = ctx.semantic().trivias().comments();
+        let comments = ctx.comments();
         let source_text = ctx.semantic().source_text();
         let commented_tests = comments.filter_map(|(_, span)| {
             let text = span.source_text(source_text);
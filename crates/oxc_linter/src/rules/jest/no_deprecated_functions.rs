@@ -18,7 +18,8 @@ fn deprecated_function(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
 
 #[derive(Debug, Default, Clone)]
 pub struct JestConfig {
-    version: String,
+    /// Major version of Jest, defaults to the `jest.version` setting
+    version: Option<usize>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -77,6 +78,7 @@ declare_oxc_lint!(
     /// ```
     NoDeprecatedFunctions,
     style,
+    fix
 );
 
 const DEPRECATED_FUNCTIONS_MAP: Map<&'static str, (usize, &'static str)> = phf_map! {
@@ -95,17 +97,9 @@ impl Rule for NoDeprecatedFunctions {
             .and_then(|v| v.get("jest"))
             .and_then(|v| v.get("version"))
             .and_then(|v| serde_json::Value::as_str(v))
-            // Todo: Fixed Me
-            // Currently set the default version to the (maybe) latest, to help to find more problems in
-            // the codebase. In the future, the version should come from the cli option or the config files,
-            // such as `package.json` or `eslint.config.js`.
-            .unwrap_or("29");
+            .and_then(|version| version.split('.').next()?.parse().ok());
 
-        let major: Vec<&str> = version.split('.').collect();
-
-        Self(Box::new(NoDeprecatedFunctionsConfig {
-            jest: JestConfig { version: major[0].to_string() },
-        }))
+        Self(Box::new(NoDeprecatedFunctionsConfig { jest: JestConfig { version } }))
     }
 
     fn run<'a>(&self, node: &oxc_semantic::AstNode<'a>, ctx: &LintContext<'a>) {
@@ -122,8 +116,9 @@ impl Rule for NoDeprecatedFunctions {
         }
 
         let node_name = chain.join(".");
-        // Todo: read from configuration
-        let jest_version_num: usize = self.jest.version.parse().unwrap_or(29);
+        // Default to the (maybe) latest version, to help to find more problems in the codebase.
+        let jest_version_num =
+            self.jest.version.or_else(|| ctx.settings().jest.major_version()).unwrap_or(29);
 
         if let Some((base_version, replacement)) = DEPRECATED_FUNCTIONS_MAP.get(&node_name) {
             if jest_version_num >= *base_version {
@@ -138,7 +133,7 @@ impl Rule for NoDeprecatedFunctions {
 
 #[test]
 fn tests() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         ("jest", Some(serde_json::json!([{ "jest": { "version": "14" } }]))),
@@ -193,4 +188,25 @@ fn tests() {
         .with_jest_plugin(true)
         .expect_fix(fix)
         .test_and_snapshot();
+
+    // The version defaults to the `jest.version` setting
+    let pass = vec![TestCase::new("jest.genMockFromModule")
+        .with_settings(serde_json::json!({ "jest": { "version": 25 } }))];
+    let fail = vec![
+        TestCase::new("jest.genMockFromModule")
+            .with_settings(serde_json::json!({ "jest": { "version": "26.0.0" } })),
+        TestCase::new("jest.genMockFromModule")
+            .with_rule_config(serde_json::json!([{ "jest": { "version": "27" } }]))
+            .with_settings(serde_json::json!({ "jest": { "version": 25 } })),
+    ];
+    Tester::new(NoDeprecatedFunctions::NAME, pass, fail).with_jest_plugin(true).test();
+
+    // The settings of a case replace the settings shared by the cases
+    let pass = vec![TestCase::new("jest.genMockFromModule")];
+    let fail = vec![TestCase::new("jest.genMockFromModule")
+        .with_settings(serde_json::json!({ "jest": { "version": 26 } }))];
+    Tester::new(NoDeprecatedFunctions::NAME, pass, fail)
+        .with_jest_plugin(true)
+        .with_settings(serde_json::json!({ "jest": { "version": 25 } }))
+        .test();
 }
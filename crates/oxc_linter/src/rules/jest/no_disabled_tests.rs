@@ -162,9 +162,7 @@ fn run<'a>(
                 ));
             }
         } else if let Expression::Identifier(ident) = &call_expr.callee {
-            if ident.name.as_str() == "pending"
-                && ctx.semantic().is_reference_to_global_variable(ident)
-            {
+            if ctx.is_global_reference(ident, "pending") {
                 // `describe('foo', function () { pending() })`
                 let (error, help) = Message::Pending.details();
                 ctx.diagnostic(no_disabled_tests_diagnostic(
@@ -50,7 +50,8 @@ declare_oxc_lint!(
     /// `();
     /// ```
     NoFocusedTests,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoFocusedTests {
@@ -66,7 +66,7 @@ declare_oxc_lint!(
     ///
     ///   // ...
     /// });
-    ///
+    /// ```
     NoRestrictedJestMethods,
     style,
 );
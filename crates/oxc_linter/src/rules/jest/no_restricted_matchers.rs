@@ -57,7 +57,7 @@ declare_oxc_lint!(
     /// ```javascript
     ///
     /// it('is false', () => {
-    ///   if this has a modifier (i.e. `not.toBeFalsy`), it would be considered fine
+    ///   // if this has a modifier (i.e. `not.toBeFalsy`), it would be considered fine
     ///   expect(a).toBeFalsy();
     /// });
     ///
@@ -72,7 +72,7 @@ declare_oxc_lint!(
     ///     expect(uploadFileMock).not.toHaveBeenCalledWith('file.name');
     ///   });
     /// });
-    ///
+    /// ```
     NoRestrictedMatchers,
     style,
 );
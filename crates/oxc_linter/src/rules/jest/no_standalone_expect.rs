@@ -47,7 +47,8 @@ declare_oxc_lint!(
     ///
     /// Prevents `expect` statements outside of a `test` or `it` block. An `expect`
     /// within a helper function (but outside of a `test` or `it` block) will not
-    /// trigger this rule.
+    /// trigger this rule. In files which are not test files, see `settings.test`, an `expect`
+    /// within any function is considered to be in a helper.
     ///
     /// Statements like `expect.hasAssertions()` will NOT trigger this rule since these
     /// calls will execute if they are not in a test block.
@@ -148,6 +149,11 @@ fn is_correct_place_to_call_expect<'a>(
         }
     }
 
+    // The functions of files which are not test files are helpers called by tests
+    if !ctx.is_test_file() {
+        return Some(());
+    }
+
     let node = parent;
     let parent = ctx.nodes().parent_node(node.id())?;
 
@@ -237,7 +243,7 @@ fn is_var_declarator_or_test_block<'a>(
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         ("expect.any(String)", None),
@@ -390,5 +396,26 @@ fn test() {
         ),
     ];
 
-    Tester::new(NoStandaloneExpect::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
+    Tester::new(NoStandaloneExpect::NAME, pass, fail)
+        .with_jest_plugin(true)
+        .change_rule_path_extension("test.tsx")
+        .test_and_snapshot();
+
+    // Functions of files which are not test files are helpers
+    let source = "export function suite(name) { describe(name, () => { expect(1).toBe(1); }); }";
+    let pass = vec![
+        TestCase::new(source).with_path("helpers.ts"),
+        // The rules of the test frameworks don't run on other files than the configured test files
+        TestCase::new("expect(1).toBe(1);")
+            .with_path("helpers.ts")
+            .with_settings(serde_json::json!({ "test": { "patterns": ["**/*.spec.ts"] } })),
+    ];
+    let fail = vec![
+        TestCase::new(source).with_path("helpers.test.ts"),
+        TestCase::new("expect(1).toBe(1);").with_path("helpers.ts"),
+        TestCase::new(source)
+            .with_path("helpers.ts")
+            .with_settings(serde_json::json!({ "test": { "patterns": ["**/helpers.ts"] } })),
+    ];
+    Tester::new(NoStandaloneExpect::NAME, pass, fail).with_jest_plugin(true).test();
 }
@@ -37,6 +37,9 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// test('one', () => {
+    ///    return expect(1).toBe(1);
+    /// });
     /// ```
     NoTestReturnStatement,
     style,
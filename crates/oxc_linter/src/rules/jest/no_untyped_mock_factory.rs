@@ -86,6 +86,7 @@ declare_oxc_lint!(
     ///
     NoUntypedMockFactory,
     style,
+    fix
 );
 
 impl Rule for NoUntypedMockFactory {
@@ -53,6 +53,7 @@ declare_oxc_lint!(
     /// ```
     PreferJestMocked,
     style,
+    fix
 );
 
 impl Rule for PreferJestMocked {
@@ -50,6 +50,7 @@ declare_oxc_lint!(
     ///
     PreferMockPromiseShorthand,
     style,
+    fix
 );
 
 impl Rule for PreferMockPromiseShorthand {
@@ -3,7 +3,7 @@ use oxc_ast::{
         Argument, AssignmentExpression, CallExpression, Expression, MemberExpression,
         SimpleAssignmentTarget,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -53,9 +53,12 @@ declare_oxc_lint!(
     /// ```
     PreferSpyOn,
     style,
+    fix
 );
 
 impl Rule for PreferSpyOn {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::AssignmentExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentExpression(assign_expr) = node.kind() else {
             return;
@@ -363,5 +363,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(CheckAccess::NAME, pass, fail).test_and_snapshot();
+    Tester::new(CheckAccess::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
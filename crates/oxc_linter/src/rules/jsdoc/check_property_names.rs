@@ -422,5 +422,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(CheckPropertyNames::NAME, pass, fail).test_and_snapshot();
+    Tester::new(CheckPropertyNames::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
@@ -204,7 +204,7 @@ impl Rule for CheckTagNames {
         let config = &self.0;
         let user_defined_tags = settings.list_user_defined_tag_names();
 
-        let is_dts = ctx.file_path().to_str().map_or(false, |p| p.ends_with(".d.ts"));
+        let is_dts = ctx.is_declaration_file();
         // NOTE: The original rule seems to check `declare` context by visiting AST nodes.
         // https://github.com/gajus/eslint-plugin-jsdoc/blob/e343ab5b1efaa59b07c600138aee070b4083857e/src/rules/checkTagNames.js#L121
         // But...
@@ -1115,7 +1115,10 @@ fn test() {
         None,
     )];
 
-    Tester::new(CheckTagNames::NAME, pass, fail).test_and_snapshot();
+    Tester::new(CheckTagNames::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
     // Currently only 1 snapshot can be saved under a rule name
-    Tester::new(CheckTagNames::NAME, dts_pass, dts_fail).change_rule_path("test.d.ts").test();
+    Tester::new(CheckTagNames::NAME, dts_pass, dts_fail)
+        .with_jsdoc_plugin(true)
+        .change_rule_path("test.d.ts")
+        .test();
 }
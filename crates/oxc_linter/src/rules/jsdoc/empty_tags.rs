@@ -360,5 +360,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(EmptyTags::NAME, pass, fail).test_and_snapshot();
+    Tester::new(EmptyTags::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
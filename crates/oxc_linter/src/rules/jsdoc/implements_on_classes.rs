@@ -288,5 +288,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(ImplementsOnClasses::NAME, pass, fail).test_and_snapshot();
+    Tester::new(ImplementsOnClasses::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
@@ -401,5 +401,5 @@ fn test() {
         //   ),
     ];
 
-    Tester::new(NoDefaults::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoDefaults::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
@@ -1370,5 +1370,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireParam::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireParam::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
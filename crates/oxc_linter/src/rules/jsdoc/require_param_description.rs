@@ -274,5 +274,7 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireParamDescription::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireParamDescription::NAME, pass, fail)
+        .with_jsdoc_plugin(true)
+        .test_and_snapshot();
 }
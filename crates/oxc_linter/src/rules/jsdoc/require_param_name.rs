@@ -184,5 +184,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireParamName::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireParamName::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
@@ -252,5 +252,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireParamType::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireParamType::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
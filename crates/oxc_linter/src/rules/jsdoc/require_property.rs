@@ -274,5 +274,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireProperty::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireProperty::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
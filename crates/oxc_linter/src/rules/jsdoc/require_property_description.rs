@@ -198,5 +198,7 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequirePropertyDescription::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequirePropertyDescription::NAME, pass, fail)
+        .with_jsdoc_plugin(true)
+        .test_and_snapshot();
 }
@@ -159,5 +159,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequirePropertyName::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequirePropertyName::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
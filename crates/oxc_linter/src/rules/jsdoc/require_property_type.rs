@@ -184,5 +184,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequirePropertyType::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequirePropertyType::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
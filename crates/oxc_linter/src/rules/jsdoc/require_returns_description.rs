@@ -253,5 +253,7 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireReturnsDescription::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireReturnsDescription::NAME, pass, fail)
+        .with_jsdoc_plugin(true)
+        .test_and_snapshot();
 }
@@ -156,5 +156,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireReturnsType::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireReturnsType::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
@@ -1452,5 +1452,5 @@ fn test() {
         ),
     ];
 
-    Tester::new(RequireYields::NAME, pass, fail).test_and_snapshot();
+    Tester::new(RequireYields::NAME, pass, fail).with_jsdoc_plugin(true).test_and_snapshot();
 }
@@ -0,0 +1,77 @@
+use oxc_ast::{AstKind, AstType};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, utils::json_properties, AstNode};
+
+fn no_duplicate_keys_diagnostic(key: &str, span0: Span, span1: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("json(no-duplicate-keys): Duplicate key {key:?}"))
+        .with_help("Remove one of the keys, JSON parsers silently keep the last value")
+        .with_labels([span0, span1])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicateKeys;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow duplicate keys in JSON objects.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// JSON parsers keep the last value of a duplicated key and silently ignore the others,
+    /// e.g. a second `"scripts"` object in a `package.json` hides the first one.
+    ///
+    /// ### Example
+    /// ```json
+    /// {
+    ///     "name": "foo",
+    ///     "name": "bar"
+    /// }
+    /// ```
+    NoDuplicateKeys,
+    correctness
+);
+
+impl Rule for NoDuplicateKeys {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ObjectExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ObjectExpression(object) = node.kind() else {
+            return;
+        };
+        let mut keys = FxHashMap::default();
+        for (key, property) in json_properties(object) {
+            let span = property.key.span();
+            if let Some(first_span) = keys.get(&key) {
+                ctx.diagnostic(no_duplicate_keys_diagnostic(&key, *first_span, span));
+            } else {
+                keys.insert(key, span);
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"{ "name": "foo", "version": "1.0.0" }"#,
+        r#"{ "a": { "b": 1 }, "b": { "a": 1 } }"#,
+        r#"[{ "a": 1 }, { "a": 2 }]"#,
+        r#"{ "a": 1, "A": 2 }"#,
+        "// comment\n{ \"a\": 1 }",
+    ];
+
+    let fail = vec![
+        r#"{ "name": "foo", "name": "bar" }"#,
+        r#"{ "scripts": { "test": "jest", "test": "vitest" } }"#,
+        r#"[{ "a": 1, "b": 2, "a": 3, "a": 4 }]"#,
+    ];
+
+    Tester::new(NoDuplicateKeys::NAME, pass, fail).with_json_plugin(true).test_and_snapshot();
+}
@@ -0,0 +1,136 @@
+use oxc_ast::ast::Expression;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_json_property, get_package_json_root, json_properties},
+};
+
+fn sort_package_dependencies_diagnostic(
+    field: &str,
+    dependency: &str,
+    span0: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "json(sort-package-dependencies): `{field}` are not sorted alphabetically"
+    ))
+    .with_help(format!("Move {dependency:?} before the dependencies it is listed after"))
+    .with_label(span0)
+}
+
+/// The fields of a `package.json` which are sorted by npm, pnpm and yarn when they add a dependency.
+const DEPENDENCY_FIELDS: [&str; 4] =
+    ["dependencies", "devDependencies", "peerDependencies", "optionalDependencies"];
+
+#[derive(Debug, Default, Clone)]
+pub struct SortPackageDependencies;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce alphabetically sorted dependencies in a `package.json`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Package managers sort the dependencies when they add one, so unsorted dependencies
+    /// produce noisy diffs, and duplicated or missing dependencies are harder to spot.
+    ///
+    /// ### Example
+    /// ```json
+    /// {
+    ///     "dependencies": {
+    ///         "react": "^18.0.0",
+    ///         "lodash": "^4.0.0"
+    ///     }
+    /// }
+    /// ```
+    SortPackageDependencies,
+    style,
+    fix
+);
+
+impl Rule for SortPackageDependencies {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(package_json) = get_package_json_root(ctx) else {
+            return;
+        };
+        for field in DEPENDENCY_FIELDS {
+            let Some(Expression::ObjectExpression(object)) = get_json_property(package_json, field)
+            else {
+                continue;
+            };
+            let dependencies = json_properties(object).collect::<Vec<_>>();
+            if dependencies.len() != object.properties.len() {
+                continue;
+            }
+            let Some(unsorted) = dependencies.windows(2).find(|pair| pair[0].0 > pair[1].0) else {
+                continue;
+            };
+            let (name, property) = &unsorted[1];
+            ctx.diagnostic_with_fix(
+                sort_package_dependencies_diagnostic(field, name, property.key.span()),
+                |fixer| {
+                    // Keep the separators between the dependencies, e.g. their indentation
+                    let mut sorted = dependencies.iter().collect::<Vec<_>>();
+                    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+                    let source_text = ctx.source_text();
+                    let mut content = String::new();
+                    for (i, (_, dependency)) in sorted.iter().enumerate() {
+                        if i > 0 {
+                            let separator = Span::new(
+                                dependencies[i - 1].1.span.end,
+                                dependencies[i].1.span.start,
+                            );
+                            content.push_str(separator.source_text(source_text));
+                        }
+                        content.push_str(dependency.span.source_text(source_text));
+                    }
+                    let span = Span::new(
+                        dependencies[0].1.span.start,
+                        dependencies[dependencies.len() - 1].1.span.end,
+                    );
+                    fixer.replace(span, content)
+                },
+            );
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"{ "name": "foo" }"#,
+        r#"{ "dependencies": {} }"#,
+        r#"{ "dependencies": { "@babel/core": "^7.0.0", "lodash": "^4.0.0", "react": "^18.0.0" } }"#,
+        r#"{ "devDependencies": { "a": "1", "b": "1" }, "peerDependencies": { "a": "1" } }"#,
+        // Only dependencies are sorted
+        r#"{ "scripts": { "test": "jest", "build": "tsc" } }"#,
+    ];
+
+    let fail = vec![
+        r#"{ "dependencies": { "react": "^18.0.0", "lodash": "^4.0.0" } }"#,
+        r#"{ "devDependencies": { "typescript": "^5.0.0", "@types/node": "^20.0.0" } }"#,
+        r#"{ "dependencies": { "a": "1", "c": "1", "b": "1" }, "optionalDependencies": { "b": "1", "a": "1" } }"#,
+    ];
+
+    let fix = vec![
+        (
+            r#"{ "dependencies": { "react": "^18.0.0", "lodash": "^4.0.0" } }"#,
+            r#"{ "dependencies": { "lodash": "^4.0.0", "react": "^18.0.0" } }"#,
+        ),
+        (
+            "{\n  \"dependencies\": {\n    \"c\": \"1\",\n    \"a\": \"1\",\n    \"b\": \"1\"\n  }\n}",
+            "{\n  \"dependencies\": {\n    \"a\": \"1\",\n    \"b\": \"1\",\n    \"c\": \"1\"\n  }\n}",
+        ),
+    ];
+
+    Tester::new(SortPackageDependencies::NAME, pass, fail)
+        .with_json_plugin(true)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -0,0 +1,164 @@
+use oxc_ast::ast::{Expression, ObjectExpression};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_json_property, get_package_json_root, json_properties},
+};
+
+fn mixed_keys_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "json(valid-package-exports): `exports` cannot mix subpaths starting with `.` and conditions",
+    )
+    .with_help("Nest the conditions in a subpath, e.g. `\".\": { \"import\": \"./index.mjs\" }`")
+    .with_label(span0)
+}
+
+fn invalid_subpath_diagnostic(subpath: &str, span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "json(valid-package-exports): Invalid subpath {subpath:?} in `exports`"
+    ))
+    .with_help("Subpaths must be `.` or start with `./`")
+    .with_label(span0)
+}
+
+fn invalid_target_diagnostic(target: &str, span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "json(valid-package-exports): Invalid target {target:?} in `exports`"
+    ))
+    .with_help("Targets must be relative paths starting with `./`")
+    .with_label(span0)
+}
+
+fn invalid_value_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("json(valid-package-exports): Invalid value in `exports`")
+        .with_help("Values must be a path, an object of subpaths or conditions, an array or `null`")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct ValidPackageExports;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Checks that the `exports` field of a `package.json` is a valid
+    /// [package entry point](https://nodejs.org/api/packages.html#package-entry-points).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Node.js and bundlers refuse to resolve the package, or some of its entry points, when the
+    /// `exports` field mixes subpaths and conditions or points to files outside of the package.
+    ///
+    /// ### Example
+    /// ```json
+    /// {
+    ///     "exports": {
+    ///         ".": "./index.js",
+    ///         "import": "./index.mjs",
+    ///         "./utils": "utils.js"
+    ///     }
+    /// }
+    /// ```
+    ValidPackageExports,
+    correctness
+);
+
+impl Rule for ValidPackageExports {
+    fn run_once(&self, ctx: &LintContext) {
+        let Some(exports) = get_package_json_root(ctx)
+            .and_then(|package_json| get_json_property(package_json, "exports"))
+        else {
+            return;
+        };
+        match exports {
+            Expression::ObjectExpression(object) => check_exports_object(object, ctx),
+            _ => check_target(exports, ctx),
+        }
+    }
+}
+
+/// Check an object of subpaths, such as `{ ".": "./index.js" }`, or of conditions.
+fn check_exports_object(object: &ObjectExpression, ctx: &LintContext) {
+    let properties = json_properties(object).collect::<Vec<_>>();
+    let subpaths = properties.iter().filter(|(key, _)| key.starts_with('.')).count();
+    if subpaths == 0 {
+        check_conditions(object, ctx);
+        return;
+    }
+    if subpaths != properties.len() {
+        ctx.diagnostic(mixed_keys_diagnostic(object.span));
+        return;
+    }
+    for (subpath, property) in properties {
+        if subpath != "." && !subpath.starts_with("./") {
+            ctx.diagnostic(invalid_subpath_diagnostic(&subpath, property.key.span()));
+        }
+        check_target(&property.value, ctx);
+    }
+}
+
+/// Check an object of conditions, such as `{ "import": "./index.mjs" }`.
+fn check_conditions(object: &ObjectExpression, ctx: &LintContext) {
+    if json_properties(object).any(|(key, _)| key.starts_with('.')) {
+        ctx.diagnostic(mixed_keys_diagnostic(object.span));
+        return;
+    }
+    for (_, property) in json_properties(object) {
+        check_target(&property.value, ctx);
+    }
+}
+
+/// Check the target of a subpath or condition.
+fn check_target(target: &Expression, ctx: &LintContext) {
+    match target {
+        Expression::StringLiteral(path) => {
+            if !path.value.starts_with("./") {
+                ctx.diagnostic(invalid_target_diagnostic(&path.value, path.span));
+            }
+        }
+        Expression::ObjectExpression(object) => check_conditions(object, ctx),
+        Expression::ArrayExpression(array) => {
+            for element in &array.elements {
+                match element.as_expression() {
+                    Some(target) => check_target(target, ctx),
+                    None => ctx.diagnostic(invalid_value_diagnostic(element.span())),
+                }
+            }
+        }
+        Expression::NullLiteral(_) => {}
+        _ => ctx.diagnostic(invalid_value_diagnostic(target.span())),
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        r#"{ "name": "foo" }"#,
+        r#"{ "exports": "./index.js" }"#,
+        r#"{ "exports": { ".": "./index.js", "./utils": "./utils.js", "./internal/*": null } }"#,
+        r#"{ "exports": { "import": "./index.mjs", "require": "./index.cjs" } }"#,
+        r#"{ "exports": { ".": { "types": "./index.d.ts", "default": "./index.js" } } }"#,
+        r#"{ "exports": { ".": { "node": { "import": "./node.mjs" }, "default": "./index.js" } } }"#,
+        r#"{ "exports": ["./index.js", { "import": "./index.mjs" }] }"#,
+        r#"{ "exports": { "./package.json": "./package.json" } }"#,
+    ];
+
+    let fail = vec![
+        r#"{ "exports": "index.js" }"#,
+        r#"{ "exports": { ".": "./index.js", "import": "./index.mjs" } }"#,
+        r#"{ "exports": { "utils": "./utils.js", "./utils": "./utils.js" } }"#,
+        r#"{ "exports": { ".": "./index.js", ".utils": "./utils.js" } }"#,
+        r#"{ "exports": { ".": "../index.js" } }"#,
+        r#"{ "exports": { "import": { ".": "./index.mjs" } } }"#,
+        r#"{ "exports": { ".": 1 } }"#,
+        r#"{ "exports": true }"#,
+    ];
+
+    Tester::new(ValidPackageExports::NAME, pass, fail).with_json_plugin(true).test_and_snapshot();
+}
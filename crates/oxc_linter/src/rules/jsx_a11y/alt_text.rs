@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue, JSXElement, JSXOpeningElement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -133,6 +133,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for AltText {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let mut alt_text = AltTextConfig::default();
         if let Some(config) = value.get(0) {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -38,6 +38,8 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Screen readers announce anchors by their content, so anchors without
+    /// accessible content can't be understood by their users.
     ///
     /// ### Example
     ///
@@ -63,6 +65,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for AnchorHasContent {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_el) = node.kind() {
             let Some(name) = &get_element_type(ctx, &jsx_el.opening_element) else {
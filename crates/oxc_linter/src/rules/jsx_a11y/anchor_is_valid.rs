@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -122,6 +122,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for AnchorIsValid {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXElement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let valid_hrefs =
             value.get("validHrefs").and_then(|v| v.as_array()).map_or_else(Vec::new, |array| {
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttribute, JSXAttributeItem, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -55,6 +55,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for AriaActivedescendantHasTabindex {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
             return;
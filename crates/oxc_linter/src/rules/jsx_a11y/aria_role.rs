@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeValue, JSXExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -90,6 +90,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for AriaRole {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXElement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let Some(value) = value.as_array() else {
             return Self::default();
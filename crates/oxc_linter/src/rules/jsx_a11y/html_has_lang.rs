@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -53,6 +53,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for HtmlHasLang {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
@@ -93,34 +95,32 @@ fn is_valid_lang_prop(item: &JSXAttributeItem) -> bool {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     fn settings() -> serde_json::Value {
-        serde_json::json!({
-            "settings": { "jsx-a11y": {
-                "components": {
-                    "HTMLTop": "html",
-                }
-            } }
-        })
+        serde_json::json!({ "jsx-a11y": {
+            "components": {
+                "HTMLTop": "html",
+            }
+        } })
     }
 
     let pass = vec![
-        (r"<div />;", None, None, None),
-        (r#"<html lang="en" />"#, None, None, None),
-        (r#"<html lang="en-US" />"#, None, None, None),
-        (r"<html lang={foo} />;", None, None, None),
-        (r"<html lang />;", None, None, None),
-        (r"<HTML />;", None, None, None),
-        ("<HTMLTop lang='en' />", None, Some(settings()), None),
+        r"<div />;".into(),
+        r#"<html lang="en" />"#.into(),
+        r#"<html lang="en-US" />"#.into(),
+        r"<html lang={foo} />;".into(),
+        r"<html lang />;".into(),
+        r"<HTML />;".into(),
+        TestCase::new("<HTMLTop lang='en' />").with_settings(settings()),
     ];
 
     let fail = vec![
-        (r"<html />;", None, None, None),
-        (r"<html {...props} />;", None, None, None),
-        (r"<html lang={undefined} />;", None, None, None),
-        (r#"<html lang="" />;"#, None, None, None),
-        ("<HTMLTop />", None, Some(settings()), None),
+        r"<html />;".into(),
+        r"<html {...props} />;".into(),
+        r"<html lang={undefined} />;".into(),
+        r#"<html lang="" />;"#.into(),
+        TestCase::new("<HTMLTop />").with_settings(settings()),
     ];
 
     Tester::new(HtmlHasLang::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
@@ -1,7 +1,7 @@
 use language_tags::LanguageTag;
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeValue, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -58,6 +58,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for Lang {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXChild, JSXExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -58,6 +58,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for MediaHasCaption {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let mut config = MediaHasCaptionConfig::default();
 
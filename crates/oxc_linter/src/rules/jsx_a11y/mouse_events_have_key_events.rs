@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXAttributeValue, AstKind};
+use oxc_ast::{ast::JSXAttributeValue, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -64,6 +64,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for MouseEventsHaveKeyEvents {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let mut config = MouseEventsHaveKeyEventsConfig::default();
 
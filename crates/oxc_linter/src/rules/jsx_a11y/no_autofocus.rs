@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -68,6 +68,8 @@ impl NoAutofocus {
 }
 
 impl Rule for NoAutofocus {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXElement]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let mut no_focus = Self::default();
 
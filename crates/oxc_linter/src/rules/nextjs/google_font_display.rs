@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_ast::{ast::JSXElementName, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -30,18 +30,30 @@ pub struct GoogleFontDisplay;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Enforce font-display behavior with Google Fonts.
     ///
     /// ### Why is this bad?
     ///
+    /// Without a `display` parameter, or with `display=auto`, `display=block` or `display=fallback`,
+    /// the browser may hide the text while the font loads, which delays the first render of the text.
+    /// `display=optional` is recommended, `display=swap` shows the text with a fallback font instead.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <link href="https://fonts.googleapis.com/css2?family=Krona+One" rel="stylesheet" />
+    /// <link href="https://fonts.googleapis.com/css2?family=Krona+One&display=block" rel="stylesheet" />
+    ///
+    /// // Good
+    /// <link href="https://fonts.googleapis.com/css2?family=Krona+One&display=optional" rel="stylesheet" />
     /// ```
     GoogleFontDisplay,
     correctness
 );
 
 impl Rule for GoogleFontDisplay {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_ast::{ast::JSXElementName, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -22,18 +22,29 @@ pub struct GoogleFontPreconnect;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Ensure `preconnect` is used with Google Fonts.
     ///
     /// ### Why is this bad?
     ///
+    /// Preconnecting to `https://fonts.gstatic.com` lets the browser set up the connection to the
+    /// origin serving the font files early, which speeds up the loading of the fonts.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <link href="https://fonts.gstatic.com" />
+    /// <link rel="preload" href="https://fonts.gstatic.com" />
+    ///
+    /// // Good
+    /// <link rel="preconnect" href="https://fonts.gstatic.com" />
     /// ```
     GoogleFontPreconnect,
     correctness
 );
 
 impl Rule for GoogleFontPreconnect {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
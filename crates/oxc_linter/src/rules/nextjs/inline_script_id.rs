@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeItem, JSXAttributeName, ObjectPropertyKind, PropertyKey},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -21,18 +21,34 @@ pub struct InlineScriptId;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Enforce an `id` attribute on `next/script` components with inline content.
     ///
     /// ### Why is this bad?
     ///
+    /// Next.js uses the `id` to track and optimize inline scripts, without it the script may be
+    /// executed more than once.
     ///
     /// ### Example
     /// ```javascript
+    /// import Script from 'next/script';
+    ///
+    /// // Bad
+    /// export default function Page() {
+    ///   return <Script>{`console.log('Hello world');`}</Script>;
+    /// }
+    ///
+    /// // Good
+    /// export default function Page() {
+    ///   return <Script id="hello-world">{`console.log('Hello world');`}</Script>;
+    /// }
     /// ```
     InlineScriptId,
     correctness
 );
 
 impl Rule for InlineScriptId {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ImportDefaultSpecifier]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ImportDefaultSpecifier(specifier) = node.kind() else {
             return;
@@ -3,7 +3,7 @@ use oxc_ast::{
         Expression, JSXAttributeItem, JSXAttributeValue, JSXElementName, JSXExpression,
         JSXOpeningElement, ObjectProperty, ObjectPropertyKind, PropertyKey,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -28,18 +28,30 @@ pub struct NextScriptForGa;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prefer the `next/script` component when using the inline script for Google Analytics.
     ///
     /// ### Why is this bad?
     ///
+    /// `next/script` loads the Google Analytics script without blocking the rendering of the page,
+    /// an inline `<script>` may slow down the loading of the page.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <script async src="https://www.googletagmanager.com/gtag/js?id=GA_MEASUREMENT_ID" />
+    ///
+    /// // Good
+    /// import Script from 'next/script';
+    ///
+    /// <Script src="https://www.googletagmanager.com/gtag/js?id=GA_MEASUREMENT_ID" />
     /// ```
     NextScriptForGa,
     correctness
 );
 
 impl Rule for NextScriptForGa {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::BindingPatternKind, AstKind};
+use oxc_ast::{ast::BindingPatternKind, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -19,18 +19,28 @@ pub struct NoAssignModuleVariable;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent assignment to the `module` variable.
     ///
     /// ### Why is this bad?
     ///
+    /// Declaring a variable named `module` shadows the CommonJS `module` object, which Next.js relies
+    /// on to bundle the page, and breaks the build.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// let module = {};
+    ///
+    /// // Good
+    /// let moduleVariable = {};
     /// ```
     NoAssignModuleVariable,
     correctness
 );
 
 impl Rule for NoAssignModuleVariable {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::VariableDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::VariableDeclaration(variable_decl) = node.kind() else {
             return;
@@ -20,12 +20,28 @@ pub struct NoAsyncClientComponent;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent client components from being async functions.
     ///
     /// ### Why is this bad?
     ///
+    /// Async components are only supported as server components, React cannot render an async client
+    /// component, i.e. a default exported component of a file with the `"use client"` directive.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// "use client";
+    ///
+    /// export default async function MyComponent() {
+    ///   return <></>;
+    /// }
+    ///
+    /// // Good
+    /// "use client";
+    ///
+    /// export default function MyComponent() {
+    ///   return <></>;
+    /// }
     /// ```
     NoAsyncClientComponent,
     correctness
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeName, JSXAttributeValue, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -28,15 +28,26 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Scripts with the `beforeInteractive` strategy are injected into the initial HTML of the
+    /// application, they are only loaded before the page is interactive when they are placed in
+    /// `pages/_document.js`.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad, in `pages/index.js`
+    /// import Script from "next/script";
+    ///
+    /// export default function Index() {
+    ///   return <Script src="https://example.com/script.js" strategy="beforeInteractive" />;
+    /// }
     /// ```
     NoBeforeInteractiveScriptOutsideDocument,
     correctness
 );
 
 impl Rule for NoBeforeInteractiveScriptOutsideDocument {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_el) = node.kind() {
             let Some(file_path) = ctx.file_path().to_str() else {
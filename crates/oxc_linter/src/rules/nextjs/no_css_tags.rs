@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeName, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -20,18 +20,29 @@ pub struct NoCssTags;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent manual stylesheet tags.
     ///
     /// ### Why is this bad?
     ///
+    /// A `<link rel="stylesheet">` tag loads the stylesheet without the optimizations of Next.js,
+    /// such as the inlining of critical CSS, and may block the rendering of the page.
+    /// Import the stylesheet instead.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <link href="/_next/static/css/styles.css" rel="stylesheet" />
+    ///
+    /// // Good
+    /// import "../styles.css";
     /// ```
     NoCssTags,
     correctness
 );
 
 impl Rule for NoCssTags {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::ModuleDeclaration, AstKind};
+use oxc_ast::{ast::ModuleDeclaration, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -18,15 +18,21 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// `next/document` is only used on the server to render the HTML of `pages/_document.js`,
+    /// importing it in another file breaks the page.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad, in `pages/index.js`
+    /// import Document from "next/document";
     /// ```
     NoDocumentImportInPage,
     correctness
 );
 
 impl Rule for NoDocumentImportInPage {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
@@ -20,9 +20,22 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// The children of a `<head>` element are not deduplicated nor merged with the ones of the other
+    /// pages, `<Head />` from `next/head` manages the head of the document across pages.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <head>
+    ///   <title>My page title</title>
+    /// </head>
+    ///
+    /// // Good
+    /// import Head from "next/head";
+    ///
+    /// <Head>
+    ///   <title>My page title</title>
+    /// </Head>
     /// ```
     NoHeadElement,
     correctness
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::ModuleDeclaration, AstKind};
+use oxc_ast::{ast::ModuleDeclaration, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -17,18 +17,28 @@ pub struct NoHeadImportInDocument;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent usage of `next/head` in `pages/_document.js`.
     ///
     /// ### Why is this bad?
     ///
+    /// `next/head` manages the head of the pages, it does not work in `pages/_document.js`, which
+    /// renders the document with the `Head` component of `next/document`.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad, in `pages/_document.js`
+    /// import Head from "next/head";
+    ///
+    /// // Good, in `pages/_document.js`
+    /// import { Head } from "next/document";
     /// ```
     NoHeadImportInDocument,
     correctness
 );
 
 impl Rule for NoHeadImportInDocument {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_ast::{ast::JSXElementName, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -17,18 +17,31 @@ pub struct NoImgElement;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent usage of `<img>` element.
     ///
     /// ### Why is this bad?
     ///
+    /// An `<img>` element is not optimized, which results in a slower
+    /// [Largest Contentful Paint](https://nextjs.org/learn/seo/web-performance/lcp) and a higher
+    /// bandwidth. The `<Image />` component of `next/image` resizes, lazy loads and converts the images.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <img src="/test.png" alt="Test picture" />
+    ///
+    /// // Good
+    /// import Image from "next/image";
+    ///
+    /// <Image src="/test.png" alt="Test picture" width={500} height={500} />
     /// ```
     NoImgElement,
     correctness
 );
 
 impl Rule for NoImgElement {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
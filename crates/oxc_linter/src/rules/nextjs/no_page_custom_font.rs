@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Class, Function, JSXAttributeItem, JSXAttributeValue, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -33,12 +33,24 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad, in `pages/index.js`
+    /// import Head from "next/head";
+    ///
+    /// export default function IndexPage() {
+    ///   return (
+    ///     <Head>
+    ///       <link href="https://fonts.googleapis.com/css2?family=Inter" rel="stylesheet" />
+    ///     </Head>
+    ///   );
+    /// }
     /// ```
     NoPageCustomFont,
     correctness,
 );
 
 impl Rule for NoPageCustomFont {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(element) = node.kind() else {
             return;
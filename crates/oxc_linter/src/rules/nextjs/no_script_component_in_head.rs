@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ImportDeclarationSpecifier, JSXChild, JSXElementName, ModuleDeclaration},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -20,18 +20,39 @@ pub struct NoScriptComponentInHead;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent usage of `next/script` in the `next/head` component.
     ///
     /// ### Why is this bad?
     ///
+    /// The `next/script` component manages the loading of the script itself, it does not work in
+    /// `next/head`.
     ///
     /// ### Example
     /// ```javascript
+    /// import Head from "next/head";
+    /// import Script from "next/script";
+    ///
+    /// // Bad
+    /// export default function Index() {
+    ///   return (
+    ///     <Head>
+    ///       <Script src="/script.js" />
+    ///     </Head>
+    ///   );
+    /// }
+    ///
+    /// // Good
+    /// export default function Index() {
+    ///   return <Script src="/script.js" />;
+    /// }
     /// ```
     NoScriptComponentInHead,
     correctness
 );
 
 impl Rule for NoScriptComponentInHead {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
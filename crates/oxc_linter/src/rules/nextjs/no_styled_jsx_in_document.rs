@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -28,12 +28,22 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad, in `pages/_document.js`
+    /// <Html>
+    ///   <Head />
+    ///   <style jsx>{`body { color: red; }`}</style>
+    ///   <body>
+    ///     <Main />
+    ///   </body>
+    /// </Html>
     /// ```
     NoStyledJsxInDocument,
     correctness,
 );
 
 impl Rule for NoStyledJsxInDocument {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(element) = node.kind() else {
             return;
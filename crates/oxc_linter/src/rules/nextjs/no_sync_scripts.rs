@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{JSXAttributeItem, JSXAttributeName, JSXElementName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -21,18 +21,30 @@ pub struct NoSyncScripts;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent synchronous scripts.
     ///
     /// ### Why is this bad?
     ///
+    /// A script without the `async` or `defer` attribute blocks the parsing and the rendering of the
+    /// page while it is loaded and executed.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// <script src="https://example.com/script.js" />
+    ///
+    /// // Good
+    /// import Script from "next/script";
+    ///
+    /// <Script src="https://example.com/script.js" />
     /// ```
     NoSyncScripts,
     correctness
 );
 
 impl Rule for NoSyncScripts {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_opening_element) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ImportDeclarationSpecifier, JSXChild, JSXElementName, ModuleDeclaration},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -20,18 +20,36 @@ pub struct NoTitleInDocumentHead;
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Prevent usage of `<title>` with the `Head` component from `next/document`.
     ///
     /// ### Why is this bad?
     ///
+    /// The `Head` component of `next/document` renders the parts of the head which are common to all
+    /// pages, the title of a page should be set with the `Head` component of `next/head`.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// import { Head } from "next/document";
+    ///
+    /// <Head>
+    ///   <title>My page title</title>
+    /// </Head>
+    ///
+    /// // Good
+    /// import Head from "next/head";
+    ///
+    /// <Head>
+    ///   <title>My page title</title>
+    /// </Head>
     /// ```
     NoTitleInDocumentHead,
     correctness
 );
 
 impl Rule for NoTitleInDocumentHead {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ModuleDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(import_decl)) =
             node.kind()
@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use phf::phf_set;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::min_distance, AstNode};
 
 fn no_typos_diagnostic(x0: &str, x1: &str, span2: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn(format!(
@@ -26,6 +26,8 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Next.js only calls the data fetching functions exported with their exact name, a misspelled
+    /// function is silently ignored and the page is rendered without its data.
     ///
     /// ### Example
     /// ```javascript
@@ -111,34 +113,6 @@ fn get_potential_typo(fn_name: &str) -> Option<&str> {
     potential_typos.first().map(|(option, _)| *option)
 }
 
-// the minimum number of operations required to convert string a to string b.
-fn min_distance(a: &str, b: &str) -> usize {
-    let m = a.len();
-    let n = b.len();
-
-    if m < n {
-        return min_distance(b, a);
-    }
-
-    if n == 0 {
-        return m;
-    }
-
-    let mut previous_row: Vec<usize> = (0..=n).collect();
-
-    for (i, s1) in a.chars().enumerate() {
-        let mut current_row = vec![i + 1];
-        for (j, s2) in b.chars().enumerate() {
-            let insertions = previous_row[j + 1] + 1;
-            let deletions = current_row[j] + 1;
-            let substitutions = previous_row[j] + usize::from(s1 != s2);
-            current_row.push(insertions.min(deletions).min(substitutions));
-        }
-        previous_row = current_row;
-    }
-    previous_row[n]
-}
-
 #[test]
 fn test() {
     use std::path::PathBuf;
@@ -2,7 +2,7 @@ use std::f64::consts as f64;
 
 // Based on https://github.com/rust-lang/rust-clippy//blob/c9a43b18f11219fa70fe632b29518581fcd589c8/clippy_lints/src/approx_const.rs
 // https://rust-lang.github.io/rust-clippy/master/#approx_constant
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -29,12 +29,15 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// let log10e = 0.434294;
     /// ```
     ApproxConstant,
     suspicious
 );
 
 impl Rule for ApproxConstant {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NumericLiteral]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NumericLiteral(number_literal) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, RegExpFlags},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -47,6 +47,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for BadReplaceAllArg {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 
 use oxc_ast::{
     ast::{Expression, NumericLiteral},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -72,6 +72,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ConstComparisons {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::LogicalExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::LogicalExpression(logical_expr) = node.kind() else {
             return;
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -36,9 +36,12 @@ declare_oxc_lint!(
     /// ```
     NoConstEnum,
     restriction,
+    fix
 );
 
 impl Rule for NoConstEnum {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSEnumDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSEnumDeclaration(enum_decl) = node.kind() {
             if !enum_decl.r#const {
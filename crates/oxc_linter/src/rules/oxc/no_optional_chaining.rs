@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -67,6 +67,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoOptionalChaining {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ChainExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let config = value.get(0);
         let message = config
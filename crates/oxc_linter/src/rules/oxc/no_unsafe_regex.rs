@@ -0,0 +1,115 @@
+use oxc_allocator::Allocator;
+use oxc_ast::AstType;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_js_regex::{
+    ast::Quantifier,
+    visitor::{walk::walk_quantifier, Visit},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::parse_regexp, AstNode};
+
+fn no_unsafe_regex_diagnostic(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("oxc(no-unsafe-regex): Unsafe regular expression")
+        .with_help("A quantifier repeating another quantifier can take exponential time to match some inputs (ReDoS). Rewrite the pattern so that no unbounded repetition is nested in another one.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeRegex;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow regular expressions with an unbounded quantifier, `*`, `+` or `{n,}`, nested in
+    /// another unbounded quantifier, e.g. `/(a+)+/`, like the `safe-regex` package.
+    /// The literals and the `RegExp` calls with a string literal pattern are checked.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The backtracking of the regular expression engine tries every way of splitting the input
+    /// between the nested repetitions before a match fails, which takes exponential time in the
+    /// length of the input. An attacker sending a long string which almost matches blocks the
+    /// event loop, a Regular Expression Denial of Service (ReDoS).
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const email = /^([a-z0-9]+\.?)*@example\.com$/;
+    ///
+    /// // Good
+    /// const email = /^[a-z0-9.]*@example\.com$/;
+    /// ```
+    NoUnsafeRegex,
+    restriction
+);
+
+impl Rule for NoUnsafeRegex {
+    const NODE_TYPES: Option<&'static [AstType]> =
+        Some(&[AstType::RegExpLiteral, AstType::NewExpression, AstType::CallExpression]);
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let allocator = Allocator::default();
+        // Invalid patterns are reported by `no-invalid-regexp`
+        let Some(Ok((pattern, _))) = parse_regexp(&allocator, node.kind(), ctx) else { return };
+        let mut finder = NestedQuantifierFinder::default();
+        finder.visit_pattern(&pattern);
+        if finder.found {
+            ctx.diagnostic(no_unsafe_regex_diagnostic(node.kind().span()));
+        }
+    }
+}
+
+/// Finds the unbounded quantifiers in the element of another unbounded quantifier.
+#[derive(Default)]
+struct NestedQuantifierFinder {
+    /// Number of unbounded quantifiers around the visited node
+    depth: usize,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for NestedQuantifierFinder {
+    fn visit_quantifier(&mut self, quantifier: &Quantifier<'a>) {
+        if quantifier.max.is_finite() {
+            walk_quantifier(self, quantifier);
+            return;
+        }
+        self.found |= self.depth > 0;
+        self.depth += 1;
+        walk_quantifier(self, quantifier);
+        self.depth -= 1;
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = /a+b*/;", None),
+        ("var foo = /(a+)b/;", None),
+        ("var foo = /(?:ab)+/;", None),
+        ("var foo = /(a{1,3})+/;", None),
+        ("var foo = /(a+)?/;", None),
+        ("var foo = /^\\d+(\\.\\d+)?$/;", None),
+        ("var foo = /(a+){2}/;", None),
+        ("var foo = new RegExp(\"(a+)b\");", None),
+        ("var foo = new RegExp(pattern);", None),
+        ("var foo = new RegExp(\"(a+\");", None),
+        ("var foo = new Foo(\"(a+)+\");", None),
+    ];
+
+    let fail = vec![
+        ("var foo = /(a+)+/;", None),
+        ("var foo = /(a*)*b/;", None),
+        ("var foo = /(\\w+\\s?)*$/;", None),
+        ("var foo = /(?:a|b+)*/;", None),
+        ("var foo = /((ab)*c)*/;", None),
+        ("if (/(x+x+)+y/.test(foo)) {}", None),
+        ("var foo = new RegExp(\"(x+x+)+y\");", None),
+        ("var foo = RegExp(\"(a+){2,}\");", None),
+    ];
+
+    Tester::new(NoUnsafeRegex::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Argument, AstKind};
+use oxc_ast::{ast::Argument, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -35,6 +35,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NumberArgOutOfRange {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(expr) = node.kind() else {
             return;
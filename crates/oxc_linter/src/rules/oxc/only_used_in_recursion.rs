@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingIdentifier, BindingPatternKind, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -59,6 +59,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for OnlyUsedInRecursion {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::Function]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::Function(function) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -39,6 +39,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for UninvokedArrayCallback {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NewExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::NewExpression(new_expr) = node.kind() else {
             return;
@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -50,6 +50,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoCommentTextnodes {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXText]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXText(jsx_text) = node.kind() else {
             return;
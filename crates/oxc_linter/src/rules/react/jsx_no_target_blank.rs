@@ -5,7 +5,7 @@ use oxc_ast::{
         match_expression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
         JSXElementName, JSXExpression, StringLiteral,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -119,6 +119,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoTargetBlank {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXOpeningElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXOpeningElement(jsx_ele) = node.kind() {
             if let JSXElementName::Identifier(tag_identifier) = &jsx_ele.name {
@@ -63,7 +63,7 @@ impl Rule for JsxNoUselessFragment {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         match node.kind() {
             AstKind::JSXElement(jsx_elem) => {
-                if !is_jsx_fragment(&jsx_elem.opening_element) {
+                if !is_jsx_fragment(&jsx_elem.opening_element, ctx) {
                     return;
                 }
                 self.check_element(node, jsx_elem, ctx);
@@ -158,11 +158,13 @@ fn is_html_element(elem_name: &JSXElementName) -> bool {
     ident.name.starts_with(char::is_lowercase)
 }
 
-fn is_jsx_fragment(elem: &JSXOpeningElement) -> bool {
+/// `<Fragment>` or `<React.Fragment>`, with the names of the JSX pragmas.
+fn is_jsx_fragment(elem: &JSXOpeningElement, ctx: &LintContext) -> bool {
+    let fragment = ctx.jsx_fragment();
     match &elem.name {
-        JSXElementName::Identifier(ident) => ident.name.as_str() == "Fragment",
+        JSXElementName::Identifier(ident) => ident.name.as_str() == fragment,
         JSXElementName::MemberExpression(mem_expr) => {
-            if mem_expr.property.name.as_str() != "Fragment" {
+            if mem_expr.property.name.as_str() != fragment {
                 return false;
             }
 
@@ -170,7 +172,7 @@ fn is_jsx_fragment(elem: &JSXOpeningElement) -> bool {
                 return false;
             };
 
-            return ident.name.as_str() == "React";
+            return ident.name.as_str() == ctx.jsx_pragma();
         }
         JSXElementName::NamespacedName(_) => false,
     }
@@ -217,7 +219,7 @@ fn is_fragment_with_only_text_and_is_not_child<'a>(
 fn test() {
     use serde_json::json;
 
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         (r"<><Foo /><Bar /></>", None),
@@ -309,4 +311,19 @@ fn test() {
     ];
 
     Tester::new(JsxNoUselessFragment::NAME, pass, fail).test_and_snapshot();
+
+    // The JSX fragment of a comment or of the settings
+    let settings = json!({ "react": { "pragma": "Preact", "fragment": "Frag" } });
+    let pass = vec![
+        TestCase::new("<Fragment />").with_settings(settings.clone()),
+        TestCase::new("<React.Frag />").with_settings(settings.clone()),
+        TestCase::new("/** @jsxFrag Frag */ <Fragment />"),
+    ];
+    let fail = vec![
+        TestCase::new("<Frag />").with_settings(settings.clone()),
+        TestCase::new("<Preact.Frag />").with_settings(settings),
+        TestCase::new("/** @jsxFrag Frag */ <Frag />"),
+        TestCase::new("/** @jsx preact.h */ /** @jsxFrag preact.Frag */ <preact.Frag />"),
+    ];
+    Tester::new(JsxNoUselessFragment::NAME, pass, fail).test();
 }
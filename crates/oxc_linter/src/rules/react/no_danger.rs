@@ -33,6 +33,7 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// <div dangerouslySetInnerHTML={{ __html: "Hello World" }}></div>;
     /// ```
     NoDanger,
     restriction
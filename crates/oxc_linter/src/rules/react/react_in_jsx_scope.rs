@@ -5,9 +5,9 @@ use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
-fn react_in_jsx_scope_diagnostic(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("eslint-plugin-react(react-in-jsx-scope): 'React' must be in scope when using JSX")
-        .with_help("When using JSX, `<a />` expands to `React.createElement(\"a\")`. Therefore the `React` variable must be in scope.")
+fn react_in_jsx_scope_diagnostic(span0: Span, pragma: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint-plugin-react(react-in-jsx-scope): '{pragma}' must be in scope when using JSX"))
+        .with_help(format!("When using JSX, `<a />` expands to `{pragma}.createElement(\"a\")`. Therefore the `{pragma}` variable must be in scope."))
         .with_label(span0)
 }
 
@@ -33,6 +33,9 @@ declare_oxc_lint!(
     /// var a = <a />;
     ///
     /// ```
+    ///
+    /// The variable is the JSX pragma, `h` with a `/** @jsx h */` comment or with
+    /// `settings.react.pragma` set to `h`.
     ReactInJsxScope,
     suspicious
 );
@@ -45,7 +48,7 @@ impl Rule for ReactInJsxScope {
             _ => return,
         };
         let scope = ctx.scopes();
-        let react_name = "React";
+        let react_name = ctx.jsx_pragma();
         if scope.get_binding(scope.root_scope_id(), react_name).is_some() {
             return;
         }
@@ -54,14 +57,16 @@ impl Rule for ReactInJsxScope {
             .ancestors(node.scope_id())
             .any(|v| scope.get_bindings(v).iter().any(|(k, _)| k.as_str() == react_name))
         {
-            ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span));
+            ctx.diagnostic(react_in_jsx_scope_diagnostic(node_span, react_name));
         }
     }
 }
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use serde_json::json;
+
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         ("var React, App; <App />;", None),
@@ -97,4 +102,18 @@ fn test() {
     ];
 
     Tester::new(ReactInJsxScope::NAME, pass, fail).test_and_snapshot();
+
+    // The JSX pragma of a comment or of the settings
+    let settings = json!({ "react": { "pragma": "h" } });
+    let pass = vec![
+        TestCase::new("/** @jsx h */ import { h } from 'preact'; <a />;"),
+        TestCase::new("/** @jsx preact.h */ import * as preact from 'preact'; <a />;"),
+        TestCase::new("import { h } from 'preact'; <a />;").with_settings(settings.clone()),
+        TestCase::new("/** @jsx jsx */ var jsx; <a />;").with_settings(settings.clone()),
+    ];
+    let fail = vec![
+        TestCase::new("/** @jsx h */ var React; <a />;"),
+        TestCase::new("var React; <a />;").with_settings(settings),
+    ];
+    Tester::new(ReactInJsxScope::NAME, pass, fail).test();
 }
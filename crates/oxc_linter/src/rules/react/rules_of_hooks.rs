@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ArrowFunctionExpression, Function},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_cfg::{
     graph::{algo, visit::Control},
@@ -106,6 +106,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for RulesOfHooks {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call) = node.kind() else { return };
 
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Expression, JSXAttributeValue, JSXElement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -37,6 +37,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for JsxNoJsxAsProp {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::JSXElement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::JSXElement(jsx_elem) = node.kind() {
             check_jsx_element(jsx_elem, ctx);
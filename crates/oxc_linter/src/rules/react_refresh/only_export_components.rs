@@ -1,478 +1,584 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
+use oxc_ast::{
+    ast::{
+        Argument, BindingPatternKind, CallExpression, Declaration, ExportDefaultDeclarationKind,
+        Expression, Statement, VariableDeclaration,
+    },
+    AstKind,
+};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule};
+use crate::{context::LintContext, rule::Rule, AstNode, ModuleFormat};
 
 lazy_static! {
     static ref POSSIBLE_REACT_EXPORT_RE: Regex = Regex::new(r"^[A-Z][a-zA-Z0-9]*$").unwrap();
     static ref STRICT_REACT_EXPORT_RE: Regex =
         Regex::new(r"^[A-Z][a-zA-Z0-9]*[a-z]+[a-zA-Z0-9]*$").unwrap();
-    static ref REACT_HOCS: [&'static str; 2] = ["with", "forwardRef"];
 }
 
-#[derive(Debug, Clone, Default)]
-pub struct OnlyExportComponents {}
+/// Higher-order components of React which return a component.
+const REACT_HOCS: [&str; 3] = ["memo", "forwardRef", "lazy"];
+
+fn report_export_all(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): This rule can't verify that `export *` only exports components.")
+        .with_label(span0)
+}
+
+fn report_named_exports(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when a file only exports components.")
+        .with_help("Use a new file to share constants or functions between components.")
+        .with_label(span0)
+}
+
+fn report_anonymous_export(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh can't handle anonymous components.")
+        .with_help("Add a name to your export.")
+        .with_label(span0)
+}
+
+fn report_local_components(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when a file only exports components.")
+        .with_help("Move your component(s) to a separate file.")
+        .with_label(span0)
+}
+
+fn report_no_export(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when a file has exports.")
+        .with_help("Move your component(s) to a separate file.")
+        .with_label(span0)
+}
+
+fn report_react_context(span0: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when a file only exports components.")
+        .with_help("Move your React context(s) to a separate file.")
+        .with_label(span0)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OnlyExportComponents(Box<OnlyExportComponentsConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct OnlyExportComponentsConfig {
+    /// Names of non-component exports which are allowed, e.g. `loader` and `meta` in Remix.
+    allow_export_names: Vec<String>,
+    /// Allow exporting primitive constants, e.g. `export const CONSTANT = 3`.
+    allow_constant_export: bool,
+    /// Names of additional higher-order components, e.g. `observer` of MobX.
+    custom_hocs: Vec<String>,
+}
+
+impl std::ops::Deref for OnlyExportComponents {
+    type Target = OnlyExportComponentsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
 
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Validates that a file only exports React components, so it can be safely updated
+    /// with fast refresh.
+    ///
+    /// Calls of `memo`, `forwardRef`, `lazy` and `with*` HOCs such as `withRouter` are recognized
+    /// as components. Additional HOCs can be configured with `customHOCs`:
+    ///
+    /// ```json
+    /// {
+    ///   "react-refresh/only-export-components": ["warn", { "customHOCs": ["observer"] }]
+    /// }
+    /// ```
+    ///
+    /// `allowExportNames` allows specific non-component exports, and `allowConstantExport`
+    /// allows exporting primitive constants.
     ///
     /// ### Why is this bad?
     ///
+    /// Fast refresh falls back to a full reload when a module exports anything besides
+    /// components, e.g. constants, functions or React contexts, losing the component state.
     ///
     /// ### Example
     /// ```javascript
+    /// export const foo = () => {};
+    /// export const Bar = () => <></>;
+    /// export const ThemeContext = createContext(null);
     /// ```
     OnlyExportComponents,
-    correctness, // TODO: change category to `correctness`, `suspicious`, `pedantic`, `perf`, `restriction`, or `style`
-             // See <https://oxc.rs/docs/contribute/linter.html#rule-category> for details
+    correctness
 );
 
-fn report_export_all(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): This rule can't verify that `export *` only exports components.")
-        .with_label(span0)
-}
-
-// fn report_named_exports(span0: Span) -> OxcDiagnostic {
-//     OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when afile only exports components.")
-//     .with_help("Use a new file to share constants or functions between components.")
-//         .with_label(span0)
-// }
-
-// fn report_anonymous_export(span0: Span) -> OxcDiagnostic {
-//     OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh can't handle anonymous components.")
-//     .with_help("Add a name to your export.")
-//         .with_label(span0)
-// }
-
-// fn report_local_components(span0: Span) -> OxcDiagnostic {
-//     OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when a file only exports components.")
-//         .with_help("Move your component(s) to a separate file.")
-//         .with_label(span0)
-// }
-
-// fn report_no_export(span0: Span) -> OxcDiagnostic {
-//     OxcDiagnostic::warn("eslint-plugin-react-refresh(only-export-components): Fast refresh only works when a file has exports.")
-//     .with_help("Move you component(s) to a separate file")
-//         .with_label(span0)
-// }
-
-// #[derive(Debug, Clone)]
-// enum BindingPatterOrIdentifier<'a> {
-//     BindingIdentifier(&'a BindingIdentifier<'a>),
-//     BindingPattern(&'a BindingPattern<'a>),
-//     IdentifierReference(&'a IdentifierReference<'a>),
-// }
-
 impl Rule for OnlyExportComponents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let config = value.get(0);
+        let strings = |key: &str| {
+            config
+                .and_then(|config| config.get(key))
+                .and_then(serde_json::Value::as_array)
+                .map(|names| {
+                    names.iter().filter_map(serde_json::Value::as_str).map(String::from).collect()
+                })
+                .unwrap_or_default()
+        };
+        Self(Box::new(OnlyExportComponentsConfig {
+            allow_export_names: strings("allowExportNames"),
+            allow_constant_export: config
+                .and_then(|config| config.get("allowConstantExport"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            custom_hocs: strings("customHOCs"),
+        }))
+    }
+
     fn run_once(&self, ctx: &LintContext) {
-        let module_record = ctx.module_record();
+        // CommonJS modules have no exports to check. Without an extension or a package `"type"`
+        // telling the format, files without import / export are not ES modules.
+        let not_esm = ctx
+            .module_format()
+            .map_or(ctx.module_record().not_esm, |format| format == ModuleFormat::CommonJs);
+        if not_esm {
+            return;
+        }
 
-        // If there's no export / import, there's no point in running this rule
-        if module_record.not_esm {
+        // We only care about React files, e.g. TSX / JSX files or files importing React
+        if !ctx.frameworks().is_react() {
             return;
         }
 
-        // We only care about TSX / JSX files
-        match module_record.resolved_absolute_path.extension() {
-            Some(ext) => {
-                if ext != "tsx" && ext != "jsx" {
-                    return;
+        let file_name = ctx.file_path().file_name().and_then(|name| name.to_str()).unwrap_or("");
+        if [".test.", ".spec.", ".cy.", ".stories."].iter().any(|part| file_name.contains(part)) {
+            return;
+        }
+
+        let Some(AstKind::Program(program)) = ctx.nodes().root_node().map(AstNode::kind) else {
+            return;
+        };
+
+        let mut exports = ExportAnalysis::default();
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportAllDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    exports.has_exports = true;
+                    ctx.diagnostic(report_export_all(decl.span));
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    exports.has_exports = true;
+                    match &decl.declaration {
+                        ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                            if let Some(id) = &func.id {
+                                self.handle_export_identifier(
+                                    &mut exports,
+                                    &id.name,
+                                    id.span,
+                                    true,
+                                    None,
+                                );
+                            } else {
+                                ctx.diagnostic(report_anonymous_export(decl.span));
+                            }
+                        }
+                        kind => match kind.as_expression().map(Expression::get_inner_expression) {
+                            Some(Expression::Identifier(ident)) => {
+                                self.handle_export_identifier(
+                                    &mut exports,
+                                    &ident.name,
+                                    ident.span,
+                                    false,
+                                    None,
+                                );
+                            }
+                            Some(Expression::CallExpression(call)) => {
+                                self.handle_export_call(&mut exports, call, ctx);
+                            }
+                            Some(Expression::ArrowFunctionExpression(_)) => {
+                                ctx.diagnostic(report_anonymous_export(decl.span));
+                            }
+                            _ => {}
+                        },
+                    }
+                }
+                Statement::ExportNamedDeclaration(decl) => {
+                    if decl.export_kind.is_type() {
+                        continue;
+                    }
+                    exports.has_exports = true;
+                    match &decl.declaration {
+                        Some(Declaration::VariableDeclaration(decl)) => {
+                            self.handle_export_variables(&mut exports, decl, ctx);
+                        }
+                        Some(Declaration::FunctionDeclaration(func)) => {
+                            if let Some(id) = &func.id {
+                                self.handle_export_identifier(
+                                    &mut exports,
+                                    &id.name,
+                                    id.span,
+                                    true,
+                                    None,
+                                );
+                            }
+                        }
+                        Some(Declaration::TSEnumDeclaration(decl)) => {
+                            exports.non_component_exports.push(decl.id.span);
+                        }
+                        _ => {}
+                    }
+                    for specifier in &decl.specifiers {
+                        // `export { App as default }` exports the component `App`
+                        let name = if specifier.exported.name() == "default" {
+                            &specifier.local
+                        } else {
+                            &specifier.exported
+                        };
+                        self.handle_export_identifier(
+                            &mut exports,
+                            &name.name(),
+                            name.span(),
+                            false,
+                            None,
+                        );
+                    }
+                }
+                Statement::VariableDeclaration(decl) => {
+                    for declarator in &decl.declarations {
+                        if let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind {
+                            exports.handle_local_identifier(&id.name, id.span);
+                        }
+                    }
+                }
+                Statement::FunctionDeclaration(func) => {
+                    if let Some(id) = &func.id {
+                        exports.handle_local_identifier(&id.name, id.span);
+                    }
                 }
+                _ => {}
             }
-            None => {
-                return;
+        }
+
+        if exports.has_exports {
+            if exports.may_have_react_export {
+                for span in exports.non_component_exports {
+                    ctx.diagnostic(report_named_exports(span));
+                }
+                for span in exports.react_context_exports {
+                    ctx.diagnostic(report_react_context(span));
+                }
+            } else {
+                for span in exports.local_components {
+                    ctx.diagnostic(report_local_components(span));
+                }
+            }
+        } else {
+            for span in exports.local_components {
+                ctx.diagnostic(report_no_export(span));
             }
         }
+    }
+}
+
+/// Exports and local components collected from the top level of a module.
+#[derive(Default)]
+struct ExportAnalysis {
+    has_exports: bool,
+    may_have_react_export: bool,
+    local_components: Vec<Span>,
+    non_component_exports: Vec<Span>,
+    react_context_exports: Vec<Span>,
+}
 
-        // No export * are allowed
-        for star_export in &module_record.star_export_entries {
-            // println!("{:#?}", star_export);
-            let Some(module_request) = &star_export.module_request else {
+impl ExportAnalysis {
+    fn handle_local_identifier(&mut self, name: &str, span: Span) {
+        if POSSIBLE_REACT_EXPORT_RE.is_match(name) {
+            self.local_components.push(span);
+        }
+    }
+}
+
+impl OnlyExportComponents {
+    fn handle_export_variables(
+        &self,
+        exports: &mut ExportAnalysis,
+        decl: &VariableDeclaration,
+        ctx: &LintContext,
+    ) {
+        for declarator in &decl.declarations {
+            let BindingPatternKind::BindingIdentifier(id) = &declarator.id.kind else {
+                exports.non_component_exports.push(declarator.id.span());
                 continue;
             };
+            let init = declarator.init.as_ref().map(Expression::get_inner_expression);
+            let is_function = match init {
+                Some(Expression::ArrowFunctionExpression(_)) => true,
+                Some(Expression::CallExpression(call)) => self.is_hoc_call(call, ctx),
+                _ => false,
+            };
+            self.handle_export_identifier(exports, &id.name, id.span, is_function, init);
+        }
+    }
 
-            let requested_modules = module_record.requested_modules.get(module_request.name());
+    /// `export default memo(function Foo() {})`
+    fn handle_export_call(
+        &self,
+        exports: &mut ExportAnalysis,
+        call: &CallExpression,
+        ctx: &LintContext,
+    ) {
+        // `connect(mapStateToProps)(Component)` of react-redux
+        if let Expression::CallExpression(callee) = &call.callee {
+            if callee.callee_name() == Some("connect") {
+                exports.may_have_react_export = true;
+                return;
+            }
+        }
 
-            if let Some(requested_modules) = requested_modules {
-                for requested_module in requested_modules {
-                    // println!("Requested module {:#?}", requested_module);
-                    if !requested_module.is_import && !requested_module.is_type {
-                        println!("should fail");
-                        ctx.diagnostic(report_export_all(star_export.span));
-                    }
-                }
+        if !self.is_hoc_call(call, ctx) {
+            ctx.diagnostic(report_anonymous_export(call.span));
+            return;
+        }
+
+        match call.arguments.first() {
+            Some(Argument::FunctionExpression(func)) if func.id.is_some() => {
+                let id = func.id.as_ref().unwrap();
+                self.handle_export_identifier(exports, &id.name, id.span, true, None);
+            }
+            // `memo(Foo)`
+            Some(Argument::Identifier(_)) => exports.may_have_react_export = true,
+            // `lazy(() => import('./Foo'))`
+            Some(Argument::ArrowFunctionExpression(_)) if call.callee_name() == Some("lazy") => {
+                exports.may_have_react_export = true;
             }
+            _ => ctx.diagnostic(report_anonymous_export(call.span)),
+        }
+    }
+
+    fn handle_export_identifier(
+        &self,
+        exports: &mut ExportAnalysis,
+        name: &str,
+        span: Span,
+        is_function: bool,
+        init: Option<&Expression>,
+    ) {
+        if self.allow_export_names.iter().any(|allowed| allowed == name) {
+            return;
+        }
+
+        if self.allow_constant_export
+            && init.is_some_and(|init| {
+                init.is_literal()
+                    || matches!(
+                        init,
+                        Expression::TemplateLiteral(_) | Expression::BinaryExpression(_)
+                    )
+            })
+        {
+            return;
         }
 
-        // let mut may_have_react_export = false;
-        // let mut react_is_in_scope = false;
-        // let mut local_components: Vec<IdentifierReference> = vec![];
-        // let mut non_component_exports: Vec<BindingPatterOrIdentifier> = vec![];
-
-        //     for node in ctx.nodes().iter() {
-        //         match node.kind() {
-        //             AstKind::ExportDefaultDeclaration(export_default_declaration) => {
-        //                 // match export_default_declaration.declaration {
-        //                 //     ExportDefaultDeclarationKind::Identifier(identifier_reference) => {
-        //                 //         handle_export_identifier(
-        //                 //             BindingPatterOrIdentifier::IdentifierReference(
-        //                 //                 identifier_reference,
-        //                 //             ),
-        //                 //             None,
-        //                 //             None,
-        //                 //             &mut non_component_exports,
-        //                 //             &mut may_have_react_export,
-        //                 //             &self.allow_export_names,
-        //                 //             self.allow_constant_export,
-        //                 //         );
-        //                 //     }
-        //                 // }
-        //             }
-        //             _ => {}
-        //         }
-        //     }
+        if is_function {
+            if POSSIBLE_REACT_EXPORT_RE.is_match(name) {
+                exports.may_have_react_export = true;
+            } else {
+                exports.non_component_exports.push(span);
+            }
+            return;
+        }
+
+        if let Some(Expression::CallExpression(call)) = init {
+            if call.callee_name() == Some("createContext") {
+                exports.react_context_exports.push(span);
+                return;
+            }
+        }
+
+        if init.is_some_and(|init| {
+            init.is_literal()
+                || matches!(
+                    init,
+                    Expression::ArrayExpression(_)
+                        | Expression::AwaitExpression(_)
+                        | Expression::BinaryExpression(_)
+                        | Expression::ChainExpression(_)
+                        | Expression::ConditionalExpression(_)
+                        | Expression::LogicalExpression(_)
+                        | Expression::ObjectExpression(_)
+                        | Expression::TemplateLiteral(_)
+                        | Expression::ThisExpression(_)
+                        | Expression::UnaryExpression(_)
+                        | Expression::UpdateExpression(_)
+                )
+        }) {
+            exports.non_component_exports.push(span);
+            return;
+        }
+
+        if POSSIBLE_REACT_EXPORT_RE.is_match(name) {
+            exports.may_have_react_export = true;
+        }
+        if !STRICT_REACT_EXPORT_RE.is_match(name) {
+            exports.non_component_exports.push(span);
+        }
     }
 
-    // fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-    //     let mut run_data = OnlyExportComponentsRun::default_from_rule(self);
-
-    //     // println!("{:#?}", node);
-    //     match node.kind() {
-    //         // ✅
-    //         AstKind::ExportAllDeclaration(export_all) => {
-    //             if export_all.export_kind == ImportOrExportKind::Type {
-    //                 return;
-    //             }
-
-    //             run_data.has_exports = true;
-
-    //             ctx.diagnostic(report_export_all(export_all.span));
-    //         }
-    //         // 🚧
-    //         AstKind::ExportDefaultDeclaration(_export_default) => {
-    //             //     run_data.has_exports = true;
-
-    //             //     /*
-    //             //      * The origin eslint rule also matches `VariableDeclaration` but that doesn't seem to be valid syntax?
-    //             //      * https://tc39.es/ecma262/#prod-ExportDeclaration
-    //             //      */
-    //             //     match &export_default.declaration {
-    //             //         ExportDefaultDeclarationKind::FunctionDeclaration(declaration) => {
-    //             //             handle_export_declaration(
-    //             //                 ExportDeclaration::FunctionDeclaration(declaration),
-    //             //                 &mut run_data,
-    //             //             );
-    //             //         }
-    //             //         ExportDefaultDeclarationKind::CallExpression(declaration) => {
-    //             //             handle_export_declaration(
-    //             //                 ExportDeclaration::CallExpression(declaration),
-    //             //                 &mut run_data,
-    //             //             );
-    //             //         }
-    //             //         ExportDefaultDeclarationKind::Identifier(identifier_reference) => {
-    //             //             handle_export_identifier(
-    //             //                 &HandleExportIdentifier::IdentifierReference(identifier_reference),
-    //             //                 None,
-    //             //                 None,
-    //             //                 &mut run_data,
-    //             //             )
-    //             //         }
-    //             //         ExportDefaultDeclarationKind::ArrowFunctionExpression(expression) => {
-    //             //             ctx.diagnostic(report_anonymous_export(expression.span));
-    //             //         }
-    //             //         _ => {}
-    //             //     }
-    //         }
-    //         // 🚧
-    //         AstKind::ExportNamedDeclaration(_named_declaration) => {
-    //             //     run_data.has_exports = true;
-
-    //             //     if let Some(_) = named_declaration.declaration {
-    //             //         handle_export_declaration(
-    //             //             ExportDeclaration::NamedDeclaration(named_declaration),
-    //             //             &mut run_data,
-    //             //         );
-    //             //     }
-
-    //             //     // for specifier in &named_declaration.specifiers {
-    //             //     //     let default_identifier = "default".to_string();
-    //             //     //     let new_identifier = match specifier.exported.name().to_string() {
-    //             //     //         default_identifier => specifier.local,
-    //             //     //         _ => specifier.exported,
-    //             //     //     };
-
-    //             //     // handle_export_identifier(new_identifier, None, None, &mut run_data)
-    //             //     // }
-    //             // }
-    //             // AstKind::VariableDeclaration(variable_declaration) => {
-    //             //     for variable in variable_declaration.declarations {
-    //             //         handle_local_identifier(Some(&variable.id), &mut run_data);
-    //             //     }
-    //         }
-    //         // 🚧
-    //         AstKind::VariableDeclaration(variable_declaration) => {
-    //             for variable in &variable_declaration.declarations {
-    //                 let variable_id = &variable.id;
-
-    //                 // handle_local_identifier(
-    //                 //     BindingPatterOrIdentifier::BindingPattern(variable_id.clone()),
-    //                 //     &mut run_data,
-    //                 // );
-    //             }
-    //         }
-    //         // ✅
-    //         AstKind::Function(function_declaration) => {
-    //             if let Some(function_declaration_id) = &function_declaration.id {
-    //                 handle_local_identifier(
-    //                     BindingPatterOrIdentifier::BindingIdentifier(function_declaration_id),
-    //                     &mut run_data,
-    //                 );
-    //             }
-    //         }
-    //         // ✅
-    //         AstKind::ImportDeclaration(import_declaration) => {
-    //             if import_declaration.source.value.to_string() == "React" {
-    //                 run_data.react_is_in_scope = true;
-    //             }
-    //         }
-    //         _ => {}
-    //     }
-
-    //     // if run_data.check_js && !run_data.react_is_in_scope {
-    //     // return;
-    //     // }
-
-    //     // if run_data.has_exports {
-    //     //     if run_data.may_have_react_export {
-
-    //     //     } else if run_data.loca
-    //     // }
-    // }
+    /// Whether `call` calls a higher-order component, e.g. `memo(...)`, `React.forwardRef(...)`,
+    /// `withRouter(...)`, one of the configured `customHOCs` or one of the
+    /// `componentWrapperFunctions` of the React settings.
+    fn is_hoc_call(&self, call: &CallExpression, ctx: &LintContext) -> bool {
+        let Some(name) = call.callee_name() else { return false };
+        let object = match &call.callee {
+            Expression::StaticMemberExpression(member) => match &member.object {
+                Expression::Identifier(ident) => Some(ident.name.as_str()),
+                _ => None,
+            },
+            _ => None,
+        };
+        REACT_HOCS.contains(&name)
+            || name.strip_prefix("with").is_some_and(|rest| rest.starts_with(char::is_uppercase))
+            || self.custom_hocs.iter().any(|hoc| hoc == name)
+            || ctx.settings().react.is_component_wrapper_function(object, name)
+    }
 }
 
-// fn handle_local_identifier<'a>(
-//     identifier_node: &'a BindingPatterOrIdentifier,
-//     local_components: &mut Vec<&'a BindingPatterOrIdentifier<'a>>,
-// ) {
-//     match identifier_node {
-//         BindingPatterOrIdentifier::BindingIdentifier(identifier) => {
-//             if POSSIBLE_REACT_EXPORT_RE.is_match(identifier.name.as_str()) {
-//                 local_components.push(&identifier_node);
-//             }
-//         }
-//         _ => {}
-//     }
-// }
-
-// fn handle_export_identifier<'a>(
-//     identifier_node: BindingPatterOrIdentifier<'a>,
-//     is_function: Option<bool>,
-//     init: Option<Expression>,
-//     non_component_exports: &mut Vec<BindingPatterOrIdentifier<'a>>,
-//     may_have_react_export: &mut bool,
-//     allow_export_names: &Vec<String>,
-//     allow_constant_export: bool,
-// ) {
-//     let BindingPatterOrIdentifier::BindingIdentifier(identifier_node) = identifier_node else {
-//         non_component_exports.push(identifier_node);
-//         return;
-//     };
-
-//     let identifier_node_name = identifier_node.name.to_string();
-
-//     if allow_export_names.contains(&identifier_node_name) {
-//         return;
-//     }
-
-//     match init {
-//         Some(Expression::StringLiteral(_))
-//         | Some(Expression::TemplateLiteral(_))
-//         | Some(Expression::BinaryExpression(_)) => {
-//             return;
-//         }
-//         _ => {
-//             if is_function.is_some_and(|is_function| is_function) {
-//                 if POSSIBLE_REACT_EXPORT_RE.is_match(&identifier_node_name) {
-//                     *may_have_react_export = true;
-//                 } else {
-//                     let binding_identifier =
-//                         BindingPatterOrIdentifier::BindingIdentifier(&identifier_node);
-
-//                     non_component_exports.push(binding_identifier);
-//                 }
-//             }
-//         }
-//     }
-// }
-
-// enum ExportDeclaration<'a> {
-//     FunctionDeclaration(&'a oxc_allocator::Box<'a, oxc_ast::ast::Function<'a>>),
-//     CallExpression(&'a oxc_allocator::Box<'a, oxc_ast::ast::CallExpression<'a>>),
-//     TSEnumDeclaration(&'a oxc_allocator::Box<'a, oxc_ast::ast::TSEnumDeclaration<'a>>),
-//     Declaration(&'a oxc_allocator::Box<'a, oxc_ast::ast::Declaration<'a>>),
-//     NamedDeclaration(&'a ExportNamedDeclaration<'a>),
-//     IdentifierReference(&'a oxc_allocator::Box<'a, oxc_ast::ast::IdentifierReference<'a>>),
-// }
-
-// fn handle_export_declaration<'a>(
-//     declaration: ExportDeclaration<'a>,
-//     run_data: &'a mut OnlyExportComponentsRun<'a>,
-// ) -> bool {
-//     // match declaration {
-//     //     ExportDeclaration::FunctionDeclaration(function) => {
-//     //         if let Some(id) = &function.id {
-//     //             handle_export_identifier(&id, Some(true), None, run_data);
-//     //         }
-//     //     }
-//     //     ExportDeclaration::CallExpression(call_expression) => {
-//     //         if let Some(callee_name) = call_expression.callee_name() {
-//     //             if REACT_HOCS.contains(&callee_name) {
-//     //                 let first_argument = call_expression.arguments.get(0);
-
-//     //                 if let Some(first_argument) = first_argument {
-//     //                     if let Argument::FunctionExpression(expression) = first_argument {
-//     //                         if let Some(expression_id) = &expression.id {
-//     //                             handle_export_identifier(expression_id, Some(true), None, run_data);
-//     //                         }
-//     //                     }
-//     //                 }
-//     //             }
-//     //         }
-//     //     }
-//     //     ExportDeclaration::TSEnumDeclaration(declaration) => {
-//     //         run_data.non_component_exports.push(&declaration.id);
-//     //     }
-//     // }
-
-//     true
-// }
-
-// enum HandleExportIdentifier<'a> {
-//     IdentifierReference(&'a oxc_allocator::Box<'a, IdentifierReference<'a>>),
-// }
-
-// fn handle_export_identifier<'a>(
-//     identifier: &'a HandleExportIdentifier<'a>,
-//     is_function: Option<bool>,
-//     init: Option<Expression>,
-//     run_data: &'a mut OnlyExportComponentsRun<'a>,
-// ) {
-//     // let identifier_name = identifier.name.to_string();
-//     //
-//     // /*
-//     //  * If there is any specific allowed export names, just ignore it.
-//     //  * Examples are `loader`, `action`, ... from Remix.run
-//     //  */
-//     // if run_data.rule.allow_export_names.contains(&identifier_name) {
-//     //     return;
-//     // }
-//     //
-//     // /*
-//     //  * If contant exports are allowed,
-//     //  * eg. `export const hello = "world"`
-//     //  * also ignore it
-//     //  */
-//     // if run_data.rule.allow_constant_export {
-//     //     match init {
-//     //         Some(Expression::StringLiteral(_)) => {
-//     //             return;
-//     //         }
-//     //         Some(Expression::TemplateLiteral(_)) => {
-//     //             return;
-//     //         }
-//     //         Some(Expression::BinaryExpression(_)) => {
-//     //             return;
-//     //         }
-//     //         _ => {}
-//     //     }
-//     // }
-//     //
-//     // if is_function.is_some() && is_function.unwrap() == true {
-//     //     if POSSIBLE_REACT_EXPORT_RE.is_match(&identifier_name) {
-//     //         run_data.may_have_react_export = true;
-//     //     } else {
-//     //         run_data.non_component_exports.push(&identifier);
-//     //     }
-//     // } else {
-//     //     if let Some(init) = init {
-//     //         match init {
-//     //             Expression::ArrayExpression(_)
-//     //             | Expression::AwaitExpression(_)
-//     //             | Expression::BinaryExpression(_)
-//     //             | Expression::ChainExpression(_)
-//     //             | Expression::ConditionalExpression(_)
-//     //             | Expression::StringLiteral(_)
-//     //             | Expression::LogicalExpression(_)
-//     //             | Expression::ObjectExpression(_)
-//     //             | Expression::TemplateLiteral(_)
-//     //             | Expression::ThisExpression(_)
-//     //             | Expression::UnaryExpression(_)
-//     //             | Expression::UpdateExpression(_) => {
-//     //                 run_data.non_component_exports.push(&identifier);
-//     //
-//     //                 return;
-//     //             }
-//     //             _ => {}
-//     //         }
-//     //     }
-//     //
-//     //     if !run_data.may_have_react_export && POSSIBLE_REACT_EXPORT_RE.is_match(&identifier_name) {
-//     //         run_data.may_have_react_export = true;
-//     //     }
-//     //
-//     //     if !STRICT_REACT_EXPORT_RE.is_match(&identifier_name) {
-//     //         run_data.non_component_exports.push(&identifier);
-//     //     }
-//     // }
-// }
-
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
-        // (r"export function Foo() {};", None),
-        // (r"function Foo() {}; export { Foo };", None),
-        // (r"function foo() {}; export default Foo;", None),
-        // (r"export default function Foo() {}", None),
-        // (r"export const Foo = () => {};", None),
-        // (r"export const Foo2 = () => {}", None),
-        // (r"export function CMS() {};", None),
-        // (r"export const SVG = forwardRef(() => <svg />);", None),
-        // (r"export const CMS = () => {};", None),
-        // (r"const Foo = () => {}; export { Foo };", None),
-        // (r"const Foo = () => {}; export default Foo;", None),
-        // (r"const foo = 4; export const Bar = () => {}; export const Baz = () => {};", None),
-        // (r"const foo = () => {}; export const Bar = () => {}; export const Baz = () => {};", None),
-        // (r"export const Foo = () => {}; export const Bar= styled.div`padding-bottom: 6px`;", None),
-        // (r"export const foo = 3;", None),
-        // (r"const foo = 3; const bar = 'Hello'; export { foo, bar };", None),
-        // (r"export const foo = () => {};", None),
-        // (r"export default function foo () {};", None),
-        // (r"export default memo(function Foo () {});", None),
-        // (r"export type * from './module';", None),
-        // (r"export const foo = () => {}; export const Bar = () => {};", None),
-        // (r"export const foo = () => {}; export const Bar = () => {};", Some(serde_json::json!([{ "checkJS": true }]))),
-        // (r"export const foo = 4; export const Bar = () => {};", Some(serde_json::json!([{ "allowConstantExport": true }]))),
-        // (r"export const CONSTANT = 'Hello world'; export const Foo = () => {};", Some(serde_json::json!([{ "allowConstantExport": true }]))),
-        // (r"const foo = 'world'; export const CONSTANT = `Hello ${foo}`; export const Foo = () => {};", Some(serde_json::json!([{ "allowConstantExport": true }]))),
-        // (r"export const loader = () => {}; export const Bar = () => {};", Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }]))),
-        // (r"export function loader() {}; export const Bar = () => {};", Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }]))),
-        // (r"export const loader = () => {}; export const meta = { title: 'Home' };", Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }]))),
-        // (r"export { App as default }; const App = () => <>Text</>;", None)
+        (r"export function Foo() {};", None),
+        (r"function Foo() {}; export { Foo };", None),
+        (r"function Foo() {}; export default Foo;", None),
+        (r"export default function Foo() {}", None),
+        (r"export const Foo = () => {};", None),
+        (r"export const Foo2 = () => {}", None),
+        (r"export function CMS() {};", None),
+        (r"export const SVG = forwardRef(() => <svg />);", None),
+        (r"export const CMS = () => {};", None),
+        (r"const Foo = () => {}; export { Foo };", None),
+        (r"const Foo = () => {}; export default Foo;", None),
+        (r"const foo = 4; export const Bar = () => {}; export const Baz = () => {};", None),
+        (r"const foo = () => {}; export const Bar = () => {}; export const Baz = () => {};", None),
+        (r"export const Foo = () => {}; export const Bar = styled.div`padding-bottom: 6px`;", None),
+        (r"export const foo = 3;", None),
+        (r"const foo = 3; const bar = 'Hello'; export { foo, bar };", None),
+        (r"export const foo = () => {};", None),
+        (r"export default function foo () {};", None),
+        (r"export default memo(function Foo () {});", None),
+        (r"export default React.memo(function Foo () {});", None),
+        (r"export type * from './module';", None),
+        (r"export type Foo = number; export const Bar = () => {};", None),
+        (
+            r"export const foo = 4; export const Bar = () => {};",
+            Some(serde_json::json!([{ "allowConstantExport": true }])),
+        ),
+        (
+            r"export const CONSTANT = 'Hello world'; export const Foo = () => {};",
+            Some(serde_json::json!([{ "allowConstantExport": true }])),
+        ),
+        (
+            r"const foo = 'world'; export const CONSTANT = `Hello ${foo}`; export const Foo = () => {};",
+            Some(serde_json::json!([{ "allowConstantExport": true }])),
+        ),
+        (
+            r"export const loader = () => {}; export const Bar = () => {};",
+            Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }])),
+        ),
+        (
+            r"export function loader() {}; export const Bar = () => {};",
+            Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }])),
+        ),
+        (
+            r"export const loader = () => {}; export const meta = { title: 'Home' };",
+            Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }])),
+        ),
+        (r"export { App as default }; const App = () => <>Text</>;", None),
+        (r"const MyComponent = () => {}; export default memo(MyComponent);", None),
+        (r"const MyComponent = () => {}; export default connect(() => ({}))(MyComponent);", None),
+        (
+            r"export const Foo = memo(() => <div />); export const Bar = React.forwardRef(() => <div />);",
+            None,
+        ),
+        (
+            r"export const Page = withRouter(() => <div />); export const Button = () => <button />;",
+            None,
+        ),
+        (
+            r"export const Settings = lazy(() => import('./Settings')); export const Foo = () => {};",
+            None,
+        ),
+        (r"export default lazy(() => import('./Settings'));", None),
+        (
+            r"const MyComponent = () => {}; export default observer(MyComponent);",
+            Some(serde_json::json!([{ "customHOCs": ["observer"] }])),
+        ),
+        (
+            r"export const Store = observer(() => <div />); export const Foo = () => {};",
+            Some(serde_json::json!([{ "customHOCs": ["observer"] }])),
+        ),
     ];
 
     let fail = vec![
+        (r"export const foo = () => {}; export const Bar = () => {};", None),
+        (r"export const foo = 4; export const Bar = () => {};", None),
+        (r"export function Component() {}; export const Aa = 'a'", None),
+        (r"const foo = 4; const Bar = () => {}; export { foo, Bar };", None),
+        (r"export * from './foo';", None),
+        (r"export default () => {};", None),
+        (r"export default memo(() => {});", None),
+        (r"export default function () {};", None),
+        (r"export const CONSTANT = 3; export const Foo = () => {};", None),
         (r"export enum Tab { Home, Settings }; export const Bar = () => {};", None),
-        // (r"export * from 'react';", None),
+        (r"const Tab = () => {}; export const tabs = [<Tab />, <Tab />];", None),
+        (
+            r"import { createRoot } from 'react-dom/client'; const App = () => {}; createRoot(document.getElementById('root')).render(<App />);",
+            None,
+        ),
+        (
+            r"export const loader = () => {}; export const Bar = () => {}; export const foo = () => {};",
+            Some(serde_json::json!([{ "allowExportNames": ["loader", "meta"] }])),
+        ),
+        (r"export const Foo = () => {}; export const context = createContext({});", None),
+        (r"export const Foo = () => {}; export const MyContext = React.createContext({});", None),
+        (r"const MyComponent = () => {}; export default observer(MyComponent);", None),
+        (r"export const withoutHoc = without(() => {}); export const Foo = () => {};", None),
     ];
 
     Tester::new(OnlyExportComponents::NAME, pass, fail)
         .with_react_refresh_plugin(true)
         .test_and_snapshot();
+
+    // Tests and stories are not hot reloaded
+    let source = "export const foo = 4; export const Bar = () => {};";
+    let pass = vec![
+        TestCase::new(source).with_path("Bar.test.tsx"),
+        TestCase::new(source).with_path("Bar.spec.jsx"),
+        TestCase::new(source).with_path("Bar.stories.tsx"),
+    ];
+    let fail = vec![TestCase::new(source).with_path("Bar.jsx")];
+    Tester::new(OnlyExportComponents::NAME, pass, fail).with_react_refresh_plugin(true).test();
+
+    // CommonJS modules are not hot reloaded
+    let source = "import React from 'react'; export const foo = 4; export const Bar = () => {};";
+    let pass = vec![TestCase::new(source).with_path("Bar.cts")];
+    let fail = vec![TestCase::new(source).with_path("Bar.mts")];
+    Tester::new(OnlyExportComponents::NAME, pass, fail).with_react_refresh_plugin(true).test();
+
+    // `componentWrapperFunctions` of the React settings are recognized as HOCs
+    let settings = serde_json::json!({
+        "react": { "componentWrapperFunctions": ["observer", { "property": "styled" }] }
+    });
+    let pass = vec![
+        TestCase::new("const Foo = () => {}; export default observer(Foo);")
+            .with_settings(settings.clone()),
+        TestCase::new("export const Foo = styled.div(() => {});").with_settings(settings.clone()),
+    ];
+    let fail =
+        vec![TestCase::new("const Foo = () => {}; export default inject(Foo);")
+            .with_settings(settings)];
+    Tester::new(OnlyExportComponents::NAME, pass, fail).with_react_refresh_plugin(true).test();
 }
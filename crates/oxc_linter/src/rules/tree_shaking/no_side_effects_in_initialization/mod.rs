@@ -105,6 +105,10 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Bundlers can only remove the unused code of a module when evaluating the module has no
+    /// side-effects, so side-effects in the initialization of a library end up in every bundle
+    /// importing it.
+    ///
     /// ### Example
     ///
     /// ```javascript
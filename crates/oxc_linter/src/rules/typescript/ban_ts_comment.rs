@@ -105,7 +105,8 @@ declare_oxc_lint!(
     /// }
     /// ```
     BanTsComment,
-    pedantic
+    pedantic,
+    fix
 );
 
 impl Rule for BanTsComment {
@@ -140,7 +141,7 @@ impl Rule for BanTsComment {
     }
 
     fn run_once(&self, ctx: &LintContext) {
-        let comments = ctx.semantic().trivias().comments();
+        let comments = ctx.comments();
         for (kind, span) in comments {
             let raw = ctx.source_range(span);
             if let Some(captures) = find_ts_comment_directive(raw, kind.is_single_line()) {
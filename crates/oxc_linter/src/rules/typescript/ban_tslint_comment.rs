@@ -30,12 +30,13 @@ declare_oxc_lint!(
     /// someCode();
     /// ```
     BanTslintComment,
-    style
+    style,
+    fix
 );
 
 impl Rule for BanTslintComment {
     fn run_once(&self, ctx: &LintContext) {
-        let comments = ctx.semantic().trivias().comments();
+        let comments = ctx.comments();
         let source_text_len = ctx.semantic().source_text().len();
 
         for (kind, span) in comments {
@@ -54,7 +54,10 @@ declare_oxc_lint!(
     /// They can also speed up TypeScript type checking performance in large codebases with many large functions.
     ///
     /// ### Example
-    /// ```javascript
+    /// ```typescript
+    /// function test() {
+    ///   return;
+    /// }
     /// ```
     ExplicitFunctionReturnType,
     restriction,
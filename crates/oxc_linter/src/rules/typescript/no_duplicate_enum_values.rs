@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -36,6 +36,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDuplicateEnumValues {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSEnumDeclaration]);
+
     #[allow(clippy::float_cmp)]
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSEnumDeclaration(enum_body) = node.kind() else {
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -36,6 +36,8 @@ fn no_dynamic_delete_diagnostic(span0: Span) -> OxcDiagnostic {
 }
 
 impl Rule for NoDynamicDelete {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::UnaryExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::UnaryExpression(expr) = node.kind() else { return };
         if !matches!(expr.operator, UnaryOperator::Delete) {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -43,6 +43,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoEmptyInterface {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSInterfaceDeclaration]);
+
     fn from_configuration(value: Value) -> Self {
         let allow_single_extends =
             value.get(0).map_or(Self::default().allow_single_extends, |config| {
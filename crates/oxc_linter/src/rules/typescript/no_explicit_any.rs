@@ -1,10 +1,10 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 use serde_json::Value;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Suggestion, rule::Rule, AstNode};
 
 fn no_explicit_any_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("typescript-eslint(no-explicit-any): Unexpected any. Specify a different type.")
@@ -82,10 +82,13 @@ declare_oxc_lint!(
     /// Whether to enable auto-fixing in which the `any` type is converted to the `unknown` type.
     /// `false` by default.
     NoExplicitAny,
-    restriction
+    restriction,
+    fix
 );
 
 impl Rule for NoExplicitAny {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSAnyKeyword]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSAnyKeyword(any) = node.kind() else {
             return;
@@ -99,7 +102,12 @@ impl Rule for NoExplicitAny {
                 fixer.replace(any.span, "unknown")
             });
         } else {
-            ctx.diagnostic(no_explicit_any_diagnostic(any.span));
+            ctx.diagnostic_with_suggestions(no_explicit_any_diagnostic(any.span), |fixer| {
+                vec![
+                    Suggestion::new("Use `unknown` instead", fixer.replace(any.span, "unknown")),
+                    Suggestion::new("Use `never` instead", fixer.replace(any.span, "never")),
+                ]
+            });
         }
     }
 
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ImportDeclarationSpecifier, ImportOrExportKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -52,9 +52,12 @@ declare_oxc_lint!(
     /// ```
     NoImportTypeSideEffects,
     restriction,
+    fix
 );
 
 impl Rule for NoImportTypeSideEffects {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ImportDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ImportDeclaration(import_decl) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{TSModuleDeclarationKind, TSModuleDeclarationName},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -43,6 +43,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoNamespace {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSModuleDeclaration]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             allow_declarations: value
@@ -81,7 +83,7 @@ impl Rule for NoNamespace {
             return;
         }
 
-        if self.allow_definition_files && ctx.source_type().is_typescript_definition() {
+        if self.allow_definition_files && ctx.is_declaration_file() {
             return;
         }
 
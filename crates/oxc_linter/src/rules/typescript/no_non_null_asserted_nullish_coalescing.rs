@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_semantic::SymbolId;
@@ -34,6 +34,8 @@ fn no_non_null_asserted_nullish_coalescing_diagnostic(span0: Span) -> OxcDiagnos
 }
 
 impl Rule for NoNonNullAssertedNullishCoalescing {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::LogicalExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::LogicalExpression(expr) = node.kind() else { return };
         let Expression::TSNonNullExpression(ts_non_null_expr) = &expr.left else { return };
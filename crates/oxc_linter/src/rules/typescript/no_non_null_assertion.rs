@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -32,6 +32,8 @@ fn no_non_null_assertion_diagnostic(span0: Span) -> OxcDiagnostic {
 }
 
 impl Rule for NoNonNullAssertion {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSNonNullExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSNonNullExpression(expr) = node.kind() else { return };
         ctx.diagnostic(no_non_null_assertion_diagnostic(expr.span));
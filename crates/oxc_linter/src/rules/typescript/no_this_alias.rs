@@ -46,16 +46,23 @@ impl Default for NoThisAliasConfig {
 declare_oxc_lint!(
     /// ### What it does
     ///
-    /// Disallow unnecessary constraints on generic types.
+    /// Disallow aliasing `this`.
     ///
     /// ### Why is this bad?
     ///
-    /// Generic type parameters (<T>) in TypeScript may be "constrained" with an extends keyword.
-    /// When no extends is provided, type parameters default a constraint to unknown. It is therefore redundant to extend from any or unknown.
+    /// Assigning a variable to `this` instead of properly using arrow functions may be a symptom of
+    /// pre-ES6 practices or not managing scope well.
     ///
-    /// the rule doesn't allow const {allowedName} = this
-    /// this is to keep 1:1 with eslint implementation
-    /// sampe with obj.<allowedName> = this
+    /// The rule doesn't allow `const {allowedName} = this`, nor `obj.<allowedName> = this`, to keep
+    /// 1:1 with the eslint implementation.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// const self = this;
+    ///
+    /// setTimeout(function () {
+    ///   self.doWork();
+    /// });
     /// ```
     NoThisAlias,
     correctness
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::TSType, AstKind};
+use oxc_ast::{ast::TSType, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -46,6 +46,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoUnnecessaryTypeConstraint {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSTypeParameterDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::TSTypeParameterDeclaration(decl) = node.kind() {
             for param in &decl.params {
@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -21,24 +21,31 @@ declare_oxc_lint!(
     ///
     /// Disallow empty exports that don't change anything in a module file.
     ///
+    /// ### Why is this bad?
+    ///
+    /// An empty `export {}` turns a script into a module, which is useless in a
+    /// file which already imports or exports something.
+    ///
     /// ### Example
     ///
-    /// ### Bad
+    /// #### Bad
     /// ```javascript
     /// export const value = 'Hello, world!';
     /// export {};
     /// ```
     ///
-    /// ### Good
+    /// #### Good
     /// ```javascript
     /// export const value = 'Hello, world!';
     /// ```
-    ///
     NoUselessEmptyExport,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessEmptyExport {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ExportNamedDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ExportNamedDeclaration(decl) = node.kind() else { return };
         if decl.declaration.is_some() || !decl.specifiers.is_empty() {
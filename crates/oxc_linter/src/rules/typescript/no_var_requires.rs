@@ -74,7 +74,9 @@ impl Rule for NoVarRequires {
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
+    use oxc_span::SourceType;
+
+    use crate::tester::{TestCase, Tester};
 
     let pass = vec![
         "import foo = require('foo');",
@@ -104,8 +106,6 @@ fn test() {
         "let foo = trick(require?.('foo'));",
         "let foo = trick?.(require('foo'));",
         "const foo = require('./foo.json') as Foo;",
-        // Because of TypeScript disallows angle bracket type assertions in .tsx files, comment out this below case all tests parsing as tsx.
-        // "const foo = <Foo>require('./foo.json');",
         "const foo: Foo = require('./foo.json').default;",
         r"
             const configValidator = new Validator(require('./a.json'));
@@ -114,4 +114,9 @@ fn test() {
     ];
 
     Tester::new(NoVarRequires::NAME, pass, fail).test_and_snapshot();
+
+    // TypeScript disallows angle bracket type assertions in .tsx files
+    let fail = vec![TestCase::new("const foo = <Foo>require('./foo.json');")
+        .with_source_type(SourceType::default().with_typescript(true))];
+    Tester::new(NoVarRequires::NAME, vec![], fail).test();
 }
@@ -1,4 +1,4 @@
-use oxc_ast::{ast::TSEnumMemberName, AstKind};
+use oxc_ast::{ast::TSEnumMemberName, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -34,6 +34,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferEnumInitializers {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSEnumDeclaration]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::TSEnumDeclaration(decl) = node.kind() else {
             return;
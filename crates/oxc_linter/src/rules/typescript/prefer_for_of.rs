@@ -3,7 +3,7 @@ use oxc_ast::{
         match_member_expression, AssignmentTarget, BindingPatternKind, Expression,
         ForStatementInit, SimpleAssignmentTarget, VariableDeclarationKind,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -109,6 +109,8 @@ impl<'a> ExpressionExt for Expression<'a> {
 }
 
 impl Rule for PreferForOf {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ForStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ForStatement(for_stmt) = node.kind() else {
             return;
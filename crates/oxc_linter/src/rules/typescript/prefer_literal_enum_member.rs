@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -41,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferLiteralEnumMember {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::TSEnumMember]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let options: Option<&serde_json::Value> = value.get(0);
 
@@ -2,12 +2,15 @@ use oxc_ast::{
     ast::{
         BinaryExpression, Expression, LogicalExpression, MemberExpression, StaticMemberExpression,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, LogicalOperator};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
 
 use crate::{
     context::LintContext,
@@ -34,22 +37,21 @@ fn zero(span0: Span, x1: &str, x2: &str, x3: Option<String>) -> OxcDiagnostic {
     d.with_label(span0)
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
 enum NonZero {
+    /// `foo.length > 0`
     #[default]
     GreaterThan,
+    /// `foo.length !== 0`
     NotEqual,
 }
-impl NonZero {
-    pub fn from(raw: &str) -> Self {
-        match raw {
-            "not-equal" => Self::NotEqual,
-            _ => Self::GreaterThan,
-        }
-    }
-}
-#[derive(Debug, Default, Clone)]
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, deny_unknown_fields)]
 pub struct ExplicitLengthCheck {
+    /// The comparison of the non-zero checks
+    #[serde(rename = "non-zero")]
     non_zero: NonZero,
 }
 
@@ -57,11 +59,10 @@ declare_oxc_lint!(
     /// ### What it does
     /// Enforce explicitly comparing the length or size property of a value.
     ///
-    /// The non-zero option can be configured with one of the following:
-    /// greater-than (default)
-    ///     Enforces non-zero to be checked with: foo.length > 0
-    /// not-equal
-    ///     Enforces non-zero to be checked with: foo.length !== 0
+    /// The truthiness checks, e.g. `if (foo.length)` and `!foo.length`, and the other
+    /// comparisons, e.g. `foo.length < 1`, are fixed to `foo.length === 0` for zero and to the
+    /// comparison of the `non-zero` option otherwise.
+    ///
     /// ### Example
     /// ```javascript
     /// // fail
@@ -77,8 +78,19 @@ declare_oxc_lint!(
     /// // pass
     /// const isEmpty = foo.length === 0;
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "unicorn/explicit-length-check": ["error", { "non-zero": "greater-than" }] }
+    /// ```
+    ///
+    /// * `non-zero`: `greater-than` to check non-zero with `foo.length > 0`, the default, or
+    ///   `not-equal` to check it with `foo.length !== 0`
     ExplicitLengthCheck,
-    pedantic
+    pedantic,
+    fix,
+    config = ExplicitLengthCheck
 );
 fn is_literal(expr: &Expression, value: f64) -> bool {
     matches!(expr, Expression::NumericLiteral(lit) if (lit.value - value).abs() < f64::EPSILON)
@@ -192,8 +204,15 @@ impl ExplicitLengthCheck {
         let mut need_pad_start = false;
         let mut need_pad_end = false;
         let parent = ctx.nodes().parent_kind(node.id());
-        let need_paren = matches!(kind, AstKind::UnaryExpression(_))
-            && matches!(parent, Some(AstKind::UnaryExpression(_) | AstKind::AwaitExpression(_)));
+        // `void !foo.length` and `a in !foo.length`, the comparison has a lower precedence
+        let need_paren = matches!(
+            parent,
+            Some(
+                AstKind::UnaryExpression(_)
+                    | AstKind::AwaitExpression(_)
+                    | AstKind::BinaryExpression(_)
+            )
+        );
         if span.start > 1 {
             let start = ctx.source_text().as_bytes()[span.start as usize - 1];
             need_pad_start = start.is_ascii_alphabetic() || !start.is_ascii();
@@ -231,6 +250,8 @@ impl ExplicitLengthCheck {
     }
 }
 impl Rule for ExplicitLengthCheck {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::MemberExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         if let AstKind::MemberExpression(MemberExpression::StaticMemberExpression(
             static_member_expr,
@@ -275,15 +296,18 @@ impl Rule for ExplicitLengthCheck {
         }
     }
 
-    fn from_configuration(value: serde_json::Value) -> Self {
-        Self {
-            non_zero: value
-                .get(0)
-                .and_then(|v| v.get("non-zero"))
-                .and_then(serde_json::Value::as_str)
-                .map(NonZero::from)
-                .unwrap_or_default(),
-        }
+    fn from_configuration(value: Value) -> Self {
+        value
+            .get(0)
+            .and_then(|config| serde_json::from_value(config.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), OxcDiagnostic> {
+        let Some(config) = value.get(0) else { return Ok(()) };
+        serde_json::from_value::<Self>(config.clone())
+            .map(|_| ())
+            .map_err(|err| OxcDiagnostic::warn(err.to_string()))
     }
 }
 
@@ -355,6 +379,7 @@ fn test() {
         ("alert(foo.length && bar())", None),
         // Use of .size in conditional "test" position
         ("let foo = arr.length ? 'non-empty' : 'empty'", None),
+        ("const bar = void !foo.length;", None),
     ];
 
     let fixes = vec![
@@ -378,7 +403,7 @@ fn test() {
         ("for (let i = 0; (bar && !foo.length); i ++) {}", "for (let i = 0; (bar && foo.length === 0); i ++) {}", None),
         ("const isEmpty = foo.length < 1;", "const isEmpty = foo.length === 0;", None),
         ("bar(foo.length >= 1)", "bar(foo.length > 0)", None),
-        // ("const bar = void !foo.length;", "const bar = void (foo.length === 0);", None),
+        ("const bar = void !foo.length;", "const bar = void (foo.length === 0);", None),
         ("const isNotEmpty = Boolean(foo.length)", "const isNotEmpty = foo.length > 0", None),
         ("const isNotEmpty = Boolean(foo.length || bar)", "const isNotEmpty = Boolean(foo.length > 0 || bar)", None),
         ("const isEmpty = Boolean(!foo.length)", "const isEmpty = foo.length === 0", None),
@@ -396,8 +421,9 @@ fn test() {
         ("delete!foo.length", "delete (foo.length === 0)", None),
         ("typeof!foo.length", "typeof (foo.length === 0)", None),
         ("void!foo.length", "void (foo.length === 0)", None),
-        ("a instanceof!foo.length", "a instanceof foo.length === 0", None),
-        ("a in!foo.length", "a in foo.length === 0", None),
+        ("a instanceof!foo.length", "a instanceof (foo.length === 0)", None),
+        ("a in!foo.length", "a in (foo.length === 0)", None),
+        ("a == foo.length < 1", "a == (foo.length === 0)", None),
         ("export default!foo.length", "export default foo.length === 0", None),
         ("if(true){}else!foo.length", "if(true){}else foo.length === 0", None),
         ("do!foo.length;while(true) {}", "do foo.length === 0;while(true) {}", None),
@@ -405,5 +431,16 @@ fn test() {
         ("for(const a of!foo.length);", "for(const a of foo.length === 0);", None),
         ("for(const a in!foo.length);", "for(const a in foo.length === 0);", None),
     ];
-    Tester::new(ExplicitLengthCheck::NAME, pass, fail).expect_fix(fixes).test_and_snapshot();
+    Tester::new(ExplicitLengthCheck::NAME, pass, fail)
+        .expect_fix(fixes)
+        .snapshot_fixes()
+        .test_and_snapshot();
+
+    let invalid = ExplicitLengthCheck::validate_configuration(&serde_json::json!([
+        { "non-zero": "not-zero" }
+    ]));
+    assert_eq!(
+        invalid.unwrap_err().to_string(),
+        "unknown variant `not-zero`, expected `greater-than` or `not-equal`"
+    );
 }
@@ -42,7 +42,15 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// Consistently named files are easier to find, and avoid conflicts between
+    /// case sensitive and case insensitive file systems.
+    ///
     /// ### Example
+    ///
+    /// With the default options, which disallow kebab case and snake case:
+    /// ```
+    /// foo/bar/baz/aaa-bbb.tsx
+    /// foo/bar/baz/foo_bar.tsx
     /// ```
     FilenameCase,
     style
@@ -2,10 +2,14 @@ use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, disable_directives::DisableRuleComment, rule::Rule};
+use crate::{
+    context::LintContext,
+    disable_directives::{directive_name, DisableRuleComment},
+    rule::Rule,
+};
 
-fn no_abusive_eslint_disable_diagnostic(span0: Span) -> OxcDiagnostic {
-    OxcDiagnostic::warn("eslint-plugin-unicorn(no-abusive-eslint-disable): Unexpected `eslint-disable` comment that does not specify any rules to disable.")
+fn no_abusive_eslint_disable_diagnostic(span0: Span, directive: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("eslint-plugin-unicorn(no-abusive-eslint-disable): Unexpected `{directive}` comment that does not specify any rules to disable."))
         .with_help("Specify the rules you want to disable.")
         .with_label(span0)
 }
@@ -20,6 +24,9 @@ declare_oxc_lint!(
     /// ### Why is this bad?
     /// When only one rule should be disabled but the `eslint-disable` comment does not specify any rules, other useful errors will also be silently ignored.
     ///
+    /// The `oxlint-disable` comments are checked as well, and a reason after `--` does not count
+    /// as a rule: `// eslint-disable-line -- reason` is reported.
+    ///
     /// ### Example
     /// ```javascript
     /// // Fail
@@ -46,13 +53,19 @@ declare_oxc_lint!(
 
 impl Rule for NoAbusiveEslintDisable {
     fn run_once(&self, ctx: &LintContext) {
+        let diagnostic = |span: Span| {
+            let directive = directive_name(span, ctx.source_text());
+            no_abusive_eslint_disable_diagnostic(span, directive)
+        };
+
         for span in ctx.disable_directives().disable_all_comments() {
-            ctx.diagnostic(no_abusive_eslint_disable_diagnostic(*span));
+            ctx.diagnostic(diagnostic(*span));
         }
 
+        // `eslint-disable-line @scope`, where `@scope` is not the name of a rule
         for DisableRuleComment { span, rules } in ctx.disable_directives().disable_rule_comments() {
-            if rules.is_empty() || !is_valid_rule_name(rules[0]) {
-                ctx.diagnostic(no_abusive_eslint_disable_diagnostic(*span));
+            if rules.is_empty() || !rules.iter().all(|rule| is_valid_rule_name(rule)) {
+                ctx.diagnostic(diagnostic(*span));
             }
         }
     }
@@ -87,6 +100,9 @@ fn test() {
         "eval(); // eslint-line-disable",
         "eval(); // some comment",
         "/* eslint-disable no-eval */",
+        "/* eslint-disable no-eval -- reason */",
+        "eval(); // oxlint-disable-line no-eval",
+        "/* oxlint-disable no-eval */",
         r"
         /* eslint-disable no-abusive-eslint-disable */
         eval(); // eslint-disable-line
@@ -133,6 +149,15 @@ fn test() {
         // eslint-disable-next-line
         eval();
         ",
+        "eval(); // eslint-disable-line -- reason",
+        "/* eslint-disable -- reason */",
+        "eval(); // eslint-disable-line no-eval, @scopewithoutplugin",
+        "eval(); // oxlint-disable-line",
+        "/* oxlint-disable */",
+        r"
+        // oxlint-disable-next-line
+        eval();
+        ",
     ];
 
     Tester::new(NoAbusiveEslintDisable::NAME, pass, fail).test_and_snapshot();
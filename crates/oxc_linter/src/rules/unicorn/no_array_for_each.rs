@@ -1,13 +1,27 @@
 use oxc_ast::{
-    ast::{match_member_expression, Expression},
-    AstKind,
+    ast::{
+        match_member_expression, Argument, ArrowFunctionExpression, BindingPatternKind, Class,
+        DoWhileStatement, Expression, ForInStatement, ForOfStatement, ForStatement,
+        FormalParameters, Function, FunctionBody, IdentifierReference, ReturnStatement, Statement,
+        ThisExpression, VariableDeclaration, WhileStatement,
+    },
+    syntax_directed_operations::BoundNames,
+    visit::walk::{
+        walk_arrow_expression, walk_do_while_statement, walk_for_in_statement,
+        walk_for_of_statement, walk_for_statement, walk_return_statement,
+        walk_variable_declaration, walk_while_statement,
+    },
+    AstKind, AstType, Visit,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::{Reference, ScopeFlags};
+use oxc_span::{GetSpan, Span};
 use phf::phf_set;
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::is_method_call, context::LintContext, fixer::Suggestion, rule::Rule, AstNode,
+};
 
 fn no_array_for_each_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-unicorn(no-array-for-each): Do not use `Array#forEach`")
@@ -43,11 +57,19 @@ declare_oxc_lint!(
     /// const foo = [1, 2, 3];
     /// for (const element of foo) { /* ... */ }
     /// ```
+    ///
+    /// The statements `array.forEach(callback)` with an inline callback are fixed to a `for…of`
+    /// loop, where `return;` becomes `continue;` and an `index` parameter iterates over
+    /// `array.entries()`. The loop is only suggested when the callback uses `this`, or when it is
+    /// not inline, e.g. `array.forEach(callback)`, as it is called with other arguments.
     NoArrayForEach,
     restriction,
+    fix
 );
 
 impl Rule for NoArrayForEach {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -86,9 +108,294 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(no_array_for_each_diagnostic(span));
+            let diagnostic = no_array_for_each_diagnostic(span);
+            // `array.forEach(callback);` as a statement, without `thisArg`
+            let statement = match ctx.nodes().parent_kind(node.id()) {
+                Some(AstKind::ExpressionStatement(statement))
+                    if statement.expression.span() == call_expr.span =>
+                {
+                    Some(statement.span)
+                }
+                _ => None,
+            };
+            let (Some(statement), [callback]) = (statement, call_expr.arguments.as_slice()) else {
+                ctx.diagnostic(diagnostic);
+                return;
+            };
+            if matches!(object, Expression::Super(_)) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+
+            match callback {
+                Argument::ArrowFunctionExpression(arrow) if !arrow.r#async => {
+                    let callback = Callback {
+                        params: &arrow.params,
+                        body: &arrow.body,
+                        expression: arrow.expression,
+                    };
+                    match callback.for_of_loop(statement, object, ctx) {
+                        Some((for_of, _)) => ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fixer.replace(statement, for_of)
+                        }),
+                        None => ctx.diagnostic(diagnostic),
+                    }
+                }
+                Argument::FunctionExpression(function)
+                    if !function.r#async
+                        && !function.generator
+                        && function.id.is_none()
+                        && function.this_param.is_none() =>
+                {
+                    let Some(body) = &function.body else {
+                        ctx.diagnostic(diagnostic);
+                        return;
+                    };
+                    let callback = Callback { params: &function.params, body, expression: false };
+                    match callback.for_of_loop(statement, object, ctx) {
+                        // `this` and `arguments` would be the ones of the enclosing function
+                        Some((for_of, true)) => {
+                            ctx.diagnostic_with_suggestions(diagnostic, |fixer| {
+                                vec![Suggestion::new(SUGGESTION, fixer.replace(statement, for_of))]
+                            });
+                        }
+                        Some((for_of, false)) => ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                            fixer.replace(statement, for_of)
+                        }),
+                        None => ctx.diagnostic(diagnostic),
+                    }
+                }
+                // `array.forEach(callback)` calls `callback(element, index, array)`
+                Argument::Identifier(_) | Argument::StaticMemberExpression(_) => {
+                    let element = "element";
+                    let is_shadowed = ctx.scopes().find_binding(node.scope_id(), element).is_some()
+                        || references_any(object, &[element])
+                        || callback
+                            .as_expression()
+                            .is_some_and(|callback| references_any(callback, &[element]));
+                    let spans = [object.span(), callback.span()];
+                    if is_shadowed || has_comments_outside(statement, &spans, ctx) {
+                        ctx.diagnostic(diagnostic);
+                        return;
+                    }
+                    ctx.diagnostic_with_suggestions(diagnostic, |fixer| {
+                        let for_of = format!(
+                            "for (const {element} of {}) {}({element});",
+                            fixer.source_range(object.span()),
+                            fixer.source_range(callback.span()),
+                        );
+                        vec![Suggestion::new(SUGGESTION, fixer.replace(statement, for_of))]
+                    });
+                }
+                _ => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+const SUGGESTION: &str = "Replace `forEach` with a `for…of` loop";
+
+/// The inline callback of `array.forEach(callback)`.
+struct Callback<'a, 'b> {
+    params: &'b FormalParameters<'a>,
+    body: &'b FunctionBody<'a>,
+    /// The body is an expression, e.g. `element => bar(element)`
+    expression: bool,
+}
+
+impl<'a> Callback<'a, '_> {
+    /// The `for…of` loop replacing the `statement` of `object.forEach(callback)`, and whether it
+    /// changes `this` or `arguments` of the callback. `None` when it would change the behavior of
+    /// the code in other ways, e.g. when a `return` within a loop of the callback would become
+    /// a `continue` of that loop.
+    fn for_of_loop(
+        &self,
+        statement: Span,
+        object: &Expression<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Option<(String, bool)> {
+        let params = &self.params.items;
+        if params.is_empty() || params.len() > 2 || self.params.rest.is_some() {
+            return None;
+        }
+        // `for (const element: Type of array)` is not valid
+        if params.iter().any(|param| {
+            param.pattern.type_annotation.is_some()
+                || param.pattern.optional
+                || matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+        }) {
+            return None;
+        }
+
+        let mut names = vec![];
+        let mut is_reassigned = false;
+        self.params.bound_names(&mut |ident| {
+            names.push(ident.name.as_str());
+            is_reassigned |= ident.symbol_id.get().is_some_and(|symbol_id| {
+                ctx.semantic().symbol_references(symbol_id).any(Reference::is_write)
+            });
+        });
+        // `array.forEach(array => {})` gives `for (const array of array) {}`
+        if references_any(object, &names) {
+            return None;
+        }
+
+        let mut body = CallbackBody::default();
+        body.visit_function_body(self.body);
+        if body.has_other_returns || body.has_var {
+            return None;
+        }
+        let spans = [object.span(), self.params.span, self.body.span];
+        if has_comments_outside(statement, &spans, ctx) {
+            return None;
+        }
+
+        let source_text = ctx.source_text();
+        let keyword = if is_reassigned { "let" } else { "const" };
+        let object = object.span().source_text(source_text);
+        let binding = match params.as_slice() {
+            [element] => format!("{keyword} {} of {object}", element.span.source_text(source_text)),
+            [element, index] => format!(
+                "{keyword} [{}, {}] of {object}.entries()",
+                index.span.source_text(source_text),
+                element.span.source_text(source_text)
+            ),
+            _ => unreachable!(),
+        };
+        let loop_body = if self.expression {
+            let Some(Statement::ExpressionStatement(expression)) = self.body.statements.first()
+            else {
+                return None;
+            };
+            format!("{};", expression.expression.span().source_text(source_text))
+        } else {
+            // `return;` becomes `continue;`
+            let mut loop_body = String::new();
+            let mut start = self.body.span.start;
+            for span in &body.returns {
+                loop_body.push_str(Span::new(start, span.start).source_text(source_text));
+                loop_body.push_str("continue");
+                // After `return`
+                start = span.start + 6;
+            }
+            loop_body.push_str(Span::new(start, self.body.span.end).source_text(source_text));
+            loop_body
+        };
+        Some((format!("for ({binding}) {loop_body}"), body.uses_this))
+    }
+}
+
+/// The statements of the body of a callback which behave differently in the body of a loop.
+#[derive(Debug, Default)]
+struct CallbackBody {
+    /// The `return;` statements of the callback, which become `continue;`
+    returns: Vec<Span>,
+    /// `return value;`, or a `return;` within a loop of the callback
+    has_other_returns: bool,
+    /// `this` or `arguments`, which are the ones of the enclosing function in a loop
+    uses_this: bool,
+    /// `var` declarations, which are hoisted to the enclosing function in a loop
+    has_var: bool,
+    loop_depth: usize,
+    /// The depth of the arrow functions within the callback, which have their own `return`
+    arrow_depth: usize,
+}
+
+impl<'a> Visit<'a> for CallbackBody {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if self.arrow_depth == 0 {
+            if stmt.argument.is_some() || self.loop_depth > 0 {
+                self.has_other_returns = true;
+            } else {
+                self.returns.push(stmt.span);
+            }
+        }
+        walk_return_statement(self, stmt);
+    }
+
+    fn visit_this_expression(&mut self, _expr: &ThisExpression) {
+        self.uses_this = true;
+    }
+
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if ident.name == "arguments" {
+            self.uses_this = true;
         }
     }
+
+    fn visit_variable_declaration(&mut self, decl: &VariableDeclaration<'a>) {
+        if decl.kind.is_var() && self.arrow_depth == 0 {
+            self.has_var = true;
+        }
+        walk_variable_declaration(self, decl);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        self.loop_depth += 1;
+        walk_for_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.loop_depth += 1;
+        walk_for_in_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        self.loop_depth += 1;
+        walk_for_of_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        self.loop_depth += 1;
+        walk_do_while_statement(self, stmt);
+        self.loop_depth -= 1;
+    }
+
+    fn visit_arrow_expression(&mut self, expr: &ArrowFunctionExpression<'a>) {
+        self.arrow_depth += 1;
+        walk_arrow_expression(self, expr);
+        self.arrow_depth -= 1;
+    }
+
+    // Functions and classes have their own `this`, `arguments`, `return` and `var`
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+/// Whether `expression` references one of `names`.
+fn references_any(expression: &Expression, names: &[&str]) -> bool {
+    struct ReferenceFinder<'n> {
+        names: &'n [&'n str],
+        found: bool,
+    }
+
+    impl<'a> Visit<'a> for ReferenceFinder<'_> {
+        fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+            self.found |= self.names.contains(&ident.name.as_str());
+        }
+    }
+
+    let mut finder = ReferenceFinder { names, found: false };
+    finder.visit_expression(expression);
+    finder.found
+}
+
+/// Whether `statement` has comments outside of `spans`, which a fix of the statement would
+/// remove.
+fn has_comments_outside(statement: Span, spans: &[Span], ctx: &LintContext) -> bool {
+    ctx.comments_in_span(statement).any(|(_, comment)| {
+        !spans.iter().any(|span| span.start <= comment.start && comment.end <= span.end)
+    })
 }
 
 pub const IGNORED_OBJECTS: phf::Set<&'static str> = phf_set! {
@@ -122,7 +429,102 @@ fn test() {
         r"foo.forEach(function element(element, element1) {})",
         r"this._listeners.forEach((listener: () => void) => listener());",
         r"return foo.forEach(element => {bar(element)});",
+        r"foo.forEach(element => bar(element));",
+        r"foo.forEach((element, index) => { bar(element, index); });",
+        r"foo.forEach(function (element) { return; });",
+        r"foo.forEach(function (element) { this.bar(element); });",
+        r"foo.forEach(bar);",
+        r"foo.forEach(bar.baz);",
+        r"foo.forEach(element => bar(element), thisArgument);",
+        r"foo.forEach(async element => { await bar(element); });",
+        r"foo.forEach(element => { for (const item of element) { return; } });",
+        r"foo.forEach(element => { return bar(element); });",
+        r"foo.forEach(element => { var bar = element; });",
+        r"foo.forEach(foo => bar(foo));",
+        r"foo.forEach(/* comment */ element => bar(element));",
+    ];
+
+    let fix = vec![
+        (r"foo.forEach(element => bar(element));", r"for (const element of foo) bar(element);"),
+        (r"foo.forEach(element => bar(element))", r"for (const element of foo) bar(element);"),
+        (r"foo.forEach(element => ({ element }));", r"for (const element of foo) ({ element });"),
+        (
+            r"foo.bar().forEach((element) => { bar(element); });",
+            r"for (const element of foo.bar()) { bar(element); }",
+        ),
+        (
+            r"foo.forEach(({ bar }, index) => { baz(bar, index); });",
+            r"for (const [index, { bar }] of foo.entries()) { baz(bar, index); }",
+        ),
+        (
+            r"(foo || bar).forEach((element, index) => { baz(element, index); });",
+            r"for (const [index, element] of (foo || bar).entries()) { baz(element, index); }",
+        ),
+        (
+            r"foo.forEach(element => { if (!element) return; bar(element); });",
+            r"for (const element of foo) { if (!element) continue; bar(element); }",
+        ),
+        (
+            r"foo.forEach(element => { switch (element) { case 1: return; } });",
+            r"for (const element of foo) { switch (element) { case 1: continue; } }",
+        ),
+        (
+            r"foo.forEach(element => { element.forEach(item => { return; }); });",
+            r"for (const element of foo) { element.forEach(item => { return; }); }",
+        ),
+        (
+            r"foo.forEach(function (element) { bar(element, function () { return this; }); });",
+            r"for (const element of foo) { bar(element, function () { return this; }); }",
+        ),
+        (
+            r"foo.forEach(element => { element = bar(element); baz(element); });",
+            r"for (let element of foo) { element = bar(element); baz(element); }",
+        ),
+        (
+            r"foo.forEach(element => { /* comment */ bar(element); });",
+            r"for (const element of foo) { /* comment */ bar(element); }",
+        ),
+        (
+            r"if (foo) foo.forEach(element => bar(element));",
+            r"if (foo) for (const element of foo) bar(element);",
+        ),
+        // Would change the behavior of the code
+        (
+            r"foo.forEach(element => { for (const item of element) { return; } });",
+            r"foo.forEach(element => { for (const item of element) { return; } });",
+        ),
+        (
+            r"foo.forEach(element => { return bar(element); });",
+            r"foo.forEach(element => { return bar(element); });",
+        ),
+        (
+            r"foo.forEach(element => { var bar = element; });",
+            r"foo.forEach(element => { var bar = element; });",
+        ),
+        (r"foo.forEach(foo => bar(foo));", r"foo.forEach(foo => bar(foo));"),
+        (
+            r"foo.forEach(element => bar(element), thisArgument);",
+            r"foo.forEach(element => bar(element), thisArgument);",
+        ),
+        (
+            r"foo.forEach(element => bar(element)); // comment",
+            r"for (const element of foo) bar(element); // comment",
+        ),
+        (
+            r"foo.forEach(/* comment */ element => bar(element));",
+            r"foo.forEach(/* comment */ element => bar(element));",
+        ),
+        (
+            r"foo.forEach(async element => { await bar(element); });",
+            r"foo.forEach(async element => { await bar(element); });",
+        ),
+        // Suggestions are not applied
+        (r"foo.forEach(bar);", r"foo.forEach(bar);"),
+        (
+            r"foo.forEach(function (element) { this.bar(element); });",
+            r"foo.forEach(function (element) { this.bar(element); });",
+        ),
     ];
 
-    Tester::new(NoArrayForEach::NAME, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayForEach::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, CallExpression, Expression, Statement},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -41,12 +41,18 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// array.reduce((obj, item) => {
+    ///   obj[item] = null;
+    ///   return obj;
+    /// }, {});
     /// ```
     NoArrayReduce,
     restriction
 );
 
 impl Rule for NoArrayReduce {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let allow_simple_operations = value
             .as_object()
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{match_member_expression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -54,6 +54,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoDocumentCookie {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::AssignmentExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentExpression(assignment_expr) = node.kind() else {
             return;
@@ -63,7 +63,7 @@ impl Rule for NoEmptyFile {
 }
 
 fn has_triple_slash_directive(ctx: &LintContext<'_>) -> bool {
-    for (kind, span) in ctx.semantic().trivias().comments() {
+    for (kind, span) in ctx.comments() {
         if !kind.is_single_line() {
             continue;
         }
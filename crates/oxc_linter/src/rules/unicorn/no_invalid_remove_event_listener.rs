@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -44,6 +44,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoInvalidRemoveEventListener {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
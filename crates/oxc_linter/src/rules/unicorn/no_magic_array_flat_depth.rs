@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{CallExpression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -43,6 +43,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoMagicArrayFlatDepth {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expression) = node.kind() else {
             return;
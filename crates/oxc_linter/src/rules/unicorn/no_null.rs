@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{
         Argument, BinaryExpression, CallExpression, Expression, NullLiteral, VariableDeclarator,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -55,7 +55,8 @@ declare_oxc_lint!(
     /// let foo
     /// ```
     NoNull,
-    style
+    style,
+    fix
 );
 
 fn match_null_arg(call_expr: &CallExpression, index: usize, span: Span) -> bool {
@@ -163,6 +164,8 @@ fn match_call_expression_pass_case(null_literal: &NullLiteral, call_expr: &CallE
 }
 
 impl Rule for NoNull {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::NullLiteral]);
+
     fn from_configuration(value: serde_json::Value) -> Self {
         Self {
             check_strict_equality: value
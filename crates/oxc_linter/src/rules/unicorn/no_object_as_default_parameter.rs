@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPatternKind, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -41,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoObjectAsDefaultParameter {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::AssignmentPattern]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::AssignmentPattern(assignment_pat) = node.kind() else {
             return;
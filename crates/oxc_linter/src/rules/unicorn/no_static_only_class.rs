@@ -1,4 +1,4 @@
-use oxc_ast::AstKind;
+use oxc_ast::{AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -43,6 +43,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for NoStaticOnlyClass {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::Class]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::Class(class) = node.kind() else {
             return;
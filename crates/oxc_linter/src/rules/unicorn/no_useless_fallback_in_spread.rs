@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{GetSpan, Span};
@@ -35,10 +35,13 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUselessFallbackInSpread,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessFallbackInSpread {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::LogicalExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::LogicalExpression(logical_expression) = node.kind() else {
             return;
@@ -125,7 +125,8 @@ declare_oxc_lint!(
     ///
     /// ```
     NoUselessSpread,
-    correctness
+    correctness,
+    fix
 );
 
 impl Rule for NoUselessSpread {
@@ -1,18 +1,36 @@
-use itertools::Itertools;
 use lazy_static::lazy_static;
 use oxc_ast::{
     ast::{
-        Argument, CallExpression, Expression, ReturnStatement, Statement, TSType, TSTypeAnnotation,
+        ArrowFunctionExpression, AssignmentPattern, BindingPatternKind, CallExpression, Expression,
+        ReturnStatement, Statement, VariableDeclarator, YieldExpression,
     },
-    AstKind,
+    AstKind, AstType,
 };
-use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
 use regex::Regex;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    context::LintContext,
+    fixer::{helpers::remove_arguments, Fix},
+    rule::Rule,
+    AstNode,
+};
 
-use crate::{context::LintContext, rule::Rule};
+fn no_useless_undefined_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "eslint-plugin-unicorn(no-useless-undefined): Do not use useless `undefined`.",
+    )
+    .with_help("`undefined` is the default value, omit it.")
+    .with_label(span)
+}
 
-static COMPARE_FUNCTION_NAMES: &'static [&str] = &[
+/// Functions whose `undefined` arguments are meaningful, e.g. `expect(foo).toBe(undefined)`
+const COMPARE_FUNCTION_NAMES: [&str; 19] = [
     "is",
     "equal",
     "notEqual",
@@ -34,9 +52,12 @@ static COMPARE_FUNCTION_NAMES: &'static [&str] = &[
     "strictNotSame",
 ];
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
 pub struct NoUselessUndefined {
+    /// Check `undefined` arguments of function calls
     check_arguments: bool,
+    /// Check arrow functions returning `undefined`
     check_arrow_function_body: bool,
 }
 
@@ -49,16 +70,50 @@ impl Default for NoUselessUndefined {
 declare_oxc_lint!(
     /// ### What it does
     ///
+    /// Disallow useless `undefined`.
     ///
     /// ### Why is this bad?
     ///
+    /// `undefined` is the default value of new variables, parameters, return
+    /// statements and arguments, so passing it explicitly is redundant.
+    ///
+    /// The `return undefined` of functions with a return type annotation are
+    /// allowed, as TypeScript requires a `return` statement for them.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// let foo = undefined;
+    /// const bar = () => undefined;
+    /// function baz(qux = undefined) {}
+    /// foo(undefined);
+    ///
+    /// // Good
+    /// let foo;
+    /// const bar = () => {};
+    /// function baz(qux) {}
+    /// foo();
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "unicorn/no-useless-undefined": [
+    ///     "error",
+    ///     { "checkArguments": true, "checkArrowFunctionBody": true }
+    ///   ]
+    /// }
     /// ```
+    ///
+    /// * `checkArguments`: report the `undefined` arguments at the end of function calls,
+    ///   `true` by default
+    /// * `checkArrowFunctionBody`: report the arrow functions returning `undefined`,
+    ///   `true` by default
     NoUselessUndefined,
-    correctness, // TODO: change category to `correctness`, `suspicious`, `pedantic`, `perf`, `restriction`, or `style`
-             // See <https://oxc.rs/docs/contribute/linter.html#rule-category> for details
+    pedantic,
+    fix,
+    config = NoUselessUndefined
 );
 
 lazy_static! {
@@ -66,725 +121,702 @@ lazy_static! {
 }
 
 impl Rule for NoUselessUndefined {
-    fn run_once<'a>(&self, ctx: &LintContext<'a>) {
-        // println!("{:#?}", ctx.nodes().iter().collect_vec());
-
-        for node in ctx.nodes().iter() {
-            println!("{:#?}", node);
-            match node.kind() {
-                // `return undefined;`
-                AstKind::Function(function) => {
-                    let Some(body) = &function.body else {
-                        continue;
-                    };
-
-                    for statement in &body.statements {
-                        let Statement::ReturnStatement(return_statement) = statement else {
-                            continue;
-                        };
-
-                        let Some(Expression::Identifier(identifier)) = &return_statement.argument
-                        else {
-                            continue;
-                        };
-
-                        if identifier.name == "undefined" {
-                            match &function.return_type {
-                                Some(_) => {
-                                    continue;
-                                }
-                                None => {}
-                            }
-                        }
-
-                        ctx.diagnostic(
-                            OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                                .with_label(return_statement.span),
-                        );
-                    }
-                    // let Some(Expression::Identifier(identifier)) = &return_statement.argument
-                    // else {
-                    //     return;
-                    // };
-
-                    // if identifier.name == "undefined" {
-                    //     ctx.scopes();
-                    //     identifier.
-                    //     ctx.diagnostic(
-                    //         OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                    //             .with_label(return_statement.span),
-                    //     );
-                    // }
-                }
-
-                // `yield undefined;`
-                AstKind::YieldExpression(yield_expression) => {
-                    let Some(Expression::Identifier(argument)) = &yield_expression.argument else {
-                        return;
-                    };
-
-                    if argument.name != "undefined" {
-                        return;
-                    }
-
-                    if yield_expression.delegate {
-                        return;
-                    }
-
-                    ctx.diagnostic(
-                        OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                            .with_label(yield_expression.span),
-                    )
-                }
-                // `() => undefined`
-                AstKind::ArrowFunctionExpression(arrow_function_expression) => {
-                    if !self.check_arrow_function_body {
-                        return;
-                    }
-
-                    for statement in &arrow_function_expression.body.statements {
-                        match statement {
-                            Statement::ReturnStatement(return_statement) => {
-                                let Some(Expression::Identifier(argument)) =
-                                    &return_statement.argument
-                                else {
-                                    continue;
-                                };
-                                if argument.name != "undefined" {
-                                    continue;
-                                }
-
-                                match &arrow_function_expression.return_type {
-                                    Some(_) => {
-                                        continue;
-                                    }
-                                    None => {}
-                                }
-
-                                ctx.diagnostic(
-                                    OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                                        .with_label(return_statement.span),
-                                );
-                            }
-                            Statement::ExpressionStatement(expression_statement) => {
-                                let Expression::Identifier(identifier_reference) =
-                                    &expression_statement.expression
-                                else {
-                                    continue;
-                                };
-
-                                if identifier_reference.name != "undefined" {
-                                    continue;
-                                }
-                                ctx.diagnostic(
-                                    OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                                        .with_label(identifier_reference.span),
-                                );
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-
-                // `let foo = undefined` / `var foo = undefined`
-                AstKind::VariableDeclaration(variable_declaration) => {
-                    if variable_declaration.kind.is_const() {
-                        return;
-                    }
-
-                    for declaration in &variable_declaration.declarations {
-                        if declaration.kind.is_const() {
-                            continue;
-                        }
-
-                        let Some(Expression::Identifier(identifier)) = &declaration.init else {
-                            continue;
-                        };
-
-                        if identifier.name == "undefined" {
-                            ctx.diagnostic(
-                                OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                                    .with_label(identifier.span),
-                            );
-                        }
-                    }
-                }
-
-                // `const { foo = undefined } = {};`
-                AstKind::AssignmentPattern(assignment_pattern) => {
-                    let Expression::Identifier(identifier) = &assignment_pattern.right else {
-                        return;
-                    };
-
-                    if identifier.name == "undefined" {
-                        ctx.diagnostic(
-                            OxcDiagnostic::warn("Dot not use useless `undefined`.")
-                                .with_label(identifier.span),
-                        );
-                    }
-                }
-
-                AstKind::CallExpression(call_expression) => {
-                    if !self.check_arguments {
-                        return;
-                    }
-
-                    if should_ignore(&call_expression.callee) {
-                        return;
-                    }
-
-                    let argument_nodes = &call_expression.arguments;
-
-                    if is_function_bind_call(&call_expression) && argument_nodes.len() != 1 {
-                        return;
-                    }
-
-                    let mut undefined_arguments = vec![];
-
-                    for argument in argument_nodes.iter().rev() {
-                        if let Argument::Identifier(identifier) = argument {
-                            if identifier.name == "undefined" {
-                                undefined_arguments.insert(0, identifier);
-                            } else {
-                                break;
-                            }
-                        }
-                    }
-
-                    if undefined_arguments.len() == 0 {
-                        return;
-                    }
-
-                    let first_undefined_argument = undefined_arguments.first();
-                    let last_undefined_argument = undefined_arguments.last();
-
-                    let span = LabeledSpan::new(
-                        Some("Do not use useless `undefined`".to_string()),
-                        first_undefined_argument.unwrap().span.start as usize,
-                        last_undefined_argument.unwrap().span.end as usize,
-                    );
-
-                    ctx.diagnostic(
-                        OxcDiagnostic::warn("Dot not use useless `undefined`.").with_label(span),
-                    );
-                }
-                _ => {}
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[
+        AstType::ReturnStatement,
+        AstType::YieldExpression,
+        AstType::ArrowFunctionExpression,
+        AstType::VariableDeclarator,
+        AstType::AssignmentPattern,
+        AstType::CallExpression,
+    ]);
+
+    fn from_configuration(value: Value) -> Self {
+        value
+            .get(0)
+            .and_then(|config| serde_json::from_value(config.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), OxcDiagnostic> {
+        let Some(config) = value.get(0) else { return Ok(()) };
+        serde_json::from_value::<Self>(config.clone())
+            .map(|_| ())
+            .map_err(|err| OxcDiagnostic::warn(err.to_string()))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::ReturnStatement(return_statement) => {
+                check_return_statement(node, return_statement, ctx);
+            }
+            AstKind::YieldExpression(yield_expression) => {
+                check_yield_expression(yield_expression, ctx);
             }
+            AstKind::ArrowFunctionExpression(arrow) if self.check_arrow_function_body => {
+                check_arrow_function_body(arrow, ctx);
+            }
+            AstKind::VariableDeclarator(declarator) => check_variable_declarator(declarator, ctx),
+            AstKind::AssignmentPattern(pattern) => check_assignment_pattern(node, pattern, ctx),
+            AstKind::CallExpression(call) if self.check_arguments => check_arguments(call, ctx),
+            _ => {}
         }
     }
 }
 
-fn is_function_bind_call(call_expression: &CallExpression<'_>) -> bool {
-    if call_expression.optional {
-        return false;
+/// Whether `expression` is `undefined`, possibly parenthesized, which is not shadowed.
+fn is_undefined(expression: &Expression, ctx: &LintContext) -> bool {
+    matches!(
+        expression.without_parenthesized(),
+        Expression::Identifier(ident) if ctx.is_global_reference(ident, "undefined")
+    )
+}
+
+/// Remove `expression` along with the whitespace before it, keeping the comments:
+/// `return /* */ undefined;` gives `return /* */;`.
+fn remove_with_leading_whitespace<'a>(expression: &Expression, ctx: &LintContext<'a>) -> Fix<'a> {
+    let span = expression.span();
+    let before = ctx.source_text()[..span.start as usize].trim_end();
+    #[allow(clippy::cast_possible_truncation)]
+    Fix::delete(Span::new(before.len() as u32, span.end))
+}
+
+/// `return undefined;`, unless the function has a return type, e.g. `(): undefined => {}`
+fn check_return_statement<'a>(
+    node: &AstNode<'a>,
+    return_statement: &ReturnStatement<'a>,
+    ctx: &LintContext<'a>,
+) {
+    let Some(argument) = &return_statement.argument else { return };
+    if !is_undefined(argument, ctx) {
+        return;
+    }
+    let has_return_type =
+        ctx.nodes().iter_parents(node.id()).find_map(|parent| match parent.kind() {
+            AstKind::Function(function) => Some(function.return_type.is_some()),
+            AstKind::ArrowFunctionExpression(arrow) => Some(arrow.return_type.is_some()),
+            _ => None,
+        });
+    if has_return_type == Some(true) {
+        return;
     }
+    ctx.diagnostic_with_fix(no_useless_undefined_diagnostic(argument.span()), |_| {
+        remove_with_leading_whitespace(argument, ctx)
+    });
+}
 
-    match &call_expression.callee {
-        Expression::StaticMemberExpression(static_member_expression) => {
-            if static_member_expression.property.name == "bind" {
-                return true;
-            }
+/// `yield undefined;`
+fn check_yield_expression<'a>(yield_expression: &YieldExpression<'a>, ctx: &LintContext<'a>) {
+    if yield_expression.delegate {
+        return;
+    }
+    let Some(argument) = &yield_expression.argument else { return };
+    if !is_undefined(argument, ctx) {
+        return;
+    }
+    ctx.diagnostic_with_fix(no_useless_undefined_diagnostic(argument.span()), |_| {
+        remove_with_leading_whitespace(argument, ctx)
+    });
+}
+
+/// `() => undefined`, unless the function has a return type, e.g. `(): undefined => undefined`
+fn check_arrow_function_body<'a>(arrow: &ArrowFunctionExpression<'a>, ctx: &LintContext<'a>) {
+    if !arrow.expression || arrow.return_type.is_some() {
+        return;
+    }
+    let Some(Statement::ExpressionStatement(statement)) = arrow.body.statements.first() else {
+        return;
+    };
+    let body = &statement.expression;
+    if !is_undefined(body, ctx) {
+        return;
+    }
+    ctx.diagnostic_with_fix(no_useless_undefined_diagnostic(body.span()), |fixer| {
+        fixer.replace(body.span(), "{}")
+    });
+}
+
+/// `let foo = undefined;`, `const` declarations need to be initialized
+fn check_variable_declarator<'a>(declarator: &VariableDeclarator<'a>, ctx: &LintContext<'a>) {
+    if declarator.kind.is_const() {
+        return;
+    }
+    let Some(init) = &declarator.init else { return };
+    if !is_undefined(init, ctx) {
+        return;
+    }
+    ctx.diagnostic_with_fix(no_useless_undefined_diagnostic(init.span()), |_| {
+        Fix::delete(Span::new(declarator.id.span().end, declarator.span.end))
+    });
+}
 
-            return false;
+/// `function foo(bar = undefined) {}` and `const {foo = undefined} = {};`
+fn check_assignment_pattern<'a>(
+    node: &AstNode<'a>,
+    pattern: &AssignmentPattern<'a>,
+    ctx: &LintContext<'a>,
+) {
+    if !is_undefined(&pattern.right, ctx) {
+        return;
+    }
+    let left = &pattern.left;
+    let is_parameter =
+        matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::FormalParameter(_)));
+    ctx.diagnostic_with_fix(no_useless_undefined_diagnostic(pattern.right.span()), |fixer| {
+        let span = Span::new(left.span().end, pattern.span.end);
+        // `(foo: Type = undefined) => {}` gives `(foo?: Type) => {}`
+        match &left.kind {
+            BindingPatternKind::BindingIdentifier(ident)
+                if is_parameter && left.type_annotation.is_some() && !left.optional =>
+            {
+                #[allow(clippy::cast_possible_truncation)]
+                let name_end = ident.span.start + ident.name.len() as u32;
+                let annotation = Span::new(name_end, left.span().end);
+                fixer.replace(
+                    Span::new(name_end, span.end),
+                    format!("?{}", fixer.source_range(annotation)),
+                )
+            }
+            _ => fixer.delete_range(span),
         }
-        _ => return false,
+    });
+}
+
+/// `foo(bar, undefined)`, the `undefined` arguments at the end of a call
+fn check_arguments<'a>(call: &CallExpression<'a>, ctx: &LintContext<'a>) {
+    if should_ignore(&call.callee) {
+        return;
+    }
+    let arguments = &call.arguments;
+    // The arguments following `this` of `foo.bind(thisArg, ...args)` are bound
+    if is_function_bind_call(call) && arguments.len() != 1 {
+        return;
     }
+    let undefined_arguments = arguments
+        .iter()
+        .rev()
+        .take_while(|argument| {
+            argument.as_expression().is_some_and(|expression| is_undefined(expression, ctx))
+        })
+        .count();
+    if undefined_arguments == 0 {
+        return;
+    }
+    let range = arguments.len() - undefined_arguments..arguments.len();
+    let span = Span::new(arguments[range.start].span().start, arguments[range.end - 1].span().end);
+    ctx.diagnostic_with_fix(no_useless_undefined_diagnostic(span), |fixer| {
+        remove_arguments(fixer.source_text(), arguments, range)
+    });
+}
+
+fn is_function_bind_call(call: &CallExpression) -> bool {
+    !call.optional
+        && matches!(&call.callee, Expression::StaticMemberExpression(member) if member.property.name == "bind")
 }
 
 fn should_ignore(callee: &Expression) -> bool {
     let name = match callee {
-        Expression::Identifier(identifier) => identifier.name.to_string(),
-        Expression::StaticMemberExpression(static_member_expression) => {
-            static_member_expression.property.name.to_string()
-        }
+        Expression::Identifier(ident) => ident.name.as_str(),
+        Expression::StaticMemberExpression(member) => member.property.name.as_str(),
         _ => return false,
     };
-
-    return COMPARE_FUNCTION_NAMES.contains(&name.as_str())
-        // `array.push(undefined)`
-        || name == "push"
-        // `array.unshift(undefined)`
-        || name == "unshift"
-        // `array.includes(undefined)`
-        || name == "includes"
-
-        // `set.add(undefined)`
-        || name == "add"
-        // `set.has(undefined)`
-        || name == "has"
-
-        // `map.set(foo, undefined)`
-        || name == "set"
-
-        // `React.createContext(undefined)`
+    COMPARE_FUNCTION_NAMES.contains(&name)
+        // `array.push(undefined)`, `array.unshift(undefined)` and `array.includes(undefined)`
+        || matches!(name, "push" | "unshift" | "includes")
+        // `set.add(undefined)`, `set.has(undefined)` and `map.set(foo, undefined)`
+        || matches!(name, "add" | "has" | "set")
+        // `React.createContext(undefined)` and `setState(undefined)`
         || name == "createContext"
-        // `setState(undefined)`
-        || SET_REG.is_match(name.as_str())
-
+        || SET_REG.is_match(name)
         // https://vuejs.org/api/reactivity-core.html#ref
-        || name == "ref'";
+        || name == "ref"
 }
 
 #[test]
 fn test() {
-    use crate::tester::Tester;
     use std::path::PathBuf;
 
+    use crate::tester::Tester;
+
     let pass = vec![
-        // ("function foo() {return;}", None, None, None),
-        // ("const foo = () => {};", None, None, None),
-        // ("let foo;", None, None, None),
-        // ("var foo;", None, None, None),
-        // ("const foo = undefined;", None, None, None),
-        // ("foo();", None, None, None),
-        // ("foo(bar,);", None, None, None),
-        // ("foo(undefined, bar);", None, None, None),
-        // ("const {foo} = {};", None, None, None),
-        // ("function foo({bar} = {}) {}", None, None, None),
-        // ("function foo(bar) {}", None, None, None),
-        // ("function* foo() {yield* undefined;}", None, None, None),
-        // ("if (Object.is(foo, undefined)){}", None, None, None),
-        // ("t.is(foo, undefined)", None, None, None),
-        // ("assert.equal(foo, undefined, message)", None, None, None),
-        // ("assert.notEqual(foo, undefined, message)", None, None, None),
-        // ("assert.strictEqual(foo, undefined, message)", None, None, None),
-        // ("assert.notStrictEqual(foo, undefined, message)", None, None, None),
-        // (r#"assert.propertyVal(foo, "bar", undefined, message)"#, None, None, None),
-        // (r#"assert.notPropertyVal(foo, "bar", undefined, message)"#, None, None, None),
-        // ("expect(foo).not(undefined)", None, None, None),
-        // (r#"expect(foo).to.have.property("bar", undefined)"#, None, None, None),
-        // ("expect(foo).toBe(undefined)", None, None, None),
-        // ("expect(foo).toContain(undefined)", None, None, None),
-        // ("expect(foo).toContainEqual(undefined)", None, None, None),
-        // ("expect(foo).toEqual(undefined)", None, None, None),
-        // ("t.same(foo, undefined)", None, None, None),
-        // ("t.notSame(foo, undefined)", None, None, None),
-        // ("t.strictSame(foo, undefined)", None, None, None),
-        // ("t.strictNotSame(foo, undefined)", None, None, None),
-        // ("expect(someFunction).toHaveBeenCalledWith(1, 2, undefined);", None, None, None),
-        // ("set.add(undefined);", None, None, None),
-        // ("map.set(foo, undefined);", None, None, None),
-        // ("array.push(foo, undefined);", None, None, None),
-        // ("array.push(undefined);", None, None, None),
-        // ("array.unshift(foo, undefined);", None, None, None),
-        // ("array.unshift(undefined);", None, None, None),
-        // ("createContext(undefined);", None, None, None),
-        // ("React.createContext(undefined);", None, None, None),
-        // ("setState(undefined)", None, None, None),
-        // ("setState?.(undefined)", None, None, None),
-        // ("props.setState(undefined)", None, None, None),
-        // ("props.setState?.(undefined)", None, None, None),
-        // ("array.includes(undefined)", None, None, None),
-        // ("set.has(undefined)", None, None, None),
-        // ("foo.bind(bar, undefined)", None, None, None),
-        // ("foo.bind(...bar, undefined)", None, None, None),
-        // ("foo.bind(...[], undefined)", None, None, None),
-        // ("foo.bind(...[undefined], undefined)", None, None, None),
-        // ("foo.bind(bar, baz, undefined)", None, None, None),
-        // ("foo?.bind(bar, undefined)", None, None, None),
-        // ("foo(undefined, undefined);", Some(serde_json::json!(optionsIgnoreArguments)), None, None),
-        // ("foo.bind(undefined);", Some(serde_json::json!(optionsIgnoreArguments)), None, None),
-        // (
-        //     "const foo = () => undefined",
-        //     Some(serde_json::json!(optionsIgnoreArrowFunctionBody)),
-        //     None,
-        //     None,
-        // ),
-        // ("prerenderPaths?.add(entry)", None, None, None),
-        // (
-        //     r#"
-        // 				function getThing(): string | undefined {
-        // 					if (someCondition) {
-        // 						return "hello world";
-        // 					}
-
-        // 					return undefined;
-        // 				}
-        // 			"#,
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     r#"
-        // 				function getThing(): string | undefined {
-        // 					if (someCondition) {
-        // 						return "hello world";
-        // 					} else if (anotherCondition) {
-        // 						return undefined;
-        // 					}
-
-        // 					return undefined;
-        // 				}
-        // 			"#,
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // ("const foo = (): undefined => {return undefined;}", None, None, None),
-        // ("const foo = (): undefined => undefined;", None, None, None),
-        // ("const foo = (): string => undefined;", None, None, None),
-        // ("const foo = function (): undefined {return undefined}", None, None, None),
-        // ("export function foo(): undefined {return undefined}", None, None, None),
-        // (
-        //     "
-        // 				const object = {
-        // 					method(): undefined {
-        // 						return undefined;
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				class A {
-        // 					method(): undefined {
-        // 						return undefined;
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				const A = class A {
-        // 					method(): undefined {
-        // 						return undefined
-        // 					}
-        // 				};
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				class A {
-        // 					static method(): undefined {
-        // 						return undefined
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				class A {
-        // 					get method(): undefined {
-        // 						return undefined;
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				class A {
-        // 					static get method(): undefined {
-        // 						return undefined;
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				class A {
-        // 					#method(): undefined {
-        // 						return undefined;
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				class A {
-        // 					private method(): undefined {
-        // 						return undefined;
-        // 					}
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // ("createContext<T>(undefined);", None, None, None),
-        // ("React.createContext<T>(undefined);", None, None, None),
-        // Oxlint doesn't support vue?
-        // (
-        //     "
-        // 				<script>
-        // 				import {ref} from 'vue';
-
-        // 				export default {
-        // 					setup() {
-        // 						return {foo: ref(undefined)};
-        // 					}
-        // 				};
-        // 				</script>
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				<script setup>
-        // 				import * as vue from 'vue';
-        // 				const foo = vue.ref(undefined);
-        // 				</script>
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
+        ("function foo() {return;}", None, None, None),
+        ("const foo = () => {};", None, None, None),
+        ("let foo;", None, None, None),
+        ("var foo;", None, None, None),
+        ("const foo = undefined;", None, None, None),
+        ("foo();", None, None, None),
+        ("foo(bar,);", None, None, None),
+        ("foo(undefined, bar);", None, None, None),
+        ("const {foo} = {};", None, None, None),
+        ("function foo({bar} = {}) {}", None, None, None),
+        ("function foo(bar) {}", None, None, None),
+        ("function* foo() {yield* undefined;}", None, None, None),
+        ("if (Object.is(foo, undefined)){}", None, None, None),
+        ("t.is(foo, undefined)", None, None, None),
+        ("assert.equal(foo, undefined, message)", None, None, None),
+        ("assert.notEqual(foo, undefined, message)", None, None, None),
+        ("assert.strictEqual(foo, undefined, message)", None, None, None),
+        ("assert.notStrictEqual(foo, undefined, message)", None, None, None),
+        (r#"assert.propertyVal(foo, "bar", undefined, message)"#, None, None, None),
+        (r#"assert.notPropertyVal(foo, "bar", undefined, message)"#, None, None, None),
+        ("expect(foo).not(undefined)", None, None, None),
+        (r#"expect(foo).to.have.property("bar", undefined)"#, None, None, None),
+        ("expect(foo).toBe(undefined)", None, None, None),
+        ("expect(foo).toContain(undefined)", None, None, None),
+        ("expect(foo).toContainEqual(undefined)", None, None, None),
+        ("expect(foo).toEqual(undefined)", None, None, None),
+        ("t.same(foo, undefined)", None, None, None),
+        ("t.notSame(foo, undefined)", None, None, None),
+        ("t.strictSame(foo, undefined)", None, None, None),
+        ("t.strictNotSame(foo, undefined)", None, None, None),
+        ("expect(someFunction).toHaveBeenCalledWith(1, 2, undefined);", None, None, None),
+        ("set.add(undefined);", None, None, None),
+        ("map.set(foo, undefined);", None, None, None),
+        ("array.push(foo, undefined);", None, None, None),
+        ("array.push(undefined);", None, None, None),
+        ("array.unshift(foo, undefined);", None, None, None),
+        ("array.unshift(undefined);", None, None, None),
+        ("createContext(undefined);", None, None, None),
+        ("React.createContext(undefined);", None, None, None),
+        ("setState(undefined)", None, None, None),
+        ("setState?.(undefined)", None, None, None),
+        ("props.setState(undefined)", None, None, None),
+        ("props.setState?.(undefined)", None, None, None),
+        ("array.includes(undefined)", None, None, None),
+        ("set.has(undefined)", None, None, None),
+        ("foo.bind(bar, undefined)", None, None, None),
+        ("foo.bind(...bar, undefined)", None, None, None),
+        ("foo.bind(...[], undefined)", None, None, None),
+        ("foo.bind(...[undefined], undefined)", None, None, None),
+        ("foo.bind(bar, baz, undefined)", None, None, None),
+        ("foo?.bind(bar, undefined)", None, None, None),
+        (
+            "foo(undefined, undefined);",
+            Some(serde_json::json!([{ "checkArguments": false }])),
+            None,
+            None,
+        ),
+        (
+            "foo.bind(undefined);",
+            Some(serde_json::json!([{ "checkArguments": false }])),
+            None,
+            None,
+        ),
+        (
+            "const foo = () => undefined",
+            Some(serde_json::json!([{ "checkArrowFunctionBody": false }])),
+            None,
+            None,
+        ),
+        ("prerenderPaths?.add(entry)", None, None, None),
+        // `undefined` is shadowed
+        ("function foo(undefined) {return undefined;}", None, None, None),
+        ("function foo(undefined) {bar(undefined);}", None, None, None),
+        (
+            r#"
+        				function getThing(): string | undefined {
+        					if (someCondition) {
+        						return "hello world";
+        					}
+
+        					return undefined;
+        				}
+        			"#,
+            None,
+            None,
+            None,
+        ),
+        (
+            r#"
+        				function getThing(): string | undefined {
+        					if (someCondition) {
+        						return "hello world";
+        					} else if (anotherCondition) {
+        						return undefined;
+        					}
+
+        					return undefined;
+        				}
+        			"#,
+            None,
+            None,
+            None,
+        ),
+        ("const foo = (): undefined => {return undefined;}", None, None, None),
+        ("const foo = (): undefined => undefined;", None, None, None),
+        ("const foo = (): string => undefined;", None, None, None),
+        ("const foo = function (): undefined {return undefined}", None, None, None),
+        ("export function foo(): undefined {return undefined}", None, None, None),
+        (
+            "
+        				const object = {
+        					method(): undefined {
+        						return undefined;
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				class A {
+        					method(): undefined {
+        						return undefined;
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				const A = class A {
+        					method(): undefined {
+        						return undefined
+        					}
+        				};
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				class A {
+        					static method(): undefined {
+        						return undefined
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				class A {
+        					get method(): undefined {
+        						return undefined;
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				class A {
+        					static get method(): undefined {
+        						return undefined;
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				class A {
+        					#method(): undefined {
+        						return undefined;
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				class A {
+        					private method(): undefined {
+        						return undefined;
+        					}
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        ("createContext<T>(undefined);", None, None, None),
+        ("React.createContext<T>(undefined);", None, None, None),
+        (
+            "
+        				<script>
+        				import {ref} from 'vue';
+
+        				export default {
+        					setup() {
+        						return {foo: ref(undefined)};
+        					}
+        				};
+        				</script>
+        			",
+            None,
+            None,
+            Some(PathBuf::from("foo.vue")),
+        ),
+        (
+            "
+        				<script setup>
+        				import * as vue from 'vue';
+        				const foo = vue.ref(undefined);
+        				</script>
+        			",
+            None,
+            None,
+            Some(PathBuf::from("foo.vue")),
+        ),
+        ("foo(undefined);", Some(serde_json::json!([{ "checkArguments": false }])), None, None),
+        (
+            "const foo = () => undefined;",
+            Some(serde_json::json!([{ "checkArrowFunctionBody": false }])),
+            None,
+            None,
+        ),
     ];
 
     let fail = vec![
-        // ("function foo() {return undefined;}", None, None, None),
+        ("function foo() {return undefined;}", None, None, None),
         ("const foo = () => undefined;", None, None, None),
-        // ("const foo = () => {return undefined;};", None, None, None),
-        // ("function foo() {return       undefined;}", None, None, None),
-        // ("function foo() {return /* comment */ undefined;}", None, None, None),
-        // ("function* foo() {yield undefined;}", None, None, None),
-        // ("function* foo() {yield                 undefined;}", None, None, None),
-        // ("let a = undefined;", None, None, None),
-        // ("let a = undefined, b = 2;", None, None, None),
-        // ("var a = undefined;", None, None, None),
-        // ("var a = undefined, b = 2;", None, None, None),
-        // ("foo(undefined);", None, None, None),
-        // ("foo(undefined, undefined);", None, None, None),
-        // ("foo(undefined,);", None, None, None),
-        // ("foo(undefined, undefined,);", None, None, None),
-        // ("foo(bar, undefined);", None, None, None),
-        // ("foo(bar, undefined, undefined);", None, None, None),
-        // ("foo(undefined, bar, undefined);", None, None, None),
-        // ("foo(bar, undefined,);", None, None, None),
-        // ("foo(undefined, bar, undefined,);", None, None, None),
-        // ("foo(bar, undefined, undefined,);", None, None, None),
-        // ("foo(undefined, bar, undefined, undefined,);", None, None, None),
-        // (
-        //     "
-        // 					foo(
-        // 						undefined,
-        // 						bar,
-        // 						undefined,
-        // 						undefined,
-        // 						undefined,
-        // 						undefined,
-        // 					)
-        // 				",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // ("const {foo = undefined} = {};", None, None, None),
-        // ("const [foo = undefined] = [];", None, None, None),
-        // ("function foo(bar = undefined) {}", None, None, None),
-        // ("function foo({bar = undefined}) {}", None, None, None),
-        // ("function foo({bar = undefined} = {}) {}", None, None, None),
-        // ("function foo([bar = undefined]) {}", None, None, None),
-        // ("function foo([bar = undefined] = []) {}", None, None, None),
-        // ("return undefined;", None, None, None), // {				"parserOptions": {					"sourceType": "script",					"ecmaFeatures": {						"globalReturn": true,					},				},			},
-        // (
-        //     "
-        // 					function foo():undefined {
-        // 						function nested() {
-        // 							return undefined;
-        // 						}
-
-        // 						return nested();
-        // 					}
-        // 				",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				foo(
-        // 					undefined,
-        // 					bar,
-        // 					undefined,
-        // 					undefined,
-        // 					undefined,
-        // 					undefined,
-        // 				)
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // ("function foo([bar = undefined] = []) {}", None, None, None),
-        // ("foo(bar, undefined, undefined);", None, None, None),
-        // ("let a = undefined, b = 2;", None, None, None),
-        // (
-        //     "
-        // 				function foo() {
-        // 					return /* */ (
-        // 						/* */
-        // 						(
-        // 							/* */
-        // 							undefined
-        // 							/* */
-        // 						)
-        // 						/* */
-        // 					) /* */ ;
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				function * foo() {
-        // 					yield /* */ (
-        // 						/* */
-        // 						(
-        // 							/* */
-        // 							undefined
-        // 							/* */
-        // 						)
-        // 						/* */
-        // 					) /* */ ;
-        // 				}
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // (
-        //     "
-        // 				const foo = () => /* */ (
-        // 					/* */
-        // 					(
-        // 						/* */
-        // 						undefined
-        // 						/* */
-        // 					)
-        // 					/* */
-        // 				);
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // ("foo.bind(undefined)", None, None, None),
-        // ("bind(foo, undefined)", None, None, None),
-        // ("foo.bind?.(bar, undefined)", None, None, None),
-        // ("foo[bind](bar, undefined)", None, None, None),
-        // ("foo.notBind(bar, undefined)", None, None, None),
-        // (
-        //     "
-        // 				<script>
-        // 				import {nextTick} from 'vue';
-        // 				const foo = nextTick(undefined);
-        // 				</script>
-        // 			",
-        //     None,
-        //     None,
-        //     None,
-        // ),
-        // ("function f(foo: Type = undefined) {}", None, None, None),
-        // ("function f(foo?: Type = undefined) {}", None, None, None),
-        // ("const f = function(foo: Type = undefined) {}", None, None, None),
-        // ("const f = (foo: Type = undefined) => {}", None, None, None),
-        // ("const f = {method(foo: Type = undefined){}}", None, None, None),
-        // ("const f = class {method(foo: Type = undefined){}}", None, None, None),
-        // ("function f(foo = undefined) {}", None, None, None),
-        // ("function a({foo} = undefined) {}", None, None, Some(PathBuf::from("'foo.ts'"))),
+        ("const foo = () => {return undefined;};", None, None, None),
+        ("function foo() {return       undefined;}", None, None, None),
+        ("function foo() {return /* comment */ undefined;}", None, None, None),
+        ("function* foo() {yield undefined;}", None, None, None),
+        ("function* foo() {yield                 undefined;}", None, None, None),
+        ("let a = undefined;", None, None, None),
+        ("let a = undefined, b = 2;", None, None, None),
+        ("var a = undefined;", None, None, None),
+        ("var a = undefined, b = 2;", None, None, None),
+        ("foo(undefined);", None, None, None),
+        ("foo(undefined, undefined);", None, None, None),
+        ("foo(undefined,);", None, None, None),
+        ("foo(undefined, undefined,);", None, None, None),
+        ("foo(bar, undefined);", None, None, None),
+        ("foo(bar, undefined, undefined);", None, None, None),
+        ("foo(undefined, bar, undefined);", None, None, None),
+        ("foo(bar, undefined,);", None, None, None),
+        ("foo(undefined, bar, undefined,);", None, None, None),
+        ("foo(bar, undefined, undefined,);", None, None, None),
+        ("foo(undefined, bar, undefined, undefined,);", None, None, None),
+        (
+            "
+        					foo(
+        						undefined,
+        						bar,
+        						undefined,
+        						undefined,
+        						undefined,
+        						undefined,
+        					)
+        				",
+            None,
+            None,
+            None,
+        ),
+        ("const {foo = undefined} = {};", None, None, None),
+        ("const [foo = undefined] = [];", None, None, None),
+        ("function foo(bar = undefined) {}", None, None, None),
+        ("function foo({bar = undefined}) {}", None, None, None),
+        ("function foo({bar = undefined} = {}) {}", None, None, None),
+        ("function foo([bar = undefined]) {}", None, None, None),
+        ("function foo([bar = undefined] = []) {}", None, None, None),
+        ("return undefined;", None, None, Some(PathBuf::from("foo.cjs"))),
+        (
+            "
+        					function foo():undefined {
+        						function nested() {
+        							return undefined;
+        						}
+
+        						return nested();
+        					}
+        				",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				foo(
+        					undefined,
+        					bar,
+        					undefined,
+        					undefined,
+        					undefined,
+        					undefined,
+        				)
+        			",
+            None,
+            None,
+            None,
+        ),
+        ("function foo([bar = undefined] = []) {}", None, None, None),
+        ("foo(bar, undefined, undefined);", None, None, None),
+        ("let a = undefined, b = 2;", None, None, None),
+        (
+            "
+        				function foo() {
+        					return /* */ (
+        						/* */
+        						(
+        							/* */
+        							undefined
+        							/* */
+        						)
+        						/* */
+        					) /* */ ;
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				function * foo() {
+        					yield /* */ (
+        						/* */
+        						(
+        							/* */
+        							undefined
+        							/* */
+        						)
+        						/* */
+        					) /* */ ;
+        				}
+        			",
+            None,
+            None,
+            None,
+        ),
+        (
+            "
+        				const foo = () => /* */ (
+        					/* */
+        					(
+        						/* */
+        						undefined
+        						/* */
+        					)
+        					/* */
+        				);
+        			",
+            None,
+            None,
+            None,
+        ),
+        ("foo.bind(undefined)", None, None, None),
+        ("bind(foo, undefined)", None, None, None),
+        ("foo.bind?.(bar, undefined)", None, None, None),
+        ("foo[bind](bar, undefined)", None, None, None),
+        ("foo.notBind(bar, undefined)", None, None, None),
+        (
+            "
+        				<script>
+        				import {nextTick} from 'vue';
+        				const foo = nextTick(undefined);
+        				</script>
+        			",
+            None,
+            None,
+            Some(PathBuf::from("foo.vue")),
+        ),
+        ("function f(foo: Type = undefined) {}", None, None, None),
+        ("function f(foo?: Type = undefined) {}", None, None, None),
+        ("const f = function(foo: Type = undefined) {}", None, None, None),
+        ("const f = (foo: Type = undefined) => {}", None, None, None),
+        ("const f = {method(foo: Type = undefined){}}", None, None, None),
+        ("const f = class {method(foo: Type = undefined){}}", None, None, None),
+        ("function f(foo = undefined) {}", None, None, None),
+        ("function a({foo} = undefined) {}", None, None, Some(PathBuf::from("foo.ts"))),
     ];
 
-    // let fix = vec![
-    //     ("function foo() {return undefined;}", "function foo() {return;}", None),
-    //     ("const foo = () => undefined;", "const foo = () => {};", None),
-    //     ("const foo = () => {return undefined;};", "const foo = () => {return;};", None),
-    //     ("function foo() {return       undefined;}", "function foo() {return;}", None),
-    //     (
-    //         "function foo() {return /* comment */ undefined;}",
-    //         "function foo() {return /* comment */;}",
-    //         None,
-    //     ),
-    //     ("function* foo() {yield undefined;}", "function* foo() {yield;}", None),
-    //     ("function* foo() {yield                 undefined;}", "function* foo() {yield;}", None),
-    //     ("let a = undefined;", "let a;", None),
-    //     ("let a = undefined, b = 2;", "let a, b = 2;", None),
-    //     ("var a = undefined;", "var a;", None),
-    //     ("var a = undefined, b = 2;", "var a, b = 2;", None),
-    //     ("foo(undefined);", "foo();", None),
-    //     ("foo(undefined, undefined);", "foo();", None),
-    //     ("foo(undefined,);", "foo();", None),
-    //     ("foo(undefined, undefined,);", "foo();", None),
-    //     ("foo(bar, undefined);", "foo(bar);", None),
-    //     ("foo(bar, undefined, undefined);", "foo(bar);", None),
-    //     ("foo(undefined, bar, undefined);", "foo(undefined, bar);", None),
-    //     ("foo(bar, undefined,);", "foo(bar,);", None),
-    //     ("foo(undefined, bar, undefined,);", "foo(undefined, bar,);", None),
-    //     ("foo(bar, undefined, undefined,);", "foo(bar,);", None),
-    //     ("foo(undefined, bar, undefined, undefined,);", "foo(undefined, bar,);", None),
-    //     (
-    //         "
-    // 						foo(
-    // 							undefined,
-    // 							bar,
-    // 							undefined,
-    // 							undefined,
-    // 							undefined,
-    // 							undefined,
-    // 						)
-    // 					",
-    //         "
-    // 						foo(
-    // 							undefined,
-    // 							bar,
-    // 						)
-    // 					",
-    //         None,
-    //     ),
-    //     ("const {foo = undefined} = {};", "const {foo} = {};", None),
-    //     ("const [foo = undefined] = [];", "const [foo] = [];", None),
-    //     ("function foo(bar = undefined) {}", "function foo(bar) {}", None),
-    //     ("function foo({bar = undefined}) {}", "function foo({bar}) {}", None),
-    //     ("function foo({bar = undefined} = {}) {}", "function foo({bar} = {}) {}", None),
-    //     ("function foo([bar = undefined]) {}", "function foo([bar]) {}", None),
-    //     ("function foo([bar = undefined] = []) {}", "function foo([bar] = []) {}", None),
-    //     ("return undefined;", "return;", None),
-    //     (
-    //         "
-    // 						function foo():undefined {
-    // 							function nested() {
-    // 								return undefined;
-    // 							}
-
-    // 							return nested();
-    // 						}
-    // 					",
-    //         "
-    // 						function foo():undefined {
-    // 							function nested() {
-    // 								return;
-    // 							}
-
-    // 							return nested();
-    // 						}
-    // 					",
-    //         None,
-    //     ),
-    // ];
-    Tester::new(NoUselessUndefined::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("function foo() {return undefined;}", "function foo() {return;}", None),
+        ("const foo = () => undefined;", "const foo = () => {};", None),
+        ("const foo = () => {return undefined;};", "const foo = () => {return;};", None),
+        ("function foo() {return       undefined;}", "function foo() {return;}", None),
+        (
+            "function foo() {return /* comment */ undefined;}",
+            "function foo() {return /* comment */;}",
+            None,
+        ),
+        ("function* foo() {yield undefined;}", "function* foo() {yield;}", None),
+        ("function* foo() {yield                 undefined;}", "function* foo() {yield;}", None),
+        ("let a = undefined;", "let a;", None),
+        ("let a = undefined, b = 2;", "let a, b = 2;", None),
+        ("var a = undefined;", "var a;", None),
+        ("var a = undefined, b = 2;", "var a, b = 2;", None),
+        ("foo(undefined);", "foo();", None),
+        ("foo(undefined, undefined);", "foo();", None),
+        ("foo(undefined,);", "foo();", None),
+        ("foo(undefined, undefined,);", "foo();", None),
+        ("foo(bar, undefined);", "foo(bar);", None),
+        ("foo(bar, undefined, undefined);", "foo(bar);", None),
+        ("foo(undefined, bar, undefined);", "foo(undefined, bar);", None),
+        ("foo(bar, undefined,);", "foo(bar,);", None),
+        ("foo(undefined, bar, undefined,);", "foo(undefined, bar,);", None),
+        ("foo(bar, undefined, undefined,);", "foo(bar,);", None),
+        ("foo(undefined, bar, undefined, undefined,);", "foo(undefined, bar,);", None),
+        (
+            "
+    						foo(
+    							undefined,
+    							bar,
+    							undefined,
+    							undefined,
+    							undefined,
+    							undefined,
+    						)
+    					",
+            "
+    						foo(
+    							undefined,
+    							bar,
+    						)
+    					",
+            None,
+        ),
+        ("const {foo = undefined} = {};", "const {foo} = {};", None),
+        ("const [foo = undefined] = [];", "const [foo] = [];", None),
+        ("function foo(bar = undefined) {}", "function foo(bar) {}", None),
+        ("function foo({bar = undefined}) {}", "function foo({bar}) {}", None),
+        ("function foo({bar = undefined} = {}) {}", "function foo({bar} = {}) {}", None),
+        ("function foo([bar = undefined]) {}", "function foo([bar]) {}", None),
+        ("function foo([bar = undefined] = []) {}", "function foo([bar] = []) {}", None),
+        ("function f(foo: Type = undefined) {}", "function f(foo?: Type) {}", None),
+        ("function f(foo?: Type = undefined) {}", "function f(foo?: Type) {}", None),
+        ("const f = (foo: Type = undefined) => {}", "const f = (foo?: Type) => {}", None),
+        ("function f({foo}: Type = undefined) {}", "function f({foo}: Type) {}", None),
+        ("let a: string = undefined;", "let a: string;", None),
+        ("foo.bind(undefined)", "foo.bind()", None),
+        ("const foo = () => (undefined);", "const foo = () => {};", None),
+        (
+            "
+    						function foo():undefined {
+    							function nested() {
+    								return undefined;
+    							}
+
+    							return nested();
+    						}
+    					",
+            "
+    						function foo():undefined {
+    							function nested() {
+    								return;
+    							}
+
+    							return nested();
+    						}
+    					",
+            None,
+        ),
+    ];
+    Tester::new(NoUselessUndefined::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
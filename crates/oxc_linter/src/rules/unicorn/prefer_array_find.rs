@@ -0,0 +1,424 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, CallExpression, Expression, MemberExpression, VariableDeclarator},
+    AstKind, AstType,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::{
+    ast_util::{is_method_call, outermost_paren_parent},
+    context::LintContext,
+    rule::Rule,
+    AstNode,
+};
+
+fn prefer_array_find_diagnostic(span: Span, method: &str, over: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "eslint-plugin-unicorn(prefer-array-find): Prefer `.{method}(…)` over {over}."
+    ))
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone, Deserialize, JsonSchema)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PreferArrayFind {
+    /// Check the last element of `.filter(…)`, e.g. `.at(-1)`
+    check_from_last: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer [`Array#find()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/find)
+    /// over the first element of [`Array#filter()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/filter).
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `.find(…)` stops at the first matching element and doesn't create an
+    /// array, while `.filter(…)` goes through the whole array.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const item = array.filter(x => isUnicorn(x))[0];
+    /// const item = array.filter(x => isUnicorn(x)).shift();
+    /// const [item] = array.filter(x => isUnicorn(x));
+    ///
+    /// // Good
+    /// const item = array.find(x => isUnicorn(x));
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// { "unicorn/prefer-array-find": ["error", { "checkFromLast": false }] }
+    /// ```
+    ///
+    /// * `checkFromLast`: also prefer [`Array#findLast()`](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast)
+    ///   over the last element of `.filter(…)`, e.g. `array.filter(fn).at(-1)` and
+    ///   `array.filter(fn).pop()`, `false` by default
+    PreferArrayFind,
+    perf,
+    fix,
+    config = PreferArrayFind
+);
+
+/// How an element of the result of `.filter(…)` is accessed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Access {
+    /// `[0]`
+    ZeroIndex,
+    /// `.shift()`
+    Shift,
+    /// `.at(-1)`
+    AtMinusOne,
+    /// `.pop()`
+    Pop,
+}
+
+impl Access {
+    fn method(self) -> &'static str {
+        match self {
+            Self::ZeroIndex | Self::Shift => "find",
+            Self::AtMinusOne | Self::Pop => "findLast",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::ZeroIndex => "`[0]`",
+            Self::Shift => "`.shift()`",
+            Self::AtMinusOne => "`.at(-1)`",
+            Self::Pop => "`.pop()`",
+        }
+    }
+}
+
+impl Rule for PreferArrayFind {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
+    fn from_configuration(value: Value) -> Self {
+        value
+            .get(0)
+            .and_then(|config| serde_json::from_value(config.clone()).ok())
+            .unwrap_or_default()
+    }
+
+    fn validate_configuration(value: &Value) -> Result<(), OxcDiagnostic> {
+        let Some(config) = value.get(0) else { return Ok(()) };
+        serde_json::from_value::<Self>(config.clone())
+            .map(|_| ())
+            .map_err(|err| OxcDiagnostic::warn(err.to_string()))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::CallExpression(call) = node.kind() else { return };
+        let Some(filter_span) = filter_property_span(call) else { return };
+
+        if let Some((access, access_span, object_end)) = self.element_access(node, call.span, ctx) {
+            ctx.diagnostic_with_fix(
+                prefer_array_find_diagnostic(filter_span, access.method(), access.description()),
+                |fixer| {
+                    vec![
+                        fixer.replace(filter_span, access.method()),
+                        fixer.delete_range(Span::new(object_end, access_span.end)),
+                    ]
+                },
+            );
+            return;
+        }
+
+        let Some(AstKind::VariableDeclarator(declarator)) =
+            outermost_paren_parent(node, ctx).map(AstNode::kind)
+        else {
+            return;
+        };
+        if declarator.init.as_ref().map(|init| init.without_parenthesized().span())
+            != Some(call.span)
+        {
+            return;
+        }
+        match &declarator.id.kind {
+            BindingPatternKind::ArrayPattern(_) => {
+                check_destructuring(declarator, filter_span, ctx);
+            }
+            BindingPatternKind::BindingIdentifier(_) => {
+                self.check_declaration(node, declarator, filter_span, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl PreferArrayFind {
+    /// The access of an element of the array of `node`, with the span of the access and the end of
+    /// the array, e.g. `[0]` of `array[0]`. `None` when the element is not accessed, or when it is
+    /// assigned, e.g. `array[0] = foo`.
+    fn element_access<'a>(
+        &self,
+        node: &AstNode<'a>,
+        span: Span,
+        ctx: &LintContext<'a>,
+    ) -> Option<(Access, Span, u32)> {
+        let parent = outermost_paren_parent(node, ctx)?;
+        let AstKind::MemberExpression(member) = parent.kind() else { return None };
+        if member.optional() || member.object().without_parenthesized().span() != span {
+            return None;
+        }
+        let object_end = member.object().span().end;
+
+        if let MemberExpression::ComputedMemberExpression(computed) = member {
+            let is_zero = matches!(
+                &computed.expression,
+                Expression::NumericLiteral(literal) if literal.value == 0.0
+            );
+            let is_assigned = matches!(
+                ctx.nodes().parent_kind(parent.id()),
+                Some(AstKind::SimpleAssignmentTarget(_))
+            );
+            return (is_zero && !is_assigned).then_some((
+                Access::ZeroIndex,
+                computed.span,
+                object_end,
+            ));
+        }
+
+        let access = match member.static_property_name()? {
+            "shift" => Access::Shift,
+            "pop" if self.check_from_last => Access::Pop,
+            "at" if self.check_from_last => Access::AtMinusOne,
+            _ => return None,
+        };
+        let Some(AstKind::CallExpression(call)) = ctx.nodes().parent_kind(parent.id()) else {
+            return None;
+        };
+        if call.optional || call.callee.without_parenthesized().span() != member.span() {
+            return None;
+        }
+        let is_access = match access {
+            Access::AtMinusOne => {
+                call.arguments.len() == 1
+                    && call.arguments[0].as_expression().is_some_and(is_minus_one)
+            }
+            _ => call.arguments.is_empty(),
+        };
+        is_access.then_some((access, call.span, object_end))
+    }
+
+    /// `const foo = array.filter(fn);` where `foo` is only used as `foo[0]`. `.shift()` and
+    /// `.pop()` change `foo`, they give another element each time.
+    fn check_declaration<'a>(
+        &self,
+        node: &AstNode<'a>,
+        declarator: &VariableDeclarator<'a>,
+        filter_span: Span,
+        ctx: &LintContext<'a>,
+    ) {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        let Some(symbol_id) = ident.symbol_id.get() else { return };
+        // The exported variables may be used by other modules
+        let is_exported = ctx
+            .nodes()
+            .iter_parents(node.id())
+            .any(|parent| matches!(parent.kind(), AstKind::ExportNamedDeclaration(_)));
+        if is_exported {
+            return;
+        }
+
+        let mut accesses = vec![];
+        for reference in ctx.semantic().symbol_references(symbol_id) {
+            let reference_node = ctx.nodes().get_node(reference.node_id());
+            match self.element_access(reference_node, reference.span(), ctx) {
+                Some(access @ (Access::ZeroIndex | Access::AtMinusOne, _, _)) => {
+                    accesses.push(access);
+                }
+                _ => return,
+            }
+        }
+        let Some(&(access, _, _)) = accesses.first() else { return };
+        if accesses.iter().any(|(other, _, _)| *other != access) {
+            return;
+        }
+
+        let diagnostic =
+            prefer_array_find_diagnostic(filter_span, access.method(), access.description());
+        // `const foo: Type[] = array.filter(fn);` would need a new type
+        if declarator.id.type_annotation.is_some() {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            let mut fixes = vec![fixer.replace(filter_span, access.method())];
+            fixes.extend(accesses.iter().map(|(_, access_span, object_end)| {
+                fixer.delete_range(Span::new(*object_end, access_span.end))
+            }));
+            fixes
+        });
+    }
+}
+
+/// `const [foo] = array.filter(fn);`
+fn check_destructuring<'a>(
+    declarator: &VariableDeclarator<'a>,
+    filter_span: Span,
+    ctx: &LintContext<'a>,
+) {
+    let BindingPatternKind::ArrayPattern(pattern) = &declarator.id.kind else { return };
+    let [Some(element)] = pattern.elements.as_slice() else { return };
+    if pattern.rest.is_some() {
+        return;
+    }
+    let diagnostic =
+        prefer_array_find_diagnostic(filter_span, "find", "destructuring `.filter(…)`");
+    // `const [foo = bar] = array.filter(fn);` uses `bar` for `undefined` only
+    if matches!(element.kind, BindingPatternKind::AssignmentPattern(_))
+        || declarator.id.type_annotation.is_some()
+    {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| {
+        vec![
+            fixer.replace(pattern.span, fixer.source_range(element.span()).to_string()),
+            fixer.replace(filter_span, "find"),
+        ]
+    });
+}
+
+/// The span of `filter` of `array.filter(fn)` or `array.filter(fn, thisArg)`.
+fn filter_property_span(call: &CallExpression) -> Option<Span> {
+    if call.optional || !is_method_call(call, None, Some(&["filter"]), Some(1), Some(2)) {
+        return None;
+    }
+    if call.arguments.iter().any(|argument| argument.as_expression().is_none()) {
+        return None;
+    }
+    match call.callee.without_parenthesized() {
+        Expression::StaticMemberExpression(member) if !member.optional => {
+            Some(member.property.span)
+        }
+        _ => None,
+    }
+}
+
+fn is_minus_one(expression: &Expression) -> bool {
+    matches!(
+        expression.without_parenthesized(),
+        Expression::UnaryExpression(unary)
+            if unary.operator == UnaryOperator::UnaryNegation
+                && matches!(&unary.argument, Expression::NumericLiteral(literal) if literal.raw == "1")
+    )
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let check_from_last = || Some(serde_json::json!([{ "checkFromLast": true }]));
+
+    let pass = vec![
+        ("array.find(foo)", None, None, None),
+        ("array.filter(foo)", None, None, None),
+        ("array.filter(foo)[1]", None, None, None),
+        ("array.filter(foo)[-1]", None, None, None),
+        ("array.filter(foo)[0.5]", None, None, None),
+        ("array.filter(foo)['0']", None, None, None),
+        ("array.filter(foo)[index]", None, None, None),
+        ("array.filter(foo)?.[0]", None, None, None),
+        ("array.filter?.(foo)[0]", None, None, None),
+        ("array?.filter(foo)[0]", None, None, None),
+        ("array[filter](foo)[0]", None, None, None),
+        ("array.filter()[0]", None, None, None),
+        ("array.filter(foo, thisArgument, extraArgument)[0]", None, None, None),
+        ("array.filter(...foo)[0]", None, None, None),
+        ("array.filter(foo)[0] = bar", None, None, None),
+        ("array.filter(foo)[0] += bar", None, None, None),
+        ("array.filter(foo)[0]++", None, None, None),
+        ("[array.filter(foo)[0]] = bar", None, None, None),
+        ("array.filter(foo).shift(bar)", None, None, None),
+        ("array.filter(foo).shift?.()", None, None, None),
+        ("array.filter(foo)?.shift()", None, None, None),
+        ("array.filter(foo).unshift()", None, None, None),
+        ("shift(array.filter(foo))", None, None, None),
+        ("const [foo, bar] = array.filter(baz)", None, None, None),
+        ("const [, foo] = array.filter(bar)", None, None, None),
+        ("const [...foo] = array.filter(bar)", None, None, None),
+        ("const [foo, ...bar] = array.filter(baz)", None, None, None),
+        ("const [] = array.filter(foo)", None, None, None),
+        ("const foo = array.filter(bar)", None, None, None),
+        ("const foo = array.filter(bar); console.log(foo)", None, None, None),
+        ("const foo = array.filter(bar); foo[0]; foo[1]", None, None, None),
+        ("const foo = array.filter(bar); foo[0]; foo.length", None, None, None),
+        ("const foo = array.filter(bar); foo.shift(); foo.shift()", None, None, None),
+        ("let foo = array.filter(bar); foo[0]; foo = []", None, None, None),
+        ("export const foo = array.filter(bar); foo[0]", None, None, None),
+        ("array.filter(foo).at(-1)", None, None, None),
+        ("array.filter(foo).pop()", None, None, None),
+        (
+            "array.filter(foo).at(-1)",
+            Some(serde_json::json!([{ "checkFromLast": false }])),
+            None,
+            None,
+        ),
+        ("array.filter(foo).at(1)", check_from_last(), None, None),
+        ("array.filter(foo).at(-2)", check_from_last(), None, None),
+        ("array.filter(foo).at(-1, bar)", check_from_last(), None, None),
+        ("array.filter(foo).pop(bar)", check_from_last(), None, None),
+        ("const foo = array.filter(bar); foo[0]; foo.at(-1)", check_from_last(), None, None),
+        ("const foo = array.filter(bar); foo.pop()", check_from_last(), None, None),
+    ];
+
+    let fail = vec![
+        ("array.filter(foo)[0]", None, None, None),
+        ("array.filter(foo, thisArgument)[0]", None, None, None),
+        ("(array.filter(foo))[0]", None, None, None),
+        ("array.filter(foo).shift()", None, None, None),
+        ("const item = array.filter(foo)[0]", None, None, None),
+        ("const [foo] = array.filter(bar)", None, None, None),
+        ("const [{foo}] = array.filter(bar)", None, None, None),
+        ("const [foo = baz] = array.filter(bar)", None, None, None),
+        ("const [foo]: Foo[] = array.filter(bar)", None, None, None),
+        ("const foo = array.filter(bar); const first = foo[0];", None, None, None),
+        ("let foo = array.filter(bar); console.log(foo[0], foo[0]);", None, None, None),
+        ("const foo: Foo[] = array.filter(bar); foo[0];", None, None, None),
+        ("array.filter(foo).at(-1)", check_from_last(), None, None),
+        ("array.filter(foo).pop()", check_from_last(), None, None),
+        ("array.filter(foo)[0]", check_from_last(), None, None),
+        ("const foo = array.filter(bar); foo.at(-1); foo.at(-1);", check_from_last(), None, None),
+    ];
+
+    let fix = vec![
+        ("array.filter(foo)[0]", "array.find(foo)", None),
+        ("array.filter(foo, thisArgument)[0]", "array.find(foo, thisArgument)", None),
+        ("(array.filter(foo))[0]", "(array.find(foo))", None),
+        ("array.filter(foo).shift()", "array.find(foo)", None),
+        ("array.filter(foo)\n\t.shift()", "array.find(foo)", None),
+        ("const [foo] = array.filter(bar)", "const foo = array.find(bar)", None),
+        ("const [{foo}] = array.filter(bar)", "const {foo} = array.find(bar)", None),
+        ("const [foo = baz] = array.filter(bar)", "const [foo = baz] = array.filter(bar)", None),
+        (
+            "const foo = array.filter(bar); const first = foo[0];",
+            "const foo = array.find(bar); const first = foo;",
+            None,
+        ),
+        (
+            "let foo = array.filter(bar); console.log(foo[0], foo[0]);",
+            "let foo = array.find(bar); console.log(foo, foo);",
+            None,
+        ),
+        ("array.filter(foo).at(-1)", "array.findLast(foo)", check_from_last()),
+        ("array.filter(foo).pop()", "array.findLast(foo)", check_from_last()),
+        (
+            "const foo = array.filter(bar); foo.at(-1);",
+            "const foo = array.findLast(bar); foo;",
+            check_from_last(),
+        ),
+    ];
+
+    Tester::new(PreferArrayFind::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
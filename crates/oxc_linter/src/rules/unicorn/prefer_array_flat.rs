@@ -2,7 +2,7 @@ use oxc_ast::{
     ast::{
         Argument, ArrayExpressionElement, BindingPatternKind, CallExpression, Expression, Statement,
     },
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -61,6 +61,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferArrayFlat {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
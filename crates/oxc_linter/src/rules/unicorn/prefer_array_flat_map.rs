@@ -1,12 +1,15 @@
 use oxc_ast::{
     ast::{Argument, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{ast_util::is_method_call, context::LintContext, rule::Rule, AstNode};
+use crate::{
+    ast_util::is_method_call, context::LintContext, fixer::helpers::replace_preserving_comments,
+    rule::Rule, AstNode,
+};
 
 fn prefer_array_flat_map_diagnostic(span0: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("eslint-plugin-unicorn(prefer-array-flat-map): `Array.flatMap` performs `Array.map` and `Array.flat` in one step.")
@@ -32,11 +35,17 @@ declare_oxc_lint!(
     ///
     /// const bar = [1,2,3].flatMap(i => [i]); // ✓ pass
     /// ```
+    ///
+    /// `.map(…).flat()` and `.map(…).flat(1)` are fixed to `.flatMap(…)`, the other depths of
+    /// `.flat(…)` are not reported.
     PreferArrayFlatMap,
-    style
+    style,
+    fix
 );
 
 impl Rule for PreferArrayFlatMap {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(flat_call_expr) = node.kind() else {
             return;
@@ -70,7 +79,27 @@ impl Rule for PreferArrayFlatMap {
             }
         }
 
-        ctx.diagnostic(prefer_array_flat_map_diagnostic(flat_call_expr.span));
+        let diagnostic = prefer_array_flat_map_diagnostic(flat_call_expr.span);
+        // `foo["map"](fn).flat()`
+        let Expression::StaticMemberExpression(map_member_expr) =
+            call_expr.callee.without_parenthesized()
+        else {
+            ctx.diagnostic(diagnostic);
+            return;
+        };
+        ctx.diagnostic_with_fix(diagnostic, |fixer| {
+            // `.flat()`, along with `?.` of `foo.map(fn)?.flat()`, keeping its comments
+            let flat_span = Span::new(member_expr.object().span().end, flat_call_expr.span.end);
+            vec![
+                fixer.replace(map_member_expr.property.span, "flatMap"),
+                replace_preserving_comments(
+                    fixer.source_text(),
+                    ctx.comments_in_span(flat_span),
+                    flat_span,
+                    "",
+                ),
+            ]
+        });
     }
 }
 
@@ -98,6 +127,7 @@ fn test() {
         ("const bar = [[1],[2],[3]].map(i => [i]).flat(foo)", None),
         ("const bar = [[1],[2],[3]].map(i => [i]).flat(foo.bar)", None),
         ("const bar = [[1],[2],[3]].map(i => [i]).flat(1.00)", None),
+        ("const bar = (foo.map(i => [i]).flat)()", None),
     ];
 
     let fail = vec![
@@ -114,7 +144,49 @@ fn test() {
         ("const bar = (([1,2,3].map(i => [i]))).flat()", None),
         ("let bar = [1,2,3] . map( x => y ) . flat () // 🤪", None),
         ("const bar = [1,2,3].map(i => [i]).flat(1);", None),
+        ("const bar = foo?.map(i => [i]).flat()", None),
+        ("const bar = foo.map(i => [i])?.flat()", None),
+        ("const bar = foo.map?.(i => [i]).flat?.()", None),
+        ("const bar = foo['map'](i => [i]).flat()", None),
+        ("const bar = foo.map(i => [i]) /* comment */ .flat(/* depth */ 1)", None),
+    ];
+
+    let fix = vec![
+        ("const bar = [1,2,3].map(i => [i]).flat()", "const bar = [1,2,3].flatMap(i => [i])", None),
+        (
+            "const bar = [1,2,3].map(i => [i]).flat(1,)",
+            "const bar = [1,2,3].flatMap(i => [i])",
+            None,
+        ),
+        (
+            "const bar = (([1,2,3].map(i => [i]))).flat()",
+            "const bar = (([1,2,3].flatMap(i => [i])))",
+            None,
+        ),
+        (
+            "let bar = [1,2,3] . map( x => y ) . flat () // 🤪",
+            "let bar = [1,2,3] . flatMap( x => y ) // 🤪",
+            None,
+        ),
+        ("const bar = foo?.map(i => [i]).flat()", "const bar = foo?.flatMap(i => [i])", None),
+        ("const bar = foo.map(i => [i])?.flat()", "const bar = foo.flatMap(i => [i])", None),
+        ("const bar = foo.map?.(i => [i]).flat?.()", "const bar = foo.flatMap?.(i => [i])", None),
+        (
+            "const bar = foo['map'](i => [i]).flat()",
+            "const bar = foo['map'](i => [i]).flat()",
+            None,
+        ),
+        (
+            "const bar = foo.map(i => [i]) /* comment */ .flat(/* depth */ 1)",
+            "const bar = foo.flatMap(i => [i])/* comment */ /* depth */ ",
+            None,
+        ),
+        (
+            "const bar = foo.map(i => [i]) // comment\n\t.flat()\nbaz()",
+            "const bar = foo.flatMap(i => [i])// comment\n\nbaz()",
+            None,
+        ),
     ];
 
-    Tester::new(PreferArrayFlatMap::NAME, pass, fail).test_and_snapshot();
+    Tester::new(PreferArrayFlatMap::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
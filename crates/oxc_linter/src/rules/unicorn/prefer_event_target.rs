@@ -1,4 +1,4 @@
-use oxc_ast::{ast::Expression, AstKind};
+use oxc_ast::{ast::Expression, AstKind, AstType};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
@@ -40,6 +40,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferEventTarget {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::IdentifierReference]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::IdentifierReference(ident) = node.kind() else {
             return;
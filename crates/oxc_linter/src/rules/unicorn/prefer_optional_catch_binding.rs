@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{BindingPattern, BindingPatternKind},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -41,6 +41,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferOptionalCatchBinding {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CatchParameter]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CatchParameter(catch_param) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{ArrayExpressionElement, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -38,6 +38,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferSetSize {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::MemberExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::MemberExpression(member_expr) = node.kind() else {
             return;
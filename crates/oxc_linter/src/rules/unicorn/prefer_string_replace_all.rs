@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{Argument, MemberExpression, RegExpFlags},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -31,12 +31,19 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
+    /// foo.replace(/a/g, bar);
+    ///
+    /// // Good
+    /// foo.replaceAll('a', bar);
     /// ```
     PreferStringReplaceAll,
     pedantic
 );
 
 impl Rule for PreferStringReplaceAll {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
@@ -1,6 +1,6 @@
 use oxc_ast::{
     ast::{match_member_expression, CallExpression, Expression, MemberExpression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -44,6 +44,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for PreferTypeError {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::ThrowStatement]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::ThrowStatement(throw_stmt) = node.kind() else {
             return;
@@ -1,7 +1,7 @@
 use lazy_static::lazy_static;
 use oxc_ast::{
     ast::{match_member_expression, Expression},
-    AstKind,
+    AstKind, AstType,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
@@ -51,6 +51,8 @@ declare_oxc_lint!(
 );
 
 impl Rule for ThrowNewError {
+    const NODE_TYPES: Option<&'static [AstType]> = Some(&[AstType::CallExpression]);
+
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::CallExpression(call_expr) = node.kind() else {
             return;
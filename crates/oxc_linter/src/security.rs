@@ -0,0 +1,100 @@
+//! The security preset of [`LintOptions::security`]: the rules reporting code which is
+//! vulnerable to injection, prototype pollution and other weaknesses, for a security scan
+//! separate from the other rules.
+//!
+//! [`LintOptions::security`]: crate::LintOptions::security
+
+use crate::RuleEnum;
+
+/// A rule of the security preset and the weakness of the code it reports.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SecurityRule {
+    pub plugin_name: &'static str,
+    pub rule_name: &'static str,
+    /// The id of the weakness in the Common Weakness Enumeration, e.g. 95 for
+    /// <https://cwe.mitre.org/data/definitions/95.html>
+    pub cwe: u32,
+}
+
+/// The rules of the security preset, which run as errors with `--security`.
+pub(crate) const SECURITY_RULES: &[SecurityRule] = &[
+    // Eval injection
+    SecurityRule { plugin_name: "eslint", rule_name: "no-eval", cwe: 95 },
+    SecurityRule { plugin_name: "eslint", rule_name: "no-script-url", cwe: 95 },
+    // Regular expression denial of service
+    SecurityRule { plugin_name: "oxc", rule_name: "no-unsafe-regex", cwe: 1333 },
+    // Prototype pollution
+    SecurityRule { plugin_name: "eslint", rule_name: "no-proto", cwe: 1321 },
+    SecurityRule { plugin_name: "eslint", rule_name: "no-prototype-builtins", cwe: 1321 },
+    // Cross-site scripting
+    SecurityRule { plugin_name: "react", rule_name: "no-danger", cwe: 79 },
+    // Reverse tabnabbing
+    SecurityRule { plugin_name: "react", rule_name: "jsx-no-target-blank", cwe: 1022 },
+    // Uninitialized memory of `new Buffer(size)`
+    SecurityRule { plugin_name: "unicorn", rule_name: "no-new-buffer", cwe: 908 },
+];
+
+/// The CWE id of the weakness reported by the rule, `None` when it is not a rule of the security
+/// preset.
+pub(crate) fn cwe(plugin_name: &str, rule_name: &str) -> Option<u32> {
+    SECURITY_RULES
+        .iter()
+        .find(|rule| rule.plugin_name == plugin_name && rule.rule_name == rule_name)
+        .map(|rule| rule.cwe)
+}
+
+pub(crate) fn is_security_rule(rule: &RuleEnum) -> bool {
+    cwe(rule.plugin_name(), rule.name()).is_some()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use oxc_allocator::Allocator;
+    use oxc_diagnostics::DiagnosticService;
+
+    use super::SECURITY_RULES;
+    use crate::{
+        rules::RULES, AllowWarnDeny, LintOptions, LintService, LintServiceOptions, Linter,
+    };
+
+    #[test]
+    fn test_security_rules_exist() {
+        for security_rule in SECURITY_RULES {
+            assert!(
+                RULES.iter().any(|rule| rule.plugin_name() == security_rule.plugin_name
+                    && rule.name() == security_rule.rule_name),
+                "{}/{} is not a rule",
+                security_rule.plugin_name,
+                security_rule.rule_name
+            );
+        }
+    }
+
+    #[test]
+    fn test_cwe_with_security() {
+        let cwe = |security: bool| {
+            let options = LintOptions::default()
+                .with_filter(vec![(AllowWarnDeny::Deny, "no-eval".to_string())])
+                .with_security(security);
+            let linter = Linter::from_options(options).unwrap();
+            let options = LintServiceOptions {
+                cwd: Path::new(".").into(),
+                paths: vec![Path::new("test.js").into()],
+                tsconfig: None,
+                cache: None,
+                stdin: None,
+            };
+            let lint_service = LintService::from_linter(linter, options);
+            let allocator = Allocator::default();
+            let diagnostic_service = DiagnosticService::default();
+            let tx_error = diagnostic_service.sender();
+            let messages = lint_service.run_source(&allocator, "eval('1');", None, false, tx_error);
+            messages.iter().map(|message| message.error.cwe).collect::<Vec<_>>()
+        };
+        // The CWE ids are only attached by the security scan
+        assert_eq!(cwe(false), [None]);
+        assert_eq!(cwe(true), [Some(95)]);
+    }
+}
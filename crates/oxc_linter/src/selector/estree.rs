@@ -0,0 +1,550 @@
+//! The ESTree view of the oxc AST which selectors are written against: the ESTree types of the
+//! nodes and the properties of the most common ones.
+
+use std::fmt;
+
+use oxc_ast::{
+    ast::{
+        Argument, ArrayExpressionElement, AssignmentTarget, BindingPatternKind, ChainElement,
+        Expression, FormalParameters, FunctionType, JSXAttributeItem, MemberExpression,
+        MethodDefinitionKind, ModuleExportName, ObjectPropertyKind, PropertyKey, PropertyKind,
+        SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_span::GetSpan;
+
+/// Kinds which are named like their ESTree type.
+macro_rules! same_type {
+    ($kind:expr, $($variant:ident),* $(,)?) => {
+        match $kind {
+            $(AstKind::$variant(_) => Some(stringify!($variant)),)*
+            _ => None,
+        }
+    };
+}
+
+/// The ESTree type of `kind`, e.g. `Literal` for all the literals except template literals.
+/// `None` for the kinds which ESTree does not have, e.g. `Argument` which wraps the arguments of
+/// calls, these are skipped when matching.
+pub(super) fn node_type(kind: AstKind) -> Option<&'static str> {
+    let node_type = match kind {
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::NumericLiteral(_)
+        | AstKind::BigIntLiteral(_)
+        | AstKind::RegExpLiteral(_)
+        | AstKind::StringLiteral(_) => "Literal",
+        AstKind::Function(function) => match function.r#type {
+            FunctionType::FunctionDeclaration => "FunctionDeclaration",
+            FunctionType::FunctionExpression => "FunctionExpression",
+            FunctionType::TSDeclareFunction => "TSDeclareFunction",
+            FunctionType::TSEmptyBodyFunctionExpression => "TSEmptyBodyFunctionExpression",
+        },
+        AstKind::Class(class) if class.is_declaration() => "ClassDeclaration",
+        AstKind::Class(_) => "ClassExpression",
+        AstKind::ObjectProperty(_) => "Property",
+        AstKind::AssignmentTargetWithDefault(_) => "AssignmentPattern",
+        AstKind::BindingRestElement(_) => "RestElement",
+        AstKind::UsingDeclaration(_) => "VariableDeclaration",
+        AstKind::FunctionBody(_) => "BlockStatement",
+        AstKind::Directive(_) => "ExpressionStatement",
+        AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(_)) => "JSXAttribute",
+        _ => same_type!(
+            kind,
+            Program,
+            ThisExpression,
+            ArrayExpression,
+            ObjectExpression,
+            TemplateLiteral,
+            TaggedTemplateExpression,
+            MemberExpression,
+            CallExpression,
+            NewExpression,
+            MetaProperty,
+            SpreadElement,
+            UpdateExpression,
+            UnaryExpression,
+            BinaryExpression,
+            PrivateInExpression,
+            LogicalExpression,
+            ConditionalExpression,
+            AssignmentExpression,
+            SequenceExpression,
+            Super,
+            AwaitExpression,
+            ChainExpression,
+            BlockStatement,
+            VariableDeclaration,
+            VariableDeclarator,
+            EmptyStatement,
+            ExpressionStatement,
+            IfStatement,
+            DoWhileStatement,
+            WhileStatement,
+            ForStatement,
+            ForInStatement,
+            ForOfStatement,
+            ContinueStatement,
+            BreakStatement,
+            ReturnStatement,
+            WithStatement,
+            SwitchStatement,
+            SwitchCase,
+            LabeledStatement,
+            ThrowStatement,
+            TryStatement,
+            CatchClause,
+            DebuggerStatement,
+            AssignmentPattern,
+            ObjectPattern,
+            ArrayPattern,
+            ArrowFunctionExpression,
+            YieldExpression,
+            ClassBody,
+            MethodDefinition,
+            PropertyDefinition,
+            PrivateIdentifier,
+            StaticBlock,
+            ImportExpression,
+            ImportDeclaration,
+            ImportSpecifier,
+            ImportDefaultSpecifier,
+            ImportNamespaceSpecifier,
+            ExportNamedDeclaration,
+            ExportDefaultDeclaration,
+            ExportAllDeclaration,
+            ExportSpecifier,
+            Decorator,
+            TSEnumDeclaration,
+            TSEnumMember,
+            TSTypeAnnotation,
+            TSLiteralType,
+            TSUnionType,
+            TSIntersectionType,
+            TSIndexedAccessType,
+            TSNamedTupleMember,
+            TSAnyKeyword,
+            TSStringKeyword,
+            TSBooleanKeyword,
+            TSNumberKeyword,
+            TSNeverKeyword,
+            TSIntrinsicKeyword,
+            TSUnknownKeyword,
+            TSNullKeyword,
+            TSUndefinedKeyword,
+            TSVoidKeyword,
+            TSSymbolKeyword,
+            TSThisType,
+            TSObjectKeyword,
+            TSBigIntKeyword,
+            TSTypeReference,
+            TSQualifiedName,
+            TSTypeParameterInstantiation,
+            TSTypeParameter,
+            TSTypeParameterDeclaration,
+            TSTypeAliasDeclaration,
+            TSClassImplements,
+            TSInterfaceDeclaration,
+            TSPropertySignature,
+            TSMethodSignature,
+            TSInterfaceHeritage,
+            TSModuleDeclaration,
+            TSModuleBlock,
+            TSTypeLiteral,
+            TSInferType,
+            TSTypeQuery,
+            TSImportType,
+            TSTemplateLiteralType,
+            TSAsExpression,
+            TSSatisfiesExpression,
+            TSTypeAssertion,
+            TSImportEqualsDeclaration,
+            TSExternalModuleReference,
+            TSNonNullExpression,
+            TSInstantiationExpression,
+            JSXElement,
+            JSXOpeningElement,
+            JSXClosingElement,
+            JSXFragment,
+            JSXNamespacedName,
+            JSXMemberExpression,
+            JSXExpressionContainer,
+            JSXSpreadAttribute,
+            JSXIdentifier,
+            JSXText,
+        )?,
+    };
+    Some(node_type)
+}
+
+/// The value of a property of a node.
+#[derive(Debug, Clone)]
+pub(super) enum Value<'a> {
+    Node(AstKind<'a>),
+    Nodes(Vec<AstKind<'a>>),
+    String(&'a str),
+    Number(f64),
+    Bool(bool),
+    Null,
+}
+
+impl<'a> Value<'a> {
+    /// The property `name` of this value, where `length` and indexes are the properties of
+    /// lists, e.g. `arguments.length` and `arguments.0`.
+    pub(super) fn get(&self, name: &str, source_text: &'a str) -> Option<Self> {
+        match self {
+            Self::Node(kind) => property(*kind, name, source_text),
+            Self::Nodes(kinds) if name == "length" =>
+            {
+                #[allow(clippy::cast_precision_loss)]
+                Some(Self::Number(kinds.len() as f64))
+            }
+            Self::Nodes(kinds) => name
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| kinds.get(index).map(|kind| Self::Node(*kind))),
+            _ => None,
+        }
+    }
+}
+
+/// Values are compared with their text, like JavaScript template strings do.
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Node(_) | Self::Nodes(_) => f.write_str("[object Object]"),
+            Self::String(value) => f.write_str(value),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Null => f.write_str("null"),
+        }
+    }
+}
+
+/// The ESTree property `name` of `kind`, e.g. `callee` of a `CallExpression`. Only the
+/// properties of expressions, declarations and literals are supported, the bodies of
+/// statements are not.
+pub(super) fn property<'a>(
+    kind: AstKind<'a>,
+    name: &str,
+    source_text: &'a str,
+) -> Option<Value<'a>> {
+    let value = match (kind, name) {
+        (_, "type") => Value::String(node_type(kind)?),
+        (
+            AstKind::BooleanLiteral(_)
+            | AstKind::NullLiteral(_)
+            | AstKind::NumericLiteral(_)
+            | AstKind::BigIntLiteral(_)
+            | AstKind::RegExpLiteral(_)
+            | AstKind::StringLiteral(_),
+            "raw",
+        ) => Value::String(kind.span().source_text(source_text)),
+
+        (AstKind::IdentifierName(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::IdentifierReference(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::BindingIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::LabelIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::PrivateIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+        (AstKind::JSXIdentifier(ident), "name") => Value::String(ident.name.as_str()),
+
+        (AstKind::StringLiteral(literal), "value") => Value::String(literal.value.as_str()),
+        (AstKind::NumericLiteral(literal), "value") => Value::Number(literal.value),
+        (AstKind::BooleanLiteral(literal), "value") => Value::Bool(literal.value),
+        (AstKind::NullLiteral(_), "value") => Value::Null,
+        (AstKind::BigIntLiteral(literal), "value") => {
+            Value::String(literal.raw.as_str().trim_end_matches('n'))
+        }
+        (AstKind::RegExpLiteral(literal), "value") => {
+            Value::String(literal.span.source_text(source_text))
+        }
+
+        (AstKind::CallExpression(call), "callee") => expression(&call.callee),
+        (AstKind::CallExpression(call), "arguments") => arguments(&call.arguments),
+        (AstKind::CallExpression(call), "optional") => Value::Bool(call.optional),
+        (AstKind::NewExpression(new), "callee") => expression(&new.callee),
+        (AstKind::NewExpression(new), "arguments") => arguments(&new.arguments),
+        (AstKind::ImportExpression(import), "source") => expression(&import.source),
+        (AstKind::TaggedTemplateExpression(tagged), "tag") => expression(&tagged.tag),
+        (AstKind::TaggedTemplateExpression(tagged), "quasi") => {
+            Value::Node(AstKind::TemplateLiteral(&tagged.quasi))
+        }
+        (AstKind::TemplateLiteral(template), "expressions") => {
+            Value::Nodes(template.expressions.iter().map(from_expression).collect())
+        }
+
+        (AstKind::MemberExpression(member), "object") => expression(member.object()),
+        (AstKind::MemberExpression(member), "property") => match member {
+            MemberExpression::StaticMemberExpression(member) => {
+                Value::Node(AstKind::IdentifierName(&member.property))
+            }
+            MemberExpression::ComputedMemberExpression(member) => expression(&member.expression),
+            MemberExpression::PrivateFieldExpression(member) => {
+                Value::Node(AstKind::PrivateIdentifier(&member.field))
+            }
+        },
+        (AstKind::MemberExpression(member), "computed") => Value::Bool(member.is_computed()),
+        (AstKind::MemberExpression(member), "optional") => Value::Bool(member.optional()),
+        (AstKind::MetaProperty(meta), "meta") => Value::Node(AstKind::IdentifierName(&meta.meta)),
+        (AstKind::MetaProperty(meta), "property") => {
+            Value::Node(AstKind::IdentifierName(&meta.property))
+        }
+
+        (AstKind::BinaryExpression(binary), "operator") => Value::String(binary.operator.as_str()),
+        (AstKind::BinaryExpression(binary), "left") => expression(&binary.left),
+        (AstKind::BinaryExpression(binary), "right") => expression(&binary.right),
+        (AstKind::LogicalExpression(logical), "operator") => {
+            Value::String(logical.operator.as_str())
+        }
+        (AstKind::LogicalExpression(logical), "left") => expression(&logical.left),
+        (AstKind::LogicalExpression(logical), "right") => expression(&logical.right),
+        (AstKind::UnaryExpression(unary), "operator") => Value::String(unary.operator.as_str()),
+        (AstKind::UnaryExpression(unary), "argument") => expression(&unary.argument),
+        (AstKind::UnaryExpression(_), "prefix") => Value::Bool(true),
+        (AstKind::UpdateExpression(update), "operator") => Value::String(update.operator.as_str()),
+        (AstKind::UpdateExpression(update), "prefix") => Value::Bool(update.prefix),
+        (AstKind::AssignmentExpression(assignment), "operator") => {
+            Value::String(assignment.operator.as_str())
+        }
+        (AstKind::AssignmentExpression(assignment), "left") => assignment_target(&assignment.left)?,
+        (AstKind::AssignmentExpression(assignment), "right") => expression(&assignment.right),
+        (AstKind::ConditionalExpression(conditional), "test") => expression(&conditional.test),
+        (AstKind::ConditionalExpression(conditional), "consequent") => {
+            expression(&conditional.consequent)
+        }
+        (AstKind::ConditionalExpression(conditional), "alternate") => {
+            expression(&conditional.alternate)
+        }
+        (AstKind::SequenceExpression(sequence), "expressions") => {
+            Value::Nodes(sequence.expressions.iter().map(from_expression).collect())
+        }
+        (AstKind::ArrayExpression(array), "elements") => {
+            Value::Nodes(array.elements.iter().map(array_element).collect())
+        }
+        (AstKind::ObjectExpression(object), "properties") => Value::Nodes(
+            object
+                .properties
+                .iter()
+                .map(|property| match property {
+                    ObjectPropertyKind::ObjectProperty(property) => {
+                        AstKind::ObjectProperty(property)
+                    }
+                    ObjectPropertyKind::SpreadProperty(spread) => AstKind::SpreadElement(spread),
+                })
+                .collect(),
+        ),
+        (AstKind::ObjectProperty(property), "key") => property_key(&property.key)?,
+        (AstKind::ObjectProperty(property), "value") => expression(&property.value),
+        (AstKind::ObjectProperty(property), "kind") => Value::String(match property.kind {
+            PropertyKind::Init => "init",
+            PropertyKind::Get => "get",
+            PropertyKind::Set => "set",
+        }),
+        (AstKind::ObjectProperty(property), "computed") => Value::Bool(property.computed),
+        (AstKind::ObjectProperty(property), "shorthand") => Value::Bool(property.shorthand),
+        (AstKind::ObjectProperty(property), "method") => Value::Bool(property.method),
+
+        (AstKind::AwaitExpression(expr), "argument") => expression(&expr.argument),
+        (AstKind::SpreadElement(spread), "argument") => expression(&spread.argument),
+        (AstKind::YieldExpression(expr), "argument") => optional_expression(expr.argument.as_ref()),
+        (AstKind::YieldExpression(expr), "delegate") => Value::Bool(expr.delegate),
+        (AstKind::ChainExpression(chain), "expression") => match &chain.expression {
+            ChainElement::CallExpression(call) => Value::Node(AstKind::CallExpression(call)),
+            element => Value::Node(AstKind::MemberExpression(element.as_member_expression()?)),
+        },
+        (AstKind::ParenthesizedExpression(expr), "expression") => expression(&expr.expression),
+        (AstKind::TSAsExpression(expr), "expression") => expression(&expr.expression),
+        (AstKind::TSSatisfiesExpression(expr), "expression") => expression(&expr.expression),
+        (AstKind::TSNonNullExpression(expr), "expression") => expression(&expr.expression),
+        (AstKind::TSTypeAssertion(expr), "expression") => expression(&expr.expression),
+
+        (AstKind::ExpressionStatement(statement), "expression") => {
+            expression(&statement.expression)
+        }
+        (AstKind::IfStatement(statement), "test") => expression(&statement.test),
+        (AstKind::WhileStatement(statement), "test") => expression(&statement.test),
+        (AstKind::DoWhileStatement(statement), "test") => expression(&statement.test),
+        (AstKind::ForStatement(statement), "test") => optional_expression(statement.test.as_ref()),
+        (AstKind::ForInStatement(statement), "right") => expression(&statement.right),
+        (AstKind::ForOfStatement(statement), "right") => expression(&statement.right),
+        (AstKind::ForOfStatement(statement), "await") => Value::Bool(statement.r#await),
+        (AstKind::SwitchStatement(statement), "discriminant") => {
+            expression(&statement.discriminant)
+        }
+        (AstKind::ReturnStatement(statement), "argument") => {
+            optional_expression(statement.argument.as_ref())
+        }
+        (AstKind::ThrowStatement(statement), "argument") => expression(&statement.argument),
+        (AstKind::LabeledStatement(statement), "label") => {
+            Value::Node(AstKind::LabelIdentifier(&statement.label))
+        }
+        (AstKind::BreakStatement(statement), "label") => statement
+            .label
+            .as_ref()
+            .map_or(Value::Null, |label| Value::Node(AstKind::LabelIdentifier(label))),
+        (AstKind::ContinueStatement(statement), "label") => statement
+            .label
+            .as_ref()
+            .map_or(Value::Null, |label| Value::Node(AstKind::LabelIdentifier(label))),
+
+        (AstKind::VariableDeclaration(declaration), "kind") => {
+            Value::String(declaration.kind.as_str())
+        }
+        (AstKind::VariableDeclaration(declaration), "declarations") => {
+            Value::Nodes(declaration.declarations.iter().map(AstKind::VariableDeclarator).collect())
+        }
+        (AstKind::VariableDeclarator(declarator), "id") => {
+            Value::Node(binding_pattern(&declarator.id.kind))
+        }
+        (AstKind::VariableDeclarator(declarator), "init") => {
+            optional_expression(declarator.init.as_ref())
+        }
+        (AstKind::Function(function), "id") => function
+            .id
+            .as_ref()
+            .map_or(Value::Null, |id| Value::Node(AstKind::BindingIdentifier(id))),
+        (AstKind::Function(function), "async") => Value::Bool(function.r#async),
+        (AstKind::Function(function), "generator") => Value::Bool(function.generator),
+        (AstKind::Function(function), "params") => params(&function.params),
+        (AstKind::ArrowFunctionExpression(arrow), "async") => Value::Bool(arrow.r#async),
+        (AstKind::ArrowFunctionExpression(_), "generator") => Value::Bool(false),
+        (AstKind::ArrowFunctionExpression(arrow), "expression") => Value::Bool(arrow.expression),
+        (AstKind::ArrowFunctionExpression(arrow), "params") => params(&arrow.params),
+        (AstKind::Class(class), "id") => {
+            class.id.as_ref().map_or(Value::Null, |id| Value::Node(AstKind::BindingIdentifier(id)))
+        }
+        (AstKind::Class(class), "superClass") => optional_expression(class.super_class.as_ref()),
+        (AstKind::MethodDefinition(method), "key") => property_key(&method.key)?,
+        (AstKind::MethodDefinition(method), "kind") => Value::String(match method.kind {
+            MethodDefinitionKind::Constructor => "constructor",
+            MethodDefinitionKind::Method => "method",
+            MethodDefinitionKind::Get => "get",
+            MethodDefinitionKind::Set => "set",
+        }),
+        (AstKind::MethodDefinition(method), "static") => Value::Bool(method.r#static),
+        (AstKind::MethodDefinition(method), "computed") => Value::Bool(method.computed),
+        (AstKind::PropertyDefinition(property), "key") => property_key(&property.key)?,
+        (AstKind::PropertyDefinition(property), "value") => {
+            optional_expression(property.value.as_ref())
+        }
+        (AstKind::PropertyDefinition(property), "static") => Value::Bool(property.r#static),
+        (AstKind::PropertyDefinition(property), "computed") => Value::Bool(property.computed),
+
+        (AstKind::ImportDeclaration(import), "source") => {
+            Value::Node(AstKind::StringLiteral(&import.source))
+        }
+        (AstKind::ImportDeclaration(import), "importKind") => {
+            Value::String(if import.import_kind.is_type() { "type" } else { "value" })
+        }
+        (AstKind::ImportSpecifier(specifier), "imported") => {
+            module_export_name(&specifier.imported)
+        }
+        (AstKind::ImportSpecifier(specifier), "local") => {
+            Value::Node(AstKind::BindingIdentifier(&specifier.local))
+        }
+        (AstKind::ImportDefaultSpecifier(specifier), "local") => {
+            Value::Node(AstKind::BindingIdentifier(&specifier.local))
+        }
+        (AstKind::ImportNamespaceSpecifier(specifier), "local") => {
+            Value::Node(AstKind::BindingIdentifier(&specifier.local))
+        }
+        (AstKind::ExportNamedDeclaration(export), "source") => export
+            .source
+            .as_ref()
+            .map_or(Value::Null, |source| Value::Node(AstKind::StringLiteral(source))),
+        (AstKind::ExportAllDeclaration(export), "source") => {
+            Value::Node(AstKind::StringLiteral(&export.source))
+        }
+        (AstKind::ExportSpecifier(specifier), "local") => module_export_name(&specifier.local),
+        (AstKind::ExportSpecifier(specifier), "exported") => {
+            module_export_name(&specifier.exported)
+        }
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn from_expression<'a>(expr: &'a Expression<'a>) -> AstKind<'a> {
+    // ESTree has no parentheses
+    AstKind::from_expression(expr.without_parenthesized())
+}
+
+fn expression<'a>(expr: &'a Expression<'a>) -> Value<'a> {
+    Value::Node(from_expression(expr))
+}
+
+fn optional_expression<'a>(expr: Option<&'a Expression<'a>>) -> Value<'a> {
+    expr.map_or(Value::Null, expression)
+}
+
+fn arguments<'a>(arguments: &'a [Argument<'a>]) -> Value<'a> {
+    Value::Nodes(
+        arguments
+            .iter()
+            .filter_map(|argument| match argument {
+                Argument::SpreadElement(spread) => Some(AstKind::SpreadElement(spread)),
+                argument => argument.as_expression().map(from_expression),
+            })
+            .collect(),
+    )
+}
+
+fn array_element<'a>(element: &'a ArrayExpressionElement<'a>) -> AstKind<'a> {
+    match element {
+        ArrayExpressionElement::SpreadElement(spread) => AstKind::SpreadElement(spread),
+        ArrayExpressionElement::Elision(elision) => AstKind::Elision(elision),
+        element => element
+            .as_expression()
+            .map_or(AstKind::ArrayExpressionElement(element), from_expression),
+    }
+}
+
+fn assignment_target<'a>(target: &'a AssignmentTarget<'a>) -> Option<Value<'a>> {
+    let kind = match target.as_simple_assignment_target()? {
+        SimpleAssignmentTarget::AssignmentTargetIdentifier(ident) => {
+            AstKind::IdentifierReference(ident)
+        }
+        target => AstKind::MemberExpression(target.as_member_expression()?),
+    };
+    Some(Value::Node(kind))
+}
+
+fn binding_pattern<'a>(pattern: &'a BindingPatternKind<'a>) -> AstKind<'a> {
+    match pattern {
+        BindingPatternKind::BindingIdentifier(ident) => AstKind::BindingIdentifier(ident),
+        BindingPatternKind::ObjectPattern(pattern) => AstKind::ObjectPattern(pattern),
+        BindingPatternKind::ArrayPattern(pattern) => AstKind::ArrayPattern(pattern),
+        BindingPatternKind::AssignmentPattern(pattern) => AstKind::AssignmentPattern(pattern),
+    }
+}
+
+fn params<'a>(params: &'a FormalParameters<'a>) -> Value<'a> {
+    let mut kinds =
+        params.items.iter().map(|param| binding_pattern(&param.pattern.kind)).collect::<Vec<_>>();
+    if let Some(rest) = &params.rest {
+        kinds.push(AstKind::BindingRestElement(rest));
+    }
+    Value::Nodes(kinds)
+}
+
+fn property_key<'a>(key: &'a PropertyKey<'a>) -> Option<Value<'a>> {
+    let kind = match key {
+        PropertyKey::StaticIdentifier(ident) => AstKind::IdentifierName(ident),
+        PropertyKey::PrivateIdentifier(ident) => AstKind::PrivateIdentifier(ident),
+        key => from_expression(key.as_expression()?),
+    };
+    Some(Value::Node(kind))
+}
+
+fn module_export_name<'a>(name: &'a ModuleExportName<'a>) -> Value<'a> {
+    Value::Node(match name {
+        ModuleExportName::IdentifierName(ident) => AstKind::IdentifierName(ident),
+        ModuleExportName::IdentifierReference(ident) => AstKind::IdentifierReference(ident),
+        ModuleExportName::StringLiteral(literal) => AstKind::StringLiteral(literal),
+    })
+}
@@ -0,0 +1,333 @@
+//! Selectors of AST nodes in the syntax of [ESQuery](https://github.com/estools/esquery), which
+//! ESLint uses for the listeners of rules, e.g. `CallExpression[callee.name="require"]`. They let
+//! users ban syntax without writing a rule, see `no-restricted-syntax`.
+//!
+//! Selectors are written against ESTree, so nodes are matched with their ESTree types and
+//! properties: `Literal` matches all the literals of oxc, and the nodes which ESTree does not
+//! have, e.g. `Argument`, are never matched and are skipped when looking for parents.
+
+mod estree;
+mod parser;
+
+use oxc_ast::AstKind;
+use oxc_semantic::{AstNode, AstNodes};
+use regex::Regex;
+use rustc_hash::FxHashSet;
+
+use self::estree::{node_type, Value};
+
+/// A parsed selector, see [`Selector::parse`].
+#[derive(Debug, Clone)]
+pub(crate) enum Selector {
+    /// `*`
+    Wildcard,
+    /// `CallExpression`, compared without case like ESQuery does
+    Type(String),
+    /// `[callee.name="require"]`
+    Attribute(Attribute),
+    /// `:function`
+    Class(NodeClass),
+    /// `:scope`, the node `:has()` is matched on
+    Scope,
+    /// `A[attr]`, all of them match
+    Compound(Vec<Selector>),
+    /// `:not(A, B)`
+    Not(Vec<Selector>),
+    /// `A, B` or `:matches(A, B)`
+    Matches(Vec<Selector>),
+    /// `:has(A, > B)`, a descendant matches one of them
+    Has(Vec<Selector>),
+    /// `A > B`, the parent and the child
+    Child(Box<Selector>, Box<Selector>),
+    /// `A B`, the ancestor and the descendant
+    Descendant(Box<Selector>, Box<Selector>),
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Attribute {
+    /// `callee.name`
+    path: Vec<String>,
+    /// Whether the attribute exists when `None`
+    test: Option<AttributeTest>,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeTest {
+    /// `="value"`, compared with the text of the attribute
+    Equals { value: String, negated: bool },
+    /// `=/regex/`, only matches strings
+    Matches { regex: Regex, negated: bool },
+    /// `>3`, only matches numbers
+    Compare(Comparison, f64),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+/// Classes of nodes, defined with their ESTree types like ESQuery does.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum NodeClass {
+    Statement,
+    Expression,
+    Declaration,
+    Function,
+    Pattern,
+}
+
+impl Selector {
+    /// Parse the selector `source`.
+    ///
+    /// The sibling combinators (`A ~ B` and `A + B`), the positional pseudo-classes (e.g.
+    /// `:first-child`), the field selectors (`.callee`) and the subject indicator (`!`) are not
+    /// supported.
+    ///
+    /// # Errors
+    ///
+    /// A description of the first syntax error or unsupported feature.
+    pub(crate) fn parse(source: &str) -> Result<Self, String> {
+        parser::Parser::new(source).parse()
+    }
+
+    /// Whether `node` matches this selector.
+    pub(crate) fn matches<'a>(
+        &self,
+        node: &AstNode<'a>,
+        nodes: &AstNodes<'a>,
+        source_text: &'a str,
+    ) -> bool {
+        let matcher = Matcher { nodes, source_text };
+        matcher.node_type(node).is_some() && matcher.matches(self, node, None)
+    }
+}
+
+struct Matcher<'n, 'a> {
+    nodes: &'n AstNodes<'a>,
+    source_text: &'a str,
+}
+
+impl<'n, 'a> Matcher<'n, 'a> {
+    fn matches(
+        &self,
+        selector: &Selector,
+        node: &AstNode<'a>,
+        scope: Option<&AstNode<'a>>,
+    ) -> bool {
+        match selector {
+            Selector::Wildcard => true,
+            Selector::Type(name) => {
+                self.node_type(node).is_some_and(|node_type| node_type.eq_ignore_ascii_case(name))
+            }
+            Selector::Attribute(attribute) => self.matches_attribute(attribute, node),
+            Selector::Class(class) => self.matches_class(*class, node),
+            Selector::Scope => match scope {
+                Some(scope) => scope.id() == node.id(),
+                None => self.nodes.root() == Some(node.id()),
+            },
+            Selector::Compound(selectors) => {
+                selectors.iter().all(|selector| self.matches(selector, node, scope))
+            }
+            Selector::Not(selectors) => {
+                !selectors.iter().any(|selector| self.matches(selector, node, scope))
+            }
+            Selector::Matches(selectors) => {
+                selectors.iter().any(|selector| self.matches(selector, node, scope))
+            }
+            Selector::Has(selectors) => self.descendants(node).any(|descendant| {
+                selectors.iter().any(|selector| self.matches(selector, descendant, Some(node)))
+            }),
+            Selector::Child(parent, child) => {
+                self.matches(child, node, scope)
+                    && self.ancestors(node).next().is_some_and(|p| self.matches(parent, p, scope))
+            }
+            Selector::Descendant(ancestor, descendant) => {
+                self.matches(descendant, node, scope)
+                    && self.ancestors(node).any(|a| self.matches(ancestor, a, scope))
+            }
+        }
+    }
+
+    fn matches_attribute(&self, attribute: &Attribute, node: &AstNode<'a>) -> bool {
+        let mut value = Value::Node(node.kind());
+        for name in &attribute.path {
+            match value.get(name, self.source_text) {
+                Some(property) => value = property,
+                // Missing attributes are different from every value
+                None => {
+                    return matches!(
+                        attribute.test,
+                        Some(
+                            AttributeTest::Equals { negated: true, .. }
+                                | AttributeTest::Matches { negated: true, .. }
+                        )
+                    )
+                }
+            }
+        }
+        match &attribute.test {
+            None => !matches!(value, Value::Null),
+            Some(AttributeTest::Equals { value: expected, negated }) => {
+                (value.to_string() == *expected) != *negated
+            }
+            Some(AttributeTest::Matches { regex, negated }) => {
+                matches!(value, Value::String(value) if regex.is_match(value)) != *negated
+            }
+            Some(AttributeTest::Compare(comparison, expected)) => {
+                let Value::Number(value) = value else { return false };
+                match comparison {
+                    Comparison::Less => value < *expected,
+                    Comparison::LessEqual => value <= *expected,
+                    Comparison::Greater => value > *expected,
+                    Comparison::GreaterEqual => value >= *expected,
+                }
+            }
+        }
+    }
+
+    /// The ESTree type of `node`, see [`node_type`]. The body of an arrow function which returns
+    /// an expression is the expression in ESTree, while oxc wraps it in a function body.
+    fn node_type(&self, node: &AstNode<'a>) -> Option<&'static str> {
+        let is_expression_body = |id| {
+            matches!(
+                self.nodes.parent_kind(id),
+                Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+            )
+        };
+        match node.kind() {
+            AstKind::FunctionBody(_) if is_expression_body(node.id()) => None,
+            AstKind::ExpressionStatement(_)
+                if self.nodes.parent_id(node.id()).is_some_and(is_expression_body) =>
+            {
+                None
+            }
+            kind => node_type(kind),
+        }
+    }
+
+    fn matches_class(&self, class: NodeClass, node: &AstNode<'a>) -> bool {
+        let Some(node_type) = self.node_type(node) else { return false };
+        let is_expression = || {
+            node_type.ends_with("Expression")
+                || node_type.ends_with("Literal")
+                || node_type == "MetaProperty"
+                || (node_type == "Identifier"
+                    && self
+                        .ancestors(node)
+                        .next()
+                        .map_or(true, |parent| self.node_type(parent) != Some("MetaProperty")))
+        };
+        match class {
+            NodeClass::Statement => {
+                node_type.ends_with("Statement") || node_type.ends_with("Declaration")
+            }
+            NodeClass::Expression => is_expression(),
+            NodeClass::Declaration => node_type.ends_with("Declaration"),
+            NodeClass::Function => matches!(
+                node_type,
+                "FunctionDeclaration" | "FunctionExpression" | "ArrowFunctionExpression"
+            ),
+            NodeClass::Pattern => node_type.ends_with("Pattern") || is_expression(),
+        }
+    }
+
+    /// The ancestors of `node` which ESTree has, from its parent to the program.
+    fn ancestors<'s>(&'s self, node: &AstNode<'a>) -> impl Iterator<Item = &'n AstNode<'a>> + 's {
+        self.nodes.iter_parents(node.id()).skip(1).filter(|node| self.node_type(node).is_some())
+    }
+
+    /// The descendants of `node` which ESTree has.
+    fn descendants<'s>(&'s self, node: &AstNode<'a>) -> impl Iterator<Item = &'n AstNode<'a>> + 's {
+        // Nodes are numbered in the order they are visited, so the descendants of a node are the
+        // nodes which follow it until one of them is not in its subtree
+        let mut subtree = FxHashSet::from_iter([node.id()]);
+        self.nodes
+            .iter()
+            .skip(node.id().index() + 1)
+            .take_while(move |descendant| {
+                let in_subtree = self
+                    .nodes
+                    .parent_id(descendant.id())
+                    .is_some_and(|parent_id| subtree.contains(&parent_id));
+                if in_subtree {
+                    subtree.insert(descendant.id());
+                }
+                in_subtree
+            })
+            .filter(|node| self.node_type(node).is_some())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{GetSpan, SourceType};
+
+    use super::Selector;
+
+    /// The source text of the nodes of `source_text` which match `selector`.
+    fn query(selector: &str, source_text: &str) -> Vec<String> {
+        let selector = Selector::parse(selector).unwrap();
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_module(true).with_jsx(true);
+        let program = Parser::new(&allocator, source_text, source_type).parse().program;
+        let program = allocator.alloc(program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        semantic
+            .nodes()
+            .iter()
+            .filter(|node| selector.matches(node, semantic.nodes(), source_text))
+            .map(|node| node.kind().span().source_text(source_text).to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_types_and_attributes() {
+        let source = "const a = require('a'); foo(1, 2); new Foo(); `${b}`;";
+        assert_eq!(query("CallExpression[callee.name='require']", source), ["require('a')"]);
+        assert_eq!(query("CallExpression[arguments.length>1]", source), ["foo(1, 2)"]);
+        assert_eq!(query("callexpression[callee.name=/^f/]", source), ["foo(1, 2)"]);
+        assert_eq!(query("[callee.name!=require][callee]", source), ["foo(1, 2)", "new Foo()"]);
+        assert_eq!(query("Literal[value=2], Literal[raw=\"'a'\"]", source), ["'a'", "2"]);
+        assert_eq!(
+            query("VariableDeclaration[kind=const] > VariableDeclarator > Identifier", source),
+            ["a"]
+        );
+        assert_eq!(query("TemplateLiteral Identifier", source), ["b"]);
+        // `Argument` is skipped
+        assert_eq!(
+            query("NewExpression > Identifier, CallExpression > Literal", source),
+            ["'a'", "1", "2", "Foo"]
+        );
+    }
+
+    #[test]
+    fn test_pseudo_classes() {
+        let source = "function f(a) { return a; } const g = () => f((0));";
+        assert_eq!(
+            query(":function:has(ReturnStatement)", source),
+            ["function f(a) { return a; }"]
+        );
+        assert_eq!(query(":function:not(:has(> BlockStatement))", source), ["() => f((0))"]);
+        assert_eq!(
+            query(":matches(ReturnStatement, ArrowFunctionExpression) > :expression", source),
+            ["a", "f((0))"]
+        );
+        // ESTree has no parentheses
+        assert_eq!(query("CallExpression > Literal[value=0]", source), ["0"]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for selector in
+            ["", "A ~ B", "A + B", ":first-child", ".callee", "[name", "[value>a]", "A >", "A)"]
+        {
+            assert!(Selector::parse(selector).is_err(), "{selector}");
+        }
+    }
+}
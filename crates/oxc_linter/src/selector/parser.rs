@@ -0,0 +1,331 @@
+use regex::Regex;
+
+use super::{Attribute, AttributeTest, Comparison, NodeClass, Selector};
+
+/// Parser of the selectors, following the grammar of ESQuery without the sibling combinators,
+/// the positional pseudo-classes and the field selectors.
+pub(super) struct Parser<'s> {
+    source: &'s str,
+    pos: usize,
+}
+
+/// Characters which end names, e.g. the type of a node or the name of an attribute.
+const RESERVED: &str = "[](),:#!=<>~+.*/\"'";
+
+impl<'s> Parser<'s> {
+    pub(super) fn new(source: &'s str) -> Self {
+        Self { source, pos: 0 }
+    }
+
+    pub(super) fn parse(mut self) -> Result<Selector, String> {
+        let selector = self.parse_selectors()?;
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) => Err(self.unexpected(c)),
+            None => Ok(selector),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let eaten = self.peek() == Some(c);
+        if eaten {
+            self.pos += c.len_utf8();
+        }
+        eaten
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.eat(c) {
+            return Ok(());
+        }
+        Err(match self.peek() {
+            Some(found) => format!("Expected `{c}` but found `{found}` at {}", self.pos),
+            None => format!("Expected `{c}` at the end"),
+        })
+    }
+
+    /// Whether whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+        self.pos > start
+    }
+
+    fn unexpected(&self, c: char) -> String {
+        format!("Unexpected `{c}` at {}", self.pos)
+    }
+
+    fn parse_name(&mut self) -> Result<&'s str, String> {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() || RESERVED.contains(c) {
+                break;
+            }
+            self.pos += c.len_utf8();
+        }
+        if self.pos == start {
+            return Err(match self.peek() {
+                Some(c) => self.unexpected(c),
+                None => "Unexpected end".to_string(),
+            });
+        }
+        Ok(&self.source[start..self.pos])
+    }
+
+    /// The value of an attribute which is not quoted, e.g. `3` or `foo`.
+    fn parse_bare_value(&mut self) -> Result<&'s str, String> {
+        let start = self.pos;
+        while let Some(c) = self.peek().filter(|c| !c.is_whitespace() && *c != ']') {
+            self.pos += c.len_utf8();
+        }
+        match &self.source[start..self.pos] {
+            "" => Err(match self.peek() {
+                Some(c) => self.unexpected(c),
+                None => "Unexpected end".to_string(),
+            }),
+            value if value.contains('(') => Err(format!("`{value}` values are not supported")),
+            value => Ok(value),
+        }
+    }
+
+    /// `A, B`
+    fn parse_selectors(&mut self) -> Result<Selector, String> {
+        let mut selectors = vec![self.parse_selector()?];
+        loop {
+            self.skip_whitespace();
+            if !self.eat(',') {
+                break;
+            }
+            selectors.push(self.parse_selector()?);
+        }
+        Ok(if selectors.len() == 1 { selectors.remove(0) } else { Selector::Matches(selectors) })
+    }
+
+    /// The selectors of `:has()`, which may start with a child combinator, e.g. `:has(> A)`.
+    fn parse_relative_selectors(&mut self) -> Result<Vec<Selector>, String> {
+        let mut selectors = vec![];
+        loop {
+            self.skip_whitespace();
+            let selector = if self.peek() == Some('>') {
+                self.parse_combinators(Selector::Scope)?
+            } else {
+                self.parse_selector()?
+            };
+            selectors.push(selector);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(selectors);
+            }
+        }
+    }
+
+    /// `A B` or `A > B`
+    fn parse_selector(&mut self) -> Result<Selector, String> {
+        self.skip_whitespace();
+        let selector = self.parse_sequence()?;
+        self.parse_combinators(selector)
+    }
+
+    fn parse_combinators(&mut self, mut selector: Selector) -> Result<Selector, String> {
+        loop {
+            let start = self.pos;
+            let skipped_whitespace = self.skip_whitespace();
+            match self.peek() {
+                Some('>') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                    let child = self.parse_sequence()?;
+                    selector = Selector::Child(Box::new(selector), Box::new(child));
+                }
+                Some(c @ ('~' | '+')) => {
+                    return Err(format!("The sibling combinator `{c}` is not supported"));
+                }
+                Some(c) if skipped_whitespace && c != ',' && c != ')' => {
+                    let descendant = self.parse_sequence()?;
+                    selector = Selector::Descendant(Box::new(selector), Box::new(descendant));
+                }
+                _ => {
+                    self.pos = start;
+                    return Ok(selector);
+                }
+            }
+        }
+    }
+
+    /// `A[attr]:not(B)`
+    fn parse_sequence(&mut self) -> Result<Selector, String> {
+        let mut selectors = vec![];
+        while let Some(c) = self.peek() {
+            let selector = match c {
+                '*' => {
+                    self.pos += 1;
+                    Selector::Wildcard
+                }
+                '[' => self.parse_attribute()?,
+                ':' => self.parse_pseudo_class()?,
+                '!' => return Err("The subject indicator `!` is not supported".to_string()),
+                '.' => return Err("Field selectors are not supported".to_string()),
+                c if c.is_whitespace() || RESERVED.contains(c) => break,
+                _ => Selector::Type(self.parse_name()?.to_string()),
+            };
+            selectors.push(selector);
+        }
+        match selectors.len() {
+            0 => Err(match self.peek() {
+                Some(c) => self.unexpected(c),
+                None => "Unexpected end".to_string(),
+            }),
+            1 => Ok(selectors.remove(0)),
+            _ => Ok(Selector::Compound(selectors)),
+        }
+    }
+
+    /// `[attr]`, `[attr="value"]`, `[attr=/regex/]` or `[attr>3]`
+    fn parse_attribute(&mut self) -> Result<Selector, String> {
+        self.expect('[')?;
+        self.skip_whitespace();
+        let mut path = vec![self.parse_name()?.to_string()];
+        while self.eat('.') {
+            path.push(self.parse_name()?.to_string());
+        }
+        self.skip_whitespace();
+
+        let test = if self.eat(']') {
+            return Ok(Selector::Attribute(Attribute { path, test: None }));
+        } else if self.eat('=') {
+            self.parse_attribute_value(false)?
+        } else if self.eat('!') {
+            self.expect('=')?;
+            self.parse_attribute_value(true)?
+        } else {
+            let comparison = if self.eat('<') {
+                if self.eat('=') {
+                    Comparison::LessEqual
+                } else {
+                    Comparison::Less
+                }
+            } else if self.eat('>') {
+                if self.eat('=') {
+                    Comparison::GreaterEqual
+                } else {
+                    Comparison::Greater
+                }
+            } else {
+                return Err(match self.peek() {
+                    Some(c) => self.unexpected(c),
+                    None => "Unexpected end".to_string(),
+                });
+            };
+            self.skip_whitespace();
+            let value = self.parse_bare_value()?;
+            let value = value
+                .parse::<f64>()
+                .map_err(|_| format!("Expected a number to compare with but found `{value}`"))?;
+            AttributeTest::Compare(comparison, value)
+        };
+        self.expect(']')?;
+        Ok(Selector::Attribute(Attribute { path, test: Some(test) }))
+    }
+
+    fn parse_attribute_value(&mut self, negated: bool) -> Result<AttributeTest, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.pos += 1;
+                let mut value = String::new();
+                loop {
+                    match self.peek() {
+                        None => return Err(format!("Unterminated string, expected `{quote}`")),
+                        Some(c) if c == quote => break,
+                        Some('\\') => {
+                            self.pos += 1;
+                            if let Some(c) = self.peek() {
+                                value.push(c);
+                                self.pos += c.len_utf8();
+                            }
+                        }
+                        Some(c) => {
+                            value.push(c);
+                            self.pos += c.len_utf8();
+                        }
+                    }
+                }
+                self.pos += 1;
+                Ok(AttributeTest::Equals { value, negated })
+            }
+            Some('/') => {
+                self.pos += 1;
+                let start = self.pos;
+                let mut escaped = false;
+                loop {
+                    match self.peek() {
+                        None => return Err("Unterminated regular expression".to_string()),
+                        Some('/') if !escaped => break,
+                        Some(c) => {
+                            escaped = c == '\\' && !escaped;
+                            self.pos += c.len_utf8();
+                        }
+                    }
+                }
+                let pattern = &self.source[start..self.pos];
+                self.pos += 1;
+                let flags_start = self.pos;
+                while self.peek().is_some_and(|c| matches!(c, 'i' | 'm' | 's' | 'u')) {
+                    self.pos += 1;
+                }
+                let flags = self.source[flags_start..self.pos].replace('u', "");
+                let pattern = if flags.is_empty() {
+                    pattern.to_string()
+                } else {
+                    format!("(?{flags}){pattern}")
+                };
+                let regex = Regex::new(&pattern).map_err(|error| error.to_string())?;
+                Ok(AttributeTest::Matches { regex, negated })
+            }
+            _ => {
+                let value = self.parse_bare_value()?.to_string();
+                Ok(AttributeTest::Equals { value, negated })
+            }
+        }
+    }
+
+    /// `:not(A)`, `:matches(A)`, `:is(A)`, `:has(A)` or the classes of nodes like `:function`
+    fn parse_pseudo_class(&mut self) -> Result<Selector, String> {
+        self.expect(':')?;
+        let name = self.parse_name()?;
+        let selector = match name.to_ascii_lowercase().as_str() {
+            "not" | "matches" | "is" | "has" => {
+                self.expect('(')?;
+                let selectors = if name.eq_ignore_ascii_case("has") {
+                    self.parse_relative_selectors()?
+                } else {
+                    match self.parse_selectors()? {
+                        Selector::Matches(selectors) => selectors,
+                        selector => vec![selector],
+                    }
+                };
+                self.expect(')')?;
+                match name.to_ascii_lowercase().as_str() {
+                    "not" => Selector::Not(selectors),
+                    "has" => Selector::Has(selectors),
+                    _ => Selector::Matches(selectors),
+                }
+            }
+            "scope" => Selector::Scope,
+            "statement" => Selector::Class(NodeClass::Statement),
+            "expression" => Selector::Class(NodeClass::Expression),
+            "declaration" => Selector::Class(NodeClass::Declaration),
+            "function" => Selector::Class(NodeClass::Function),
+            "pattern" => Selector::Class(NodeClass::Pattern),
+            _ => return Err(format!("The pseudo-class `:{name}` is not supported")),
+        };
+        Ok(selector)
+    }
+}
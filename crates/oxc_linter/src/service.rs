@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     ffi::OsStr,
     fs,
     path::{Path, PathBuf},
@@ -8,20 +8,35 @@ use std::{
 };
 
 use dashmap::DashMap;
-use oxc_allocator::Allocator;
-use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
+use oxc_allocator::{Allocator, AllocatorPool};
+use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic, Severity};
 use oxc_parser::Parser;
 use oxc_resolver::Resolver;
-use oxc_semantic::{ModuleRecord, SemanticBuilder};
-use oxc_span::{SourceType, VALID_EXTENSIONS};
-use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
+use oxc_semantic::{ModuleRecord, SemanticBuilder, SemanticScratch};
+use oxc_span::{SourceType, Span, VALID_EXTENSIONS};
+use rayon::{
+    iter::ParallelBridge,
+    prelude::{IntoParallelRefIterator, ParallelIterator},
+};
 use rustc_hash::FxHashSet;
 
 use crate::{
-    partial_loader::{JavaScriptSource, PartialLoader, LINT_PARTIAL_LOADER_EXT},
-    Fixer, LintContext, Linter, Message,
+    is_generated,
+    json::{is_json_path, parse_json, JSON_EXT},
+    module_format::ModuleFormat,
+    module_graph::{ModuleGraph, ModuleState},
+    package_json::PackageJsons,
+    partial_loader::{
+        JavaScriptSource, Notebook, PartialLoader, LINT_PARTIAL_LOADER_EXT, NOTEBOOK_EXT,
+    },
+    reduce::reduce_messages,
+    tsconfig::Tsconfigs,
+    Fixer, GeneratedFiles, LintCache, LintContext, Linter, Message, ProjectFile,
 };
 
+/// The maximum number of times a file is linted and fixed with `--fix`, the same as ESLint.
+const MAX_FIX_PASSES: usize = 10;
+
 pub struct LintServiceOptions {
     /// Current working directory
     pub cwd: Box<Path>,
@@ -29,8 +44,25 @@ pub struct LintServiceOptions {
     /// All paths to lint
     pub paths: Vec<Box<Path>>,
 
-    /// TypeScript `tsconfig.json` path for reading path alias and project references
+    /// TypeScript `tsconfig.json` used for all the files, for reading path aliases, project
+    /// references and compiler options, instead of the nearest `tsconfig.json` of each file
     pub tsconfig: Option<PathBuf>,
+
+    /// Skip the files linted without diagnostics by a previous run, unless they or their
+    /// configuration changed. With the import plugin, whose rules depend on other files, only the
+    /// module records of the dependencies of the linted files are taken from the cache.
+    pub cache: Option<Arc<LintCache>>,
+
+    /// Source text linted instead of the content of the file at the single path of `paths`,
+    /// e.g. the unsaved buffer of an editor read from stdin
+    pub stdin: Option<String>,
+}
+
+/// A file fixed without writing it, see [`LintOptions::dry_run`](crate::LintOptions::dry_run).
+pub struct FixedFile {
+    pub path: PathBuf,
+    pub source_text: String,
+    pub fixed_code: String,
 }
 
 #[derive(Clone)]
@@ -55,16 +87,110 @@ impl LintService {
     }
 
     pub fn number_of_dependencies(&self) -> usize {
-        self.runtime.module_map.len() - self.runtime.paths.len()
+        self.runtime.module_graph.len() - self.runtime.paths.len()
+    }
+
+    /// Number of files linted by [`LintService::run`].
+    pub fn number_of_paths(&self) -> usize {
+        self.runtime.paths.len()
+    }
+
+    /// Number of files linted by [`LintService::run`] which could not be parsed, whose syntax
+    /// errors are reported instead of the problems found by the rules.
+    ///
+    /// # Panics
+    ///
+    /// If a thread linting a file panicked.
+    pub fn number_of_parse_failures(&self) -> usize {
+        self.runtime.parse_failures.lock().unwrap().len()
+    }
+
+    pub fn module_graph(&self) -> &ModuleGraph {
+        &self.runtime.module_graph
+    }
+
+    /// The files fixed by [`LintService::run`] in dry run mode, sorted by path.
+    ///
+    /// # Panics
+    ///
+    /// If a thread fixing a file panicked.
+    pub fn fixed_files(&self) -> Vec<FixedFile> {
+        let mut fixed_files = std::mem::take(&mut *self.runtime.fixed_files.lock().unwrap());
+        fixed_files.sort_unstable_by(|a, b| a.path.cmp(&b.path));
+        fixed_files
+    }
+
+    /// A service to lint the files affected by the `changed` files, which were modified, created
+    /// or deleted since this service ran: the changed files and the files importing them, directly
+    /// or through other files, which are part of `paths`.
+    ///
+    /// The linter, the resolver and the modules of the module graph which are not affected by the
+    /// changes are shared with this service, so only the affected files are parsed again.
+    #[must_use]
+    pub fn with_changed_paths(&self, changed: &[Box<Path>], paths: &[Box<Path>]) -> Self {
+        let runtime = &self.runtime;
+        // Created and deleted files change how import specifiers resolve
+        let created_or_deleted = changed.iter().any(|path| {
+            !path.exists() || !(runtime.paths.contains(path) || runtime.module_graph.contains(path))
+        });
+        // The resolvers cache the `tsconfig.json` files
+        let tsconfig_changed = changed.iter().any(|path| {
+            path.file_name().is_some_and(|name| name.to_string_lossy().starts_with("tsconfig"))
+        });
+        if created_or_deleted || tsconfig_changed {
+            runtime.resolver.clear_cache();
+        }
+        // Linted files are keyed by their path as given in the graph, and resolved dependencies by
+        // their canonicalized path
+        let mut affected = FxHashSet::default();
+        for path in changed {
+            for path in [path.clone(), canonicalize(path)] {
+                affected.extend(runtime.module_graph.dependents(&path));
+                affected.insert(path);
+            }
+        }
+        for path in &affected {
+            runtime.module_graph.remove(path);
+        }
+        let affected = affected.iter().map(|path| canonicalize(path)).collect::<FxHashSet<_>>();
+        let paths =
+            paths.iter().filter(|path| affected.contains(&canonicalize(path))).cloned().collect();
+        let runtime = Runtime {
+            cwd: runtime.cwd.clone(),
+            paths,
+            linter: Arc::clone(&runtime.linter),
+            resolver: Arc::clone(&runtime.resolver),
+            resolvers: DashMap::default(),
+            module_graph: Arc::clone(&runtime.module_graph),
+            // The `package.json` and `tsconfig.json` files may have changed too
+            package_jsons: PackageJsons::default(),
+            tsconfigs: Tsconfigs::new(runtime.tsconfigs.config_file().map(Path::to_path_buf)),
+            cache_state: CacheState::default(),
+            cache: runtime.cache.clone(),
+            allocator_pool: AllocatorPool::default(),
+            semantic_scratches: Mutex::default(),
+            project_files: Mutex::default(),
+            parse_failures: Mutex::default(),
+            fixed_files: Mutex::default(),
+            stdin: runtime.stdin.clone(),
+        };
+        Self { runtime: Arc::new(runtime) }
     }
 
     /// # Panics
     pub fn run(&self, tx_error: &DiagnosticSender) {
         self.runtime
             .paths
-            .iter()
-            .par_bridge()
+            .par_iter()
             .for_each_with(&self.runtime, |runtime, path| runtime.process_path(path, tx_error));
+        self.runtime.run_on_project(tx_error);
+        if let Some(cache) = self.runtime.cache.as_deref() {
+            if let Err(diagnostic) = cache.save() {
+                let diagnostics =
+                    DiagnosticService::wrap_diagnostics(cache.location(), "", vec![diagnostic]);
+                tx_error.send(Some(diagnostics)).unwrap();
+            }
+        }
         tx_error.send(None).unwrap();
     }
 
@@ -74,32 +200,72 @@ impl LintService {
         &self,
         allocator: &'a Allocator,
         source_text: &'a str,
+        source_type: Option<SourceType>,
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        self.runtime
+        let mut messages = self
+            .runtime
             .paths
             .iter()
             .flat_map(|path| {
-                let source_type = SourceType::from_path(path).unwrap();
                 self.runtime.init_cache_state(path);
-                self.runtime.process_source(
+                let ext = path.extension().and_then(OsStr::to_str).unwrap_or_default();
+                let sources = source_type
+                    .is_none()
+                    .then(|| PartialLoader::parse(ext, source_text))
+                    .flatten()
+                    .unwrap_or_else(|| {
+                        let source_type = source_type.unwrap_or_else(|| {
+                            ModuleFormat::source_type(path).unwrap_or_else(|err| {
+                                assert!(is_json_path(path), "{err:?}");
+                                SourceType::default()
+                            })
+                        });
+                        vec![JavaScriptSource::new(source_text, source_type, 0)]
+                    });
+                self.runtime.process_partial_sources(
                     path,
                     allocator,
-                    source_text,
-                    source_type,
+                    sources,
                     check_syntax_errors,
                     tx_error,
                 )
             })
-            .collect::<Vec<_>>()
+            .collect::<Vec<_>>();
+        let files = std::mem::take(&mut *self.runtime.project_files.lock().unwrap());
+        messages.extend(
+            self.runtime
+                .linter
+                .run_on_project(files, &self.runtime.module_graph, |_| {
+                    Some(source_text.to_string())
+                })
+                .into_iter()
+                .flat_map(|(_, _, diagnostics)| diagnostics)
+                .map(|diagnostic| Message::new(diagnostic, None)),
+        );
+        messages
     }
 }
 
+/// The canonicalized `path`, which may have been deleted.
+fn canonicalize(path: &Path) -> Box<Path> {
+    path.canonicalize()
+        .or_else(|err| match (path.parent(), path.file_name()) {
+            (Some(parent), Some(file_name)) => {
+                let parent = if parent.as_os_str().is_empty() { Path::new(".") } else { parent };
+                parent.canonicalize().map(|parent| parent.join(file_name))
+            }
+            _ => Err(err),
+        })
+        .unwrap_or_else(|_| path.to_path_buf())
+        .into_boxed_path()
+}
+
 /// `CacheState` and `CacheStateEntry` are used to fix the problem where
 /// there is a brief moment when a concurrent fetch can miss the cache.
 ///
-/// Given `ModuleMap` is a `DashMap`, which conceptually is a `RwLock<HashMap>`.
+/// Given `ModuleGraph` is a `DashMap`, which conceptually is a `RwLock<HashMap>`.
 /// When two requests read the map at the exact same time from different threads,
 /// both will miss the cache so both thread will make a request.
 ///
@@ -113,69 +279,112 @@ enum CacheStateEntry {
     PendingStore(usize),
 }
 
-/// Keyed by canonicalized path
-type ModuleMap = DashMap<Box<Path>, ModuleState>;
-
-#[derive(Clone)]
-enum ModuleState {
-    Resolved(Arc<ModuleRecord>),
-    Ignored,
-}
-
 pub struct Runtime {
     cwd: Box<Path>,
     /// All paths to lint
     paths: FxHashSet<Box<Path>>,
-    linter: Linter,
-    resolver: Option<Resolver>,
-    module_map: ModuleMap,
+    linter: Arc<Linter>,
+    /// Resolves the dependencies of the import plugin and [`LintContext::resolve`] for the files
+    /// without a `tsconfig.json`
+    resolver: Arc<Resolver>,
+    /// The resolvers of the files with a `tsconfig.json`, keyed by its path, sharing the cache of
+    /// `resolver`
+    resolvers: DashMap<PathBuf, Arc<Resolver>>,
+    module_graph: Arc<ModuleGraph>,
+    /// The nearest `package.json` of the linted files
+    package_jsons: PackageJsons,
+    /// The `tsconfig.json` of the linted files
+    tsconfigs: Tsconfigs,
     cache_state: CacheState,
+    cache: Option<Arc<LintCache>>,
+    /// Arenas reused from one file to the next
+    allocator_pool: AllocatorPool,
+    /// Buffers of the semantic analysis reused from one file to the next
+    semantic_scratches: Mutex<Vec<SemanticScratch>>,
+    /// Files collected for [`Linter::run_on_project`]
+    project_files: Mutex<Vec<ProjectFile>>,
+    /// Linted files which could not be parsed
+    parse_failures: Mutex<FxHashSet<PathBuf>>,
+    /// Files fixed in dry run mode
+    fixed_files: Mutex<Vec<FixedFile>>,
+    /// See [`LintServiceOptions::stdin`]
+    stdin: Option<String>,
 }
 
 impl Runtime {
     fn new(linter: Linter, options: LintServiceOptions) -> Self {
-        let resolver = linter.options().import_plugin.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
-        });
+        let settings = &linter.settings().import_resolver;
+        let tsconfig =
+            options.tsconfig.or_else(|| settings.tsconfig().map(|path| options.cwd.join(path)));
+        let resolver = Arc::new(Self::get_resolver(&settings.node.extensions));
         Self {
             cwd: options.cwd,
             paths: options.paths.iter().cloned().collect(),
-            linter,
+            linter: Arc::new(linter),
             resolver,
-            module_map: ModuleMap::default(),
+            resolvers: DashMap::default(),
+            module_graph: Arc::default(),
+            package_jsons: PackageJsons::default(),
+            tsconfigs: Tsconfigs::new(tsconfig),
             cache_state: CacheState::default(),
+            cache: options.cache,
+            allocator_pool: AllocatorPool::default(),
+            semantic_scratches: Mutex::default(),
+            project_files: Mutex::default(),
+            parse_failures: Mutex::default(),
+            fixed_files: Mutex::default(),
+            stdin: options.stdin,
         }
     }
 
-    fn get_resolver(tsconfig: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
-        let tsconfig = tsconfig.and_then(|path| {
-            if path.is_file() {
-                Some(TsconfigOptions { config_file: path, references: TsconfigReferences::Auto })
-            } else {
-                None
-            }
-        });
-
+    /// `extensions` default to all the extensions oxlint can lint.
+    fn get_resolver(extensions: &[String]) -> Resolver {
+        use oxc_resolver::ResolveOptions;
         Resolver::new(ResolveOptions {
-            extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
+            extensions: if extensions.is_empty() {
+                VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect()
+            } else {
+                extensions.to_vec()
+            },
             condition_names: vec!["module".into(), "require".into()],
-            tsconfig,
             ..ResolveOptions::default()
         })
     }
 
+    /// The resolver of the file at `path`, following the `paths` and the project references of
+    /// its `tsconfig.json`.
+    fn resolver(&self, path: &Path) -> Arc<Resolver> {
+        use oxc_resolver::{TsconfigOptions, TsconfigReferences};
+        let Some(tsconfig) = self.tsconfigs.find_path(&self.cwd.join(path)) else {
+            return Arc::clone(&self.resolver);
+        };
+        let resolver = self.resolvers.entry(tsconfig.clone()).or_insert_with(|| {
+            let mut options = self.resolver.options().clone();
+            options.tsconfig = Some(TsconfigOptions {
+                config_file: tsconfig,
+                references: TsconfigReferences::Auto,
+            });
+            Arc::new(self.resolver.clone_with_options(options))
+        });
+        Arc::clone(&resolver)
+    }
+
     fn get_source_type_and_text(
+        &self,
         path: &Path,
         ext: &str,
     ) -> Option<Result<(SourceType, String), Error>> {
-        let source_type = SourceType::from_path(path);
-        let not_supported_yet =
-            source_type.as_ref().is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext));
+        let source_type = ModuleFormat::source_type(path);
+        let not_supported_yet = source_type
+            .as_ref()
+            .is_err_and(|_| !LINT_PARTIAL_LOADER_EXT.contains(&ext) && !JSON_EXT.contains(&ext));
         if not_supported_yet {
             return None;
         }
         let source_type = source_type.unwrap_or_default();
+        if let Some(source_text) = self.stdin.as_ref().filter(|_| self.paths.contains(path)) {
+            return Some(Ok((source_type, source_text.clone())));
+        }
         let file_result = fs::read_to_string(path).map_err(|e| {
             Error::new(OxcDiagnostic::error(format!(
                 "Failed to open file {path:?} with error \"{e}\""
@@ -197,7 +406,13 @@ impl Runtime {
             return;
         };
 
-        let Some(source_type_and_text) = Self::get_source_type_and_text(path, ext) else {
+        // JSON files are linted by the json plugin, they are not part of the module graph
+        if JSON_EXT.contains(&ext) && !self.paths.contains(path) {
+            self.ignore_path(path);
+            return;
+        }
+
+        let Some(source_type_and_text) = self.get_source_type_and_text(path, ext) else {
             self.ignore_path(path);
             return;
         };
@@ -211,36 +426,353 @@ impl Runtime {
             }
         };
 
-        let sources = PartialLoader::parse(ext, &source_text);
-        let is_processed_by_partial_loader = sources.is_some();
-        let sources =
-            sources.unwrap_or_else(|| vec![JavaScriptSource::new(&source_text, source_type, 0)]);
+        if ext == NOTEBOOK_EXT {
+            self.process_notebook(path, &source_text, tx_error);
+            return;
+        }
+
+        // The dependencies which did not change since the cache was saved are not parsed again
+        if let Some(cache) = self.module_cache(path) {
+            if let Some(module_record) = cache.module(path, LintCache::module_key(&source_text)) {
+                self.link_module(path, &Arc::new(module_record), tx_error);
+                return;
+            }
+        }
+
+        // Build artifacts linted by accident, e.g. the bundles of a `dist/` directory which is not
+        // ignored. Only the linted paths are checked, the imported modules are always used.
+        let generated_files = self.linter.options().generated_files;
+        let generated = generated_files != GeneratedFiles::Lint
+            && self.paths.contains(path)
+            && is_generated(&source_text);
+        if generated && generated_files == GeneratedFiles::Skip {
+            self.ignore_path(path);
+            return;
+        }
+
+        let cache = self.cache().filter(|_| self.paths.contains(path));
+        let cache_key = cache.map(|_| {
+            let package_json = self.package_jsons.find(&self.cwd.join(path));
+            LintCache::key(&source_text, self.linter.config_hash(path, package_json.as_deref()))
+        });
+        if let (Some(cache), Some(key)) = (cache, cache_key) {
+            if cache.is_clean(path, key) {
+                return;
+            }
+        }
+
+        let sources = PartialLoader::parse(ext, &source_text)
+            .unwrap_or_else(|| vec![JavaScriptSource::new(&source_text, source_type, 0)]);
 
         if sources.is_empty() {
             self.ignore_path(path);
             return;
         }
 
-        for JavaScriptSource { source_text, source_type, .. } in sources {
-            let allocator = Allocator::default();
-            let mut messages =
-                self.process_source(path, &allocator, source_text, source_type, true, tx_error);
+        let allocator = self.allocator_pool.get();
+        let messages = self.process_partial_sources(path, &allocator, sources, true, tx_error);
+
+        if let (Some(cache), Some(key)) = (cache, cache_key) {
+            cache.set_clean(path, key, messages.is_empty());
+        }
+
+        if generated {
+            let diagnostics = self
+                .reduce_messages(&source_text, messages)
+                .into_iter()
+                .map(|diagnostic| match diagnostic.severity {
+                    Severity::Error => diagnostic.with_severity(Severity::Warning),
+                    _ => diagnostic,
+                })
+                .collect();
+            self.report_diagnostics(path, &source_text, diagnostics, tx_error);
+            return;
+        }
+
+        if !self.linter.options().fix {
+            self.report_messages(path, &source_text, messages, tx_error);
+            return;
+        }
 
-            // TODO: Span is wrong, ban this feature for file process by `PartialLoader`.
-            if !is_processed_by_partial_loader && self.linter.options().fix {
-                let fix_result = Fixer::new(source_text, messages).fix();
-                fs::write(path, fix_result.fixed_code.as_bytes()).unwrap();
-                messages = fix_result.messages;
+        let (mut fixed_code, mut diagnostics) = self.apply_fixes(path, &source_text, messages);
+        // Fixes skipped as they overlap with others, and the problems of the fixed code, are fixed
+        // by linting the fixed code again until no fix applies, like the multipass fixer of ESLint.
+        // The rules of the import plugin depend on the module graph built from the files on disk,
+        // so their files are fixed in a single pass.
+        let multipass = !self.linter.options().import_plugin;
+        let mut passes = 1;
+        while let Some(code) = fixed_code.as_deref().filter(|_| multipass) {
+            self.project_files.lock().unwrap().retain(|file| &*file.path != path);
+            let sources = PartialLoader::parse(ext, code)
+                .unwrap_or_else(|| vec![JavaScriptSource::new(code, source_type, 0)]);
+            let allocator = self.allocator_pool.get();
+            let messages = self.process_partial_sources(path, &allocator, sources, true, tx_error);
+            if passes == MAX_FIX_PASSES {
+                diagnostics = self.reduce_messages(code, messages);
+                break;
             }
+            let (next_code, next_diagnostics) = self.apply_fixes(path, code, messages);
+            diagnostics = next_diagnostics;
+            let Some(next_code) = next_code else { break };
+            fixed_code = Some(next_code);
+            passes += 1;
+        }
 
-            if !messages.is_empty() {
-                self.ignore_path(path);
-                let errors = messages.into_iter().map(|m| m.error).collect();
-                let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-                let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
-                tx_error.send(Some(diagnostics)).unwrap();
+        let Some(fixed_code) = fixed_code else {
+            self.report_diagnostics(path, &source_text, diagnostics, tx_error);
+            return;
+        };
+        if !self.linter.options().dry_run {
+            fs::write(path, fixed_code.as_bytes()).unwrap();
+        }
+        // The problems left by a single pass are relative to the code before its fixes
+        let diagnostics_source_text = if multipass { &fixed_code } else { &source_text };
+        self.report_diagnostics(path, diagnostics_source_text, diagnostics, tx_error);
+        if self.linter.options().dry_run {
+            self.fixed_files.lock().unwrap().push(FixedFile {
+                path: path.to_path_buf(),
+                source_text,
+                fixed_code,
+            });
+        }
+    }
+
+    /// Apply the fixes of `messages` to `source_text`, returning the fixed code if any fix was
+    /// applied, and the problems which are left.
+    fn apply_fixes(
+        &self,
+        path: &Path,
+        source_text: &str,
+        mut messages: Vec<Message<'_>>,
+    ) -> (Option<String>, Vec<OxcDiagnostic>) {
+        if let Some(reviewer) = self.linter.fix_reviewer() {
+            reviewer.review(path, source_text, &mut messages);
+        }
+        let fix_result = Fixer::new(source_text, messages).fix();
+        let diagnostics = self.reduce_messages(source_text, fix_result.messages);
+        (fix_result.fixed.then(|| fix_result.fixed_code.into_owned()), diagnostics)
+    }
+
+    fn report_messages(
+        &self,
+        path: &Path,
+        source_text: &str,
+        messages: Vec<Message<'_>>,
+        tx_error: &DiagnosticSender,
+    ) {
+        let diagnostics = self.reduce_messages(source_text, messages);
+        self.report_diagnostics(path, source_text, diagnostics, tx_error);
+    }
+
+    /// The diagnostics to report for `messages`, see [`reduce_messages`].
+    fn reduce_messages(&self, source_text: &str, messages: Vec<Message<'_>>) -> Vec<OxcDiagnostic> {
+        let options = self.linter.options();
+        reduce_messages(
+            messages,
+            source_text,
+            options.max_problems_per_rule,
+            options.collapse_same_line,
+        )
+    }
+
+    fn report_diagnostics(
+        &self,
+        path: &Path,
+        source_text: &str,
+        diagnostics: Vec<OxcDiagnostic>,
+        tx_error: &DiagnosticSender,
+    ) {
+        if diagnostics.is_empty() {
+            return;
+        }
+        self.ignore_path(path);
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, diagnostics);
+        tx_error.send(Some(diagnostics)).unwrap();
+    }
+
+    /// Lint the scripts extracted from a file, e.g. the `<script>` blocks of a `.vue` file,
+    /// and map the spans of their messages back to the file.
+    #[allow(clippy::cast_possible_truncation)]
+    fn process_partial_sources<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+        sources: Vec<JavaScriptSource<'a>>,
+        check_syntax_errors: bool,
+        tx_error: &DiagnosticSender,
+    ) -> Vec<Message<'a>> {
+        let mut messages = vec![];
+        for JavaScriptSource { source_text, source_type, start } in sources {
+            let source_messages = self.process_source(
+                path,
+                allocator,
+                source_text,
+                source_type,
+                check_syntax_errors,
+                tx_error,
+            );
+            messages.extend(source_messages.into_iter().map(|m| m.move_offset(start as u32)));
+        }
+        messages
+    }
+
+    /// Lint the code cells of a notebook together, as they share their scope,
+    /// and report the diagnostics of each cell separately.
+    fn process_notebook(&self, path: &Path, source_text: &str, tx_error: &DiagnosticSender) {
+        let Some(notebook) = Notebook::parse(source_text) else {
+            self.ignore_path(path);
+            return;
+        };
+
+        let allocator = self.allocator_pool.get();
+        let messages = self.process_source(
+            path,
+            &allocator,
+            &notebook.source_text,
+            notebook.source_type,
+            true,
+            tx_error,
+        );
+        if messages.is_empty() {
+            return;
+        }
+
+        self.ignore_path(path);
+        let path = path.strip_prefix(&self.cwd).unwrap_or(path);
+        let mut cells = BTreeMap::<usize, (Span, Vec<OxcDiagnostic>)>::new();
+        let mut rest = vec![];
+        for message in messages {
+            if let Some(cell) = notebook.cell_at(message.start()) {
+                let diagnostic = Notebook::map_diagnostic(cell, message.error);
+                cells.entry(cell.number).or_insert_with(|| (cell.span, vec![])).1.push(diagnostic);
+            } else {
+                rest.push(message.error);
             }
         }
+        for (number, (span, diagnostics)) in cells {
+            let cell_path = PathBuf::from(format!("{} [cell {number}]", path.display()));
+            let cell_source_text = span.source_text(&notebook.source_text);
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(&cell_path, cell_source_text, diagnostics);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+        if !rest.is_empty() {
+            let diagnostics =
+                DiagnosticService::wrap_diagnostics(path, &notebook.source_text, rest);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
+    /// The lint cache, which is not used with the import plugin as its rules depend on other files.
+    fn cache(&self) -> Option<&LintCache> {
+        self.cache.as_deref().filter(|_| !self.linter.options().import_plugin)
+    }
+
+    /// Run the project-level hooks of all rules after all files are linted.
+    fn run_on_project(&self, tx_error: &DiagnosticSender) {
+        let files = std::mem::take(&mut *self.project_files.lock().unwrap());
+        let read_source = |path: &Path| fs::read_to_string(path).ok();
+        for (path, source_text, diagnostics) in
+            self.linter.run_on_project(files, &self.module_graph, read_source)
+        {
+            let path = path.strip_prefix(&self.cwd).unwrap_or(&path);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, diagnostics);
+            tx_error.send(Some(diagnostics)).unwrap();
+        }
+    }
+
+    /// Add `module_record`, the module record of the file at `path`, to the module graph, and link
+    /// it to the module records of its dependencies, which are processed first.
+    fn link_module(
+        &self,
+        path: &Path,
+        module_record: &Arc<ModuleRecord>,
+        tx_error: &DiagnosticSender,
+    ) {
+        self.module_graph.insert(path, ModuleState::Resolved(Arc::clone(module_record)));
+        self.update_cache_state(path);
+
+        // Retrieve all dependency modules from this module.
+        // The `paths` of a tsconfig only apply to the files in its directory, which is
+        // absolute, so relative paths are resolved from the current directory and made
+        // relative again to match the paths of the linted files
+        let dir = self.cwd.join(path.parent().unwrap());
+        let relative = path.is_relative();
+        let resolver = self.resolver(path);
+        module_record
+            .requested_modules
+            .keys()
+            .par_bridge()
+            .map_with(&*resolver, |resolver, specifier| {
+                resolver.resolve(&dir, specifier).ok().map(|r| (specifier, r))
+            })
+            .flatten()
+            .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
+                let path = resolution.path();
+                let path =
+                    if relative { path.strip_prefix(&self.cwd).unwrap_or(path) } else { path };
+                self.process_path(path, tx_error);
+                let Some(target_module_record) = self.module_graph.get(path) else {
+                    return;
+                };
+                // Append target_module to loaded_modules
+                self.module_graph.add_dependency(module_record, specifier, &target_module_record);
+            });
+
+        // The thread is blocked here until all dependent modules are resolved.
+
+        // Resolve and append `star_export_bindings`
+        for export_entry in &module_record.star_export_entries {
+            let Some(remote_module_record_ref) = export_entry
+                .module_request
+                .as_ref()
+                .and_then(|module_request| module_record.loaded_modules.get(module_request.name()))
+            else {
+                continue;
+            };
+            let remote_module_record = remote_module_record_ref.value();
+
+            // Append both remote `bindings` and `exported_bindings_from_star_export`
+            let remote_exported_bindings_from_star_export = remote_module_record
+                .exported_bindings_from_star_export
+                .iter()
+                .flat_map(|r| r.value().clone());
+            let remote_bindings = remote_module_record
+                .exported_bindings
+                .keys()
+                .cloned()
+                .chain(remote_exported_bindings_from_star_export)
+                .collect::<Vec<_>>();
+            module_record
+                .exported_bindings_from_star_export
+                .entry(remote_module_record.resolved_absolute_path.clone())
+                .or_default()
+                .value_mut()
+                .extend(remote_bindings);
+        }
+    }
+
+    /// The cache keeping the module record of the file at `path`, a dependency of the linted files,
+    /// see [`LintCache`]. The records of the files with partial sources, e.g. `.vue` files, are
+    /// not kept as they are built from a part of the file.
+    fn module_cache(&self, path: &Path) -> Option<&LintCache> {
+        let partial = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(|ext| LINT_PARTIAL_LOADER_EXT.contains(&ext));
+        self.cache.as_deref().filter(|_| {
+            self.linter.options().import_plugin && !partial && !self.paths.contains(path)
+        })
+    }
+
+    /// Record that the file at `path` could not be parsed, see
+    /// [`LintService::number_of_parse_failures`]. The dependencies of the linted files are not
+    /// counted.
+    fn parse_failed(&self, path: &Path) {
+        if self.paths.contains(path) {
+            self.parse_failures.lock().unwrap().insert(path.to_path_buf());
+        }
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -253,11 +785,16 @@ impl Runtime {
         check_syntax_errors: bool,
         tx_error: &DiagnosticSender,
     ) -> Vec<Message<'a>> {
-        let ret = Parser::new(allocator, source_text, source_type)
-            .allow_return_outside_function(true)
-            .parse();
+        let ret = if is_json_path(path) {
+            parse_json(allocator, source_text)
+        } else {
+            Parser::new(allocator, source_text, source_type)
+                .allow_return_outside_function(true)
+                .parse()
+        };
 
         if !ret.errors.is_empty() {
+            self.parse_failed(path);
             return ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
@@ -276,70 +813,10 @@ impl Runtime {
         let module_record = semantic_builder.module_record();
 
         if self.linter.options().import_plugin {
-            self.module_map.insert(
-                path.to_path_buf().into_boxed_path(),
-                ModuleState::Resolved(Arc::clone(&module_record)),
-            );
-            self.update_cache_state(path);
-
-            // Retrieve all dependency modules from this module.
-            let dir = path.parent().unwrap();
-            module_record
-                .requested_modules
-                .keys()
-                .par_bridge()
-                .map_with(self.resolver.as_ref().unwrap(), |resolver, specifier| {
-                    resolver.resolve(dir, specifier).ok().map(|r| (specifier, r))
-                })
-                .flatten()
-                .for_each_with(tx_error, |tx_error, (specifier, resolution)| {
-                    let path = resolution.path();
-                    self.process_path(path, tx_error);
-                    let Some(target_module_record_ref) = self.module_map.get(path) else {
-                        return;
-                    };
-                    let ModuleState::Resolved(target_module_record) =
-                        target_module_record_ref.value()
-                    else {
-                        return;
-                    };
-                    // Append target_module to loaded_modules
-                    module_record
-                        .loaded_modules
-                        .insert(specifier.clone(), Arc::clone(target_module_record));
-                });
-
-            // The thread is blocked here until all dependent modules are resolved.
-
-            // Resolve and append `star_export_bindings`
-            for export_entry in &module_record.star_export_entries {
-                let Some(remote_module_record_ref) =
-                    export_entry.module_request.as_ref().and_then(|module_request| {
-                        module_record.loaded_modules.get(module_request.name())
-                    })
-                else {
-                    continue;
-                };
-                let remote_module_record = remote_module_record_ref.value();
-
-                // Append both remote `bindings` and `exported_bindings_from_star_export`
-                let remote_exported_bindings_from_star_export = remote_module_record
-                    .exported_bindings_from_star_export
-                    .iter()
-                    .flat_map(|r| r.value().clone());
-                let remote_bindings = remote_module_record
-                    .exported_bindings
-                    .keys()
-                    .cloned()
-                    .chain(remote_exported_bindings_from_star_export)
-                    .collect::<Vec<_>>();
-                module_record
-                    .exported_bindings_from_star_export
-                    .entry(remote_module_record.resolved_absolute_path.clone())
-                    .or_default()
-                    .value_mut()
-                    .extend(remote_bindings);
+            if let Some(cache) = self.module_cache(path) {
+                cache.set_module(path, LintCache::module_key(source_text), &module_record);
             }
+            self.link_module(path, &module_record, tx_error);
 
             // Stop if the current module is not marked for lint.
             if !self.paths.contains(path) {
@@ -347,14 +824,25 @@ impl Runtime {
             }
         }
 
-        let semantic_ret = semantic_builder.build(program);
+        let mut scratch = self.semantic_scratches.lock().unwrap().pop().unwrap_or_default();
+        let semantic_ret = semantic_builder.build_with_scratch(program, &mut scratch);
+        self.semantic_scratches.lock().unwrap().push(scratch);
 
         if !semantic_ret.errors.is_empty() {
+            self.parse_failed(path);
             return semantic_ret.errors.into_iter().map(|err| Message::new(err, None)).collect();
         };
 
+        if self.linter.has_project_rules() {
+            self.project_files.lock().unwrap().push(ProjectFile::new(path, module_record));
+        }
+
         let lint_ctx =
-            LintContext::new(path.to_path_buf().into_boxed_path(), Rc::new(semantic_ret.semantic));
+            LintContext::new(path.to_path_buf().into_boxed_path(), Rc::new(semantic_ret.semantic))
+                .with_module_graph(&self.module_graph)
+                .with_resolver(&self.resolver(path))
+                .with_package_json(self.package_jsons.find(&self.cwd.join(path)))
+                .with_tsconfig(self.tsconfigs.find(&self.cwd.join(path)));
         self.linter.run(lint_ctx)
     }
 
@@ -375,7 +863,7 @@ impl Runtime {
             })
             .unwrap();
 
-        let cache_hit = if self.module_map.contains_key(path) {
+        let cache_hit = if self.module_graph.contains(path) {
             true
         } else {
             let i = if let CacheStateEntry::PendingStore(i) = *state { i } else { 0 };
@@ -415,7 +903,7 @@ impl Runtime {
 
     fn ignore_path(&self, path: &Path) {
         if self.linter.options().import_plugin {
-            self.module_map.insert(path.to_path_buf().into_boxed_path(), ModuleState::Ignored);
+            self.module_graph.insert(path, ModuleState::Ignored);
             self.update_cache_state(path);
         }
     }
@@ -2,7 +2,7 @@ use std::fmt::Write;
 
 use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::{rules::RULES, Linter, RuleCategory};
+use crate::{rules::RULES, Linter, RuleCategory, RuleFixMeta};
 
 pub struct RuleTable {
     pub sections: Vec<RuleTableSection>,
@@ -21,8 +21,11 @@ pub struct RuleTableRow {
     pub name: &'static str,
     pub plugin: String,
     pub category: RuleCategory,
+    pub fix: RuleFixMeta,
     pub documentation: Option<&'static str>,
     pub turned_on_by_default: bool,
+    /// Whether the rule takes options, e.g. `["error", "smart"]` for `eqeqeq`
+    pub has_options: bool,
 }
 
 impl Default for RuleTable {
@@ -48,7 +51,9 @@ impl RuleTable {
                     documentation: rule.documentation(),
                     plugin: rule.plugin_name().to_string(),
                     category: rule.category(),
+                    fix: rule.fix(),
                     turned_on_by_default: default_rules.contains(name),
+                    has_options: rule.has_options(),
                 }
             })
             .collect::<Vec<_>>();
@@ -85,6 +90,64 @@ impl RuleTable {
 
         RuleTable { total, sections, turned_on_by_default_count: default_rules.len() }
     }
+
+    /// The rows of all the sections, sorted by plugin and name.
+    pub fn rows(&self) -> Vec<&RuleTableRow> {
+        let mut rows = self.sections.iter().flat_map(|section| &section.rows).collect::<Vec<_>>();
+        rows.sort_by_key(|row| (row.plugin.as_str(), row.name));
+        rows
+    }
+
+    /// Render all the rules as a single plain text table, with a column for each piece of
+    /// metadata.
+    pub fn render_text_table(&self) -> String {
+        let rows = self.rows();
+        let rule_width = rows.iter().map(|row| row.name.len()).max().unwrap_or_default();
+        let plugin_width = rows.iter().map(|row| row.plugin.len()).max().unwrap_or_default();
+        let category_width = "restriction".len();
+        let fix_width = "dangerous_fix".len();
+
+        let mut s = String::new();
+        writeln!(
+            s,
+            "{:<rule_width$}  {:<plugin_width$}  {:<category_width$}  Default  {:<fix_width$}  Options",
+            "Rule", "Plugin", "Category", "Fix"
+        )
+        .unwrap();
+        for row in rows {
+            let category = row.category.to_string().to_lowercase();
+            let default = if row.turned_on_by_default { "on" } else { "off" };
+            let fix = row.fix.name().unwrap_or("none");
+            let options = if row.has_options { "yes" } else { "no" };
+            writeln!(
+                s,
+                "{:<rule_width$}  {:<plugin_width$}  {category:<category_width$}  {default:<7}  {fix:<fix_width$}  {options}",
+                row.name, row.plugin
+            )
+            .unwrap();
+        }
+        writeln!(s, "\nDefault: {}", self.turned_on_by_default_count).unwrap();
+        writeln!(s, "Total: {}", self.total).unwrap();
+        s
+    }
+
+    /// Render all the rules as a JSON array of objects with the metadata of each rule, sorted
+    /// by plugin and name.
+    pub fn render_json(&self) -> serde_json::Value {
+        self.rows()
+            .into_iter()
+            .map(|row| {
+                serde_json::json!({
+                    "name": row.name,
+                    "plugin": row.plugin,
+                    "category": row.category.to_string().to_lowercase(),
+                    "default": row.turned_on_by_default,
+                    "fix": row.fix.name(),
+                    "options": row.has_options,
+                })
+            })
+            .collect()
+    }
 }
 
 impl RuleTableSection {
@@ -99,16 +162,23 @@ impl RuleTableSection {
         writeln!(s, "{}", category.description()).unwrap();
 
         let x = "";
-        writeln!(s, "| {:<rule_width$} | {:<plugin_width$} | Default |", "Rule name", "Source")
+        let fix_width = "dangerous_fix".len();
+        writeln!(
+            s,
+            "| {:<rule_width$} | {:<plugin_width$} | Default | {:<fix_width$} |",
+            "Rule name", "Source", "Fix"
+        )
+        .unwrap();
+        writeln!(s, "| {x:-<rule_width$} | {x:-<plugin_width$} | {x:-<7} | {x:-<fix_width$} |")
             .unwrap();
-        writeln!(s, "| {x:-<rule_width$} | {x:-<plugin_width$} | {x:-<7} |").unwrap();
 
         for row in rows {
             let rule_name = row.name;
             let plugin_name = &row.plugin;
             let (default, default_width) =
                 if row.turned_on_by_default { ("✅", 6) } else { ("", 7) };
-            writeln!(s, "| {rule_name:<rule_width$} | {plugin_name:<plugin_width$} | {default:<default_width$} |").unwrap();
+            let fix = row.fix.name().unwrap_or_default();
+            writeln!(s, "| {rule_name:<rule_width$} | {plugin_name:<plugin_width$} | {default:<default_width$} | {fix:<fix_width$} |").unwrap();
         }
 
         s
@@ -1,12 +1,15 @@
 use std::{
     env,
+    fmt::Write,
     path::{Path, PathBuf},
 };
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, GraphicalTheme, NamedSource};
+use oxc_span::SourceType;
 use serde::Deserialize;
-use serde_json::Value;
+use serde_json::{Map, Value};
+use similar::TextDiff;
 
 use crate::{
     rules::RULES, AllowWarnDeny, Fixer, LintOptions, LintService, LintServiceOptions, Linter,
@@ -20,12 +23,84 @@ enum TestResult {
     Fixed(String),
 }
 
+/// Which fixes are applied when running a test case
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FixMode {
+    None,
+    Safe,
+    Dangerous,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct TestCase {
+    /// Label of the case in the snapshot, after its index
+    name: Option<String>,
     source: String,
     rule_config: Option<Value>,
     eslint_config: Option<Value>,
     path: Option<PathBuf>,
+    source_type: Option<SourceType>,
+}
+
+impl TestCase {
+    pub fn new<S: Into<String>>(source: S) -> Self {
+        Self { source: source.into(), ..Self::default() }
+    }
+
+    /// Name of the case, which labels its diagnostics in the snapshot, e.g. `fail[3]: nested`
+    #[must_use]
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_rule_config(mut self, rule_config: Value) -> Self {
+        self.rule_config = Some(rule_config);
+        self
+    }
+
+    /// Shared settings for this case, e.g. `{ "jsx-a11y": { "components": { "Link": "a" } } }`
+    #[must_use]
+    pub fn with_settings(self, settings: Value) -> Self {
+        self.with_eslint_config_entry("settings", settings)
+    }
+
+    /// Global variables for this case, e.g. `{ "foo": "readonly" }`
+    #[must_use]
+    pub fn with_globals(self, globals: Value) -> Self {
+        self.with_eslint_config_entry("globals", globals)
+    }
+
+    /// Environments for this case, e.g. `{ "browser": true }`
+    #[must_use]
+    pub fn with_env(self, env: Value) -> Self {
+        self.with_eslint_config_entry("env", env)
+    }
+
+    /// Lint the case as the file at `path`, relative to the current working directory of the
+    /// [`Tester`]. The source type is inferred from its extension.
+    #[must_use]
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Parse the case with `source_type` instead of inferring it from the path, e.g. as a script.
+    #[must_use]
+    pub fn with_source_type(mut self, source_type: SourceType) -> Self {
+        self.source_type = Some(source_type);
+        self
+    }
+
+    fn with_eslint_config_entry(mut self, key: &str, value: Value) -> Self {
+        let config = self.eslint_config.get_or_insert_with(|| Value::Object(Map::default()));
+        let Value::Object(config) = config else {
+            panic!("eslint config must be an object, got {config}");
+        };
+        config.insert(key.to_string(), value);
+        self
+    }
 }
 
 impl From<&str> for TestCase {
@@ -61,7 +136,7 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
             Option<PathBuf>,
         ),
     ) -> Self {
-        Self { source: source.to_string(), rule_config, eslint_config, path }
+        Self { source: source.to_string(), rule_config, eslint_config, path, ..Self::default() }
     }
 }
 
@@ -92,15 +167,21 @@ pub struct Tester {
     expect_pass: Vec<TestCase>,
     expect_fail: Vec<TestCase>,
     expect_fix: Vec<ExpectFix>,
+    expect_dangerous_fix: Vec<ExpectFix>,
     snapshot: String,
+    snapshot_fixes: bool,
     current_working_directory: Box<Path>,
+    /// Settings shared by the cases which do not have their own, see [`Tester::with_settings`]
+    settings: Option<Value>,
     import_plugin: bool,
+    jsdoc_plugin: bool,
     jest_plugin: bool,
     vitest_plugin: bool,
     jsx_a11y_plugin: bool,
     nextjs_plugin: bool,
     react_perf_plugin: bool,
     react_refresh_plugin: bool,
+    json_plugin: bool,
 }
 
 impl Tester {
@@ -120,15 +201,20 @@ impl Tester {
             expect_pass,
             expect_fail,
             expect_fix: vec![],
+            expect_dangerous_fix: vec![],
             snapshot: String::new(),
+            snapshot_fixes: false,
             current_working_directory,
+            settings: None,
             import_plugin: false,
+            jsdoc_plugin: false,
             jest_plugin: false,
             jsx_a11y_plugin: false,
             nextjs_plugin: false,
             react_perf_plugin: false,
             vitest_plugin: false,
             react_refresh_plugin: false,
+            json_plugin: false,
         }
     }
 
@@ -149,6 +235,11 @@ impl Tester {
         self
     }
 
+    pub fn with_jsdoc_plugin(mut self, yes: bool) -> Self {
+        self.jsdoc_plugin = yes;
+        self
+    }
+
     pub fn with_jest_plugin(mut self, yes: bool) -> Self {
         self.jest_plugin = yes;
         self
@@ -179,6 +270,30 @@ impl Tester {
         self
     }
 
+    /// Enable the json plugin, whose rules are tested on a `package.json` file.
+    pub fn with_json_plugin(mut self, yes: bool) -> Self {
+        self.json_plugin = yes;
+        if yes {
+            self.rule_path = PathBuf::from("package.json");
+        }
+        self
+    }
+
+    /// Shared settings for all the cases, e.g. `{ "jsx-a11y": { "components": { "Link": "a" } } }`.
+    /// The cases with [`TestCase::with_settings`] use their own settings instead.
+    pub fn with_settings(mut self, settings: Value) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /// Add the source code before and after fixing each of the failing cases to the snapshot, as
+    /// a diff below their diagnostics. The fixes of [`Tester::expect_dangerous_fix`] are shown
+    /// separately when they differ from the safe ones.
+    pub fn snapshot_fixes(mut self) -> Self {
+        self.snapshot_fixes = true;
+        self
+    }
+
     /// Add cases that should fix problems found in the source code.
     ///
     /// These cases will fail if no fixes are produced or if the fixed source
@@ -206,6 +321,15 @@ impl Tester {
         self
     }
 
+    /// Add cases that should be fixed with `--fix-dangerously`, see [`Tester::expect_fix`].
+    ///
+    /// Fixes reported with [`LintContext::diagnostic_with_dangerous_fix`](crate::LintContext::diagnostic_with_dangerous_fix)
+    /// are not applied in the cases of [`Tester::expect_fix`].
+    pub fn expect_dangerous_fix<F: Into<ExpectFix>>(mut self, expect_fix: Vec<F>) -> Self {
+        self.expect_dangerous_fix = expect_fix.into_iter().map(Into::into).collect::<Vec<_>>();
+        self
+    }
+
     pub fn test(&mut self) {
         self.test_pass();
         self.test_fail();
@@ -225,54 +349,117 @@ impl Tester {
     }
 
     fn test_pass(&mut self) {
-        for TestCase { source, rule_config, eslint_config, path } in self.expect_pass.clone() {
-            let result = self.run(&source, rule_config, &eslint_config, path, false);
+        for case in self.expect_pass.clone() {
+            let result = self.run(&case, FixMode::None);
             let passed = result == TestResult::Passed;
-            assert!(passed, "expect test to pass: {source} {}", self.snapshot);
+            assert!(passed, "expect test to pass: {} {}", case.source, self.snapshot);
         }
     }
 
     fn test_fail(&mut self) {
-        for TestCase { source, rule_config, eslint_config, path } in self.expect_fail.clone() {
-            let result = self.run(&source, rule_config, &eslint_config, path, false);
+        for (index, case) in self.expect_fail.clone().into_iter().enumerate() {
+            // Label the diagnostics of each case, so that the changes of a snapshot can be
+            // traced back to the cases
+            if !self.snapshot.is_empty() {
+                self.snapshot.push('\n');
+            }
+            let _ = match &case.name {
+                Some(name) => writeln!(self.snapshot, "fail[{index}]: {name}"),
+                None => writeln!(self.snapshot, "fail[{index}]"),
+            };
+            let start = self.snapshot.len();
+            let result = self.run(&case, FixMode::None);
             let failed = result == TestResult::Failed;
-            assert!(failed, "expect test to fail: {source}");
+            assert!(failed, "expect test to fail: {}", case.source);
+            // Reports start with an empty line
+            if self.snapshot[start..].starts_with('\n') {
+                self.snapshot.remove(start);
+            }
+            if self.snapshot_fixes {
+                self.snapshot_fix(&case);
+            }
+        }
+    }
+
+    /// Add the diff of the fixes of the failing `case` to the snapshot, nothing when it has no
+    /// fixes.
+    fn snapshot_fix(&mut self, case: &TestCase) {
+        let fixed = |tester: &mut Self, fix_mode| match tester.run(case, fix_mode) {
+            TestResult::Fixed(fixed) if fixed != case.source => Some(fixed),
+            _ => None,
+        };
+        let safe = fixed(self, FixMode::Safe);
+        let dangerous =
+            fixed(self, FixMode::Dangerous).filter(|fixed| Some(fixed) != safe.as_ref());
+        for (label, fixed) in [("fix", safe), ("dangerous fix", dangerous)] {
+            let Some(fixed) = fixed else { continue };
+            let diff = TextDiff::from_lines(&case.source, &fixed)
+                .unified_diff()
+                .missing_newline_hint(false)
+                .header("before", "after")
+                .to_string();
+            let _ = writeln!(self.snapshot, "  {label}:");
+            for line in diff.lines() {
+                let _ = writeln!(self.snapshot, "  {line}");
+            }
         }
     }
 
     fn test_fix(&mut self) {
-        for fix in self.expect_fix.clone() {
-            let ExpectFix { source, expected, rule_config: config } = fix;
-            let result = self.run(&source, config, &None, None, true);
+        let safe_fixes = self.expect_fix.clone().into_iter().map(|fix| (fix, FixMode::Safe));
+        let dangerous_fixes =
+            self.expect_dangerous_fix.clone().into_iter().map(|fix| (fix, FixMode::Dangerous));
+        for (fix, fix_mode) in safe_fixes.chain(dangerous_fixes) {
+            let ExpectFix { source, expected, rule_config } = fix;
+            let case = TestCase { source, rule_config, ..TestCase::default() };
+            let result = self.run(&case, fix_mode);
+            let source = case.source;
             match result {
-                TestResult::Fixed(fixed_str) => assert_eq!(
-                    expected, fixed_str,
-                    r#"Expected "{source}" to be fixed into "{expected}""#
-                ),
+                TestResult::Fixed(fixed_str) => {
+                    if expected != fixed_str {
+                        let diff = TextDiff::from_lines(&expected, &fixed_str)
+                            .unified_diff()
+                            .missing_newline_hint(false)
+                            .header("expected", "fixed")
+                            .to_string();
+                        panic!("Expected \"{source}\" to be fixed into \"{expected}\":\n{diff}");
+                    }
+                }
                 TestResult::Passed => panic!("Expected a fix, but test passed: {source}"),
                 TestResult::Failed => panic!("Expected a fix, but test failed: {source}"),
             }
         }
     }
 
-    fn run(
-        &mut self,
-        source_text: &str,
-        rule_config: Option<Value>,
-        eslint_config: &Option<Value>,
-        path: Option<PathBuf>,
-        is_fix: bool,
-    ) -> TestResult {
+    fn run(&mut self, case: &TestCase, fix_mode: FixMode) -> TestResult {
+        let TestCase { source, rule_config, eslint_config, path, source_type, .. } = case;
+        let source_text = source.as_str();
         let allocator = Allocator::default();
-        let rule = self.find_rule().read_json(rule_config.unwrap_or_default());
+        let rule = self.find_rule();
+        let rule_config = rule_config.clone().unwrap_or_default();
+        if let Err(error) = rule.validate_json(&rule_config) {
+            panic!("Invalid rule options {rule_config} for test case {source}: {}", error.message);
+        }
+        let rule = rule.read_json(rule_config);
         let options = LintOptions::default()
-            .with_fix(is_fix)
+            .with_fix(fix_mode != FixMode::None)
+            .with_fix_dangerously(fix_mode == FixMode::Dangerous)
             .with_import_plugin(self.import_plugin)
+            .with_jsdoc_plugin(self.jsdoc_plugin)
             .with_jest_plugin(self.jest_plugin)
             .with_vitest_plugin(self.vitest_plugin)
             .with_jsx_a11y_plugin(self.jsx_a11y_plugin)
             .with_nextjs_plugin(self.nextjs_plugin)
-            .with_react_perf_plugin(self.react_perf_plugin);
+            .with_react_perf_plugin(self.react_perf_plugin)
+            .with_react_refresh_plugin(self.react_refresh_plugin)
+            .with_json_plugin(self.json_plugin);
+        let mut eslint_config = eslint_config.clone();
+        if let Some(settings) = &self.settings {
+            if !eslint_config.as_ref().is_some_and(|config| config.get("settings").is_some()) {
+                let config = eslint_config.get_or_insert_with(|| Value::Object(Map::default()));
+                config["settings"] = settings.clone();
+            }
+        }
         let eslint_config = eslint_config
             .as_ref()
             .map_or_else(OxlintConfig::default, |v| OxlintConfig::deserialize(v).unwrap());
@@ -291,17 +478,18 @@ impl Tester {
 
         let cwd = self.current_working_directory.clone();
         let paths = vec![path_to_lint.into_boxed_path()];
-        let options = LintServiceOptions { cwd, paths, tsconfig: None };
+        let options = LintServiceOptions { cwd, paths, tsconfig: None, cache: None, stdin: None };
         let lint_service = LintService::from_linter(linter, options);
         let diagnostic_service = DiagnosticService::default();
         let tx_error = diagnostic_service.sender();
-        let result = lint_service.run_source(&allocator, source_text, false, tx_error);
+        let result =
+            lint_service.run_source(&allocator, source_text, *source_type, false, tx_error);
 
         if result.is_empty() {
             return TestResult::Passed;
         }
 
-        if is_fix {
+        if fix_mode != FixMode::None {
             let fix_result = Fixer::new(source_text, result).fix();
             return TestResult::Fixed(fix_result.fixed_code.to_string());
         }
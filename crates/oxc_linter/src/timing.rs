@@ -0,0 +1,188 @@
+use std::{fmt::Write, path::Path, sync::Mutex, time::Duration};
+
+use rustc_hash::FxHashMap;
+
+/// Wall time spent in a rule, accumulated over all linted files.
+#[derive(Debug, Clone)]
+pub struct RuleTiming {
+    pub plugin_name: &'static str,
+    pub rule_name: &'static str,
+    pub duration: Duration,
+    /// Number of files the rule ran on
+    pub files: usize,
+    /// The file the rule spent the most time on, with the time spent
+    pub slowest_file: Option<(Box<Path>, Duration)>,
+}
+
+impl RuleTiming {
+    /// The id of the rule, e.g. `eslint/no-debugger`.
+    pub fn id(&self) -> String {
+        format!("{}/{}", self.plugin_name, self.rule_name)
+    }
+}
+
+/// Wall time spent in each rule, accumulated over all linted files.
+#[derive(Debug, Default)]
+pub struct RuleTimings(Mutex<FxHashMap<(&'static str, &'static str), RuleTiming>>);
+
+impl RuleTimings {
+    /// Record `duration` spent in a rule while linting the file at `path`, which is none for the
+    /// time spent on the whole project.
+    ///
+    /// # Panics
+    ///
+    /// When the lock is poisoned.
+    pub fn add(
+        &self,
+        plugin_name: &'static str,
+        rule_name: &'static str,
+        path: Option<&Path>,
+        duration: Duration,
+    ) {
+        let mut timings = self.0.lock().unwrap();
+        let timing = timings.entry((plugin_name, rule_name)).or_insert_with(|| RuleTiming {
+            plugin_name,
+            rule_name,
+            duration: Duration::ZERO,
+            files: 0,
+            slowest_file: None,
+        });
+        timing.duration += duration;
+        let Some(path) = path else { return };
+        timing.files += 1;
+        if timing.slowest_file.as_ref().map_or(true, |(_, slowest)| duration > *slowest) {
+            timing.slowest_file = Some((path.into(), duration));
+        }
+    }
+
+    /// Timings sorted from the slowest to the fastest rule.
+    ///
+    /// # Panics
+    ///
+    /// When the lock is poisoned.
+    pub fn sorted(&self) -> Vec<RuleTiming> {
+        let mut timings = self.0.lock().unwrap().values().cloned().collect::<Vec<_>>();
+        timings.sort_unstable_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then(a.plugin_name.cmp(b.plugin_name))
+                .then(a.rule_name.cmp(b.rule_name))
+        });
+        timings
+    }
+
+    /// Table of the `limit` slowest rules, in the format of `TIMING=1 eslint`, with the file each
+    /// of them spent the most time on.
+    ///
+    /// ```text
+    /// Rule                  | Time (ms) | Relative | Files | Slowest file
+    /// :---------------------|----------:|---------:|------:|:-----------------------
+    /// import/no-cycle       |    52.472 |    61.1% |   120 | src/index.ts (3.207 ms)
+    /// ```
+    pub fn table(&self, limit: usize) -> String {
+        let timings = self.sorted();
+        let total = timings.iter().map(|timing| timing.duration).sum::<Duration>();
+        let rows = timings
+            .iter()
+            .take(limit)
+            .map(|timing| {
+                #[allow(clippy::cast_precision_loss)]
+                let relative = if total.is_zero() {
+                    0.0
+                } else {
+                    timing.duration.as_nanos() as f64 * 100.0 / total.as_nanos() as f64
+                };
+                let slowest_file =
+                    timing.slowest_file.as_ref().map_or_else(String::new, |(path, duration)| {
+                        format!("{} ({} ms)", path.display(), millis(*duration))
+                    });
+                [
+                    timing.id(),
+                    millis(timing.duration),
+                    format!("{relative:.1}%"),
+                    timing.files.to_string(),
+                    slowest_file,
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let header = ["Rule", "Time (ms)", "Relative", "Files", "Slowest file"];
+        let mut widths = header.map(str::len);
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.len());
+            }
+        }
+        let [rule, time, relative, files, slowest] = widths;
+
+        let mut table = String::new();
+        let _ = writeln!(
+            table,
+            "{:rule$} | {:>time$} | {:>relative$} | {:>files$} | {}",
+            header[0], header[1], header[2], header[3], header[4]
+        );
+        let _ = writeln!(
+            table,
+            ":{}|{}:|{}:|{}:|:{}",
+            "-".repeat(rule),
+            "-".repeat(time + 1),
+            "-".repeat(relative + 1),
+            "-".repeat(files + 1),
+            "-".repeat(slowest)
+        );
+        for row in rows {
+            let line = format!(
+                "{:rule$} | {:>time$} | {:>relative$} | {:>files$} | {}",
+                row[0], row[1], row[2], row[3], row[4]
+            );
+            let _ = writeln!(table, "{}", line.trim_end());
+        }
+        table
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, time::Duration};
+
+    use super::RuleTimings;
+
+    #[test]
+    fn test_table() {
+        let timings = RuleTimings::default();
+        let ms = Duration::from_millis;
+        timings.add("eslint", "no-debugger", Some(Path::new("a.js")), ms(1));
+        timings.add("eslint", "no-debugger", Some(Path::new("b.js")), ms(2));
+        timings.add("typescript", "no-unused-vars", Some(Path::new("a.js")), ms(4));
+        timings.add("eslint", "no-unused-vars", Some(Path::new("a.js")), ms(3));
+        timings.add("import", "no-cycle", None, ms(10));
+
+        let sorted = timings.sorted();
+        assert_eq!(
+            sorted.iter().map(super::RuleTiming::id).collect::<Vec<_>>(),
+            [
+                "import/no-cycle",
+                "typescript/no-unused-vars",
+                "eslint/no-debugger",
+                "eslint/no-unused-vars"
+            ]
+        );
+        assert_eq!(sorted[2].files, 2);
+        assert_eq!(sorted[2].slowest_file, Some((Path::new("b.js").into(), ms(2))));
+
+        assert_eq!(
+            timings.table(3),
+            "\
+Rule                      | Time (ms) | Relative | Files | Slowest file
+:-------------------------|----------:|---------:|------:|:---------------
+import/no-cycle           |    10.000 |    50.0% |     0 |
+typescript/no-unused-vars |     4.000 |    20.0% |     1 | a.js (4.000 ms)
+eslint/no-debugger        |     3.000 |    15.0% |     2 | b.js (2.000 ms)
+"
+        );
+    }
+}
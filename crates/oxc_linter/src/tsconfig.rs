@@ -0,0 +1,320 @@
+//! The nearest `tsconfig.json` of the linted files, see [`LintContext::tsconfig`].
+//!
+//! [`LintContext::tsconfig`]: crate::LintContext::tsconfig
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use dashmap::DashMap;
+use rustc_hash::FxHashSet;
+use serde::Deserialize;
+
+/// The `compilerOptions` of a `tsconfig.json` which rules look at, including the options
+/// inherited with `extends`.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CompilerOptions {
+    /// The version of the emitted JavaScript, e.g. `"ES2022"` or `"ESNext"`
+    pub target: Option<String>,
+    /// The declaration files of the runtime APIs, e.g. `["ES2022", "DOM"]`
+    pub lib: Option<Vec<String>>,
+    pub strict: Option<bool>,
+    pub strict_null_checks: Option<bool>,
+    pub no_implicit_any: Option<bool>,
+    pub use_unknown_in_catch_variables: Option<bool>,
+    /// Not enabled by `strict`
+    pub no_unchecked_indexed_access: Option<bool>,
+}
+
+impl CompilerOptions {
+    /// Fill the options not set in `self` with the options of the `extends`ed `base`.
+    fn inherit(&mut self, base: Self) {
+        self.target = self.target.take().or(base.target);
+        self.lib = self.lib.take().or(base.lib);
+        self.strict = self.strict.or(base.strict);
+        self.strict_null_checks = self.strict_null_checks.or(base.strict_null_checks);
+        self.no_implicit_any = self.no_implicit_any.or(base.no_implicit_any);
+        self.use_unknown_in_catch_variables =
+            self.use_unknown_in_catch_variables.or(base.use_unknown_in_catch_variables);
+        self.no_unchecked_indexed_access =
+            self.no_unchecked_indexed_access.or(base.no_unchecked_indexed_access);
+    }
+
+    /// The value of a flag of the `strict` family, which defaults to `strict` when not set.
+    fn strict_flag(&self, flag: Option<bool>) -> bool {
+        flag.or(self.strict).unwrap_or(false)
+    }
+
+    /// Whether `null` and `undefined` are excluded from the other types, with
+    /// `strictNullChecks` or `strict`.
+    pub fn strict_null_checks(&self) -> bool {
+        self.strict_flag(self.strict_null_checks)
+    }
+
+    /// Whether implicit `any` types are reported, with `noImplicitAny` or `strict`.
+    pub fn no_implicit_any(&self) -> bool {
+        self.strict_flag(self.no_implicit_any)
+    }
+
+    /// Whether the variables of `catch` clauses are `unknown` instead of `any`, with
+    /// `useUnknownInCatchVariables` or `strict`.
+    pub fn use_unknown_in_catch_variables(&self) -> bool {
+        self.strict_flag(self.use_unknown_in_catch_variables)
+    }
+
+    /// Whether `lib` lists the library `name`, ignoring case, e.g. `has_lib("dom")` for
+    /// `"lib": ["ES2022", "DOM"]`.
+    ///
+    /// The default libraries of the `target`, used when `lib` is not set, are not taken into
+    /// account.
+    pub fn has_lib(&self, name: &str) -> bool {
+        self.lib.iter().flatten().any(|lib| lib.eq_ignore_ascii_case(name))
+    }
+
+    /// The environments whose globals are declared by the libraries of `lib`, e.g. `browser`
+    /// for `DOM`.
+    pub(crate) fn lib_envs(&self) -> impl Iterator<Item = &str> + '_ {
+        [("dom", "browser"), ("webworker", "worker")]
+            .into_iter()
+            .filter(|(lib, _)| self.has_lib(lib))
+            .map(|(_, env)| env)
+    }
+}
+
+/// A parsed `tsconfig.json`.
+#[derive(Debug, Default, Clone)]
+pub struct Tsconfig {
+    /// The path of the file
+    pub path: PathBuf,
+    pub compiler_options: CompilerOptions,
+}
+
+/// The fields of a `tsconfig.json` as written in the file.
+#[derive(Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct RawTsconfig {
+    extends: Extends,
+    compiler_options: CompilerOptions,
+}
+
+/// `"extends": "./base.json"`, or an array since TypeScript 5.0 whose later entries override the
+/// earlier ones.
+#[derive(Default, Deserialize)]
+#[serde(untagged)]
+enum Extends {
+    #[default]
+    None,
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Tsconfig {
+    /// Parse the `tsconfig.json` at `path` and the files it `extends`, `None` when it cannot be
+    /// read or is not valid. Bases which cannot be found are skipped, as the resolver does.
+    pub fn read(path: &Path) -> Option<Self> {
+        let compiler_options = Self::read_compiler_options(path, &mut FxHashSet::default())?;
+        Some(Self { path: path.to_path_buf(), compiler_options })
+    }
+
+    /// Parse the content of a `tsconfig.json`, comments and trailing commas included, without
+    /// following `extends`. `None` when it is not valid.
+    pub fn parse(json: &str) -> Option<Self> {
+        let raw = Self::parse_raw(json)?;
+        Some(Self { path: PathBuf::new(), compiler_options: raw.compiler_options })
+    }
+
+    fn parse_raw(json: &str) -> Option<RawTsconfig> {
+        let mut json = json.to_string();
+        json_strip_comments::strip(&mut json).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// `visited` stops the cycles of `extends`.
+    fn read_compiler_options(
+        path: &Path,
+        visited: &mut FxHashSet<PathBuf>,
+    ) -> Option<CompilerOptions> {
+        if !visited.insert(path.to_path_buf()) {
+            return None;
+        }
+        let raw = Self::parse_raw(&fs::read_to_string(path).ok()?)?;
+        let bases = match raw.extends {
+            Extends::None => vec![],
+            Extends::One(base) => vec![base],
+            Extends::Many(bases) => bases,
+        };
+        let dir = path.parent()?;
+        let mut compiler_options = raw.compiler_options;
+        // The last base has the highest priority, so it is inherited from first
+        for base in bases.iter().rev() {
+            let Some(base_path) = Self::find_base(dir, base) else { continue };
+            if let Some(base) = Self::read_compiler_options(&base_path, visited) {
+                compiler_options.inherit(base);
+            }
+        }
+        Some(compiler_options)
+    }
+
+    /// The path of the `extends` entry `base` of a `tsconfig.json` in `dir`: a relative or
+    /// absolute path, or a package in `node_modules`, e.g. `@tsconfig/node20/tsconfig.json` or
+    /// `@tsconfig/strictest`.
+    fn find_base(dir: &Path, base: &str) -> Option<PathBuf> {
+        let with_json = |path: PathBuf| {
+            if path.extension().is_some_and(|ext| ext == "json") {
+                path
+            } else {
+                // Not `with_extension`, which would replace `.base` of `tsconfig.base`
+                let mut path = path.into_os_string();
+                path.push(".json");
+                PathBuf::from(path)
+            }
+        };
+        if base.starts_with('.') || Path::new(base).is_absolute() {
+            return Some(with_json(dir.join(base))).filter(|path| path.is_file());
+        }
+        dir.ancestors().map(|dir| dir.join("node_modules").join(base)).find_map(|path| {
+            [path.join("tsconfig.json"), with_json(path)].into_iter().find(|path| path.is_file())
+        })
+    }
+}
+
+/// Cache of the `tsconfig.json` of the directories containing linted files.
+#[derive(Default)]
+pub(crate) struct Tsconfigs {
+    /// The `tsconfig.json` used for all the files, from `--tsconfig` or the `import/resolver`
+    /// settings, instead of the nearest one
+    config_file: Option<PathBuf>,
+    /// The path of the nearest `tsconfig.json` of each directory, if any
+    nearest: DashMap<PathBuf, Option<PathBuf>>,
+    /// Each `tsconfig.json` read, `None` when it is not valid
+    parsed: DashMap<PathBuf, Option<Arc<Tsconfig>>>,
+}
+
+impl Tsconfigs {
+    pub fn new(config_file: Option<PathBuf>) -> Self {
+        Self { config_file, ..Self::default() }
+    }
+
+    /// The `tsconfig.json` used for all the files, if any.
+    pub fn config_file(&self) -> Option<&Path> {
+        self.config_file.as_deref()
+    }
+
+    /// The path of the `tsconfig.json` of the file at `path`: the configured one, else the one in
+    /// its directory or the nearest of its ancestors.
+    pub fn find_path(&self, path: &Path) -> Option<PathBuf> {
+        if let Some(config_file) = &self.config_file {
+            return Some(config_file.clone()).filter(|path| path.is_file());
+        }
+        let dir = path.parent()?;
+        // Relative paths are relative to the current directory, whose ancestors are searched too
+        let dir =
+            if dir.is_absolute() { dir.to_path_buf() } else { env::current_dir().ok()?.join(dir) };
+        self.nearest
+            .entry(dir.clone())
+            .or_insert_with(|| {
+                dir.ancestors().map(|dir| dir.join("tsconfig.json")).find(|path| path.is_file())
+            })
+            .clone()
+    }
+
+    /// The parsed `tsconfig.json` of the file at `path`, see [`Tsconfigs::find_path`]. `None`
+    /// when it is not valid.
+    pub fn find(&self, path: &Path) -> Option<Arc<Tsconfig>> {
+        let path = self.find_path(path)?;
+        self.parsed
+            .entry(path.clone())
+            .or_insert_with(|| Tsconfig::read(&path).map(Arc::new))
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::{Tsconfig, Tsconfigs};
+
+    #[test]
+    fn test_parse() {
+        let tsconfig = Tsconfig::parse(
+            r#"{
+                // comments and trailing commas are allowed
+                "compilerOptions": {
+                    "target": "ES2022",
+                    "lib": ["ES2022", "DOM"],
+                    "strict": true,
+                    "useUnknownInCatchVariables": false,
+                },
+            }"#,
+        )
+        .unwrap();
+        let options = &tsconfig.compiler_options;
+        assert_eq!(options.target.as_deref(), Some("ES2022"));
+        assert!(options.has_lib("dom"));
+        assert!(!options.has_lib("webworker"));
+        assert_eq!(options.lib_envs().collect::<Vec<_>>(), ["browser"]);
+        assert!(options.strict_null_checks());
+        assert!(options.no_implicit_any());
+        assert!(!options.use_unknown_in_catch_variables());
+
+        let options = Tsconfig::parse("{}").unwrap().compiler_options;
+        assert!(!options.strict_null_checks());
+        assert_eq!(options.lib_envs().count(), 0);
+        assert!(Tsconfig::parse("{ invalid").is_none());
+    }
+
+    #[test]
+    fn test_find_nearest() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("packages/a/src")).unwrap();
+        fs::create_dir_all(root.join("packages/b/src")).unwrap();
+        fs::create_dir_all(root.join("node_modules/@tsconfig/strictest")).unwrap();
+        fs::write(
+            root.join("node_modules/@tsconfig/strictest/tsconfig.json"),
+            r#"{ "compilerOptions": { "strict": true, "noUncheckedIndexedAccess": true } }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("tsconfig.base.json"),
+            r#"{ "extends": "@tsconfig/strictest", "compilerOptions": { "target": "ES2020" } }"#,
+        )
+        .unwrap();
+        fs::write(root.join("tsconfig.json"), r#"{ "extends": "./tsconfig.base" }"#).unwrap();
+        fs::write(
+            root.join("packages/a/tsconfig.json"),
+            r#"{
+                "extends": ["../../tsconfig.base.json", "./missing.json"],
+                "compilerOptions": { "target": "ESNext", "strictNullChecks": false }
+            }"#,
+        )
+        .unwrap();
+
+        let tsconfigs = Tsconfigs::default();
+        let find = |path: &str| tsconfigs.find(&root.join(path)).unwrap();
+
+        let a = find("packages/a/src/index.ts");
+        assert_eq!(a.path, root.join("packages/a/tsconfig.json"));
+        assert_eq!(a.compiler_options.target.as_deref(), Some("ESNext"));
+        assert!(!a.compiler_options.strict_null_checks());
+        assert!(a.compiler_options.no_implicit_any());
+        assert_eq!(a.compiler_options.no_unchecked_indexed_access, Some(true));
+
+        let b = find("packages/b/src/index.ts");
+        assert_eq!(b.path, root.join("tsconfig.json"));
+        assert_eq!(b.compiler_options.target.as_deref(), Some("ES2020"));
+        assert!(b.compiler_options.strict_null_checks());
+
+        let tsconfigs = Tsconfigs::new(Some(root.join("tsconfig.base.json")));
+        let tsconfig = tsconfigs.find(&root.join("packages/a/src/index.ts")).unwrap();
+        assert_eq!(tsconfig.path, root.join("tsconfig.base.json"));
+
+        // Cycles of `extends` are stopped
+        fs::write(root.join("tsconfig.json"), r#"{ "extends": "./tsconfig.json" }"#).unwrap();
+        assert!(Tsconfig::read(&root.join("tsconfig.json")).is_some());
+    }
+}
@@ -230,3 +230,136 @@ pub fn collect_params(params: &FormalParameters) -> Vec<ParamKind> {
 
     collected
 }
+
+/// A structured view of the common tags of a JSDoc, with the tag names resolved by the
+/// `tagNamePreference` of the `jsdoc` settings, e.g. `@arg` for `@param`.
+#[derive(Debug, Default)]
+pub struct JSDocSummary<'a> {
+    pub description: String,
+    /// `@param {type} name description`
+    pub params: Vec<JSDocParam<'a>>,
+    /// `@returns {type} description`
+    pub returns: Option<JSDocReturns<'a>>,
+    /// `@deprecated reason`, with an empty reason when there is none
+    pub deprecated: Option<String>,
+    /// `@type {type}`
+    pub r#type: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct JSDocParam<'a> {
+    /// The name without the brackets and the default of optional parameters, e.g. `foo` for
+    /// `[foo=1]`
+    pub name: Option<&'a str>,
+    pub r#type: Option<&'a str>,
+    pub optional: bool,
+    pub description: String,
+    /// The span of the tag
+    pub span: Span,
+}
+
+#[derive(Debug)]
+pub struct JSDocReturns<'a> {
+    pub r#type: Option<&'a str>,
+    pub description: String,
+    /// The span of the tag
+    pub span: Span,
+}
+
+impl<'a> JSDocSummary<'a> {
+    pub fn new(jsdoc: &JSDoc<'a>, settings: &JSDocPluginSettings) -> Self {
+        let param = settings.resolve_tag_name("param");
+        let returns = settings.resolve_tag_name("returns");
+        let deprecated = settings.resolve_tag_name("deprecated");
+        let r#type = settings.resolve_tag_name("type");
+
+        let mut summary = Self { description: jsdoc.comment().parsed(), ..Self::default() };
+        for tag in jsdoc.tags() {
+            let kind = tag.kind.parsed();
+            if kind == param {
+                let (type_part, name_part, comment_part) = tag.type_name_comment();
+                summary.params.push(JSDocParam {
+                    name: name_part.map(|name| name.parsed()),
+                    r#type: type_part.map(|r#type| r#type.parsed()),
+                    optional: name_part.is_some_and(|name| name.optional),
+                    description: comment_part.parsed(),
+                    span: tag.span,
+                });
+            } else if kind == returns {
+                let (type_part, comment_part) = tag.type_comment();
+                summary.returns = Some(JSDocReturns {
+                    r#type: type_part.map(|r#type| r#type.parsed()),
+                    description: comment_part.parsed(),
+                    span: tag.span,
+                });
+            } else if kind == deprecated {
+                summary.deprecated = Some(tag.comment().parsed());
+            } else if kind == r#type {
+                summary.r#type = tag.r#type().map(|r#type| r#type.parsed());
+            }
+        }
+        summary
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::AstKind;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use crate::LintContext;
+
+    #[test]
+    fn test_jsdoc_summary() {
+        let source_text = "
+            /**
+             * Add numbers.
+             * @param {number} a The first number
+             * @param {number} [b=1]
+             * @returns {number} The sum
+             * @deprecated Use `sum` instead
+             */
+            const add = (a, b = 1) => a + b;
+            function noJSDoc() {}
+        ";
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type)
+            .with_cfg(true)
+            .with_trivias(ret.trivias)
+            .with_build_jsdoc(true)
+            .build(program)
+            .semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), Rc::new(semantic));
+
+        let arrow = ctx
+            .nodes()
+            .iter()
+            .find(|node| matches!(node.kind(), AstKind::ArrowFunctionExpression(_)))
+            .unwrap();
+        let summary = ctx.jsdoc_of(arrow).unwrap();
+        assert_eq!(summary.description, "Add numbers.");
+        assert_eq!(summary.params.len(), 2);
+        assert_eq!(summary.params[0].name, Some("a"));
+        assert_eq!(summary.params[0].r#type, Some("number"));
+        assert_eq!(summary.params[0].description, "The first number");
+        assert_eq!(summary.params[1].name, Some("b"));
+        assert!(summary.params[1].optional);
+        let returns = summary.returns.unwrap();
+        assert_eq!(returns.r#type, Some("number"));
+        assert_eq!(returns.description, "The sum");
+        assert_eq!(summary.deprecated.as_deref(), Some("Use `sum` instead"));
+        assert!(summary.r#type.is_none());
+
+        let function =
+            ctx.nodes().iter().find(|node| matches!(node.kind(), AstKind::Function(_))).unwrap();
+        assert!(ctx.jsdoc_of(function).is_none());
+    }
+}
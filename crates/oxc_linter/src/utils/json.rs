@@ -0,0 +1,48 @@
+use oxc_ast::{
+    ast::{Expression, ObjectExpression, ObjectProperty, ObjectPropertyKind, Statement},
+    AstKind,
+};
+
+use crate::{AstNode, LintContext};
+
+/// The root value of the JSON document linted by `ctx`, see [`crate::json`].
+pub fn get_json_root<'a>(ctx: &LintContext<'a>) -> Option<&'a Expression<'a>> {
+    let AstKind::Program(program) = ctx.nodes().root_node().map(AstNode::kind)? else {
+        return None;
+    };
+    match program.body.first()? {
+        Statement::ExpressionStatement(stmt) => Some(&stmt.expression),
+        _ => None,
+    }
+}
+
+/// The root object of the `package.json` file linted by `ctx`, `None` for other files.
+pub fn get_package_json_root<'a>(ctx: &LintContext<'a>) -> Option<&'a ObjectExpression<'a>> {
+    if ctx.file_path().file_name().map_or(true, |name| name != "package.json") {
+        return None;
+    }
+    match get_json_root(ctx)? {
+        Expression::ObjectExpression(object) => Some(object),
+        _ => None,
+    }
+}
+
+/// The properties of a JSON object with their keys.
+pub fn json_properties<'a, 'b>(
+    object: &'b ObjectExpression<'a>,
+) -> impl Iterator<Item = (String, &'b ObjectProperty<'a>)> + 'b {
+    object.properties.iter().filter_map(|property| match property {
+        ObjectPropertyKind::ObjectProperty(property) => {
+            Some((property.key.static_name()?.to_string(), &**property))
+        }
+        ObjectPropertyKind::SpreadProperty(_) => None,
+    })
+}
+
+/// The value of the property `key` of a JSON object.
+pub fn get_json_property<'a, 'b>(
+    object: &'b ObjectExpression<'a>,
+    key: &str,
+) -> Option<&'b Expression<'a>> {
+    json_properties(object).find(|(name, _)| name == key).map(|(_, property)| &property.value)
+}
@@ -1,15 +1,18 @@
 mod jest;
 mod jsdoc;
+mod json;
 mod nextjs;
 mod react;
 mod react_perf;
+mod regexp;
 mod tree_shaking;
 mod unicorn;
 
 use crate::LintContext;
 
 pub use self::{
-    jest::*, jsdoc::*, nextjs::*, react::*, react_perf::*, tree_shaking::*, unicorn::*,
+    jest::*, jsdoc::*, json::*, nextjs::*, react::*, react_perf::*, regexp::*, tree_shaking::*,
+    unicorn::*,
 };
 
 /// Check if the Jest rule is adapted to Vitest.
@@ -20,25 +23,30 @@ pub fn is_jest_rule_adapted_to_vitest(rule_name: &str) -> bool {
 }
 
 pub fn get_test_plugin_name(ctx: &LintContext) -> &'static str {
-    if is_using_vitest(ctx) {
+    if ctx.frameworks().is_vitest() {
         "eslint-plugin-vitest"
     } else {
         "eslint-plugin-jest"
     }
 }
 
-fn is_using_vitest(ctx: &LintContext) -> bool {
-    // If import 'vitest' is found, we assume the user is using vitest.
-    if ctx
-        .semantic()
-        .module_record()
-        .import_entries
-        .iter()
-        .any(|entry| entry.module_request.name().as_str() == "vitest")
-    {
-        return true;
-    }
+/// The minimum number of single character insertions, deletions or substitutions
+/// required to convert string `a` to string `b`.
+pub fn min_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let (a, b) = if a.len() < b.len() { (b, a) } else { (a, b) };
 
-    // Or, find the eslint config file
-    ctx.rules().iter().any(|rule| rule.plugin_name == "vitest")
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    for (i, s1) in a.iter().enumerate() {
+        let mut current_row = vec![i + 1];
+        for (j, s2) in b.iter().enumerate() {
+            let insertions = previous_row[j + 1] + 1;
+            let deletions = current_row[j] + 1;
+            let substitutions = previous_row[j] + usize::from(s1 != s2);
+            current_row.push(insertions.min(deletions).min(substitutions));
+        }
+        previous_row = current_row;
+    }
+    previous_row[b.len()]
 }
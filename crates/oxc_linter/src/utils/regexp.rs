@@ -0,0 +1,76 @@
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{Argument, Expression, RegExpFlags, RegExpLiteral},
+    AstKind,
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_js_regex::{
+    ast::{Flags, Pattern},
+    parser::{FlagsParser, ParserOptions, PatternParser},
+};
+use oxc_span::Span;
+
+use crate::LintContext;
+
+/// Parse the regular expression of `kind`, which is a literal like `/a+/u`, or
+/// `new RegExp(pattern, flags)` or `RegExp(pattern, flags)` with string literals. Returns `None`
+/// for the other nodes.
+///
+/// The spans of the nodes are in the source text, except for the patterns of `RegExp` with
+/// escapes, e.g. `"\\d"`, whose spans start at the start of the string.
+pub fn parse_regexp<'a, 'b>(
+    allocator: &'b Allocator,
+    kind: AstKind<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<Result<(Pattern<'b>, Flags), OxcDiagnostic>>
+where
+    'a: 'b,
+{
+    let (callee, arguments) = match kind {
+        AstKind::RegExpLiteral(literal) => return Some(parse_regexp_literal(allocator, literal)),
+        AstKind::NewExpression(new_expr) => (&new_expr.callee, &new_expr.arguments),
+        AstKind::CallExpression(call_expr) => (&call_expr.callee, &call_expr.arguments),
+        _ => return None,
+    };
+    let Expression::Identifier(ident) = callee.without_parenthesized() else { return None };
+    if ident.name != "RegExp" || !ctx.semantic().is_reference_to_global_variable(ident) {
+        return None;
+    }
+    let Some(Argument::StringLiteral(pattern)) = arguments.first() else { return None };
+    let flags = match arguments.get(1) {
+        None => FlagsParser::new("").parse(),
+        Some(Argument::StringLiteral(flags)) => {
+            FlagsParser::new(flags.value.as_str()).with_span_offset(flags.span.start + 1).parse()
+        }
+        // The flags are unknown
+        Some(_) => return None,
+    };
+    let flags = match flags {
+        Ok(flags) => flags,
+        Err(error) => return Some(Err(error)),
+    };
+    let options = ParserOptions::from_flags(flags).with_span_offset(pattern.span.start + 1);
+    let pattern = PatternParser::new(allocator, pattern.value.as_str(), options).parse();
+    Some(pattern.map(|pattern| (pattern, flags)))
+}
+
+fn parse_regexp_literal<'a>(
+    allocator: &'a Allocator,
+    literal: &RegExpLiteral<'a>,
+) -> Result<(Pattern<'a>, Flags), OxcDiagnostic> {
+    let regex = &literal.regex;
+    let flags = Flags {
+        span: Span::new(literal.span.end - regex.flags.bits().count_ones(), literal.span.end),
+        dot_all: regex.flags.contains(RegExpFlags::S),
+        global: regex.flags.contains(RegExpFlags::G),
+        has_indices: regex.flags.contains(RegExpFlags::D),
+        ignore_case: regex.flags.contains(RegExpFlags::I),
+        multiline: regex.flags.contains(RegExpFlags::M),
+        sticky: regex.flags.contains(RegExpFlags::Y),
+        unicode: regex.flags.contains(RegExpFlags::U),
+        unicode_sets: regex.flags.contains(RegExpFlags::V),
+    };
+    let options = ParserOptions::from_flags(flags).with_span_offset(literal.span.start + 1);
+    let pattern = PatternParser::new(allocator, regex.pattern.as_str(), options).parse()?;
+    Ok((pattern, flags))
+}
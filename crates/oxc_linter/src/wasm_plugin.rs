@@ -0,0 +1,353 @@
+//! Custom rules compiled to WebAssembly, loaded with `oxlint --plugin ./my_rules.wasm`.
+//!
+//! A plugin is a WebAssembly module without imports, so its rules cannot access the file system,
+//! the network or the environment. It exchanges UTF-8 encoded JSON with the linter through its
+//! linear memory, and exports:
+//!
+//! * `memory`, its linear memory.
+//! * `oxlint_alloc(size: i32) -> i32`, which allocates `size` bytes for the input of
+//!   `oxlint_lint` and returns their address.
+//! * `oxlint_plugin() -> i64`, which returns the name and the rules of the plugin as
+//!   `{ "name": "my-company", "rules": [{ "name": "no-foo" }] }`.
+//! * `oxlint_lint(ptr: i32, len: i32) -> i64`, which lints the file described by the `len` bytes
+//!   at `ptr`, `{ "path": "src/index.js", "sourceText": "…", "rules": [{ "name": "no-foo",
+//!   "options": [] }], "program": {…} }`, with the rules of the plugin enabled for the file and
+//!   the ESTree AST of the file, like the JavaScript plugins of `napi/linter`. It returns the
+//!   problems as `[{ "rule": "no-foo", "message": "…", "help": "…", "start": 0, "end": 3,
+//!   "fix": { "content": "bar", "start": 0, "end": 3 } }]`, where `help` and `fix` are optional.
+//!
+//! The functions returning JSON return its address in the high 32 bits and its length in the low
+//! 32 bits. Positions are byte offsets into the UTF-8 encoded source text. Each file is linted by
+//! a new instance of the module, so plugins don't need to free their memory.
+//!
+//! The rules of a plugin are configured as `{plugin}/{rule}` in the `rules` of the configuration
+//! files, and run as warnings when they are not configured.
+
+use std::{
+    fs,
+    hash::{Hash, Hasher},
+    path::Path,
+    sync::Mutex,
+};
+
+use once_cell::sync::Lazy;
+use oxc_ast::AstKind;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+use rustc_hash::{FxHashSet, FxHasher};
+use serde::Deserialize;
+use serde_json::Value;
+use wasmi::{
+    Config, Engine, Instance, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder,
+};
+
+use crate::{config::OxlintConfig, AllowWarnDeny, LintContext, Message};
+
+/// The number of instructions a plugin may execute to lint a file, a few seconds of work, which
+/// stops the plugins stuck in a loop.
+const FUEL_PER_FILE: u64 = 1 << 30;
+
+/// The maximum size of the linear memory of a plugin.
+const MEMORY_LIMIT: usize = 256 << 20;
+
+/// The names of the loaded plugins and of their rules. Diagnostics refer to them as `&'static str`,
+/// so they are leaked, but only once, as the language server reloads the plugins with the
+/// configuration.
+static NAMES: Lazy<Mutex<FxHashSet<&'static str>>> = Lazy::new(Mutex::default);
+
+fn intern(name: String) -> &'static str {
+    let mut names = NAMES.lock().unwrap();
+    if let Some(&name) = names.get(name.as_str()) {
+        return name;
+    }
+    let name = &*name.leak();
+    names.insert(name);
+    name
+}
+
+/// A plugin loaded with [`WasmPlugin::load`], see the [module documentation](self).
+pub struct WasmPlugin {
+    /// Interned, see [`NAMES`]
+    name: &'static str,
+    rules: Vec<&'static str>,
+    engine: Engine,
+    module: Module,
+    /// Hash of the WebAssembly module, part of the key of the files in the lint cache
+    hash: u64,
+}
+
+#[derive(Deserialize)]
+struct PluginMeta {
+    name: String,
+    rules: Vec<RuleMeta>,
+}
+
+#[derive(Deserialize)]
+struct RuleMeta {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct PluginDiagnostic {
+    rule: String,
+    message: String,
+    help: Option<String>,
+    start: u32,
+    end: u32,
+    fix: Option<PluginFix>,
+}
+
+#[derive(Deserialize)]
+struct PluginFix {
+    content: String,
+    start: u32,
+    end: u32,
+}
+
+impl WasmPlugin {
+    /// Load the plugin compiled to WebAssembly at `path`.
+    ///
+    /// # Errors
+    ///
+    /// If the file cannot be read, is not a WebAssembly module, or does not implement the
+    /// interface of plugins.
+    pub fn load(path: &Path) -> Result<Self, OxcDiagnostic> {
+        let failed = |error: &dyn std::fmt::Display| {
+            OxcDiagnostic::error(format!("Failed to load the plugin {path:?}: {error}"))
+        };
+        let bytes = fs::read(path).map_err(|error| failed(&error))?;
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, bytes.as_slice()).map_err(|error| failed(&error))?;
+        let mut hasher = FxHasher::default();
+        bytes.hash(&mut hasher);
+
+        let mut instance = PluginInstance::new(&engine, &module).map_err(|error| failed(&error))?;
+        let meta = instance.plugin().map_err(|error| failed(&error))?;
+        let meta = serde_json::from_slice::<PluginMeta>(&meta).map_err(|error| failed(&error))?;
+        if meta.name.is_empty() || meta.name.contains('/') {
+            return Err(failed(&format!("invalid plugin name {:?}", meta.name)));
+        }
+        Ok(Self {
+            name: intern(meta.name),
+            rules: meta.rules.into_iter().map(|rule| intern(rule.name)).collect(),
+            engine,
+            module,
+            hash: hasher.finish(),
+        })
+    }
+
+    /// The name of the plugin, which prefixes its rules in the configuration files.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+
+    /// The names of the rules of the plugin.
+    pub fn rules(&self) -> &[&'static str] {
+        &self.rules
+    }
+
+    pub(crate) fn hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Whether `key` is a rule of the plugin, e.g. `my-company/no-foo`.
+    pub(crate) fn has_rule(&self, key: &str) -> bool {
+        key.split_once('/').is_some_and(|(plugin_name, rule_name)| {
+            plugin_name == self.name && self.rules.contains(&rule_name)
+        })
+    }
+
+    /// The rules of the plugin which run on the file, with their severity and options in
+    /// `config`.
+    fn enabled_rules<'c>(
+        &self,
+        config: &'c OxlintConfig,
+    ) -> Vec<(&'static str, AllowWarnDeny, Option<&'c Value>)> {
+        self.rules
+            .iter()
+            .filter_map(|&rule_name| {
+                let rule_config = config.rules.iter().find(|rule_config| {
+                    rule_config.plugin_name == self.name && rule_config.rule_name == rule_name
+                });
+                let severity = rule_config.map_or(AllowWarnDeny::Warn, |config| config.severity);
+                let options = rule_config.and_then(|config| config.config.as_ref());
+                severity.is_warn_deny().then_some((rule_name, severity, options))
+            })
+            .collect()
+    }
+
+    /// Run the rules of the plugin enabled by `config` on the file of `ctx`.
+    pub(crate) fn run<'a>(&self, ctx: &LintContext<'a>, config: &OxlintConfig) -> Vec<Message<'a>> {
+        let rules = self.enabled_rules(config);
+        if rules.is_empty() {
+            return vec![];
+        }
+        let Some(AstKind::Program(program)) = ctx.nodes().root_node().map(|node| node.kind())
+        else {
+            return vec![];
+        };
+        let program = match serde_json::to_string(program) {
+            Ok(program) => program,
+            Err(error) => return vec![self.failure(&error.to_string())],
+        };
+        let enabled_rules = rules
+            .iter()
+            .map(|(name, _, options)| {
+                serde_json::json!({ "name": name, "options": options.cloned().unwrap_or_default() })
+            })
+            .collect::<Vec<_>>();
+        // The program is serialized last, so plugins can read the other fields first
+        let input = format!(
+            r#"{{"path":{},"sourceText":{},"rules":{},"program":{}}}"#,
+            Value::from(ctx.file_path().to_string_lossy()),
+            Value::from(ctx.source_text()),
+            Value::from(enabled_rules),
+            program,
+        );
+
+        let diagnostics = PluginInstance::new(&self.engine, &self.module)
+            .and_then(|mut instance| instance.lint(input.as_bytes()))
+            .and_then(|output| {
+                serde_json::from_slice::<Vec<PluginDiagnostic>>(&output)
+                    .map_err(|error| error.to_string())
+            });
+        let diagnostics = match diagnostics {
+            Ok(diagnostics) => diagnostics,
+            Err(error) => return vec![self.failure(&error)],
+        };
+
+        let source_text = ctx.source_text();
+        let mut messages = vec![];
+        for diagnostic in diagnostics {
+            let Some(&(rule_name, severity, _)) =
+                rules.iter().find(|(name, _, _)| *name == diagnostic.rule)
+            else {
+                return vec![self.failure(&format!("unknown rule {:?}", diagnostic.rule))];
+            };
+            let mut spans = std::iter::once((diagnostic.start, diagnostic.end))
+                .chain(diagnostic.fix.as_ref().map(|fix| (fix.start, fix.end)));
+            if !spans.all(|(start, end)| is_valid_span(source_text, start, end)) {
+                return vec![self.failure(&format!(
+                    "the problem of the rule {rule_name:?} is out of the source text or splits a \
+                     character"
+                ))];
+            }
+
+            let rule_ctx = ctx
+                .clone()
+                .with_rule_name(rule_name)
+                .with_plugin_name(self.name)
+                .with_severity(severity);
+            let mut error =
+                OxcDiagnostic::warn(format!("{}({rule_name}): {}", self.name, diagnostic.message))
+                    .with_label(Span::new(diagnostic.start, diagnostic.end));
+            if let Some(help) = diagnostic.help {
+                error = error.with_help(help);
+            }
+            match diagnostic.fix {
+                Some(fix) => rule_ctx.diagnostic_with_fix(error, |fixer| {
+                    fixer.replace(Span::new(fix.start, fix.end), fix.content)
+                }),
+                None => rule_ctx.diagnostic(error),
+            }
+            messages.extend(rule_ctx.into_message());
+        }
+        messages
+    }
+
+    fn failure<'a>(&self, error: &str) -> Message<'a> {
+        let error = OxcDiagnostic::error(format!("The plugin `{}` failed: {error}", self.name));
+        Message::new(error, None)
+    }
+}
+
+/// Whether the span from `start` to `end` is in `source_text`, and doesn't split a character, which
+/// the fixer and the renderer of diagnostics would panic on.
+fn is_valid_span(source_text: &str, start: u32, end: u32) -> bool {
+    start <= end
+        && source_text.is_char_boundary(start as usize)
+        && source_text.is_char_boundary(end as usize)
+}
+
+/// An instance of the module of a plugin, with its own memory.
+struct PluginInstance {
+    store: Store<StoreLimits>,
+    instance: Instance,
+    memory: Memory,
+}
+
+impl PluginInstance {
+    fn new(engine: &Engine, module: &Module) -> Result<Self, String> {
+        let limits = StoreLimitsBuilder::new().memory_size(MEMORY_LIMIT).build();
+        let mut store = Store::new(engine, limits);
+        store.limiter(|limits| limits);
+        store.add_fuel(FUEL_PER_FILE).map_err(|error| error.to_string())?;
+        // Plugins are not given any imports
+        let instance = Linker::new(engine)
+            .instantiate(&mut store, module)
+            .and_then(|instance| instance.start(&mut store))
+            .map_err(|error| error.to_string())?;
+        let memory = instance.get_memory(&store, "memory").ok_or("the memory is not exported")?;
+        Ok(Self { store, instance, memory })
+    }
+
+    fn plugin(&mut self) -> Result<Vec<u8>, String> {
+        let plugin = self
+            .instance
+            .get_typed_func::<(), i64>(&self.store, "oxlint_plugin")
+            .map_err(|error| error.to_string())?;
+        let output = plugin.call(&mut self.store, ()).map_err(|error| error.to_string())?;
+        self.read(output)
+    }
+
+    fn lint(&mut self, input: &[u8]) -> Result<Vec<u8>, String> {
+        let alloc = self
+            .instance
+            .get_typed_func::<i32, i32>(&self.store, "oxlint_alloc")
+            .map_err(|error| error.to_string())?;
+        let lint = self
+            .instance
+            .get_typed_func::<(i32, i32), i64>(&self.store, "oxlint_lint")
+            .map_err(|error| error.to_string())?;
+        let len = i32::try_from(input.len()).map_err(|_| "the file is too large")?;
+        let ptr = alloc.call(&mut self.store, len).map_err(|error| error.to_string())?;
+        #[allow(clippy::cast_sign_loss)]
+        self.memory
+            .write(&mut self.store, ptr as u32 as usize, input)
+            .map_err(|error| error.to_string())?;
+        let output = lint.call(&mut self.store, (ptr, len)).map_err(|error| error.to_string())?;
+        self.read(output)
+    }
+
+    /// The bytes at the address in the high 32 bits of `packed`, of the length in its low 32 bits.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn read(&self, packed: i64) -> Result<Vec<u8>, String> {
+        let packed = packed as u64;
+        let (ptr, len) = ((packed >> 32) as usize, (packed as u32) as usize);
+        // Checked before copying, the length returned by the plugin may be up to 4 GiB
+        let bytes = self
+            .memory
+            .data(&self.store)
+            .get(ptr..ptr.saturating_add(len))
+            .ok_or("the output is out of the memory of the plugin")?;
+        Ok(bytes.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_valid_span;
+
+    #[test]
+    fn test_is_valid_span() {
+        let source_text = "\"é\";";
+        assert!(is_valid_span(source_text, 1, 3));
+        assert!(is_valid_span(source_text, 5, 5));
+        assert!(!is_valid_span(source_text, 1, 2));
+        assert!(!is_valid_span(source_text, 2, 3));
+        assert!(!is_valid_span(source_text, 3, 1));
+        assert!(!is_valid_span(source_text, 0, 6));
+    }
+}
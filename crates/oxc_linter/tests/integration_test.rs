@@ -1,4 +1,4 @@
-use oxc_linter::{RuleCategory, RuleMeta};
+use oxc_linter::{RuleCategory, RuleFixMeta, RuleMeta};
 use oxc_macros::declare_oxc_lint_test;
 
 struct TestRule;
@@ -21,6 +21,40 @@ declare_oxc_lint_test!(
     correctness
 );
 
+struct TestRule3;
+
+declare_oxc_lint_test!(
+    /// Dummy description3
+    TestRule3,
+    correctness,
+    dangerous_fix,
+    config = TestRule3Config
+);
+
+struct TestRule4;
+
+declare_oxc_lint_test!(
+    /// Dummy description4
+    TestRule4,
+    nursery,
+    fix_dangerous
+);
+
+struct TestRule5;
+
+declare_oxc_lint_test!(
+    /// Dummy description5
+    TestRule5,
+    style,
+    none
+);
+
+#[derive(schemars::JsonSchema)]
+#[allow(dead_code)]
+struct TestRule3Config {
+    dummy_option: bool,
+}
+
 #[test]
 fn test_declare_oxc_lint() {
     // Simple, multiline documentation
@@ -31,4 +65,16 @@ fn test_declare_oxc_lint() {
 
     // Auto-generated kebab-case name
     assert_eq!(TestRule::NAME, "test-rule");
+
+    // Fixes are optional
+    assert_eq!(TestRule::FIX, RuleFixMeta::None);
+    assert_eq!(TestRule3::FIX, RuleFixMeta::DangerousFix);
+    assert_eq!(TestRule4::FIX, RuleFixMeta::DangerousFix);
+    assert_eq!(TestRule5::FIX, RuleFixMeta::None);
+    assert_eq!(TestRule4::CATEGORY, RuleCategory::Nursery);
+
+    // Schema of the options
+    let mut gen = schemars::gen::SchemaGenerator::default();
+    assert!(TestRule::config_schema(&mut gen).is_none());
+    assert!(TestRule3::config_schema(&mut gen).is_some());
 }
@@ -53,7 +53,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
     let expanded = quote! {
         #(pub use self::#use_stmts::#struct_names;)*
 
-        use crate::{context::LintContext, rule::{Rule, RuleCategory, RuleMeta}, AstNode};
+        use crate::{context::LintContext, project::ProjectContext, rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta}, AstNode};
         use oxc_semantic::SymbolId;
 
         #[derive(Debug, Clone)]
@@ -81,13 +81,39 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn fix(&self) -> RuleFixMeta {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::FIX),*
+                }
+            }
+
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
                     #(Self::#struct_names(_) => #struct_names::documentation()),*
                 }
             }
 
-            pub fn plugin_name(&self) -> &str {
+            /// Whether the rule takes options. Rules keep their options in their struct, the rules
+            /// without fields take none.
+            pub fn has_options(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(_) => std::mem::size_of::<#struct_names>() != 0),*
+                }
+            }
+
+            pub fn config_schema(&self, gen: &mut schemars::gen::SchemaGenerator) -> Option<schemars::schema::Schema> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::config_schema(gen)),*
+                }
+            }
+
+            pub fn node_types(&self) -> Option<&'static [oxc_ast::AstType]> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::NODE_TYPES),*
+                }
+            }
+
+            pub fn plugin_name(&self) -> &'static str {
                 match self {
                     #(Self::#struct_names(_) => #plugin_names),*
                 }
@@ -101,6 +127,12 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            pub fn validate_json(&self, value: &serde_json::Value) -> Result<(), oxc_diagnostics::OxcDiagnostic> {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::validate_configuration(value)),*
+                }
+            }
+
             pub(super) fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
                 match self {
                     #(Self::#struct_names(rule) => rule.run(node, ctx)),*
@@ -118,6 +150,18 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                     #(Self::#struct_names(rule) => rule.run_once(ctx)),*
                 }
             }
+
+            pub(super) fn run_on_project(&self, ctx: &ProjectContext) {
+                match self {
+                    #(Self::#struct_names(rule) => rule.run_on_project(ctx)),*
+                }
+            }
+
+            pub(super) fn runs_on_project(&self) -> bool {
+                match self {
+                    #(Self::#struct_names(rule) => rule.runs_on_project()),*
+                }
+            }
         }
 
         impl std::hash::Hash for RuleEnum {
@@ -1,14 +1,17 @@
 use convert_case::{Case, Casing};
 use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token,
+    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token, Type,
 };
 
 pub struct LintRuleMeta {
     name: Ident,
-    category: Ident,
+    category: TokenStream2,
+    fix: TokenStream2,
+    config: Option<Type>,
     documentation: String,
     pub used_in_test: bool,
 }
@@ -30,36 +33,59 @@ impl Parse for LintRuleMeta {
 
         let struct_name = input.parse()?;
         input.parse::<Token!(,)>()?;
-        let category = input.parse()?;
+        let category = parse_category(&input.parse()?)?;
+        let mut fix = quote! { RuleFixMeta::None };
+        let mut config = None;
+        while input.parse::<Option<Token!(,)>>()?.is_some() {
+            if input.peek(Ident) && input.peek2(Token!(=)) {
+                let key = input.parse::<Ident>()?;
+                if key != "config" {
+                    return Err(Error::new_spanned(key, "unexpected key, expected `config`"));
+                }
+                input.parse::<Token!(=)>()?;
+                config = Some(input.parse()?);
+            } else if input.peek(Ident) {
+                fix = parse_fix(&input.parse()?)?;
+            } else {
+                break;
+            }
+        }
 
         // Ignore the rest
         input.parse::<proc_macro2::TokenStream>()?;
 
-        Ok(Self { name: struct_name, category, documentation, used_in_test: false })
+        Ok(Self { name: struct_name, category, fix, config, documentation, used_in_test: false })
     }
 }
 
 pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
-    let LintRuleMeta { name, category, documentation, used_in_test } = metadata;
+    let LintRuleMeta { name, category, fix, config, documentation, used_in_test } = metadata;
+    // Reported next to the implementation, so uses of the rule don't fail to compile as well
+    let documentation_error = (!used_in_test)
+        .then(|| check_documentation(&documentation).err())
+        .flatten()
+        .map(|message| Error::new_spanned(&name, message).to_compile_error());
     let canonical_name = name.to_string().to_case(Case::Kebab);
-    let category = match category.to_string().as_str() {
-        "correctness" => quote! { RuleCategory::Correctness },
-        "suspicious" => quote! { RuleCategory::Suspicious },
-        "pedantic" => quote! { RuleCategory::Pedantic },
-        "perf" => quote! { RuleCategory::Perf },
-        "style" => quote! { RuleCategory::Style },
-        "restriction" => quote! { RuleCategory::Restriction },
-        "nursery" => quote! { RuleCategory::Nursery },
-        _ => panic!("invalid rule category"),
-    };
+
+    let config_schema = config.map(|config| {
+        quote! {
+            fn config_schema(
+                gen: &mut schemars::gen::SchemaGenerator,
+            ) -> Option<schemars::schema::Schema> {
+                Some(gen.subschema_for::<#config>())
+            }
+        }
+    });
 
     let import_statement = if used_in_test {
         None
     } else {
-        Some(quote! { use crate::rule::{RuleCategory, RuleMeta}; })
+        Some(quote! { use crate::rule::{RuleCategory, RuleFixMeta, RuleMeta}; })
     };
 
     let output = quote! {
+        #documentation_error
+
         #import_statement
 
         impl RuleMeta for #name {
@@ -67,15 +93,105 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             const CATEGORY: RuleCategory = #category;
 
+            const FIX: RuleFixMeta = #fix;
+
             fn documentation() -> Option<&'static str> {
                 Some(#documentation)
             }
+
+            #config_schema
         }
     };
 
     TokenStream::from(output)
 }
 
+const CATEGORIES: &str =
+    "`correctness`, `suspicious`, `pedantic`, `perf`, `style`, `restriction` or `nursery`";
+
+fn parse_category(category: &Ident) -> Result<TokenStream2> {
+    Ok(match category.to_string().as_str() {
+        "correctness" => quote! { RuleCategory::Correctness },
+        "suspicious" => quote! { RuleCategory::Suspicious },
+        "pedantic" => quote! { RuleCategory::Pedantic },
+        "perf" => quote! { RuleCategory::Perf },
+        "style" => quote! { RuleCategory::Style },
+        "restriction" => quote! { RuleCategory::Restriction },
+        "nursery" => quote! { RuleCategory::Nursery },
+        // The placeholder of `just new-rule`
+        "TODO" => {
+            return Err(Error::new_spanned(
+                category,
+                format!(
+                    "replace the `TODO` category of the rule with {CATEGORIES}, see <https://oxc.rs/docs/contribute/linter.html#rule-category>"
+                ),
+            ))
+        }
+        _ => {
+            return Err(Error::new_spanned(
+                category,
+                format!("invalid rule category, expected {CATEGORIES}"),
+            ))
+        }
+    })
+}
+
+fn parse_fix(fix: &Ident) -> Result<TokenStream2> {
+    Ok(match fix.to_string().as_str() {
+        "none" => quote! { RuleFixMeta::None },
+        "fix" => quote! { RuleFixMeta::Fix },
+        "fix_dangerous" | "dangerous_fix" => quote! { RuleFixMeta::DangerousFix },
+        "suggestion" => quote! { RuleFixMeta::Suggestion },
+        _ => {
+            return Err(Error::new_spanned(
+                fix,
+                "invalid rule fix kind, expected `fix`, `fix_dangerous`, `suggestion` or `none`",
+            ))
+        }
+    })
+}
+
+/// The sections of the documentation which are rendered by `oxlint explain` and on the website.
+const DOCUMENTATION_SECTIONS: [&str; 4] =
+    ["What it does", "Why is this bad?", "Example", "Examples"];
+
+/// Check that the documentation has a `### What it does` section, and that none of the
+/// [`DOCUMENTATION_SECTIONS`] is left empty, e.g. with an empty code block or a `TODO`.
+fn check_documentation(documentation: &str) -> std::result::Result<(), String> {
+    let mut sections = Vec::<(&str, bool)>::new();
+    let mut in_code_block = false;
+    for line in documentation.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if !in_code_block {
+            if let Some(title) = line.strip_prefix("### ") {
+                sections.push((title.trim(), false));
+                continue;
+            }
+        }
+        if let Some((_, non_empty)) = sections.last_mut() {
+            let line = line.trim();
+            *non_empty |= !line.is_empty() && line != "TODO";
+        }
+    }
+
+    if in_code_block {
+        return Err("unclosed code block in the rule documentation".to_string());
+    }
+    if !sections.iter().any(|(title, _)| *title == "What it does") {
+        return Err("missing `### What it does` section in the rule documentation".to_string());
+    }
+    match sections
+        .iter()
+        .find(|(title, non_empty)| !non_empty && DOCUMENTATION_SECTIONS.contains(title))
+    {
+        Some((title, _)) => Err(format!("empty `### {title}` section in the rule documentation")),
+        None => Ok(()),
+    }
+}
+
 fn parse_attr<'a, const LEN: usize>(
     path: [&'static str; LEN],
     attr: &'a Attribute,
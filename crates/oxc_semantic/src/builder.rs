@@ -1,6 +1,6 @@
 //! Semantic Builder
 
-use std::{cell::RefCell, path::PathBuf, sync::Arc};
+use std::{cell::RefCell, mem, path::PathBuf, sync::Arc};
 
 #[allow(clippy::wildcard_imports)]
 use oxc_ast::{ast::*, AstKind, Trivias, Visit};
@@ -18,6 +18,7 @@ use crate::{
     class::ClassTableBuilder,
     diagnostics::redeclaration,
     jsdoc::JSDocBuilder,
+    jsx_pragma::JsxPragmas,
     label::LabelBuilder,
     module_record::ModuleRecordBuilder,
     node::{AstNode, AstNodeId, AstNodes, NodeFlags},
@@ -87,6 +88,15 @@ pub struct SemanticBuilderReturn<'a> {
     pub errors: Vec<OxcDiagnostic>,
 }
 
+/// The buffers only used while the semantic model is built, which can be reused by the next
+/// build with [`SemanticBuilder::build_with_scratch`] to save their allocations.
+#[derive(Debug, Default)]
+pub struct SemanticScratch {
+    function_stack: Vec<AstNodeId>,
+    namespace_stack: Vec<SymbolId>,
+    ast_nodes_records: Vec<Vec<AstNodeId>>,
+}
+
 impl<'a> SemanticBuilder<'a> {
     pub fn new(source_text: &'a str, source_type: SourceType) -> Self {
         let scope = ScopeTree::default();
@@ -162,7 +172,21 @@ impl<'a> SemanticBuilder<'a> {
         self
     }
 
-    pub fn build(mut self, program: &Program<'a>) -> SemanticBuilderReturn<'a> {
+    pub fn build(self, program: &Program<'a>) -> SemanticBuilderReturn<'a> {
+        self.build_with_scratch(program, &mut SemanticScratch::default())
+    }
+
+    /// Build the semantic model with the buffers of `scratch`, which are given back to it
+    /// afterwards.
+    pub fn build_with_scratch(
+        mut self,
+        program: &Program<'a>,
+        scratch: &mut SemanticScratch,
+    ) -> SemanticBuilderReturn<'a> {
+        self.function_stack = mem::take(&mut scratch.function_stack);
+        self.namespace_stack = mem::take(&mut scratch.namespace_stack);
+        self.ast_nodes_records = mem::take(&mut scratch.ast_nodes_records);
+
         if self.source_type.is_typescript_definition() {
             let scope_id = self.scope.add_scope(None, ScopeFlags::Top);
             program.scope_id.set(Some(scope_id));
@@ -177,6 +201,14 @@ impl<'a> SemanticBuilder<'a> {
 
         let jsdoc = if self.build_jsdoc { self.jsdoc.build() } else { JSDocFinder::default() };
 
+        scratch.function_stack = self.function_stack;
+        scratch.function_stack.clear();
+        scratch.namespace_stack = self.namespace_stack;
+        scratch.namespace_stack.clear();
+        scratch.ast_nodes_records = self.ast_nodes_records;
+        scratch.ast_nodes_records.clear();
+
+        let jsx_pragmas = JsxPragmas::parse(self.source_text, &self.trivias);
         let semantic = Semantic {
             source_text: self.source_text,
             source_type: self.source_type,
@@ -187,6 +219,7 @@ impl<'a> SemanticBuilder<'a> {
             classes: self.class_table_builder.build(),
             module_record: Arc::clone(&self.module_record),
             jsdoc,
+            jsx_pragmas,
             unused_labels: self.label_builder.unused_node_ids,
             cfg: self.cfg.map(ControlFlowGraphBuilder::build),
         };
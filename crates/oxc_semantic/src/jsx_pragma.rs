@@ -0,0 +1,72 @@
+use oxc_ast::{CommentKind, Trivias};
+
+/// The `@jsx` and `@jsxFrag` comments of a file, setting the functions JSX is compiled to by the
+/// classic runtime, e.g. `/** @jsx h */` and `/** @jsxFrag Fragment */` for Preact.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct JsxPragmas<'a> {
+    /// The factory of the elements, e.g. `h` or `React.createElement`
+    pub factory: Option<&'a str>,
+    /// The component of the fragments, e.g. `Fragment` or `React.Fragment`
+    pub fragment: Option<&'a str>,
+}
+
+impl<'a> JsxPragmas<'a> {
+    /// The pragmas of the block comments of the file, the first of each one when repeated.
+    pub fn parse(source_text: &'a str, trivias: &Trivias) -> Self {
+        let mut pragmas = Self::default();
+        for (kind, span) in trivias.comments() {
+            if kind != CommentKind::MultiLine {
+                continue;
+            }
+            let comment = span.source_text(source_text);
+            if !comment.contains("@jsx") {
+                continue;
+            }
+            let mut words = comment.split_whitespace().map(|word| word.trim_start_matches('*'));
+            while let Some(word) = words.next() {
+                let pragma = match word {
+                    "@jsx" => &mut pragmas.factory,
+                    "@jsxFrag" => &mut pragmas.fragment,
+                    _ => continue,
+                };
+                if pragma.is_none() {
+                    *pragma = words.next().filter(|name| !name.is_empty());
+                }
+            }
+        }
+        pragmas
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::JsxPragmas;
+
+    fn parse(source_text: &str) -> JsxPragmas<'_> {
+        let allocator = Allocator::default();
+        let ret =
+            Parser::new(&allocator, source_text, SourceType::default().with_jsx(true)).parse();
+        JsxPragmas::parse(source_text, &ret.trivias)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("<a />"), JsxPragmas::default());
+        assert_eq!(
+            parse("/** @jsx h */\n/** @jsxFrag Fragment */\n<a />"),
+            JsxPragmas { factory: Some("h"), fragment: Some("Fragment") }
+        );
+        assert_eq!(
+            parse("/**\n * @jsx preact.h\n * @jsxFrag preact.Fragment\n */"),
+            JsxPragmas { factory: Some("preact.h"), fragment: Some("preact.Fragment") }
+        );
+        assert_eq!(parse("/* @jsx h */ /* @jsx jsx */").factory, Some("h"));
+        // Only block comments are pragmas
+        assert_eq!(parse("// @jsx h").factory, None);
+        assert_eq!(parse("/* @jsxRuntime classic */").factory, None);
+    }
+}
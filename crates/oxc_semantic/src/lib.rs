@@ -4,6 +4,7 @@ mod checker;
 mod class;
 mod diagnostics;
 mod jsdoc;
+mod jsx_pragma;
 mod label;
 mod module_record;
 mod node;
@@ -15,9 +16,10 @@ pub mod dot;
 
 use std::sync::Arc;
 
-pub use builder::{SemanticBuilder, SemanticBuilderReturn};
+pub use builder::{SemanticBuilder, SemanticBuilderReturn, SemanticScratch};
 use class::ClassTable;
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
+pub use jsx_pragma::JsxPragmas;
 pub use node::{AstNode, AstNodeId, AstNodes};
 use oxc_ast::{ast::IdentifierReference, AstKind, Trivias};
 use oxc_cfg::ControlFlowGraph;
@@ -54,6 +56,8 @@ pub struct Semantic<'a> {
 
     jsdoc: JSDocFinder<'a>,
 
+    jsx_pragmas: JsxPragmas<'a>,
+
     unused_labels: FxHashSet<AstNodeId>,
 
     cfg: Option<ControlFlowGraph>,
@@ -96,6 +100,11 @@ impl<'a> Semantic<'a> {
         &self.jsdoc
     }
 
+    /// The `@jsx` and `@jsxFrag` comments of the file.
+    pub fn jsx_pragmas(&self) -> JsxPragmas<'a> {
+        self.jsx_pragmas
+    }
+
     pub fn module_record(&self) -> &ModuleRecord {
         self.module_record.as_ref()
     }
@@ -5,11 +5,14 @@ use oxc_ast::{ast::*, syntax_directed_operations::BoundNames};
 use oxc_span::{CompactStr, GetSpan, Span};
 #[allow(clippy::wildcard_imports)]
 use oxc_syntax::module_record::*;
+use rustc_hash::FxHashMap;
 
 #[derive(Default)]
 pub struct ModuleRecordBuilder {
     pub module_record: ModuleRecord,
     export_entries: Vec<ExportEntry>,
+    /// Kinds of the bindings declared at the top level, for [`ExportEntry::binding_kind`]
+    binding_kinds: FxHashMap<CompactStr, ExportBindingKind>,
 }
 
 impl ModuleRecordBuilder {
@@ -18,6 +21,10 @@ impl ModuleRecordBuilder {
     }
 
     pub fn visit(&mut self, program: &Program) {
+        for stmt in &program.body {
+            self.collect_binding_kinds(stmt);
+        }
+
         self.module_record.not_esm = true;
         // This avoids additional checks on TypeScript `TsModuleBlock` which
         // also has `ModuleDeclaration`s.
@@ -37,6 +44,79 @@ impl ModuleRecordBuilder {
         self.module_record
     }
 
+    fn collect_binding_kinds(&mut self, stmt: &Statement) {
+        let decl = match stmt {
+            Statement::ExportNamedDeclaration(decl) => decl.declaration.as_ref(),
+            Statement::ExportDefaultDeclaration(decl) => {
+                if let Some(id) = match &decl.declaration {
+                    ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+                    ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+                    _ => None,
+                } {
+                    let kind = Self::default_export_binding_kind(&decl.declaration);
+                    self.binding_kinds.insert(id.name.to_compact_str(), kind);
+                }
+                None
+            }
+            _ => stmt.as_declaration(),
+        };
+        match decl {
+            Some(Declaration::VariableDeclaration(decl)) => {
+                for declarator in &decl.declarations {
+                    let kind = Self::variable_binding_kind(decl.kind, declarator.init.as_ref());
+                    declarator.id.bound_names(&mut |ident| {
+                        self.binding_kinds.insert(ident.name.to_compact_str(), kind);
+                    });
+                }
+            }
+            Some(Declaration::FunctionDeclaration(func)) => {
+                if let Some(id) = &func.id {
+                    self.binding_kinds
+                        .insert(id.name.to_compact_str(), ExportBindingKind::Function);
+                }
+            }
+            Some(Declaration::ClassDeclaration(class)) => {
+                if let Some(id) = &class.id {
+                    self.binding_kinds.insert(id.name.to_compact_str(), ExportBindingKind::Class);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn variable_binding_kind(
+        kind: VariableDeclarationKind,
+        init: Option<&Expression>,
+    ) -> ExportBindingKind {
+        match init.map(Expression::get_inner_expression) {
+            Some(Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)) => {
+                ExportBindingKind::Function
+            }
+            Some(Expression::ClassExpression(_)) => ExportBindingKind::Class,
+            _ if kind.is_const() => ExportBindingKind::Const,
+            _ => ExportBindingKind::Variable,
+        }
+    }
+
+    fn default_export_binding_kind(decl: &ExportDefaultDeclarationKind) -> ExportBindingKind {
+        match decl {
+            ExportDefaultDeclarationKind::FunctionDeclaration(_) => ExportBindingKind::Function,
+            ExportDefaultDeclarationKind::ClassDeclaration(_) => ExportBindingKind::Class,
+            _ => match decl.as_expression().map(Expression::get_inner_expression) {
+                Some(
+                    Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_),
+                ) => ExportBindingKind::Function,
+                Some(Expression::ClassExpression(_)) => ExportBindingKind::Class,
+                Some(_) => ExportBindingKind::Expression,
+                None => ExportBindingKind::Unknown,
+            },
+        }
+    }
+
+    fn local_binding_kind(&self, name: &str) -> ExportBindingKind {
+        self.binding_kinds.get(name).copied().unwrap_or_default()
+    }
+
     fn add_module_request(&mut self, name_span: &NameSpan, is_type: bool, is_import: bool) {
         self.module_record
             .requested_modules
@@ -130,6 +210,7 @@ impl ModuleRecordBuilder {
                                     },
                                     export_name: ee.export_name.clone(),
                                     span: ee.span,
+                                    statement_span: ee.statement_span,
                                     ..ExportEntry::default()
                                 };
                                 self.append_indirect_export_entry(export_entry);
@@ -224,6 +305,7 @@ impl ModuleRecordBuilder {
                 ))
             }),
             span: decl.span,
+            statement_span: decl.span,
             ..ExportEntry::default()
         };
         self.add_export_entry(export_entry);
@@ -246,8 +328,10 @@ impl ModuleRecordBuilder {
         let exported_name_span = decl.exported.span();
         self.add_default_export(exported_name_span);
 
+        let mut binding_kind = Self::default_export_binding_kind(&decl.declaration);
         let local_name = match &decl.declaration {
             ExportDefaultDeclarationKind::Identifier(ident) => {
+                binding_kind = self.local_binding_kind(&ident.name);
                 ExportLocalName::Default(NameSpan::new(ident.name.to_compact_str(), ident.span))
             }
             ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
@@ -266,6 +350,8 @@ impl ModuleRecordBuilder {
             export_name: ExportExportName::Default(exported_name.span()),
             local_name,
             span: decl.declaration.span(),
+            statement_span: decl.span,
+            binding_kind,
             ..ExportEntry::default()
         };
         self.add_export_entry(export_entry);
@@ -293,22 +379,37 @@ impl ModuleRecordBuilder {
             );
         }
 
-        if let Some(decl) = &decl.declaration {
-            decl.bound_names(&mut |ident| {
+        if let Some(declaration) = &decl.declaration {
+            let mut add_declaration = |ident: &BindingIdentifier, span: Span| {
                 let export_name =
                     ExportExportName::Name(NameSpan::new(ident.name.to_compact_str(), ident.span));
                 let local_name =
                     ExportLocalName::Name(NameSpan::new(ident.name.to_compact_str(), ident.span));
                 let export_entry = ExportEntry {
-                    span: decl.span(),
+                    span,
+                    statement_span: decl.span,
                     module_request: module_request.clone(),
                     import_name: ExportImportName::Null,
                     export_name,
                     local_name,
+                    binding_kind: self.local_binding_kind(&ident.name),
                 };
                 self.add_export_entry(export_entry);
                 self.add_export_binding(ident.name.to_compact_str(), ident.span);
-            });
+            };
+            match declaration {
+                // Each declarator gets its own span, e.g. `a = 1` and `b = 2` in `export const a = 1, b = 2`
+                Declaration::VariableDeclaration(var_decl) => {
+                    for declarator in &var_decl.declarations {
+                        declarator.id.bound_names(&mut |ident| {
+                            add_declaration(ident, declarator.span);
+                        });
+                    }
+                }
+                _ => declaration.bound_names(&mut |ident| {
+                    add_declaration(ident, declaration.span());
+                }),
+            }
         }
 
         for specifier in &decl.specifiers {
@@ -324,20 +425,26 @@ impl ModuleRecordBuilder {
             } else {
                 ExportImportName::Null
             };
-            let local_name = if module_request.is_some() {
-                ExportLocalName::Null
+            let (local_name, binding_kind) = if module_request.is_some() {
+                (ExportLocalName::Null, ExportBindingKind::Unknown)
             } else {
-                ExportLocalName::Name(NameSpan::new(
-                    specifier.local.name().to_compact_str(),
-                    specifier.local.span(),
-                ))
+                let local_name = specifier.local.name();
+                (
+                    ExportLocalName::Name(NameSpan::new(
+                        local_name.to_compact_str(),
+                        specifier.local.span(),
+                    )),
+                    self.local_binding_kind(&local_name),
+                )
             };
             let export_entry = ExportEntry {
                 span: specifier.span,
+                statement_span: decl.span,
                 module_request: module_request.clone(),
                 import_name,
                 export_name,
                 local_name,
+                binding_kind,
             };
             self.add_export_entry(export_entry);
             self.add_export_binding(
@@ -99,6 +99,7 @@ mod module_record_tests {
             module_request: Some(NameSpan::new("mod".into(), Span::new(14, 19))),
             import_name: ExportImportName::AllButDefault,
             span: Span::new(0, 19),
+            statement_span: Span::new(0, 19),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.star_export_entries.len(), 1);
@@ -115,6 +116,7 @@ mod module_record_tests {
             import_name: ExportImportName::All,
             export_name: ExportExportName::Name(NameSpan::new("ns".into(), Span::new(12, 14))),
             span: Span::new(0, 25),
+            statement_span: Span::new(0, 25),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.indirect_export_entries.len(), 1);
@@ -130,6 +132,7 @@ mod module_record_tests {
             export_name: ExportExportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             local_name: ExportLocalName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             span: Span::new(9, 10),
+            statement_span: Span::new(0, 12),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -145,6 +148,7 @@ mod module_record_tests {
             export_name: ExportExportName::Name(NameSpan::new("v".into(), Span::new(14, 15))),
             local_name: ExportLocalName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             span: Span::new(9, 15),
+            statement_span: Span::new(0, 17),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -161,6 +165,7 @@ mod module_record_tests {
             export_name: ExportExportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             import_name: ExportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             span: Span::new(9, 10),
+            statement_span: Span::new(0, 23),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.indirect_export_entries.len(), 1);
@@ -177,6 +182,7 @@ mod module_record_tests {
             export_name: ExportExportName::Name(NameSpan::new("v".into(), Span::new(14, 15))),
             import_name: ExportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
             span: Span::new(9, 15),
+            statement_span: Span::new(0, 28),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.indirect_export_entries.len(), 1);
@@ -190,7 +196,9 @@ mod module_record_tests {
         let export_entry = ExportEntry {
             export_name: ExportExportName::Name(NameSpan::new("v".into(), Span::new(11, 12))),
             local_name: ExportLocalName::Name(NameSpan::new("v".into(), Span::new(11, 12))),
-            span: Span::new(7, 12),
+            span: Span::new(11, 12),
+            statement_span: Span::new(0, 12),
+            binding_kind: ExportBindingKind::Variable,
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -205,6 +213,8 @@ mod module_record_tests {
             export_name: ExportExportName::Default(Span::new(7, 14)),
             local_name: ExportLocalName::Name(NameSpan::new("f".into(), Span::new(24, 25))),
             span: Span::new(15, 30),
+            statement_span: Span::new(0, 30),
+            binding_kind: ExportBindingKind::Function,
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -219,6 +229,8 @@ mod module_record_tests {
             export_name: ExportExportName::Default(Span::new(7, 14)),
             local_name: ExportLocalName::Null,
             span: Span::new(15, 28),
+            statement_span: Span::new(0, 28),
+            binding_kind: ExportBindingKind::Function,
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -233,6 +245,8 @@ mod module_record_tests {
             export_name: ExportExportName::Default(Span::new(7, 14)),
             local_name: ExportLocalName::Null,
             span: Span::new(15, 17),
+            statement_span: Span::new(0, 17),
+            binding_kind: ExportBindingKind::Expression,
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -246,6 +260,7 @@ mod module_record_tests {
             export_name: ExportExportName::Name(NameSpan::new("default".into(), Span::new(9, 16))),
             local_name: ExportLocalName::Name(NameSpan::new("default".into(), Span::new(9, 16))),
             span: Span::new(9, 16),
+            statement_span: Span::new(0, 18),
             ..ExportEntry::default()
         };
         assert_eq!(module_record.local_export_entries.len(), 1);
@@ -262,9 +277,11 @@ mod module_record_tests {
             ExportEntry {
                 module_request: Some(NameSpan::new("mod".into(), Span::new(18, 23))),
                 span: Span::new(33, 34),
+                statement_span: Span::new(24, 37),
                 import_name: ExportImportName::Name(NameSpan::new("x".into(), Span::new(9, 10))),
                 export_name: ExportExportName::Name(NameSpan::new("x".into(), Span::new(33, 34))),
                 local_name: ExportLocalName::Null,
+                ..ExportEntry::default()
             }
         );
         assert_eq!(
@@ -272,10 +289,85 @@ mod module_record_tests {
             ExportEntry {
                 module_request: Some(NameSpan::new("mod".into(), Span::new(57, 62))),
                 span: Span::new(37, 63),
+                statement_span: Span::new(37, 63),
                 import_name: ExportImportName::All,
                 export_name: ExportExportName::Name(NameSpan::new("ns".into(), Span::new(49, 51))),
                 local_name: ExportLocalName::Null,
+                ..ExportEntry::default()
             }
         );
     }
+
+    #[test]
+    fn export_declarators() {
+        let module_record = build("export const a = 1, b = () => {}");
+        assert_eq!(module_record.local_export_entries.len(), 2);
+        let [a, b] =
+            [&module_record.local_export_entries[0], &module_record.local_export_entries[1]];
+        assert_eq!(a.span, Span::new(13, 18));
+        assert_eq!(b.span, Span::new(20, 32));
+        assert_eq!(a.statement_span, Span::new(0, 32));
+        assert_eq!(b.statement_span, Span::new(0, 32));
+        assert_eq!(a.binding_kind, ExportBindingKind::Const);
+        assert_eq!(b.binding_kind, ExportBindingKind::Function);
+    }
+
+    #[test]
+    fn export_binding_kinds() {
+        let module_record = build(
+            "function f() {} class C {} const c = 1; let l = 1; const g = function() {};
+            const K = class {}; const w = memo(() => {});
+            export { f, C, c, l, g, K, w, unknown };
+            export class D {}
+            export let x = (() => {});",
+        );
+        let kinds = module_record
+            .local_export_entries
+            .iter()
+            .map(|e| match &e.export_name {
+                ExportExportName::Name(name) => (name.name().to_string(), e.binding_kind),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        let expected = [
+            ("f", ExportBindingKind::Function),
+            ("C", ExportBindingKind::Class),
+            ("c", ExportBindingKind::Const),
+            ("l", ExportBindingKind::Variable),
+            ("g", ExportBindingKind::Function),
+            ("K", ExportBindingKind::Class),
+            ("w", ExportBindingKind::Const),
+            ("unknown", ExportBindingKind::Unknown),
+            ("D", ExportBindingKind::Class),
+            ("x", ExportBindingKind::Function),
+        ];
+        assert_eq!(
+            kinds,
+            expected.iter().map(|(name, kind)| ((*name).to_string(), *kind)).collect::<Vec<_>>()
+        );
+
+        let module_record = build("class C {} export default C");
+        assert_eq!(module_record.local_export_entries[0].binding_kind, ExportBindingKind::Class);
+        let module_record = build("export default () => {}");
+        assert_eq!(module_record.local_export_entries[0].binding_kind, ExportBindingKind::Function);
+    }
+
+    #[test]
+    fn star_export_names() {
+        let module_record = build("export * from 'mod'");
+        let entry = &module_record.star_export_entries[0];
+        assert_eq!(module_record.star_export_names(entry), None);
+
+        let remote = build(
+            "export const a = 1; export function b() {} export default 1; export * from 'other'",
+        );
+        remote
+            .exported_bindings_from_star_export
+            .insert(PathBuf::from("other"), vec!["c".into(), "a".into()]);
+        module_record.loaded_modules.insert("mod".into(), remote);
+        assert_eq!(
+            module_record.star_export_names(entry),
+            Some(vec!["a".into(), "b".into(), "c".into()])
+        );
+    }
 }
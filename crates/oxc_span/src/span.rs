@@ -276,6 +276,74 @@ impl Span {
         &source_text[self.start as usize..self.end as usize]
     }
 
+    /// Get a snippet of text from a source string that the [`Span`] covers, `None` when the
+    /// [`Span`] is out of bounds or does not start and end on UTF-8 character boundaries, unlike
+    /// [`Span::source_text`] which panics.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let source = "'🎉' + a";
+    /// assert_eq!(Span::new(0, 6).try_source_text(source), Some("'🎉'"));
+    /// assert_eq!(Span::new(0, 2).try_source_text(source), None);
+    /// assert_eq!(Span::new(0, 20).try_source_text(source), None);
+    /// ```
+    pub fn try_source_text<'a>(&self, source_text: &'a str) -> Option<&'a str> {
+        source_text.get(self.start as usize..self.end as usize)
+    }
+
+    /// Create a [`Span`] that has its end position moved after the comma following it, along
+    /// with the whitespace before the comma. The [`Span`] is unchanged when it is not followed
+    /// by a comma.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let source = "foo(a , b)";
+    /// let a = Span::new(4, 5);
+    /// assert_eq!(a.expand_to_include_trailing_comma(source), Span::new(4, 7));
+    /// let b = Span::new(8, 9);
+    /// assert_eq!(b.expand_to_include_trailing_comma(source), b);
+    /// ```
+    #[must_use]
+    pub fn expand_to_include_trailing_comma(self, source_text: &str) -> Self {
+        let Some(after) = source_text.get(self.end as usize..) else { return self };
+        let trimmed = after.trim_start();
+        if !trimmed.starts_with(',') {
+            return self;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        let whitespace = (after.len() - trimmed.len()) as u32;
+        self.expand_right(whitespace + 1)
+    }
+
+    /// Create a [`Span`] without the whitespace at the start and at the end of the text it
+    /// covers, e.g. the span of the token of a node whose span includes the surrounding
+    /// whitespace. The [`Span`] is unchanged when it is out of bounds or not on UTF-8 character
+    /// boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// use oxc_span::Span;
+    ///
+    /// let source = "( \u{a0}foo\n)";
+    /// assert_eq!(Span::new(1, 8).shrink_to_token(source), Span::new(4, 7));
+    /// assert_eq!(Span::new(1, 2).shrink_to_token(source), Span::new(2, 2));
+    /// ```
+    #[must_use]
+    pub fn shrink_to_token(self, source_text: &str) -> Self {
+        let Some(text) = self.try_source_text(source_text) else { return self };
+        let start = text.trim_start();
+        if start.is_empty() {
+            return Self::empty(self.end);
+        }
+        let end = start.trim_end();
+        #[allow(clippy::cast_possible_truncation)]
+        Self::new(self.end - start.len() as u32, self.end - (start.len() - end.len()) as u32)
+    }
+
     /// Create a [`LabeledSpan`] covering this [`Span`] with the given label.
     #[must_use]
     pub fn label<S: Into<String>>(self, label: S) -> LabeledSpan {
@@ -360,6 +428,22 @@ mod test {
         assert!(Span::new(0, 3) < Span::new(2, 5));
     }
 
+    #[test]
+    fn test_multi_byte_characters() {
+        let source = "`👍${a}` , '🎉'";
+        let template = Span::new(0, 10);
+        assert_eq!(template.try_source_text(source), Some("`👍${a}`"));
+        assert_eq!(template.shrink_right(1).try_source_text(source), Some("`👍${a}"));
+        assert_eq!(Span::new(1, 3).try_source_text(source), None);
+        let comma = template.expand_to_include_trailing_comma(source);
+        assert_eq!(comma.source_text(source), "`👍${a}` ,");
+        let string = Span::new(12, 19);
+        assert_eq!(string.shrink_to_token(source), Span::new(13, 19));
+        assert_eq!(string.shrink_to_token(source).source_text(source), "'🎉'");
+        // Not on character boundaries
+        assert_eq!(Span::new(2, 4).shrink_to_token(source), Span::new(2, 4));
+    }
+
     #[test]
     fn test_ordering_greater() {
         assert!(Span::new(0, 1) > Span::new(0, 0));
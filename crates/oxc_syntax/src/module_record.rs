@@ -85,6 +85,32 @@ impl ModuleRecord {
     pub fn new(resolved_absolute_path: PathBuf) -> Self {
         Self { resolved_absolute_path, ..Self::default() }
     }
+
+    /// Names re-exported by an `export * from "mod"` entry of [`ModuleRecord::star_export_entries`],
+    /// resolved through [`ModuleRecord::loaded_modules`]. This includes the names the requested
+    /// module itself re-exports with `export *`, but never `default`.
+    ///
+    /// Returns `None` when the requested module has not been loaded.
+    pub fn star_export_names(&self, entry: &ExportEntry) -> Option<Vec<CompactStr>> {
+        let module_request = entry.module_request.as_ref()?;
+        let remote_module_record_ref = self.loaded_modules.get(module_request.name())?;
+        let remote_module_record = remote_module_record_ref.value();
+        let mut names = remote_module_record
+            .exported_bindings
+            .keys()
+            .cloned()
+            .chain(
+                remote_module_record
+                    .exported_bindings_from_star_export
+                    .iter()
+                    .flat_map(|r| r.value().clone()),
+            )
+            .filter(|name| name.as_str() != "default")
+            .collect::<Vec<_>>();
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+        names.dedup();
+        Some(names)
+    }
 }
 
 impl fmt::Debug for ModuleRecord {
@@ -171,9 +197,13 @@ impl ImportImportName {
 /// [`ExportEntry`](https://tc39.es/ecma262/#exportentry-record)
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct ExportEntry {
-    /// Span for the entire export entry
+    /// Span for the entire export entry, e.g. the specifier of `export { a as b }`,
+    /// or the declarator of `export const a = 1, b = 2`
     pub span: Span,
 
+    /// Span of the export statement this entry is declared in
+    pub statement_span: Span,
+
     /// The String value of the ModuleSpecifier of the ExportDeclaration.
     /// null if the ExportDeclaration does not have a ModuleSpecifier.
     pub module_request: Option<NameSpan>,
@@ -190,6 +220,41 @@ pub struct ExportEntry {
     /// The name that is used to locally access the exported value from within the importing module.
     /// null if the exported value is not locally accessible from within the module.
     pub local_name: ExportLocalName,
+
+    /// Kind of the exported binding, when it is declared in this module
+    pub binding_kind: ExportBindingKind,
+}
+
+/// Kind of the binding exported by an [`ExportEntry`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExportBindingKind {
+    /// `function f() {}`, or a variable initialized with a function or an arrow function
+    Function,
+    /// `class C {}`, or a variable initialized with a class
+    Class,
+    /// A `const` variable which is not initialized with a function or a class
+    Const,
+    /// A `let` or `var` variable which is not initialized with a function or a class
+    Variable,
+    /// `export default` of any other expression
+    Expression,
+    /// Re-exported bindings and bindings which are not declared at the top level of the module
+    #[default]
+    Unknown,
+}
+
+impl ExportBindingKind {
+    pub fn is_function(self) -> bool {
+        self == Self::Function
+    }
+
+    pub fn is_class(self) -> bool {
+        self == Self::Class
+    }
+
+    pub fn is_const(self) -> bool {
+        self == Self::Const
+    }
 }
 
 /// `ImportName` for `ExportEntry`
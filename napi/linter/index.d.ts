@@ -0,0 +1,61 @@
+/* tslint:disable */
+/* eslint-disable */
+
+/* auto-generated by NAPI-RS */
+
+export interface LinterOptions {
+  /**
+   * Name of the linted file, which determines its source type and the rules which apply.
+   *
+   * Default: `file.js`
+   */
+  sourceFilename?: string
+  /**
+   * Path to an oxlint configuration file, e.g. `.oxlintrc.json`.
+   * The built-in rules of the `correctness` category run without a configuration file.
+   */
+  configPath?: string
+}
+export interface LintResult {
+  /**
+   * The ESTree compatible AST, serialized as JSON, which the rules of JavaScript plugins visit.
+   *
+   * Positions are byte offsets into the UTF-8 encoded source text.
+   */
+  program: string
+  comments: Array<Comment>
+  /** The syntax errors of the file, the rules don't run when there are any. */
+  errors: Array<Diagnostic>
+  /** The problems reported by the built-in rules. */
+  diagnostics: Array<Diagnostic>
+}
+export interface Comment {
+  type: 'Line' | 'Block'
+  value: string
+  start: number
+  end: number
+}
+export interface Diagnostic {
+  /**
+   * The ESLint id of the rule which reported the problem, e.g. `no-debugger` or
+   * `unicorn/no-null`, `undefined` for syntax errors and invalid directives.
+   */
+  ruleId?: string
+  message: string
+  severity: 'error' | 'warning'
+  start: number
+  end: number
+}
+/**
+ * Lint `source_text` with the built-in rules, and return its AST for the rules of JavaScript
+ * plugins.
+ *
+ * # Errors
+ *
+ * * The configuration file cannot be read or is invalid
+ *
+ * # Panics
+ *
+ * * Serde JSON serialization
+ */
+export function lintSync(sourceText: string, options?: LinterOptions | undefined | null): LintResult
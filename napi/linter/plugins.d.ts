@@ -0,0 +1,88 @@
+import type { Comment, LinterOptions } from './index'
+
+/** A node of the ESTree AST of oxc, with `start` and `end` byte offsets. */
+export interface Node {
+  type: string
+  start: number
+  end: number
+  parent: Node | null
+  [key: string]: any
+}
+
+export interface SourceCode {
+  text: string
+  ast: Node
+  comments: Array<Comment>
+  /** The keys of the child nodes of each node type, in the order they are visited. */
+  visitorKeys: Record<string, Array<string>>
+  getText(node?: Node): string
+  getAllComments(): Array<Comment>
+  /** The 1-based line and the 0-based column, in UTF-16 code units, of the byte `offset`. */
+  getLocFromIndex(offset: number): { line: number; column: number }
+}
+
+export interface ReportDescriptor {
+  node?: Node
+  /** Byte offsets of the problem, instead of the ones of `node`. */
+  loc?: { start: number; end: number }
+  message?: string
+  messageId?: string
+  data?: Record<string, unknown>
+}
+
+export interface RuleContext {
+  id: string
+  options: Array<unknown>
+  filename: string
+  sourceCode: SourceCode
+  getFilename(): string
+  getSourceCode(): SourceCode
+  report(descriptor: ReportDescriptor): void
+}
+
+/** Listeners of node types, called with `${type}:exit` when leaving the nodes. */
+export type RuleListener = Record<string, (node: Node) => void>
+
+export interface Rule {
+  meta?: { messages?: Record<string, string>; [key: string]: unknown }
+  create(context: RuleContext): RuleListener
+}
+
+export interface Plugin {
+  rules?: Record<string, Rule>
+}
+
+export type RuleLevel = 'off' | 'warn' | 'error' | 0 | 1 | 2
+
+export interface PluginLintOptions {
+  /** Default: `file.js` */
+  filename?: string
+  configPath?: LinterOptions['configPath']
+  /** The rules of the registered plugins, e.g. `{ "my/no-foo": ["error", { ...options }] }` */
+  rules?: Record<string, RuleLevel | [RuleLevel, ...Array<unknown>]>
+}
+
+export interface LintMessage {
+  /** `null` for syntax errors */
+  ruleId: string | null
+  /** 1 for warnings, 2 for errors */
+  severity: 1 | 2
+  message: string
+  start: number
+  end: number
+  line: number
+  column: number
+  endLine: number
+  endColumn: number
+}
+
+export class PluginHost {
+  /** Register the rules of `plugin`, which are configured as `${name}/${ruleName}`. */
+  registerPlugin(name: string, plugin: Plugin): void
+  /**
+   * Lint `sourceText` with the built-in rules and the configured rules of the registered plugins.
+   * The messages are sorted by position, the rules of the plugins don't run when the file has
+   * syntax errors.
+   */
+  lint(sourceText: string, options?: PluginLintOptions): Array<LintMessage>
+}
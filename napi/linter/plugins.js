@@ -0,0 +1,209 @@
+// Host of JavaScript plugins: rules written like ESLint rules, which visit the ESTree AST of oxc
+// and report problems with `context.report`.
+//
+// ```js
+// const { PluginHost } = require('@oxc-linter/binding/plugins')
+//
+// const host = new PluginHost()
+// host.registerPlugin('my', {
+//   rules: {
+//     'no-foo': {
+//       meta: { messages: { foo: 'Unexpected {{ name }}.' } },
+//       create(context) {
+//         return {
+//           Identifier(node) {
+//             if (node.name === 'foo') context.report({ node, messageId: 'foo', data: { name: node.name } })
+//           },
+//         }
+//       },
+//     },
+//   },
+// })
+// const messages = host.lint('foo()', { filename: 'file.js', rules: { 'my/no-foo': 'error' } })
+// ```
+
+const { lintSync } = require('./index.js')
+
+const SEVERITIES = { off: 0, warn: 1, error: 2, 0: 0, 1: 1, 2: 2 }
+
+class PluginHost {
+  #rules = new Map()
+
+  /**
+   * Register the rules of `plugin`, which are configured as `${name}/${ruleName}`.
+   */
+  registerPlugin(name, plugin) {
+    for (const [ruleName, rule] of Object.entries(plugin.rules ?? {})) {
+      this.#rules.set(`${name}/${ruleName}`, rule)
+    }
+  }
+
+  /**
+   * Lint `sourceText` with the built-in rules and the configured rules of the registered plugins.
+   * The messages are sorted by position, the rules of the plugins don't run when the file has
+   * syntax errors.
+   */
+  lint(sourceText, options = {}) {
+    const { filename = 'file.js', configPath, rules = {} } = options
+    const result = lintSync(sourceText, { sourceFilename: filename, configPath })
+    const sourceCode = new SourceCode(sourceText, result)
+    const messages = [...result.errors, ...result.diagnostics].map((diagnostic) =>
+      sourceCode.message(diagnostic.ruleId ?? null, diagnostic.severity === 'error' ? 2 : 1, diagnostic.message, diagnostic.start, diagnostic.end)
+    )
+
+    if (result.errors.length === 0) {
+      const listeners = new Map()
+      for (const [ruleId, config] of Object.entries(rules)) {
+        const [level, ...ruleOptions] = Array.isArray(config) ? config : [config]
+        const severity = SEVERITIES[level]
+        if (severity === undefined) {
+          throw new Error(`Invalid severity ${JSON.stringify(level)} of rule \`${ruleId}\`.`)
+        }
+        if (severity === 0) continue
+        const rule = this.#rules.get(ruleId)
+        if (!rule) {
+          throw new Error(`Rule \`${ruleId}\` not found, register its plugin first.`)
+        }
+        const context = new RuleContext(ruleId, rule, severity, ruleOptions, filename, sourceCode, messages)
+        for (const [selector, listener] of Object.entries(rule.create(context))) {
+          if (!listeners.has(selector)) listeners.set(selector, [])
+          listeners.get(selector).push(listener)
+        }
+      }
+      for (const [selector, node] of sourceCode.events) {
+        for (const listener of listeners.get(selector) ?? []) listener(node)
+      }
+    }
+
+    return messages.sort((a, b) => a.start - b.start || a.end - b.end)
+  }
+}
+
+/**
+ * The source text and the AST of the linted file. Positions of nodes and comments are byte
+ * offsets into the UTF-8 encoded source text, as in the AST of oxc.
+ */
+class SourceCode {
+  #buffer
+  #lineStarts = [0]
+
+  constructor(text, result) {
+    this.text = text
+    this.#buffer = Buffer.from(text)
+    for (let i = 0; i < this.#buffer.length; i++) {
+      if (this.#buffer[i] === 0x0a) this.#lineStarts.push(i + 1)
+    }
+    this.ast = JSON.parse(result.program)
+    this.comments = result.comments
+    /** The keys of the child nodes of each node type, in the order they are visited. */
+    this.visitorKeys = {}
+    /** The node types of the visit, `${type}:exit` when leaving a node, with their nodes. */
+    this.events = []
+    this.#visit(this.ast, null)
+    for (const [type, keys] of Object.entries(this.visitorKeys)) this.visitorKeys[type] = [...keys]
+  }
+
+  #visit(node, parent) {
+    node.parent = parent
+    this.events.push([node.type, node])
+    const keys = (this.visitorKeys[node.type] ??= new Set())
+    for (const [key, value] of Object.entries(node)) {
+      if (key === 'parent') continue
+      if (isNode(value)) {
+        keys.add(key)
+        this.#visit(value, node)
+      } else if (Array.isArray(value) && value.some(isNode)) {
+        keys.add(key)
+        for (const child of value) if (isNode(child)) this.#visit(child, node)
+      }
+    }
+    this.events.push([`${node.type}:exit`, node])
+  }
+
+  getText(node) {
+    return node ? this.#buffer.subarray(node.start, node.end).toString() : this.text
+  }
+
+  getAllComments() {
+    return this.comments
+  }
+
+  /** The 1-based line and the 0-based column, in UTF-16 code units, of the byte `offset`. */
+  getLocFromIndex(offset) {
+    let low = 0
+    let high = this.#lineStarts.length - 1
+    while (low < high) {
+      const mid = (low + high + 1) >> 1
+      if (this.#lineStarts[mid] <= offset) low = mid
+      else high = mid - 1
+    }
+    const column = this.#buffer.subarray(this.#lineStarts[low], offset).toString().length
+    return { line: low + 1, column }
+  }
+
+  message(ruleId, severity, message, start, end) {
+    const loc = this.getLocFromIndex(start)
+    const endLoc = this.getLocFromIndex(end)
+    return {
+      ruleId,
+      severity,
+      message,
+      start,
+      end,
+      line: loc.line,
+      column: loc.column + 1,
+      endLine: endLoc.line,
+      endColumn: endLoc.column + 1,
+    }
+  }
+}
+
+/** The `context` argument of the `create` function of rules. */
+class RuleContext {
+  #rule
+  #severity
+  #messages
+
+  constructor(id, rule, severity, options, filename, sourceCode, messages) {
+    this.id = id
+    this.options = options
+    this.filename = filename
+    this.sourceCode = sourceCode
+    this.#rule = rule
+    this.#severity = severity
+    this.#messages = messages
+  }
+
+  getFilename() {
+    return this.filename
+  }
+
+  getSourceCode() {
+    return this.sourceCode
+  }
+
+  /**
+   * Report a problem at `node`, or at `loc` given as byte offsets `{ start, end }`.
+   * The message is either `message` or the message `messageId` of the `meta.messages` of the
+   * rule, with the `{{ key }}` placeholders replaced by the values of `data`.
+   */
+  report({ node, loc, message, messageId, data = {} }) {
+    if (messageId !== undefined) {
+      message = this.#rule.meta?.messages?.[messageId]
+      if (message === undefined) {
+        throw new Error(`Rule \`${this.id}\` has no message \`${messageId}\`.`)
+      }
+    }
+    message = message.replace(/\{\{\s*([^{}]+?)\s*\}\}/g, (placeholder, key) =>
+      key in data ? String(data[key]) : placeholder
+    )
+    const { start, end } = loc ?? node
+    this.#messages.push(this.sourceCode.message(this.id, this.#severity, message, start, end))
+  }
+}
+
+function isNode(value) {
+  return value !== null && typeof value === 'object' && typeof value.type === 'string'
+}
+
+module.exports.PluginHost = PluginHost
@@ -0,0 +1,141 @@
+use std::{path::PathBuf, rc::Rc};
+
+use napi_derive::napi;
+use oxc_allocator::Allocator;
+use oxc_ast::CommentKind;
+use oxc_diagnostics::{eslint_rule_id, OxcDiagnostic, Severity};
+use oxc_linter::{LintContext, LintOptions, Linter};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+#[napi(object)]
+#[derive(Default)]
+pub struct LinterOptions {
+    /// Name of the linted file, which determines its source type and the rules which apply.
+    ///
+    /// Default: `file.js`
+    pub source_filename: Option<String>,
+    /// Path to an oxlint configuration file, e.g. `.oxlintrc.json`.
+    /// The built-in rules of the `correctness` category run without a configuration file.
+    pub config_path: Option<String>,
+}
+
+#[napi(object)]
+pub struct LintResult {
+    /// The ESTree compatible AST, serialized as JSON, which the rules of JavaScript plugins visit.
+    ///
+    /// Positions are byte offsets into the UTF-8 encoded source text.
+    pub program: String,
+    pub comments: Vec<Comment>,
+    /// The syntax errors of the file, the rules don't run when there are any.
+    pub errors: Vec<Diagnostic>,
+    /// The problems reported by the built-in rules.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[napi(object)]
+pub struct Comment {
+    #[napi(ts_type = "'Line' | 'Block'")]
+    pub r#type: &'static str,
+    pub value: String,
+    pub start: u32,
+    pub end: u32,
+}
+
+#[napi(object)]
+pub struct Diagnostic {
+    /// The ESLint id of the rule which reported the problem, e.g. `no-debugger` or
+    /// `unicorn/no-null`, `undefined` for syntax errors and invalid directives.
+    pub rule_id: Option<String>,
+    pub message: String,
+    #[napi(ts_type = "'error' | 'warning'")]
+    pub severity: &'static str,
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Diagnostic {
+    fn new(diagnostic: &OxcDiagnostic, rule_name: Option<&str>) -> Self {
+        // The messages of the rules start with their id, e.g. `eslint(no-debugger): `
+        let (rule_id, message) = rule_name
+            .and_then(|rule_name| {
+                let (id, message) = diagnostic.message.split_once(": ")?;
+                id.ends_with(&format!("({rule_name})")).then(|| (eslint_rule_id(id), message))
+            })
+            .map_or((None, diagnostic.message.as_str()), |(id, message)| (Some(id), message));
+        let severity = match diagnostic.severity {
+            Severity::Error => "error",
+            _ => "warning",
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        let (start, end) =
+            diagnostic.labels.as_ref().and_then(|labels| labels.first()).map_or((0, 0), |label| {
+                (label.offset() as u32, (label.offset() + label.len()) as u32)
+            });
+        Self { rule_id, message: message.to_string(), severity, start, end }
+    }
+}
+
+/// Lint `source_text` with the built-in rules, and return its AST for the rules of JavaScript
+/// plugins.
+///
+/// # Errors
+///
+/// * The configuration file cannot be read or is invalid
+///
+/// # Panics
+///
+/// * Serde JSON serialization
+#[allow(clippy::needless_pass_by_value)]
+#[napi]
+pub fn lint_sync(source_text: String, options: Option<LinterOptions>) -> napi::Result<LintResult> {
+    let options = options.unwrap_or_default();
+    let path = PathBuf::from(options.source_filename.unwrap_or_else(|| "file.js".to_string()));
+    let linter = Linter::from_options(
+        LintOptions::default().with_config_path(options.config_path.map(PathBuf::from)),
+    )
+    .map_err(|error| napi::Error::from_reason(format!("{error:?}")))?;
+
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(&path).unwrap_or_default();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let program = allocator.alloc(ret.program);
+
+    let comments = ret
+        .trivias
+        .comments()
+        .map(|(kind, span)| Comment {
+            r#type: match kind {
+                CommentKind::SingleLine => "Line",
+                CommentKind::MultiLine => "Block",
+            },
+            value: span.source_text(&source_text).to_string(),
+            start: span.start,
+            end: span.end,
+        })
+        .collect::<Vec<Comment>>();
+
+    let mut errors =
+        ret.errors.iter().map(|error| Diagnostic::new(error, None)).collect::<Vec<_>>();
+    let mut diagnostics = vec![];
+    if errors.is_empty() {
+        let semantic_ret = SemanticBuilder::new(&source_text, source_type)
+            .with_cfg(true)
+            .with_trivias(ret.trivias)
+            .with_check_syntax_error(true)
+            .build(program);
+        errors.extend(semantic_ret.errors.iter().map(|error| Diagnostic::new(error, None)));
+        if errors.is_empty() {
+            let ctx = LintContext::new(path.into_boxed_path(), Rc::new(semantic_ret.semantic));
+            diagnostics = linter
+                .run(ctx)
+                .iter()
+                .map(|message| Diagnostic::new(&message.error, message.rule_name()))
+                .collect();
+        }
+    }
+
+    let program = serde_json::to_string(&program).unwrap();
+    Ok(LintResult { program, comments, errors, diagnostics })
+}
@@ -0,0 +1,45 @@
+import oxc from './index.js';
+import plugins from './plugins.js';
+import assert from 'assert';
+
+console.log(`Testing on ${process.platform}-${process.arch}`)
+
+const ret = oxc.lintSync("debugger;\nfoo", { sourceFilename: "test.js" });
+console.log(ret);
+assert(JSON.parse(ret.program).body.length == 2);
+assert(ret.errors.length == 0);
+assert.deepEqual(ret.diagnostics.map((d) => d.ruleId), ["no-debugger"]);
+
+assert(oxc.lintSync("let = ;").errors.length == 1);
+
+const host = new plugins.PluginHost();
+host.registerPlugin("my", {
+  rules: {
+    "no-foo": {
+      meta: { messages: { foo: "Unexpected {{ name }}." } },
+      create(context) {
+        return {
+          Identifier(node) {
+            if (node.name === "foo") {
+              assert.equal(node.parent.type, "CallExpression");
+              context.report({ node, messageId: "foo", data: { name: node.name } });
+            }
+          },
+          "Program:exit"(node) {
+            assert.equal(context.sourceCode.getText(node.body[1]), "foo();");
+          },
+        };
+      },
+    },
+  },
+});
+const messages = host.lint("debugger;\n/* ñ */ foo();", { filename: "test.js", rules: { "my/no-foo": "error" } });
+console.log(messages);
+assert.deepEqual(
+  messages.map(({ ruleId, severity, message, line, column }) => ({ ruleId, severity, message, line, column })),
+  [
+    { ruleId: "no-debugger", severity: 1, message: "`debugger` statement is not allowed", line: 1, column: 1 },
+    { ruleId: "my/no-foo", severity: 2, message: "Unexpected foo.", line: 2, column: 9 },
+  ],
+);
+assert.throws(() => host.lint("", { rules: { "my/no-bar": "error" } }));
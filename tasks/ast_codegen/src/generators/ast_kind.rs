@@ -120,6 +120,11 @@ impl Generator for AstKindGenerator {
         let kinds: Vec<Variant> =
             have_kinds.iter().map(|(ident, typ)| parse_quote!(#ident(&'a #typ))).collect_vec();
 
+        let type_matches: Vec<Arm> = have_kinds
+            .iter()
+            .map(|(ident, _)| parse_quote!(Self :: #ident(_) => AstType :: #ident))
+            .collect_vec();
+
         let span_matches: Vec<Arm> = have_kinds
             .iter()
             .map(|(ident, _)| parse_quote!(Self :: #ident(it) => it.span()))
@@ -135,7 +140,7 @@ impl Generator for AstKindGenerator {
 
             endl!();
 
-            #[derive(Debug, Clone, Copy)]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
             pub enum AstType {
                 #(#types),*,
             }
@@ -150,6 +155,17 @@ impl Generator for AstKindGenerator {
 
             endl!();
 
+            impl AstKind<'_> {
+                /// The type of the node, without its data.
+                pub fn ty(&self) -> AstType {
+                    match self {
+                        #(#type_matches),*,
+                    }
+                }
+            }
+
+            endl!();
+
             impl<'a> GetSpan for AstKind<'a> {
                 #[allow(clippy::match_same_arms)]
                 fn span(&self) -> Span {
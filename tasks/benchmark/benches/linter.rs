@@ -44,7 +44,8 @@ fn bench_linter(criterion: &mut Criterion) {
                     .with_jest_plugin(true)
                     .with_jsx_a11y_plugin(true)
                     .with_nextjs_plugin(true)
-                    .with_react_perf_plugin(true);
+                    .with_react_perf_plugin(true)
+                    .with_react_refresh_plugin(true);
                 let linter = Linter::from_options(lint_options).unwrap();
                 let semantic = Rc::new(semantic_ret.semantic);
                 b.iter(|| {
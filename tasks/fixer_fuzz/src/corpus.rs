@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+
+use oxc_span::VALID_EXTENSIONS;
+use walkdir::WalkDir;
+
+pub struct Source {
+    pub path: PathBuf,
+    pub source_text: String,
+}
+
+/// Seeds exercising the fixable rules. Mutations are applied on top of these.
+const SEEDS: &[(&str, &str)] = &[
+    ("eqeqeq.js", "if (a == b) {} if (typeof a != 'string') {}"),
+    ("no_debugger.js", "function foo() { debugger; return 1 }"),
+    ("no_unsafe_negation.js", "if (!key in object) {} if (!obj instanceof Ctor) {}"),
+    ("no_unused_labels.js", "A: for (const a of b) { foo(); } B: { bar(); }"),
+    ("no_useless_constructor.js", "class A extends B { constructor(...args) { super(...args); } }"),
+    ("no_useless_escape.js", r#"const a = "\a"; const b = /\#/; const c = `\d`;"#),
+    ("sort_imports.js", "import { b, a } from 'foo';\nimport c from 'bar';"),
+    ("use_isnan.js", "if (x === NaN) {} switch (y) { case NaN: break; }"),
+    ("valid_typeof.js", "typeof foo === 'strnig'; typeof bar == 'undefimed';"),
+    ("jest.test.js", "describe.only('a', () => { fit('b', () => { expect(a).toBeCalled(); expect(b).toEqual(null); expect(c.length).toBe(1); }); });"),
+    ("jest_prefix.test.js", "xdescribe('a', () => { xit('b', () => {}); test.skip('c'); });"),
+    ("array_type.ts", "let a: Array<string> = []; let b: ReadonlyArray<number> = [];"),
+    ("ban_ts_comment.ts", "// @ts-ignore\nconst a: any = 1;\n/* tslint:disable */"),
+    ("consistent_type.ts", "type T = { a: string }; interface I { [key: string]: number }"),
+    ("no_explicit_any.ts", "function foo(a: any): any { return a as any; }"),
+    ("no_import_type_side_effects.ts", "import { type A, type B } from 'mod'; export {};"),
+    ("prefer_as_const.ts", "let a: 'foo' = 'foo'; let b = 'bar' as 'bar';"),
+    ("prefer_function_type.ts", "interface Fn { (a: string): void }"),
+    ("no_const_enum.ts", "const enum E { A, B }"),
+    ("unicorn_braces.js", "switch (a) { case 1: foo(); break; } class A {   } try {   } catch {}"),
+    ("unicorn_escape.js", r#"const a = "\xa9"; const b = 0XFF; const c = 1.0; const d = 10000000;"#),
+    ("unicorn_console.js", "console.log(' a ', 'b '); new Array(...[1, 2]); Promise.all([p]);"),
+    ("unicorn_misc.js", "if (foo.length) {} foo instanceof Array; a ? b : c ? d : e; let x = null;"),
+    ("unicorn_spread.js", "const a = [...[1, 2]]; const b = {...(c || {})}; Array.from(set);"),
+    ("unicorn_dom.js", "node.innerText; document.getElementById('a'); [].slice.call(arguments); n.toFixed();"),
+    ("unicorn_await.js", "async function f() { await 1; return foo(await Promise.resolve(2)); }"),
+    ("comments.js", "if (a /* a */ == /* b */ b) { debugger /* c */; }\nfoo.length // length\n"),
+];
+
+/// Load the built-in seeds, plus every lintable file under `dir` when given.
+pub fn load(dir: Option<&Path>) -> Vec<Source> {
+    let mut sources = SEEDS
+        .iter()
+        .map(|(name, source_text)| Source {
+            path: PathBuf::from(name),
+            source_text: (*source_text).to_string(),
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(dir) = dir {
+        sources.extend(
+            WalkDir::new(dir)
+                .into_iter()
+                .filter_map(Result::ok)
+                .filter(|e| {
+                    e.path()
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| VALID_EXTENSIONS.contains(&ext))
+                })
+                .filter_map(|e| {
+                    let source_text = std::fs::read_to_string(e.path()).ok()?;
+                    Some(Source { path: e.into_path(), source_text })
+                }),
+        );
+    }
+
+    sources
+}
@@ -0,0 +1,251 @@
+//! Fixer fuzzing harness
+//!
+//! Mutates a corpus of JavaScript and TypeScript sources, applies the fixes of every lint rule,
+//! and checks that
+//!
+//! * the fixed output still parses,
+//! * fixes do not drop comments inside the range they rewrite,
+//! * fixing converges, i.e. the rule no longer reports fixable problems after a few passes.
+//!
+//! Usage: `cargo run -p oxc_fixer_fuzz -- [--seed N] [--iterations N] [--rule NAME] [--corpus DIR]`
+
+mod corpus;
+mod mutate;
+
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use oxc_allocator::Allocator;
+use oxc_linter::{
+    table::RuleTable, AllowWarnDeny, Fixer, LintContext, LintOptions, Linter, Message,
+};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, Span};
+
+use crate::{corpus::Source, mutate::Mutator};
+
+/// Number of fix passes after which a rule is considered to not converge.
+const MAX_PASSES: usize = 10;
+
+pub struct FuzzOptions {
+    /// Seed of the mutator, runs with the same seed are reproducible.
+    pub seed: u64,
+    /// Number of mutated variants generated per corpus file.
+    pub iterations: usize,
+    /// Only check the rule with this name.
+    pub rule: Option<String>,
+    /// Directory with additional corpus files.
+    pub corpus: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureKind {
+    /// The fixed output contains syntax errors.
+    ParseError,
+    /// A fix removed a comment located inside the range it rewrites.
+    DroppedComment,
+    /// The fix did not change the source although the rule still reports fixable problems.
+    NoProgress,
+    /// The rule still reports fixable problems after [`MAX_PASSES`] passes.
+    NoConvergence,
+}
+
+pub struct Failure {
+    pub rule: &'static str,
+    pub kind: FailureKind,
+    pub path: PathBuf,
+    /// Source the rule was run on.
+    pub source_text: String,
+    /// Output of the last fix pass.
+    pub output: String,
+    pub detail: Option<String>,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {:?} in {}", self.rule, self.kind, self.path.display())?;
+        if let Some(detail) = &self.detail {
+            writeln!(f, "  {detail}")?;
+        }
+        writeln!(f, "--- source\n{}", self.source_text)?;
+        writeln!(f, "+++ output\n{}", self.output)
+    }
+}
+
+impl FuzzOptions {
+    /// Run the fuzzer, returning at most one failure per rule and failure kind.
+    pub fn run(&self) -> Vec<Failure> {
+        let sources = corpus::load(self.corpus.as_deref());
+        let fuzzer = Fuzzer::new(self.rule.as_deref());
+        let mut mutator = Mutator::new(self.seed);
+        let mut failures: Vec<Failure> = vec![];
+
+        let mut record = |failure: Failure| {
+            if !failures.iter().any(|f| f.rule == failure.rule && f.kind == failure.kind) {
+                failures.push(failure);
+            }
+        };
+
+        for Source { path, source_text } in &sources {
+            let Ok(source_type) = SourceType::from_path(path) else { continue };
+            fuzzer.check(path, source_text, &mut record);
+            for _ in 0..self.iterations {
+                if let Some(mutated) = mutator.mutate(source_text, source_type) {
+                    fuzzer.check(path, &mutated, &mut record);
+                }
+            }
+        }
+
+        failures
+    }
+}
+
+struct Fuzzer {
+    /// All rules, used for skipping sources without any fixable problem.
+    all: Linter,
+    /// One linter per rule, so fixes and convergence can be attributed to a rule.
+    rules: Vec<(&'static str, Linter)>,
+}
+
+impl Fuzzer {
+    fn new(filter: Option<&str>) -> Self {
+        let mut names = RuleTable::new()
+            .sections
+            .into_iter()
+            .flat_map(|section| section.rows)
+            .map(|row| row.name)
+            .filter(|name| filter.is_none() || filter == Some(*name))
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names.dedup();
+
+        let all = create_linter(
+            names.iter().map(|name| (AllowWarnDeny::Warn, (*name).to_string())).collect(),
+        );
+        let rules = names
+            .into_iter()
+            .map(|name| (name, create_linter(vec![(AllowWarnDeny::Warn, name.to_string())])))
+            .collect();
+        Self { all, rules }
+    }
+
+    fn check(&self, path: &Path, source_text: &str, record: &mut impl FnMut(Failure)) {
+        let allocator = Allocator::default();
+        let Ok((messages, _)) = lint(&self.all, &allocator, path, source_text) else { return };
+        if !messages.iter().any(has_fix) {
+            return;
+        }
+
+        for (rule, linter) in &self.rules {
+            if let Err((kind, output, detail)) = check_rule(linter, path, source_text) {
+                record(Failure {
+                    rule,
+                    kind,
+                    path: path.to_path_buf(),
+                    source_text: source_text.to_string(),
+                    output,
+                    detail,
+                });
+            }
+        }
+    }
+}
+
+fn create_linter(filter: Vec<(AllowWarnDeny, String)>) -> Linter {
+    let options = LintOptions::default()
+        .with_filter(filter)
+        .with_fix(true)
+        .with_jsdoc_plugin(true)
+        .with_jest_plugin(true)
+        .with_vitest_plugin(true)
+        .with_jsx_a11y_plugin(true)
+        .with_nextjs_plugin(true)
+        .with_react_perf_plugin(true)
+        .with_react_refresh_plugin(true);
+    Linter::from_options(options).unwrap()
+}
+
+/// Apply the fixes of a single rule until it stops reporting fixable problems.
+fn check_rule(
+    linter: &Linter,
+    path: &Path,
+    source_text: &str,
+) -> Result<(), (FailureKind, String, Option<String>)> {
+    let mut code = source_text.to_string();
+    for _ in 0..MAX_PASSES {
+        let allocator = Allocator::default();
+        let (messages, comments) = lint(linter, &allocator, path, &code)
+            .map_err(|error| (FailureKind::ParseError, code.clone(), Some(error)))?;
+        let messages = messages.into_iter().filter(has_fix).collect::<Vec<_>>();
+        if messages.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(comment) = find_dropped_comment(&code, &messages, &comments) {
+            let detail = format!("dropped comment `{comment}`");
+            let fixed = Fixer::new(&code, messages).fix().fixed_code.into_owned();
+            return Err((FailureKind::DroppedComment, fixed, Some(detail)));
+        }
+
+        let fixed = Fixer::new(&code, messages).fix().fixed_code.into_owned();
+        if fixed == code {
+            return Err((FailureKind::NoProgress, fixed, None));
+        }
+        code = fixed;
+    }
+    Err((FailureKind::NoConvergence, code, None))
+}
+
+fn has_fix(message: &Message) -> bool {
+    // Merging overlapping fixes results in an empty fix, which does nothing.
+    message.fix.as_ref().is_some_and(|fix| !fix.span.is_unspanned() || !fix.content.is_empty())
+}
+
+fn find_dropped_comment<'s>(
+    source_text: &'s str,
+    messages: &[Message],
+    comments: &[Span],
+) -> Option<&'s str> {
+    messages.iter().filter_map(|message| message.fix.as_ref()).find_map(|fix| {
+        comments
+            .iter()
+            .filter(|comment| fix.span.start <= comment.start && comment.end <= fix.span.end)
+            .map(|comment| comment.source_text(source_text))
+            .find(|comment| !fix.content.contains(comment))
+    })
+}
+
+/// Lint `source_text`, returning the reported messages and the spans of all comments,
+/// or the first syntax error.
+fn lint<'a>(
+    linter: &Linter,
+    allocator: &'a Allocator,
+    path: &Path,
+    source_text: &'a str,
+) -> Result<(Vec<Message<'a>>, Vec<Span>), String> {
+    let source_type = SourceType::from_path(path).unwrap_or_default();
+    let ret = Parser::new(allocator, source_text, source_type).parse();
+    if let Some(error) = ret.errors.first() {
+        return Err(error.to_string());
+    }
+    let comments = ret.trivias.comments().map(|(_, span)| span).collect();
+
+    let program = allocator.alloc(ret.program);
+    let ret = SemanticBuilder::new(source_text, source_type)
+        .with_cfg(true)
+        .with_build_jsdoc(true)
+        .with_trivias(ret.trivias)
+        .with_check_syntax_error(true)
+        .build_module_record(path.to_path_buf(), program)
+        .build(program);
+    if let Some(error) = ret.errors.first() {
+        return Err(error.to_string());
+    }
+
+    let ctx = LintContext::new(path.to_path_buf().into_boxed_path(), Rc::new(ret.semantic));
+    Ok((linter.run(ctx), comments))
+}
@@ -0,0 +1,27 @@
+#![allow(clippy::print_stdout)]
+use std::process::ExitCode;
+
+use oxc_fixer_fuzz::FuzzOptions;
+use pico_args::Arguments;
+
+fn main() -> ExitCode {
+    let mut args = Arguments::from_env();
+
+    let options = FuzzOptions {
+        seed: args.opt_value_from_str("--seed").unwrap().unwrap_or(0x5eed),
+        iterations: args.opt_value_from_str("--iterations").unwrap().unwrap_or(20),
+        rule: args.opt_value_from_str("--rule").unwrap(),
+        corpus: args.opt_value_from_str("--corpus").unwrap(),
+    };
+
+    let failures = options.run();
+    for failure in &failures {
+        println!("{failure}");
+    }
+    println!("Found {} fixer failure(s)", failures.len());
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
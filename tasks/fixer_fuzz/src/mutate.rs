@@ -0,0 +1,82 @@
+use oxc_allocator::Allocator;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{GetSpan, SourceType};
+
+/// Trivia inserted between tokens.
+/// Fixers are expected to keep working, and to keep comments, when these appear inside the
+/// ranges they rewrite.
+const INSERTIONS: &[&str] = &[" ", "\n", "/* fuzz */", "// fuzz\n", "/** @type {any} */", "\n\n"];
+
+/// Deterministic source mutator, driven by a xorshift pseudo random number generator.
+pub struct Mutator {
+    state: u64,
+}
+
+impl Mutator {
+    pub fn new(seed: u64) -> Self {
+        // xorshift gets stuck on zero
+        Self { state: seed.max(1) }
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// Insert one to three pieces of trivia at AST node boundaries.
+    /// Returns `None` when the source cannot be parsed.
+    pub fn mutate(&mut self, source_text: &str, source_type: SourceType) -> Option<String> {
+        let boundaries = node_boundaries(source_text, source_type)?;
+        if boundaries.is_empty() {
+            return None;
+        }
+
+        let count = 1 + self.below(3);
+        let mut edits = (0..count)
+            .map(|_| {
+                let position = boundaries[self.below(boundaries.len())];
+                let insertion = INSERTIONS[self.below(INSERTIONS.len())];
+                (position, insertion)
+            })
+            .collect::<Vec<_>>();
+        edits.sort_unstable_by_key(|(position, _)| std::cmp::Reverse(*position));
+
+        let mut output = source_text.to_string();
+        for (position, insertion) in edits {
+            output.insert_str(position as usize, insertion);
+        }
+        Some(output)
+    }
+}
+
+/// Start and end offsets of every AST node, which are always token boundaries.
+fn node_boundaries(source_text: &str, source_type: SourceType) -> Option<Vec<u32>> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return None;
+    }
+    let program = allocator.alloc(ret.program);
+    let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+    let mut boundaries = semantic
+        .nodes()
+        .iter()
+        .flat_map(|node| {
+            let span = node.kind().span();
+            [span.start, span.end]
+        })
+        .collect::<Vec<_>>();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    Some(boundaries)
+}
@@ -0,0 +1,79 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use serde::Deserialize;
+
+use crate::Diagnostic;
+
+/// Output of `eslint --format json`
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FileResult {
+    file_path: PathBuf,
+    messages: Vec<MessageResult>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MessageResult {
+    /// `null` for syntax errors
+    rule_id: Option<String>,
+    line: usize,
+    column: usize,
+}
+
+pub fn run(config: Option<&Path>, paths: &[PathBuf]) -> Result<Vec<Diagnostic>, String> {
+    let mut command = Command::new("npx");
+    command.args(["--no-install", "eslint", "--format", "json"]);
+    if let Some(config) = config {
+        command.arg("--no-eslintrc").arg("-c").arg(config);
+    }
+    command.args(paths);
+
+    let output =
+        command.output().map_err(|err| format!("Failed to run ESLint, is it installed? {err}"))?;
+    // ESLint exits with 1 when there are lint errors
+    if !matches!(output.status.code(), Some(0 | 1)) {
+        return Err(format!(
+            "ESLint failed with {}:\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        return Err(format!(
+            "ESLint produced no output, is it installed?\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    parse_results(&stdout)
+}
+
+pub fn read_results(path: &Path) -> Result<Vec<Diagnostic>, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read ESLint results {}: {err}", path.display()))?;
+    parse_results(&json)
+}
+
+fn parse_results(json: &str) -> Result<Vec<Diagnostic>, String> {
+    let results = serde_json::from_str::<Vec<FileResult>>(json)
+        .map_err(|err| format!("Failed to parse ESLint results: {err}"))?;
+    Ok(results
+        .into_iter()
+        .flat_map(|result| {
+            let path = result.file_path.canonicalize().unwrap_or(result.file_path);
+            result.messages.into_iter().filter_map(move |message| {
+                Some(Diagnostic {
+                    rule: message.rule_id?,
+                    path: path.clone(),
+                    line: message.line,
+                    column: message.column,
+                })
+            })
+        })
+        .collect())
+}
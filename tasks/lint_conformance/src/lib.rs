@@ -0,0 +1,57 @@
+//! ESLint conformance runner
+//!
+//! Runs oxlint and ESLint with the same `.eslintrc.json` over a corpus, then diffs the
+//! reported diagnostics per rule to produce a compatibility report.
+//!
+//! ESLint is run with `npx eslint --no-eslintrc -c <config> --format json <paths>`,
+//! alternatively pass the output of a previous ESLint run with `--eslint-results <file>`.
+//!
+//! Usage: `cargo run -p oxc_lint_conformance -- --config .eslintrc.json [--detail] <paths>`
+
+mod eslint;
+mod oxlint;
+mod report;
+
+use std::path::PathBuf;
+
+pub struct ConformanceOptions {
+    /// ESLint v8 configuration file shared by both linters.
+    pub config: Option<PathBuf>,
+    /// Output of `eslint --format json`, ESLint is run through `npx` when omitted.
+    pub eslint_results: Option<PathBuf>,
+    /// List every mismatching diagnostic in the report.
+    pub detail: bool,
+    /// Files and directories to lint.
+    pub paths: Vec<PathBuf>,
+}
+
+/// A diagnostic reported by either linter, positions are 1-based.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Diagnostic {
+    /// ESLint rule id, e.g. `no-debugger` or `@typescript-eslint/no-explicit-any`.
+    pub rule: String,
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl ConformanceOptions {
+    /// # Errors
+    ///
+    /// * The configuration cannot be parsed by oxlint
+    /// * ESLint cannot be run, or its output cannot be parsed
+    pub fn run(&self) -> Result<String, String> {
+        let paths = if self.paths.is_empty() {
+            vec![std::env::current_dir().map_err(|err| err.to_string())?]
+        } else {
+            self.paths.clone()
+        };
+        let files = oxlint::collect_files(&paths);
+        let (implemented_rules, oxlint) = oxlint::run(self.config.clone(), &files)?;
+        let eslint = match &self.eslint_results {
+            Some(results) => eslint::read_results(results)?,
+            None => eslint::run(self.config.as_deref(), &paths)?,
+        };
+        Ok(report::render(&implemented_rules, oxlint, eslint, self.detail))
+    }
+}
@@ -0,0 +1,27 @@
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+use std::process::ExitCode;
+
+use oxc_lint_conformance::ConformanceOptions;
+use pico_args::Arguments;
+
+fn main() -> ExitCode {
+    let mut args = Arguments::from_env();
+
+    let options = ConformanceOptions {
+        config: args.opt_value_from_str("--config").unwrap(),
+        eslint_results: args.opt_value_from_str("--eslint-results").unwrap(),
+        detail: args.contains("--detail"),
+        paths: args.finish().into_iter().map(Into::into).collect(),
+    };
+
+    match options.run() {
+        Ok(report) => {
+            println!("{report}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use oxc_diagnostics::{diagnostic_channel, Error};
+use oxc_linter::{
+    partial_loader::LINT_PARTIAL_LOADER_EXT, table::RuleTable, AllowWarnDeny, LintOptions,
+    LintService, LintServiceOptions, Linter,
+};
+use oxc_span::VALID_EXTENSIONS;
+use rustc_hash::FxHashSet;
+use walkdir::WalkDir;
+
+use crate::Diagnostic;
+
+/// All lintable files under `paths`, skipping `node_modules`.
+pub fn collect_files(paths: &[PathBuf]) -> Vec<PathBuf> {
+    paths
+        .iter()
+        .flat_map(|path| {
+            WalkDir::new(path)
+                .into_iter()
+                .filter_entry(|e| e.file_name() != "node_modules")
+                .filter_map(Result::ok)
+                .filter(|e| !e.file_type().is_dir())
+                .map(walkdir::DirEntry::into_path)
+        })
+        .filter(|path| {
+            path.extension().and_then(|ext| ext.to_str()).is_some_and(|ext| {
+                VALID_EXTENSIONS.contains(&ext) || LINT_PARTIAL_LOADER_EXT.contains(&ext)
+            })
+        })
+        .filter_map(|path| path.canonicalize().ok())
+        .collect()
+}
+
+/// Lint `files` with only the rules enabled in `config`.
+/// Returns the ESLint ids of all rules implemented by oxlint along with the diagnostics.
+pub fn run(
+    config: Option<PathBuf>,
+    files: &[PathBuf],
+) -> Result<(FxHashSet<String>, Vec<Diagnostic>), String> {
+    let options = LintOptions::default()
+        .with_filter(vec![(AllowWarnDeny::Allow, "all".to_string())])
+        .with_config_path(config)
+        .with_import_plugin(true)
+        .with_jsdoc_plugin(true)
+        .with_jest_plugin(true)
+        .with_vitest_plugin(true)
+        .with_jsx_a11y_plugin(true)
+        .with_nextjs_plugin(true)
+        .with_react_perf_plugin(true)
+        .with_react_refresh_plugin(true);
+    let linter = Linter::from_options(options).map_err(|err| format!("{err:?}"))?;
+
+    let cwd = std::env::current_dir().map_err(|err| err.to_string())?;
+    let paths = files.iter().map(|path| path.clone().into_boxed_path()).collect();
+    let options = LintServiceOptions {
+        cwd: cwd.clone().into_boxed_path(),
+        paths,
+        tsconfig: None,
+        cache: None,
+        stdin: None,
+    };
+    let service = LintService::new(linter, options);
+
+    // The channel is bounded, the diagnostics are received while the files are linted
+    let (tx, rx) = diagnostic_channel();
+    let diagnostics = std::thread::scope(|scope| {
+        scope.spawn(move || service.run(&tx));
+        rx.into_iter()
+            .flatten()
+            .flat_map(|(path, errors)| {
+                // Diagnostics are reported relative to the current working directory
+                let path = cwd.join(path);
+                errors.into_iter().filter_map(move |error| to_diagnostic(&path, &error))
+            })
+            .collect()
+    });
+
+    let implemented_rules = RuleTable::new()
+        .sections
+        .into_iter()
+        .flat_map(|section| section.rows)
+        .filter_map(|row| eslint_rule_id(&row.plugin, row.name))
+        .collect();
+
+    Ok((implemented_rules, diagnostics))
+}
+
+fn to_diagnostic(path: &Path, error: &Error) -> Option<Diagnostic> {
+    // Rule diagnostics are formatted as `plugin(rule): message`
+    let message = error.to_string();
+    let (scope, rule) = message.split_once(':')?.0.strip_suffix(')')?.split_once('(')?;
+    let rule = eslint_rule_id_from_scope(scope, rule)?;
+    let label = error.labels()?.next()?;
+    let span = error.source_code()?.read_span(label.inner(), 0, 0).ok()?;
+    Some(Diagnostic {
+        rule,
+        path: path.to_path_buf(),
+        line: span.line() + 1,
+        column: span.column() + 1,
+    })
+}
+
+/// Map a diagnostic scope such as `eslint-plugin-unicorn` to an ESLint rule id.
+fn eslint_rule_id_from_scope(scope: &str, rule: &str) -> Option<String> {
+    let plugin = match scope {
+        "eslint" => return Some(rule.to_string()),
+        "typescript-eslint" => "typescript",
+        "eslint-plugin-next" => "nextjs",
+        scope => scope.strip_prefix("eslint-plugin-")?,
+    };
+    eslint_rule_id(&plugin.replace('-', "_"), rule)
+}
+
+/// Map an oxlint plugin name and rule name to an ESLint rule id.
+/// Returns `None` for oxlint specific rules.
+fn eslint_rule_id(plugin: &str, rule: &str) -> Option<String> {
+    let prefix = match plugin {
+        "eslint" => return Some(rule.to_string()),
+        "oxc" | "tree_shaking" => return None,
+        "typescript" => "@typescript-eslint",
+        "nextjs" => "@next/next",
+        "jsx_a11y" => "jsx-a11y",
+        "react_perf" => "react-perf",
+        "react_refresh" => "react-refresh",
+        // For backwards compatibility, react hook rules reside in the react plugin.
+        "react" if rule == "rules-of-hooks" => "react-hooks",
+        plugin => plugin,
+    };
+    Some(format!("{prefix}/{rule}"))
+}
@@ -0,0 +1,105 @@
+use std::fmt::Write;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::Diagnostic;
+
+#[derive(Default)]
+struct RuleResult {
+    matched: usize,
+    only_oxlint: Vec<Diagnostic>,
+    only_eslint: Vec<Diagnostic>,
+}
+
+impl RuleResult {
+    #[allow(clippy::cast_precision_loss)]
+    fn conformance(&self) -> f64 {
+        let total = self.matched + self.only_oxlint.len() + self.only_eslint.len();
+        if total == 0 {
+            return 100.0;
+        }
+        self.matched as f64 / total as f64 * 100.0
+    }
+}
+
+/// Render a markdown report comparing diagnostics per rule.
+/// Rules not implemented by oxlint are listed separately.
+pub fn render(
+    implemented_rules: &FxHashSet<String>,
+    oxlint: Vec<Diagnostic>,
+    eslint: Vec<Diagnostic>,
+    detail: bool,
+) -> String {
+    let eslint_set = eslint.iter().cloned().collect::<FxHashSet<_>>();
+    let oxlint_set = oxlint.iter().cloned().collect::<FxHashSet<_>>();
+
+    let mut results: FxHashMap<String, RuleResult> = FxHashMap::default();
+    let mut not_implemented: FxHashMap<String, usize> = FxHashMap::default();
+
+    for diagnostic in oxlint {
+        let result = results.entry(diagnostic.rule.clone()).or_default();
+        if eslint_set.contains(&diagnostic) {
+            result.matched += 1;
+        } else {
+            result.only_oxlint.push(diagnostic);
+        }
+    }
+    for diagnostic in eslint {
+        if !implemented_rules.contains(&diagnostic.rule) {
+            *not_implemented.entry(diagnostic.rule).or_default() += 1;
+        } else if !oxlint_set.contains(&diagnostic) {
+            results.entry(diagnostic.rule.clone()).or_default().only_eslint.push(diagnostic);
+        }
+    }
+
+    let mut rules = results.into_iter().collect::<Vec<_>>();
+    rules.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let mut out = String::new();
+    let passed = rules.iter().filter(|(_, r)| r.only_oxlint.is_empty() && r.only_eslint.is_empty());
+    writeln!(out, "Rules with full parity: {}/{}\n", passed.count(), rules.len()).unwrap();
+    writeln!(out, "| Rule | Matched | Only oxlint | Only ESLint | Conformance |").unwrap();
+    writeln!(out, "| ---- | ------- | ----------- | ----------- | ----------- |").unwrap();
+    for (rule, result) in &rules {
+        writeln!(
+            out,
+            "| {rule} | {} | {} | {} | {:.2}% |",
+            result.matched,
+            result.only_oxlint.len(),
+            result.only_eslint.len(),
+            result.conformance()
+        )
+        .unwrap();
+    }
+
+    if !not_implemented.is_empty() {
+        let mut not_implemented = not_implemented.into_iter().collect::<Vec<_>>();
+        not_implemented.sort();
+        writeln!(out, "\nReported by ESLint, not implemented by oxlint:\n").unwrap();
+        for (rule, count) in not_implemented {
+            writeln!(out, "* {rule} ({count})").unwrap();
+        }
+    }
+
+    if detail {
+        for (rule, result) in &rules {
+            let mismatches = result
+                .only_oxlint
+                .iter()
+                .map(|d| ("oxlint", d))
+                .chain(result.only_eslint.iter().map(|d| ("ESLint", d)));
+            for (linter, d) in mismatches {
+                writeln!(
+                    out,
+                    "\n{rule}: only reported by {linter} at {}:{}:{}",
+                    d.path.display(),
+                    d.line,
+                    d.column
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    out
+}
@@ -5,5 +5,5 @@ mod rules;
 pub use self::{
     cli::print_cli,
     json_schema::{print_schema_json, print_schema_markdown},
-    rules::print_rules,
+    rules::{print_rules, print_rules_json, write_rule_docs},
 };
@@ -1,4 +1,6 @@
-use oxc_linter::table::RuleTable;
+use std::{fs, path::Path};
+
+use oxc_linter::{docs::RuleDocs, table::RuleTable};
 
 // `cargo run -p website linter-rules > /path/to/oxc/oxc-project.github.io/src/docs/guide/usage/linter/generated-rules.md`
 // <https://oxc.rs/docs/guide/usage/linter/rules.html>
@@ -31,3 +33,22 @@ The progress of all rule implementations is tracked [here](https://github.com/ox
 
 ");
 }
+
+// `cargo run -p website linter-rules-json > /path/to/rules.json`
+pub fn print_rules_json() {
+    println!("{}", serde_json::to_string_pretty(&RuleDocs::all()).unwrap());
+}
+
+// `cargo run -p website linter-rule-docs /path/to/oxc/oxc-project.github.io/src/docs/guide/usage/linter/rules`
+/// Write the documentation of each rule to `{dir}/{plugin}/{name}.md`.
+///
+/// # Panics
+///
+/// Panics if the files cannot be written.
+pub fn write_rule_docs(dir: &Path) {
+    for docs in RuleDocs::all() {
+        let plugin_dir = dir.join(docs.plugin);
+        fs::create_dir_all(&plugin_dir).unwrap();
+        fs::write(plugin_dir.join(format!("{}.md", docs.name)), docs.render_markdown()).unwrap();
+    }
+}
@@ -13,6 +13,11 @@ fn main() {
         "linter-schema-markdown" => linter::print_schema_markdown(),
         "linter-cli" => linter::print_cli(),
         "linter-rules" => linter::print_rules(),
+        "linter-rules-json" => linter::print_rules_json(),
+        "linter-rule-docs" => {
+            let dir: std::path::PathBuf = args.free_from_str().expect("output directory");
+            linter::write_rule_docs(&dir);
+        }
         _ => println!("Missing task command."),
     }
 }